@@ -21,7 +21,7 @@ let changedFile = entryFile
     )
     // Create an init function which imports the wasm file.
     .concat(
-        `let __initializedIotaWasm = false\r\n\r\nexport function init(path) {\r\n    if (__initializedIotaWasm) {\r\n        return Promise.resolve(wasm)\r\n    }\r\n    return __wbg_init(path || '${artifact}_bg.wasm').then(() => {\r\n        __initializedIotaWasm = true\r\n        return wasm\r\n    })\r\n}\r\n`,
+        `let __initializedIotaWasm = false\r\n\r\nexport function init(input) {\r\n    if (__initializedIotaWasm) {\r\n        return Promise.resolve(wasm)\r\n    }\r\n    return __wbg_init(input || '${artifact}_bg.wasm').then(() => {\r\n        __initializedIotaWasm = true\r\n        return wasm\r\n    })\r\n}\r\n`,
     );
 
 fs.writeFileSync(
@@ -36,11 +36,14 @@ const entryFileTs = fs.readFileSync(entryFilePathTs).toString();
 let changedFileTs = entryFileTs.concat(
     `
 /**
-* Loads the Wasm file so the lib can be used, relative path to Wasm file
+* Loads the Wasm file so the lib can be used.
 *
-* @param {string | undefined} path
+* \`input\` is either the path to the Wasm file or, for runtimes that cannot fetch it - e.g. Cloudflare Workers -
+* the Wasm module itself. Call \`configureRuntime\` beforehand to swap platform specific facilities.
+*
+* @param {string | URL | Request | Response | BufferSource | WebAssembly.Module | undefined} input
 */
-export function init (path?: string): Promise<void>;`,
+export function init (input?: string | URL | Request | Response | BufferSource | WebAssembly.Module): Promise<void>;`,
 );
 fs.writeFileSync(
     entryFilePathTs,
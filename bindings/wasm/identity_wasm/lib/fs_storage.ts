@@ -0,0 +1,74 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// Only available in the `node` build, through `@iota/identity-wasm/node/fs_storage`, since it depends on Node.js'
+// `fs` module.
+
+import { mkdir, readFile, unlink, writeFile } from "node:fs/promises";
+import { join } from "node:path";
+import { KeyValueJwkStore, KeyValueKeyIdStore, KeyValueStore } from "./kv_storage";
+
+/** A {@link KeyValueStore} persisting every value in its own file of a directory. */
+export class FsStore implements KeyValueStore {
+    /**
+     * Creates a new {@link FsStore} persisting values in `directory`, which is created on first write.
+     */
+    constructor(private readonly directory: string) {}
+
+    public async get(key: string): Promise<string | undefined> {
+        try {
+            return await readFile(this._path(key), "utf8");
+        } catch (err) {
+            if (isNotFound(err)) {
+                return undefined;
+            }
+            throw err;
+        }
+    }
+
+    public async set(key: string, value: string): Promise<void> {
+        await mkdir(this.directory, { recursive: true, mode: 0o700 });
+        await writeFile(this._path(key), value, { encoding: "utf8", mode: 0o600 });
+    }
+
+    public async delete(key: string): Promise<boolean> {
+        try {
+            await unlink(this._path(key));
+            return true;
+        } catch (err) {
+            if (isNotFound(err)) {
+                return false;
+            }
+            throw err;
+        }
+    }
+
+    private _path(key: string): string {
+        // Keys may contain `/`, e.g. the base64 encoded method digests of key ids.
+        return join(this.directory, encodeURIComponent(key));
+    }
+}
+
+/**
+ * A {@link KeyValueJwkStore} persisting keys in a directory, for Node.js.
+ *
+ * Private keys are stored unencrypted, in files only readable by the current user.
+ */
+export class FsJwkStore extends KeyValueJwkStore {
+    /** Creates a new {@link FsJwkStore} persisting keys in `directory`. */
+    constructor(directory: string) {
+        super(new FsStore(directory));
+    }
+}
+
+/** A {@link KeyValueKeyIdStore} persisting key ids in a directory, for Node.js. */
+export class FsKeyIdStore extends KeyValueKeyIdStore {
+    /** Creates a new {@link FsKeyIdStore} persisting key ids in `directory`. */
+    constructor(directory: string) {
+        super(new FsStore(directory));
+    }
+}
+
+function isNotFound(err: unknown): boolean {
+    return (err as NodeJS.ErrnoException | undefined)?.code === "ENOENT";
+}
@@ -3,9 +3,12 @@
 
 import "./append_functions.js";
 
+export * from "./indexeddb_storage";
 export * from "./jose";
 export * from "./jwk_storage";
 export * from "./key_id_storage";
+export * from "./kv_storage";
+export * from "./runtime";
 
 export * from "~identity_wasm";
 
@@ -0,0 +1,102 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { KeyValueJwkStore, KeyValueKeyIdStore, KeyValueStore } from "./kv_storage";
+
+const JWK_OBJECT_STORE = "jwks";
+const KEY_ID_OBJECT_STORE = "keyIds";
+const DEFAULT_DATABASE_NAME = "iota-identity";
+
+/** A {@link KeyValueStore} backed by an object store of an IndexedDB database. */
+export class IndexedDbStore implements KeyValueStore {
+    private _database: Promise<IDBDatabase> | undefined;
+
+    /**
+     * Creates a new {@link IndexedDbStore} persisting values in the object store `objectStoreName` - either `"jwks"`
+     * or `"keyIds"` - of the database `databaseName`, opened through `indexedDB`.
+     *
+     * The database is created on first use.
+     */
+    constructor(
+        private readonly databaseName: string,
+        private readonly objectStoreName: string,
+        private readonly indexedDB: IDBFactory = globalThis.indexedDB,
+    ) {
+        if (objectStoreName !== JWK_OBJECT_STORE && objectStoreName !== KEY_ID_OBJECT_STORE) {
+            throw new Error(`unknown object store ${objectStoreName}`);
+        }
+    }
+
+    public async get(key: string): Promise<string | undefined> {
+        const value = await this._request("readonly", (store) => store.get(key));
+        return typeof value === "string" ? value : undefined;
+    }
+
+    public async set(key: string, value: string): Promise<void> {
+        await this._request("readwrite", (store) => store.put(value, key));
+    }
+
+    public async delete(key: string): Promise<boolean> {
+        // `IDBObjectStore.delete` doesn't report whether the key existed.
+        const count = await this._request("readwrite", (store) => store.count(key));
+        if (count === 0) {
+            return false;
+        }
+        await this._request("readwrite", (store) => store.delete(key));
+        return true;
+    }
+
+    private async _request<T>(
+        mode: IDBTransactionMode,
+        request: (store: IDBObjectStore) => IDBRequest<T>,
+    ): Promise<T> {
+        const database = await this._open();
+        const store = database.transaction(this.objectStoreName, mode).objectStore(this.objectStoreName);
+        return promisify(request(store));
+    }
+
+    private _open(): Promise<IDBDatabase> {
+        if (!this._database) {
+            if (!this.indexedDB) {
+                throw new Error("IndexedDB is not available in this runtime");
+            }
+            const request = this.indexedDB.open(this.databaseName, 1);
+            request.onupgradeneeded = () => {
+                for (const name of [JWK_OBJECT_STORE, KEY_ID_OBJECT_STORE]) {
+                    if (!request.result.objectStoreNames.contains(name)) {
+                        request.result.createObjectStore(name);
+                    }
+                }
+            };
+            this._database = promisify(request);
+        }
+        return this._database;
+    }
+}
+
+/**
+ * A {@link KeyValueJwkStore} persisting keys in IndexedDB, for browsers.
+ *
+ * Private keys are stored unencrypted, readable by any script of the same origin.
+ */
+export class IndexedDbJwkStore extends KeyValueJwkStore {
+    /** Creates a new {@link IndexedDbJwkStore} persisting keys in the database `databaseName`. */
+    constructor(databaseName: string = DEFAULT_DATABASE_NAME, indexedDB?: IDBFactory) {
+        super(new IndexedDbStore(databaseName, JWK_OBJECT_STORE, indexedDB));
+    }
+}
+
+/** A {@link KeyValueKeyIdStore} persisting key ids in IndexedDB, for browsers. */
+export class IndexedDbKeyIdStore extends KeyValueKeyIdStore {
+    /** Creates a new {@link IndexedDbKeyIdStore} persisting key ids in the database `databaseName`. */
+    constructor(databaseName: string = DEFAULT_DATABASE_NAME, indexedDB?: IDBFactory) {
+        super(new IndexedDbStore(databaseName, KEY_ID_OBJECT_STORE, indexedDB));
+    }
+}
+
+function promisify<T>(request: IDBRequest<T>): Promise<T> {
+    return new Promise((resolve, reject) => {
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+}
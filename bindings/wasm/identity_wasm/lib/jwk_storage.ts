@@ -1,9 +1,13 @@
 import * as ed from "@noble/ed25519";
-import { decodeB64, encodeB64, Jwk, JwkGenOutput, JwkStorage, ProofAlgorithm, ProofUpdateCtx } from "~identity_wasm";
-import { EdCurve, JwkType, JwsAlgorithm } from "./jose";
-
-type Ed25519PrivateKey = Uint8Array;
-type Ed25519PublicKey = Uint8Array;
+import { Jwk, JwkGenOutput, JwkStorage, ProofAlgorithm, ProofUpdateCtx } from "~identity_wasm";
+import { EdCurve, JwsAlgorithm } from "./jose";
+import {
+    decodeJwk,
+    Ed25519PrivateKey,
+    encodeJwk,
+    generateEd25519PrivateKey,
+    randomKeyId,
+} from "./key_storage_utils";
 
 export class JwkMemStore implements JwkStorage {
     /** The map from key identifiers to Jwks. */
@@ -28,7 +32,7 @@ export class JwkMemStore implements JwkStorage {
         }
 
         const keyId = randomKeyId();
-        const privKey: Ed25519PrivateKey = ed.utils.randomPrivateKey();
+        const privKey: Ed25519PrivateKey = generateEd25519PrivateKey();
         const jwk = await encodeJwk(privKey, algorithm);
 
         this._keys.set(keyId, jwk);
@@ -93,46 +97,6 @@ export class JwkMemStore implements JwkStorage {
     }
 }
 
-// Encodes a Ed25519 keypair into a Jwk.
-async function encodeJwk(privateKey: Ed25519PrivateKey, alg: JwsAlgorithm): Promise<Jwk> {
-    const publicKey = await ed.getPublicKey(privateKey);
-    let x = encodeB64(publicKey);
-    let d = encodeB64(privateKey);
-
-    const jwk = new Jwk({
-        "kty": JwkType.Okp,
-        "crv": "Ed25519",
-        d,
-        x,
-        alg,
-    });
-    jwk.setKid(jwk.thumbprintSha256B64());
-
-    return jwk;
-}
-
-function decodeJwk(jwk: Jwk): [Ed25519PrivateKey, Ed25519PublicKey] {
-    if (jwk.alg() !== JwsAlgorithm.EdDSA) {
-        throw new Error("unsupported `alg`");
-    }
-
-    const paramsOkp = jwk.paramsOkp();
-    if (paramsOkp) {
-        const d = paramsOkp.d;
-
-        if (d) {
-            let textEncoder = new TextEncoder();
-            const privateKey = decodeB64(textEncoder.encode(d));
-            const publicKey = decodeB64(textEncoder.encode(paramsOkp.x));
-            return [privateKey, publicKey];
-        } else {
-            throw new Error("missing private key component");
-        }
-    } else {
-        throw new Error("expected Okp params");
-    }
-}
-
 export interface JwkStorageBBSPlusExt {
     // Generate a new BLS12381 key represented as a JSON Web Key.
     generateBBS: (algorithm: ProofAlgorithm) => Promise<JwkGenOutput>;
@@ -149,20 +113,3 @@ export interface JwkStorageBBSPlusExt {
         proofCtx: ProofUpdateCtx,
     ) => Promise<Uint8Array>;
 }
-
-// Returns a random number between `min` and `max` (inclusive).
-// SAFETY NOTE: This is not cryptographically secure randomness and thus not suitable for production use.
-// It suffices for our testing implementation however and avoids an external dependency.
-function getRandomNumber(min: number, max: number): number {
-    return Math.floor(Math.random() * (max - min + 1)) + min;
-}
-
-// Returns a random key id.
-function randomKeyId(): string {
-    const randomness = new Uint8Array(20);
-    for (let index = 0; index < randomness.length; index++) {
-        randomness[index] = getRandomNumber(0, 255);
-    }
-
-    return encodeB64(randomness);
-}
@@ -4,6 +4,7 @@ import * as ed from "@noble/ed25519";
 import { ml_dsa44, ml_dsa65, ml_dsa87 } from "@noble/post-quantum/ml-dsa";
 import { decodeB64, encodeB64, Jwk, JwkGenOutput, JwkStorage, JwkStoragePQ } from "~identity_wasm";
 import { EdCurve, JwkType, JwsAlgorithm } from "./jose";
+import { getRandomValues } from "./runtime";
 
 type Ed25519PrivateKey = Uint8Array;
 type Ed25519PublicKey = Uint8Array;
@@ -40,7 +41,7 @@ export class JwkPqMemStore implements JwkStorage, JwkStoragePQ {
         }
 
         const keyId = randomKeyId();
-        const privKey: Ed25519PrivateKey = ed.utils.randomPrivateKey();
+        const privKey: Ed25519PrivateKey = getRandomValues(new Uint8Array(32));
 
         const publicKey: Ed25519PublicKey = await ed.getPublicKey(privKey);
         const jwk = await encodeJwk(privKey, publicKey, algorithm);
@@ -251,19 +252,7 @@ function decodeJwk(jwk: Jwk): [Uint8Array, Uint8Array] {
     }
 }
 
-// Returns a random number between `min` and `max` (inclusive).
-// SAFETY NOTE: This is not cryptographically secure randomness and thus not suitable for production use.
-// It suffices for our testing implementation however and avoids an external dependency.
-function getRandomNumber(min: number, max: number): number {
-    return Math.floor(Math.random() * (max - min + 1)) + min;
-}
-
 // Returns a random key id.
 function randomKeyId(): string {
-    const randomness = new Uint8Array(20);
-    for (let index = 0; index < randomness.length; index++) {
-        randomness[index] = getRandomNumber(0, 255);
-    }
-
-    return encodeB64(randomness);
+    return encodeB64(getRandomValues(new Uint8Array(20)));
 }
@@ -1,5 +1,5 @@
-import { encode as base64Encode } from "base64-arraybuffer";
 import type { KeyIdStorage, MethodDigest } from "~identity_wasm";
+import { methodDigestToString } from "./key_storage_utils";
 
 export class KeyIdMemStore implements KeyIdStorage {
     private _keyIds: Map<string, string>;
@@ -43,11 +43,3 @@ export class KeyIdMemStore implements KeyIdStorage {
         return this._keyIds.size;
     }
 }
-
-/**
- * Converts a `MethodDigest` to a base64 encoded string.
- */
-function methodDigestToString(methodDigest: MethodDigest): string {
-    let arrayBuffer = methodDigest.pack().buffer;
-    return base64Encode(arrayBuffer);
-}
@@ -0,0 +1,69 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import * as ed from "@noble/ed25519";
+import { encode as base64Encode } from "base64-arraybuffer";
+import { decodeB64, encodeB64, Jwk, MethodDigest } from "~identity_wasm";
+import { JwkType, JwsAlgorithm } from "./jose";
+import { getRandomValues } from "./runtime";
+
+export type Ed25519PrivateKey = Uint8Array;
+export type Ed25519PublicKey = Uint8Array;
+
+// Generates a new Ed25519 private key from the configured source of randomness.
+export function generateEd25519PrivateKey(): Ed25519PrivateKey {
+    return getRandomValues(new Uint8Array(32));
+}
+
+// Encodes a Ed25519 keypair into a Jwk.
+export async function encodeJwk(privateKey: Ed25519PrivateKey, alg: JwsAlgorithm): Promise<Jwk> {
+    const publicKey = await ed.getPublicKey(privateKey);
+    let x = encodeB64(publicKey);
+    let d = encodeB64(privateKey);
+
+    const jwk = new Jwk({
+        "kty": JwkType.Okp,
+        "crv": "Ed25519",
+        d,
+        x,
+        alg,
+    });
+    jwk.setKid(jwk.thumbprintSha256B64());
+
+    return jwk;
+}
+
+export function decodeJwk(jwk: Jwk): [Ed25519PrivateKey, Ed25519PublicKey] {
+    if (jwk.alg() !== JwsAlgorithm.EdDSA) {
+        throw new Error("unsupported `alg`");
+    }
+
+    const paramsOkp = jwk.paramsOkp();
+    if (paramsOkp) {
+        const d = paramsOkp.d;
+
+        if (d) {
+            let textEncoder = new TextEncoder();
+            const privateKey = decodeB64(textEncoder.encode(d));
+            const publicKey = decodeB64(textEncoder.encode(paramsOkp.x));
+            return [privateKey, publicKey];
+        } else {
+            throw new Error("missing private key component");
+        }
+    } else {
+        throw new Error("expected Okp params");
+    }
+}
+
+// Returns a random key id.
+export function randomKeyId(): string {
+    return encodeB64(getRandomValues(new Uint8Array(20)));
+}
+
+/**
+ * Converts a `MethodDigest` to a base64 encoded string.
+ */
+export function methodDigestToString(methodDigest: MethodDigest): string {
+    let arrayBuffer = methodDigest.pack().buffer;
+    return base64Encode(arrayBuffer);
+}
@@ -0,0 +1,144 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import * as ed from "@noble/ed25519";
+import { Jwk, JwkGenOutput, JwkStorage, KeyIdStorage, MethodDigest } from "~identity_wasm";
+import { EdCurve, JwsAlgorithm } from "./jose";
+import {
+    decodeJwk,
+    Ed25519PrivateKey,
+    encodeJwk,
+    generateEd25519PrivateKey,
+    methodDigestToString,
+    randomKeyId,
+} from "./key_storage_utils";
+
+/**
+ * An asynchronous key-value store persisting the entries of {@link KeyValueJwkStore} and
+ * {@link KeyValueKeyIdStore}, e.g. backed by IndexedDB, the filesystem or a Cloudflare Workers KV namespace.
+ */
+export interface KeyValueStore {
+    /** Returns the value stored under `key`, if any. */
+    get(key: string): Promise<string | undefined>;
+    /** Stores `value` under `key`, replacing any previous value. */
+    set(key: string, value: string): Promise<void>;
+    /** Removes the value stored under `key`, returning whether there was one. */
+    delete(key: string): Promise<boolean>;
+}
+
+/**
+ * A {@link JwkStorage} persisting Ed25519 keys in a {@link KeyValueStore}.
+ *
+ * Private keys are stored unencrypted: the store must be protected accordingly.
+ */
+export class KeyValueJwkStore implements JwkStorage {
+    private _store: KeyValueStore;
+
+    /** Creates a new {@link KeyValueJwkStore} persisting keys in `store`. */
+    constructor(store: KeyValueStore) {
+        this._store = store;
+    }
+
+    public static ed25519KeyType(): string {
+        return "Ed25519";
+    }
+
+    public async generate(keyType: string, algorithm: JwsAlgorithm): Promise<JwkGenOutput> {
+        if (keyType !== KeyValueJwkStore.ed25519KeyType()) {
+            throw new Error(`unsupported key type ${keyType}`);
+        }
+
+        if (algorithm !== JwsAlgorithm.EdDSA) {
+            throw new Error(`unsupported algorithm`);
+        }
+
+        const keyId = randomKeyId();
+        const privKey: Ed25519PrivateKey = generateEd25519PrivateKey();
+        const jwk = await encodeJwk(privKey, algorithm);
+
+        await this._store.set(keyId, JSON.stringify(jwk.toJSON()));
+
+        const publicJWK = jwk.toPublic();
+        if (!publicJWK) {
+            throw new Error(`JWK is not a public key`);
+        }
+
+        return new JwkGenOutput(keyId, publicJWK);
+    }
+
+    public async sign(keyId: string, data: Uint8Array, publicKey: Jwk): Promise<Uint8Array> {
+        if (publicKey.alg() !== JwsAlgorithm.EdDSA) {
+            throw new Error("unsupported JWS algorithm");
+        } else {
+            if (publicKey.paramsOkp()?.crv !== (EdCurve.Ed25519 as string)) {
+                throw new Error("unsupported Okp parameter");
+            }
+        }
+
+        const value = await this._store.get(keyId);
+        if (value === undefined) {
+            throw new Error(`key with id ${keyId} not found`);
+        }
+
+        const [privateKey, _] = decodeJwk(Jwk.fromJSON(JSON.parse(value)));
+        return ed.sign(data, privateKey);
+    }
+
+    public async insert(jwk: Jwk): Promise<string> {
+        const keyId = randomKeyId();
+
+        if (!jwk.isPrivate()) {
+            throw new Error("expected a JWK with all private key components set");
+        }
+
+        if (!jwk.alg()) {
+            throw new Error("expected a Jwk with an `alg` parameter");
+        }
+
+        await this._store.set(keyId, JSON.stringify(jwk.toJSON()));
+
+        return keyId;
+    }
+
+    public async delete(keyId: string): Promise<void> {
+        if (!await this._store.delete(keyId)) {
+            throw new Error(`key with id ${keyId} not found`);
+        }
+    }
+
+    public async exists(keyId: string): Promise<boolean> {
+        return await this._store.get(keyId) !== undefined;
+    }
+}
+
+/** A {@link KeyIdStorage} persisting key ids in a {@link KeyValueStore}. */
+export class KeyValueKeyIdStore implements KeyIdStorage {
+    private _store: KeyValueStore;
+
+    /** Creates a new {@link KeyValueKeyIdStore} persisting key ids in `store`. */
+    constructor(store: KeyValueStore) {
+        this._store = store;
+    }
+
+    public async insertKeyId(methodDigest: MethodDigest, keyId: string): Promise<void> {
+        const methodDigestAsString: string = methodDigestToString(methodDigest);
+        if (await this._store.get(methodDigestAsString) !== undefined) {
+            throw new Error("KeyId already exists");
+        }
+        await this._store.set(methodDigestAsString, keyId);
+    }
+
+    public async getKeyId(methodDigest: MethodDigest): Promise<string> {
+        const value = await this._store.get(methodDigestToString(methodDigest));
+        if (value === undefined) {
+            throw new Error("KeyId not found");
+        }
+        return value;
+    }
+
+    public async deleteKeyId(methodDigest: MethodDigest): Promise<void> {
+        if (!await this._store.delete(methodDigestToString(methodDigest))) {
+            throw new Error("KeyId not found!");
+        }
+    }
+}
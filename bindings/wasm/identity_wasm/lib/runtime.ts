@@ -0,0 +1,94 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { IotaClient, IotaHTTPTransport } from "@iota/iota-sdk/client";
+import { JwkStorage, KeyIdStorage, Storage } from "~identity_wasm";
+import { JwkMemStore } from "./jwk_storage";
+import { KeyIdMemStore } from "./key_id_storage";
+
+/**
+ * Platform specific facilities used by the library.
+ *
+ * The defaults target browsers and Node.js. Runtimes lacking some of their globals - e.g. Cloudflare Workers or
+ * Deno - or applications wanting to route requests or persist keys differently can swap them through
+ * {@link configureRuntime}. The facilities are handed to the library's components, the global scope is never
+ * modified.
+ */
+export interface RuntimeOptions {
+    /**
+     * The `fetch` implementation used by the clients created through {@link createIotaClient}.
+     *
+     * Default: `globalThis.fetch`.
+     */
+    fetch?: typeof globalThis.fetch;
+    /**
+     * The source of cryptographically secure randomness used by the bundled key storages, with the semantics of
+     * `crypto.getRandomValues`.
+     *
+     * Default: `globalThis.crypto.getRandomValues`.
+     */
+    getRandomValues?: <T extends ArrayBufferView>(array: T) => T;
+    /**
+     * Creates the {@link JwkStorage} of the {@link Storage} returned by {@link createStorage},
+     * e.g. an {@link IndexedDbJwkStore} in browsers or an `FsJwkStore` in Node.js.
+     *
+     * Default: a new {@link JwkMemStore}.
+     */
+    jwkStorage?: () => JwkStorage;
+    /**
+     * Creates the {@link KeyIdStorage} of the {@link Storage} returned by {@link createStorage},
+     * e.g. an {@link IndexedDbKeyIdStore} in browsers or an `FsKeyIdStore` in Node.js.
+     *
+     * Default: a new {@link KeyIdMemStore}.
+     */
+    keyIdStorage?: () => KeyIdStorage;
+}
+
+let runtimeOptions: RuntimeOptions = {};
+
+/**
+ * Replaces the platform specific facilities used by the library. Absent options keep their current value, those
+ * explicitly set to `undefined` are reset to their default.
+ *
+ * The Wasm module itself draws the randomness it needs, e.g. for signature schemes implemented in Rust, from
+ * `globalThis.crypto`, which must therefore be available in every runtime.
+ */
+export function configureRuntime(options: RuntimeOptions): void {
+    runtimeOptions = { ...runtimeOptions, ...options };
+}
+
+/** Returns the options set through {@link configureRuntime}. */
+export function runtime(): Readonly<RuntimeOptions> {
+    return runtimeOptions;
+}
+
+/** Returns the configured `fetch` implementation. */
+export function getFetch(): typeof globalThis.fetch {
+    const fetch = runtimeOptions.fetch ?? globalThis.fetch?.bind(globalThis);
+    if (!fetch) {
+        throw new Error("no `fetch` implementation is available, set one through `configureRuntime`");
+    }
+    return fetch;
+}
+
+/** Creates an {@link IotaClient} connected to `url`, sending its requests through the configured `fetch`. */
+export function createIotaClient(url: string): IotaClient {
+    return new IotaClient({ transport: new IotaHTTPTransport({ url, fetch: getFetch() }) });
+}
+
+/** Creates a new {@link Storage} from the key storages configured through {@link configureRuntime}. */
+export function createStorage(): Storage {
+    const jwkStorage = runtimeOptions.jwkStorage ? runtimeOptions.jwkStorage() : new JwkMemStore();
+    const keyIdStorage = runtimeOptions.keyIdStorage ? runtimeOptions.keyIdStorage() : new KeyIdMemStore();
+    return new Storage(jwkStorage, keyIdStorage);
+}
+
+/** Fills `array` with cryptographically secure random values from the configured source. */
+export function getRandomValues<T extends ArrayBufferView>(array: T): T {
+    const getRandomValues = runtimeOptions.getRandomValues
+        ?? globalThis.crypto?.getRandomValues.bind(globalThis.crypto);
+    if (!getRandomValues) {
+        throw new Error("no source of randomness is available, set one through `configureRuntime`");
+    }
+    return getRandomValues(array);
+}
@@ -1,4 +1,4 @@
-// Copyright 2020-2022 IOTA Stiftung
+// Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
 use proc_macro::TokenStream;
@@ -16,6 +16,15 @@ use syn::ItemStruct;
 struct InterfaceArguments {
   /// Name of the TypeScript interface. Otherwise use the struct identifier.
   name: Option<String>,
+  /// Case convention applied to field identifiers, mirroring `#[serde(rename_all = "...")]`.
+  /// Only `"camelCase"` is supported.
+  rename_all: Option<String>,
+  /// Path of a Rust type with named fields whose serde representation the interface describes.
+  ///
+  /// The annotated struct is then only used to declare the interface: it is not emitted, but each of its fields
+  /// is checked at compile time to exist on the mirrored type with the same Rust type, and a test checks that
+  /// the interface declares exactly the fields the mirrored type deserializes.
+  mirrors: Option<syn::Path>,
 
   /// Whether all fields should be marked as optional. Can be overridden per field.
   optional: darling::util::Flag,
@@ -28,7 +37,7 @@ struct InterfaceArguments {
 struct FieldArguments {
   /// Name of the TypeScript field. Otherwise use the field identifier.
   name: Option<String>,
-  /// Type of the TypeScript field.
+  /// Type of the TypeScript field. Otherwise inferred from the Rust type of the field.
   #[darling(rename = "type")]
   ts_type: Option<String>,
   /// Whether the field should be marked as an optional property with a question mark.
@@ -39,37 +48,105 @@ struct FieldArguments {
   readonly: Option<bool>,
 }
 
-/// Extracts the doc-comment, if present, from a list of attributes.
-///
-/// NOTE: merges multiple lines, removing linebreaks for now...
+/// Extracts the doc-comment, if present, from a list of attributes, one entry per line.
 ///
 /// E.g.
 /// ```
 /// /// Doc-comment for `Foo`.
 /// struct Foo {}
 /// ```
-/// will return: `"Doc-comment for `Foo`."`.
+/// will return: `["Doc-comment for `Foo`."]`.
 ///
 /// Also supports the `#[doc = "Some comment"]` syntax, which `///` is transformed into.
-fn extract_doc_comment(attributes: &[syn::Attribute]) -> Option<String> {
-  let doc_comment: String = attributes
+fn extract_doc_comment(attributes: &[syn::Attribute]) -> Vec<String> {
+  attributes
     .iter()
     .filter_map(|attribute| {
       let meta = attribute.parse_meta().ok()?;
       if let syn::Meta::NameValue(meta) = meta {
-        if let syn::Lit::Str(doc_str) = meta.lit {
-          return Some(doc_str.value().trim().to_owned());
+        if meta.path.is_ident("doc") {
+          if let syn::Lit::Str(doc_str) = meta.lit {
+            return Some(doc_str.value().trim().to_owned());
+          }
         }
       }
       None
     })
-    .collect::<Vec<String>>()
-    .join(" ");
+    .collect()
+}
 
-  if doc_comment.is_empty() {
-    None
-  } else {
-    Some(doc_comment)
+/// Formats doc-comment lines as a JSDoc comment with the given indentation.
+fn format_doc_comment(lines: &[String], indent: &str) -> String {
+  match lines {
+    [] => String::new(),
+    [line] => format!("{indent}/** {line} */\n"),
+    lines => {
+      let body: String = lines
+        .iter()
+        .map(|line| {
+          if line.is_empty() {
+            format!("{indent} *\n")
+          } else {
+            format!("{indent} * {line}\n")
+          }
+        })
+        .collect();
+      format!("{indent}/**\n{body}{indent} */\n")
+    }
+  }
+}
+
+/// Converts a snake_case identifier to camelCase.
+fn to_camel_case(ident: &str) -> String {
+  let mut camel_case = String::with_capacity(ident.len());
+  let mut capitalize_next = false;
+  for character in ident.chars() {
+    if character == '_' {
+      capitalize_next = !camel_case.is_empty();
+    } else if capitalize_next {
+      camel_case.extend(character.to_uppercase());
+      capitalize_next = false;
+    } else {
+      camel_case.push(character);
+    }
+  }
+  camel_case
+}
+
+/// Infers the TypeScript type of a field from its Rust type.
+///
+/// `Option<T>` maps to the type of `T`, since absence is expressed through the optional marker of the property.
+/// Types without a primitive TypeScript counterpart map to the TypeScript type of the same name, which holds
+/// for the classes exported by these bindings.
+fn infer_typescript_type(ty: &syn::Type) -> Option<String> {
+  match ty {
+    syn::Type::Path(type_path) if type_path.qself.is_none() => {
+      let segment = type_path.path.segments.last()?;
+      let generic_argument = || match &segment.arguments {
+        syn::PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+          Some(syn::GenericArgument::Type(ty)) => infer_typescript_type(ty),
+          _ => None,
+        },
+        _ => None,
+      };
+      let typescript_type: String = match segment.ident.to_string().as_str() {
+        "Option" | "Box" => generic_argument()?,
+        "Vec" => format!("Array<{}>", generic_argument()?),
+        "String" | "str" => "string".to_owned(),
+        "bool" => "boolean".to_owned(),
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" | "f32" | "f64" => {
+          "number".to_owned()
+        }
+        ident => ident.to_owned(),
+      };
+      Some(typescript_type)
+    }
+    syn::Type::Reference(reference) => infer_typescript_type(&reference.elem),
+    syn::Type::Tuple(tuple) => {
+      let elements: Vec<String> = tuple.elems.iter().map(infer_typescript_type).collect::<Option<_>>()?;
+      Some(format!("[{}]", elements.join(", ")))
+    }
+    _ => None,
   }
 }
 
@@ -89,9 +166,7 @@ pub fn typescript(args: TokenStream, input: TokenStream) -> TokenStream {
 
   // Extract comment, name for interface.
   // Default to struct ident if unspecified.
-  let interface_comment: String = extract_doc_comment(&data_struct.attrs)
-    .map(|comment| format!("/** {comment} */\n"))
-    .unwrap_or_default();
+  let interface_comment: String = format_doc_comment(&extract_doc_comment(&data_struct.attrs), "");
   let interface_name: String = if let Some(name) = interface_args.name {
     name
   } else {
@@ -104,6 +179,13 @@ pub fn typescript(args: TokenStream, input: TokenStream) -> TokenStream {
     Fields::Named(fields) => fields,
     _ => panic!("typescript attribute only supports structs with named fields"),
   };
+  let camel_case: bool = match interface_args.rename_all.as_deref() {
+    None => false,
+    Some("camelCase") => true,
+    Some(other) => panic!("typescript attribute does not support `rename_all = \"{other}\"`"),
+  };
+  // Field names as exposed to TypeScript, used to check mirrored types.
+  let mut field_names: Vec<String> = Vec::with_capacity(fields.named.len());
 
   // Build TypeScript interface definition, extract attributes from fields.
   // E.g. #[typescript(optional, readonly, type = "string | bool")].
@@ -118,13 +200,20 @@ pub fn typescript(args: TokenStream, input: TokenStream) -> TokenStream {
           return Err(TokenStream::from(err.write_errors()));
         }
       };
-      let doc_comment: String = extract_doc_comment(&field.attrs)
-        .map(|comment| format!("  /** {comment} */\n"))
-        .unwrap_or_default();
+      let doc_comment: String = format_doc_comment(&extract_doc_comment(&field.attrs), "  ");
       let field_name: String = field_args
         .name
-        .or_else(|| field.ident.as_ref().map(|ident| ident.to_string()))
+        .or_else(|| {
+          let ident: String = field.ident.as_ref()?.to_string();
+          let ident: &str = ident.strip_prefix("r#").unwrap_or(&ident);
+          Some(if camel_case {
+            to_camel_case(ident)
+          } else {
+            ident.to_owned()
+          })
+        })
         .expect("typescript attribute missing name and field has no identifier");
+      field_names.push(field_name.clone());
       let readonly: &str = match (field_args.readonly, interface_args.readonly.is_present()) {
         (Some(true), _) | (None, true) => "readonly ",
         _ => "",
@@ -133,7 +222,7 @@ pub fn typescript(args: TokenStream, input: TokenStream) -> TokenStream {
         (Some(true), _) | (None, true) => "?",
         _ => "",
       };
-      let typescript_type: String = match field_args.ts_type {
+      let typescript_type: String = match field_args.ts_type.or_else(|| infer_typescript_type(&field.ty)) {
         Some(ts_type) => ts_type,
         None => panic!("typescript field `{}` missing type", field_name),
       };
@@ -167,12 +256,51 @@ pub fn typescript(args: TokenStream, input: TokenStream) -> TokenStream {
   let typescript_definition: String = format!(r##"r#"{typescript_interface}{typescript_fields}}}"#;"##);
   let insert: proc_macro2::TokenStream = typescript_definition.parse().unwrap();
 
-  // Preserve the input struct with the field attributes removed and
-  // export the custom TypeScript interface definition via wasm-bindgen.
-  TokenStream::from(quote! {
-    #data_struct
-
+  let custom_section: proc_macro2::TokenStream = quote! {
     #[wasm_bindgen(typescript_custom_section)]
     const #section_token: &'static str = #insert
+  };
+
+  let Some(mirrored_type) = interface_args.mirrors else {
+    // Preserve the input struct with the field attributes removed and
+    // export the custom TypeScript interface definition via wasm-bindgen.
+    return TokenStream::from(quote! {
+      #data_struct
+
+      #custom_section
+    });
+  };
+
+  // Only export the interface definition, checking that its fields exist on the mirrored type with the same types
+  // and that it declares every field the mirrored type deserializes.
+  let field_idents = fields.named.iter().map(|field| &field.ident);
+  let field_types = fields.named.iter().map(|field| &field.ty);
+  let test_module: syn::Ident = syn::Ident::new(
+    &format!("__typescript_{}", interface_name.to_lowercase()),
+    interface_name.span(),
+  );
+  TokenStream::from(quote! {
+    #custom_section
+
+    const _: () = {
+      #[allow(dead_code)]
+      fn typescript_interface_fields(value: &#mirrored_type) {
+        #(let _: &#field_types = &value.#field_idents;)*
+      }
+    };
+
+    #[cfg(test)]
+    mod #test_module {
+      use super::*;
+
+      #[test]
+      fn typescript_interface_matches_serde_fields() {
+        let mut expected: ::std::vec::Vec<&str> = crate::common::serde_field_names::<#mirrored_type>().to_vec();
+        let mut declared: ::std::vec::Vec<&str> = ::std::vec![#(#field_names),*];
+        expected.sort_unstable();
+        declared.sort_unstable();
+        assert_eq!(declared, expected, "`{}` does not match the fields of `{}`", #interface_name, stringify!(#mirrored_type));
+      }
+    }
   })
 }
@@ -0,0 +1,89 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The lock guarding the documents shared between JS and Rust.
+//!
+//! By default documents are guarded by a [`tokio::sync::RwLock`], whose asynchronous `read` and `write` wait for
+//! the document to be released. When the `single-threaded` feature is enabled, a [`RefCell`](std::cell::RefCell) is
+//! used instead, whose `read` and `write` fail - like their `try_` counterparts - when the document is in use, e.g. by
+//! a pending promise.
+
+use crate::error::Result;
+use crate::error::WasmResult;
+
+#[cfg(not(feature = "single-threaded"))]
+mod strategy {
+  pub(crate) type Lock<T> = tokio::sync::RwLock<T>;
+  pub(crate) type ReadGuard<'a, T> = tokio::sync::RwLockReadGuard<'a, T>;
+  pub(crate) type WriteGuard<'a, T> = tokio::sync::RwLockWriteGuard<'a, T>;
+
+  /// Makes a new [`ReadGuard`] for a component of the locked data.
+  pub(crate) fn map_read_guard<'a, T, U>(guard: ReadGuard<'a, T>, f: impl FnOnce(&T) -> &U) -> ReadGuard<'a, U> {
+    tokio::sync::RwLockReadGuard::map(guard, f)
+  }
+}
+
+#[cfg(feature = "single-threaded")]
+mod strategy {
+  pub(crate) type Lock<T> = std::cell::RefCell<T>;
+  pub(crate) type ReadGuard<'a, T> = std::cell::Ref<'a, T>;
+  pub(crate) type WriteGuard<'a, T> = std::cell::RefMut<'a, T>;
+
+  /// Makes a new [`ReadGuard`] for a component of the borrowed data.
+  pub(crate) fn map_read_guard<'a, T, U>(guard: ReadGuard<'a, T>, f: impl FnOnce(&T) -> &U) -> ReadGuard<'a, U> {
+    std::cell::Ref::map(guard, f)
+  }
+}
+
+pub(crate) use strategy::map_read_guard;
+pub(crate) use strategy::ReadGuard;
+pub(crate) use strategy::WriteGuard;
+
+/// A document shared with JS.
+pub(crate) struct DocumentLock<T>(strategy::Lock<T>);
+
+impl<T> DocumentLock<T> {
+  pub(crate) fn new(value: T) -> Self {
+    Self(strategy::Lock::new(value))
+  }
+
+  #[cfg(not(feature = "single-threaded"))]
+  pub(crate) fn try_read(&self) -> Result<ReadGuard<'_, T>> {
+    self.0.try_read().wasm_result()
+  }
+
+  #[cfg(not(feature = "single-threaded"))]
+  pub(crate) fn try_write(&self) -> Result<WriteGuard<'_, T>> {
+    self.0.try_write().wasm_result()
+  }
+
+  #[cfg(not(feature = "single-threaded"))]
+  pub(crate) async fn read(&self) -> Result<ReadGuard<'_, T>> {
+    Ok(self.0.read().await)
+  }
+
+  #[cfg(not(feature = "single-threaded"))]
+  pub(crate) async fn write(&self) -> Result<WriteGuard<'_, T>> {
+    Ok(self.0.write().await)
+  }
+
+  #[cfg(feature = "single-threaded")]
+  pub(crate) fn try_read(&self) -> Result<ReadGuard<'_, T>> {
+    self.0.try_borrow().wasm_result()
+  }
+
+  #[cfg(feature = "single-threaded")]
+  pub(crate) fn try_write(&self) -> Result<WriteGuard<'_, T>> {
+    self.0.try_borrow_mut().wasm_result()
+  }
+
+  #[cfg(feature = "single-threaded")]
+  pub(crate) async fn read(&self) -> Result<ReadGuard<'_, T>> {
+    self.try_read()
+  }
+
+  #[cfg(feature = "single-threaded")]
+  pub(crate) async fn write(&self) -> Result<WriteGuard<'_, T>> {
+    self.try_write()
+  }
+}
@@ -7,6 +7,8 @@ use identity_iota::document::CoreDocument;
 use identity_iota::prelude::IotaDocument;
 use js_sys::Array;
 
+use crate::common::map_read_guard;
+use crate::common::ReadGuard;
 use crate::did::ArrayIToCoreDocument;
 use crate::did::CoreDocumentLock;
 use crate::did::IToCoreDocument;
@@ -30,7 +32,7 @@ impl ImportedDocumentLock {
   /// Obtain a read guard which implements `AsRef<CoreDocument>`.
   pub(crate) fn try_read(&self) -> Result<ImportedDocumentReadGuard<'_>> {
     match self {
-      Self::Iota(lock) => Ok(ImportedDocumentReadGuard(tokio::sync::RwLockReadGuard::map(
+      Self::Iota(lock) => Ok(ImportedDocumentReadGuard(map_read_guard(
         lock.try_read()?,
         IotaDocument::core_document,
       ))),
@@ -46,15 +48,14 @@ impl ImportedDocumentLock {
     }
   }
 
-  // Currently unused, but might be needed in the future.
-  #[allow(dead_code)]
-  pub(crate) async fn read(&self) -> ImportedDocumentReadGuard<'_> {
+  /// Obtain a read guard, waiting for the document to be released if the locking strategy allows it.
+  pub(crate) async fn read(&self) -> Result<ImportedDocumentReadGuard<'_>> {
     match self {
-      Self::Iota(lock) => ImportedDocumentReadGuard(tokio::sync::RwLockReadGuard::map(
-        lock.read().await,
+      Self::Iota(lock) => Ok(ImportedDocumentReadGuard(map_read_guard(
+        lock.read().await?,
         IotaDocument::core_document,
-      )),
-      Self::Core(loc) => ImportedDocumentReadGuard(loc.read().await),
+      ))),
+      Self::Core(lock) => Ok(ImportedDocumentReadGuard(lock.read().await?)),
     }
   }
 }
@@ -77,7 +78,7 @@ impl From<&ArrayIToCoreDocument> for Vec<ImportedDocumentLock> {
   }
 }
 
-pub(crate) struct ImportedDocumentReadGuard<'a>(tokio::sync::RwLockReadGuard<'a, CoreDocument>);
+pub(crate) struct ImportedDocumentReadGuard<'a>(ReadGuard<'a, CoreDocument>);
 
 impl AsRef<CoreDocument> for ImportedDocumentReadGuard<'_> {
   fn as_ref(&self) -> &CoreDocument {
@@ -5,9 +5,14 @@ pub use timestamp::*;
 pub use types::*;
 pub(crate) use utils::*;
 
+pub(crate) use self::document_lock::map_read_guard;
+pub(crate) use self::document_lock::DocumentLock;
+pub(crate) use self::document_lock::ReadGuard;
+pub(crate) use self::document_lock::WriteGuard;
 pub(crate) use self::imported_document_lock::ImportedDocumentLock;
 pub(crate) use self::imported_document_lock::ImportedDocumentReadGuard;
 
+mod document_lock;
 mod imported_document_lock;
 mod timestamp;
 mod types;
@@ -1,10 +1,14 @@
-// Copyright 2020-2022 IOTA Stiftung
+// Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
+
+use serde::de::DeserializeOwned;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
 
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult;
 
 /// Special-case for deserializing [`js_sys::Map`], which otherwise serializes to JSON as an empty
@@ -25,3 +29,78 @@ where
     value.into_serde().wasm_result()
   }
 }
+
+/// Deserializes the options object passed for the TypeScript interface `interface`.
+///
+/// Unlike [`JsValue::into_serde`], errors name the path of the offending property, e.g.
+/// `verificationOptions.methodScope`, and properties not declared by `T` are reported with a console warning - they
+/// are still ignored, so that callers passing extra properties keep working.
+pub(crate) fn parse_options<T>(options: &JsValue, interface: &str) -> Result<T>
+where
+  T: DeserializeOwned,
+{
+  let invalid_options = |message: String| -> JsValue {
+    WasmError::new(
+      Cow::Borrowed("InvalidOptions"),
+      Cow::Owned(format!("invalid {interface}: {message}")),
+    )
+    .into()
+  };
+
+  let json: String = js_sys::JSON::stringify(options)
+    .map(String::from)
+    .map_err(|_| invalid_options("options cannot be serialized to JSON".to_owned()))?;
+
+  let mut unknown_properties: Vec<String> = Vec::new();
+  let mut json_deserializer = serde_json::Deserializer::from_str(&json);
+  let deserializer =
+    serde_ignored::Deserializer::new(&mut json_deserializer, |path| unknown_properties.push(path.to_string()));
+  let parsed: T = serde_path_to_error::deserialize(deserializer)
+    .map_err(|error| invalid_options(format!("`{}`: {}", error.path(), error.inner())))?;
+
+  for path in unknown_properties {
+    crate::macros::console_warn(&format!("{interface}: ignoring unknown property `{path}`"));
+  }
+
+  Ok(parsed)
+}
+
+/// Returns the names of the fields deserialized by the derived [`serde::Deserialize`] implementation of `T`.
+#[cfg(test)]
+pub(crate) fn serde_field_names<T>() -> &'static [&'static str]
+where
+  T: DeserializeOwned,
+{
+  use serde::de::Error as _;
+  use serde::de::Visitor;
+  use serde::forward_to_deserialize_any;
+
+  struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+  impl<'de> serde::Deserializer<'de> for FieldNames<'_> {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+      Err(Self::Error::custom("expected a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+      self,
+      _name: &'static str,
+      fields: &'static [&'static str],
+      _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error> {
+      *self.0 = fields;
+      Err(Self::Error::custom("field names captured"))
+    }
+
+    forward_to_deserialize_any! {
+      bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option unit
+      unit_struct newtype_struct seq tuple tuple_struct map enum identifier ignored_any
+    }
+  }
+
+  let mut fields: &'static [&'static str] = &[];
+  let _ = T::deserialize(FieldNames(&mut fields));
+  fields
+}
@@ -3,6 +3,7 @@
 
 use identity_iota::core::Context;
 use identity_iota::core::Object;
+use identity_iota::core::ToJson;
 use identity_iota::credential::Credential;
 use identity_iota::credential::CredentialBuilder;
 use identity_iota::credential::DomainLinkageCredentialBuilder;
@@ -221,6 +222,14 @@ impl WasmCredential {
     self.0.set_proof(proof.map(|wasm_proof| wasm_proof.0))
   }
 
+  /// Serializes this to RFC 8785 canonical JSON, identical to the output of the Rust library.
+  ///
+  /// Useful to compare hashes of the same {@link Credential} across languages.
+  #[wasm_bindgen(js_name = toCanonicalJSON)]
+  pub fn to_canonical_json(&self) -> Result<String> {
+    self.0.to_jcs().wasm_result()
+  }
+
   /// Serializes the `Credential` as a JWT claims set
   /// in accordance with [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
   ///
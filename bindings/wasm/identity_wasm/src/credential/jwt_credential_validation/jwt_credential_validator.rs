@@ -6,6 +6,7 @@ use identity_iota::core::Url;
 use identity_iota::credential::JwtCredentialValidator;
 use identity_iota::credential::JwtCredentialValidatorUtils;
 use identity_iota::credential::StatusCheck;
+use identity_iota::credential::SuspensionCheck;
 use identity_iota::did::CoreDID;
 
 use super::options::WasmJwtCredentialValidationOptions;
@@ -15,6 +16,7 @@ use crate::common::WasmTimestamp;
 use crate::credential::jwt::AnyJwt;
 use crate::credential::jwt::WasmJwtVcV2;
 use crate::credential::options::WasmStatusCheck;
+use crate::credential::options::WasmSuspensionCheck;
 use crate::credential::revocation::status_list_2021::WasmStatusList2021Credential;
 use crate::credential::CredentialAny;
 use crate::credential::WasmDecodedJwtCredential;
@@ -91,6 +93,40 @@ impl WasmJwtCredentialValidator {
       .map(WasmDecodedJwtCredential)
   }
 
+  /// Decodes and validates a {@link Credential} issued as a JWS like {@link JwtCredentialValidator.validate},
+  /// checking its `StatusList2021` status against `statusList` rather than against the `RevocationBitmap2022`
+  /// services of `issuer`.
+  ///
+  /// The status is checked according to `options.status`, and suspended credentials are handled according to
+  /// `options.suspension`. The status is only checked once all other validations succeeded.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  #[wasm_bindgen(js_name = validateWithStatusList2021)]
+  pub fn validate_with_status_list_2021(
+    &self,
+    credential_jwt: &WasmJwt,
+    issuer: &IToCoreDocument,
+    status_list: &WasmStatusList2021Credential,
+    options: &WasmJwtCredentialValidationOptions,
+    fail_fast: WasmFailFast,
+  ) -> Result<WasmDecodedJwtCredential> {
+    let issuer_lock = ImportedDocumentLock::from(issuer);
+    let issuer_guard = issuer_lock.try_read()?;
+
+    self
+      .0
+      .validate_with_status_list_2021(
+        &credential_jwt.0,
+        &issuer_guard,
+        &status_list.inner,
+        &options.0,
+        fail_fast.into(),
+      )
+      .wasm_result()
+      .map(WasmDecodedJwtCredential)
+  }
+
   /// Decodes and validates a {@link CredentialV2} issued as a JWS. A {@link DecodedJwtCredentialV2} is returned upon
   /// success.
   ///
@@ -209,6 +245,14 @@ impl WasmJwtCredentialValidator {
       .map(WasmDecodedJwtCredentialV2)
   }
 
+  /// Validates the semantic structure of the {@link Credential}.
+  ///
+  /// This does not validate against the credential's schema nor the structure of the subject claims.
+  #[wasm_bindgen(js_name = checkStructure)]
+  pub fn check_structure(credential: &CredentialAny) -> Result<()> {
+    JwtCredentialValidatorUtils::check_structure(&*credential.try_to_dyn_credential()?).wasm_result()
+  }
+
   /// Validate that the credential expires on or after the specified timestamp.
   #[wasm_bindgen(js_name = checkExpiresOnOrAfter)]
   pub fn check_expires_on_or_after(credential: &CredentialAny, timestamp: &WasmTimestamp) -> Result<()> {
@@ -262,16 +306,20 @@ impl WasmJwtCredentialValidator {
   }
 
   /// Checks whether the credential status has been revoked using `StatusList2021`.
+  ///
+  /// Suspended credentials are rejected unless `suspensionCheck` is `SuspensionCheck.AllowSuspended`.
   #[wasm_bindgen(js_name = checkStatusWithStatusList2021)]
   pub fn check_status_with_status_list_2021(
     credential: &CredentialAny,
     status_list: &WasmStatusList2021Credential,
     status_check: WasmStatusCheck,
+    suspension_check: Option<WasmSuspensionCheck>,
   ) -> Result<()> {
-    JwtCredentialValidatorUtils::check_status_with_status_list_2021(
+    JwtCredentialValidatorUtils::check_status_with_status_list_2021_options(
       &*credential.try_to_dyn_credential()?,
       &status_list.inner,
       status_check.into(),
+      suspension_check.map(SuspensionCheck::from).unwrap_or_default(),
     )
     .wasm_result()
   }
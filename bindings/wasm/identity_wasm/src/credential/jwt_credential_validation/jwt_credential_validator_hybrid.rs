@@ -6,6 +6,7 @@ use identity_iota::core::Url;
 use identity_iota::credential::JwtCredentialValidatorHybrid;
 use identity_iota::credential::JwtCredentialValidatorUtils;
 use identity_iota::credential::StatusCheck;
+use identity_iota::credential::SuspensionCheck;
 use identity_iota::did::CoreDID;
 
 use super::options::WasmJwtCredentialValidationOptions;
@@ -13,6 +14,7 @@ use crate::common::ImportedDocumentLock;
 use crate::common::ImportedDocumentReadGuard;
 use crate::common::WasmTimestamp;
 use crate::credential::options::WasmStatusCheck;
+use crate::credential::options::WasmSuspensionCheck;
 use crate::credential::revocation::status_list_2021::WasmStatusList2021Credential;
 use crate::credential::WasmCredential;
 use crate::credential::WasmDecodedJwtCredential;
@@ -177,16 +179,20 @@ impl WasmJwtCredentialValidatorHybrid {
   }
 
   /// Checks wheter the credential status has been revoked using `StatusList2021`.
+  ///
+  /// Suspended credentials are rejected unless `suspensionCheck` is `SuspensionCheck.AllowSuspended`.
   #[wasm_bindgen(js_name = checkStatusWithStatusList2021)]
   pub fn check_status_with_status_list_2021(
     credential: &WasmCredential,
     status_list: &WasmStatusList2021Credential,
     status_check: WasmStatusCheck,
+    suspension_check: Option<WasmSuspensionCheck>,
   ) -> Result<()> {
-    JwtCredentialValidatorUtils::check_status_with_status_list_2021(
+    JwtCredentialValidatorUtils::check_status_with_status_list_2021_options(
       &credential.0,
       &status_list.inner,
       status_check.into(),
+      suspension_check.map(SuspensionCheck::from).unwrap_or_default(),
     )
     .wasm_result()
   }
@@ -1,9 +1,11 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::common::parse_options;
 use crate::error::Result;
-use crate::error::WasmResult;
+use identity_iota::core::Timestamp;
 use identity_iota::credential::KeyBindingJwtValidationOptions;
+use proc_typescript::typescript;
 use wasm_bindgen::prelude::*;
 
 /// Options to declare validation criteria when validating credentials.
@@ -15,7 +17,7 @@ impl WasmKeyBindingJwtValidationOptions {
   #[wasm_bindgen(constructor)]
   pub fn new(options: Option<IKeyBindingJwtValidationOptions>) -> Result<WasmKeyBindingJwtValidationOptions> {
     if let Some(opts) = options {
-      let options: KeyBindingJwtValidationOptions = opts.into_serde().wasm_result()?;
+      let options: KeyBindingJwtValidationOptions = parse_options(&opts, "IKeyBindingJwtValidationOptions")?;
       Ok(WasmKeyBindingJwtValidationOptions::from(options))
     } else {
       Ok(WasmKeyBindingJwtValidationOptions::from(
@@ -47,32 +49,35 @@ extern "C" {
   pub type IKeyBindingJwtValidationOptions;
 }
 
-#[wasm_bindgen(typescript_custom_section)]
-const I_KEY_BINDING_JWT_VALIDATION_OPTIONS: &'static str = r#"
-/** Holds options to create a new `KeyBindingJwtValidationOptions`. */
-interface IKeyBindingJwtValidationOptions {
-    /**
-     * Validates the nonce value of the KB-JWT claims.
-     */
-    readonly nonce?: string;
-
-    /**
-     * Validates the `aud` properties in the KB-JWT claims.
-     */
-    readonly aud?: string;
-
-    /**
-     * Declares that the KB-JWT is considered invalid if the `iat` value in the claims
-     * is earlier than this timestamp.
-     */
-    readonly earliestIssuanceDate?: Timestamp;
-
-    /**
-     * Declares that the KB-JWT is considered invalid if the `iat` value in the claims is
-     * later than this timestamp.
-     *
-     * Uses the current timestamp during validation if not set.
-     */
-    readonly latestIssuanceDate?: Timestamp;
-
-}"#;
+/// Holds options to create a new `KeyBindingJwtValidationOptions`.
+#[typescript(
+  name = "IKeyBindingJwtValidationOptions",
+  readonly,
+  optional,
+  rename_all = "camelCase",
+  mirrors = "KeyBindingJwtValidationOptions"
+)]
+struct KeyBindingJwtValidationOptionsInterface {
+  /// Validates the nonce value of the KB-JWT claims.
+  nonce: Option<String>,
+  /// Validates the `aud` properties in the KB-JWT claims.
+  aud: Option<String>,
+  /// Declares that the KB-JWT is considered invalid if the `iat` value in the claims
+  /// is earlier than this timestamp.
+  earliest_issuance_date: Option<Timestamp>,
+  /// Declares that the KB-JWT is considered invalid if the `iat` value in the claims is
+  /// later than this timestamp.
+  ///
+  /// Uses the current timestamp during validation if not set.
+  latest_issuance_date: Option<Timestamp>,
+  /// Declares that the KB-JWT is considered invalid if its `iat` value is more than this many seconds
+  /// in the past.
+  max_age: Option<u32>,
+  /// Declares that the KB-JWT is considered invalid if its claims do not contain an `nbf` value.
+  ///
+  /// Default: `false`.
+  require_not_before: bool,
+  /// The name of an SD-JWT claim, e.g. `sub`, that binds the token to its holder. The claim must be disclosed and
+  /// contain the DID of the holder document used to validate the KB-JWT.
+  holder_binding_claim: Option<String>,
+}
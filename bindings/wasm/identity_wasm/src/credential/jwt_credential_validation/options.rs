@@ -1,11 +1,21 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::common::parse_options;
 use crate::error::Result;
-use crate::error::WasmResult;
+use proc_typescript::typescript;
 use wasm_bindgen::prelude::*;
 
+use identity_iota::core::Timestamp;
+use identity_iota::core::Url;
+use identity_iota::credential::IssuerConsistency;
+use identity_iota::credential::IssuerHint;
+use identity_iota::credential::IssuerLocationOptions;
 use identity_iota::credential::JwtCredentialValidationOptions;
+use identity_iota::credential::StatusCheck;
+use identity_iota::credential::SubjectHolderRelationship;
+use identity_iota::credential::SuspensionCheck;
+use identity_iota::document::verifiable::JwsVerificationOptions;
 
 /// Options to declare validation criteria when validating credentials.
 #[wasm_bindgen(js_name = JwtCredentialValidationOptions)]
@@ -16,7 +26,7 @@ impl WasmJwtCredentialValidationOptions {
   #[wasm_bindgen(constructor)]
   pub fn new(options: Option<IJwtCredentialValidationOptions>) -> Result<WasmJwtCredentialValidationOptions> {
     if let Some(opts) = options {
-      let options: JwtCredentialValidationOptions = opts.into_serde().wasm_result()?;
+      let options: JwtCredentialValidationOptions = parse_options(&opts, "IJwtCredentialValidationOptions")?;
       Ok(WasmJwtCredentialValidationOptions::from(options))
     } else {
       Ok(WasmJwtCredentialValidationOptions::from(
@@ -48,28 +58,60 @@ extern "C" {
   pub type IJwtCredentialValidationOptions;
 }
 
-#[wasm_bindgen(typescript_custom_section)]
-const I_JWT_CREDENTIAL_VALIDATION_OPTIONS: &'static str = r#"
-/** Holds options to create a new {@link JwtCredentialValidationOptions}. */
-interface IJwtCredentialValidationOptions {
-    /** Declare that the credential is **not** considered valid if it expires before this {@link Timestamp}.
-     * Uses the current datetime during validation if not set. */
-    readonly earliestExpiryDate?: Timestamp;
-
-    /** Declare that the credential is **not** considered valid if it was issued later than this {@link Timestamp}.
-     * Uses the current datetime during validation if not set. */
-    readonly latestIssuanceDate?: Timestamp;
-
-    /** Validation behaviour for `credentialStatus`.
-     *
-     * Default: `StatusCheck.Strict`. */
-    readonly status?: StatusCheck;
-
-    /** Declares how credential subjects must relate to the presentation holder during validation.
-    *
-    * <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships> */
-    readonly subjectHolderRelationship?: [string, SubjectHolderRelationship];
+/// Holds options to create a new {@link JwtCredentialValidationOptions}.
+#[typescript(
+  name = "IJwtCredentialValidationOptions",
+  readonly,
+  optional,
+  rename_all = "camelCase",
+  mirrors = "JwtCredentialValidationOptions"
+)]
+struct JwtCredentialValidationOptionsInterface {
+  /// Declare that the credential is **not** considered valid if it expires before this {@link Timestamp}.
+  /// Uses the current datetime during validation if not set.
+  earliest_expiry_date: Option<Timestamp>,
+  /// Declare that the credential is **not** considered valid if it was issued later than this {@link Timestamp}.
+  /// Uses the current datetime during validation if not set.
+  latest_issuance_date: Option<Timestamp>,
+  /// Validation behaviour for `credentialStatus`.
+  ///
+  /// Default: `StatusCheck.Strict`.
+  status: StatusCheck,
+  /// Validation behaviour for suspended credentials.
+  ///
+  /// Default: `SuspensionCheck.FailOnSuspended`.
+  suspension: SuspensionCheck,
+  /// Declares how credential subjects must relate to the presentation holder during validation.
+  ///
+  /// <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships>
+  #[typescript(type = "[string, SubjectHolderRelationship]")]
+  subject_holder_relationship: Option<(Url, SubjectHolderRelationship)>,
+  /// Options which affect the verification of the signature on the credential.
+  verification_options: JwsVerificationOptions,
+  /// Declares how the issuer and the verification method used to sign the credential are located.
+  ///
+  /// Default: the `kid` header parameter must be an absolute DID URL identifying the issuer.
+  issuer_location: IssuerLocationOptions,
+}
 
-    /** Options which affect the verification of the signature on the credential. */
-    readonly verifierOptions?: JwsVerificationOptions;
-}"#;
+/// Declares how the issuer of a JWT credential - and the verification method used to sign it - is located.
+///
+/// The issuer's DID is taken from the first hint in `precedence` that is present. All present hints must agree,
+/// and a relative `kid` (e.g. `#key-1`) is resolved against the located issuer's DID.
+#[typescript(
+  name = "IssuerLocationOptions",
+  readonly,
+  rename_all = "camelCase",
+  mirrors = "IssuerLocationOptions"
+)]
+struct IssuerLocationOptionsInterface {
+  /// The hints consulted to locate the issuer, in order of precedence:
+  /// `"kid"` for the `kid` header parameter as an absolute DID URL, `"iss"` for the `iss` claim.
+  #[typescript(type = "(\"kid\" | \"iss\")[]")]
+  precedence: Vec<IssuerHint>,
+  /// `"strict"` requires every hint in `precedence` to be present, `"lenient"` at least one.
+  ///
+  /// Default: `"strict"`.
+  #[typescript(optional, type = "\"strict\" | \"lenient\"")]
+  consistency: IssuerConsistency,
+}
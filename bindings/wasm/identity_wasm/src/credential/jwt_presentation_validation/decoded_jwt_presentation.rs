@@ -51,6 +51,13 @@ impl WasmDecodedJwtPresentation {
     self.0.issuance_date.map(WasmTimestamp::from)
   }
 
+  /// The point in time after which the presentation is no longer considered valid, i.e. the earlier of its
+  /// expiration date and the end of the `maxAge` window set during validation.
+  #[wasm_bindgen(js_name = validUntil)]
+  pub fn valid_until(&self) -> Option<WasmTimestamp> {
+    self.0.valid_until.map(WasmTimestamp::from)
+  }
+
   /// The `aud` property parsed from JWT claims.
   #[wasm_bindgen]
   pub fn audience(&self) -> Option<String> {
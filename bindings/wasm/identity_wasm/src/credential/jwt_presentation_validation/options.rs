@@ -1,9 +1,12 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::common::parse_options;
 use crate::error::Result;
-use crate::error::WasmResult;
+use identity_iota::core::Timestamp;
 use identity_iota::credential::JwtPresentationValidationOptions;
+use identity_iota::document::verifiable::JwsVerificationOptions;
+use proc_typescript::typescript;
 use wasm_bindgen::prelude::*;
 
 /// Options to declare validation criteria when validating presentation.
@@ -18,7 +21,7 @@ impl WasmJwtPresentationValidationOptions {
   #[wasm_bindgen(constructor)]
   pub fn new(options: Option<IJwtPresentationValidationOptions>) -> Result<WasmJwtPresentationValidationOptions> {
     if let Some(opts) = options {
-      let options: JwtPresentationValidationOptions = opts.into_serde().wasm_result()?;
+      let options: JwtPresentationValidationOptions = parse_options(&opts, "IJwtPresentationValidationOptions")?;
       Ok(WasmJwtPresentationValidationOptions::from(options))
     } else {
       Ok(WasmJwtPresentationValidationOptions::from(
@@ -49,24 +52,28 @@ extern "C" {
   pub type IJwtPresentationValidationOptions;
 }
 
-#[wasm_bindgen(typescript_custom_section)]
-const I_JWT_PRESENTATION_VALIDATION_OPTIONS: &'static str = r#"
-/** Holds options to create a new {@link JwtPresentationValidationOptions}. */
-interface IJwtPresentationValidationOptions {
-    /** 
-     * Options which affect the verification of the signature on the presentation. 
-     */
-    readonly presentationVerifierOptions?: JwsVerificationOptions;
-
-    /**
-     * Declare that the presentation is **not** considered valid if it expires before this {@link Timestamp}.
-     * Uses the current datetime during validation if not set. 
-     */
-    readonly earliestExpiryDate?: Timestamp;
-
-    /**
-     * Declare that the presentation is **not** considered valid if it was issued later than this {@link Timestamp}.
-     * Uses the current datetime during validation if not set. 
-     */
-    readonly latestIssuanceDate?: Timestamp;
-}"#;
+/// Holds options to create a new {@link JwtPresentationValidationOptions}.
+#[typescript(
+  name = "IJwtPresentationValidationOptions",
+  readonly,
+  optional,
+  rename_all = "camelCase",
+  mirrors = "JwtPresentationValidationOptions"
+)]
+struct JwtPresentationValidationOptionsInterface {
+  /// Options which affect the verification of the signature on the presentation.
+  presentation_verifier_options: JwsVerificationOptions,
+  /// Declare that the presentation is **not** considered valid if it expires before this {@link Timestamp}.
+  /// Uses the current datetime during validation if not set.
+  earliest_expiry_date: Option<Timestamp>,
+  /// Declare that the presentation is **not** considered valid if it was issued later than this {@link Timestamp}.
+  /// Uses the current datetime during validation if not set.
+  latest_issuance_date: Option<Timestamp>,
+  /// Declare that the presentation is **not** considered valid if it was issued more than this many seconds ago.
+  /// Presentations without an issuance date are rejected when set.
+  max_age: Option<u32>,
+  /// Declare that the presentation is **not** considered valid unless it contains an `nbf` claim.
+  ///
+  /// Default: `false`.
+  require_not_before: bool,
+}
@@ -4,6 +4,7 @@
 use identity_iota::credential::FailFast;
 use identity_iota::credential::StatusCheck;
 use identity_iota::credential::SubjectHolderRelationship;
+use identity_iota::credential::SuspensionCheck;
 use serde_repr::Deserialize_repr;
 use serde_repr::Serialize_repr;
 use wasm_bindgen::prelude::*;
@@ -38,6 +39,31 @@ impl From<WasmStatusCheck> for StatusCheck {
   }
 }
 
+/// Controls validation behaviour for credentials that have been suspended, i.e. temporarily revoked, by their
+/// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
+///
+/// Revoked credentials are always rejected.
+#[wasm_bindgen(js_name = SuspensionCheck)]
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum WasmSuspensionCheck {
+  /// Reject suspended credentials.
+  ///
+  /// This is the default.
+  FailOnSuspended = 0,
+  /// Accept suspended credentials.
+  AllowSuspended = 1,
+}
+
+impl From<WasmSuspensionCheck> for SuspensionCheck {
+  fn from(suspension_check: WasmSuspensionCheck) -> Self {
+    match suspension_check {
+      WasmSuspensionCheck::FailOnSuspended => Self::FailOnSuspended,
+      WasmSuspensionCheck::AllowSuspended => Self::AllowSuspended,
+    }
+  }
+}
+
 /// Declares how credential subjects must relate to the presentation holder.
 ///
 /// See also the [Subject-Holder Relationship](https://www.w3.org/TR/vc-data-model/#subject-holder-relationships) section of the specification.
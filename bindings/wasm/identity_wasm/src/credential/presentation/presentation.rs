@@ -3,6 +3,7 @@
 
 use identity_iota::core::Context;
 use identity_iota::core::Object;
+use identity_iota::core::ToJson;
 use identity_iota::credential::Presentation;
 use identity_iota::credential::PresentationBuilder;
 use wasm_bindgen::prelude::*;
@@ -155,6 +156,14 @@ impl WasmPresentation {
   pub fn properties(&self) -> Result<MapStringAny> {
     MapStringAny::try_from(&self.0.properties)
   }
+
+  /// Serializes this to RFC 8785 canonical JSON, identical to the output of the Rust library.
+  ///
+  /// Useful to compare hashes of the same {@link Presentation} across languages.
+  #[wasm_bindgen(js_name = toCanonicalJSON)]
+  pub fn to_canonical_json(&self) -> Result<String> {
+    self.0.to_jcs().wasm_result()
+  }
 }
 
 impl_wasm_json!(WasmPresentation, Presentation);
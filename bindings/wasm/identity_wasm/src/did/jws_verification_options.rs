@@ -1,10 +1,13 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::common::parse_options;
 use crate::error::Result;
-use crate::error::WasmResult;
 use crate::verification::WasmMethodScope;
+use identity_iota::did::DIDUrl;
 use identity_iota::document::verifiable::JwsVerificationOptions;
+use identity_iota::verification::MethodScope;
+use proc_typescript::typescript;
 use wasm_bindgen::prelude::*;
 
 use super::WasmDIDUrl;
@@ -19,7 +22,7 @@ impl WasmJwsVerificationOptions {
   #[wasm_bindgen(constructor)]
   pub fn new(options: Option<IJwsVerificationOptions>) -> Result<WasmJwsVerificationOptions> {
     if let Some(options) = options {
-      let options: JwsVerificationOptions = options.into_serde().wasm_result()?;
+      let options: JwsVerificationOptions = parse_options(&options, "IJwsVerificationOptions")?;
       Ok(WasmJwsVerificationOptions(options))
     } else {
       Ok(WasmJwsVerificationOptions(Default::default()))
@@ -55,21 +58,22 @@ extern "C" {
   pub type IJwsVerificationOptions;
 }
 
-#[wasm_bindgen(typescript_custom_section)]
-const I_JWS_SIGNATURE_OPTIONS: &'static str = r#"
-/** Holds options to create {@link JwsVerificationOptions}. */
-interface IJwsVerificationOptions {
-    /** Verify that the `nonce` set in the protected header matches this.
-     * 
-     * [More Info](https://tools.ietf.org/html/rfc8555#section-6.5.2)
-     */
-    readonly nonce?: string;
-
-    /** Verify the signing verification method relationship matches this.*/
-    readonly methodScope?: MethodScope;
-
-    /** The DID URL of the method, whose JWK should be used to verify the JWS.
-     * If unset, the `kid` of the JWS is used as the DID Url.
-     */
-    readonly methodId?: DIDUrl;
-}"#;
+/// Holds options to create {@link JwsVerificationOptions}.
+#[typescript(
+  name = "IJwsVerificationOptions",
+  readonly,
+  optional,
+  rename_all = "camelCase",
+  mirrors = "JwsVerificationOptions"
+)]
+struct JwsVerificationOptionsInterface {
+  /// Verify that the `nonce` set in the protected header matches this.
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc8555#section-6.5.2)
+  nonce: Option<String>,
+  /// Verify the signing verification method relationship matches this.
+  method_scope: Option<MethodScope>,
+  /// The DID URL of the method, whose JWK should be used to verify the JWS.
+  /// If unset, the `kid` of the JWS is used as the DID Url.
+  method_id: Option<DIDUrl>,
+}
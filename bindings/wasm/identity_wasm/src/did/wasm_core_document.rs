@@ -9,13 +9,16 @@ use crate::common::ArrayCoreMethodRef;
 use crate::common::ArrayService;
 use crate::common::ArrayString;
 use crate::common::ArrayVerificationMethod;
+use crate::common::DocumentLock;
 use crate::common::MapStringAny;
 use crate::common::OptionOneOrManyString;
 use crate::common::PromiseString;
 use crate::common::PromiseVoid;
+use crate::common::ReadGuard;
 use crate::common::RecordStringAny;
 use crate::common::UDIDUrlQuery;
 use crate::common::UOneOrManyNumber;
+use crate::common::WriteGuard;
 use crate::credential::ArrayCoreDID;
 use crate::credential::UnknownCredential;
 use crate::credential::WasmCredential;
@@ -46,6 +49,7 @@ use identity_iota::core::Object;
 use identity_iota::core::OneOrMany;
 use identity_iota::core::OneOrSet;
 use identity_iota::core::OrderedSet;
+use identity_iota::core::ToJson;
 use identity_iota::core::Url;
 use identity_iota::credential::JwtPresentationOptions;
 use identity_iota::credential::Presentation;
@@ -68,26 +72,26 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::future_to_promise;
 
-pub(crate) struct CoreDocumentLock(tokio::sync::RwLock<CoreDocument>);
+pub(crate) struct CoreDocumentLock(DocumentLock<CoreDocument>);
 
 impl CoreDocumentLock {
   pub(crate) fn new(input: CoreDocument) -> Self {
-    Self(tokio::sync::RwLock::new(input))
+    Self(DocumentLock::new(input))
   }
 
-  pub(crate) fn try_read(&self) -> Result<tokio::sync::RwLockReadGuard<'_, CoreDocument>> {
-    self.0.try_read().wasm_result()
+  pub(crate) fn try_read(&self) -> Result<ReadGuard<'_, CoreDocument>> {
+    self.0.try_read()
   }
 
-  pub(crate) fn try_write(&self) -> Result<tokio::sync::RwLockWriteGuard<'_, CoreDocument>> {
-    self.0.try_write().wasm_result()
+  pub(crate) fn try_write(&self) -> Result<WriteGuard<'_, CoreDocument>> {
+    self.0.try_write()
   }
 
-  pub(crate) async fn read(&self) -> tokio::sync::RwLockReadGuard<'_, CoreDocument> {
+  pub(crate) async fn read(&self) -> Result<ReadGuard<'_, CoreDocument>> {
     self.0.read().await
   }
 
-  pub(crate) async fn write(&self) -> tokio::sync::RwLockWriteGuard<'_, CoreDocument> {
+  pub(crate) async fn write(&self) -> Result<WriteGuard<'_, CoreDocument>> {
     self.0.write().await
   }
 }
@@ -608,6 +612,14 @@ impl WasmCoreDocument {
       .wasm_result()
   }
 
+  /// Serializes this to RFC 8785 canonical JSON, identical to the output of the Rust library.
+  ///
+  /// Useful to compare hashes of the same {@link CoreDocument} across languages.
+  #[wasm_bindgen(js_name = toCanonicalJSON)]
+  pub fn to_canonical_json(&self) -> Result<String> {
+    self.0.try_read()?.to_jcs().wasm_result()
+  }
+
   // ===========================================================================
   // Storage
   // ===========================================================================
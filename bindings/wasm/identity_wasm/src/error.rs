@@ -287,6 +287,26 @@ impl From<TryLockError> for WasmError<'_> {
   }
 }
 
+#[cfg(feature = "single-threaded")]
+impl From<std::cell::BorrowError> for WasmError<'_> {
+  fn from(error: std::cell::BorrowError) -> Self {
+    Self {
+      name: Cow::Borrowed("TryLockError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+    }
+  }
+}
+
+#[cfg(feature = "single-threaded")]
+impl From<std::cell::BorrowMutError> for WasmError<'_> {
+  fn from(error: std::cell::BorrowMutError) -> Self {
+    Self {
+      name: Cow::Borrowed("TryLockError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+    }
+  }
+}
+
 impl From<serde_wasm_bindgen::Error> for WasmError<'_> {
   fn from(error: serde_wasm_bindgen::Error) -> Self {
     Self {
@@ -314,6 +334,15 @@ impl From<identity_iota::credential::sd_jwt_vc::Error> for WasmError<'_> {
   }
 }
 
+impl From<identity_iota::credential::presentation_exchange::Error> for WasmError<'_> {
+  fn from(error: identity_iota::credential::presentation_exchange::Error) -> Self {
+    Self {
+      name: Cow::Borrowed("PresentationExchangeError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+    }
+  }
+}
+
 impl From<AdapterError> for WasmError<'_> {
   fn from(error: AdapterError) -> Self {
     Self {
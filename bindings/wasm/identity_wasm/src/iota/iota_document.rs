@@ -8,6 +8,7 @@ use identity_iota::core::OneOrMany;
 
 use identity_iota::core::OrderedSet;
 use identity_iota::core::Timestamp;
+use identity_iota::core::ToJson;
 use identity_iota::core::Url;
 use identity_iota::credential::JwtPresentationOptions;
 use identity_iota::credential::Presentation;
@@ -15,6 +16,7 @@ use identity_iota::credential::Presentation;
 use identity_iota::did::DIDUrl;
 use identity_iota::iota::IotaDID;
 use identity_iota::iota::IotaDocument;
+use identity_iota::iota::StateMetadataDocument;
 use identity_iota::iota::StateMetadataEncoding;
 use identity_iota::storage::key_storage::KeyType;
 use identity_iota::storage::storage::JwkDocumentExt;
@@ -31,14 +33,17 @@ use wasm_bindgen_futures::future_to_promise;
 use crate::common::ArrayService;
 use crate::common::ArrayString;
 use crate::common::ArrayVerificationMethod;
+use crate::common::DocumentLock;
 use crate::common::MapStringAny;
 use crate::common::OptionOneOrManyString;
 use crate::common::OptionTimestamp;
 use crate::common::PromiseString;
 use crate::common::PromiseVoid;
+use crate::common::ReadGuard;
 use crate::common::RecordStringAny;
 use crate::common::UDIDUrlQuery;
 use crate::common::UOneOrManyNumber;
+use crate::common::WriteGuard;
 use crate::common::WasmTimestamp;
 use crate::credential::PromiseJpt;
 use crate::credential::UnknownCredential;
@@ -80,26 +85,26 @@ use crate::verification::WasmMethodScope;
 use crate::verification::WasmVerificationMethod;
 use identity_iota::storage::JwpDocumentExt;
 
-pub(crate) struct IotaDocumentLock(tokio::sync::RwLock<IotaDocument>);
+pub(crate) struct IotaDocumentLock(DocumentLock<IotaDocument>);
 
 impl IotaDocumentLock {
   pub(crate) fn new(value: IotaDocument) -> Self {
-    Self(tokio::sync::RwLock::new(value))
+    Self(DocumentLock::new(value))
   }
 
-  pub(crate) fn try_read(&self) -> Result<tokio::sync::RwLockReadGuard<'_, IotaDocument>> {
-    self.0.try_read().wasm_result()
+  pub(crate) fn try_read(&self) -> Result<ReadGuard<'_, IotaDocument>> {
+    self.0.try_read()
   }
 
-  pub(crate) fn try_write(&self) -> Result<tokio::sync::RwLockWriteGuard<'_, IotaDocument>> {
-    self.0.try_write().wasm_result()
+  pub(crate) fn try_write(&self) -> Result<WriteGuard<'_, IotaDocument>> {
+    self.0.try_write()
   }
 
-  pub(crate) async fn read(&self) -> tokio::sync::RwLockReadGuard<'_, IotaDocument> {
+  pub(crate) async fn read(&self) -> Result<ReadGuard<'_, IotaDocument>> {
     self.0.read().await
   }
 
-  pub(crate) async fn write(&self) -> tokio::sync::RwLockWriteGuard<'_, IotaDocument> {
+  pub(crate) async fn write(&self) -> Result<WriteGuard<'_, IotaDocument>> {
     self.0.write().await
   }
 }
@@ -435,6 +440,15 @@ impl WasmIotaDocument {
       .wasm_result()
   }
 
+  /// Returns the number of bytes {@link IotaDocument.packWithEncoding} produces for the given `encoding`,
+  /// e.g. to compare the on-chain storage cost of different encodings.
+  #[wasm_bindgen(js_name = packedLength)]
+  pub fn packed_length(&self, encoding: WasmStateMetadataEncoding) -> Result<usize> {
+    StateMetadataDocument::from(self.0.try_read()?.clone())
+      .packed_len(StateMetadataEncoding::from(encoding))
+      .wasm_result()
+  }
+
   // ===========================================================================
   // Metadata
   // ===========================================================================
@@ -577,6 +591,14 @@ impl WasmIotaDocument {
     JsValue::from_serde(&iota_document).wasm_result()
   }
 
+  /// Serializes this to RFC 8785 canonical JSON, identical to the output of the Rust library.
+  ///
+  /// Useful to compare hashes of the same {@link IotaDocument} across languages.
+  #[wasm_bindgen(js_name = toCanonicalJSON)]
+  pub fn to_canonical_json(&self) -> Result<String> {
+    self.0.try_read()?.to_jcs().wasm_result()
+  }
+
   /// Deserializes an instance from a plain JS representation.
   #[wasm_bindgen(js_name = fromJSON)]
   pub fn from_json(json: &JsValue) -> Result<WasmIotaDocument> {
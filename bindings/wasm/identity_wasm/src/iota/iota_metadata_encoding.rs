@@ -13,12 +13,15 @@ use wasm_bindgen::prelude::*;
 pub enum WasmStateMetadataEncoding {
   /// State Metadata encoded as JSON.
   Json = 0,
+  /// State Metadata encoded as CBOR, which is usually more compact than JSON.
+  Cbor = 1,
 }
 
 impl From<WasmStateMetadataEncoding> for StateMetadataEncoding {
   fn from(encoding: WasmStateMetadataEncoding) -> Self {
     match encoding {
       WasmStateMetadataEncoding::Json => Self::Json,
+      WasmStateMetadataEncoding::Cbor => Self::Cbor,
     }
   }
 }
@@ -24,6 +24,7 @@ pub mod error;
 pub mod iota;
 pub mod jose;
 pub mod jpt;
+pub mod presentation_exchange;
 pub mod resolver;
 pub mod revocation;
 pub mod sd_jwt;
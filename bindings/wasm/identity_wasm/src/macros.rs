@@ -15,6 +15,9 @@ macro_rules! log {
 extern "C" {
   #[wasm_bindgen(js_namespace = console, js_name = log)]
   pub fn console_log(s: &str);
+
+  #[wasm_bindgen(js_namespace = console, js_name = warn)]
+  pub fn console_warn(s: &str);
 }
 
 /// Logging macro without the need for web_sys dependency
@@ -0,0 +1,144 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::credential::presentation_exchange::CandidateCredential;
+use identity_iota::credential::presentation_exchange::ClaimFormat;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Result;
+use crate::error::WasmResult;
+use crate::sd_jwt::WasmHasher;
+use crate::sd_jwt::WasmSdJwt;
+use crate::sd_jwt_vc::WasmSdJwtVc;
+
+#[wasm_bindgen(typescript_custom_section)]
+const I_CANDIDATE_CREDENTIAL: &str = r#"
+/** JSON representation of a {@link CandidateCredential}. */
+interface ICandidateCredential {
+  /** Claim format of the credential, e.g. `jwt_vc_json` or `dc+sd-jwt`. */
+  readonly format: string;
+  /** Serialization of the credential. */
+  readonly raw: string;
+  /** The claims input descriptors' fields are evaluated against. */
+  readonly claims: unknown;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "ICandidateCredential")]
+  pub type ICandidateCredential;
+
+  #[wasm_bindgen(typescript_type = "Array<CandidateCredential>")]
+  pub type ArrayCandidateCredential;
+}
+
+#[derive(Serialize, Deserialize)]
+struct CandidateCredentialJson {
+  format: String,
+  raw: String,
+  claims: Value,
+}
+
+impl From<CandidateCredentialJson> for CandidateCredential {
+  fn from(json: CandidateCredentialJson) -> Self {
+    CandidateCredential::new(ClaimFormat::from(json.format.as_str()), json.raw, json.claims)
+  }
+}
+
+/// One of a Holder's credentials, ready to be evaluated against a {@link PresentationDefinition}.
+///
+/// Credentials are not verified in any way: their claims are only extracted to be matched against input descriptors.
+#[derive(Clone)]
+#[wasm_bindgen(js_name = CandidateCredential)]
+pub struct WasmCandidateCredential(pub(crate) CandidateCredential);
+
+impl_wasm_clone!(WasmCandidateCredential, CandidateCredential);
+
+#[wasm_bindgen(js_class = CandidateCredential)]
+impl WasmCandidateCredential {
+  /// Creates a new {@link CandidateCredential} of the given `format` from its serialization and its (disclosed)
+  /// claims.
+  #[wasm_bindgen(constructor)]
+  pub fn new(format: String, raw: String, claims: JsValue) -> Result<WasmCandidateCredential> {
+    let claims: Value = claims.into_serde().wasm_result()?;
+    Ok(Self(CandidateCredential::new(
+      ClaimFormat::from(format.as_str()),
+      raw,
+      claims,
+    )))
+  }
+
+  /// Creates a `jwt_vc_json` {@link CandidateCredential} from a JWT encoded credential, evaluating fields against
+  /// its claims set, e.g. `$.vc.credentialSubject`.
+  #[wasm_bindgen(js_name = jwtVc)]
+  pub fn jwt_vc(jwt: &str) -> Result<WasmCandidateCredential> {
+    CandidateCredential::jwt_vc(jwt).map(Self).wasm_result()
+  }
+
+  /// Creates a `vc+sd-jwt` {@link CandidateCredential} from an {@link SdJwt}, evaluating fields against its disclosed
+  /// claims.
+  #[wasm_bindgen(js_name = sdJwt)]
+  pub fn sd_jwt(sd_jwt: &WasmSdJwt, hasher: &WasmHasher) -> Result<WasmCandidateCredential> {
+    CandidateCredential::sd_jwt(&sd_jwt.0, hasher).map(Self).wasm_result()
+  }
+
+  /// Creates a `dc+sd-jwt` {@link CandidateCredential} from an {@link SdJwtVc}, evaluating fields against its
+  /// disclosed claims.
+  #[wasm_bindgen(js_name = sdJwtVc)]
+  pub fn sd_jwt_vc(sd_jwt_vc: &WasmSdJwtVc, hasher: &WasmHasher) -> Result<WasmCandidateCredential> {
+    CandidateCredential::sd_jwt_vc(&sd_jwt_vc.0, hasher)
+      .map(Self)
+      .wasm_result()
+  }
+
+  /// Returns the claim format of this credential.
+  #[wasm_bindgen]
+  pub fn format(&self) -> String {
+    self.0.format().to_string()
+  }
+
+  /// Returns the serialization of this credential.
+  #[wasm_bindgen(js_name = asString)]
+  pub fn as_string(&self) -> String {
+    self.0.as_str().to_owned()
+  }
+
+  /// Returns the claims fields are evaluated against.
+  #[wasm_bindgen]
+  pub fn claims(&self) -> Result<JsValue> {
+    JsValue::from_serde(self.0.claims()).wasm_result()
+  }
+
+  /// Serializes this to a JSON object.
+  #[wasm_bindgen(js_name = toJSON)]
+  pub fn to_json(&self) -> Result<ICandidateCredential> {
+    let json = CandidateCredentialJson {
+      format: self.0.format().to_string(),
+      raw: self.0.as_str().to_owned(),
+      claims: self.0.claims().clone(),
+    };
+    JsValue::from_serde(&json).map(JsCast::unchecked_into).wasm_result()
+  }
+
+  /// Deserializes an instance from a JSON object.
+  #[wasm_bindgen(js_name = fromJSON)]
+  pub fn from_json(json: ICandidateCredential) -> Result<WasmCandidateCredential> {
+    json
+      .into_serde::<CandidateCredentialJson>()
+      .map(|json| Self(json.into()))
+      .wasm_result()
+  }
+}
+
+impl TryFrom<&ArrayCandidateCredential> for Vec<CandidateCredential> {
+  type Error = JsValue;
+
+  fn try_from(credentials: &ArrayCandidateCredential) -> Result<Self> {
+    let credentials: Vec<CandidateCredentialJson> = credentials.into_serde().wasm_result()?;
+    Ok(credentials.into_iter().map(CandidateCredential::from).collect())
+  }
+}
@@ -0,0 +1,176 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::credential::presentation_exchange::CandidateCredential;
+use identity_iota::credential::presentation_exchange::InputDescriptorMatch;
+use identity_iota::credential::presentation_exchange::PresentationDefinition;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Result;
+use crate::error::WasmResult;
+
+use super::ArrayCandidateCredential;
+use super::WasmSubmission;
+
+#[wasm_bindgen(typescript_custom_section)]
+const I_PRESENTATION_DEFINITION: &str = r#"
+/** A claim requested by an input descriptor. */
+interface PresentationDefinitionField {
+  id?: string;
+  /** JSONPath expressions locating the claim, tried in order. */
+  path: string[];
+  name?: string;
+  purpose?: string;
+  /** A JSON Schema the claim's value must satisfy. */
+  filter?: unknown;
+  optional?: boolean;
+  intent_to_retain?: boolean;
+}
+
+/** Requirements on a single credential. */
+interface InputDescriptor {
+  id: string;
+  name?: string;
+  purpose?: string;
+  format?: Record<string, unknown>;
+  group?: string[];
+  constraints?: {
+    limit_disclosure?: "required" | "preferred";
+    fields?: PresentationDefinitionField[];
+  };
+}
+
+/** How input descriptors can be combined. */
+interface SubmissionRequirement {
+  name?: string;
+  purpose?: string;
+  rule: "all" | "pick";
+  count?: number;
+  min?: number;
+  max?: number;
+  from?: string;
+  from_nested?: SubmissionRequirement[];
+}
+
+/** A DIF Presentation Exchange 2.0 presentation definition, as sent by a Verifier. */
+interface IPresentationDefinition {
+  id: string;
+  name?: string;
+  purpose?: string;
+  format?: Record<string, unknown>;
+  input_descriptors: InputDescriptor[];
+  submission_requirements?: SubmissionRequirement[];
+}
+
+/** A credential satisfying an input descriptor. */
+interface CredentialMatch {
+  /** Index of the credential among the evaluated ones. */
+  index: number;
+  /** JSON pointers to the claims selected by the descriptor's fields. */
+  pointers: string[];
+}
+
+/** The credentials satisfying an input descriptor. */
+interface InputDescriptorMatch {
+  /** Identifier of the input descriptor. */
+  descriptorId: string;
+  /** The satisfying credentials, in the order they were given. */
+  credentials: CredentialMatch[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "IPresentationDefinition")]
+  pub type IPresentationDefinition;
+
+  #[wasm_bindgen(typescript_type = "Array<InputDescriptorMatch>")]
+  pub type ArrayInputDescriptorMatch;
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InputDescriptorMatchJson<'m> {
+  descriptor_id: &'m str,
+  credentials: Vec<CredentialMatchJson<'m>>,
+}
+
+#[derive(Serialize)]
+struct CredentialMatchJson<'m> {
+  index: usize,
+  pointers: &'m [String],
+}
+
+impl<'m> From<&'m InputDescriptorMatch> for InputDescriptorMatchJson<'m> {
+  fn from(descriptor_match: &'m InputDescriptorMatch) -> Self {
+    Self {
+      descriptor_id: &descriptor_match.descriptor_id,
+      credentials: descriptor_match
+        .credentials
+        .iter()
+        .map(|credential| CredentialMatchJson {
+          index: credential.index,
+          pointers: &credential.pointers,
+        })
+        .collect(),
+    }
+  }
+}
+
+/// A Verifier's requirements on the credentials a Holder must present, as defined in
+/// [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#presentation-definition).
+///
+/// Fields are evaluated locally, JSONPath expressions and JSON Schema filters included.
+#[derive(Clone, Serialize)]
+#[serde(transparent)]
+#[wasm_bindgen(js_name = PresentationDefinition)]
+pub struct WasmPresentationDefinition(pub(crate) PresentationDefinition);
+
+impl_wasm_json!(WasmPresentationDefinition, PresentationDefinition);
+impl_wasm_clone!(WasmPresentationDefinition, PresentationDefinition);
+
+#[wasm_bindgen(js_class = PresentationDefinition)]
+impl WasmPresentationDefinition {
+  /// Creates a new {@link PresentationDefinition} from its JSON representation.
+  #[wasm_bindgen(constructor)]
+  pub fn new(definition: IPresentationDefinition) -> Result<WasmPresentationDefinition> {
+    definition.into_serde().map(Self).wasm_result()
+  }
+
+  /// Returns the identifier of this definition.
+  #[wasm_bindgen]
+  pub fn id(&self) -> String {
+    self.0.id.clone()
+  }
+
+  /// Returns, for each input descriptor, the `credentials` satisfying it.
+  ///
+  /// A credential satisfies a descriptor when its format is accepted, every mandatory field's path selects a claim
+  /// matching the field's filter, and - if the descriptor requires limited disclosure - its format is selectively
+  /// disclosable.
+  #[wasm_bindgen]
+  pub fn evaluate(&self, credentials: &ArrayCandidateCredential) -> Result<ArrayInputDescriptorMatch> {
+    let credentials: Vec<CandidateCredential> = credentials.try_into()?;
+    let matches = self.0.evaluate(&credentials).wasm_result()?;
+    let matches: Vec<InputDescriptorMatchJson<'_>> = matches.iter().map(InputDescriptorMatchJson::from).collect();
+
+    JsValue::from_serde(&matches).map(JsCast::unchecked_into).wasm_result()
+  }
+
+  /// Selects among `credentials` the ones to submit in order to satisfy this definition and builds the corresponding
+  /// {@link PresentationSubmission} with identifier `submissionId`.
+  ///
+  /// Every input descriptor must be satisfied unless the definition has submission requirements, in which case only
+  /// the descriptors picked by those are. Credentials satisfying more than one descriptor are submitted once.
+  #[wasm_bindgen]
+  #[allow(non_snake_case)]
+  pub fn submit(&self, submissionId: String, credentials: &ArrayCandidateCredential) -> Result<WasmSubmission> {
+    let credentials: Vec<CandidateCredential> = credentials.try_into()?;
+    self
+      .0
+      .submit(submissionId, &credentials)
+      .map(WasmSubmission)
+      .wasm_result()
+  }
+}
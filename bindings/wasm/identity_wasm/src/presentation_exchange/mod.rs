@@ -0,0 +1,10 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod candidate;
+mod definition;
+mod submission;
+
+pub use candidate::*;
+pub use definition::*;
+pub use submission::*;
@@ -0,0 +1,133 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::credential::presentation_exchange::PresentationSubmission;
+use identity_iota::credential::presentation_exchange::Submission;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::error::Result;
+use crate::error::WasmResult;
+
+#[wasm_bindgen(typescript_custom_section)]
+const I_PRESENTATION_SUBMISSION: &str = r#"
+/** Location of the credential satisfying an input descriptor. */
+interface DescriptorMapEntry {
+  id: string;
+  format: string;
+  path: string;
+  path_nested?: DescriptorMapEntry;
+}
+
+/** A DIF Presentation Exchange 2.0 presentation submission. */
+interface IPresentationSubmission {
+  id: string;
+  definition_id: string;
+  descriptor_map: DescriptorMapEntry[];
+}
+
+/** A credential to submit. */
+interface SubmittedCredential {
+  /** Index of the credential among the evaluated ones. */
+  index: number;
+  /**
+   * JSON pointers to the only claims that should be disclosed, when some descriptor limits disclosure.
+   * For selectively disclosable formats these can be passed to e.g. `SdJwtVcPresentationBuilder.disclose` after
+   * concealing every claim.
+   */
+  disclose?: string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "IPresentationSubmission")]
+  pub type IPresentationSubmission;
+
+  #[wasm_bindgen(typescript_type = "Array<DescriptorMapEntry>")]
+  pub type ArrayDescriptorMapEntry;
+
+  #[wasm_bindgen(typescript_type = "Array<SubmittedCredential>")]
+  pub type ArraySubmittedCredential;
+}
+
+/// Describes how the credentials submitted by a Holder satisfy a {@link PresentationDefinition}, as defined in
+/// [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#presentation-submission).
+#[derive(Clone, Serialize)]
+#[serde(transparent)]
+#[wasm_bindgen(js_name = PresentationSubmission)]
+pub struct WasmPresentationSubmission(pub(crate) PresentationSubmission);
+
+impl_wasm_json!(WasmPresentationSubmission, PresentationSubmission);
+impl_wasm_clone!(WasmPresentationSubmission, PresentationSubmission);
+
+#[wasm_bindgen(js_class = PresentationSubmission)]
+impl WasmPresentationSubmission {
+  /// Creates a new {@link PresentationSubmission} from its JSON representation.
+  #[wasm_bindgen(constructor)]
+  pub fn new(submission: IPresentationSubmission) -> Result<WasmPresentationSubmission> {
+    submission.into_serde().map(Self).wasm_result()
+  }
+
+  /// Returns the identifier of this submission.
+  #[wasm_bindgen]
+  pub fn id(&self) -> String {
+    self.0.id.clone()
+  }
+
+  /// Returns the identifier of the satisfied {@link PresentationDefinition}.
+  #[wasm_bindgen(js_name = definitionId)]
+  pub fn definition_id(&self) -> String {
+    self.0.definition_id.clone()
+  }
+
+  /// Returns the location of the credential satisfying each input descriptor.
+  #[wasm_bindgen(js_name = descriptorMap)]
+  pub fn descriptor_map(&self) -> Result<ArrayDescriptorMapEntry> {
+    JsValue::from_serde(&self.0.descriptor_map)
+      .map(JsCast::unchecked_into)
+      .wasm_result()
+  }
+}
+
+#[derive(Serialize)]
+struct SubmittedCredentialJson<'s> {
+  index: usize,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  disclose: Option<&'s [String]>,
+}
+
+/// The credentials a Holder must submit to satisfy a {@link PresentationDefinition}, together with the
+/// {@link PresentationSubmission} describing them.
+#[derive(Clone)]
+#[wasm_bindgen(js_name = Submission)]
+pub struct WasmSubmission(pub(crate) Submission);
+
+impl_wasm_clone!(WasmSubmission, Submission);
+
+#[wasm_bindgen(js_class = Submission)]
+impl WasmSubmission {
+  /// Returns the submission to send along the credentials.
+  #[wasm_bindgen(js_name = presentationSubmission)]
+  pub fn presentation_submission(&self) -> WasmPresentationSubmission {
+    WasmPresentationSubmission(self.0.presentation_submission.clone())
+  }
+
+  /// Returns the credentials to submit, in the order assumed by the descriptor map.
+  #[wasm_bindgen]
+  pub fn credentials(&self) -> Result<ArraySubmittedCredential> {
+    let credentials: Vec<SubmittedCredentialJson<'_>> = self
+      .0
+      .credentials
+      .iter()
+      .map(|credential| SubmittedCredentialJson {
+        index: credential.index,
+        disclose: credential.disclose.as_deref(),
+      })
+      .collect();
+
+    JsValue::from_serde(&credentials)
+      .map(JsCast::unchecked_into)
+      .wasm_result()
+  }
+}
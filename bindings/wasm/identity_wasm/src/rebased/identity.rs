@@ -25,6 +25,7 @@ use crate::error::wasm_error;
 use crate::error::Result;
 use crate::error::WasmResult;
 use crate::iota::WasmIotaDocument;
+use crate::iota::WasmStateMetadataEncoding;
 use crate::rebased::proposals::WasmAccessSubIdentityTx;
 use crate::rebased::proposals::WasmCreateBorrowProposal;
 use crate::rebased::proposals::WasmCreateConfigChangeProposal;
@@ -352,6 +353,15 @@ impl WasmIdentityBuilder {
     Self(self.0.threshold(threshold))
   }
 
+  /// Sets the {@link StateMetadataEncoding} used to store the identity's DID Document on-chain.
+  /// Defaults to {@link StateMetadataEncoding.Json}; {@link StateMetadataEncoding.Cbor} is usually more compact.
+  ///
+  /// Later updates of the DID Document keep the encoding it was created with.
+  #[wasm_bindgen(js_name = stateMetadataEncoding)]
+  pub fn state_metadata_encoding(self, encoding: WasmStateMetadataEncoding) -> Self {
+    Self(self.0.state_metadata_encoding(encoding.into()))
+  }
+
   pub fn controllers(self, controllers: Vec<ControllerAndVotingPower>) -> Result<Self> {
     let inner_builder = self.0.controllers_with_delegation(
       controllers
@@ -4,11 +4,13 @@
 mod controller;
 mod identity;
 mod proposals;
+mod wallet_signer;
 mod wasm_identity_client;
 mod wasm_identity_client_read_only;
 
 pub use controller::*;
 pub use identity::*;
+pub use wallet_signer::*;
 pub use wasm_identity_client::*;
 pub use wasm_identity_client_read_only::*;
 
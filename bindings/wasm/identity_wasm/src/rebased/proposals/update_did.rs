@@ -314,16 +314,16 @@ impl WasmCreateUpdateDidProposal {
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
     let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let mut identity_lock = self.identity.0.write().await;
     let action = if let Some(did_doc) = self.updated_did_doc.as_ref() {
-      let did_doc = did_doc.0.read().await.clone();
-      UpdateDidDocument::new(did_doc)
+      let did_doc = did_doc.0.read().await?.clone();
+      UpdateDidDocument::new_with_encoding(did_doc, identity_lock.state_metadata_encoding())
     } else if self.delete {
       UpdateDidDocument::delete()
     } else {
       UpdateDidDocument::deactivate()
     };
 
-    let mut identity_lock = self.identity.0.write().await;
     let tx = Proposal::<UpdateDidDocument>::create(
       action,
       self.expiration_epoch,
@@ -345,16 +345,16 @@ impl WasmCreateUpdateDidProposal {
     client: &WasmCoreClientReadOnly,
   ) -> Result<Option<WasmProposalUpdateDid>> {
     let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let mut identity_lock = self.identity.0.write().await;
     let action = if let Some(did_doc) = self.updated_did_doc.as_ref() {
-      let did_doc = did_doc.0.read().await.clone();
-      UpdateDidDocument::new(did_doc)
+      let did_doc = did_doc.0.read().await?.clone();
+      UpdateDidDocument::new_with_encoding(did_doc, identity_lock.state_metadata_encoding())
     } else if self.delete {
       UpdateDidDocument::deactivate()
     } else {
       UpdateDidDocument::delete()
     };
 
-    let mut identity_lock = self.identity.0.write().await;
     let tx = Proposal::<UpdateDidDocument>::create(
       action,
       self.expiration_epoch,
@@ -0,0 +1,108 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::Encoding as _;
+use iota_interaction_ts::WasmPublicKey;
+use js_sys::Promise;
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::error::Result;
+
+#[wasm_bindgen(typescript_custom_section)]
+const _TYPE_DEFS: &str = r#"
+/**
+ * Hands a transaction to a wallet for signing, e.g. through dapp-kit's `signTransaction`.
+ *
+ * `transaction` is the base64 encoded, BCS serialized transaction data. The returned promise resolves either to the
+ * wallet's response - whose `bytes`, when set, must match `transaction` - or directly to the base64 encoded
+ * signature. It may stay pending for as long as the user takes to approve the transaction.
+ */
+export type WalletSignTransactionFn = (
+  transaction: string,
+) => Promise<{ bytes?: string; signature: string } | string>;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[derive(Clone)]
+  #[wasm_bindgen(typescript_type = WalletSignTransactionFn, extends = js_sys::Function)]
+  pub type WasmWalletSignTransactionFn;
+
+  #[wasm_bindgen(method, js_name = toIotaBytes)]
+  fn to_iota_bytes(this: &WasmPublicKey) -> Vec<u8>;
+
+  #[wasm_bindgen(method, js_name = toIotaAddress)]
+  fn to_iota_address(this: &WasmPublicKey) -> String;
+}
+
+/// A {@link TransactionSigner} delegating signatures to a browser wallet, so that the user's wallet - rather than
+/// a {@link StorageSigner} - controls the identity.
+///
+/// Transactions are built as usual and their bytes are handed to `signTransaction`; execution resumes once
+/// the wallet returns a signature, however long the user takes to approve it.
+#[wasm_bindgen(js_name = WalletSigner)]
+#[derive(Clone)]
+pub struct WasmWalletSigner {
+  public_key: WasmPublicKey,
+  sign_transaction: WasmWalletSignTransactionFn,
+}
+
+#[wasm_bindgen(js_class = WalletSigner)]
+impl WasmWalletSigner {
+  /// Creates a new {@link WalletSigner} for the wallet account with the given `public_key`.
+  #[wasm_bindgen(constructor)]
+  pub fn new(public_key: WasmPublicKey, sign_transaction: WasmWalletSignTransactionFn) -> Self {
+    Self {
+      public_key,
+      sign_transaction,
+    }
+  }
+
+  /// Returns the address of the wallet account.
+  #[wasm_bindgen(js_name = keyId)]
+  pub fn key_id(&self) -> String {
+    self.public_key.to_iota_address()
+  }
+
+  /// Has the wallet sign the BCS serialized transaction data `data`, returning the base64 encoded signature.
+  #[wasm_bindgen]
+  pub async fn sign(&self, data: &[u8]) -> Result<String> {
+    let transaction: String = Base64::encode(data);
+    let promise: Promise = Promise::resolve(
+      &self
+        .sign_transaction
+        .call1(&JsValue::NULL, &JsValue::from_str(&transaction))?,
+    );
+    let response: JsValue = JsFuture::from(promise).await?;
+
+    if let Some(signature) = response.as_string() {
+      return Ok(signature);
+    }
+
+    let signed_bytes: JsValue = Reflect::get(&response, &JsValue::from_str("bytes"))?;
+    if let Some(signed_bytes) = signed_bytes.as_string() {
+      if signed_bytes != transaction {
+        return Err(JsError::new("the wallet signed a transaction different from the requested one").into());
+      }
+    }
+
+    Reflect::get(&response, &JsValue::from_str("signature"))?
+      .as_string()
+      .ok_or_else(|| JsError::new("the wallet's response does not contain a signature").into())
+  }
+
+  /// Returns the public key of the wallet account.
+  #[wasm_bindgen(js_name = publicKey)]
+  pub async fn public_key(&self) -> Result<WasmPublicKey> {
+    Ok(self.public_key.clone())
+  }
+
+  /// Returns the public key of the wallet account, prefixed with its signature scheme flag.
+  #[wasm_bindgen(js_name = iotaPublicKeyBytes)]
+  pub async fn iota_public_key_bytes(&self) -> Result<Vec<u8>> {
+    Ok(self.public_key.to_iota_bytes())
+  }
+}
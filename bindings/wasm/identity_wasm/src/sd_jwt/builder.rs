@@ -5,6 +5,7 @@ use identity_iota::sd_jwt_payload::SdJwtBuilder;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 
+use crate::credential::WasmCredential;
 use crate::error::Result;
 use crate::error::WasmResult;
 use crate::sd_jwt::WasmSdJwt;
@@ -19,8 +20,10 @@ pub struct WasmSdJwtBuilder(pub(crate) SdJwtBuilder<WasmHasher>);
 
 #[wasm_bindgen(js_class = SdJwtBuilder)]
 impl WasmSdJwtBuilder {
-  /// Creates a new {@link SdJwtVcBuilder} using `object` JSON representation and a given
+  /// Creates a new {@link SdJwtBuilder} using `object` JSON representation and a given
   /// hasher `hasher`.
+  ///
+  /// `salt_size` is the size in bytes of the salts used in disclosures, defaults to 30.
   #[wasm_bindgen(constructor)]
   pub fn new(object: js_sys::Object, hasher: WasmHasher, salt_size: Option<usize>) -> Result<Self> {
     let object = serde_wasm_bindgen::from_value::<serde_json::Value>(object.into()).wasm_result()?;
@@ -30,6 +33,19 @@ impl WasmSdJwtBuilder {
       .wasm_result()
   }
 
+  /// Creates a new {@link SdJwtBuilder} starting from a {@link Credential} that is converted to a JWT claim set.
+  ///
+  /// `salt_size` is the size in bytes of the salts used in disclosures, defaults to 30.
+  #[wasm_bindgen(js_name = fromCredential)]
+  pub fn from_credential(credential: &WasmCredential, hasher: WasmHasher, salt_size: Option<usize>) -> Result<Self> {
+    let claims = credential.0.serialize_jwt(None).wasm_result()?;
+    let object = serde_json::from_str::<serde_json::Value>(&claims).wasm_result()?;
+    let salt_size = salt_size.unwrap_or(30);
+    SdJwtBuilder::new_with_hasher_and_salt_size(object, hasher, salt_size)
+      .map(Self)
+      .wasm_result()
+  }
+
   /// Substitutes a value with the digest of its disclosure.
   ///
   /// ## Notes
@@ -47,9 +63,9 @@ impl WasmSdJwtBuilder {
 
   /// Sets the JWT header.
   /// ## Notes
-  /// - if {@link SdJwtVcBuilder.header} is not called, the default header is used: ```json { "typ": "sd-jwt", "alg":
+  /// - if {@link SdJwtBuilder.header} is not called, the default header is used: ```json { "typ": "sd-jwt", "alg":
   ///   "<algorithm used in SdJwtBuilder.finish>" } ```
-  /// - `alg` is always replaced with the value passed to {@link SdJwtVcBuilder.finish}.
+  /// - `alg` is always replaced with the value passed to {@link SdJwtBuilder.finish}.
   pub fn headers(self, headers: js_sys::Object) -> Self {
     let headers = serde_wasm_bindgen::from_value(headers.into()).expect("JS object is a valid JSON object");
     Self(self.0.headers(headers))
@@ -83,7 +99,7 @@ impl WasmSdJwtBuilder {
     Ok(Self(self.0.require_key_binding(key_bind)))
   }
 
-  /// Creates an {@link SdJwtVc} with the provided data.
+  /// Creates an {@link SdJwt} with the provided data, signing it with `signer` using the JWS algorithm `alg`.
   pub async fn finish(self, signer: &WasmJwsSigner, alg: &str) -> Result<WasmSdJwt> {
     self.0.finish(signer, alg).await.map(WasmSdJwt).wasm_result()
   }
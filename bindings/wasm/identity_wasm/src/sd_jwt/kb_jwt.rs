@@ -15,6 +15,7 @@ use crate::common::WasmTimestamp;
 use crate::error::Result;
 use crate::error::WasmResult;
 
+use super::WasmHasher;
 use super::WasmJwsSigner;
 use super::WasmSdJwt;
 
@@ -113,34 +114,49 @@ impl WasmKeyBindingJwtBuilder {
       .wasm_result()
   }
 
+  /// Sets the time at which the KB-JWT is issued.
   #[wasm_bindgen]
   pub fn iat(self, iat: WasmTimestamp) -> Self {
     let iat = iat.0.to_unix();
     Self(self.0.iat(iat))
   }
 
+  /// Sets the intended receiver of the KB-JWT, usually the verifier.
   #[wasm_bindgen]
   pub fn aud(self, aud: String) -> Self {
     Self(self.0.aud(aud))
   }
 
+  /// Sets the nonce - e.g. the challenge provided by the verifier - ensuring the freshness of the KB-JWT.
   #[wasm_bindgen]
   pub fn nonce(self, nonce: String) -> Self {
     Self(self.0.nonce(nonce))
   }
 
+  /// Sets the custom claim `name` to `value`.
   #[wasm_bindgen(js_name = "insertProperty")]
   pub fn insert_property(self, name: String, value: JsValue) -> Result<Self> {
     let value = serde_wasm_bindgen::from_value(value).wasm_result()?;
     Ok(Self(self.0.insert_property(&name, value)))
   }
 
+  /// Creates a {@link KeyBindingJwt} bound to `sd_jwt`, signing it with `signer` using the JWS algorithm `alg`.
+  ///
+  /// `hasher` computes the `sd_hash` claim and must implement the hash function referenced by `sd_jwt`'s `_sd_alg`;
+  /// defaults to {@link Sha256Hasher}.
   #[wasm_bindgen]
-  pub async fn finish(self, sd_jwt: &WasmSdJwt, alg: &str, signer: &WasmJwsSigner) -> Result<WasmKeyBindingJwt> {
-    self
-      .0
-      .finish(&sd_jwt.0, &Sha256Hasher, alg, signer)
-      .await
+  pub async fn finish(
+    self,
+    sd_jwt: &WasmSdJwt,
+    alg: &str,
+    signer: &WasmJwsSigner,
+    hasher: Option<WasmHasher>,
+  ) -> Result<WasmKeyBindingJwt> {
+    let result = match hasher {
+      Some(hasher) => self.0.finish(&sd_jwt.0, &hasher, alg, signer).await,
+      None => self.0.finish(&sd_jwt.0, &Sha256Hasher, alg, signer).await,
+    };
+    result
       .map(WasmKeyBindingJwt)
       .map_err(|e| credential::Error::from(sd_jwt_vc::Error::SdJwt(e)))
       .wasm_result()
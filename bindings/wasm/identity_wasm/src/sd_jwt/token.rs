@@ -86,6 +86,18 @@ impl WasmSdJwt {
       .wasm_result()
   }
 
+  /// Returns the {@link KeyBindingJwt} attached to this token, if any.
+  #[wasm_bindgen(js_name = "keyBindingJwt")]
+  pub fn key_binding_jwt(&self) -> Option<WasmKeyBindingJwt> {
+    self.0.key_binding_jwt().cloned().map(WasmKeyBindingJwt)
+  }
+
+  /// Prepares a presentation of this token, allowing the holder to conceal claims before presenting it.
+  #[wasm_bindgen(js_name = "intoPresentation")]
+  pub fn into_presentation(self, hasher: &WasmHasher) -> Result<WasmSdJwtPresentationBuilder> {
+    WasmSdJwtPresentationBuilder::new(self, hasher)
+  }
+
   /// Attaches a {@link KeyBindingJwt} to this {@link SdJwt} token.
   /// ## Notes
   /// This method does *not* validate the given {@link KeyBindingJwt} in any way.
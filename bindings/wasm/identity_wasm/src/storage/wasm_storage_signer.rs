@@ -52,7 +52,7 @@ impl WasmStorageSigner {
     fragment: &str,
   ) -> std::result::Result<Self, JsError> {
     let document_lock = ImportedDocumentLock::from(document);
-    let document_lock = document_lock.read().await;
+    let document_lock = document_lock.read().await.map_err(|e| e.unchecked_into::<JsError>())?;
 
     let signer = StorageSigner::new_from_vm_fragment(&storage.0, document_lock.as_ref(), fragment).await?;
     let public_key = signer.public_key_jwk().clone();
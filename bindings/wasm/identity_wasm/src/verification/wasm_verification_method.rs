@@ -105,6 +105,17 @@ impl WasmVerificationMethod {
     *self.0.data_mut() = data.0.clone();
   }
 
+  /// Returns the public key of this {@link VerificationMethod} as a {@link Jwk}, converting the key material of
+  /// legacy `Ed25519VerificationKey2018/2020` and `X25519KeyAgreementKey2019/2020` methods.
+  #[wasm_bindgen(js_name = publicKeyAsJwk)]
+  pub fn public_key_as_jwk(&self) -> Result<WasmJwk> {
+    self
+      .0
+      .public_key_as_jwk()
+      .map(|jwk| WasmJwk::from(jwk.into_owned()))
+      .wasm_result()
+  }
+
   /// Get custom properties of the Verification Method.
   #[wasm_bindgen]
   pub fn properties(&self) -> Result<MapStringAny> {
@@ -0,0 +1,55 @@
+const assert = require("assert");
+import { mkdtemp, rm } from "node:fs/promises";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { JwsAlgorithm, MethodDigest, VerificationMethod } from "../node";
+import { FsJwkStore, FsKeyIdStore } from "../node/fs_storage";
+import { createVerificationMethod } from "./key_id_storage";
+
+describe("#FsStorage", function() {
+    let directory: string;
+
+    beforeEach(async function() {
+        directory = await mkdtemp(join(tmpdir(), "identity-wasm-"));
+    });
+
+    afterEach(async function() {
+        await rm(directory, { recursive: true, force: true });
+    });
+
+    it("persists keys across instances", async () => {
+        const testData = Uint8Array.from([0xff, 0xee, 0xdd, 0xcc]);
+        const keystore = new FsJwkStore(join(directory, "keys"));
+        const genOutput = await keystore.generate(FsJwkStore.ed25519KeyType(), JwsAlgorithm.EdDSA);
+        const keyId = genOutput.keyId();
+        const publicKey = genOutput.jwk().toPublic()!;
+        const signature = await keystore.sign(keyId, testData, publicKey);
+
+        // A new instance over the same directory signs with the same key.
+        const reopened = new FsJwkStore(join(directory, "keys"));
+        assert.ok(await reopened.exists(keyId));
+        assert.deepStrictEqual(await reopened.sign(keyId, testData, publicKey), signature);
+
+        await reopened.delete(keyId);
+        assert.ok(!await keystore.exists(keyId));
+        await assert.rejects(keystore.delete(keyId));
+        await assert.rejects(keystore.sign(keyId, testData, publicKey));
+    });
+
+    it("persists key ids across instances", async () => {
+        const KEY_ID = "my-key-id";
+        const vm: VerificationMethod = createVerificationMethod();
+        const methodDigest: MethodDigest = new MethodDigest(vm);
+        const store = new FsKeyIdStore(join(directory, "key-ids"));
+
+        await assert.rejects(store.deleteKeyId(methodDigest));
+        await store.insertKeyId(methodDigest, KEY_ID);
+        await assert.rejects(store.insertKeyId(methodDigest, KEY_ID));
+
+        const reopened = new FsKeyIdStore(join(directory, "key-ids"));
+        assert.equal(await reopened.getKeyId(MethodDigest.unpack(methodDigest.pack())), KEY_ID);
+
+        await reopened.deleteKeyId(methodDigest);
+        await assert.rejects(store.getKeyId(methodDigest));
+    });
+});
@@ -0,0 +1,90 @@
+import * as assert from "assert";
+import { CandidateCredential, PresentationDefinition, PresentationSubmission } from "../node";
+
+function encode(value: unknown): string {
+    return Buffer.from(JSON.stringify(value)).toString("base64url");
+}
+
+function jwtVc(credentialSubject: Record<string, unknown>): string {
+    const claims = {
+        iss: "did:example:issuer",
+        nbf: 1704067200,
+        vc: {
+            "@context": ["https://www.w3.org/2018/credentials/v1"],
+            type: ["VerifiableCredential", "UniversityDegreeCredential"],
+            credentialSubject,
+        },
+    };
+    return `${encode({ alg: "EdDSA", kid: "did:example:issuer#key-1" })}.${encode(claims)}.${encode("signature")}`;
+}
+
+const definition = new PresentationDefinition({
+    id: "degree-check",
+    input_descriptors: [{
+        id: "degree",
+        constraints: {
+            fields: [
+                {
+                    path: ["$.vc.type"],
+                    filter: { type: "array", contains: { const: "UniversityDegreeCredential" } },
+                },
+                {
+                    path: ["$.vc.credentialSubject.gpa", "$.credentialSubject.gpa"],
+                    filter: { type: "number", minimum: 3 },
+                },
+            ],
+        },
+    }],
+});
+
+describe("PresentationDefinition", function() {
+    it("evaluates JSONPath expressions and JSON Schema filters", () => {
+        const credentials = [
+            CandidateCredential.jwtVc(jwtVc({ gpa: 2.5 })),
+            CandidateCredential.jwtVc(jwtVc({ gpa: 3.8 })),
+        ];
+
+        const matches = definition.evaluate(credentials);
+        assert.deepStrictEqual(matches, [{
+            descriptorId: "degree",
+            credentials: [{ index: 1, pointers: ["/vc/type", "/vc/credentialSubject/gpa"] }],
+        }]);
+        // Credentials are still usable after being evaluated.
+        assert.deepStrictEqual(credentials[0].format(), "jwt_vc_json");
+    });
+
+    it("builds the presentation submission", () => {
+        const credentials = [
+            CandidateCredential.jwtVc(jwtVc({ gpa: 2.5 })),
+            CandidateCredential.jwtVc(jwtVc({ gpa: 3.8 })),
+        ];
+
+        const submission = definition.submit("submission-1", credentials);
+        assert.deepStrictEqual(submission.credentials(), [{ index: 1 }]);
+        const presentationSubmission = submission.presentationSubmission();
+        assert.deepStrictEqual(presentationSubmission.definitionId(), "degree-check");
+        assert.deepStrictEqual(presentationSubmission.descriptorMap(), [{ id: "degree", format: "jwt_vc_json", path: "$" }]);
+        assert.deepStrictEqual(
+            PresentationSubmission.fromJSON(presentationSubmission.toJSON()).toJSON(),
+            presentationSubmission.toJSON(),
+        );
+    });
+
+    it("fails when an input descriptor cannot be satisfied", () => {
+        const credentials = [CandidateCredential.jwtVc(jwtVc({ gpa: 2.5 }))];
+
+        assert.throws(() => definition.submit("submission-2", credentials), { name: "PresentationExchangeError" });
+    });
+
+    it("evaluates credentials with arbitrary claims", () => {
+        const credential = new CandidateCredential("dc+sd-jwt", "eyJ...~", {
+            vct: "https://example.com/degree",
+            credentialSubject: { gpa: 3.1 },
+            vc: { type: ["VerifiableCredential", "UniversityDegreeCredential"] },
+        });
+
+        const [match] = definition.evaluate([credential]);
+        assert.deepStrictEqual(match.credentials.map((c) => c.index), [0]);
+        assert.deepStrictEqual(CandidateCredential.fromJSON(credential.toJSON()).claims(), credential.claims());
+    });
+});
@@ -0,0 +1,66 @@
+const assert = require("assert");
+import {
+    configureRuntime,
+    createIotaClient,
+    createStorage,
+    JwkMemStore,
+    JwsAlgorithm,
+    KeyIdMemStore,
+    Storage,
+} from "../node";
+
+describe("#configureRuntime", function() {
+    const originalFetch = globalThis.fetch;
+    const originalGetRandomValues = globalThis.crypto.getRandomValues;
+
+    after(function() {
+        configureRuntime({
+            fetch: undefined,
+            getRandomValues: undefined,
+            jwkStorage: undefined,
+            keyIdStorage: undefined,
+        });
+    });
+
+    it("injects fetch without replacing the global one", async () => {
+        const requested: string[] = [];
+        configureRuntime({
+            fetch: async (input) => {
+                requested.push(input.toString());
+                return new Response("{}");
+            },
+        });
+
+        const client = createIotaClient("https://node.example.com");
+        // The response is not a valid JSON-RPC one, only the request is of interest.
+        await client.getChainIdentifier().catch(() => undefined);
+        assert.deepStrictEqual(requested, ["https://node.example.com"]);
+        assert.strictEqual(globalThis.fetch, originalFetch);
+    });
+
+    it("uses the configured source of randomness", async () => {
+        let calls = 0;
+        configureRuntime({
+            getRandomValues: (array) => {
+                calls += 1;
+                return originalGetRandomValues.call(globalThis.crypto, array);
+            },
+        });
+
+        const memstore = new JwkMemStore();
+        await memstore.generate(JwkMemStore.ed25519KeyType(), JwsAlgorithm.EdDSA);
+        // Both the key id and the private key are drawn from it.
+        assert.strictEqual(calls, 2);
+        assert.strictEqual(globalThis.crypto.getRandomValues, originalGetRandomValues);
+    });
+
+    it("creates storages from the configured factories", async () => {
+        const jwkStorage = new JwkMemStore();
+        const keyIdStorage = new KeyIdMemStore();
+        configureRuntime({ jwkStorage: () => jwkStorage, keyIdStorage: () => keyIdStorage });
+
+        const storage: Storage = createStorage();
+        assert.strictEqual(storage.keyStorage(), jwkStorage);
+        assert.strictEqual(storage.keyIdStorage(), keyIdStorage);
+    });
+});
@@ -0,0 +1,89 @@
+import * as assert from "assert";
+import { readFileSync } from "fs";
+import { CoreDocument, Credential, JwtCredentialValidator } from "../node";
+
+// Replays the test vectors generated by `identity_credential/tests/serialization_differential.rs` against the
+// bindings, asserting the same canonical serialization and validation outcome as the Rust library.
+// Run through `npm run test:differential`, which builds and runs both halves.
+const VECTORS_PATH = process.env.IDENTITY_DIFFERENTIAL_VECTORS;
+
+type Outcome = "unparsable" | "invalid" | "valid";
+
+interface TestVector {
+    kind: "credential" | "document";
+    input: any;
+    outcome: Outcome;
+    canonical?: string;
+}
+
+interface Result {
+    outcome: Outcome;
+    canonical?: string;
+}
+
+function runCredential(input: any): Result {
+    let credential: Credential;
+    try {
+        credential = Credential.fromJSON(input);
+    } catch {
+        return { outcome: "unparsable" };
+    }
+    const canonical = credential.toCanonicalJSON();
+    try {
+        JwtCredentialValidator.checkStructure(credential);
+    } catch {
+        return { outcome: "invalid", canonical };
+    }
+    return { outcome: "valid", canonical };
+}
+
+function runDocument(input: any): Result {
+    let document: CoreDocument;
+    try {
+        document = CoreDocument.fromJSON(input);
+    } catch {
+        return { outcome: "unparsable" };
+    }
+    return { outcome: "valid", canonical: document.toCanonicalJSON() };
+}
+
+function replay(kind: TestVector["kind"], run: (input: any) => Result) {
+    const vectors: TestVector[] = JSON.parse(readFileSync(VECTORS_PATH!, "utf8"));
+    const mismatches: string[] = [];
+
+    vectors.forEach((vector, index) => {
+        if (vector.kind !== kind) {
+            return;
+        }
+        const result = run(vector.input);
+        // Compare the UTF-8 encodings, i.e. the bytes that would get hashed or signed.
+        const canonical = result.canonical === undefined ? undefined : Buffer.from(result.canonical, "utf8");
+        const expected = vector.canonical == null ? undefined : Buffer.from(vector.canonical, "utf8");
+        const sameCanonical = canonical === undefined || expected === undefined
+            ? canonical === expected
+            : canonical.equals(expected);
+
+        if (result.outcome !== vector.outcome || !sameCanonical) {
+            mismatches.push(
+                `test vector ${index}: expected ${vector.outcome} ${vector.canonical}, got ${result.outcome} ${result.canonical}`,
+            );
+        }
+    });
+
+    assert.deepStrictEqual(mismatches, [], `${mismatches.length} ${kind} test vectors diverge from Rust`);
+}
+
+describe("serialization differential", function() {
+    before(function() {
+        if (!VECTORS_PATH) {
+            this.skip();
+        }
+    });
+
+    it("credentials match Rust", () => {
+        replay("credential", runCredential);
+    });
+    it("documents match Rust", () => {
+        replay("document", runDocument);
+    });
+});
@@ -466,10 +466,22 @@ describe("#OptionParsing", function() {
                 latestIssuanceDate: Timestamp.nowUTC(),
                 status: StatusCheck.SkipAll,
                 subjectHolderRelationship: ["did:iota:0x123", SubjectHolderRelationship.SubjectOnNonTransferable],
-                verifierOptions: new JwsVerificationOptions({
+                verificationOptions: new JwsVerificationOptions({
                     nonce: "nonce",
                 }),
             });
+        }),
+        it("JwtCredentialValidationOptions ignores unknown properties", () => {
+            assert.doesNotThrow(() => new JwtCredentialValidationOptions({ verifierOptions: {} } as any));
+        }),
+        it("JwtCredentialValidationOptions rejects invalid options with their path", () => {
+            assert.throws(
+                () =>
+                    new JwtCredentialValidationOptions({
+                        issuerLocation: { precedence: ["sub"] },
+                    } as any),
+                /`issuerLocation.precedence\[0\]`/,
+            );
         });
 });
 
@@ -0,0 +1,188 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON Canonicalization Scheme ([RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)).
+
+use serde_json::Number;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// Integers up to this magnitude are represented exactly by an IEEE 754 double.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Serializes `value` as RFC 8785 canonical JSON.
+pub(crate) fn to_canonical_json(value: &Value) -> Result<String> {
+  let mut output = String::new();
+  write_value(value, &mut output)?;
+  Ok(output)
+}
+
+fn write_value(value: &Value, output: &mut String) -> Result<()> {
+  match value {
+    Value::Null => output.push_str("null"),
+    Value::Bool(bool) => output.push_str(if *bool { "true" } else { "false" }),
+    Value::Number(number) => output.push_str(&format_number(number)),
+    Value::String(string) => write_string(string, output)?,
+    Value::Array(array) => {
+      output.push('[');
+      for (i, element) in array.iter().enumerate() {
+        if i > 0 {
+          output.push(',');
+        }
+        write_value(element, output)?;
+      }
+      output.push(']');
+    }
+    Value::Object(object) => {
+      // Properties are sorted by the UTF-16 code units of their names.
+      let mut entries: Vec<(&String, &Value)> = object.iter().collect();
+      entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+
+      output.push('{');
+      for (i, (key, value)) in entries.into_iter().enumerate() {
+        if i > 0 {
+          output.push(',');
+        }
+        write_string(key, output)?;
+        output.push(':');
+        write_value(value, output)?;
+      }
+      output.push('}');
+    }
+  }
+
+  Ok(())
+}
+
+/// The escaping rules of RFC 8785 match the ones of `serde_json`.
+fn write_string(string: &str, output: &mut String) -> Result<()> {
+  output.push_str(&serde_json::to_string(string).map_err(Error::EncodeJSON)?);
+  Ok(())
+}
+
+/// Formats `number` like ECMAScript's `Number.prototype.toString`, as mandated by RFC 8785.
+fn format_number(number: &Number) -> String {
+  if let Some(integer) = number.as_u64().filter(|integer| *integer <= MAX_SAFE_INTEGER) {
+    return integer.to_string();
+  }
+  if let Some(integer) = number
+    .as_i64()
+    .filter(|integer| integer.unsigned_abs() <= MAX_SAFE_INTEGER)
+  {
+    return integer.to_string();
+  }
+
+  let float = number.as_f64().expect("JSON numbers are representable as f64");
+  if float == 0.0 {
+    return "0".to_owned();
+  }
+
+  // Rust's exponential notation yields the shortest digits that round-trip, e.g. `1.2345e-7`.
+  let exponential = format!("{:e}", float.abs());
+  let (mantissa, exponent) = exponential.split_once('e').expect("exponential notation");
+  let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+  let exponent: i32 = exponent.parse().expect("valid exponent");
+
+  // `digits` is the shortest digit string `s` such that `float == s * 10^(n - k)`, following ECMA-262.
+  let k = digits.len() as i32;
+  let n = exponent + 1;
+  let mut output = String::new();
+  if float.is_sign_negative() {
+    output.push('-');
+  }
+
+  if k <= n && n <= 21 {
+    output.push_str(&digits);
+    output.extend(std::iter::repeat('0').take((n - k) as usize));
+  } else if 0 < n && n <= 21 {
+    output.push_str(&digits[..n as usize]);
+    output.push('.');
+    output.push_str(&digits[n as usize..]);
+  } else if -6 < n && n <= 0 {
+    output.push_str("0.");
+    output.extend(std::iter::repeat('0').take(-n as usize));
+    output.push_str(&digits);
+  } else {
+    output.push_str(&digits[..1]);
+    if k > 1 {
+      output.push('.');
+      output.push_str(&digits[1..]);
+    }
+    output.push('e');
+    output.push(if n - 1 < 0 { '-' } else { '+' });
+    output.push_str(&(n - 1).abs().to_string());
+  }
+
+  output
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn number(value: f64) -> String {
+    format_number(&Number::from_f64(value).unwrap())
+  }
+
+  #[test]
+  fn numbers_follow_ecmascript() {
+    assert_eq!(number(0.0), "0");
+    assert_eq!(number(-0.0), "0");
+    assert_eq!(number(4.50), "4.5");
+    assert_eq!(number(2e-3), "0.002");
+    assert_eq!(number(0.000001), "0.000001");
+    assert_eq!(number(1e-7), "1e-7");
+    assert_eq!(number(1e20), "100000000000000000000");
+    assert_eq!(number(1e21), "1e+21");
+    assert_eq!(number(1e30), "1e+30");
+    assert_eq!(number(-1.5e-10), "-1.5e-10");
+    assert_eq!(number(333333333.33333329), "333333333.3333333");
+    assert_eq!(number(9007199254740992.0), "9007199254740992");
+    assert_eq!(format_number(&Number::from(u64::MAX)), "18446744073709552000");
+    assert_eq!(format_number(&Number::from(-42)), "-42");
+  }
+
+  #[test]
+  fn rfc_8785_example() {
+    let value = json!({
+      "numbers": [333333333.33333329, 1E30, 4.50, 2e-3, 0.000000000000000000000000001],
+      "string": "\u{20ac}$\u{000F}\u{000a}A'\u{0042}\u{0022}\u{005c}\\\"/",
+      "literals": [null, true, false]
+    });
+
+    assert_eq!(
+      to_canonical_json(&value).unwrap(),
+      r#"{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],"string":"€$\u000f\nA'B\"\\\\\"/"}"#
+    );
+  }
+
+  #[test]
+  fn properties_are_sorted_by_utf16_code_units() {
+    let value = json!({
+      "\u{20ac}": "Euro Sign",
+      "\r": "Carriage Return",
+      "\u{fb33}": "Hebrew Letter Dalet With Dagesh",
+      "1": "One",
+      "\u{1f600}": "Emoji: Grinning Face",
+      "\u{0080}": "Control",
+      "\u{00f6}": "Latin Small Letter O With Diaeresis"
+    });
+    let values = [
+      "Carriage Return",
+      "One",
+      "Control",
+      "Latin Small Letter O With Diaeresis",
+      "Euro Sign",
+      "Emoji: Grinning Face",
+      "Hebrew Letter Dalet With Dagesh",
+    ];
+
+    let canonical = to_canonical_json(&value).unwrap();
+    let positions: Vec<usize> = values.iter().map(|value| canonical.find(value).unwrap()).collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+}
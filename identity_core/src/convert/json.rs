@@ -30,6 +30,12 @@ pub trait ToJson: Serialize + Sized {
   fn to_json_pretty(&self) -> Result<String> {
     serde_json::to_string_pretty(self).map_err(Error::EncodeJSON)
   }
+
+  /// Serialize `self` as a string of canonical JSON, following the
+  /// [JSON Canonicalization Scheme (RFC 8785)](https://www.rfc-editor.org/rfc/rfc8785).
+  fn to_jcs(&self) -> Result<String> {
+    super::canonical_json::to_canonical_json(&self.to_json_value()?)
+  }
 }
 
 impl<T> ToJson for T where T: Serialize {}
@@ -6,7 +6,14 @@
 pub use self::json::FmtJson;
 pub use self::json::FromJson;
 pub use self::json::ToJson;
+pub use self::versioned::migrate;
+pub use self::versioned::FromVersionedJson;
+pub use self::versioned::ToVersionedJson;
+pub use self::versioned::VersionedSchema;
+pub use self::versioned::VERSIONED_JSON_MEDIA_TYPE;
 pub use base_encoding::*;
 
 mod base_encoding;
+mod canonical_json;
 mod json;
+mod versioned;
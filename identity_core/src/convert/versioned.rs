@@ -0,0 +1,265 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The media type of versioned JSON envelopes, carried in their `typ` member. See [`VersionedSchema`].
+pub const VERSIONED_JSON_MEDIA_TYPE: &str = "application/vnd.iota.identity.versioned+json";
+
+/// A type persisted across releases of the library, whose serialized form carries the version of its schema.
+///
+/// Values are written inside an envelope
+/// `{ "typ": VERSIONED_JSON_MEDIA_TYPE, "kind": KIND, "version": VERSION, "data": <value> }` through
+/// [`ToVersionedJson`] and read back through [`FromVersionedJson`], which migrates data written by older
+/// releases to the current schema. Data written before the type was versioned - i.e. without an envelope - is
+/// treated as version `0`.
+///
+/// Envelopes are recognized by their `typ` member alone, never by the shape of the JSON, so that unversioned data
+/// which happens to have `kind`, `version` and `data` properties is not mistaken for one.
+pub trait VersionedSchema: Serialize + DeserializeOwned {
+  /// Name identifying the persisted type.
+  const KIND: &'static str;
+
+  /// The current version of the schema. Must be incremented whenever the serialized form changes
+  /// incompatibly, together with a new step in [`VersionedSchema::migrate_step`].
+  const VERSION: u16;
+
+  /// Migrates `data`, serialized according to version `from` of the schema, to version `from + 1`.
+  ///
+  /// The default implementation considers version `0`, i.e. unversioned data, identical to version `1`
+  /// and supports no other migration.
+  fn migrate_step(from: u16, data: Value) -> Result<Value> {
+    match from {
+      0 => Ok(data),
+      _ => Err(Error::SchemaMigration {
+        kind: Self::KIND,
+        from,
+        message: "no migration available".to_owned(),
+      }),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Envelope<T> {
+  typ: String,
+  kind: String,
+  version: u16,
+  data: T,
+}
+
+#[derive(Serialize)]
+struct EnvelopeRef<'a, T> {
+  typ: &'static str,
+  kind: &'static str,
+  version: u16,
+  data: &'a T,
+}
+
+/// Serializes [`VersionedSchema`] types as versioned JSON.
+pub trait ToVersionedJson: VersionedSchema {
+  /// Serializes `self` as versioned JSON bytes.
+  fn to_versioned_json(&self) -> Result<Vec<u8>> {
+    serde_json::to_vec(&EnvelopeRef {
+      typ: VERSIONED_JSON_MEDIA_TYPE,
+      kind: Self::KIND,
+      version: Self::VERSION,
+      data: self,
+    })
+    .map_err(Error::EncodeJSON)
+  }
+}
+
+impl<T> ToVersionedJson for T where T: VersionedSchema {}
+
+/// Deserializes [`VersionedSchema`] types from versioned JSON, migrating older versions.
+pub trait FromVersionedJson: VersionedSchema {
+  /// Deserializes `Self` from versioned JSON bytes written by this or any older release.
+  fn from_versioned_json(json: &(impl AsRef<[u8]> + ?Sized)) -> Result<Self> {
+    let data: Value = migrate_value::<Self>(serde_json::from_slice(json.as_ref()).map_err(Error::DecodeJSON)?)?;
+    serde_json::from_value(data).map_err(Error::DecodeJSON)
+  }
+}
+
+impl<T> FromVersionedJson for T where T: VersionedSchema {}
+
+/// Upgrades versioned JSON bytes of a `T`, written by this or any older release, to the current version of its
+/// schema without otherwise altering the value.
+///
+/// Useful to upgrade persisted state in place after updating the library.
+pub fn migrate<T: VersionedSchema>(json: &(impl AsRef<[u8]> + ?Sized)) -> Result<Vec<u8>> {
+  let data: Value = migrate_value::<T>(serde_json::from_slice(json.as_ref()).map_err(Error::DecodeJSON)?)?;
+  serde_json::to_vec(&Envelope {
+    typ: VERSIONED_JSON_MEDIA_TYPE.to_owned(),
+    kind: T::KIND.to_owned(),
+    version: T::VERSION,
+    data,
+  })
+  .map_err(Error::EncodeJSON)
+}
+
+/// Returns the data of `json` migrated to the current version of `T`'s schema.
+fn migrate_value<T: VersionedSchema>(json: Value) -> Result<Value> {
+  let (mut version, mut data) = match json {
+    Value::Object(ref object) if is_envelope(object) => {
+      let envelope: Envelope<Value> = serde_json::from_value(json).map_err(Error::DecodeJSON)?;
+      if envelope.kind != T::KIND {
+        return Err(Error::SchemaKindMismatch {
+          expected: T::KIND,
+          found: envelope.kind,
+        });
+      }
+      (envelope.version, envelope.data)
+    }
+    unversioned => (0, unversioned),
+  };
+
+  if version > T::VERSION {
+    return Err(Error::UnsupportedSchemaVersion {
+      kind: T::KIND,
+      version,
+      latest: T::VERSION,
+    });
+  }
+  while version < T::VERSION {
+    data = T::migrate_step(version, data)?;
+    version += 1;
+  }
+
+  Ok(data)
+}
+
+fn is_envelope(object: &serde_json::Map<String, Value>) -> bool {
+  object.get("typ").and_then(Value::as_str) == Some(VERSIONED_JSON_MEDIA_TYPE)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::convert::ToJson;
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Mapping {
+    method: String,
+    key_id: String,
+  }
+
+  impl VersionedSchema for Mapping {
+    const KIND: &'static str = "Mapping";
+    const VERSION: u16 = 2;
+
+    fn migrate_step(from: u16, mut data: Value) -> Result<Value> {
+      match from {
+        0 => Ok(data),
+        // Version 2 renamed `keyId` to `key_id`.
+        1 => {
+          let key_id = data["keyId"].take();
+          data["key_id"] = key_id;
+          data.as_object_mut().unwrap().remove("keyId");
+          Ok(data)
+        }
+        _ => unreachable!(),
+      }
+    }
+  }
+
+  fn mapping() -> Mapping {
+    Mapping {
+      method: "#key-1".to_owned(),
+      key_id: "abc".to_owned(),
+    }
+  }
+
+  #[test]
+  fn round_trip() {
+    let json = mapping().to_versioned_json().unwrap();
+    assert_eq!(
+      serde_json::from_slice::<Value>(&json).unwrap(),
+      json!({
+        "typ": VERSIONED_JSON_MEDIA_TYPE,
+        "kind": "Mapping",
+        "version": 2,
+        "data": { "method": "#key-1", "key_id": "abc" }
+      })
+    );
+    assert_eq!(Mapping::from_versioned_json(&json).unwrap(), mapping());
+  }
+
+  #[test]
+  fn older_versions_are_migrated() {
+    let unversioned = json!({ "method": "#key-1", "keyId": "abc" }).to_json_vec().unwrap();
+    assert_eq!(Mapping::from_versioned_json(&unversioned).unwrap(), mapping());
+
+    let v1 = json!({
+      "typ": VERSIONED_JSON_MEDIA_TYPE,
+      "kind": "Mapping",
+      "version": 1,
+      "data": { "method": "#key-1", "keyId": "abc" }
+    })
+    .to_json_vec()
+    .unwrap();
+    assert_eq!(Mapping::from_versioned_json(&v1).unwrap(), mapping());
+    assert_eq!(migrate::<Mapping>(&v1).unwrap(), mapping().to_versioned_json().unwrap());
+  }
+
+  #[test]
+  fn newer_versions_and_other_kinds_are_rejected() {
+    let v3 = json!({ "typ": VERSIONED_JSON_MEDIA_TYPE, "kind": "Mapping", "version": 3, "data": {} })
+      .to_json_vec()
+      .unwrap();
+    assert!(matches!(
+      Mapping::from_versioned_json(&v3).unwrap_err(),
+      Error::UnsupportedSchemaVersion {
+        version: 3,
+        latest: 2,
+        ..
+      }
+    ));
+
+    let other = json!({ "typ": VERSIONED_JSON_MEDIA_TYPE, "kind": "Other", "version": 1, "data": {} })
+      .to_json_vec()
+      .unwrap();
+    assert!(matches!(
+      Mapping::from_versioned_json(&other).unwrap_err(),
+      Error::SchemaKindMismatch { .. }
+    ));
+  }
+
+  #[test]
+  fn envelopes_are_detected_by_their_media_type() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Shaped {
+      kind: String,
+      version: u16,
+      data: Value,
+    }
+
+    impl VersionedSchema for Shaped {
+      const KIND: &'static str = "Shaped";
+      const VERSION: u16 = 1;
+    }
+
+    // Unversioned data shaped like an envelope is not mistaken for one.
+    let shaped = Shaped {
+      kind: "Other".to_owned(),
+      version: 7,
+      data: json!({}),
+    };
+    assert_eq!(
+      Shaped::from_versioned_json(&shaped.to_json_vec().unwrap()).unwrap(),
+      shaped
+    );
+    assert_eq!(
+      Shaped::from_versioned_json(&shaped.to_versioned_json().unwrap()).unwrap(),
+      shaped
+    );
+  }
+}
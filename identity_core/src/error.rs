@@ -36,4 +36,32 @@ pub enum Error {
   /// Caused by attempting to convert a collection with duplicate keys into an OrderedSet.
   #[error("duplicate key in OrderedSet")]
   OrderedSetDuplicate,
+  /// Caused by attempting to deserialize a versioned value written by a newer release of the library.
+  #[error("unsupported version {version} of `{kind}`, latest supported version is {latest}")]
+  UnsupportedSchemaVersion {
+    /// The kind of the versioned value.
+    kind: &'static str,
+    /// The version of the value.
+    version: u16,
+    /// The latest version supported by this release.
+    latest: u16,
+  },
+  /// Caused by attempting to deserialize a versioned value of a different kind.
+  #[error("expected a versioned `{expected}`, found `{found}`")]
+  SchemaKindMismatch {
+    /// The expected kind.
+    expected: &'static str,
+    /// The kind of the value.
+    found: String,
+  },
+  /// Caused by a failure to migrate a versioned value to a newer version of its schema.
+  #[error("failed to migrate `{kind}` from version {from}: {message}")]
+  SchemaMigration {
+    /// The kind of the versioned value.
+    kind: &'static str,
+    /// The version the migration started from.
+    from: u16,
+    /// Description of the failure.
+    message: String,
+  },
 }
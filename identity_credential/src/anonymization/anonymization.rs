@@ -0,0 +1,401 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::AnonymizationError;
+
+type Result<T, E = AnonymizationError> = std::result::Result<T, E>;
+
+/// The precision a date is generalized to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DatePrecision {
+  /// The year, e.g. `1990`.
+  Year,
+  /// The year and month, e.g. `1990-01`.
+  Month,
+}
+
+/// How the value of a claim is generalized before being exported.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Generalization {
+  /// The value is exported as is. Only meant for claims that are not identifying on their own, e.g. a credential type.
+  Keep,
+  /// A number is replaced by the interval of size `width` it falls in, e.g. `[20, 30)`.
+  Range {
+    /// Size of the intervals, which start at multiples of it.
+    width: f64,
+  },
+  /// A string is replaced by its first `keep` characters followed by `*`, e.g. `75*` for the postal code `75011`.
+  Truncate {
+    /// Number of characters kept.
+    keep: usize,
+  },
+  /// A date or RFC 3339 timestamp is replaced by its year or month.
+  Date {
+    /// Precision of the exported date.
+    precision: DatePrecision,
+  },
+  /// A birth date or RFC 3339 timestamp is replaced by the interval of size `width` the age it denotes falls in.
+  AgeRange {
+    /// Size of the intervals, in years.
+    width: u32,
+  },
+  /// A value is replaced by the category it is mapped to, e.g. a city by its region.
+  Category {
+    /// Maps the textual representation of values to their category.
+    mapping: BTreeMap<String, Value>,
+    /// The category of unmapped values. Unmapped values are exported as `null` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<Value>,
+  },
+}
+
+impl Generalization {
+  /// Generalizes `value`, returning a description of the problem if `value` cannot be generalized.
+  fn apply(&self, value: &Value, now: Timestamp) -> std::result::Result<Value, String> {
+    match self {
+      Self::Keep => Ok(value.clone()),
+      Self::Range { width } if !width.is_finite() || *width <= 0.0 => Err(format!("invalid range width {width}")),
+      Self::Range { width } => {
+        let number = value.as_f64().ok_or_else(|| format!("{value} is not a number"))?;
+        let lower = (number / width).floor() * width;
+        Ok(Value::String(format!(
+          "[{}, {})",
+          format_number(lower),
+          format_number(lower + width)
+        )))
+      }
+      Self::Truncate { keep } => {
+        let string = value.as_str().ok_or_else(|| format!("{value} is not a string"))?;
+        Ok(Value::String(string.chars().take(*keep).chain(['*']).collect()))
+      }
+      Self::Date { precision } => {
+        let (year, month, _) = parse_date(value)?;
+        Ok(Value::String(match precision {
+          DatePrecision::Year => format!("{year:04}"),
+          DatePrecision::Month => format!("{year:04}-{month:02}"),
+        }))
+      }
+      Self::AgeRange { width: 0 } => Err("invalid age range width 0".to_owned()),
+      Self::AgeRange { width } => {
+        let (year, month, day) = parse_date(value)?;
+        let (now_year, now_month, now_day) = parse_date(&Value::String(now.to_rfc3339()))?;
+        let age = now_year - year - i64::from((now_month, now_day) < (month, day));
+        if age < 0 {
+          return Err(format!("{value} is in the future"));
+        }
+        let lower = age / i64::from(*width) * i64::from(*width);
+        Ok(Value::String(format!("[{lower}, {})", lower + i64::from(*width))))
+      }
+      Self::Category { mapping, default } => {
+        let key = match value {
+          Value::String(string) => string.clone(),
+          value => value.to_string(),
+        };
+        Ok(mapping.get(&key).or(default.as_ref()).cloned().unwrap_or(Value::Null))
+      }
+    }
+  }
+}
+
+/// A rule exporting a single claim of a credential, after generalizing it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymizationRule {
+  /// JSON pointer to the claim in the credential, e.g. `/credentialSubject/birthDate`.
+  pub claim: String,
+  /// Name of the field of the exported record.
+  pub field: String,
+  /// How the claim is generalized.
+  pub generalization: Generalization,
+}
+
+impl AnonymizationRule {
+  /// Creates a new [`AnonymizationRule`] exporting the claim at `claim` as `field`, generalized by `generalization`.
+  pub fn new(claim: impl Into<String>, field: impl Into<String>, generalization: Generalization) -> Self {
+    Self {
+      claim: claim.into(),
+      field: field.into(),
+      generalization,
+    }
+  }
+}
+
+/// A group of credentials sharing the same generalized record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedRecord {
+  /// The generalized record, mapping the field of each rule to its generalized claim.
+  pub fields: Object,
+  /// Number of credentials in the group.
+  pub count: usize,
+}
+
+/// The result of anonymizing a set of credentials.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnonymizedDataset {
+  /// The groups of at least `k` credentials, in order of first occurrence.
+  pub records: Vec<AggregatedRecord>,
+  /// Number of credentials left out, because they belong to groups of less than `k` credentials.
+  pub suppressed: usize,
+}
+
+/// A policy turning verified credentials into [k-anonymous](https://en.wikipedia.org/wiki/K-anonymity) aggregated
+/// records, to be exported to analytics pipelines in place of the credentials themselves.
+///
+/// Every record only contains the claims selected by the policy's rules, generalized as configured. Records are then
+/// grouped, and groups of less than `k` credentials are suppressed, so that no exported record can be traced back to
+/// less than `k` credentials.
+///
+/// ```
+/// # use identity_credential::anonymization::AnonymizationPolicy;
+/// # use serde_json::json;
+/// let policy: AnonymizationPolicy = serde_json::from_value(json!({
+///   "k": 2,
+///   "rules": [
+///     { "claim": "/credentialSubject/age", "field": "age", "generalization": { "type": "range", "width": 10 } },
+///     { "claim": "/credentialSubject/postalCode", "field": "area", "generalization": { "type": "truncate", "keep": 2 } }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// let credentials = [
+///   json!({ "credentialSubject": { "name": "Alice", "age": 34, "postalCode": "75011" } }),
+///   json!({ "credentialSubject": { "name": "Bob", "age": 38, "postalCode": "75002" } }),
+///   json!({ "credentialSubject": { "name": "Carol", "age": 61, "postalCode": "13001" } }),
+/// ];
+/// let dataset = policy.anonymize(&credentials).unwrap();
+/// assert_eq!(dataset.records.len(), 1);
+/// assert_eq!(dataset.records[0].fields["age"], "[30, 40)");
+/// assert_eq!(dataset.records[0].fields["area"], "75*");
+/// assert_eq!(dataset.records[0].count, 2);
+/// assert_eq!(dataset.suppressed, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnonymizationPolicy {
+  /// Minimum number of credentials sharing an exported record.
+  pub k: usize,
+  /// The rules of this policy.
+  pub rules: Vec<AnonymizationRule>,
+}
+
+impl AnonymizationPolicy {
+  /// Creates a new [`AnonymizationPolicy`] without rules, exporting groups of at least `k` credentials.
+  pub fn new(k: usize) -> Self {
+    Self { k, rules: Vec::new() }
+  }
+
+  /// Adds a rule to this policy.
+  pub fn rule(mut self, rule: AnonymizationRule) -> Self {
+    self.rules.push(rule);
+    self
+  }
+
+  /// Generalizes the claims of a single `credential` into a record. Missing claims are exported as `null`.
+  ///
+  /// ## Warning
+  /// A single record is not k-anonymous: use [`AnonymizationPolicy::anonymize`] to export records.
+  pub fn generalize<C: Serialize>(&self, credential: &C) -> Result<Object> {
+    self.generalize_at(credential, Timestamp::now_utc())
+  }
+
+  /// Generalizes and aggregates `credentials`, suppressing the groups of less than `k` credentials.
+  ///
+  /// Credentials can be of any serializable type, e.g. [`Credential`](crate::credential::Credential),
+  /// [`CredentialV2`](crate::credential::CredentialV2) or the disclosed claims of an SD-JWT VC.
+  pub fn anonymize<'c, C, I>(&self, credentials: I) -> Result<AnonymizedDataset>
+  where
+    C: Serialize + 'c,
+    I: IntoIterator<Item = &'c C>,
+  {
+    if self.k == 0 {
+      return Err(AnonymizationError::InvalidK);
+    }
+
+    let now = Timestamp::now_utc();
+    let mut groups: Vec<AggregatedRecord> = Vec::new();
+    // Maps the serialization of each group's record to its index in `groups`.
+    let mut group_indices: HashMap<String, usize> = HashMap::new();
+    for credential in credentials {
+      let fields = self.generalize_at(credential, now)?;
+      let key = serde_json::to_string(&fields).map_err(AnonymizationError::Serialization)?;
+      match group_indices.get(&key) {
+        Some(&index) => groups[index].count += 1,
+        None => {
+          group_indices.insert(key, groups.len());
+          groups.push(AggregatedRecord { fields, count: 1 });
+        }
+      }
+    }
+
+    let (records, suppressed): (Vec<_>, Vec<_>) = groups.into_iter().partition(|group| group.count >= self.k);
+    Ok(AnonymizedDataset {
+      records,
+      suppressed: suppressed.iter().map(|group| group.count).sum(),
+    })
+  }
+
+  fn generalize_at<C: Serialize>(&self, credential: &C, now: Timestamp) -> Result<Object> {
+    let credential = serde_json::to_value(credential).map_err(AnonymizationError::Serialization)?;
+    let mut record = Object::new();
+    for rule in &self.rules {
+      if !rule.claim.starts_with('/') {
+        return Err(AnonymizationError::InvalidPointer(rule.claim.clone()));
+      }
+      let value = match credential.pointer(&rule.claim).filter(|value| !value.is_null()) {
+        Some(value) => rule
+          .generalization
+          .apply(value, now)
+          .map_err(|reason| AnonymizationError::Generalization {
+            claim: rule.claim.clone(),
+            reason,
+          })?,
+        None => Value::Null,
+      };
+      record.insert(rule.field.clone(), value);
+    }
+
+    Ok(record)
+  }
+}
+
+/// Formats `number` without a fractional part when it is integral.
+fn format_number(number: f64) -> String {
+  if number.fract() == 0.0 && number.abs() < i64::MAX as f64 {
+    (number as i64).to_string()
+  } else {
+    number.to_string()
+  }
+}
+
+/// Returns the year, month and day of a `YYYY-MM-DD` date or an RFC 3339 timestamp.
+///
+/// The date of a timestamp is the one in its own offset, i.e. it is not converted to UTC.
+fn parse_date(value: &Value) -> std::result::Result<(i64, u32, u32), String> {
+  let invalid = || format!("{value} is not a date");
+  let string = value.as_str().ok_or_else(invalid)?;
+  let parsed = if string.len() == 10 {
+    Timestamp::parse(&format!("{string}T00:00:00Z"))
+  } else {
+    Timestamp::parse(string)
+  };
+  parsed.map_err(|_| invalid())?;
+
+  // Both formats start with the `YYYY-MM-DD` full date.
+  let date = string.get(..10).ok_or_else(invalid)?;
+  let year = date[..4].parse().map_err(|_| invalid())?;
+  let month = date[5..7].parse().map_err(|_| invalid())?;
+  let day = date[8..10].parse().map_err(|_| invalid())?;
+
+  Ok((year, month, day))
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn generalizations() {
+    let now = Timestamp::parse("2025-06-15T00:00:00Z").unwrap();
+    let apply = |generalization: Generalization, value: Value| generalization.apply(&value, now);
+
+    assert_eq!(
+      apply(Generalization::Range { width: 5.0 }, json!(42)),
+      Ok(json!("[40, 45)"))
+    );
+    assert_eq!(
+      apply(Generalization::Range { width: 0.5 }, json!(1.7)),
+      Ok(json!("[1.5, 2)"))
+    );
+    assert_eq!(
+      apply(Generalization::Truncate { keep: 3 }, json!("SW1A 1AA")),
+      Ok(json!("SW1*"))
+    );
+    assert_eq!(
+      apply(
+        Generalization::Date {
+          precision: DatePrecision::Month
+        },
+        json!("1990-01-02T10:00:00Z")
+      ),
+      Ok(json!("1990-01"))
+    );
+    // The date is the local one, not the UTC one.
+    assert_eq!(
+      apply(
+        Generalization::Date {
+          precision: DatePrecision::Year
+        },
+        json!("1990-01-01T00:30:00+02:00")
+      ),
+      Ok(json!("1990"))
+    );
+    assert_eq!(
+      apply(Generalization::AgeRange { width: 10 }, json!("1995-06-16")),
+      Ok(json!("[20, 30)"))
+    );
+    assert_eq!(
+      apply(Generalization::AgeRange { width: 10 }, json!("1995-06-15")),
+      Ok(json!("[30, 40)"))
+    );
+
+    let category = Generalization::Category {
+      mapping: BTreeMap::from([("Lyon".to_owned(), json!("ARA")), ("Grenoble".to_owned(), json!("ARA"))]),
+      default: Some(json!("other")),
+    };
+    assert_eq!(apply(category.clone(), json!("Lyon")), Ok(json!("ARA")));
+    assert_eq!(apply(category, json!("Paris")), Ok(json!("other")));
+
+    assert!(apply(Generalization::Range { width: 10.0 }, json!("42")).is_err());
+    assert!(apply(Generalization::Range { width: f64::INFINITY }, json!(42)).is_err());
+    assert!(apply(Generalization::AgeRange { width: 10 }, json!("2030-01-01")).is_err());
+  }
+
+  #[test]
+  fn raw_claims_are_not_exported() {
+    let policy = AnonymizationPolicy::new(1)
+      .rule(AnonymizationRule::new(
+        "/credentialSubject/birthDate",
+        "birthYear",
+        Generalization::Date {
+          precision: DatePrecision::Year,
+        },
+      ))
+      .rule(AnonymizationRule::new("/type", "type", Generalization::Keep));
+
+    let credential = json!({
+      "type": ["VerifiableCredential", "PidCredential"],
+      "credentialSubject": { "name": "Alice", "birthDate": "1990-05-01" }
+    });
+    let dataset = policy
+      .anonymize([&credential, &json!({ "type": "VerifiableCredential" })])
+      .unwrap();
+
+    assert_eq!(dataset.suppressed, 0);
+    assert_eq!(
+      dataset.records[0].fields,
+      json!({ "birthYear": "1990", "type": ["VerifiableCredential", "PidCredential"] })
+        .as_object()
+        .unwrap()
+        .clone()
+    );
+    assert_eq!(dataset.records[1].fields["birthYear"], Value::Null);
+    assert!(matches!(
+      AnonymizationPolicy::new(0).anonymize([&credential]),
+      Err(AnonymizationError::InvalidK)
+    ));
+  }
+}
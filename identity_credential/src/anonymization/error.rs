@@ -0,0 +1,25 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// An error caused by a failure to anonymize credentials.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum AnonymizationError {
+  /// Caused by a rule whose claim is not a valid JSON pointer.
+  #[error("invalid JSON pointer `{0}`")]
+  InvalidPointer(String),
+  /// Caused by a claim that cannot be generalized as configured, e.g. a range generalization of a string.
+  #[error("claim `{claim}` cannot be generalized: {reason}")]
+  Generalization {
+    /// The pointer of the claim.
+    claim: String,
+    /// A description of the problem.
+    reason: String,
+  },
+  /// Caused by a credential that cannot be serialized to JSON.
+  #[error("credential cannot be serialized")]
+  Serialization(#[source] serde_json::Error),
+  /// Caused by a policy whose `k` is zero.
+  #[error("k must be at least 1")]
+  InvalidK,
+}
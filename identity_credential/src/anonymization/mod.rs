@@ -0,0 +1,17 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Anonymization: turning verified credentials into k-anonymous aggregated records, so that verifiers can feed
+//! analytics pipelines without raw personal data leaving the validation boundary.
+//!
+//! An [`AnonymizationPolicy`] is a list of [`AnonymizationRule`]s, each one exporting the claim found at a JSON pointer
+//! of a credential after applying a [`Generalization`] to it - e.g. turning a birth date into an age range. Identical
+//! records are then aggregated, and the ones shared by less than `k` credentials are suppressed. Policies can be
+//! (de)serialized, so that they can be kept in configuration files.
+
+#[allow(clippy::module_inception)]
+mod anonymization;
+mod error;
+
+pub use self::anonymization::*;
+pub use error::*;
@@ -0,0 +1,462 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Number;
+use serde_json::Value;
+
+use crate::credential::Subject;
+
+use super::ClaimsMappingError;
+
+type Result<T, E = ClaimsMappingError> = std::result::Result<T, E>;
+
+/// The type a source value is coerced to before being written as a claim.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ClaimType {
+  /// The value is copied as is.
+  #[default]
+  Any,
+  /// A string. Numbers and booleans are converted to their textual representation.
+  String,
+  /// An integer. Integral numbers and strings containing one are accepted.
+  Integer,
+  /// A number. Numbers and strings containing one are accepted.
+  Number,
+  /// A boolean. `true`, `false` - in any case - `0` and `1` are accepted, as strings too.
+  Boolean,
+  /// A calendar date, written as `YYYY-MM-DD`. Dates and RFC 3339 timestamps are accepted.
+  Date,
+  /// An RFC 3339 timestamp. RFC 3339 timestamps and UNIX timestamps - in seconds - are accepted.
+  Timestamp,
+}
+
+impl Display for ClaimType {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let name = match self {
+      Self::Any => "any",
+      Self::String => "string",
+      Self::Integer => "integer",
+      Self::Number => "number",
+      Self::Boolean => "boolean",
+      Self::Date => "date",
+      Self::Timestamp => "timestamp",
+    };
+    f.write_str(name)
+  }
+}
+
+/// A constraint a claim must satisfy, checked after coercion.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ClaimConstraint {
+  /// The claim must be equal to one of the given values.
+  OneOf(Vec<Value>),
+  /// The claim must be a string or an array with at least the given length.
+  MinLength(usize),
+  /// The claim must be a string or an array with at most the given length.
+  MaxLength(usize),
+  /// The claim must be a number greater than or equal to the given one.
+  Minimum(f64),
+  /// The claim must be a number less than or equal to the given one.
+  Maximum(f64),
+}
+
+impl ClaimConstraint {
+  /// Returns a description of the violation if `value` doesn't satisfy this constraint.
+  fn check(&self, value: &Value) -> Option<String> {
+    let length = || match value {
+      Value::String(s) => Some(s.chars().count()),
+      Value::Array(array) => Some(array.len()),
+      _ => None,
+    };
+    match self {
+      Self::OneOf(allowed) if !allowed.contains(value) => Some(format!("{value} is not an allowed value")),
+      Self::MinLength(min) if length().map_or(true, |len| len < *min) => Some(format!("length must be at least {min}")),
+      Self::MaxLength(max) if length().map_or(true, |len| len > *max) => Some(format!("length must be at most {max}")),
+      Self::Minimum(min) if value.as_f64().map_or(true, |n| n < *min) => Some(format!("must be at least {min}")),
+      Self::Maximum(max) if value.as_f64().map_or(true, |n| n > *max) => Some(format!("must be at most {max}")),
+      _ => None,
+    }
+  }
+}
+
+/// A rule mapping a single value of the source data to a claim.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimRule {
+  /// JSON pointer to the value in the source data, e.g. `/employee/givenName`.
+  pub from: String,
+  /// JSON pointer to the claim to write, e.g. `/given_name`. Intermediate objects are created as needed.
+  pub to: String,
+  /// The type the value is coerced to.
+  #[serde(default, rename = "type")]
+  pub claim_type: ClaimType,
+  /// The value used when the source data has none.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub default: Option<Value>,
+  /// Whether the claim is simply omitted when the source data has no value - and no default is given.
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub optional: bool,
+  /// Whether the claim is to be selectively disclosable, when mapping to an SD-JWT.
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub disclosable: bool,
+  /// Constraints the claim must satisfy.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub constraints: Vec<ClaimConstraint>,
+}
+
+impl ClaimRule {
+  /// Creates a new [`ClaimRule`] copying the value at `from` to the claim at `to`, as is.
+  pub fn new(from: impl Into<String>, to: impl Into<String>) -> Self {
+    Self {
+      from: from.into(),
+      to: to.into(),
+      claim_type: ClaimType::Any,
+      default: None,
+      optional: false,
+      disclosable: false,
+      constraints: Vec::new(),
+    }
+  }
+
+  /// Sets the type the value is coerced to.
+  pub fn claim_type(mut self, claim_type: ClaimType) -> Self {
+    self.claim_type = claim_type;
+    self
+  }
+
+  /// Sets the value used when the source data has none.
+  pub fn default_value(mut self, value: impl Into<Value>) -> Self {
+    self.default = Some(value.into());
+    self
+  }
+
+  /// Omits the claim when the source data has no value.
+  pub fn optional(mut self) -> Self {
+    self.optional = true;
+    self
+  }
+
+  /// Makes the claim selectively disclosable.
+  pub fn disclosable(mut self) -> Self {
+    self.disclosable = true;
+    self
+  }
+
+  /// Adds a constraint the claim must satisfy.
+  pub fn constraint(mut self, constraint: ClaimConstraint) -> Self {
+    self.constraints.push(constraint);
+    self
+  }
+
+  /// Maps `source` according to this rule, returning [`None`] if the claim is to be omitted.
+  fn map(&self, source: &Value) -> Result<Option<Value>> {
+    check_pointer(&self.from)?;
+    let value = match source.pointer(&self.from).filter(|value| !value.is_null()) {
+      Some(value) => value.clone(),
+      None => match (&self.default, self.optional) {
+        (Some(default), _) => default.clone(),
+        (None, true) => return Ok(None),
+        (None, false) => return Err(ClaimsMappingError::MissingValue(self.from.clone())),
+      },
+    };
+
+    let value = coerce(value, self.claim_type).map_err(|found| ClaimsMappingError::Coercion {
+      pointer: self.from.clone(),
+      expected: self.claim_type,
+      found,
+    })?;
+
+    if let Some(reason) = self.constraints.iter().find_map(|constraint| constraint.check(&value)) {
+      return Err(ClaimsMappingError::ConstraintViolation {
+        target: self.to.clone(),
+        reason,
+      });
+    }
+
+    Ok(Some(value))
+  }
+}
+
+/// A declarative mapping from arbitrary source data to the claims of a credential.
+///
+/// ```
+/// # use identity_credential::claims_mapping::ClaimsMapping;
+/// # use serde_json::json;
+/// let mapping: ClaimsMapping = serde_json::from_value(json!({
+///   "rules": [
+///     { "from": "/employee/name", "to": "/name", "type": "string" },
+///     { "from": "/employee/birthDate", "to": "/birthdate", "type": "date", "disclosable": true },
+///     { "from": "/employee/grade", "to": "/employment/grade", "type": "integer", "optional": true }
+///   ]
+/// }))
+/// .unwrap();
+///
+/// let record = json!({ "employee": { "name": "Alice", "birthDate": "1990-01-01T00:00:00Z", "grade": "3" } });
+/// let claims = mapping.apply(&record).unwrap();
+/// assert_eq!(claims["birthdate"], "1990-01-01");
+/// assert_eq!(claims["employment"]["grade"], 3);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClaimsMapping {
+  /// The rules of this mapping, applied in order.
+  pub rules: Vec<ClaimRule>,
+}
+
+impl ClaimsMapping {
+  /// Creates a new, empty, [`ClaimsMapping`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a rule to this mapping.
+  pub fn rule(mut self, rule: ClaimRule) -> Self {
+    self.rules.push(rule);
+    self
+  }
+
+  /// Maps `source` into a claims object.
+  pub fn apply(&self, source: &Value) -> Result<Object> {
+    let mut claims = Object::new();
+    for rule in &self.rules {
+      check_pointer(&rule.to)?;
+      if let Some(value) = rule.map(source)? {
+        insert_at(&mut claims, &rule.to, value)?;
+      }
+    }
+
+    Ok(claims)
+  }
+
+  /// Maps `source` into a credential [`Subject`]. A top-level `id` claim is used as the subject's [`Subject::id`].
+  pub fn apply_to_subject(&self, source: &Value) -> Result<Subject> {
+    let mut properties = self.apply(source)?;
+    let id = match properties.remove("id") {
+      Some(Value::String(id)) => Some(Url::parse(id).map_err(|_| ClaimsMappingError::InvalidSubject)?),
+      None => None,
+      Some(_) => return Err(ClaimsMappingError::InvalidSubject),
+    };
+
+    Ok(Subject { id, properties })
+  }
+
+  /// Returns the JSON pointers of the claims that are to be selectively disclosable, in a format suitable for
+  /// [`SdJwtVcBuilder::make_concealable`](crate::sd_jwt_vc::SdJwtVcBuilder::make_concealable).
+  pub fn disclosable_claims(&self) -> impl Iterator<Item = &str> + '_ {
+    self
+      .rules
+      .iter()
+      .filter(|rule| rule.disclosable)
+      .map(|rule| rule.to.as_str())
+  }
+
+  /// Maps `source` into the claims of an [`SdJwtVcBuilder`](crate::sd_jwt_vc::SdJwtVcBuilder), making the claims of
+  /// disclosable rules concealable.
+  #[cfg(feature = "sd-jwt-vc")]
+  pub fn apply_to_sd_jwt_vc(&self, source: &Value) -> Result<crate::sd_jwt_vc::SdJwtVcBuilder> {
+    let claims = Value::Object(self.apply(source)?);
+    let mut builder = crate::sd_jwt_vc::SdJwtVcBuilder::new(&claims).map_err(ClaimsMappingError::SdJwtVc)?;
+    // Omitted optional claims have nothing to conceal.
+    for pointer in self
+      .disclosable_claims()
+      .filter(|pointer| claims.pointer(pointer).is_some())
+    {
+      builder = builder.make_concealable(pointer).map_err(ClaimsMappingError::SdJwtVc)?;
+    }
+
+    Ok(builder)
+  }
+}
+
+fn check_pointer(pointer: &str) -> Result<()> {
+  if pointer.is_empty() || !pointer.starts_with('/') {
+    return Err(ClaimsMappingError::InvalidPointer(pointer.to_owned()));
+  }
+  Ok(())
+}
+
+/// Writes `value` at `pointer` in `claims`, creating intermediate objects as needed.
+fn insert_at(claims: &mut Object, pointer: &str, value: Value) -> Result<()> {
+  let tokens: Vec<String> = pointer[1..]
+    .split('/')
+    .map(|token| token.replace("~1", "/").replace("~0", "~"))
+    .collect();
+  let (last, parents) = tokens.split_last().expect("split yields at least one token");
+
+  let mut current = claims;
+  for token in parents {
+    let entry = current
+      .entry(token.clone())
+      .or_insert_with(|| Value::Object(Object::new()));
+    let Value::Object(object) = entry else {
+      return Err(ClaimsMappingError::TargetConflict(pointer.to_owned()));
+    };
+    current = object;
+  }
+  if current.contains_key(last) {
+    return Err(ClaimsMappingError::TargetConflict(pointer.to_owned()));
+  }
+  current.insert(last.clone(), value);
+
+  Ok(())
+}
+
+/// Coerces `value` to `claim_type`, giving `value` back if it cannot be coerced.
+fn coerce(value: Value, claim_type: ClaimType) -> std::result::Result<Value, Value> {
+  let coerced = match (claim_type, &value) {
+    (ClaimType::Any, _) => Some(value.clone()),
+    (ClaimType::String, Value::String(_)) => Some(value.clone()),
+    (ClaimType::String, Value::Number(n)) => Some(Value::String(n.to_string())),
+    (ClaimType::String, Value::Bool(b)) => Some(Value::String(b.to_string())),
+    (ClaimType::Integer, Value::Number(n)) => as_integer(n).map(Value::from),
+    (ClaimType::Integer, Value::String(s)) => s.trim().parse::<i64>().ok().map(Value::from),
+    (ClaimType::Number, Value::Number(_)) => Some(value.clone()),
+    (ClaimType::Number, Value::String(s)) => {
+      let s = s.trim();
+      match s.parse::<i64>() {
+        Ok(n) => Some(Value::from(n)),
+        Err(_) => s.parse::<f64>().ok().and_then(Number::from_f64).map(Value::Number),
+      }
+    }
+    (ClaimType::Boolean, Value::Bool(_)) => Some(value.clone()),
+    (ClaimType::Boolean, Value::Number(n)) => match n.as_u64() {
+      Some(0) => Some(Value::Bool(false)),
+      Some(1) => Some(Value::Bool(true)),
+      _ => None,
+    },
+    (ClaimType::Boolean, Value::String(s)) => match s.trim().to_ascii_lowercase().as_str() {
+      "true" | "1" => Some(Value::Bool(true)),
+      "false" | "0" => Some(Value::Bool(false)),
+      _ => None,
+    },
+    (ClaimType::Date, Value::String(s)) => {
+      let s = s.trim();
+      let timestamp = Timestamp::parse(s).or_else(|_| Timestamp::parse(&format!("{s}T00:00:00Z")));
+      timestamp
+        .ok()
+        .map(|timestamp| Value::String(timestamp.to_rfc3339()[..10].to_owned()))
+    }
+    (ClaimType::Timestamp, Value::String(s)) => Timestamp::parse(s.trim())
+      .ok()
+      .map(|timestamp| Value::String(timestamp.to_rfc3339())),
+    (ClaimType::Timestamp, Value::Number(n)) => as_integer(n)
+      .and_then(|seconds| Timestamp::from_unix(seconds).ok())
+      .map(|timestamp| Value::String(timestamp.to_rfc3339())),
+    _ => None,
+  };
+
+  coerced.ok_or(value)
+}
+
+fn as_integer(n: &Number) -> Option<i64> {
+  n.as_i64().or_else(|| {
+    n.as_f64()
+      .filter(|f| f.fract() == 0.0 && f.abs() < i64::MAX as f64)
+      .map(|f| f as i64)
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn record() -> Value {
+    json!({
+      "id": "did:example:alice",
+      "person": {
+        "name": "Alice",
+        "age": "42",
+        "active": "TRUE",
+        "hired": 1700000000,
+        "birth": "1982-03-04",
+        "country": "CH"
+      }
+    })
+  }
+
+  #[test]
+  fn rules_coerce_and_nest_claims() {
+    let mapping = ClaimsMapping::new()
+      .rule(ClaimRule::new("/person/name", "/name").claim_type(ClaimType::String))
+      .rule(ClaimRule::new("/person/age", "/age").claim_type(ClaimType::Integer))
+      .rule(ClaimRule::new("/person/active", "/employment/active").claim_type(ClaimType::Boolean))
+      .rule(ClaimRule::new("/person/hired", "/employment/since").claim_type(ClaimType::Timestamp))
+      .rule(ClaimRule::new("/person/birth", "/birthdate").claim_type(ClaimType::Date))
+      .rule(ClaimRule::new("/person/nickname", "/nickname").optional())
+      .rule(ClaimRule::new("/person/level", "/employment/level").default_value(1));
+
+    let claims = Value::Object(mapping.apply(&record()).unwrap());
+    assert_eq!(
+      claims,
+      json!({
+        "name": "Alice",
+        "age": 42,
+        "employment": { "active": true, "since": "2023-11-14T22:13:20Z", "level": 1 },
+        "birthdate": "1982-03-04"
+      })
+    );
+  }
+
+  #[test]
+  fn missing_values_and_violations_are_reported() {
+    let missing = ClaimsMapping::new().rule(ClaimRule::new("/person/nickname", "/nickname"));
+    assert!(matches!(
+      missing.apply(&record()),
+      Err(ClaimsMappingError::MissingValue(pointer)) if pointer == "/person/nickname"
+    ));
+
+    let uncoercible = ClaimsMapping::new().rule(ClaimRule::new("/person/name", "/age").claim_type(ClaimType::Integer));
+    assert!(matches!(
+      uncoercible.apply(&record()),
+      Err(ClaimsMappingError::Coercion { .. })
+    ));
+
+    let constrained = ClaimsMapping::new().rule(
+      ClaimRule::new("/person/country", "/country").constraint(ClaimConstraint::OneOf(vec![json!("DE"), json!("IT")])),
+    );
+    assert!(matches!(
+      constrained.apply(&record()),
+      Err(ClaimsMappingError::ConstraintViolation { .. })
+    ));
+
+    let conflicting = ClaimsMapping::new()
+      .rule(ClaimRule::new("/person/name", "/name"))
+      .rule(ClaimRule::new("/person/country", "/name/country"));
+    assert!(matches!(
+      conflicting.apply(&record()),
+      Err(ClaimsMappingError::TargetConflict(_))
+    ));
+  }
+
+  #[test]
+  fn mappings_are_serde_configurable() {
+    let mapping: ClaimsMapping = serde_json::from_value(json!({
+      "rules": [
+        { "from": "/id", "to": "/id" },
+        { "from": "/person/age", "to": "/age", "type": "number", "constraints": [{ "minimum": 18 }] },
+        { "from": "/person/name", "to": "/name", "disclosable": true }
+      ]
+    }))
+    .unwrap();
+
+    let subject = mapping.apply_to_subject(&record()).unwrap();
+    assert_eq!(subject.id.unwrap().as_str(), "did:example:alice");
+    assert_eq!(subject.properties["age"], 42);
+    assert_eq!(mapping.disclosable_claims().collect::<Vec<_>>(), ["/name"]);
+
+    let roundtrip: ClaimsMapping = serde_json::from_value(serde_json::to_value(&mapping).unwrap()).unwrap();
+    assert_eq!(roundtrip, mapping);
+  }
+}
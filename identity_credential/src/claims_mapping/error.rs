@@ -0,0 +1,46 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::Value;
+
+use super::ClaimType;
+
+/// An error caused by a failure to map source data into claims.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum ClaimsMappingError {
+  /// Caused by a rule whose source or target is not a valid JSON pointer.
+  #[error("invalid JSON pointer `{0}`")]
+  InvalidPointer(String),
+  /// Caused by a required value that is missing from the source data.
+  #[error("missing required value at `{0}`")]
+  MissingValue(String),
+  /// Caused by a value that cannot be coerced to the expected type.
+  #[error("value at `{pointer}` cannot be coerced to {expected}: {found}")]
+  Coercion {
+    /// The source pointer of the value.
+    pointer: String,
+    /// The type the value was to be coerced to.
+    expected: ClaimType,
+    /// The value found in the source data.
+    found: Value,
+  },
+  /// Caused by a value that doesn't satisfy one of its rule's constraints.
+  #[error("claim `{target}` violates constraint: {reason}")]
+  ConstraintViolation {
+    /// The target pointer of the claim.
+    target: String,
+    /// A description of the violated constraint.
+    reason: String,
+  },
+  /// Caused by a claim that cannot be written, because its target is already taken by a non-object value.
+  #[error("claim `{0}` conflicts with a previously mapped claim")]
+  TargetConflict(String),
+  /// Caused by a failure to build a credential subject from the mapped claims.
+  #[error("mapped claims are not a valid credential subject")]
+  InvalidSubject,
+  /// Caused by a failure to build an SD-JWT VC from the mapped claims.
+  #[cfg(feature = "sd-jwt-vc")]
+  #[error("mapped claims cannot be turned into an SD-JWT VC")]
+  SdJwtVc(#[source] crate::sd_jwt_vc::Error),
+}
@@ -0,0 +1,16 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Claims mapping: declarative rules turning arbitrary source data - e.g. an HR record or a registry entry - into
+//! the claims of a credential.
+//!
+//! A [`ClaimsMapping`] is a list of [`ClaimRule`]s, each one copying the value found at a JSON pointer of the source
+//! to a JSON pointer of the resulting claims, after coercing it to a [`ClaimType`] and checking it against a set of
+//! [`ClaimConstraint`]s. Mappings can be (de)serialized, so that they can be kept in configuration files.
+
+#[allow(clippy::module_inception)]
+mod claims_mapping;
+mod error;
+
+pub use self::claims_mapping::*;
+pub use error::*;
@@ -17,6 +17,7 @@ use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
+use identity_core::convert::VersionedSchema;
 
 use crate::credential::CredentialBuilder;
 use crate::credential::CredentialSealed;
@@ -223,6 +224,14 @@ where
   }
 }
 
+impl<T> VersionedSchema for Credential<T>
+where
+  T: Serialize + serde::de::DeserializeOwned,
+{
+  const KIND: &'static str = "Credential";
+  const VERSION: u16 = 1;
+}
+
 impl<T> CredentialSealed for Credential<T> {}
 
 impl<T> CredentialT for Credential<T>
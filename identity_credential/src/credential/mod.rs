@@ -22,12 +22,14 @@ mod linked_domain_service;
 mod linked_verifiable_presentation_service;
 mod policy;
 mod proof;
+mod redaction;
 mod refresh;
 #[cfg(feature = "revocation-bitmap")]
 mod revocation_bitmap_status;
 mod schema;
 mod status;
 mod subject;
+mod subject_schema;
 
 use identity_core::common::Context;
 use identity_core::common::Object;
@@ -49,6 +51,9 @@ pub use self::linked_domain_service::LinkedDomainService;
 pub use self::linked_verifiable_presentation_service::LinkedVerifiablePresentationService;
 pub use self::policy::Policy;
 pub use self::proof::Proof;
+pub use self::redaction::redact;
+pub use self::redaction::Redacted;
+pub use self::redaction::Redactor;
 pub use self::refresh::RefreshService;
 #[cfg(feature = "revocation-bitmap")]
 pub use self::revocation_bitmap_status::try_index_to_u32;
@@ -57,14 +62,24 @@ pub use self::revocation_bitmap_status::RevocationBitmapStatus;
 pub use self::schema::Schema;
 pub use self::status::Status;
 pub use self::subject::Subject;
+pub use self::subject_schema::*;
 pub use credential_v2::Credential as CredentialV2;
 pub use enveloped_credential::*;
+#[cfg(feature = "derive")]
+pub use identity_credential_derive::CredentialSubjectSchema;
 
 #[cfg(feature = "validator")]
 pub(crate) use self::jwt_serialization::CredentialJwtClaims;
 #[cfg(feature = "presentation")]
 pub(crate) use self::jwt_serialization::IssuanceDateClaims;
 
+/// Items used by the code generated through `#[derive(CredentialSubjectSchema)]`.
+#[doc(hidden)]
+#[cfg(feature = "derive")]
+pub mod __private {
+  pub use identity_core::common::Value;
+}
+
 trait CredentialSealed {}
 
 /// A VerifiableCredential type. This trait is implemented for [Credential]
@@ -0,0 +1,364 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Replacement for masked values.
+const MASK: &str = "***";
+/// Keys whose values identify an entity, and are therefore truncated rather than masked.
+const IDENTIFIER_KEYS: &[&str] = &["id", "issuer", "holder", "iss", "sub", "jti"];
+/// Keys whose values don't disclose anything about the subject, and are therefore kept as they are.
+const KEPT_KEYS: &[&str] = &[
+  "@context",
+  "type",
+  "issuanceDate",
+  "expirationDate",
+  "validFrom",
+  "validUntil",
+  "iat",
+  "nbf",
+  "exp",
+];
+/// Keys whose values are credentials or presentations, and are therefore redacted following the same rules.
+const NESTED_KEYS: &[&str] = &["vc", "vp", "verifiableCredential"];
+
+/// Returns a logging-safe version of `value` - e.g. a [`Credential`](crate::credential::Credential), a presentation
+/// or JWT claims - using the default [`Redactor`].
+///
+/// See [`Redactor`] for the redaction rules.
+pub fn redact<T>(value: &T) -> Redacted
+where
+  T: Serialize + ?Sized,
+{
+  Redactor::default().redact(value)
+}
+
+/// Produces logging-safe versions of credentials, presentations and their JWT claims.
+///
+/// By default, every value is masked - preserving the structure of objects and arrays - except for:
+/// - `@context`, `type`, and the validity dates - `issuanceDate`, `expirationDate`, `validFrom`, `validUntil`, `iat`,
+///   `nbf` and `exp` - which are kept;
+/// - identifiers - `id`, `issuer`, `holder`, `iss`, `sub` and `jti` - which are truncated, keeping the DID method;
+/// - credentials and presentations embedded as JSON in `verifiableCredential`, `vc` and `vp`, which are redacted
+///   following these rules. Embedded JWTs are masked.
+///
+/// Claims about credential subjects are always masked, whatever their key, except for the subjects' `id`s.
+///
+/// Additional values can be masked - or subject claims revealed - through their
+/// [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901), e.g. `/credentialSubject/degree/type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redactor {
+  masked: Vec<String>,
+  revealed: Vec<String>,
+  visible_chars: usize,
+}
+
+impl Default for Redactor {
+  fn default() -> Self {
+    Self {
+      masked: Vec::new(),
+      revealed: Vec::new(),
+      visible_chars: 6,
+    }
+  }
+}
+
+impl Redactor {
+  /// Creates a new [`Redactor`] applying the default redaction rules.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Masks the value located at the JSON Pointer `pointer`. Masking takes precedence over revealing.
+  pub fn mask(mut self, pointer: impl Into<String>) -> Self {
+    self.masked.push(pointer.into());
+    self
+  }
+
+  /// Leaves the value located at the JSON Pointer `pointer` untouched.
+  pub fn reveal(mut self, pointer: impl Into<String>) -> Self {
+    self.revealed.push(pointer.into());
+    self
+  }
+
+  /// Sets how many characters are kept at both ends of a truncated identifier. Default: 6.
+  pub fn visible_chars(mut self, visible_chars: usize) -> Self {
+    self.visible_chars = visible_chars;
+    self
+  }
+
+  /// Returns a logging-safe version of `value`.
+  ///
+  /// Values that cannot be represented as JSON are masked altogether.
+  pub fn redact<T>(&self, value: &T) -> Redacted
+  where
+    T: Serialize + ?Sized,
+  {
+    let Ok(mut value) = serde_json::to_value(value) else {
+      return Redacted(Value::String(MASK.to_owned()));
+    };
+
+    let revealed: Vec<(&str, Value)> = self
+      .revealed
+      .iter()
+      .filter_map(|pointer| {
+        value
+          .pointer(pointer)
+          .map(|revealed| (pointer.as_str(), revealed.clone()))
+      })
+      .collect();
+
+    self.redact_value(&mut value);
+
+    for (pointer, revealed) in revealed {
+      if let Some(target) = value.pointer_mut(pointer) {
+        *target = revealed;
+      }
+    }
+    for pointer in &self.masked {
+      if let Some(target) = value.pointer_mut(pointer) {
+        *target = Value::String(MASK.to_owned());
+      }
+    }
+
+    Redacted(value)
+  }
+
+  fn redact_value(&self, value: &mut Value) {
+    match value {
+      Value::Object(object) => {
+        for (key, value) in object.iter_mut() {
+          match key.as_str() {
+            "credentialSubject" => self.redact_subjects(value),
+            key if NESTED_KEYS.contains(&key) => self.redact_value(value),
+            key if IDENTIFIER_KEYS.contains(&key) => self.redact_identifier(value),
+            key if KEPT_KEYS.contains(&key) => (),
+            _ => mask_leaves(value),
+          }
+        }
+      }
+      Value::Array(array) => array.iter_mut().for_each(|value| self.redact_value(value)),
+      _ => mask_leaves(value),
+    }
+  }
+
+  fn redact_subjects(&self, value: &mut Value) {
+    match value {
+      Value::Array(subjects) => subjects.iter_mut().for_each(|subject| self.redact_subjects(subject)),
+      Value::Object(subject) => {
+        for (key, value) in subject.iter_mut() {
+          if key == "id" {
+            self.redact_identifier(value);
+          } else {
+            mask_leaves(value);
+          }
+        }
+      }
+      _ => mask_leaves(value),
+    }
+  }
+
+  fn redact_identifier(&self, value: &mut Value) {
+    match value {
+      Value::String(identifier) => *identifier = self.truncate(identifier),
+      _ => self.redact_value(value),
+    }
+  }
+
+  /// Truncates `identifier`, keeping the scheme and method of DIDs, e.g. `did:iota:0x8036…9b2ec6`.
+  fn truncate(&self, identifier: &str) -> String {
+    let prefix_len: usize = identifier
+      .strip_prefix("did:")
+      .and_then(|did| did.find(':'))
+      .map(|method_len| "did:".len() + method_len + 1)
+      .unwrap_or_default();
+    let (prefix, rest) = identifier.split_at(prefix_len);
+
+    let chars: Vec<char> = rest.chars().collect();
+    if chars.len() <= 2 * self.visible_chars {
+      return format!("{prefix}{MASK}");
+    }
+    let head: String = chars[..self.visible_chars].iter().collect();
+    let tail: String = chars[chars.len() - self.visible_chars..].iter().collect();
+    format!("{prefix}{head}…{tail}")
+  }
+}
+
+/// Replaces every non-container value found in `value` with [`MASK`].
+fn mask_leaves(value: &mut Value) {
+  match value {
+    Value::Object(object) => object.values_mut().for_each(mask_leaves),
+    Value::Array(array) => array.iter_mut().for_each(mask_leaves),
+    _ => *value = Value::String(MASK.to_owned()),
+  }
+}
+
+/// A logging-safe, redacted version of a value, see [`Redactor`].
+///
+/// Both its [`Display`] and [`Debug`] implementations output compact JSON, so that it can be used as a field
+/// value in structured logging, e.g. `tracing::info!(credential = %redact(&credential))`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Redacted(Value);
+
+impl Redacted {
+  /// Returns a reference to the redacted JSON value.
+  pub fn as_value(&self) -> &Value {
+    &self.0
+  }
+
+  /// Consumes this [`Redacted`], returning the redacted JSON value.
+  pub fn into_value(self) -> Value {
+    self.0
+  }
+}
+
+impl Display for Redacted {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Display::fmt(&self.0, f)
+  }
+}
+
+impl Debug for Redacted {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Display::fmt(&self.0, f)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::common::OneOrMany;
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::Evidence;
+
+  const DID: &str = "did:iota:0x8036235b6b5939435a45d68bcea7890eef399209a669c8c263fac7f5089b2ec6";
+
+  fn credential() -> Credential<Object> {
+    Credential::from_json_value(json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "id": "https://example.edu/credentials/3732",
+      "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+      "issuer": { "id": DID, "name": "Example University" },
+      "issuanceDate": "2010-01-01T00:00:00Z",
+      "credentialSubject": {
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+        "name": "Alice",
+        "degree": { "type": "BachelorDegree", "grades": [28, 30] }
+      }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn subject_claims_are_masked_and_identifiers_truncated() {
+    let redacted = redact(&credential()).into_value();
+
+    assert_eq!(
+      redacted["credentialSubject"],
+      json!({
+        "id": "did:example:ebfeb1…12ec21",
+        "name": MASK,
+        "degree": { "type": MASK, "grades": [MASK, MASK] }
+      })
+    );
+    assert_eq!(redacted["issuer"]["id"], "did:iota:0x8036…9b2ec6");
+    assert_eq!(redacted["issuer"]["name"], MASK);
+    assert_eq!(redacted["id"], "https:…s/3732");
+    assert_eq!(redacted["issuanceDate"], "2010-01-01T00:00:00Z");
+    assert_eq!(
+      redacted["type"],
+      json!(["VerifiableCredential", "UniversityDegreeCredential"])
+    );
+    assert!(!redact(&credential()).to_string().contains("Alice"));
+  }
+
+  #[test]
+  fn json_pointers_override_defaults() {
+    let redacted = Redactor::new()
+      .reveal("/credentialSubject/degree/type")
+      .reveal("/issuer/name")
+      .mask("/issuanceDate")
+      .visible_chars(4)
+      .redact(&credential())
+      .into_value();
+
+    assert_eq!(redacted["credentialSubject"]["degree"]["type"], "BachelorDegree");
+    assert_eq!(redacted["credentialSubject"]["name"], MASK);
+    assert_eq!(redacted["issuer"]["name"], "Example University");
+    assert_eq!(redacted["issuanceDate"], MASK);
+    assert_eq!(redacted["issuer"]["id"], "did:iota:0x80…2ec6");
+  }
+
+  #[test]
+  fn embedded_credentials_are_redacted() {
+    let presentation = json!({
+      "holder": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+      "verifiableCredential": ["eyJhbGciOiJFZERTQSJ9.eyJzdWIiOiJBbGljZSJ9.c2ln", credential()]
+    });
+    let redacted = redact(&presentation).into_value();
+
+    assert_eq!(redacted["holder"], "did:example:ebfeb1…12ec21");
+    assert_eq!(redacted["verifiableCredential"][0], MASK);
+    assert_eq!(redacted["verifiableCredential"][1]["credentialSubject"]["name"], MASK);
+  }
+
+  #[test]
+  fn claims_outside_the_allowlist_are_masked() {
+    let mut credential = credential();
+    credential.evidence = OneOrMany::One(
+      Evidence::from_json_value(json!({
+        "id": "https://example.edu/evidence/f2aeec97",
+        "type": "DocumentVerification",
+        "documentPresence": "Physical",
+        "verifier": "Alice's notary"
+      }))
+      .unwrap(),
+    );
+    credential
+      .properties
+      .insert("nationality".to_owned(), "Atlantis".into());
+    let redacted = redact(&credential).into_value();
+
+    assert_eq!(
+      redacted["evidence"],
+      json!({
+        "id": MASK,
+        "type": MASK,
+        "documentPresence": MASK,
+        "verifier": MASK
+      })
+    );
+    assert_eq!(redacted["nationality"], MASK);
+    assert!(!redact(&credential).to_string().contains("Alice"));
+  }
+
+  #[test]
+  fn credentials_nested_in_jwt_claims_are_redacted() {
+    let claims = json!({
+      "iss": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+      "nonce": "343s$FSFDa-",
+      "vp": {
+        "type": "VerifiablePresentation",
+        "verifiableCredential": [{ "vc": credential() }]
+      }
+    });
+    let redacted = redact(&claims).into_value();
+
+    assert_eq!(redacted["iss"], "did:example:ebfeb1…12ec21");
+    assert_eq!(redacted["nonce"], MASK);
+    assert_eq!(redacted["vp"]["type"], "VerifiablePresentation");
+    let nested = &redacted["vp"]["verifiableCredential"][0]["vc"];
+    assert_eq!(nested["credentialSubject"]["name"], MASK);
+    assert_eq!(nested["issuer"]["name"], MASK);
+    assert_eq!(nested["issuer"]["id"], "did:iota:0x8036…9b2ec6");
+  }
+}
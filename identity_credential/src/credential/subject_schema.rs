@@ -0,0 +1,329 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Credential subjects defined as Rust types.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The JSON Schema dialect used by [`ClaimSchema`] implementations.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// A type that can describe the JSON values it serializes to through a JSON Schema.
+///
+/// This trait is implemented for the most common primitive and collection types and can be derived - together
+/// with [`CredentialSubjectSchema`] - for structs through `#[derive(CredentialSubjectSchema)]`.
+pub trait ClaimSchema {
+  /// Whether a claim of this type must be present in its enclosing object.
+  const REQUIRED: bool = true;
+
+  /// Returns the JSON Schema describing this type.
+  fn json_schema() -> Value;
+}
+
+/// A credential subject defined as a Rust type.
+///
+/// Implementations are usually derived through `#[derive(CredentialSubjectSchema)]` - available with
+/// the `derive` feature - which also generates a `CLAIM_<FIELD>` constant for every claim of the subject.
+///
+/// # Example
+/// ```ignore
+/// use identity_credential::credential::CredentialSubjectSchema;
+///
+/// /// A university degree.
+/// #[derive(serde::Serialize, serde::Deserialize, CredentialSubjectSchema)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Degree {
+///   /// The name of the degree.
+///   degree_name: String,
+///   graduation_year: Option<u16>,
+/// }
+///
+/// assert_eq!(Degree::CLAIM_DEGREE_NAME, "/degreeName");
+/// assert_eq!(Degree::CLAIM_PATHS, &["/degreeName", "/graduationYear"]);
+/// ```
+pub trait CredentialSubjectSchema: ClaimSchema + Serialize + DeserializeOwned {
+  /// JSON pointers - relative to the credential subject - of all the claims defined by this type.
+  const CLAIM_PATHS: &'static [&'static str];
+
+  /// Returns the JSON Schema of this type as a standalone document, i.e. including its `$schema` dialect.
+  fn subject_json_schema() -> Value {
+    let mut schema = Self::json_schema();
+    if let Value::Object(ref mut object) = schema {
+      object.insert("$schema".to_owned(), JSON_SCHEMA_DIALECT.into());
+    }
+
+    schema
+  }
+
+  /// Converts this value into a credential [`Subject`].
+  ///
+  /// A top-level `id` claim, if present, is used as the subject's [`Subject::id`].
+  fn to_subject(&self) -> Result<Subject> {
+    let Value::Object(mut properties) = self.to_json_value().map_err(|_| Error::InvalidSubject)? else {
+      return Err(Error::InvalidSubject);
+    };
+    let id = match properties.remove("id") {
+      Some(Value::String(id)) => Some(Url::parse(id).map_err(|_| Error::InvalidSubject)?),
+      Some(Value::Null) | None => None,
+      Some(_) => return Err(Error::InvalidSubject),
+    };
+
+    Ok(Subject { id, properties })
+  }
+
+  /// Parses and validates this type from a credential [`Subject`].
+  fn from_subject(subject: &Subject) -> Result<Self> {
+    let value = subject.to_json_value().map_err(|_| Error::InvalidSubject)?;
+    Self::from_json_value(value).map_err(|_| Error::InvalidSubject)
+  }
+}
+
+/// Incrementally builds the JSON Schema of an object.
+///
+/// Mostly used by the code generated through `#[derive(CredentialSubjectSchema)]`.
+#[derive(Debug, Default, Clone)]
+pub struct ObjectSchemaBuilder {
+  title: Option<String>,
+  description: Option<String>,
+  properties: Object,
+  required: Vec<Value>,
+}
+
+impl ObjectSchemaBuilder {
+  /// Creates a new [`ObjectSchemaBuilder`] for an object without any property.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the `title` of the schema.
+  #[must_use]
+  pub fn title(mut self, title: impl Into<String>) -> Self {
+    self.title = Some(title.into());
+    self
+  }
+
+  /// Sets the `description` of the schema.
+  #[must_use]
+  pub fn description(mut self, description: impl Into<String>) -> Self {
+    self.description = Some(description.into());
+    self
+  }
+
+  /// Adds a property of type `T`, marking it as required if `T` is.
+  #[must_use]
+  pub fn property<T: ClaimSchema + ?Sized>(self, name: &str, description: Option<&str>) -> Self {
+    self.property_with_requirement::<T>(name, description, T::REQUIRED)
+  }
+
+  /// Adds a property of type `T` that can be omitted regardless of its type,
+  /// e.g. because of a `#[serde(default)]` annotation.
+  #[must_use]
+  pub fn optional_property<T: ClaimSchema + ?Sized>(self, name: &str, description: Option<&str>) -> Self {
+    self.property_with_requirement::<T>(name, description, false)
+  }
+
+  fn property_with_requirement<T: ClaimSchema + ?Sized>(
+    mut self,
+    name: &str,
+    description: Option<&str>,
+    required: bool,
+  ) -> Self {
+    let mut schema = T::json_schema();
+    if let (Some(description), Value::Object(object)) = (description, &mut schema) {
+      object.insert("description".to_owned(), description.into());
+    }
+    self.properties.insert(name.to_owned(), schema);
+    if required {
+      self.required.push(name.into());
+    }
+
+    self
+  }
+
+  /// Returns the resulting JSON Schema.
+  pub fn build(self) -> Value {
+    let mut schema = Object::new();
+    if let Some(title) = self.title {
+      schema.insert("title".to_owned(), title.into());
+    }
+    if let Some(description) = self.description {
+      schema.insert("description".to_owned(), description.into());
+    }
+    schema.insert("type".to_owned(), "object".into());
+    schema.insert("properties".to_owned(), Value::Object(self.properties));
+    if !self.required.is_empty() {
+      schema.insert("required".to_owned(), Value::Array(self.required));
+    }
+
+    Value::Object(schema)
+  }
+}
+
+macro_rules! impl_claim_schema {
+  ($schema:tt => $($ty:ty),+ $(,)?) => {
+    $(
+      impl ClaimSchema for $ty {
+        fn json_schema() -> Value {
+          json!($schema)
+        }
+      }
+    )+
+  };
+}
+
+impl_claim_schema!({ "type": "string" } => String, str, char);
+impl_claim_schema!({ "type": "boolean" } => bool);
+impl_claim_schema!({ "type": "integer" } => i8, i16, i32, i64, i128, isize);
+impl_claim_schema!({ "type": "integer", "minimum": 0 } => u8, u16, u32, u64, u128, usize);
+impl_claim_schema!({ "type": "number" } => f32, f64);
+impl_claim_schema!({ "type": "string", "format": "uri" } => Url);
+impl_claim_schema!({ "type": "string", "format": "date-time" } => Timestamp);
+impl_claim_schema!({ "type": "object" } => Object);
+impl_claim_schema!({} => Value);
+
+impl<T: ClaimSchema + ?Sized> ClaimSchema for &T {
+  const REQUIRED: bool = T::REQUIRED;
+
+  fn json_schema() -> Value {
+    T::json_schema()
+  }
+}
+
+impl<T: ClaimSchema + ?Sized> ClaimSchema for Box<T> {
+  const REQUIRED: bool = T::REQUIRED;
+
+  fn json_schema() -> Value {
+    T::json_schema()
+  }
+}
+
+/// `None` serializes to `null` unless skipped, hence both are accepted.
+impl<T: ClaimSchema> ClaimSchema for Option<T> {
+  const REQUIRED: bool = false;
+
+  fn json_schema() -> Value {
+    json!({ "anyOf": [T::json_schema(), { "type": "null" }] })
+  }
+}
+
+impl<T: ClaimSchema> ClaimSchema for [T] {
+  fn json_schema() -> Value {
+    json!({ "type": "array", "items": T::json_schema() })
+  }
+}
+
+impl<T: ClaimSchema> ClaimSchema for Vec<T> {
+  fn json_schema() -> Value {
+    <[T]>::json_schema()
+  }
+}
+
+impl<T: ClaimSchema> ClaimSchema for BTreeSet<T> {
+  fn json_schema() -> Value {
+    json!({ "type": "array", "items": T::json_schema(), "uniqueItems": true })
+  }
+}
+
+impl<T: ClaimSchema, S> ClaimSchema for HashSet<T, S> {
+  fn json_schema() -> Value {
+    BTreeSet::<T>::json_schema()
+  }
+}
+
+impl<T: ClaimSchema> ClaimSchema for OneOrMany<T> {
+  fn json_schema() -> Value {
+    json!({ "anyOf": [T::json_schema(), Vec::<T>::json_schema()] })
+  }
+}
+
+impl<T: ClaimSchema> ClaimSchema for BTreeMap<String, T> {
+  fn json_schema() -> Value {
+    json!({ "type": "object", "additionalProperties": T::json_schema() })
+  }
+}
+
+impl<T: ClaimSchema, S> ClaimSchema for HashMap<String, T, S> {
+  fn json_schema() -> Value {
+    BTreeMap::<String, T>::json_schema()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde::Deserialize;
+
+  use super::*;
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Degree {
+    id: Url,
+    name: String,
+    year: Option<u16>,
+  }
+
+  impl ClaimSchema for Degree {
+    fn json_schema() -> Value {
+      ObjectSchemaBuilder::new()
+        .title("Degree")
+        .property::<Url>("id", None)
+        .property::<String>("name", Some("The name of the degree."))
+        .property::<Option<u16>>("year", None)
+        .build()
+    }
+  }
+
+  impl CredentialSubjectSchema for Degree {
+    const CLAIM_PATHS: &'static [&'static str] = &["/id", "/name", "/year"];
+  }
+
+  #[test]
+  fn object_schema() {
+    let expected = json!({
+      "$schema": JSON_SCHEMA_DIALECT,
+      "title": "Degree",
+      "type": "object",
+      "properties": {
+        "id": { "type": "string", "format": "uri" },
+        "name": { "type": "string", "description": "The name of the degree." },
+        "year": { "anyOf": [{ "type": "integer", "minimum": 0 }, { "type": "null" }] },
+      },
+      "required": ["id", "name"],
+    });
+
+    assert_eq!(Degree::subject_json_schema(), expected);
+  }
+
+  #[test]
+  fn subject_roundtrip() {
+    let degree = Degree {
+      id: Url::parse("did:example:123").unwrap(),
+      name: "Bachelor of Science".to_owned(),
+      year: None,
+    };
+    let subject = degree.to_subject().unwrap();
+    assert_eq!(subject.id.as_ref(), Some(&degree.id));
+    assert!(!subject.properties.contains_key("id"));
+    assert_eq!(Degree::from_subject(&subject).unwrap(), degree);
+
+    let invalid = Subject::with_properties(Object::from_iter([("name".to_owned(), 42.into())]));
+    assert!(matches!(Degree::from_subject(&invalid), Err(Error::InvalidSubject)));
+  }
+}
@@ -0,0 +1,306 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use identity_verification::jws::Decoder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::credential::CredentialJwtClaims;
+use crate::credential::Jwt;
+use crate::delegation::DelegationValidationError;
+use crate::delegation::DELEGATION_CREDENTIAL_TYPE;
+
+/// An ordered list of delegation credentials linking a root issuer to the issuer of a credential.
+///
+/// The first delegation credential is issued by the root issuer, every following one by the delegate of its
+/// predecessor. The delegate of the last delegation credential is the issuer of the credential the chain is presented
+/// with.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DelegationChain {
+  delegations: Vec<Jwt>,
+}
+
+impl DelegationChain {
+  /// Creates a new [`DelegationChain`] from delegation credentials ordered from the root issuer onwards.
+  pub fn new(delegations: Vec<Jwt>) -> Self {
+    Self { delegations }
+  }
+
+  /// Builds a [`DelegationChain`] from the root issuer to `issuer`, out of unordered `candidates`.
+  ///
+  /// The chain ends as soon as the issuer of a delegation credential is one of the `trusted_roots`. Candidates that
+  /// cannot be decoded or are not delegation credentials are ignored.
+  ///
+  /// # Warning
+  /// This only orders the candidates according to their claims, their signatures are **not** verified.
+  /// Use [`JwtDelegationValidator`](crate::delegation::JwtDelegationValidator) to validate the resulting chain.
+  ///
+  /// # Errors
+  /// [`DelegationValidationError::UnresolvableChain`] if no chain from any of the `trusted_roots` to `issuer` can be
+  /// built.
+  pub fn build<I>(issuer: &CoreDID, candidates: I, trusted_roots: &[CoreDID]) -> Result<Self, DelegationValidationError>
+  where
+    I: IntoIterator<Item = Jwt>,
+  {
+    let candidates: Vec<(Jwt, DelegationClaims)> = candidates
+      .into_iter()
+      .filter_map(|jwt| {
+        let claims = decode_credential(&jwt)
+          .ok()
+          .and_then(|credential| DelegationClaims::from_credential(&credential, 0).ok())?;
+        Some((jwt, claims))
+      })
+      .collect();
+
+    let path: Vec<usize> = find_path(issuer, &candidates, trusted_roots)
+      .ok_or_else(|| DelegationValidationError::UnresolvableChain(issuer.clone()))?;
+
+    let delegations: Vec<Jwt> = path.into_iter().map(|index| candidates[index].0.clone()).collect();
+    Ok(Self { delegations })
+  }
+
+  /// Returns the delegation credentials, ordered from the root issuer onwards.
+  pub fn delegations(&self) -> &[Jwt] {
+    &self.delegations
+  }
+
+  /// Consumes the chain, returning its delegation credentials.
+  pub fn into_delegations(self) -> Vec<Jwt> {
+    self.delegations
+  }
+
+  /// Returns the number of delegation credentials in the chain.
+  pub fn len(&self) -> usize {
+    self.delegations.len()
+  }
+
+  /// Returns whether the chain contains no delegation credential.
+  pub fn is_empty(&self) -> bool {
+    self.delegations.is_empty()
+  }
+}
+
+impl From<Vec<Jwt>> for DelegationChain {
+  fn from(delegations: Vec<Jwt>) -> Self {
+    Self::new(delegations)
+  }
+}
+
+/// Breadth-first search for the shortest path of delegations from a trusted root to `delegate`, returning the indices
+/// of its candidates ordered from the root onwards.
+///
+/// Every DID is visited at most once, so that the search is linear in the number of candidates.
+fn find_path(
+  delegate: &CoreDID,
+  candidates: &[(Jwt, DelegationClaims)],
+  trusted_roots: &[CoreDID],
+) -> Option<Vec<usize>> {
+  let mut delegated_to: HashMap<&CoreDID, Vec<usize>> = HashMap::new();
+  for (index, (_, claims)) in candidates.iter().enumerate() {
+    delegated_to.entry(&claims.delegate).or_default().push(index);
+  }
+
+  // The candidate through which each visited DID was reached, on its way to `delegate`.
+  let mut reached_through: HashMap<&CoreDID, Option<usize>> = HashMap::from([(delegate, None)]);
+  let mut queue: VecDeque<&CoreDID> = VecDeque::from([delegate]);
+  while let Some(did) = queue.pop_front() {
+    for &index in delegated_to.get(did).into_iter().flatten() {
+      let issuer = &candidates[index].1.issuer;
+      if trusted_roots.contains(issuer) {
+        let mut path = vec![index];
+        let mut next = reached_through[did];
+        while let Some(index) = next {
+          path.push(index);
+          next = reached_through[&candidates[index].1.delegate];
+        }
+        return Some(path);
+      }
+      if !reached_through.contains_key(issuer) {
+        reached_through.insert(issuer, Some(index));
+        queue.push_back(issuer);
+      }
+    }
+  }
+
+  None
+}
+
+/// Decodes the credential of a JWT **without** verifying its signature.
+fn decode_credential(jwt: &Jwt) -> Result<Credential<Object>, DelegationValidationError> {
+  let decoded = Decoder::new()
+    .decode_compact_serialization(jwt.as_str().as_bytes(), None)
+    .map_err(|err| DelegationValidationError::Decoding(err.into()))?;
+
+  CredentialJwtClaims::<'_, Object>::from_json_slice(decoded.claims())
+    .map_err(|err| DelegationValidationError::Decoding(err.into()))?
+    .try_into_credential()
+    .map_err(|err| DelegationValidationError::Decoding(err.into()))
+}
+
+/// The claims of a delegation credential.
+#[derive(Debug, Clone)]
+pub(crate) struct DelegationClaims {
+  pub(crate) issuer: CoreDID,
+  pub(crate) delegate: CoreDID,
+  pub(crate) delegated_types: Vec<String>,
+  pub(crate) path_length: u32,
+  pub(crate) issuance_date: Timestamp,
+  pub(crate) expiration_date: Option<Timestamp>,
+}
+
+impl DelegationClaims {
+  /// Extracts the delegation claims out of `credential`, located at position `index` of a chain.
+  pub(crate) fn from_credential<T>(
+    credential: &Credential<T>,
+    index: usize,
+  ) -> Result<Self, DelegationValidationError> {
+    let malformed = |message: &'static str| DelegationValidationError::MalformedDelegation { index, message };
+
+    if !credential.types.iter().any(|type_| type_ == DELEGATION_CREDENTIAL_TYPE) {
+      return Err(malformed("missing IssuerDelegationCredential type"));
+    }
+    let issuer: CoreDID = CoreDID::parse(credential.issuer.url().as_str()).map_err(|_| malformed("invalid issuer"))?;
+
+    let subject = match credential.credential_subject.as_slice() {
+      [subject] => subject,
+      _ => return Err(malformed("exactly one credential subject is required")),
+    };
+    let delegate: CoreDID = subject
+      .id
+      .as_ref()
+      .and_then(|id| CoreDID::parse(id.as_str()).ok())
+      .ok_or_else(|| malformed("the subject id must be the DID of the delegate"))?;
+
+    let delegated_types: Vec<String> = match subject.properties.get("delegatedTypes") {
+      Some(Value::Array(values)) => values
+        .iter()
+        .map(|value| value.as_str().map(ToOwned::to_owned))
+        .collect::<Option<_>>()
+        .ok_or_else(|| malformed("delegatedTypes must be an array of strings"))?,
+      _ => return Err(malformed("delegatedTypes must be an array of strings")),
+    };
+    let path_length: u32 = match subject.properties.get("pathLength") {
+      None => 0,
+      Some(value) => value
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| malformed("pathLength must be a non-negative integer"))?,
+    };
+
+    Ok(Self {
+      issuer,
+      delegate,
+      delegated_types,
+      path_length,
+      issuance_date: credential.issuance_date,
+      expiration_date: credential.expiration_date,
+    })
+  }
+
+  /// Returns whether `timestamp` falls within the validity period of the delegation.
+  pub(crate) fn is_valid_at(&self, timestamp: Timestamp) -> bool {
+    self.issuance_date <= timestamp && !matches!(self.expiration_date, Some(expiration) if timestamp > expiration)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::ToJson;
+  use identity_verification::jws::JwsAlgorithm;
+  use identity_verification::jws::JwsHeader;
+  use identity_verification::jwu::encode_b64;
+
+  use super::*;
+  use crate::delegation::DelegationCredentialBuilder;
+
+  fn did(value: &str) -> CoreDID {
+    value.parse().unwrap()
+  }
+
+  /// Returns an unsigned JWT of a delegation from `issuer` to `delegate`, sufficient for ordering.
+  fn delegation(issuer: &str, delegate: &str) -> Jwt {
+    let credential = DelegationCredentialBuilder::new()
+      .issuer(did(issuer))
+      .delegate(did(delegate))
+      .delegated_type("UniversityDegreeCredential")
+      .expiration_date(Timestamp::from_unix(Timestamp::now_utc().to_unix() + 3600).unwrap())
+      .build()
+      .unwrap();
+    let mut header = JwsHeader::new();
+    header.set_alg(JwsAlgorithm::EdDSA);
+    Jwt::new(format!(
+      "{}.{}.{}",
+      encode_b64(header.to_json().unwrap()),
+      encode_b64(credential.serialize_jwt(None).unwrap()),
+      encode_b64("signature"),
+    ))
+  }
+
+  #[test]
+  fn build_orders_candidates_from_the_root() {
+    let root_to_a = delegation("did:example:root", "did:example:a");
+    let a_to_b = delegation("did:example:a", "did:example:b");
+    let unrelated = delegation("did:example:other", "did:example:c");
+
+    let chain = DelegationChain::build(
+      &did("did:example:b"),
+      [unrelated, a_to_b.clone(), root_to_a.clone()],
+      &[did("did:example:root")],
+    )
+    .unwrap();
+    assert_eq!(chain.delegations(), [root_to_a, a_to_b]);
+  }
+
+  #[test]
+  fn build_skips_dead_ends_and_cycles() {
+    let root_to_a = delegation("did:example:root", "did:example:a");
+    let x_to_a = delegation("did:example:x", "did:example:a");
+    let a_to_x = delegation("did:example:a", "did:example:x");
+
+    let chain = DelegationChain::build(
+      &did("did:example:a"),
+      [x_to_a, a_to_x, root_to_a.clone()],
+      &[did("did:example:root")],
+    )
+    .unwrap();
+    assert_eq!(chain.delegations(), [root_to_a]);
+  }
+
+  #[test]
+  fn build_fails_without_trusted_root() {
+    let err = DelegationChain::build(
+      &did("did:example:a"),
+      [delegation("did:example:root", "did:example:a")],
+      &[did("did:example:other")],
+    )
+    .unwrap_err();
+    assert!(matches!(err, DelegationValidationError::UnresolvableChain(_)));
+  }
+
+  #[test]
+  fn build_is_not_slowed_down_by_unrelated_candidates() {
+    // Densely delegating DIDs without any route to the trusted root would make an exhaustive search explode.
+    let dids: Vec<String> = (0..40).map(|n| format!("did:example:{n}")).collect();
+    let mut candidates: Vec<Jwt> = dids
+      .iter()
+      .flat_map(|issuer| dids.iter().map(move |delegate| delegation(issuer, delegate)))
+      .collect();
+    assert!(DelegationChain::build(&did("did:example:0"), candidates.clone(), &[did("did:example:root")]).is_err());
+
+    let root_to_last = delegation("did:example:root", "did:example:39");
+    candidates.push(root_to_last.clone());
+    let chain = DelegationChain::build(&did("did:example:0"), candidates, &[did("did:example:root")]).unwrap();
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.delegations()[0], root_to_last);
+  }
+}
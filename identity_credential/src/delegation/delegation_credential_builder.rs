@@ -0,0 +1,182 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DID;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+use crate::credential::Subject;
+use crate::error::Result;
+use crate::Error;
+
+/// The `type` of a credential delegating issuance authority to its subject.
+pub const DELEGATION_CREDENTIAL_TYPE: &str = "IssuerDelegationCredential";
+
+/// Builder for a credential through which an issuer delegates the issuance of credentials of given types to another
+/// issuer, the delegate.
+///
+/// The resulting credential has the shape:
+/// ```json
+/// {
+///   "type": ["VerifiableCredential", "IssuerDelegationCredential"],
+///   "issuer": "<delegator>",
+///   "credentialSubject": {
+///     "id": "<delegate>",
+///     "delegatedTypes": ["UniversityDegreeCredential"],
+///     "pathLength": 0
+///   },
+///   ...
+/// }
+/// ```
+///
+/// The builder expects `issuer`, `delegate`, `expirationDate` and at least one delegated type to be set.
+/// `pathLength` - the number of further delegations the delegate is allowed to make - defaults to `0`.
+/// Setting `issuanceDate` is optional. If unset the current time will be used.
+#[derive(Debug, Default)]
+pub struct DelegationCredentialBuilder {
+  pub(crate) issuer: Option<Url>,
+  pub(crate) delegate: Option<Url>,
+  pub(crate) delegated_types: Vec<String>,
+  pub(crate) path_length: u32,
+  pub(crate) issuance_date: Option<Timestamp>,
+  pub(crate) expiration_date: Option<Timestamp>,
+}
+
+impl DelegationCredentialBuilder {
+  /// Creates a new `DelegationCredentialBuilder`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the value of the `issuer`, i.e. the delegator.
+  #[must_use]
+  pub fn issuer(mut self, did: CoreDID) -> Self {
+    self.issuer = Some(did.into_url().into());
+    self
+  }
+
+  /// Sets the delegate as `credentialSubject.id`.
+  #[must_use]
+  pub fn delegate(mut self, did: CoreDID) -> Self {
+    self.delegate = Some(did.into_url().into());
+    self
+  }
+
+  /// Adds a credential type the delegate is allowed to issue.
+  #[must_use]
+  pub fn delegated_type(mut self, value: impl Into<String>) -> Self {
+    self.delegated_types.push(value.into());
+    self
+  }
+
+  /// Adds credential types the delegate is allowed to issue.
+  #[must_use]
+  pub fn delegated_types<I: IntoIterator<Item = String>>(mut self, values: I) -> Self {
+    self.delegated_types.extend(values);
+    self
+  }
+
+  /// Sets the number of further delegations the delegate is allowed to make.
+  #[must_use]
+  pub fn path_length(mut self, value: u32) -> Self {
+    self.path_length = value;
+    self
+  }
+
+  /// Sets the value of the `Credential` `issuanceDate`.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `expirationDate`.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.expiration_date = Some(value);
+    self
+  }
+
+  /// Returns a new `Credential` based on the `DelegationCredentialBuilder` configuration.
+  pub fn build(self) -> Result<Credential<Object>> {
+    let issuer: Url = self.issuer.ok_or(Error::MissingIssuer)?;
+    let delegate: Url = self.delegate.ok_or(Error::MissingSubject)?;
+    if self.delegated_types.is_empty() {
+      return Err(Error::InvalidSubject);
+    }
+
+    let mut properties: Object = Object::new();
+    properties.insert("delegatedTypes".into(), self.delegated_types.into());
+    properties.insert("pathLength".into(), self.path_length.into());
+
+    Ok(Credential {
+      context: OneOrMany::One(Credential::<Object>::base_context().clone()),
+      id: None,
+      types: OneOrMany::Many(vec![
+        Credential::<Object>::base_type().to_owned(),
+        DELEGATION_CREDENTIAL_TYPE.to_owned(),
+      ]),
+      credential_subject: OneOrMany::One(Subject::with_id_and_properties(delegate, properties)),
+      issuer: Issuer::Url(issuer),
+      issuance_date: self.issuance_date.unwrap_or_else(Timestamp::now_utc),
+      expiration_date: Some(self.expiration_date.ok_or(Error::MissingExpirationDate)?),
+      credential_status: None,
+      credential_schema: Vec::new().into(),
+      refresh_service: Vec::new().into(),
+      terms_of_use: Vec::new().into(),
+      evidence: Vec::new().into(),
+      non_transferable: None,
+      properties: Object::new(),
+      proof: None,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Timestamp;
+  use identity_did::CoreDID;
+
+  use super::*;
+
+  fn did(value: &str) -> CoreDID {
+    value.parse().unwrap()
+  }
+
+  #[test]
+  fn test_builder_with_all_fields_set_succeeds() {
+    let credential = DelegationCredentialBuilder::new()
+      .issuer(did("did:example:root"))
+      .delegate(did("did:example:delegate"))
+      .delegated_type("UniversityDegreeCredential")
+      .path_length(1)
+      .expiration_date(Timestamp::now_utc())
+      .build()
+      .unwrap();
+
+    assert!(credential.types.iter().any(|type_| type_ == DELEGATION_CREDENTIAL_TYPE));
+    let subject = credential.credential_subject.get(0).unwrap();
+    assert_eq!(subject.id.as_ref().unwrap().as_str(), "did:example:delegate");
+    assert_eq!(subject.properties["delegatedTypes"][0], "UniversityDegreeCredential");
+    assert_eq!(subject.properties["pathLength"], 1);
+  }
+
+  #[test]
+  fn test_builder_requires_expiration_date_and_delegated_types() {
+    let builder = || {
+      DelegationCredentialBuilder::new()
+        .issuer(did("did:example:root"))
+        .delegate(did("did:example:delegate"))
+    };
+
+    let err = builder().delegated_type("A").build().unwrap_err();
+    assert!(matches!(err, Error::MissingExpirationDate));
+    let err = builder().expiration_date(Timestamp::now_utc()).build().unwrap_err();
+    assert!(matches!(err, Error::InvalidSubject));
+  }
+}
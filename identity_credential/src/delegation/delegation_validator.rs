@@ -0,0 +1,322 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+
+use crate::credential::Credential;
+use crate::credential::Jwt;
+use crate::delegation::delegation_chain::DelegationClaims;
+use crate::delegation::DelegationChain;
+use crate::delegation::DelegationValidationError;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtCredentialValidatorUtils;
+
+/// A validator for credentials issued on behalf of a root issuer through a [`DelegationChain`].
+pub struct JwtDelegationValidator<V: JwsVerifier> {
+  validator: JwtCredentialValidator<V>,
+}
+
+impl<V: JwsVerifier> JwtDelegationValidator<V> {
+  /// Create a new [`JwtDelegationValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self {
+      validator: JwtCredentialValidator::with_signature_verifier(signature_verifier),
+    }
+  }
+
+  /// Validates `credential`, issued by the last delegate of `chain`, on behalf of one of the `trusted_roots`.
+  ///
+  /// * `credential`: the credential issued by the last delegate.
+  /// * `chain`: the delegation credentials linking one of the `trusted_roots` to the issuer of `credential`.
+  /// * `trusted_roots`: the issuers that are trusted to delegate issuance.
+  /// * `issuers`: DID Documents of the issuers of `credential` and of all delegation credentials in `chain`.
+  /// * `options`: validation options applied to `credential` and to every delegation credential.
+  ///
+  /// Every delegation credential is validated with `options` - in particular it must not be expired - and must:
+  /// - have an expiration date,
+  /// - be issued by the delegate of the previous delegation credential, or by a trusted root if it is the first one,
+  /// - allow, through its `pathLength`, the delegation credentials that follow it in the chain,
+  /// - have been issued within the validity period of the previous delegation credential,
+  /// - delegate every type of `credential` - except for `VerifiableCredential` -,
+  /// - have been valid when `credential` was issued.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  pub fn validate<DOC, T>(
+    &self,
+    credential: &Jwt,
+    chain: &DelegationChain,
+    trusted_roots: &[CoreDID],
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, DelegationValidationError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let delegations: Vec<DelegationClaims> = self.validate_chain(chain, trusted_roots, issuers, options, fail_fast)?;
+    // Safety: `validate_chain` errors on empty chains.
+    let last_delegate: &CoreDID = &delegations.last().expect("chain is not empty").delegate;
+
+    let issuer: &DOC = issuer_document(credential, chain.len(), last_delegate, issuers)?;
+    let decoded: DecodedJwtCredential<T> = self
+      .validator
+      .validate(credential, issuer, options, fail_fast)
+      .map_err(DelegationValidationError::InvalidCredential)?;
+
+    for (index, delegation) in delegations.iter().enumerate() {
+      if let Some(type_) = decoded
+        .credential
+        .types
+        .iter()
+        .find(|type_| *type_ != Credential::<T>::base_type() && !delegation.delegated_types.contains(type_))
+      {
+        return Err(DelegationValidationError::TypeNotDelegated {
+          index,
+          type_: type_.clone(),
+        });
+      }
+      if !delegation.is_valid_at(decoded.credential.issuance_date) {
+        return Err(DelegationValidationError::IssuedOutsideDelegation(index));
+      }
+    }
+
+    Ok(decoded)
+  }
+
+  /// Validates the delegation credentials of `chain`, returning their claims.
+  fn validate_chain<DOC: AsRef<CoreDocument>>(
+    &self,
+    chain: &DelegationChain,
+    trusted_roots: &[CoreDID],
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<Vec<DelegationClaims>, DelegationValidationError> {
+    if chain.is_empty() {
+      return Err(DelegationValidationError::EmptyChain);
+    }
+
+    let mut delegations: Vec<DelegationClaims> = Vec::with_capacity(chain.len());
+    for (index, jwt) in chain.delegations().iter().enumerate() {
+      let issuer: &DOC = match delegations.last() {
+        Some(previous) => issuer_document(jwt, index, &previous.delegate, issuers)?,
+        None => {
+          let root: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(jwt)
+            .map_err(|err| DelegationValidationError::Decoding(err.into()))?;
+          if !trusted_roots.contains(&root) {
+            return Err(DelegationValidationError::UntrustedRoot(root));
+          }
+          issuer_document(jwt, index, &root, issuers)?
+        }
+      };
+
+      let decoded: DecodedJwtCredential = self
+        .validator
+        .validate(jwt, issuer, options, fail_fast)
+        .map_err(|source| DelegationValidationError::InvalidDelegation { index, source })?;
+      let delegation: DelegationClaims = DelegationClaims::from_credential(&decoded.credential, index)?;
+
+      if delegation.expiration_date.is_none() {
+        return Err(DelegationValidationError::MissingExpirationDate(index));
+      }
+      let further_delegations: usize = chain.len() - index - 1;
+      if (delegation.path_length as usize) < further_delegations {
+        return Err(DelegationValidationError::PathLengthExceeded {
+          index,
+          path_length: delegation.path_length,
+        });
+      }
+      if let Some(previous) = delegations.last() {
+        if !previous.is_valid_at(delegation.issuance_date) {
+          return Err(DelegationValidationError::IssuedOutsideDelegation(index - 1));
+        }
+      }
+
+      delegations.push(delegation);
+    }
+
+    Ok(delegations)
+  }
+}
+
+/// Checks that `jwt`, at position `index` of a chain, is issued by `expected` and returns the DID Document of
+/// `expected` out of `issuers`.
+fn issuer_document<'a, DOC: AsRef<CoreDocument>>(
+  jwt: &Jwt,
+  index: usize,
+  expected: &CoreDID,
+  issuers: &'a [DOC],
+) -> Result<&'a DOC, DelegationValidationError> {
+  let found: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(jwt)
+    .map_err(|err| DelegationValidationError::Decoding(err.into()))?;
+  if found != *expected {
+    return Err(DelegationValidationError::BrokenChain {
+      index,
+      expected: expected.clone(),
+      found,
+    });
+  }
+
+  issuers
+    .iter()
+    .find(|document| document.as_ref().id() == expected)
+    .ok_or_else(|| DelegationValidationError::MissingIssuerDocument(expected.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Duration;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_did::DID;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use once_cell::sync::Lazy;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::delegation::DelegationCredentialBuilder;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+
+  static JWT_DELEGATION_VALIDATOR_ED25519: Lazy<JwtDelegationValidator<EdDSAJwsVerifier>> =
+    Lazy::new(|| JwtDelegationValidator::with_signature_verifier(EdDSAJwsVerifier::default()));
+
+  const DEGREE: &str = "UniversityDegreeCredential";
+
+  struct Party {
+    document: CoreDocument,
+    secret_key: SecretKey,
+    fragment: String,
+  }
+
+  impl Party {
+    fn new() -> Self {
+      let (document, secret_key, fragment) = generate_jwk_document_with_keys();
+      Self {
+        document,
+        secret_key,
+        fragment,
+      }
+    }
+
+    fn did(&self) -> CoreDID {
+      self.document.id().clone()
+    }
+
+    fn delegate_to(&self, delegate: &Party, path_length: u32) -> Jwt {
+      let credential: Credential = DelegationCredentialBuilder::new()
+        .issuer(self.did())
+        .delegate(delegate.did())
+        .delegated_type(DEGREE)
+        .path_length(path_length)
+        .issuance_date(Timestamp::now_utc().checked_sub(Duration::days(1)).unwrap())
+        .expiration_date(Timestamp::now_utc().checked_add(Duration::days(365)).unwrap())
+        .build()
+        .unwrap();
+      self.sign(&credential)
+    }
+
+    fn issue(&self, type_: &str) -> Jwt {
+      let credential: Credential = CredentialBuilder::default()
+        .issuer(Url::parse(self.did().as_str()).unwrap())
+        .type_(type_)
+        .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+        .build()
+        .unwrap();
+      self.sign(&credential)
+    }
+
+    fn sign(&self, credential: &Credential) -> Jwt {
+      sign_credential_jwt(credential, &self.document, &self.fragment, &self.secret_key)
+    }
+  }
+
+  fn validate(
+    credential: &Jwt,
+    chain: &DelegationChain,
+    root: &Party,
+    issuers: &[&Party],
+  ) -> Result<DecodedJwtCredential<Object>, DelegationValidationError> {
+    let documents: Vec<&CoreDocument> = issuers.iter().map(|issuer| &issuer.document).collect();
+    JWT_DELEGATION_VALIDATOR_ED25519.validate(
+      credential,
+      chain,
+      &[root.did()],
+      &documents,
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+  }
+
+  #[test]
+  fn test_valid_delegation_chain() {
+    let (root, delegate, sub_delegate) = (Party::new(), Party::new(), Party::new());
+    let chain = DelegationChain::build(
+      &sub_delegate.did(),
+      [delegate.delegate_to(&sub_delegate, 0), root.delegate_to(&delegate, 1)],
+      &[root.did()],
+    )
+    .unwrap();
+
+    let credential = sub_delegate.issue(DEGREE);
+    let issuers = [&root, &delegate, &sub_delegate];
+    assert!(validate(&credential, &chain, &root, &issuers).is_ok());
+  }
+
+  #[test]
+  fn test_path_length_is_enforced() {
+    let (root, delegate, sub_delegate) = (Party::new(), Party::new(), Party::new());
+    let chain = DelegationChain::new(vec![
+      root.delegate_to(&delegate, 0),
+      delegate.delegate_to(&sub_delegate, 0),
+    ]);
+
+    let credential = sub_delegate.issue(DEGREE);
+    let issuers = [&root, &delegate, &sub_delegate];
+    let err = validate(&credential, &chain, &root, &issuers).unwrap_err();
+    assert!(matches!(
+      err,
+      DelegationValidationError::PathLengthExceeded { index: 0, .. }
+    ));
+  }
+
+  #[test]
+  fn test_credential_types_must_be_delegated() {
+    let (root, delegate) = (Party::new(), Party::new());
+    let chain = DelegationChain::new(vec![root.delegate_to(&delegate, 0)]);
+
+    let credential = delegate.issue("DriversLicenseCredential");
+    let err = validate(&credential, &chain, &root, &[&root, &delegate]).unwrap_err();
+    assert!(matches!(
+      err,
+      DelegationValidationError::TypeNotDelegated { index: 0, .. }
+    ));
+  }
+
+  #[test]
+  fn test_chain_must_link_trusted_root_to_issuer() {
+    let (root, delegate, other) = (Party::new(), Party::new(), Party::new());
+    let chain = DelegationChain::new(vec![root.delegate_to(&delegate, 0)]);
+    let issuers = [&root, &delegate, &other];
+
+    let err = validate(&other.issue(DEGREE), &chain, &root, &issuers).unwrap_err();
+    assert!(matches!(err, DelegationValidationError::BrokenChain { index: 1, .. }));
+
+    let err = validate(&delegate.issue(DEGREE), &chain, &other, &issuers).unwrap_err();
+    assert!(matches!(err, DelegationValidationError::UntrustedRoot(_)));
+
+    let err = validate(&delegate.issue(DEGREE), &DelegationChain::default(), &root, &issuers).unwrap_err();
+    assert!(matches!(err, DelegationValidationError::EmptyChain));
+  }
+}
@@ -0,0 +1,80 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::CoreDID;
+
+use crate::validator::CompoundCredentialValidationError;
+
+/// An error caused by a failure to build or validate a delegation chain.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum DelegationValidationError {
+  /// Caused when the credential issued by the last delegate cannot be successfully validated.
+  #[error("invalid credential")]
+  InvalidCredential(#[source] CompoundCredentialValidationError),
+  /// Caused when a delegation credential of the chain cannot be successfully validated.
+  #[error("invalid delegation credential at position {index}")]
+  InvalidDelegation {
+    /// Position of the delegation credential in the chain.
+    index: usize,
+    /// Validation errors of the delegation credential.
+    #[source]
+    source: CompoundCredentialValidationError,
+  },
+  /// Caused by a credential that is not a well-formed delegation credential.
+  #[error("malformed delegation credential at position {index}: {message}")]
+  MalformedDelegation {
+    /// Position of the delegation credential in the chain.
+    index: usize,
+    /// A message providing more context.
+    message: &'static str,
+  },
+  /// Caused by a credential or a delegation credential that could not be decoded.
+  #[error("could not decode credential")]
+  Decoding(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused by a chain without any delegation credential.
+  #[error("the delegation chain is empty")]
+  EmptyChain,
+  /// Caused by a chain whose first issuer is not one of the trusted root issuers.
+  #[error("the delegation chain is rooted in untrusted issuer {0}")]
+  UntrustedRoot(CoreDID),
+  /// Caused by a delegation credential that is not issued by the delegate of the previous one, or a credential not
+  /// issued by the last delegate of the chain.
+  #[error("the delegation chain is broken at position {index}: expected issuer {expected}, found {found}")]
+  BrokenChain {
+    /// Position in the chain - the length of the chain denotes the credential issued by the last delegate.
+    index: usize,
+    /// The expected issuer.
+    expected: CoreDID,
+    /// The actual issuer.
+    found: CoreDID,
+  },
+  /// Caused by a chain that could not be built out of the candidate delegation credentials.
+  #[error("no delegation chain could be built up to issuer {0}")]
+  UnresolvableChain(CoreDID),
+  /// Caused by a delegation credential without an expiration date.
+  #[error("delegation credential at position {0} has no expiration date")]
+  MissingExpirationDate(usize),
+  /// Caused by a credential of a type that has not been delegated.
+  #[error("credential type {type_} has not been delegated by the delegation credential at position {index}")]
+  TypeNotDelegated {
+    /// Position of the delegation credential in the chain.
+    index: usize,
+    /// The credential type that is not covered by the delegation.
+    type_: String,
+  },
+  /// Caused by a chain that is longer than allowed by one of its delegation credentials.
+  #[error("delegation credential at position {index} allows at most {path_length} further delegations")]
+  PathLengthExceeded {
+    /// Position of the delegation credential in the chain.
+    index: usize,
+    /// The number of further delegations allowed by the delegation credential.
+    path_length: u32,
+  },
+  /// Caused by a credential issued outside of the validity period of a delegation credential.
+  #[error("credential issued outside of the validity period of the delegation credential at position {0}")]
+  IssuedOutsideDelegation(usize),
+  /// Caused by a missing DID Document for an issuer of the chain.
+  #[error("missing DID document of issuer {0}")]
+  MissingIssuerDocument(CoreDID),
+}
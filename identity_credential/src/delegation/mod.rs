@@ -0,0 +1,18 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Delegated issuance: a root issuer authorizes a sub-issuer to issue credentials of certain types on its behalf.
+//!
+//! A root issuer grants the authority by issuing an [`IssuerDelegationCredential`](DELEGATION_CREDENTIAL_TYPE)
+//! to the sub-issuer. Credentials issued by the sub-issuer are only accepted when presented together with a
+//! [`DelegationChain`] linking it back to a trusted root.
+
+mod delegation_chain;
+mod delegation_credential_builder;
+mod delegation_validator;
+mod error;
+
+pub use self::delegation_chain::*;
+pub use self::delegation_credential_builder::*;
+pub use self::delegation_validator::*;
+pub use error::*;
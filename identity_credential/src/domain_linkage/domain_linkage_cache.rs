@@ -0,0 +1,291 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+
+use crate::domain_linkage::DomainLinkageConfiguration;
+use crate::validator::JwtCredentialValidationOptions;
+
+/// A cache of validated [`DomainLinkageConfiguration`]s, keyed by origin.
+///
+/// Every DID linked to an origin is cached until the expiration date of the Domain Linkage Credential that
+/// established the linkage, optionally capped by a maximum age. Expired linkages are ignored by lookups and removed
+/// by [`DomainLinkageCache::purge_expired`].
+///
+/// Linkages only hold for the exact configuration and validation options they were validated with: caching a
+/// linkage established by another configuration - or under other options - for the same origin replaces all of the
+/// origin's linkages.
+///
+/// The cache is filled by
+/// [`JwtDomainLinkageValidator::validate_linkage_cached`](super::JwtDomainLinkageValidator::validate_linkage_cached)
+/// and [`JwtDomainLinkageValidator::validate_origins`](super::JwtDomainLinkageValidator::validate_origins), sparing
+/// verifiers that check the same origins over and over - e.g. browser extensions - repeated signature
+/// verifications.
+#[derive(Debug, Default)]
+pub struct DomainLinkageCache {
+  max_age: Option<Duration>,
+  entries: RwLock<HashMap<String, CacheEntry>>,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+  /// The serialization of the configuration and validation options the linkages were validated with.
+  fingerprint: String,
+  configuration: DomainLinkageConfiguration,
+  linked_dids: BTreeMap<CoreDID, Timestamp>,
+}
+
+impl DomainLinkageCache {
+  /// Creates a new, empty [`DomainLinkageCache`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Caps the time linkages are cached for to `max_age`, regardless of the validity of their credentials.
+  ///
+  /// Linkages established by credentials without an expiration date are only cached if a maximum age is set.
+  pub fn with_max_age(mut self, max_age: Duration) -> Self {
+    self.max_age = Some(max_age);
+    self
+  }
+
+  /// Records that `did` is linked to the origin of `domain` through `configuration`, validated with
+  /// `validation_options`, until `valid_until` or the maximum age of the cache, whichever comes first.
+  ///
+  /// Nothing is cached if neither bound is known.
+  pub fn insert(
+    &self,
+    domain: &Url,
+    configuration: DomainLinkageConfiguration,
+    validation_options: &JwtCredentialValidationOptions,
+    did: CoreDID,
+    valid_until: Option<Timestamp>,
+  ) {
+    let max_age_bound: Option<Timestamp> = self
+      .max_age
+      .and_then(|max_age| Timestamp::now_utc().checked_add(max_age));
+    let Some(expires_at) = [valid_until, max_age_bound].into_iter().flatten().min() else {
+      return;
+    };
+    let Some(fingerprint) = fingerprint(&configuration, validation_options) else {
+      return;
+    };
+
+    let mut entries = self.entries.write().expect("domain linkage cache lock poisoned");
+    let entry = entries.entry(origin_key(domain)).or_insert_with(|| CacheEntry {
+      fingerprint: fingerprint.clone(),
+      configuration: configuration.clone(),
+      linked_dids: BTreeMap::new(),
+    });
+    if entry.fingerprint != fingerprint {
+      *entry = CacheEntry {
+        fingerprint,
+        configuration,
+        linked_dids: BTreeMap::new(),
+      };
+    }
+    entry.linked_dids.insert(did, expires_at);
+  }
+
+  /// Returns whether `did` is linked to the origin of `domain` according to a cached, unexpired linkage.
+  ///
+  /// The linkage is the one established by the configuration the origin was last validated with.
+  pub fn is_linked(&self, domain: &Url, did: &CoreDID) -> bool {
+    let now: Timestamp = Timestamp::now_utc();
+    let entries = self.entries.read().expect("domain linkage cache lock poisoned");
+    entries
+      .get(&origin_key(domain))
+      .and_then(|entry| entry.linked_dids.get(did))
+      .is_some_and(|expires_at| *expires_at > now)
+  }
+
+  /// Returns whether `did` is linked to the origin of `domain` according to a cached, unexpired linkage validated
+  /// from `configuration` with `validation_options`.
+  pub fn is_validated(
+    &self,
+    domain: &Url,
+    configuration: &DomainLinkageConfiguration,
+    validation_options: &JwtCredentialValidationOptions,
+    did: &CoreDID,
+  ) -> bool {
+    let Some(fingerprint) = fingerprint(configuration, validation_options) else {
+      return false;
+    };
+    let now: Timestamp = Timestamp::now_utc();
+    let entries = self.entries.read().expect("domain linkage cache lock poisoned");
+    entries
+      .get(&origin_key(domain))
+      .filter(|entry| entry.fingerprint == fingerprint)
+      .and_then(|entry| entry.linked_dids.get(did))
+      .is_some_and(|expires_at| *expires_at > now)
+  }
+
+  /// Returns the DIDs linked to the origin of `domain` according to cached, unexpired linkages.
+  pub fn linked_dids(&self, domain: &Url) -> Vec<CoreDID> {
+    let now: Timestamp = Timestamp::now_utc();
+    let entries = self.entries.read().expect("domain linkage cache lock poisoned");
+    entries
+      .get(&origin_key(domain))
+      .map(|entry| {
+        entry
+          .linked_dids
+          .iter()
+          .filter(|(_, expires_at)| **expires_at > now)
+          .map(|(did, _)| did.clone())
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// Returns the cached [`DomainLinkageConfiguration`] of the origin of `domain`, if it links at least one DID that
+  /// has not expired.
+  pub fn configuration(&self, domain: &Url) -> Option<DomainLinkageConfiguration> {
+    let now: Timestamp = Timestamp::now_utc();
+    let entries = self.entries.read().expect("domain linkage cache lock poisoned");
+    entries
+      .get(&origin_key(domain))
+      .filter(|entry| entry.linked_dids.values().any(|expires_at| *expires_at > now))
+      .map(|entry| entry.configuration.clone())
+  }
+
+  /// Removes all linkages of the origin of `domain`.
+  pub fn remove(&self, domain: &Url) {
+    self
+      .entries
+      .write()
+      .expect("domain linkage cache lock poisoned")
+      .remove(&origin_key(domain));
+  }
+
+  /// Removes all expired linkages, along with the origins left without any.
+  pub fn purge_expired(&self) {
+    let now: Timestamp = Timestamp::now_utc();
+    let mut entries = self.entries.write().expect("domain linkage cache lock poisoned");
+    entries.retain(|_, entry| {
+      entry.linked_dids.retain(|_, expires_at| *expires_at > now);
+      !entry.linked_dids.is_empty()
+    });
+  }
+
+  /// Removes all cached linkages.
+  pub fn clear(&self) {
+    self
+      .entries
+      .write()
+      .expect("domain linkage cache lock poisoned")
+      .clear();
+  }
+
+  /// Returns the number of cached origins, including those whose linkages have all expired.
+  pub fn len(&self) -> usize {
+    self.entries.read().expect("domain linkage cache lock poisoned").len()
+  }
+
+  /// Returns whether the cache is empty.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+fn origin_key(domain: &Url) -> String {
+  domain.origin().ascii_serialization()
+}
+
+/// Serializes `configuration` and `validation_options`, so that they can be compared with the ones of a cached entry.
+fn fingerprint(
+  configuration: &DomainLinkageConfiguration,
+  validation_options: &JwtCredentialValidationOptions,
+) -> Option<String> {
+  serde_json::to_string(&(configuration, validation_options)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::credential::Jwt;
+
+  use super::*;
+
+  fn did(id: &str) -> CoreDID {
+    CoreDID::parse(format!("did:example:{id}")).unwrap()
+  }
+
+  fn configuration() -> DomainLinkageConfiguration {
+    DomainLinkageConfiguration::new(vec![])
+  }
+
+  fn options() -> JwtCredentialValidationOptions {
+    JwtCredentialValidationOptions::default()
+  }
+
+  #[test]
+  fn linkages_are_cached_per_origin() {
+    let cache = DomainLinkageCache::new();
+    let domain = Url::parse("https://foo.example.com").unwrap();
+    let valid_until = Timestamp::now_utc().checked_add(Duration::days(1));
+
+    cache.insert(&domain, configuration(), &options(), did("a"), valid_until);
+
+    assert!(cache.is_linked(&Url::parse("https://foo.example.com/some/path").unwrap(), &did("a")));
+    assert!(!cache.is_linked(&domain, &did("b")));
+    assert!(!cache.is_linked(&Url::parse("https://bar.example.com").unwrap(), &did("a")));
+    assert_eq!(cache.linked_dids(&domain), vec![did("a")]);
+    assert!(cache.configuration(&domain).is_some());
+
+    cache.remove(&domain);
+    assert!(cache.is_empty());
+  }
+
+  #[test]
+  fn linkages_are_bound_to_configuration_and_options() {
+    let cache = DomainLinkageCache::new();
+    let domain = Url::parse("https://foo.example.com").unwrap();
+    let valid_until = Timestamp::now_utc().checked_add(Duration::days(1));
+    let other_configuration = DomainLinkageConfiguration::new(vec![Jwt::from("header.payload.signature".to_owned())]);
+    let other_options = options().earliest_expiry_date(Timestamp::now_utc());
+
+    cache.insert(&domain, configuration(), &options(), did("a"), valid_until);
+    assert!(cache.is_validated(&domain, &configuration(), &options(), &did("a")));
+    assert!(!cache.is_validated(&domain, &other_configuration, &options(), &did("a")));
+    assert!(!cache.is_validated(&domain, &configuration(), &other_options, &did("a")));
+
+    // Linkages of another configuration replace the ones of the origin.
+    cache.insert(&domain, other_configuration.clone(), &options(), did("b"), valid_until);
+    assert!(!cache.is_linked(&domain, &did("a")));
+    assert!(cache.is_validated(&domain, &other_configuration, &options(), &did("b")));
+  }
+
+  #[test]
+  fn expired_linkages_are_ignored() {
+    let cache = DomainLinkageCache::new();
+    let domain = Url::parse("https://foo.example.com").unwrap();
+    let expired = Timestamp::now_utc().checked_sub(Duration::seconds(1));
+
+    cache.insert(&domain, configuration(), &options(), did("a"), expired);
+    assert!(!cache.is_linked(&domain, &did("a")));
+    assert!(cache.configuration(&domain).is_none());
+
+    cache.purge_expired();
+    assert!(cache.is_empty());
+  }
+
+  #[test]
+  fn max_age_bounds_expiry() {
+    let domain = Url::parse("https://foo.example.com").unwrap();
+
+    // Without any bound, nothing is cached.
+    let cache = DomainLinkageCache::new();
+    cache.insert(&domain, configuration(), &options(), did("a"), None);
+    assert!(cache.is_empty());
+
+    let cache = DomainLinkageCache::new().with_max_age(Duration::minutes(5));
+    cache.insert(&domain, configuration(), &options(), did("a"), None);
+    assert!(cache.is_linked(&domain, &did("a")));
+  }
+}
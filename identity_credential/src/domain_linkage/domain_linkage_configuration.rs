@@ -168,6 +168,20 @@ mod __fetch_configuration {
         DomainLinkageConfiguration::from_json_slice(&json).map_err(|err| DomainLinkageError(Box::new(err)))?;
       Ok(domain_linkage_configuration)
     }
+
+    /// Fetches the DID Configuration resources of many domains concurrently, as with
+    /// [`DomainLinkageConfiguration::fetch_configuration`].
+    ///
+    /// Returns the outcome for every domain, in the same order as `domains`.
+    pub async fn fetch_configurations(
+      domains: impl IntoIterator<Item = Url>,
+    ) -> Vec<(Url, Result<DomainLinkageConfiguration>)> {
+      futures::future::join_all(domains.into_iter().map(|domain| async move {
+        let configuration = Self::fetch_configuration(domain.clone()).await;
+        (domain, configuration)
+      }))
+      .await
+    }
   }
 }
 
@@ -11,6 +11,7 @@ use crate::validator::JwtCredentialValidationOptions;
 use crate::validator::JwtCredentialValidator;
 use crate::validator::JwtCredentialValidatorUtils;
 use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_did::CoreDID;
 use identity_document::document::CoreDocument;
@@ -18,6 +19,7 @@ use identity_verification::jws::JwsVerifier;
 
 use crate::validator::DecodedJwtCredential;
 
+use super::DomainLinkageCache;
 use super::DomainLinkageValidationErrorList;
 use super::DomainLinkageValidationResult;
 use crate::utils::url_only_includes_origin;
@@ -164,6 +166,19 @@ impl<V: JwsVerifier> JwtDomainLinkageValidator<V> {
     domain: &Url,
     validation_options: &JwtCredentialValidationOptions,
   ) -> DomainLinkageValidationResult {
+    self
+      .validate_credential_expiry(issuer, credential, domain, validation_options)
+      .map(|_| ())
+  }
+
+  /// Validates a Domain Linkage Credential, returning its expiration date.
+  fn validate_credential_expiry<DOC: AsRef<CoreDocument>>(
+    &self,
+    issuer: &DOC,
+    credential: &Jwt,
+    domain: &Url,
+    validation_options: &JwtCredentialValidationOptions,
+  ) -> Result<Option<Timestamp>, DomainLinkageValidationError> {
     let decoded_credential: DecodedJwtCredential = self
       .validator
       .validate(credential, issuer, validation_options, FailFast::AllErrors)
@@ -261,7 +276,129 @@ impl<V: JwsVerifier> JwtDomainLinkageValidator<V> {
         });
       }
     }
-    Ok(())
+    Ok(credential.expiration_date)
+  }
+
+  /// Validates the linkage between a domain and a DID like [`JwtDomainLinkageValidator::validate_linkage`], using
+  /// `cache` to skip linkages that were already validated and have not expired yet.
+  ///
+  /// Upon successful validation, the linkage is cached until the latest expiration date of the valid Domain Linkage
+  /// Credentials issued by `issuer`. A cached linkage is only reused for the very same `configuration` and
+  /// `validation_options`, see [`DomainLinkageCache`].
+  ///
+  /// # Errors
+  /// Same as [`JwtDomainLinkageValidator::validate_linkage`].
+  pub fn validate_linkage_cached<DOC: AsRef<CoreDocument>>(
+    &self,
+    cache: &DomainLinkageCache,
+    issuer: &DOC,
+    configuration: &DomainLinkageConfiguration,
+    domain: &Url,
+    validation_options: &JwtCredentialValidationOptions,
+  ) -> DomainLinkageValidationResult {
+    let did: &CoreDID = issuer.as_ref().id();
+    if cache.is_validated(domain, configuration, validation_options, did) {
+      return Ok(());
+    }
+
+    let (valid, invalid): (Vec<_>, Vec<_>) = self
+      .linked_credentials(issuer, configuration)?
+      .map(|credential| self.validate_credential_expiry(issuer, credential, domain, validation_options))
+      .partition(Result::is_ok);
+
+    if !valid.is_empty() {
+      // Safety: `valid` is a list of prefiltered `Ok(_)`.
+      let valid_until: Option<Timestamp> = valid.into_iter().map(Result::unwrap).max().flatten();
+      cache.insert(
+        domain,
+        configuration.clone(),
+        validation_options,
+        did.clone(),
+        valid_until,
+      );
+      Ok(())
+    } else {
+      let errors = invalid
+        .into_iter()
+        .map(Result::unwrap_err) // Safety: `invalid` is a list of prefiltered `Err(_)`.
+        .collect();
+      Err(DomainLinkageValidationError {
+        cause: DomainLinkageValidationErrorCause::List,
+        source: Some(DomainLinkageValidationErrorList::new(errors).into()),
+      })
+    }
+  }
+
+  /// Validates the Domain Linkage Configurations of many origins at once.
+  ///
+  /// * `issuers`: DID Documents of the DIDs that may be linked to the origins.
+  /// * `origins`: pairs of a domain and the Domain Linkage Configuration fetched from it.
+  /// * `validation_options`: Further validation options to be applied on the Domain Linkage Credentials.
+  /// * `cache`: cache of validated linkages, which is consulted and updated.
+  ///
+  /// Returns, for every domain in `origins` and in the same order, the DIDs among `issuers` that are linked to it.
+  /// Linkages to DIDs not in `issuers` are ignored.
+  ///
+  /// # Errors
+  /// The result of a domain is an error if none of `issuers` could be linked to it, including when its configuration
+  /// doesn't contain any credential issued by them.
+  pub fn validate_origins<'a, DOC: AsRef<CoreDocument>>(
+    &self,
+    issuers: &[DOC],
+    origins: impl IntoIterator<Item = (&'a Url, &'a DomainLinkageConfiguration)>,
+    validation_options: &JwtCredentialValidationOptions,
+    cache: &DomainLinkageCache,
+  ) -> Vec<(Url, Result<Vec<CoreDID>, DomainLinkageValidationError>)> {
+    origins
+      .into_iter()
+      .map(|(domain, configuration)| {
+        let mut linked_dids: Vec<CoreDID> = Vec::new();
+        let mut errors: Vec<DomainLinkageValidationError> = Vec::new();
+        for issuer in issuers {
+          match self.validate_linkage_cached(cache, issuer, configuration, domain, validation_options) {
+            Ok(()) => linked_dids.push(issuer.as_ref().id().clone()),
+            Err(error) => errors.push(error),
+          }
+        }
+
+        let result = if !linked_dids.is_empty() {
+          Ok(linked_dids)
+        } else {
+          Err(DomainLinkageValidationError {
+            cause: DomainLinkageValidationErrorCause::List,
+            source: Some(DomainLinkageValidationErrorList::new(errors).into()),
+          })
+        };
+        (domain.clone(), result)
+      })
+      .collect()
+  }
+
+  /// Returns the credentials of `configuration` issued by `issuer`.
+  fn linked_credentials<'a, DOC: AsRef<CoreDocument>>(
+    &self,
+    issuer: &DOC,
+    configuration: &'a DomainLinkageConfiguration,
+  ) -> Result<impl Iterator<Item = &'a Jwt>, DomainLinkageValidationError> {
+    let issuers: Vec<CoreDID> = configuration.issuers().map_err(|err| DomainLinkageValidationError {
+      cause: DomainLinkageValidationErrorCause::InvalidJwt,
+      source: Some(err.into()),
+    })?;
+    let did: CoreDID = issuer.as_ref().id().clone();
+    if !issuers.contains(&did) {
+      return Err(DomainLinkageValidationError {
+        cause: DomainLinkageValidationErrorCause::InvalidStructure,
+        source: None,
+      });
+    }
+
+    Ok(
+      configuration
+        .linked_dids()
+        .iter()
+        .zip(issuers)
+        .filter_map(move |(credential, iss)| (iss == did).then_some(credential)),
+    )
   }
 }
 
@@ -270,6 +407,7 @@ mod tests {
   use crate::credential::Credential;
   use crate::credential::Jws;
   use crate::credential::Jwt;
+  use crate::domain_linkage::DomainLinkageCache;
   use crate::domain_linkage::DomainLinkageConfiguration;
   use crate::domain_linkage::DomainLinkageCredentialBuilder;
   use crate::domain_linkage::DomainLinkageValidationErrorCause;
@@ -718,6 +856,79 @@ mod tests {
     assert!(validation_result.is_ok());
   }
 
+  #[test]
+  fn test_validate_linkage_cached() {
+    let (document, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential: Credential = create_domain_linkage_credential(document.id());
+    let jwt: Jwt = sign_credential_jwt(&credential, &document, &fragment, &secret_key);
+    let configuration: DomainLinkageConfiguration = DomainLinkageConfiguration::new(vec![jwt]);
+    let cache = DomainLinkageCache::new();
+
+    JWT_DOMAIN_LINKAGE_VALIDATOR_ED25519
+      .validate_linkage_cached(
+        &cache,
+        &document,
+        &configuration,
+        &url_foo(),
+        &JwtCredentialValidationOptions::default(),
+      )
+      .unwrap();
+    assert!(cache.is_linked(&url_foo(), document.id()));
+
+    // Cached linkages only hold for the configuration they were validated from: a forged one is rejected.
+    let (other_document, other_secret_key, _) = generate_jwk_document_with_keys();
+    let forged: Jwt = sign_credential_jwt(&credential, &document, &fragment, &other_secret_key);
+    assert!(JWT_DOMAIN_LINKAGE_VALIDATOR_ED25519
+      .validate_linkage_cached(
+        &cache,
+        &document,
+        &DomainLinkageConfiguration::new(vec![forged]),
+        &url_foo(),
+        &JwtCredentialValidationOptions::default(),
+      )
+      .is_err());
+    // Nor are they reused under stricter options.
+    let strict_options = JwtCredentialValidationOptions::default()
+      .earliest_expiry_date(Timestamp::now_utc().checked_add(Duration::days(730)).unwrap());
+    assert!(JWT_DOMAIN_LINKAGE_VALIDATOR_ED25519
+      .validate_linkage_cached(&cache, &document, &configuration, &url_foo(), &strict_options)
+      .is_err());
+    assert!(JWT_DOMAIN_LINKAGE_VALIDATOR_ED25519
+      .validate_linkage_cached(
+        &cache,
+        &other_document,
+        &configuration,
+        &url_foo(),
+        &JwtCredentialValidationOptions::default(),
+      )
+      .is_err());
+  }
+
+  #[test]
+  fn test_validate_origins() {
+    let (document, secret_key, fragment) = generate_jwk_document_with_keys();
+    let (other_document, _, _) = generate_jwk_document_with_keys();
+    let credential: Credential = create_domain_linkage_credential(document.id());
+    let jwt: Jwt = sign_credential_jwt(&credential, &document, &fragment, &secret_key);
+    let configuration: DomainLinkageConfiguration = DomainLinkageConfiguration::new(vec![jwt]);
+    let other_domain: Url = Url::parse("https://bar.example.com").unwrap();
+    let cache = DomainLinkageCache::new();
+
+    let results = JWT_DOMAIN_LINKAGE_VALIDATOR_ED25519.validate_origins(
+      &[&document, &other_document],
+      [(&url_foo(), &configuration), (&other_domain, &configuration)],
+      &JwtCredentialValidationOptions::default(),
+      &cache,
+    );
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].0, url_foo());
+    assert_eq!(results[0].1.as_ref().unwrap(), &vec![document.id().clone()]);
+    assert_eq!(results[1].0, other_domain);
+    assert!(results[1].1.is_err());
+    assert_eq!(cache.len(), 1);
+  }
+
   fn url_foo() -> Url {
     Url::parse("https://foo.example.com").unwrap()
   }
@@ -3,11 +3,13 @@
 
 //! Implementation of [Domain Linkage](https://identity.foundation/.well-known/resources/did-configuration/).
 
+mod domain_linkage_cache;
 mod domain_linkage_configuration;
 mod domain_linkage_credential_builder;
 mod domain_linkage_validator;
 mod error;
 
+pub use self::domain_linkage_cache::*;
 pub use self::domain_linkage_configuration::*;
 pub use self::domain_linkage_credential_builder::*;
 pub use self::domain_linkage_validator::*;
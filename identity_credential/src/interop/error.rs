@@ -0,0 +1,28 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+
+/// An error caused by a failure to load interoperability fixtures.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum InteropError {
+  /// Caused by a fixture file or directory that cannot be read.
+  #[error("failed to read \"{}\"", path.display())]
+  Io {
+    /// The path that could not be read.
+    path: PathBuf,
+    /// The underlying error.
+    #[source]
+    source: std::io::Error,
+  },
+  /// Caused by a file that is not a valid fixture.
+  #[error("invalid fixture \"{}\"", path.display())]
+  InvalidFixture {
+    /// The path of the fixture.
+    path: PathBuf,
+    /// The underlying error.
+    #[source]
+    source: serde_json::Error,
+  },
+}
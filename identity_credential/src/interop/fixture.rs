@@ -0,0 +1,128 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::path::Path;
+
+use identity_core::common::Timestamp;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::JwkSet;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::InteropError;
+
+/// The format of the token held by an [`InteropFixture`], named after its OpenID4VC credential format identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum FixtureFormat {
+  /// A W3C Verifiable Credential encoded as a JWT.
+  #[serde(rename = "jwt_vc_json")]
+  JwtVc,
+  /// A W3C Verifiable Presentation encoded as a JWT, embedding JWT credentials.
+  #[serde(rename = "jwt_vp_json")]
+  JwtVp,
+  /// An SD-JWT VC, optionally with a KB-JWT.
+  #[serde(rename = "dc+sd-jwt", alias = "vc+sd-jwt")]
+  SdJwtVc,
+}
+
+impl Display for FixtureFormat {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(match self {
+      Self::JwtVc => "jwt_vc_json",
+      Self::JwtVp => "jwt_vp_json",
+      Self::SdJwtVc => "dc+sd-jwt",
+    })
+  }
+}
+
+/// The outcome of validating a fixture's token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+  /// The token is valid.
+  Valid,
+  /// The token is invalid.
+  Invalid,
+}
+
+/// A token produced by another implementation, together with what is needed to validate it and the outcome
+/// expected from a conforming verifier.
+///
+/// Fixtures are (de)serialized as JSON objects, e.g.:
+/// ```json
+/// {
+///   "name": "expired-credential",
+///   "source": "walt.id",
+///   "format": "jwt_vc_json",
+///   "token": "eyJ...",
+///   "issuer_documents": [{ "id": "did:example:issuer", "...": "..." }],
+///   "validated_at": "2024-06-01T00:00:00Z",
+///   "expected": "invalid"
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct InteropFixture {
+  /// A name identifying the fixture within its source.
+  pub name: String,
+  /// The implementation that produced the token, e.g. `aca-py`.
+  pub source: String,
+  /// A human-readable description of what the fixture exercises.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  /// The format of [`Self::token`].
+  pub format: FixtureFormat,
+  /// The token to validate, in its compact serialization.
+  pub token: String,
+  /// The DID Documents of the credentials' issuers, for JWT credentials and presentations.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub issuer_documents: Vec<CoreDocument>,
+  /// The DID Document of the presentation's holder, for JWT presentations.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub holder_document: Option<CoreDocument>,
+  /// The issuer's public keys, for SD-JWT VCs.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub issuer_jwks: Option<JwkSet>,
+  /// The point in time the token's validity period is checked against, so that fixtures do not expire.
+  /// Defaults to the current time.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub validated_at: Option<Timestamp>,
+  /// The outcome expected from a conforming verifier.
+  pub expected: Verdict,
+}
+
+impl InteropFixture {
+  /// Parses a fixture from its JSON representation.
+  pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+    serde_json::from_str(json)
+  }
+
+  /// Loads all the fixtures found in `dir`, i.e. its `.json` files, sorted by file name.
+  /// Subdirectories are not traversed.
+  pub fn from_dir(dir: impl AsRef<Path>) -> Result<Vec<Self>, InteropError> {
+    let dir = dir.as_ref();
+    let io_error = |path: &Path| {
+      let path = path.to_owned();
+      move |source| InteropError::Io { path, source }
+    };
+
+    let mut paths = std::fs::read_dir(dir)
+      .map_err(io_error(dir))?
+      .map(|entry| entry.map(|entry| entry.path()))
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(io_error(dir))?;
+    paths.retain(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "json"));
+    paths.sort();
+
+    paths
+      .into_iter()
+      .map(|path| {
+        let json = std::fs::read_to_string(&path).map_err(io_error(&path))?;
+        Self::from_json(&json).map_err(|source| InteropError::InvalidFixture { path, source })
+      })
+      .collect()
+  }
+}
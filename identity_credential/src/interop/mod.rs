@@ -0,0 +1,21 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Interoperability harness: checks this crate's validators against credentials and presentations produced by other
+//! implementations, e.g. ACA-Py, walt.id or Sphereon.
+//!
+//! Each [`InteropFixture`] is a JSON file holding a token, the key material needed to verify it and the outcome its
+//! producer expects from a conforming verifier. An [`InteropRunner`] validates every fixture of a suite and collects
+//! the outcomes into a [`CompatibilityReport`], which lists - per implementation - the fixtures on which we disagree.
+//!
+//! The fixtures under `tests/fixtures/interop` reproduce the output of ACA-Py, walt.id and Sphereon and are
+//! validated by this crate's tests. They are signed with test keys rather than captured from the tools themselves:
+//! fixtures exported from an implementation's own test suite can be loaded with [`InteropFixture::from_dir`].
+
+mod error;
+mod fixture;
+mod runner;
+
+pub use error::*;
+pub use fixture::*;
+pub use runner::*;
@@ -0,0 +1,308 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::JwsVerifierFn;
+use identity_verification::jws::VerificationInput;
+use sd_jwt::Sha256Hasher;
+use serde::Serialize;
+
+use super::FixtureFormat;
+use super::InteropFixture;
+use super::Verdict;
+use crate::credential::Jwt;
+use crate::sd_jwt_vc::SdJwtVc;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtPresentationValidationOptions;
+use crate::validator::JwtPresentationValidator;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Validates [`InteropFixture`]s with this crate's validators and reports whether their outcomes match the expected
+/// ones.
+///
+/// Only the properties our validators check by default are taken into account: signatures, validity periods, the
+/// structure of the credentials and - for presentations - the holder's binding. Status, type and schema are not
+/// checked.
+#[derive(Debug)]
+pub struct InteropRunner<V> {
+  verifier: V,
+}
+
+impl<V: JwsVerifier> InteropRunner<V> {
+  /// Creates a new [`InteropRunner`] that delegates cryptographic signature verification to `verifier`.
+  pub fn new(verifier: V) -> Self {
+    Self { verifier }
+  }
+
+  /// Validates every fixture of `fixtures`.
+  pub fn run<'f>(&self, fixtures: impl IntoIterator<Item = &'f InteropFixture>) -> CompatibilityReport {
+    CompatibilityReport {
+      results: fixtures.into_iter().map(|fixture| self.run_fixture(fixture)).collect(),
+    }
+  }
+
+  /// Validates `fixture`.
+  pub fn run_fixture(&self, fixture: &InteropFixture) -> FixtureResult {
+    let at = fixture.validated_at.unwrap_or_else(Timestamp::now_utc);
+    let outcome = match fixture.format {
+      FixtureFormat::JwtVc => self.validate_jwt_vc(fixture, at),
+      FixtureFormat::JwtVp => self.validate_jwt_vp(fixture, at),
+      FixtureFormat::SdJwtVc => self.validate_sd_jwt_vc(fixture, at),
+    };
+
+    FixtureResult {
+      name: fixture.name.clone(),
+      source: fixture.source.clone(),
+      format: fixture.format,
+      expected: fixture.expected,
+      actual: if outcome.is_ok() {
+        Verdict::Valid
+      } else {
+        Verdict::Invalid
+      },
+      error: outcome.err().map(|e| e.to_string()),
+    }
+  }
+
+  fn validate_jwt_vc(&self, fixture: &InteropFixture, at: Timestamp) -> Result<(), BoxError> {
+    self.validate_credential(&Jwt::new(fixture.token.clone()), &fixture.issuer_documents, at)
+  }
+
+  fn validate_jwt_vp(&self, fixture: &InteropFixture, at: Timestamp) -> Result<(), BoxError> {
+    let holder = fixture
+      .holder_document
+      .as_ref()
+      .ok_or("the fixture lacks the holder's DID Document")?;
+    let options = JwtPresentationValidationOptions::new()
+      .earliest_expiry_date(at)
+      .latest_issuance_date(at);
+    let verify = |input: VerificationInput, key: &Jwk| self.verifier.verify(input, key);
+    let presentation = JwtPresentationValidator::with_signature_verifier(JwsVerifierFn::from(verify))
+      .validate::<CoreDocument, Jwt, Object>(&Jwt::new(fixture.token.clone()), holder, &options)?;
+
+    for credential in presentation.presentation.verifiable_credential.iter() {
+      self.validate_credential(credential, &fixture.issuer_documents, at)?;
+    }
+
+    Ok(())
+  }
+
+  fn validate_credential(&self, credential: &Jwt, issuers: &[CoreDocument], at: Timestamp) -> Result<(), BoxError> {
+    let issuer = JwtCredentialValidatorUtils::extract_issuer_from_jwt::<CoreDID>(credential)?;
+    let issuer_document = issuers
+      .iter()
+      .find(|document| document.id() == &issuer)
+      .ok_or_else(|| format!("the fixture lacks the DID Document of \"{issuer}\""))?;
+    let options = JwtCredentialValidationOptions::new()
+      .earliest_expiry_date(at)
+      .latest_issuance_date(at);
+    let verify = |input: VerificationInput, key: &Jwk| self.verifier.verify(input, key);
+    JwtCredentialValidator::with_signature_verifier(JwsVerifierFn::from(verify)).validate::<_, Object>(
+      credential,
+      issuer_document,
+      &options,
+      FailFast::FirstError,
+    )?;
+
+    Ok(())
+  }
+
+  fn validate_sd_jwt_vc(&self, fixture: &InteropFixture, at: Timestamp) -> Result<(), BoxError> {
+    let sd_jwt_vc = SdJwtVc::parse(&fixture.token)?;
+    let jwks = fixture
+      .issuer_jwks
+      .as_ref()
+      .ok_or("the fixture lacks the issuer's JWK Set")?;
+    let issuer_jwk = match sd_jwt_vc.headers().get("kid").and_then(|kid| kid.as_str()) {
+      Some(kid) => jwks.iter().find(|jwk| jwk.kid() == Some(kid)),
+      None if jwks.len() == 1 => jwks.iter().next(),
+      None => None,
+    }
+    .ok_or("the issuer's JWK Set lacks the token's key")?;
+
+    sd_jwt_vc.verify_signature(&self.verifier, issuer_jwk)?;
+    sd_jwt_vc.disclosure_stats(&Sha256Hasher)?;
+    let claims = sd_jwt_vc.claims();
+    if claims.nbf.is_some_and(|nbf| nbf > at) {
+      return Err("the token is not yet valid".into());
+    }
+    if claims.exp.is_some_and(|exp| exp <= at) {
+      return Err("the token has expired".into());
+    }
+
+    Ok(())
+  }
+}
+
+/// The outcome of validating an [`InteropFixture`].
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct FixtureResult {
+  /// The name of the fixture.
+  pub name: String,
+  /// The implementation that produced the fixture.
+  pub source: String,
+  /// The format of the fixture's token.
+  pub format: FixtureFormat,
+  /// The outcome expected by the fixture.
+  pub expected: Verdict,
+  /// The outcome of our validation.
+  pub actual: Verdict,
+  /// The reason the token was deemed invalid, if it was.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+}
+
+impl FixtureResult {
+  /// Whether our validation agrees with the fixture.
+  pub fn passed(&self) -> bool {
+    self.expected == self.actual
+  }
+}
+
+/// The number of fixtures of an implementation on which our validation agrees and disagrees.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SourceSummary {
+  /// The number of fixtures on which our validation agrees.
+  pub passed: usize,
+  /// The number of fixtures on which our validation disagrees.
+  pub failed: usize,
+}
+
+/// The results of running a suite of [`InteropFixture`]s.
+///
+/// The report can be serialized to JSON, or displayed as a Markdown summary.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompatibilityReport {
+  /// The result of each fixture, in the order they were run.
+  pub results: Vec<FixtureResult>,
+}
+
+impl CompatibilityReport {
+  /// Whether our validation agrees with every fixture.
+  pub fn is_compatible(&self) -> bool {
+    self.results.iter().all(FixtureResult::passed)
+  }
+
+  /// Returns the fixtures on which our validation disagrees.
+  pub fn failures(&self) -> impl Iterator<Item = &FixtureResult> {
+    self.results.iter().filter(|result| !result.passed())
+  }
+
+  /// Summarizes the results per implementation.
+  pub fn by_source(&self) -> BTreeMap<&str, SourceSummary> {
+    let mut summaries = BTreeMap::<&str, SourceSummary>::new();
+    for result in &self.results {
+      let summary = summaries.entry(result.source.as_str()).or_default();
+      if result.passed() {
+        summary.passed += 1;
+      } else {
+        summary.failed += 1;
+      }
+    }
+
+    summaries
+  }
+}
+
+impl Display for CompatibilityReport {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "| Source | Passed | Failed |")?;
+    writeln!(f, "|---|---|---|")?;
+    for (source, summary) in self.by_source() {
+      writeln!(f, "| {source} | {} | {} |", summary.passed, summary.failed)?;
+    }
+
+    let mut failures = self.failures().peekable();
+    if failures.peek().is_some() {
+      writeln!(f)?;
+      writeln!(f, "Failures:")?;
+      for failure in failures {
+        write!(
+          f,
+          "- {}/{} ({}): expected {:?}, got {:?}",
+          failure.source, failure.name, failure.format, failure.expected, failure.actual
+        )?;
+        match &failure.error {
+          Some(error) => writeln!(f, ": {error}")?,
+          None => writeln!(f)?,
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use identity_verification::jws::SignatureVerificationError;
+
+  use super::*;
+
+  struct NoVerifier;
+
+  impl JwsVerifier for NoVerifier {
+    fn verify(&self, _input: VerificationInput, _public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+      unreachable!("no fixture reaches signature verification")
+    }
+  }
+
+  fn fixture(name: &str, source: &str, format: &str, expected: &str) -> InteropFixture {
+    InteropFixture::from_json(&format!(
+      r#"{{"name":"{name}","source":"{source}","format":"{format}","token":"not-a-token","expected":"{expected}"}}"#
+    ))
+    .unwrap()
+  }
+
+  #[test]
+  fn report_summarizes_disagreements_per_source() {
+    let fixtures = [
+      fixture("malformed-jwt", "aca-py", "jwt_vc_json", "invalid"),
+      fixture("malformed-sd-jwt", "aca-py", "vc+sd-jwt", "invalid"),
+      fixture("malformed-vp", "walt.id", "jwt_vp_json", "valid"),
+    ];
+    let report = InteropRunner::new(NoVerifier).run(&fixtures);
+
+    assert!(!report.is_compatible());
+    assert_eq!(
+      report.by_source().get("aca-py"),
+      Some(&SourceSummary { passed: 2, failed: 0 })
+    );
+    let failures: Vec<_> = report.failures().map(|failure| failure.name.as_str()).collect();
+    assert_eq!(failures, ["malformed-vp"]);
+    assert!(report.to_string().contains("| walt.id | 0 | 1 |"));
+  }
+
+  #[test]
+  fn bundled_fixtures_are_validated_as_expected() {
+    let fixtures = InteropFixture::from_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/interop")).unwrap();
+    let report = InteropRunner::new(EdDSAJwsVerifier::default()).run(&fixtures);
+
+    assert!(report.is_compatible(), "{report}");
+    for source in ["aca-py", "sphereon", "walt.id"] {
+      assert!(report
+        .by_source()
+        .get(source)
+        .is_some_and(|summary| summary.passed >= 2));
+    }
+    assert!(report
+      .results
+      .iter()
+      .any(|result| result.expected == Verdict::Invalid && result.error.is_some()));
+  }
+}
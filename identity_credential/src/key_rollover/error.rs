@@ -0,0 +1,39 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::DIDUrl;
+
+use crate::validator::CompoundCredentialValidationError;
+
+/// An error caused by a failure to validate a key rollover announcement, or a credential signed with a key that
+/// has been rolled over.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum KeyRolloverValidationError {
+  /// Caused when a key rollover announcement cannot be successfully validated.
+  #[error("invalid key rollover announcement")]
+  InvalidAnnouncement(#[source] CompoundCredentialValidationError),
+  /// Caused by a credential that is not a well-formed key rollover announcement.
+  #[error("malformed key rollover announcement: {0}")]
+  MalformedAnnouncement(&'static str),
+  /// Caused by a key rollover announcement that is not signed with the key it replaces.
+  #[error("the key rollover announcement is not signed by the previous method")]
+  SignerMismatch,
+  /// Caused when a credential cannot be successfully validated.
+  #[error("invalid credential")]
+  InvalidCredential(#[source] CompoundCredentialValidationError),
+  /// Caused by a credential or an announcement that could not be decoded.
+  #[error("could not decode credential")]
+  Decoding(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused by a credential signed with a method that is neither part of the issuer's DID Document nor rolled
+  /// over by any of the given announcements.
+  #[error("no key rollover announcement covers method {0}")]
+  UnknownMethod(DIDUrl),
+  /// Caused by a rollover whose next method is missing from the issuer's DID Document.
+  #[error("the rollover to method {0} has not been carried out")]
+  RolloverNotCarriedOut(DIDUrl),
+  /// Caused by a credential signed with a rolled over key, issued after the rollover took effect and its grace
+  /// period elapsed.
+  #[error("credential issued with method {0} after it was rolled over")]
+  IssuedAfterRollover(DIDUrl),
+}
@@ -0,0 +1,112 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_verification::jwk::Jwk;
+
+use crate::credential::Credential;
+use crate::key_rollover::KeyRolloverValidationError;
+use crate::key_rollover::KEY_ROLLOVER_CREDENTIAL_TYPE;
+use crate::validator::JwtCredentialValidatorUtils;
+
+/// A validated key rollover announcement.
+///
+/// Obtained through
+/// [`JwtKeyRolloverValidator::validate_announcement`](super::JwtKeyRolloverValidator::validate_announcement)
+/// and meant to be persisted by verifiers, since it can only be validated while the previous method is still part of
+/// the issuer's DID Document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyRollover {
+  pub(crate) issuer: CoreDID,
+  pub(crate) previous_method: DIDUrl,
+  pub(crate) previous_key: Jwk,
+  pub(crate) next_method: DIDUrl,
+  pub(crate) effective_from: Timestamp,
+}
+
+impl KeyRollover {
+  /// The DID of the issuer rolling over its key.
+  pub fn issuer(&self) -> &CoreDID {
+    &self.issuer
+  }
+
+  /// The verification method being replaced.
+  pub fn previous_method(&self) -> &DIDUrl {
+    &self.previous_method
+  }
+
+  /// The public key of the verification method being replaced.
+  pub fn previous_key(&self) -> &Jwk {
+    &self.previous_key
+  }
+
+  /// The verification method replacing the previous one.
+  pub fn next_method(&self) -> &DIDUrl {
+    &self.next_method
+  }
+
+  /// The time from which the next method replaces the previous one.
+  pub fn effective_from(&self) -> Timestamp {
+    self.effective_from
+  }
+
+  /// Extracts the rollover announced by `credential`, without validating it.
+  pub(crate) fn from_credential(credential: &Credential) -> Result<Self, KeyRolloverValidationError> {
+    use KeyRolloverValidationError::MalformedAnnouncement;
+
+    if !credential
+      .types
+      .iter()
+      .any(|type_| type_ == KEY_ROLLOVER_CREDENTIAL_TYPE)
+    {
+      return Err(MalformedAnnouncement("missing KeyRolloverCredential type"));
+    }
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer(credential)
+      .map_err(|err| KeyRolloverValidationError::Decoding(err.into()))?;
+    let subject = match credential.credential_subject.as_slice() {
+      [subject] => subject,
+      _ => return Err(MalformedAnnouncement("expected exactly one subject")),
+    };
+    if subject.id.as_ref().map(|id| id.as_str()) != Some(issuer.as_str()) {
+      return Err(MalformedAnnouncement("the subject must be the issuer"));
+    }
+
+    let property = |name: &'static str| -> Result<&Value, KeyRolloverValidationError> {
+      subject
+        .properties
+        .get(name)
+        .ok_or(MalformedAnnouncement("missing rollover property"))
+    };
+    let method = |name: &'static str| -> Result<DIDUrl, KeyRolloverValidationError> {
+      property(name)?
+        .as_str()
+        .and_then(|method| DIDUrl::parse(method).ok())
+        .filter(|method| method.did() == &issuer)
+        .ok_or(MalformedAnnouncement("rollover methods must be DID URLs of the issuer"))
+    };
+
+    let previous_method: DIDUrl = method("previousMethod")?;
+    let next_method: DIDUrl = method("nextMethod")?;
+    let previous_key: Jwk = Jwk::from_json_value(property("previousKey")?.clone())
+      .ok()
+      .filter(Jwk::is_public)
+      .ok_or(MalformedAnnouncement("previousKey must be a public JWK"))?;
+    let effective_from: Timestamp = property("effectiveFrom")?
+      .as_str()
+      .and_then(|timestamp| Timestamp::parse(timestamp).ok())
+      .ok_or(MalformedAnnouncement("effectiveFrom must be a timestamp"))?;
+
+    Ok(Self {
+      issuer,
+      previous_method,
+      previous_key,
+      next_method,
+      effective_from,
+    })
+  }
+}
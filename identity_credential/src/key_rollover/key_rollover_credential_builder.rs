@@ -0,0 +1,211 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::ToJson;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_verification::jwk::Jwk;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+use crate::credential::Subject;
+use crate::error::Result;
+use crate::Error;
+
+/// The `type` of a credential announcing a key rollover.
+pub const KEY_ROLLOVER_CREDENTIAL_TYPE: &str = "KeyRolloverCredential";
+
+/// Builder for a credential through which an issuer announces that a verification method of its DID Document
+/// replaces another one from a given time on.
+///
+/// The resulting credential has the shape:
+/// ```json
+/// {
+///   "type": ["VerifiableCredential", "KeyRolloverCredential"],
+///   "issuer": "<issuer>",
+///   "credentialSubject": {
+///     "id": "<issuer>",
+///     "previousMethod": "<issuer>#old-key",
+///     "previousKey": { "kty": "OKP", ... },
+///     "nextMethod": "<issuer>#new-key",
+///     "effectiveFrom": "2025-01-01T00:00:00Z"
+///   },
+///   ...
+/// }
+/// ```
+///
+/// The credential must be signed with the previous method, whose public key is embedded in the credential so that
+/// it can still be verified once the method is removed from the issuer's DID Document.
+///
+/// The builder expects `issuer`, `previousMethod`, `previousKey`, `nextMethod` and `effectiveFrom` to be set.
+/// Setting `issuanceDate` is optional. If unset the current time will be used.
+#[derive(Debug, Default)]
+pub struct KeyRolloverCredentialBuilder {
+  pub(crate) issuer: Option<CoreDID>,
+  pub(crate) previous_method: Option<DIDUrl>,
+  pub(crate) previous_key: Option<Jwk>,
+  pub(crate) next_method: Option<DIDUrl>,
+  pub(crate) effective_from: Option<Timestamp>,
+  pub(crate) issuance_date: Option<Timestamp>,
+}
+
+impl KeyRolloverCredentialBuilder {
+  /// Creates a new `KeyRolloverCredentialBuilder`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the value of the `issuer`, which is also the credential's subject.
+  #[must_use]
+  pub fn issuer(mut self, did: CoreDID) -> Self {
+    self.issuer = Some(did);
+    self
+  }
+
+  /// Sets the verification method being replaced, i.e. the one signing the credential.
+  #[must_use]
+  pub fn previous_method(mut self, method_id: DIDUrl) -> Self {
+    self.previous_method = Some(method_id);
+    self
+  }
+
+  /// Sets the public key of the verification method being replaced.
+  #[must_use]
+  pub fn previous_key(mut self, jwk: Jwk) -> Self {
+    self.previous_key = Some(jwk);
+    self
+  }
+
+  /// Sets the verification method replacing the previous one.
+  #[must_use]
+  pub fn next_method(mut self, method_id: DIDUrl) -> Self {
+    self.next_method = Some(method_id);
+    self
+  }
+
+  /// Sets the time from which the next method replaces the previous one.
+  #[must_use]
+  pub fn effective_from(mut self, value: Timestamp) -> Self {
+    self.effective_from = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `issuanceDate`.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Returns a new `Credential` based on the `KeyRolloverCredentialBuilder` configuration.
+  pub fn build(self) -> Result<Credential<Object>> {
+    let issuer: CoreDID = self.issuer.ok_or(Error::MissingIssuer)?;
+    let previous_method: DIDUrl = self.previous_method.ok_or(Error::InvalidSubject)?;
+    let next_method: DIDUrl = self.next_method.ok_or(Error::InvalidSubject)?;
+    let effective_from: Timestamp = self.effective_from.ok_or(Error::InvalidSubject)?;
+    // Only the public parameters of the previous key may be disclosed.
+    let previous_key: Jwk = self
+      .previous_key
+      .as_ref()
+      .and_then(Jwk::to_public)
+      .ok_or(Error::InvalidSubject)?;
+    if previous_method.did() != &issuer || next_method.did() != &issuer || previous_method == next_method {
+      return Err(Error::InvalidSubject);
+    }
+
+    let mut properties: Object = Object::new();
+    properties.insert("previousMethod".into(), previous_method.to_string().into());
+    properties.insert(
+      "previousKey".into(),
+      previous_key.to_json_value().map_err(|_| Error::InvalidSubject)?,
+    );
+    properties.insert("nextMethod".into(), next_method.to_string().into());
+    properties.insert("effectiveFrom".into(), effective_from.to_rfc3339().into());
+    let issuer: Url = issuer.into_url().into();
+
+    Ok(Credential {
+      context: OneOrMany::One(Credential::<Object>::base_context().clone()),
+      id: None,
+      types: OneOrMany::Many(vec![
+        Credential::<Object>::base_type().to_owned(),
+        KEY_ROLLOVER_CREDENTIAL_TYPE.to_owned(),
+      ]),
+      credential_subject: OneOrMany::One(Subject::with_id_and_properties(issuer.clone(), properties)),
+      issuer: Issuer::Url(issuer),
+      issuance_date: self.issuance_date.unwrap_or_else(Timestamp::now_utc),
+      expiration_date: None,
+      credential_status: None,
+      credential_schema: Vec::new().into(),
+      refresh_service: Vec::new().into(),
+      terms_of_use: Vec::new().into(),
+      evidence: Vec::new().into(),
+      non_transferable: None,
+      properties: Object::new(),
+      proof: None,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_verification::jwk::EdCurve;
+  use identity_verification::jwk::JwkParamsOkp;
+
+  use super::*;
+
+  fn did() -> CoreDID {
+    "did:example:issuer".parse().unwrap()
+  }
+
+  fn jwk() -> Jwk {
+    let mut params = JwkParamsOkp::new();
+    params.crv = EdCurve::Ed25519.name().to_owned();
+    params.x = "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo".to_owned();
+    params.d = Some("nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A".to_owned());
+    Jwk::from_params(params)
+  }
+
+  #[test]
+  fn test_builder_discloses_public_key_only() {
+    let credential = KeyRolloverCredentialBuilder::new()
+      .issuer(did())
+      .previous_method(did().join("#old").unwrap())
+      .previous_key(jwk())
+      .next_method(did().join("#new").unwrap())
+      .effective_from(Timestamp::now_utc())
+      .build()
+      .unwrap();
+
+    assert!(credential
+      .types
+      .iter()
+      .any(|type_| type_ == KEY_ROLLOVER_CREDENTIAL_TYPE));
+    let subject = credential.credential_subject.get(0).unwrap();
+    assert_eq!(subject.id.as_ref().unwrap().as_str(), "did:example:issuer");
+    assert_eq!(subject.properties["nextMethod"], "did:example:issuer#new");
+    assert!(subject.properties["previousKey"].get("x").is_some());
+    assert!(subject.properties["previousKey"].get("d").is_none());
+  }
+
+  #[test]
+  fn test_builder_requires_methods_of_the_issuer() {
+    let other: CoreDID = "did:example:other".parse().unwrap();
+    let err = KeyRolloverCredentialBuilder::new()
+      .issuer(did())
+      .previous_method(did().join("#old").unwrap())
+      .previous_key(jwk())
+      .next_method(other.join("#new").unwrap())
+      .effective_from(Timestamp::now_utc())
+      .build()
+      .unwrap_err();
+    assert!(matches!(err, Error::InvalidSubject));
+
+    let err = KeyRolloverCredentialBuilder::new().issuer(did()).build().unwrap_err();
+    assert!(matches!(err, Error::InvalidSubject));
+  }
+}
@@ -0,0 +1,382 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::Decoder;
+use identity_verification::jws::JwsValidationItem;
+use identity_verification::jws::JwsVerifier;
+
+use crate::credential::Jwt;
+use crate::key_rollover::KeyRollover;
+use crate::key_rollover::KeyRolloverValidationError;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtValidationError;
+use crate::validator::SignerContext;
+
+/// A validator for key rollover announcements and for credentials signed with keys that have been rolled over.
+pub struct JwtKeyRolloverValidator<V: JwsVerifier> {
+  validator: JwtCredentialValidator<V>,
+}
+
+impl<V: JwsVerifier> JwtKeyRolloverValidator<V> {
+  /// Create a new [`JwtKeyRolloverValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self {
+      validator: JwtCredentialValidator::with_signature_verifier(signature_verifier),
+    }
+  }
+
+  /// Validates a key rollover announcement, created through
+  /// [`KeyRolloverCredentialBuilder`](crate::key_rollover::KeyRolloverCredentialBuilder).
+  ///
+  /// * `announcement`: the announcement, signed with the previous method.
+  /// * `issuer`: the DID Document of the issuer, which must still contain the previous method.
+  /// * `options`: validation options applied to the announcement.
+  ///
+  /// The announcement must be signed with its previous method, and the key it discloses must be the one of the
+  /// previous method in `issuer`. The returned [`KeyRollover`] should be stored, since it can no longer be validated
+  /// once the previous method is removed from the issuer's DID Document.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  pub fn validate_announcement<DOC: AsRef<CoreDocument>>(
+    &self,
+    announcement: &Jwt,
+    issuer: &DOC,
+    options: &JwtCredentialValidationOptions,
+  ) -> Result<KeyRollover, KeyRolloverValidationError> {
+    let decoded: DecodedJwtCredential = self
+      .validator
+      .validate(announcement, issuer, options, FailFast::FirstError)
+      .map_err(KeyRolloverValidationError::InvalidAnnouncement)?;
+    let rollover: KeyRollover = KeyRollover::from_credential(&decoded.credential)?;
+
+    let signer: DIDUrl = signing_method(announcement, options)?;
+    if signer != rollover.previous_method {
+      return Err(KeyRolloverValidationError::SignerMismatch);
+    }
+    let published_key_matches: bool = issuer
+      .as_ref()
+      .resolve_method(&rollover.previous_method, None)
+      .and_then(|method| method.public_key_as_jwk().ok())
+      .is_some_and(|jwk| jwk.as_ref() == &rollover.previous_key);
+    if !published_key_matches {
+      return Err(KeyRolloverValidationError::MalformedAnnouncement(
+        "previousKey is not the key of the previous method",
+      ));
+    }
+
+    Ok(rollover)
+  }
+
+  /// Decodes and validates a [`Credential`](crate::credential::Credential) issued as a JWT, accepting signatures
+  /// made with keys that have been rolled over.
+  ///
+  /// * `credential`: the credential.
+  /// * `issuer`: the up-to-date DID Document of the issuer.
+  /// * `rollovers`: validated key rollovers of the issuer.
+  /// * `grace_period`: how long after a rollover takes effect credentials may still be issued with the previous key.
+  /// * `options`: validation options applied to the credential.
+  ///
+  /// Credentials signed with a method of `issuer` are validated as with [`JwtCredentialValidator::validate`].
+  /// Otherwise, the signing method must be the previous method of one of `rollovers`, whose next method must be part
+  /// of `issuer`, and the credential must have been issued before the rollover took effect or within
+  /// `grace_period` thereafter. Credentials whose cutoff overflows the range of [`Timestamp`] are rejected.
+  ///
+  /// # Security
+  /// The issuance date is asserted by the credential itself, i.e. by whoever holds the rolled over key. The grace
+  /// period therefore does not protect against a compromised key, which can sign credentials backdated to before the
+  /// rollover: rollovers in response to a compromise must be handled by not passing the affected announcement in
+  /// `rollovers` at all.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  pub fn validate<DOC, T>(
+    &self,
+    credential: &Jwt,
+    issuer: &DOC,
+    rollovers: &[KeyRollover],
+    grace_period: Duration,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, KeyRolloverValidationError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let method_id: DIDUrl = signing_method(credential, options)?;
+    let document: &CoreDocument = issuer.as_ref();
+    if document.id() != method_id.did()
+      || document
+        .resolve_method(&method_id, options.verification_options.method_scope)
+        .is_some()
+    {
+      return self
+        .validator
+        .validate(credential, issuer, options, fail_fast)
+        .map_err(KeyRolloverValidationError::InvalidCredential);
+    }
+
+    let rollover: &KeyRollover = rollovers
+      .iter()
+      .find(|rollover| rollover.previous_method == method_id)
+      .ok_or_else(|| KeyRolloverValidationError::UnknownMethod(method_id.clone()))?;
+    if document.resolve_method(&rollover.next_method, None).is_none() {
+      return Err(KeyRolloverValidationError::RolloverNotCarriedOut(
+        rollover.next_method.clone(),
+      ));
+    }
+
+    let decoded: JwsValidationItem<'_> = JwtCredentialValidator::<V>::decode(credential.as_str())
+      .map_err(|err| KeyRolloverValidationError::Decoding(err.into()))?;
+    let decoded_credential: DecodedJwtCredential<T> = JwtCredentialValidator::<V>::verify_decoded_signature(
+      decoded,
+      &rollover.previous_key,
+      self.validator.signature_verifier(),
+    )
+    .map_err(invalid_credential)?;
+    let issuer_id: CoreDID =
+      JwtCredentialValidatorUtils::extract_issuer(&decoded_credential.credential).map_err(invalid_credential)?;
+    if &issuer_id != method_id.did() {
+      return Err(invalid_credential(JwtValidationError::IdentifierMismatch {
+        signer_ctx: SignerContext::Issuer,
+      }));
+    }
+
+    // Without an upper bound, the previous key may be used indefinitely: a cutoff that cannot be represented is
+    // treated as already elapsed.
+    let within_grace_period = rollover
+      .effective_from
+      .checked_add(grace_period)
+      .is_some_and(|cutoff| decoded_credential.credential.issuance_date <= cutoff);
+    if !within_grace_period {
+      return Err(KeyRolloverValidationError::IssuedAfterRollover(method_id));
+    }
+
+    JwtCredentialValidator::<V>::validate_decoded_credential::<CoreDocument, T>(
+      &decoded_credential.credential,
+      std::slice::from_ref(document),
+      options,
+      fail_fast,
+    )
+    .map_err(KeyRolloverValidationError::InvalidCredential)?;
+
+    Ok(decoded_credential)
+  }
+}
+
+/// Returns the DID URL of the method that signed `jwt`.
+fn signing_method(jwt: &Jwt, options: &JwtCredentialValidationOptions) -> Result<DIDUrl, KeyRolloverValidationError> {
+  let decoded: JwsValidationItem<'_> = Decoder::new()
+    .decode_compact_serialization(jwt.as_str().as_bytes(), None)
+    .map_err(|err| KeyRolloverValidationError::Decoding(err.into()))?;
+  options
+    .issuer_location
+    .locate_method(&decoded, &options.verification_options)
+    .map_err(|err| KeyRolloverValidationError::Decoding(err.into()))
+}
+
+fn invalid_credential(error: JwtValidationError) -> KeyRolloverValidationError {
+  KeyRolloverValidationError::InvalidCredential(CompoundCredentialValidationError {
+    validation_errors: vec![error],
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_did::DID;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use identity_verification::MethodScope;
+  use identity_verification::VerificationMethod;
+  use once_cell::sync::Lazy;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::key_rollover::KeyRolloverCredentialBuilder;
+  use crate::validator::test_utils::encode_public_ed25519_jwk;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+
+  static JWT_KEY_ROLLOVER_VALIDATOR_ED25519: Lazy<JwtKeyRolloverValidator<EdDSAJwsVerifier>> =
+    Lazy::new(|| JwtKeyRolloverValidator::with_signature_verifier(EdDSAJwsVerifier::default()));
+
+  const NEXT: &str = "#next";
+
+  /// An issuer rolling over its key: `before` contains both methods, `after` only the next one.
+  struct Rotation {
+    before: CoreDocument,
+    after: CoreDocument,
+    previous_fragment: String,
+    previous_key: SecretKey,
+  }
+
+  impl Rotation {
+    fn new() -> Self {
+      let (mut before, previous_key, previous_fragment) = generate_jwk_document_with_keys();
+      let next_key: SecretKey = SecretKey::generate().unwrap();
+      let next_method = VerificationMethod::new_from_jwk(
+        before.id().clone(),
+        encode_public_ed25519_jwk(&next_key.public_key()),
+        Some(NEXT),
+      )
+      .unwrap();
+      before
+        .insert_method(next_method, MethodScope::VerificationMethod)
+        .unwrap();
+
+      let mut after: CoreDocument = before.clone();
+      after.remove_method(&before.id().to_url().join(&previous_fragment).unwrap());
+
+      Self {
+        before,
+        after,
+        previous_fragment,
+        previous_key,
+      }
+    }
+
+    fn previous_method(&self) -> DIDUrl {
+      self.before.id().to_url().join(&self.previous_fragment).unwrap()
+    }
+
+    fn announcement(&self, effective_from: Timestamp) -> Jwt {
+      let previous_key = self
+        .before
+        .resolve_method(&self.previous_method(), None)
+        .unwrap()
+        .public_key_as_jwk()
+        .unwrap()
+        .into_owned();
+      let credential: Credential = KeyRolloverCredentialBuilder::new()
+        .issuer(self.before.id().clone())
+        .previous_method(self.previous_method())
+        .previous_key(previous_key)
+        .next_method(self.before.id().to_url().join(NEXT).unwrap())
+        .effective_from(effective_from)
+        .build()
+        .unwrap();
+      self.sign(&credential)
+    }
+
+    fn issue(&self, issuance_date: Timestamp) -> Jwt {
+      let credential: Credential = CredentialBuilder::default()
+        .issuer(Url::parse(self.before.id().as_str()).unwrap())
+        .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+        .issuance_date(issuance_date)
+        .build()
+        .unwrap();
+      self.sign(&credential)
+    }
+
+    fn sign(&self, credential: &Credential) -> Jwt {
+      sign_credential_jwt(credential, &self.before, &self.previous_fragment, &self.previous_key)
+    }
+
+    fn rollover(&self, effective_from: Timestamp) -> KeyRollover {
+      JWT_KEY_ROLLOVER_VALIDATOR_ED25519
+        .validate_announcement(
+          &self.announcement(effective_from),
+          &self.before,
+          &JwtCredentialValidationOptions::default(),
+        )
+        .unwrap()
+    }
+  }
+
+  fn validate(
+    credential: &Jwt,
+    issuer: &CoreDocument,
+    rollovers: &[KeyRollover],
+  ) -> Result<DecodedJwtCredential<Object>, KeyRolloverValidationError> {
+    JWT_KEY_ROLLOVER_VALIDATOR_ED25519.validate(
+      credential,
+      issuer,
+      rollovers,
+      Duration::days(1),
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+  }
+
+  fn days_ago(days: u32) -> Timestamp {
+    Timestamp::now_utc().checked_sub(Duration::days(days)).unwrap()
+  }
+
+  #[test]
+  fn test_announcement_is_validated() {
+    let rotation = Rotation::new();
+    let rollover = rotation.rollover(days_ago(0));
+    assert_eq!(rollover.previous_method(), &rotation.previous_method());
+    assert_eq!(rollover.issuer(), rotation.before.id());
+
+    // The announcement can't be validated once the previous method is gone.
+    let err = JWT_KEY_ROLLOVER_VALIDATOR_ED25519
+      .validate_announcement(
+        &rotation.announcement(days_ago(0)),
+        &rotation.after,
+        &JwtCredentialValidationOptions::default(),
+      )
+      .unwrap_err();
+    assert!(matches!(err, KeyRolloverValidationError::InvalidAnnouncement(_)));
+  }
+
+  #[test]
+  fn test_rolled_over_key_is_accepted_before_cutoff() {
+    let rotation = Rotation::new();
+    let rollover = rotation.rollover(days_ago(10));
+
+    let credential: Jwt = rotation.issue(days_ago(30));
+    assert!(validate(&credential, &rotation.before, &[]).is_ok());
+    assert!(matches!(
+      validate(&credential, &rotation.after, &[]).unwrap_err(),
+      KeyRolloverValidationError::UnknownMethod(_)
+    ));
+    assert!(validate(&credential, &rotation.after, &[rollover.clone()]).is_ok());
+
+    // Within the grace period.
+    let credential: Jwt = rotation.issue(days_ago(10).checked_add(Duration::hours(12)).unwrap());
+    assert!(validate(&credential, &rotation.after, &[rollover.clone()]).is_ok());
+
+    // After the grace period.
+    let credential: Jwt = rotation.issue(days_ago(5));
+    assert!(matches!(
+      validate(&credential, &rotation.after, &[rollover]).unwrap_err(),
+      KeyRolloverValidationError::IssuedAfterRollover(_)
+    ));
+  }
+
+  #[test]
+  fn test_overflowing_cutoff_is_treated_as_elapsed() {
+    let rotation = Rotation::new();
+    let rollover = rotation.rollover(Timestamp::parse("9999-12-31T00:00:00Z").unwrap());
+
+    let err = validate(&rotation.issue(days_ago(1)), &rotation.after, &[rollover]).unwrap_err();
+    assert!(matches!(err, KeyRolloverValidationError::IssuedAfterRollover(_)));
+  }
+
+  #[test]
+  fn test_rollover_must_be_carried_out() {
+    let rotation = Rotation::new();
+    let rollover = rotation.rollover(days_ago(10));
+    let mut document: CoreDocument = rotation.after.clone();
+    document.remove_method(rollover.next_method());
+
+    let err = validate(&rotation.issue(days_ago(30)), &document, &[rollover]).unwrap_err();
+    assert!(matches!(err, KeyRolloverValidationError::RolloverNotCarriedOut(_)));
+  }
+}
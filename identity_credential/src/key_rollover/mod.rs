@@ -0,0 +1,19 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Key rollover: an issuer announces that a new verification method replaces an old one from a given time on.
+//!
+//! The announcement is a [`KeyRolloverCredential`](KEY_ROLLOVER_CREDENTIAL_TYPE) signed with the old key while it's
+//! still part of the issuer's DID Document. Once validated, it allows credentials signed with the old key to be
+//! validated after the key has been removed from the DID Document, as long as they were issued before the rollover
+//! took effect - or within a grace period thereafter.
+
+mod error;
+mod key_rollover;
+mod key_rollover_credential_builder;
+mod key_rollover_validator;
+
+pub use self::key_rollover::*;
+pub use self::key_rollover_credential_builder::*;
+pub use self::key_rollover_validator::*;
+pub use error::*;
@@ -14,13 +14,31 @@
   clippy::missing_safety_doc
 )]
 
+#[cfg(feature = "anonymization")]
+pub mod anonymization;
+#[cfg(feature = "claims-mapping")]
+pub mod claims_mapping;
 #[cfg(feature = "credential")]
 pub mod credential;
+#[cfg(feature = "delegation")]
+pub mod delegation;
 #[cfg(feature = "domain-linkage")]
 pub mod domain_linkage;
 pub mod error;
+#[cfg(feature = "interop-tests")]
+pub mod interop;
+#[cfg(feature = "key-rollover")]
+pub mod key_rollover;
+#[cfg(feature = "oid4vc")]
+pub mod oid4vc;
+#[cfg(feature = "openid4vp")]
+pub mod openid4vp;
 #[cfg(feature = "presentation")]
 pub mod presentation;
+#[cfg(feature = "presentation-exchange")]
+pub mod presentation_exchange;
+#[cfg(feature = "credential-profiles")]
+pub mod profiles;
 #[cfg(feature = "revocation-bitmap")]
 pub mod revocation;
 mod utils;
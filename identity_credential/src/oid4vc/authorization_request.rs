@@ -0,0 +1,138 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::decode_uri;
+use super::encode_uri;
+use super::query_param;
+use super::Error;
+use super::QrPayload;
+use super::Result;
+
+/// The default endpoint of a Wallet's authorization endpoint, used when the Wallet's own endpoint is unknown.
+pub const AUTHORIZATION_REQUEST_ENDPOINT: &str = "openid4vp://";
+
+/// An OpenID4VP authorization request passed by reference, as defined in
+/// [JAR](https://www.rfc-editor.org/rfc/rfc9101.html#name-passing-a-request-object-by-).
+///
+/// The Wallet fetches the signed request object from `request_uri`, which keeps the URI - and the QR code
+/// encoding it - small regardless of the request's content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorizationRequestUri {
+  /// The Verifier's client identifier, including its prefix - e.g. `x509_san_dns:verifier.example.com`.
+  pub client_id: String,
+  /// The URL the request object can be fetched from.
+  pub request_uri: Url,
+  /// The HTTP method the Wallet must use to fetch the request object. Defaults to `GET` when unset.
+  pub request_uri_method: Option<RequestUriMethod>,
+}
+
+impl AuthorizationRequestUri {
+  /// Creates a new [`AuthorizationRequestUri`].
+  pub fn new(client_id: impl Into<String>, request_uri: Url) -> Self {
+    Self {
+      client_id: client_id.into(),
+      request_uri,
+      request_uri_method: None,
+    }
+  }
+
+  /// Sets the HTTP method the Wallet must use to fetch the request object.
+  #[must_use]
+  pub fn request_uri_method(mut self, method: RequestUriMethod) -> Self {
+    self.request_uri_method = Some(method);
+    self
+  }
+
+  /// Encodes this request as a URI for the default endpoint `openid4vp://`.
+  pub fn to_uri(&self) -> String {
+    self.to_uri_with_endpoint(AUTHORIZATION_REQUEST_ENDPOINT)
+  }
+
+  /// Encodes this request as a URI for the Wallet's authorization endpoint `endpoint`.
+  pub fn to_uri_with_endpoint(&self, endpoint: &str) -> String {
+    let mut params = vec![
+      ("client_id", self.client_id.as_str()),
+      ("request_uri", self.request_uri.as_str()),
+    ];
+    if let Some(method) = self.request_uri_method {
+      params.push(("request_uri_method", method.as_str()));
+    }
+
+    encode_uri(endpoint, params)
+  }
+
+  /// Encodes this request as a [`QrPayload`] for the default endpoint.
+  pub fn to_qr_payload(&self) -> QrPayload {
+    QrPayload::new(self.to_uri())
+  }
+
+  /// Parses an authorization request URI, regardless of its endpoint.
+  pub fn parse(uri: &str) -> Result<Self> {
+    let (_, params) = decode_uri(uri)?;
+    let missing_param = |name: &str| Error::InvalidUri(format!("missing \"{name}\" parameter"));
+
+    let client_id = query_param(&params, "client_id")?.ok_or_else(|| missing_param("client_id"))?;
+    let request_uri = query_param(&params, "request_uri")?.ok_or_else(|| missing_param("request_uri"))?;
+    let request_uri = Url::parse(request_uri).map_err(|e| Error::InvalidUri(e.to_string()))?;
+    let request_uri_method = query_param(&params, "request_uri_method")?
+      .map(|method| match method {
+        "get" => Ok(RequestUriMethod::Get),
+        "post" => Ok(RequestUriMethod::Post),
+        _ => Err(Error::InvalidUri(format!("invalid request_uri_method \"{method}\""))),
+      })
+      .transpose()?;
+
+    Ok(Self {
+      client_id: client_id.to_owned(),
+      request_uri,
+      request_uri_method,
+    })
+  }
+}
+
+/// The HTTP method used to fetch a request object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequestUriMethod {
+  /// `GET`.
+  Get,
+  /// `POST`, which allows the Wallet to send its capabilities to the Verifier.
+  Post,
+}
+
+impl RequestUriMethod {
+  /// Returns the parameter value for this method.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Get => "get",
+      Self::Post => "post",
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn authorization_request_uri_roundtrip() {
+    let request = AuthorizationRequestUri::new(
+      "redirect_uri:https://verifier.example.com/callback",
+      Url::parse("https://verifier.example.com/requests/42").unwrap(),
+    )
+    .request_uri_method(RequestUriMethod::Post);
+    let uri = request.to_uri();
+
+    assert_eq!(
+      uri,
+      "openid4vp://?client_id=redirect_uri%3Ahttps%3A%2F%2Fverifier.example.com%2Fcallback\
+       &request_uri=https%3A%2F%2Fverifier.example.com%2Frequests%2F42&request_uri_method=post"
+    );
+    assert_eq!(AuthorizationRequestUri::parse(&uri).unwrap(), request);
+    assert!(AuthorizationRequestUri::parse("openid4vp://?client_id=foo").is_err());
+  }
+}
@@ -0,0 +1,306 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::decode_uri;
+use super::encode_uri;
+use super::query_param;
+use super::Error;
+use super::QrPayload;
+use super::Result;
+
+/// The default endpoint of a Wallet's credential offer, used when the Wallet's own endpoint is unknown.
+pub const CREDENTIAL_OFFER_ENDPOINT: &str = "openid-credential-offer://";
+/// The grant type identifier of the Pre-Authorized Code Flow.
+pub const PRE_AUTHORIZED_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:pre-authorized_code";
+
+/// An OpenID4VCI credential offer.
+///
+/// See [Credential Offer](https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html#name-credential-offer-parameters).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CredentialOffer {
+  /// The URL of the Credential Issuer the Wallet is requested to obtain credentials from.
+  pub credential_issuer: Url,
+  /// The identifiers of the credential configurations - as listed in the issuer's metadata - that are offered.
+  pub credential_configuration_ids: Vec<String>,
+  /// The grant types the Credential Issuer is prepared to process for this offer.
+  #[serde(default, skip_serializing_if = "Grants::is_empty")]
+  pub grants: Grants,
+}
+
+impl CredentialOffer {
+  /// Returns a [`CredentialOfferBuilder`] for an offer from `credential_issuer`.
+  pub fn builder(credential_issuer: Url) -> CredentialOfferBuilder {
+    CredentialOfferBuilder::new(credential_issuer)
+  }
+}
+
+/// The grants of a [`CredentialOffer`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Grants {
+  /// Parameters of the Authorization Code Flow.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub authorization_code: Option<AuthorizationCodeGrant>,
+  /// Parameters of the Pre-Authorized Code Flow.
+  #[serde(
+    rename = "urn:ietf:params:oauth:grant-type:pre-authorized_code",
+    skip_serializing_if = "Option::is_none"
+  )]
+  pub pre_authorized_code: Option<PreAuthorizedCodeGrant>,
+}
+
+impl Grants {
+  /// Returns whether no grant has been specified.
+  pub fn is_empty(&self) -> bool {
+    self.authorization_code.is_none() && self.pre_authorized_code.is_none()
+  }
+}
+
+/// Parameters of the Authorization Code Flow grant.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorizationCodeGrant {
+  /// Value binding the subsequent authorization request to this offer.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub issuer_state: Option<String>,
+  /// The authorization server to use, when the issuer relies on more than one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub authorization_server: Option<Url>,
+}
+
+/// Parameters of the Pre-Authorized Code Flow grant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreAuthorizedCodeGrant {
+  /// The code representing the issuer's authorization for the Wallet to obtain credentials.
+  #[serde(rename = "pre-authorized_code")]
+  pub pre_authorized_code: String,
+  /// Describes the transaction code the user must provide together with the pre-authorized code, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tx_code: Option<TxCode>,
+  /// The authorization server to use, when the issuer relies on more than one.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub authorization_server: Option<Url>,
+}
+
+/// Description of a transaction code - e.g. a PIN sent out of band - required by the Pre-Authorized Code Flow.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxCode {
+  /// The characters the transaction code is made of.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub input_mode: Option<TxCodeInputMode>,
+  /// The length of the transaction code.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub length: Option<u32>,
+  /// Guidance for the user on how to obtain the transaction code.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+}
+
+impl TxCode {
+  /// Creates a new [`TxCode`] made of `length` characters of kind `input_mode`.
+  pub fn new(input_mode: TxCodeInputMode, length: u32) -> Self {
+    Self {
+      input_mode: Some(input_mode),
+      length: Some(length),
+      description: None,
+    }
+  }
+
+  /// Sets the guidance for the user on how to obtain the transaction code.
+  #[must_use]
+  pub fn description(mut self, description: impl Into<String>) -> Self {
+    self.description = Some(description.into());
+    self
+  }
+}
+
+/// The kind of characters a [`TxCode`] is made of.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TxCodeInputMode {
+  /// Digits only.
+  #[default]
+  Numeric,
+  /// Any character.
+  Text,
+}
+
+/// Builder for [`CredentialOffer`].
+#[derive(Debug, Clone)]
+pub struct CredentialOfferBuilder {
+  credential_issuer: Url,
+  credential_configuration_ids: Vec<String>,
+  grants: Grants,
+}
+
+impl CredentialOfferBuilder {
+  /// Creates a new [`CredentialOfferBuilder`] for an offer from `credential_issuer`.
+  pub fn new(credential_issuer: Url) -> Self {
+    Self {
+      credential_issuer,
+      credential_configuration_ids: Vec::new(),
+      grants: Grants::default(),
+    }
+  }
+
+  /// Adds a credential configuration to the offer.
+  #[must_use]
+  pub fn credential_configuration_id(mut self, id: impl Into<String>) -> Self {
+    self.credential_configuration_ids.push(id.into());
+    self
+  }
+
+  /// Enables the Authorization Code Flow, optionally binding it to `issuer_state`.
+  #[must_use]
+  pub fn authorization_code(mut self, issuer_state: Option<String>) -> Self {
+    self.grants.authorization_code = Some(AuthorizationCodeGrant {
+      issuer_state,
+      authorization_server: None,
+    });
+    self
+  }
+
+  /// Enables the Pre-Authorized Code Flow with the given code, optionally protected by a transaction code.
+  #[must_use]
+  pub fn pre_authorized_code(mut self, code: impl Into<String>, tx_code: Option<TxCode>) -> Self {
+    self.grants.pre_authorized_code = Some(PreAuthorizedCodeGrant {
+      pre_authorized_code: code.into(),
+      tx_code,
+      authorization_server: None,
+    });
+    self
+  }
+
+  /// Returns the resulting [`CredentialOffer`].
+  ///
+  /// # Errors
+  /// Fails if no credential configuration has been added.
+  pub fn build(self) -> Result<CredentialOffer> {
+    if self.credential_configuration_ids.is_empty() {
+      return Err(Error::MissingCredentialConfiguration);
+    }
+
+    Ok(CredentialOffer {
+      credential_issuer: self.credential_issuer,
+      credential_configuration_ids: self.credential_configuration_ids,
+      grants: self.grants,
+    })
+  }
+}
+
+/// A credential offer as conveyed to a Wallet, either embedding the offer or referencing it.
+///
+/// Offers passed by reference result in much smaller URIs and should be preferred for QR codes
+/// whenever the offer is large - see [`QrPayload::guidance`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialOfferUri {
+  /// The offer is embedded in the URI through the `credential_offer` parameter.
+  ByValue(CredentialOffer),
+  /// The offer can be fetched from the URL in the `credential_offer_uri` parameter.
+  ByReference(Url),
+}
+
+impl CredentialOfferUri {
+  /// Encodes this offer as a URI for the default endpoint `openid-credential-offer://`.
+  pub fn to_uri(&self) -> Result<String> {
+    self.to_uri_with_endpoint(CREDENTIAL_OFFER_ENDPOINT)
+  }
+
+  /// Encodes this offer as a URI for the Wallet's credential offer endpoint `endpoint`.
+  pub fn to_uri_with_endpoint(&self, endpoint: &str) -> Result<String> {
+    Ok(match self {
+      Self::ByValue(offer) => encode_uri(endpoint, [("credential_offer", serde_json::to_string(offer)?.as_str())]),
+      Self::ByReference(url) => encode_uri(endpoint, [("credential_offer_uri", url.as_str())]),
+    })
+  }
+
+  /// Encodes this offer as a [`QrPayload`] for the default endpoint.
+  pub fn to_qr_payload(&self) -> Result<QrPayload> {
+    self.to_uri().map(QrPayload::new)
+  }
+
+  /// Parses a credential offer URI, regardless of its endpoint.
+  pub fn parse(uri: &str) -> Result<Self> {
+    let (_, params) = decode_uri(uri)?;
+    match (
+      query_param(&params, "credential_offer")?,
+      query_param(&params, "credential_offer_uri")?,
+    ) {
+      (Some(offer), None) => Ok(Self::ByValue(serde_json::from_str(offer)?)),
+      (None, Some(url)) => Url::parse(url)
+        .map(Self::ByReference)
+        .map_err(|e| Error::InvalidUri(e.to_string())),
+      _ => Err(Error::InvalidUri(
+        "exactly one of \"credential_offer\" and \"credential_offer_uri\" must be present".to_owned(),
+      )),
+    }
+  }
+}
+
+impl From<CredentialOffer> for CredentialOfferUri {
+  fn from(offer: CredentialOffer) -> Self {
+    Self::ByValue(offer)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn offer() -> CredentialOffer {
+    CredentialOffer::builder(Url::parse("https://issuer.example.com").unwrap())
+      .credential_configuration_id("UniversityDegree")
+      .pre_authorized_code(
+        "adhjhdjajkdkhjhdj",
+        Some(TxCode::new(TxCodeInputMode::Numeric, 4).description("Check your e-mail")),
+      )
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn credential_offer_serialization() {
+    let expected = json!({
+      "credential_issuer": "https://issuer.example.com/",
+      "credential_configuration_ids": ["UniversityDegree"],
+      "grants": {
+        "urn:ietf:params:oauth:grant-type:pre-authorized_code": {
+          "pre-authorized_code": "adhjhdjajkdkhjhdj",
+          "tx_code": { "input_mode": "numeric", "length": 4, "description": "Check your e-mail" }
+        }
+      }
+    });
+
+    assert_eq!(serde_json::to_value(offer()).unwrap(), expected);
+  }
+
+  #[test]
+  fn offer_without_configurations_fails() {
+    let result = CredentialOffer::builder(Url::parse("https://issuer.example.com").unwrap()).build();
+    assert!(matches!(result, Err(Error::MissingCredentialConfiguration)));
+  }
+
+  #[test]
+  fn credential_offer_uri_roundtrip() {
+    let by_value = CredentialOfferUri::from(offer());
+    let uri = by_value.to_uri().unwrap();
+    assert!(uri.starts_with("openid-credential-offer://?credential_offer=%7B"));
+    assert_eq!(CredentialOfferUri::parse(&uri).unwrap(), by_value);
+
+    let by_reference = CredentialOfferUri::ByReference(Url::parse("https://issuer.example.com/offers/123").unwrap());
+    let uri = by_reference
+      .to_uri_with_endpoint("https://wallet.example.com/offer")
+      .unwrap();
+    assert_eq!(
+      uri,
+      "https://wallet.example.com/offer?credential_offer_uri=https%3A%2F%2Fissuer.example.com%2Foffers%2F123"
+    );
+    assert_eq!(CredentialOfferUri::parse(&uri).unwrap(), by_reference);
+
+    assert!(CredentialOfferUri::parse("openid-credential-offer://?foo=bar").is_err());
+  }
+}
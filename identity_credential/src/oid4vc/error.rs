@@ -0,0 +1,29 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Errors that may occur when building or parsing OpenID4VC URIs.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+  /// A credential offer must reference at least one credential configuration.
+  #[error("a credential offer must reference at least one credential configuration")]
+  MissingCredentialConfiguration,
+  /// The given URI is not a valid credential offer or authorization request.
+  #[error("invalid URI: {0}")]
+  InvalidUri(String),
+  /// Failed to (de)serialize a JSON payload.
+  #[error("invalid JSON payload")]
+  Json(#[from] serde_json::Error),
+  /// Failed to compress or decompress a QR payload.
+  #[error("QR payload compression failed")]
+  Compression(#[source] std::io::Error),
+  /// A compressed QR payload is not valid.
+  #[error("invalid compressed QR payload")]
+  InvalidCompressedPayload(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// A compressed QR payload inflates to more than the given number of bytes.
+  #[error("compressed QR payload exceeds {0} bytes once decompressed")]
+  DecompressedPayloadTooLarge(usize),
+}
+
+/// Either a value of type `T` or an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
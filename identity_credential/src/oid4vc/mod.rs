@@ -0,0 +1,50 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for [OpenID for Verifiable Credential Issuance](https://openid.net/specs/openid-4-verifiable-credential-issuance-1_0.html)
+//! credential offers and [OpenID for Verifiable Presentations](https://openid.net/specs/openid-4-verifiable-presentations-1_0.html)
+//! authorization requests, producing URIs ready to be encoded as QR codes.
+
+mod authorization_request;
+mod credential_offer;
+mod error;
+mod qr;
+
+pub use authorization_request::*;
+pub use credential_offer::*;
+pub use error::*;
+pub use qr::*;
+
+use url::form_urlencoded;
+
+/// Appends `params` to `endpoint` as an `application/x-www-form-urlencoded` query.
+fn encode_uri<'a>(endpoint: &str, params: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+  let query = form_urlencoded::Serializer::new(String::new())
+    .extend_pairs(params)
+    .finish();
+  format!("{endpoint}?{query}")
+}
+
+/// Splits `uri` into its endpoint and decoded query parameters.
+pub(crate) fn decode_uri(uri: &str) -> Result<(&str, Vec<(String, String)>)> {
+  let (endpoint, query) = uri
+    .split_once('?')
+    .ok_or_else(|| Error::InvalidUri("missing query parameters".to_owned()))?;
+  let params = form_urlencoded::parse(query.as_bytes()).into_owned().collect();
+
+  Ok((endpoint, params))
+}
+
+/// Returns the value of the unique query parameter `name`.
+pub(crate) fn query_param<'p>(params: &'p [(String, String)], name: &str) -> Result<Option<&'p str>> {
+  let mut values = params
+    .iter()
+    .filter(|(key, _)| key == name)
+    .map(|(_, value)| value.as_str());
+  let value = values.next();
+  if values.next().is_some() {
+    return Err(Error::InvalidUri(format!("duplicated query parameter \"{name}\"")));
+  }
+
+  Ok(value)
+}
@@ -0,0 +1,222 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::io::Read;
+use std::io::Write;
+
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+
+use super::Error;
+use super::Result;
+
+/// Up to this size, payloads result in QR codes that are reliably scanned from screens and printouts alike.
+pub const QR_COMFORTABLE_MAX_BYTES: usize = 300;
+
+/// The maximum size of a payload decompressed by [`QrPayload::decompress`].
+pub const QR_DECOMPRESSED_MAX_BYTES: usize = 64 * 1024;
+
+/// The error correction level of a QR code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum QrErrorCorrection {
+  /// About 7% of the code can be restored.
+  Low,
+  /// About 15% of the code can be restored.
+  Medium,
+  /// About 25% of the code can be restored.
+  Quartile,
+  /// About 30% of the code can be restored.
+  High,
+}
+
+impl QrErrorCorrection {
+  /// Returns the number of bytes the largest QR code (version 40) can hold in byte mode at this level.
+  pub const fn max_bytes(&self) -> usize {
+    match self {
+      Self::Low => 2953,
+      Self::Medium => 2331,
+      Self::Quartile => 1663,
+      Self::High => 1273,
+    }
+  }
+}
+
+/// How well a [`QrPayload`] lends itself to be encoded as a QR code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QrGuidance {
+  /// The payload is small enough to be reliably scanned.
+  Comfortable,
+  /// The payload fits in a QR code with [`QrErrorCorrection::Medium`] error correction, but the resulting
+  /// code is dense and may be hard to scan. Passing the payload by reference is advised.
+  Dense,
+  /// The payload doesn't fit in a QR code with [`QrErrorCorrection::Medium`] error correction and must be
+  /// passed by reference.
+  TooLarge,
+}
+
+/// A string ready to be encoded as a QR code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QrPayload(String);
+
+impl QrPayload {
+  /// Creates a new [`QrPayload`].
+  pub fn new(data: impl Into<String>) -> Self {
+    Self(data.into())
+  }
+
+  /// Returns the payload as a string slice.
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  /// Returns the payload's size in bytes.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns whether the payload is empty.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Returns whether the payload fits in a QR code with error correction `level`.
+  pub fn fits(&self, level: QrErrorCorrection) -> bool {
+    self.len() <= level.max_bytes()
+  }
+
+  /// Returns the highest error correction level at which this payload fits in a QR code, if any.
+  pub fn max_error_correction(&self) -> Option<QrErrorCorrection> {
+    [
+      QrErrorCorrection::High,
+      QrErrorCorrection::Quartile,
+      QrErrorCorrection::Medium,
+      QrErrorCorrection::Low,
+    ]
+    .into_iter()
+    .find(|level| self.fits(*level))
+  }
+
+  /// Returns how well this payload lends itself to be encoded as a QR code.
+  pub fn guidance(&self) -> QrGuidance {
+    if self.len() <= QR_COMFORTABLE_MAX_BYTES {
+      QrGuidance::Comfortable
+    } else if self.fits(QrErrorCorrection::Medium) {
+      QrGuidance::Dense
+    } else {
+      QrGuidance::TooLarge
+    }
+  }
+
+  /// Returns this payload compressed with DEFLATE and encoded in base64url.
+  ///
+  /// # Warning
+  /// Compressed payloads are not part of the OpenID4VC specifications: only use them with Wallets known
+  /// to support them - see [`QrPayload::decompress`].
+  pub fn compress(&self) -> Result<QrPayload> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(self.0.as_bytes()).map_err(Error::Compression)?;
+    let compressed = encoder.finish().map_err(Error::Compression)?;
+
+    Ok(Self(BaseEncoding::encode(&compressed, Base::Base64Url)))
+  }
+
+  /// Reverses [`QrPayload::compress`].
+  ///
+  /// # Errors
+  /// Fails with [`Error::DecompressedPayloadTooLarge`] if the payload inflates to more than
+  /// [`QR_DECOMPRESSED_MAX_BYTES`].
+  pub fn decompress(compressed: &str) -> Result<QrPayload> {
+    let bytes =
+      BaseEncoding::decode(compressed, Base::Base64Url).map_err(|e| Error::InvalidCompressedPayload(e.into()))?;
+    // Reading one byte past the limit tells apart payloads that exceed it from those that fill it exactly.
+    let mut decompressed = Vec::new();
+    DeflateDecoder::new(bytes.as_slice())
+      .take(QR_DECOMPRESSED_MAX_BYTES as u64 + 1)
+      .read_to_end(&mut decompressed)
+      .map_err(Error::Compression)?;
+    if decompressed.len() > QR_DECOMPRESSED_MAX_BYTES {
+      return Err(Error::DecompressedPayloadTooLarge(QR_DECOMPRESSED_MAX_BYTES));
+    }
+
+    String::from_utf8(decompressed)
+      .map(Self)
+      .map_err(|e| Error::InvalidCompressedPayload(e.into()))
+  }
+
+  /// Consumes this payload, returning the underlying string.
+  pub fn into_string(self) -> String {
+    self.0
+  }
+}
+
+impl AsRef<str> for QrPayload {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Display for QrPayload {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl From<QrPayload> for String {
+  fn from(payload: QrPayload) -> Self {
+    payload.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn guidance_depends_on_size() {
+    assert_eq!(QrPayload::new("a".repeat(100)).guidance(), QrGuidance::Comfortable);
+    assert_eq!(QrPayload::new("a".repeat(1000)).guidance(), QrGuidance::Dense);
+    assert_eq!(QrPayload::new("a".repeat(3000)).guidance(), QrGuidance::TooLarge);
+
+    assert_eq!(
+      QrPayload::new("a".repeat(1500)).max_error_correction(),
+      Some(QrErrorCorrection::Quartile)
+    );
+    assert_eq!(QrPayload::new("a".repeat(3000)).max_error_correction(), None);
+  }
+
+  #[test]
+  fn compression_roundtrip() {
+    let payload = QrPayload::new(format!(
+      "openid-credential-offer://?credential_offer={}",
+      "a".repeat(1000)
+    ));
+    let compressed = payload.compress().unwrap();
+
+    assert!(compressed.len() < payload.len());
+    assert_eq!(QrPayload::decompress(compressed.as_str()).unwrap(), payload);
+    assert!(QrPayload::decompress("not base64!").is_err());
+  }
+
+  #[test]
+  fn decompression_is_bounded() {
+    let fitting = QrPayload::new("a".repeat(QR_DECOMPRESSED_MAX_BYTES))
+      .compress()
+      .unwrap();
+    assert_eq!(
+      QrPayload::decompress(fitting.as_str()).unwrap().len(),
+      QR_DECOMPRESSED_MAX_BYTES
+    );
+
+    let bomb = QrPayload::new("a".repeat(QR_DECOMPRESSED_MAX_BYTES + 1))
+      .compress()
+      .unwrap();
+    assert!(matches!(
+      QrPayload::decompress(bomb.as_str()),
+      Err(Error::DecompressedPayloadTooLarge(QR_DECOMPRESSED_MAX_BYTES))
+    ));
+  }
+}
@@ -0,0 +1,621 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_core::common::StringOrUrl;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jwu::decode_b64;
+use sd_jwt::KeyBindingJwtBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::ClientIdScheme;
+use super::Error;
+use super::Result;
+use crate::oid4vc::decode_uri;
+use crate::oid4vc::query_param;
+use crate::oid4vc::AuthorizationRequestUri;
+use crate::oid4vc::RequestUriMethod;
+use crate::presentation::JwtPresentationOptions;
+use crate::presentation_exchange::PresentationDefinition;
+
+/// The only response type supported by OpenID4VP.
+const VP_TOKEN: &str = "vp_token";
+
+/// Query parameters whose value is a JSON document when the request is passed by value.
+const JSON_PARAMS: &[&str] = &["presentation_definition", "client_metadata"];
+
+/// How the Wallet sends its authorization response to the Verifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ResponseMode {
+  /// The response is sent as the fragment of the redirect URI.
+  #[serde(rename = "fragment")]
+  Fragment,
+  /// The response is sent as the query of the redirect URI.
+  #[serde(rename = "query")]
+  Query,
+  /// The response is `POST`ed to the response URI.
+  #[serde(rename = "direct_post")]
+  DirectPost,
+  /// The response is encrypted and `POST`ed to the response URI.
+  #[serde(rename = "direct_post.jwt")]
+  DirectPostJwt,
+}
+
+/// An [OpenID4VP](https://openid.net/specs/openid-4-verifiable-presentations-1_0.html) authorization request,
+/// asking a Wallet for the presentation of credentials described by a DIF presentation definition.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorizationRequest {
+  /// The requested response type, i.e. `vp_token`.
+  pub response_type: String,
+  /// The Verifier's client identifier, possibly prefixed by its [`ClientIdScheme`].
+  pub client_id: String,
+  /// The client identifier scheme, as a separate parameter. Only used by drafts of OpenID4VP.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub client_id_scheme: Option<String>,
+  /// How the response must be sent. Defaults to [`ResponseMode::Fragment`] when unset.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub response_mode: Option<ResponseMode>,
+  /// The URI the response must be `POST`ed to, for the `direct_post` response modes.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub response_uri: Option<Url>,
+  /// The URI the Wallet redirects to with the response, for the other response modes.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub redirect_uri: Option<Url>,
+  /// The nonce presentations must be bound to.
+  pub nonce: String,
+  /// An opaque value the response must echo.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub state: Option<String>,
+  /// The presentation definition describing the requested credentials.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub presentation_definition: Option<PresentationDefinition>,
+  /// The URL the presentation definition can be fetched from.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub presentation_definition_uri: Option<Url>,
+  /// The Verifier's metadata, e.g. the formats it supports.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub client_metadata: Option<Object>,
+  /// Miscellaneous parameters, e.g. the `iss` and `aud` claims of a request object.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl AuthorizationRequest {
+  /// Creates a new [`AuthorizationRequest`] from the Verifier `client_id`, asking for the credentials described by
+  /// `presentation_definition` bound to `nonce`.
+  pub fn new(
+    client_id: impl Into<String>,
+    nonce: impl Into<String>,
+    presentation_definition: PresentationDefinition,
+  ) -> Self {
+    Self {
+      response_type: VP_TOKEN.to_owned(),
+      client_id: client_id.into(),
+      client_id_scheme: None,
+      response_mode: None,
+      response_uri: None,
+      redirect_uri: None,
+      nonce: nonce.into(),
+      state: None,
+      presentation_definition: Some(presentation_definition),
+      presentation_definition_uri: None,
+      client_metadata: None,
+      properties: Object::new(),
+    }
+  }
+
+  /// Asks the Wallet to `POST` its response to `response_uri`.
+  #[must_use]
+  pub fn response_uri(mut self, response_uri: Url) -> Self {
+    self.response_mode = Some(ResponseMode::DirectPost);
+    self.response_uri = Some(response_uri);
+    self
+  }
+
+  /// Sets the opaque value the response must echo.
+  #[must_use]
+  pub fn state(mut self, state: impl Into<String>) -> Self {
+    self.state = Some(state.into());
+    self
+  }
+
+  /// Returns the scheme of this request's client identifier, together with the identifier proper.
+  pub fn client_id_scheme(&self) -> Result<(ClientIdScheme, &str)> {
+    ClientIdScheme::split(&self.client_id, self.client_id_scheme.as_deref())
+  }
+
+  /// Returns options for JWT presentations answering this request, i.e. with this request's client identifier as
+  /// audience.
+  ///
+  /// The request's `nonce` must be set in the presentation's JWS header when signing it.
+  pub fn jwt_presentation_options(&self) -> JwtPresentationOptions {
+    let audience = StringOrUrl::parse(&self.client_id).expect("parsing is infallible");
+    JwtPresentationOptions::default().audience(audience)
+  }
+
+  /// Returns a builder for the Key Binding JWT of SD-JWTs answering this request, i.e. with this request's client
+  /// identifier as audience and its nonce.
+  pub fn key_binding_jwt_builder(&self) -> KeyBindingJwtBuilder {
+    KeyBindingJwtBuilder::new()
+      .aud(self.client_id.clone())
+      .nonce(self.nonce.clone())
+      .iat(Timestamp::now_utc().to_unix())
+  }
+
+  /// Checks the structure of this request.
+  pub fn check_structure(&self) -> Result<()> {
+    if !self
+      .response_type
+      .split(' ')
+      .any(|response_type| response_type == VP_TOKEN)
+    {
+      return Err(Error::InvalidRequest(format!(
+        "unsupported response_type \"{}\"",
+        self.response_type
+      )));
+    }
+    if self.nonce.is_empty() {
+      return Err(Error::InvalidRequest("missing nonce".to_owned()));
+    }
+    if self.presentation_definition.is_some() && self.presentation_definition_uri.is_some() {
+      return Err(Error::InvalidRequest(
+        "presentation_definition and presentation_definition_uri are mutually exclusive".to_owned(),
+      ));
+    }
+    match self.response_mode {
+      Some(ResponseMode::DirectPost | ResponseMode::DirectPostJwt) => {
+        if self.response_uri.is_none() {
+          return Err(Error::InvalidRequest("missing response_uri".to_owned()));
+        }
+        if self.redirect_uri.is_some() {
+          return Err(Error::InvalidRequest(
+            "redirect_uri must not be set with the direct_post response mode".to_owned(),
+          ));
+        }
+      }
+      _ if self.response_uri.is_some() => {
+        return Err(Error::InvalidRequest(
+          "response_uri is only allowed with the direct_post response mode".to_owned(),
+        ));
+      }
+      _ => {}
+    }
+
+    Ok(())
+  }
+}
+
+/// A type fetching the resources an [`AuthorizationRequest`] passes by reference, i.e. request objects and
+/// presentation definitions.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait RequestFetcher {
+  /// Fetches the resource at `url` using `method`, returning the response body.
+  async fn fetch(
+    &self,
+    url: &Url,
+    method: RequestUriMethod,
+  ) -> std::result::Result<String, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Criteria for validating an [`UnverifiedAuthorizationRequest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AuthorizationRequestValidationOptions {
+  /// The client identifier schemes the Wallet accepts. Defaults to [`ClientIdScheme::RedirectUri`] and
+  /// [`ClientIdScheme::DecentralizedIdentifier`].
+  pub allowed_client_id_schemes: Vec<ClientIdScheme>,
+  /// The identifiers of the Verifiers pre-registered with the Wallet.
+  pub pre_registered_clients: Vec<String>,
+}
+
+impl Default for AuthorizationRequestValidationOptions {
+  fn default() -> Self {
+    Self {
+      allowed_client_id_schemes: vec![ClientIdScheme::RedirectUri, ClientIdScheme::DecentralizedIdentifier],
+      pre_registered_clients: Vec::new(),
+    }
+  }
+}
+
+impl AuthorizationRequestValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the client identifier schemes the Wallet accepts.
+  pub fn allowed_client_id_schemes(mut self, schemes: impl IntoIterator<Item = ClientIdScheme>) -> Self {
+    self.allowed_client_id_schemes = schemes.into_iter().collect();
+    self
+  }
+
+  /// Accepts requests from the pre-registered Verifier `client_id`, allowing [`ClientIdScheme::PreRegistered`].
+  pub fn pre_registered_client(mut self, client_id: impl Into<String>) -> Self {
+    if !self.allowed_client_id_schemes.contains(&ClientIdScheme::PreRegistered) {
+      self.allowed_client_id_schemes.push(ClientIdScheme::PreRegistered);
+    }
+    self.pre_registered_clients.push(client_id.into());
+    self
+  }
+}
+
+/// An [`AuthorizationRequest`] received by a Wallet, whose Verifier is yet to be authenticated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnverifiedAuthorizationRequest {
+  request: AuthorizationRequest,
+  request_object: Option<RequestObject>,
+}
+
+/// A request passed as a JWT, either by value or by reference.
+#[derive(Debug, Clone, PartialEq)]
+struct RequestObject {
+  jwt: String,
+  signed: bool,
+}
+
+impl UnverifiedAuthorizationRequest {
+  /// Parses an authorization request URI, regardless of its endpoint, fetching the request object and the
+  /// presentation definition through `fetcher` if they are passed by reference.
+  pub async fn from_uri<F>(uri: &str, fetcher: &F) -> Result<Self>
+  where
+    F: RequestFetcher + ?Sized,
+  {
+    let (_, params) = decode_uri(uri)?;
+    let param = |name: &str| query_param(&params, name);
+
+    let jwt = match (param("request")?, param("request_uri")?) {
+      (Some(_), Some(_)) => {
+        return Err(Error::InvalidRequest(
+          "request and request_uri are mutually exclusive".to_owned(),
+        ))
+      }
+      (Some(jwt), None) => Some(jwt.to_owned()),
+      (None, Some(_)) => {
+        let AuthorizationRequestUri {
+          request_uri,
+          request_uri_method,
+          ..
+        } = AuthorizationRequestUri::parse(uri)?;
+        let jwt = fetcher
+          .fetch(&request_uri, request_uri_method.unwrap_or(RequestUriMethod::Get))
+          .await
+          .map_err(|source| Error::Fetch {
+            url: request_uri.to_string(),
+            source,
+          })?;
+        Some(jwt)
+      }
+      (None, None) => None,
+    };
+
+    let mut this = match jwt {
+      Some(jwt) => {
+        let this = Self::from_request_object(jwt.trim())?;
+        // The client identifier outside of the request object must match the one inside.
+        if let Some(client_id) = param("client_id")? {
+          if client_id != this.request.client_id {
+            return Err(Error::ClientAuthentication(
+              "client_id doesn't match the request object's".to_owned(),
+            ));
+          }
+        }
+        this
+      }
+      None => {
+        let mut object = Object::new();
+        for (key, value) in &params {
+          let value = if JSON_PARAMS.contains(&key.as_str()) {
+            serde_json::from_str(value)?
+          } else {
+            Value::String(value.clone())
+          };
+          object.insert(key.clone(), value);
+        }
+        Self {
+          request: serde_json::from_value(Value::Object(object))?,
+          request_object: None,
+        }
+      }
+    };
+
+    if let (None, Some(uri)) = (
+      &this.request.presentation_definition,
+      &this.request.presentation_definition_uri,
+    ) {
+      let definition = fetcher
+        .fetch(uri, RequestUriMethod::Get)
+        .await
+        .map_err(|source| Error::Fetch {
+          url: uri.to_string(),
+          source,
+        })?;
+      this.request.presentation_definition = Some(serde_json::from_str(&definition)?);
+      this.request.presentation_definition_uri = None;
+    }
+
+    Ok(this)
+  }
+
+  /// Parses a request object, i.e. an authorization request encoded as a - possibly unsigned - JWT.
+  pub fn from_request_object(jwt: &str) -> Result<Self> {
+    let invalid = || Error::InvalidRequest("malformed request object".to_owned());
+    let mut segments = jwt.split('.');
+    let (Some(header), Some(claims), Some(signature), None) =
+      (segments.next(), segments.next(), segments.next(), segments.next())
+    else {
+      return Err(invalid());
+    };
+
+    let header: Object = serde_json::from_slice(&decode_b64(header).map_err(|_| invalid())?)?;
+    let request: AuthorizationRequest = serde_json::from_slice(&decode_b64(claims).map_err(|_| invalid())?)?;
+    let unsigned = header.get("alg").and_then(Value::as_str) == Some("none");
+    if unsigned != signature.is_empty() {
+      return Err(invalid());
+    }
+
+    Ok(Self {
+      request,
+      request_object: Some(RequestObject {
+        jwt: jwt.to_owned(),
+        signed: !unsigned,
+      }),
+    })
+  }
+
+  /// Returns the request, whose content must not be trusted before it's verified.
+  pub fn unverified_request(&self) -> &AuthorizationRequest {
+    &self.request
+  }
+
+  /// Returns the DID of the Verifier if it uses the [`ClientIdScheme::DecentralizedIdentifier`] scheme, i.e. whose
+  /// DID Document must be resolved to verify the request.
+  pub fn client_did(&self) -> Option<CoreDID> {
+    match self.request.client_id_scheme() {
+      Ok((ClientIdScheme::DecentralizedIdentifier, did)) => CoreDID::parse(did).ok(),
+      _ => None,
+    }
+  }
+
+  /// Authenticates the Verifier according to the request's client identifier scheme, returning the verified request.
+  ///
+  /// The following schemes are supported:
+  /// - [`ClientIdScheme::RedirectUri`]: the request must not be signed, and the client identifier must be the URI the
+  ///   response is sent to;
+  /// - [`ClientIdScheme::DecentralizedIdentifier`]: the request must be signed by a verification method of the
+  ///   Verifier's `client_document`;
+  /// - [`ClientIdScheme::PreRegistered`]: the client identifier must be pre-registered in `options`. A signed request
+  ///   is verified against `client_document`.
+  pub fn verify<V, DOC>(
+    self,
+    verifier: &V,
+    client_document: Option<&DOC>,
+    options: &AuthorizationRequestValidationOptions,
+  ) -> Result<AuthorizationRequest>
+  where
+    V: JwsVerifier,
+    DOC: AsRef<CoreDocument>,
+  {
+    self.request.check_structure()?;
+    let (scheme, identifier) = self.request.client_id_scheme()?;
+    if !options.allowed_client_id_schemes.contains(&scheme) {
+      return Err(Error::UnsupportedClientIdScheme(scheme.to_string()));
+    }
+
+    let signed_jwt = self
+      .request_object
+      .as_ref()
+      .filter(|object| object.signed)
+      .map(|object| object.jwt.as_str());
+    match scheme {
+      ClientIdScheme::RedirectUri => {
+        if signed_jwt.is_some() {
+          return Err(Error::ClientAuthentication(
+            "requests of the redirect_uri scheme must not be signed".to_owned(),
+          ));
+        }
+        let target = self
+          .request
+          .response_uri
+          .as_ref()
+          .or(self.request.redirect_uri.as_ref());
+        if target.map(|url| url.as_str()) != Some(identifier) {
+          return Err(Error::ClientAuthentication(
+            "client_id doesn't match the response or redirect URI".to_owned(),
+          ));
+        }
+      }
+      ClientIdScheme::DecentralizedIdentifier => {
+        let jwt = signed_jwt.ok_or_else(|| {
+          Error::ClientAuthentication("requests of the decentralized_identifier scheme must be signed".to_owned())
+        })?;
+        let did = CoreDID::parse(identifier).map_err(|e| Error::ClientAuthentication(e.to_string()))?;
+        let document = client_document
+          .map(AsRef::as_ref)
+          .filter(|document| document.id() == &did)
+          .ok_or_else(|| Error::MissingDocument(did.to_string()))?;
+        let decoded = document
+          .verify_jws(jwt, None, verifier, &JwsVerificationOptions::default())
+          .map_err(Error::RequestSignature)?;
+        let kid = decoded
+          .protected
+          .kid()
+          .and_then(|kid| DIDUrl::parse(kid).ok())
+          .ok_or_else(|| Error::ClientAuthentication("request object kid is not a DID URL".to_owned()))?;
+        if kid.did() != &did {
+          return Err(Error::ClientAuthentication(
+            "request object is not signed by the client".to_owned(),
+          ));
+        }
+      }
+      ClientIdScheme::PreRegistered => {
+        if !options.pre_registered_clients.iter().any(|client| client == identifier) {
+          return Err(Error::ClientAuthentication(format!(
+            "client \"{identifier}\" is not pre-registered"
+          )));
+        }
+        if let Some(jwt) = signed_jwt {
+          let document = client_document.ok_or_else(|| Error::MissingDocument(identifier.to_owned()))?;
+          document
+            .as_ref()
+            .verify_jws(jwt, None, verifier, &JwsVerificationOptions::default())
+            .map_err(Error::RequestSignature)?;
+        }
+      }
+      scheme => return Err(Error::UnsupportedClientIdScheme(scheme.to_string())),
+    }
+
+    Ok(self.request)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use identity_verification::jwk::Jwk;
+  use identity_verification::jws::SignatureVerificationError;
+  use identity_verification::jws::VerificationInput;
+  use identity_verification::jwu::encode_b64;
+  use serde_json::json;
+  use url::form_urlencoded;
+
+  use super::*;
+
+  struct TestFetcher(HashMap<String, String>);
+
+  #[async_trait]
+  impl RequestFetcher for TestFetcher {
+    async fn fetch(
+      &self,
+      url: &Url,
+      _method: RequestUriMethod,
+    ) -> std::result::Result<String, Box<dyn std::error::Error + Send + Sync + 'static>> {
+      self.0.get(url.as_str()).cloned().ok_or_else(|| "not found".into())
+    }
+  }
+
+  struct NoVerifier;
+
+  impl JwsVerifier for NoVerifier {
+    fn verify(&self, _: VerificationInput, _: &Jwk) -> std::result::Result<(), SignatureVerificationError> {
+      unreachable!()
+    }
+  }
+
+  fn definition() -> Value {
+    json!({ "id": "pd-1", "input_descriptors": [{ "id": "degree", "constraints": { "fields": [] } }] })
+  }
+
+  fn unsigned_request_object(claims: Value) -> String {
+    format!(
+      "{}.{}.",
+      encode_b64(r#"{"alg":"none"}"#),
+      encode_b64(serde_json::to_vec(&claims).unwrap())
+    )
+  }
+
+  #[tokio::test]
+  async fn request_by_value() {
+    let uri = format!(
+      "openid4vp://?response_type=vp_token&client_id=redirect_uri%3Ahttps%3A%2F%2Fverifier.example.com%2Fcb\
+       &response_mode=direct_post&response_uri=https%3A%2F%2Fverifier.example.com%2Fcb&nonce=n-0S6_WzA2Mj\
+       &presentation_definition={}",
+      form_urlencoded::byte_serialize(definition().to_string().as_bytes()).collect::<String>()
+    );
+    let request = UnverifiedAuthorizationRequest::from_uri(&uri, &TestFetcher(HashMap::new()))
+      .await
+      .unwrap();
+    assert_eq!(request.client_did(), None);
+
+    let request = request
+      .verify::<_, CoreDocument>(&NoVerifier, None, &AuthorizationRequestValidationOptions::default())
+      .unwrap();
+    assert_eq!(request.nonce, "n-0S6_WzA2Mj");
+    assert_eq!(request.presentation_definition.unwrap().id, "pd-1");
+  }
+
+  #[tokio::test]
+  async fn request_by_reference() {
+    let claims = json!({
+      "response_type": "vp_token",
+      "client_id": "redirect_uri:https://verifier.example.com/cb",
+      "redirect_uri": "https://verifier.example.com/cb",
+      "nonce": "n-0S6_WzA2Mj",
+      "presentation_definition_uri": "https://verifier.example.com/pd/1"
+    });
+    let fetcher = TestFetcher(HashMap::from([
+      (
+        "https://verifier.example.com/requests/1".to_owned(),
+        unsigned_request_object(claims),
+      ),
+      ("https://verifier.example.com/pd/1".to_owned(), definition().to_string()),
+    ]));
+
+    let uri = "openid4vp://?client_id=redirect_uri%3Ahttps%3A%2F%2Fverifier.example.com%2Fcb\
+               &request_uri=https%3A%2F%2Fverifier.example.com%2Frequests%2F1";
+    let request = UnverifiedAuthorizationRequest::from_uri(uri, &fetcher).await.unwrap();
+    assert_eq!(
+      request
+        .unverified_request()
+        .presentation_definition
+        .as_ref()
+        .unwrap()
+        .id,
+      "pd-1"
+    );
+    assert!(request
+      .clone()
+      .verify::<_, CoreDocument>(&NoVerifier, None, &AuthorizationRequestValidationOptions::default())
+      .is_ok());
+
+    // The redirect_uri scheme may be disallowed.
+    let options =
+      AuthorizationRequestValidationOptions::new().allowed_client_id_schemes([ClientIdScheme::DecentralizedIdentifier]);
+    assert!(matches!(
+      request.verify::<_, CoreDocument>(&NoVerifier, None, &options),
+      Err(Error::UnsupportedClientIdScheme(_))
+    ));
+
+    // The client identifier outside the request object must match the one inside.
+    let uri = "openid4vp://?client_id=redirect_uri%3Ahttps%3A%2F%2Fattacker.example.com\
+               &request_uri=https%3A%2F%2Fverifier.example.com%2Frequests%2F1";
+    assert!(matches!(
+      UnverifiedAuthorizationRequest::from_uri(uri, &fetcher).await,
+      Err(Error::ClientAuthentication(_))
+    ));
+  }
+
+  #[test]
+  fn redirect_uri_scheme_checks() {
+    let mut claims = json!({
+      "response_type": "vp_token",
+      "client_id": "redirect_uri:https://verifier.example.com/cb",
+      "response_mode": "direct_post",
+      "response_uri": "https://attacker.example.com/cb",
+      "nonce": "n-0S6_WzA2Mj",
+      "presentation_definition": definition()
+    });
+    let request =
+      UnverifiedAuthorizationRequest::from_request_object(&unsigned_request_object(claims.clone())).unwrap();
+    assert!(matches!(
+      request.verify::<_, CoreDocument>(&NoVerifier, None, &AuthorizationRequestValidationOptions::default()),
+      Err(Error::ClientAuthentication(_))
+    ));
+
+    claims["client_id"] = json!("verifier-42");
+    let request = UnverifiedAuthorizationRequest::from_request_object(&unsigned_request_object(claims)).unwrap();
+    let options = AuthorizationRequestValidationOptions::new().pre_registered_client("verifier-42");
+    assert!(request.verify::<_, CoreDocument>(&NoVerifier, None, &options).is_ok());
+  }
+}
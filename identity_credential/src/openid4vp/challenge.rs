@@ -0,0 +1,267 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// The number of random bytes of a minted nonce.
+const NONCE_LENGTH: usize = 32;
+
+/// A nonce minted by a Verifier for a session, to be bound to the presentations - e.g. the KB-JWTs of SD-JWTs - a
+/// Wallet submits during that session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Challenge {
+  /// The nonce, to be set as the `nonce` of an [`AuthorizationRequest`](super::AuthorizationRequest).
+  pub nonce: String,
+  /// Identifier of the Verifier's session the nonce was minted for.
+  pub session_id: String,
+  /// The time after which the nonce is no longer accepted.
+  pub expires_at: Timestamp,
+}
+
+impl Challenge {
+  /// Returns whether the nonce is expired at `now`.
+  pub fn is_expired_at(&self, now: Timestamp) -> bool {
+    self.expires_at <= now
+  }
+}
+
+/// Errors that may occur when minting or consuming a [`Challenge`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ChallengeError {
+  /// The nonce was never minted, or was already consumed.
+  #[error("unknown or already used nonce")]
+  UnknownNonce,
+  /// The nonce was minted for another session.
+  #[error("nonce was not minted for session `{0}`")]
+  SessionMismatch(String),
+  /// The nonce is expired.
+  #[error("nonce expired at {0}")]
+  Expired(Timestamp),
+  /// Failed to generate a random nonce.
+  #[error("failed to generate a nonce")]
+  Generation(#[source] BoxError),
+  /// The [`ChallengeStore`] failed.
+  #[error("challenge store failure")]
+  Store(#[source] BoxError),
+}
+
+/// Persists the [`Challenge`]s minted by a [`ChallengeManager`] until they are consumed.
+///
+/// Verifiers running multiple instances must share the store among them, e.g. by backing it with a database.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait ChallengeStore {
+  /// Persists `challenge`.
+  async fn insert(&self, challenge: Challenge) -> Result<(), BoxError>;
+
+  /// Removes and returns the challenge of `nonce`, if any.
+  ///
+  /// Removal must be atomic, so that a nonce can be taken at most once even under concurrent requests.
+  async fn take(&self, nonce: &str) -> Result<Option<Challenge>, BoxError>;
+
+  /// Removes all challenges expired at `now`.
+  async fn purge_expired(&self, now: Timestamp) -> Result<(), BoxError>;
+}
+
+/// An in-memory [`ChallengeStore`].
+#[derive(Debug, Default)]
+pub struct MemChallengeStore {
+  challenges: RwLock<HashMap<String, Challenge>>,
+}
+
+impl MemChallengeStore {
+  /// Creates a new, empty [`MemChallengeStore`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the number of stored challenges, including expired ones.
+  pub fn len(&self) -> usize {
+    self.challenges.read().expect("challenge store lock poisoned").len()
+  }
+
+  /// Returns whether the store is empty.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ChallengeStore for MemChallengeStore {
+  async fn insert(&self, challenge: Challenge) -> Result<(), BoxError> {
+    self
+      .challenges
+      .write()
+      .expect("challenge store lock poisoned")
+      .insert(challenge.nonce.clone(), challenge);
+    Ok(())
+  }
+
+  async fn take(&self, nonce: &str) -> Result<Option<Challenge>, BoxError> {
+    Ok(
+      self
+        .challenges
+        .write()
+        .expect("challenge store lock poisoned")
+        .remove(nonce),
+    )
+  }
+
+  async fn purge_expired(&self, now: Timestamp) -> Result<(), BoxError> {
+    self
+      .challenges
+      .write()
+      .expect("challenge store lock poisoned")
+      .retain(|_, challenge| !challenge.is_expired_at(now));
+    Ok(())
+  }
+}
+
+/// Mints and validates the nonces of a Verifier, following the OpenID4VP nonce lifecycle: each nonce is random,
+/// tied to a session, short-lived and accepted at most once.
+///
+/// A nonce is [minted](ChallengeManager::mint) when creating an [`AuthorizationRequest`](super::AuthorizationRequest)
+/// and [consumed](ChallengeManager::consume) before validating the response to it, e.g. with
+/// [`AuthorizationResponseValidator::validate`](super::AuthorizationResponseValidator::validate).
+#[derive(Debug)]
+pub struct ChallengeManager<S> {
+  store: S,
+  ttl: Duration,
+}
+
+impl<S: ChallengeStore> ChallengeManager<S> {
+  /// The time a nonce is valid for, unless set with [`ChallengeManager::with_ttl`].
+  pub const DEFAULT_TTL: Duration = Duration::minutes(5);
+
+  /// Creates a new [`ChallengeManager`] persisting its nonces in `store`.
+  pub fn new(store: S) -> Self {
+    Self {
+      store,
+      ttl: Self::DEFAULT_TTL,
+    }
+  }
+
+  /// Sets the time a nonce is valid for after being minted.
+  pub fn with_ttl(mut self, ttl: Duration) -> Self {
+    self.ttl = ttl;
+    self
+  }
+
+  /// Returns the underlying [`ChallengeStore`].
+  pub fn store(&self) -> &S {
+    &self.store
+  }
+
+  /// Mints and persists a new random nonce for the session `session_id`.
+  pub async fn mint(&self, session_id: impl Into<String>) -> Result<Challenge, ChallengeError> {
+    let mut bytes = [0; NONCE_LENGTH];
+    crypto::utils::rand::fill(&mut bytes).map_err(|err| ChallengeError::Generation(err.to_string().into()))?;
+    let expires_at = Timestamp::now_utc()
+      .checked_add(self.ttl)
+      .ok_or_else(|| ChallengeError::Generation("nonce expiration overflows".into()))?;
+
+    let challenge = Challenge {
+      nonce: BaseEncoding::encode(&bytes, Base::Base64Url),
+      session_id: session_id.into(),
+      expires_at,
+    };
+    self
+      .store
+      .insert(challenge.clone())
+      .await
+      .map_err(ChallengeError::Store)?;
+
+    Ok(challenge)
+  }
+
+  /// Consumes `nonce`, checking that it was minted for the session `session_id` and is not expired.
+  ///
+  /// The nonce is no longer accepted afterwards, whether it is valid or not.
+  pub async fn consume(&self, nonce: &str, session_id: &str) -> Result<Challenge, ChallengeError> {
+    let challenge = self
+      .store
+      .take(nonce)
+      .await
+      .map_err(ChallengeError::Store)?
+      .ok_or(ChallengeError::UnknownNonce)?;
+
+    if challenge.session_id != session_id {
+      return Err(ChallengeError::SessionMismatch(session_id.to_owned()));
+    }
+    if challenge.is_expired_at(Timestamp::now_utc()) {
+      return Err(ChallengeError::Expired(challenge.expires_at));
+    }
+
+    Ok(challenge)
+  }
+
+  /// Removes all expired nonces from the store.
+  pub async fn purge_expired(&self) -> Result<(), ChallengeError> {
+    self
+      .store
+      .purge_expired(Timestamp::now_utc())
+      .await
+      .map_err(ChallengeError::Store)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn nonces_are_single_use() {
+    let manager = ChallengeManager::new(MemChallengeStore::new());
+    let challenge = manager.mint("session-1").await.unwrap();
+    assert_ne!(manager.mint("session-1").await.unwrap().nonce, challenge.nonce);
+
+    manager.consume(&challenge.nonce, "session-1").await.unwrap();
+    assert!(matches!(
+      manager.consume(&challenge.nonce, "session-1").await,
+      Err(ChallengeError::UnknownNonce)
+    ));
+  }
+
+  #[tokio::test]
+  async fn nonces_are_bound_to_their_session() {
+    let manager = ChallengeManager::new(MemChallengeStore::new());
+    let challenge = manager.mint("session-1").await.unwrap();
+
+    assert!(matches!(
+      manager.consume(&challenge.nonce, "session-2").await,
+      Err(ChallengeError::SessionMismatch(_))
+    ));
+    // A failed attempt burns the nonce.
+    assert!(matches!(
+      manager.consume(&challenge.nonce, "session-1").await,
+      Err(ChallengeError::UnknownNonce)
+    ));
+  }
+
+  #[tokio::test]
+  async fn expired_nonces_are_rejected_and_purged() {
+    let manager = ChallengeManager::new(MemChallengeStore::new()).with_ttl(Duration::seconds(0));
+    let challenge = manager.mint("session-1").await.unwrap();
+    let _ = manager.mint("session-2").await.unwrap();
+
+    assert!(matches!(
+      manager.consume(&challenge.nonce, "session-1").await,
+      Err(ChallengeError::Expired(_))
+    ));
+
+    manager.purge_expired().await.unwrap();
+    assert!(manager.store().is_empty());
+  }
+}
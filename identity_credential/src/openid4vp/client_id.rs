@@ -0,0 +1,121 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use super::Error;
+use super::Result;
+
+/// How a Wallet authenticates the Verifier identified by a `client_id`, as defined in
+/// [OpenID4VP](https://openid.net/specs/openid-4-verifiable-presentations-1_0.html#name-client-identifier-prefix-an).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ClientIdScheme {
+  /// The Verifier is known to the Wallet in advance.
+  PreRegistered,
+  /// The client identifier is the Verifier's redirect or response URI. Requests must not be signed.
+  RedirectUri,
+  /// The client identifier is a DID, whose DID Document holds the key signing the request.
+  DecentralizedIdentifier,
+  /// The client identifier is a DNS name found in the certificate signing the request.
+  X509SanDns,
+  /// The client identifier is the hash of the certificate signing the request.
+  X509Hash,
+  /// The Verifier is authenticated through a Verifier attestation JWT.
+  VerifierAttestation,
+  /// The client identifier is an OpenID Federation entity identifier.
+  OpenIdFederation,
+}
+
+impl ClientIdScheme {
+  /// Returns the prefix identifying this scheme in a `client_id`, if any.
+  pub const fn prefix(&self) -> Option<&'static str> {
+    match self {
+      Self::PreRegistered => None,
+      Self::RedirectUri => Some("redirect_uri"),
+      Self::DecentralizedIdentifier => Some("decentralized_identifier"),
+      Self::X509SanDns => Some("x509_san_dns"),
+      Self::X509Hash => Some("x509_hash"),
+      Self::VerifierAttestation => Some("verifier_attestation"),
+      Self::OpenIdFederation => Some("openid_federation"),
+    }
+  }
+
+  /// Splits `client_id` into its scheme and the identifier proper.
+  ///
+  /// When set, `legacy_scheme` is the value of the `client_id_scheme` parameter used by drafts of OpenID4VP prior to
+  /// the introduction of prefixes, in which case `client_id` is returned unchanged.
+  pub fn split<'c>(client_id: &'c str, legacy_scheme: Option<&str>) -> Result<(Self, &'c str)> {
+    if let Some(scheme) = legacy_scheme {
+      let scheme = match scheme {
+        "pre-registered" => Self::PreRegistered,
+        "redirect_uri" => Self::RedirectUri,
+        "did" => Self::DecentralizedIdentifier,
+        "x509_san_dns" => Self::X509SanDns,
+        "verifier_attestation" => Self::VerifierAttestation,
+        "entity_id" => Self::OpenIdFederation,
+        scheme => return Err(Error::UnsupportedClientIdScheme(scheme.to_owned())),
+      };
+      return Ok((scheme, client_id));
+    }
+
+    // DIDs were used as is before the `decentralized_identifier` prefix was introduced.
+    if client_id.starts_with("did:") {
+      return Ok((Self::DecentralizedIdentifier, client_id));
+    }
+
+    let Some((prefix, identifier)) = client_id.split_once(':') else {
+      return Ok((Self::PreRegistered, client_id));
+    };
+    let scheme = match prefix {
+      "redirect_uri" => Self::RedirectUri,
+      "decentralized_identifier" => Self::DecentralizedIdentifier,
+      "x509_san_dns" => Self::X509SanDns,
+      "x509_hash" => Self::X509Hash,
+      "verifier_attestation" => Self::VerifierAttestation,
+      "openid_federation" => Self::OpenIdFederation,
+      // Identifiers of pre-registered clients may contain colons, e.g. `https://verifier.example.com`.
+      "http" | "https" => return Ok((Self::PreRegistered, client_id)),
+      prefix => return Err(Error::UnsupportedClientIdScheme(prefix.to_owned())),
+    };
+
+    Ok((scheme, identifier))
+  }
+}
+
+impl Display for ClientIdScheme {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.prefix().unwrap_or("pre-registered"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn split_client_id() {
+    assert_eq!(
+      ClientIdScheme::split("redirect_uri:https://verifier.example.com/cb", None).unwrap(),
+      (ClientIdScheme::RedirectUri, "https://verifier.example.com/cb")
+    );
+    assert_eq!(
+      ClientIdScheme::split("decentralized_identifier:did:iota:0x01", None).unwrap(),
+      (ClientIdScheme::DecentralizedIdentifier, "did:iota:0x01")
+    );
+    assert_eq!(
+      ClientIdScheme::split("did:iota:0x01", None).unwrap(),
+      (ClientIdScheme::DecentralizedIdentifier, "did:iota:0x01")
+    );
+    assert_eq!(
+      ClientIdScheme::split("https://verifier.example.com", Some("redirect_uri")).unwrap(),
+      (ClientIdScheme::RedirectUri, "https://verifier.example.com")
+    );
+    assert_eq!(
+      ClientIdScheme::split("verifier-42", None).unwrap(),
+      (ClientIdScheme::PreRegistered, "verifier-42")
+    );
+    assert!(ClientIdScheme::split("unknown:verifier", None).is_err());
+  }
+}
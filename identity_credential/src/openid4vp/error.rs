@@ -0,0 +1,59 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Errors that may occur when processing OpenID4VP authorization requests and responses.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+  /// The authorization request is malformed or misses a required parameter.
+  #[error("invalid authorization request: {0}")]
+  InvalidRequest(String),
+  /// The authorization request URI is malformed.
+  #[error("invalid authorization request URI")]
+  InvalidUri(#[from] crate::oid4vc::Error),
+  /// Failed to fetch a resource passed by reference, e.g. a request object.
+  #[error("failed to fetch `{url}`")]
+  Fetch {
+    /// The URL of the resource.
+    url: String,
+    /// The cause of the failure.
+    #[source]
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+  /// The client identifier scheme of the request is not supported or not allowed.
+  #[error("unsupported client identifier scheme `{0}`")]
+  UnsupportedClientIdScheme(String),
+  /// The request doesn't satisfy the rules of its client identifier scheme.
+  #[error("client authentication failed: {0}")]
+  ClientAuthentication(String),
+  /// The signature of the request object is invalid.
+  #[error("invalid request object signature")]
+  RequestSignature(#[source] identity_document::Error),
+  /// The authorization response is malformed or doesn't match its request.
+  #[error("invalid authorization response: {0}")]
+  InvalidResponse(String),
+  /// A presentation is submitted in a format that is not supported.
+  #[error("unsupported presentation format `{0}`")]
+  UnsupportedFormat(String),
+  /// The DID Document needed to validate a presentation was not provided.
+  #[error("missing DID Document of `{0}`")]
+  MissingDocument(String),
+  /// A submitted presentation is invalid.
+  #[error("invalid presentation for input descriptor \"{descriptor_id}\"")]
+  InvalidPresentation {
+    /// Identifier of the input descriptor the presentation was submitted for.
+    descriptor_id: String,
+    /// The cause of the failure.
+    #[source]
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+  /// The submitted credentials don't satisfy the request's presentation definition.
+  #[error(transparent)]
+  PresentationExchange(#[from] crate::presentation_exchange::Error),
+  /// Failed to (de)serialize a JSON payload.
+  #[error("invalid JSON payload")]
+  Json(#[from] serde_json::Error),
+}
+
+/// Either a value of type `T` or an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,24 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for [OpenID for Verifiable Presentations](https://openid.net/specs/openid-4-verifiable-presentations-1_0.html).
+//!
+//! A Wallet parses the Verifier's [`AuthorizationRequest`] - passed by value or by reference - with
+//! [`UnverifiedAuthorizationRequest`], authenticates the Verifier according to its [`ClientIdScheme`], and answers with
+//! an [`AuthorizationResponse`] carrying SD-JWT and JWT presentations, built with [`AuthorizationResponseBuilder`].
+//! The Verifier validates the response with [`AuthorizationResponseValidator`], after consuming the nonce it minted for
+//! the request with [`ChallengeManager`].
+
+mod authorization_request;
+mod challenge;
+mod client_id;
+mod error;
+mod response;
+mod verifier;
+
+pub use authorization_request::*;
+pub use challenge::*;
+pub use client_id::*;
+pub use error::*;
+pub use response::*;
+pub use verifier::*;
@@ -0,0 +1,278 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::OneOrMany;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use sd_jwt::RequiredKeyBinding;
+use sd_jwt::SdJwt;
+use serde::Deserialize;
+use serde::Serialize;
+use url::form_urlencoded;
+
+use super::AuthorizationRequest;
+use super::Error;
+use super::Result;
+use crate::credential::Jwt;
+use crate::presentation_exchange::ClaimFormat;
+use crate::presentation_exchange::DescriptorMapEntry;
+use crate::presentation_exchange::PresentationSubmission;
+use crate::validator::JwtPresentationValidatorUtils;
+
+/// The claim format of JWT encoded VC Data Model 1.1 presentations.
+pub(crate) const JWT_VP_JSON: &str = "jwt_vp_json";
+
+/// The authorization response of a Wallet, carrying the presentations requested by an [`AuthorizationRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorizationResponse {
+  /// The presentations: a single one, or an array of them.
+  pub vp_token: OneOrMany<String>,
+  /// Where each presentation is found in `vp_token` and which input descriptor it satisfies.
+  pub presentation_submission: PresentationSubmission,
+  /// The `state` of the request, if any.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub state: Option<String>,
+}
+
+impl AuthorizationResponse {
+  /// Encodes this response as an `application/x-www-form-urlencoded` body, as sent by the `direct_post` response
+  /// mode.
+  pub fn to_form_urlencoded(&self) -> Result<String> {
+    let vp_token = match &self.vp_token {
+      OneOrMany::One(presentation) => presentation.clone(),
+      many => serde_json::to_string(many)?,
+    };
+    let presentation_submission = serde_json::to_string(&self.presentation_submission)?;
+
+    let mut serializer = form_urlencoded::Serializer::new(String::new());
+    serializer.append_pair("vp_token", &vp_token);
+    serializer.append_pair("presentation_submission", &presentation_submission);
+    if let Some(state) = &self.state {
+      serializer.append_pair("state", state);
+    }
+
+    Ok(serializer.finish())
+  }
+
+  /// Decodes a response from an `application/x-www-form-urlencoded` body.
+  pub fn from_form_urlencoded(body: &str) -> Result<Self> {
+    let mut vp_token = None;
+    let mut presentation_submission = None;
+    let mut state = None;
+    for (key, value) in form_urlencoded::parse(body.as_bytes()) {
+      let slot = match key.as_ref() {
+        "vp_token" => &mut vp_token,
+        "presentation_submission" => &mut presentation_submission,
+        "state" => &mut state,
+        _ => continue,
+      };
+      if slot.replace(value.into_owned()).is_some() {
+        return Err(Error::InvalidResponse(format!("duplicated parameter \"{key}\"")));
+      }
+    }
+
+    let missing_param = |name: &str| Error::InvalidResponse(format!("missing \"{name}\" parameter"));
+    let vp_token = vp_token.ok_or_else(|| missing_param("vp_token"))?;
+    let vp_token = if vp_token.starts_with('[') {
+      OneOrMany::Many(serde_json::from_str(&vp_token)?)
+    } else {
+      OneOrMany::One(vp_token)
+    };
+    let presentation_submission = presentation_submission.ok_or_else(|| missing_param("presentation_submission"))?;
+
+    Ok(Self {
+      vp_token,
+      presentation_submission: serde_json::from_str(&presentation_submission)?,
+      state,
+    })
+  }
+
+  /// Returns the presentation found at `path` in `vp_token`, i.e. `$` for a single presentation and `$[i]` for
+  /// the i-th presentation of an array.
+  pub fn presentation(&self, path: &str) -> Result<&str> {
+    let presentation = match (&self.vp_token, path) {
+      (OneOrMany::One(presentation), "$") => Some(presentation),
+      (OneOrMany::Many(presentations), path) => path
+        .strip_prefix("$[")
+        .and_then(|path| path.strip_suffix(']'))
+        .and_then(|index| index.parse::<usize>().ok())
+        .and_then(|index| presentations.get(index)),
+      _ => None,
+    };
+
+    presentation
+      .map(String::as_str)
+      .ok_or_else(|| Error::InvalidResponse(format!("no presentation found at `{path}`")))
+  }
+
+  /// Returns the DIDs of the holders of the presentations in this response, so that their DID Documents can be
+  /// resolved before validating it.
+  pub fn holders(&self) -> Result<Vec<CoreDID>> {
+    let mut holders: Vec<CoreDID> = Vec::new();
+    for entry in &self.presentation_submission.descriptor_map {
+      let presentation = self.presentation(&entry.path)?;
+      let holder = presentation_holder(&entry.format, presentation).map_err(|source| Error::InvalidPresentation {
+        descriptor_id: entry.id.clone(),
+        source,
+      })?;
+      if !holders.contains(&holder) {
+        holders.push(holder);
+      }
+    }
+
+    Ok(holders)
+  }
+}
+
+/// Returns the DID of the holder of `presentation`, in the claim format `format`.
+pub(crate) fn presentation_holder(
+  format: &str,
+  presentation: &str,
+) -> std::result::Result<CoreDID, Box<dyn std::error::Error + Send + Sync + 'static>> {
+  match ClaimFormat::from(format) {
+    ClaimFormat::VcSdJwt => {
+      let sd_jwt = SdJwt::parse(presentation)?;
+      match sd_jwt.required_key_bind() {
+        Some(RequiredKeyBinding::Kid(kid)) => Ok(DIDUrl::parse(kid)?.did().clone()),
+        _ => Err("the SD-JWT is not bound to a DID".into()),
+      }
+    }
+    ClaimFormat::Other(format) if format == JWT_VP_JSON => Ok(
+      JwtPresentationValidatorUtils::extract_holder::<CoreDID>(&Jwt::new(presentation.to_owned()))?,
+    ),
+    _ => Err(Error::UnsupportedFormat(format.to_owned()).into()),
+  }
+}
+
+/// A builder for the [`AuthorizationResponse`] of a Wallet.
+///
+/// Presentations must be bound to the request beforehand:
+/// - SD-JWTs through a KB-JWT built with [`AuthorizationRequest::key_binding_jwt_builder`];
+/// - JWT presentations through the options returned by [`AuthorizationRequest::jwt_presentation_options`] and the
+///   request's `nonce`.
+#[derive(Debug, Clone)]
+pub struct AuthorizationResponseBuilder<'r> {
+  request: &'r AuthorizationRequest,
+  submission_id: String,
+  presentations: Vec<(String, Vec<DescriptorMapEntry>)>,
+}
+
+impl<'r> AuthorizationResponseBuilder<'r> {
+  /// Creates a new [`AuthorizationResponseBuilder`] answering `request`, whose presentation submission has the
+  /// identifier `submission_id`.
+  pub fn new(request: &'r AuthorizationRequest, submission_id: impl Into<String>) -> Self {
+    Self {
+      request,
+      submission_id: submission_id.into(),
+      presentations: Vec::new(),
+    }
+  }
+
+  /// Adds an SD-JWT satisfying the input descriptors `descriptor_ids`.
+  pub fn sd_jwt<I, S>(mut self, sd_jwt: &SdJwt, descriptor_ids: I) -> Result<Self>
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    if sd_jwt.required_key_bind().is_some() && sd_jwt.key_binding_jwt().is_none() {
+      return Err(Error::InvalidResponse("the SD-JWT misses its KB-JWT".to_owned()));
+    }
+
+    let format = ClaimFormat::VcSdJwt.to_string();
+    let entries = descriptor_ids
+      .into_iter()
+      .map(|id| DescriptorMapEntry::new(id, &format, ""))
+      .collect();
+    self.presentations.push((sd_jwt.presentation(), entries));
+    Ok(self)
+  }
+
+  /// Adds a JWT encoded VC Data Model 1.1 presentation, whose credentials satisfy input descriptors as given by
+  /// `credentials`, i.e. pairs of an input descriptor identifier and the index of the satisfying credential in the
+  /// presentation.
+  pub fn jwt_vp<I, S>(mut self, presentation: &Jwt, credentials: I) -> Self
+  where
+    I: IntoIterator<Item = (S, usize)>,
+    S: Into<String>,
+  {
+    let entries = credentials
+      .into_iter()
+      .map(|(id, index)| {
+        let id = id.into();
+        let nested = DescriptorMapEntry::new(
+          &id,
+          ClaimFormat::JwtVcJson.as_str(),
+          format!("$.vp.verifiableCredential[{index}]"),
+        );
+        DescriptorMapEntry::new(id, JWT_VP_JSON, "").path_nested(nested)
+      })
+      .collect();
+    self.presentations.push((presentation.as_str().to_owned(), entries));
+    self
+  }
+
+  /// Builds the [`AuthorizationResponse`].
+  pub fn build(self) -> Result<AuthorizationResponse> {
+    let definition = self
+      .request
+      .presentation_definition
+      .as_ref()
+      .ok_or_else(|| Error::InvalidRequest("missing presentation_definition".to_owned()))?;
+    if self.presentations.is_empty() {
+      return Err(Error::InvalidResponse("no presentation".to_owned()));
+    }
+
+    let single = self.presentations.len() == 1;
+    let mut vp_token = Vec::with_capacity(self.presentations.len());
+    let mut descriptor_map = Vec::new();
+    for (index, (presentation, entries)) in self.presentations.into_iter().enumerate() {
+      let path = if single { "$".to_owned() } else { format!("$[{index}]") };
+      descriptor_map.extend(entries.into_iter().map(|mut entry| {
+        entry.path.clone_from(&path);
+        entry
+      }));
+      vp_token.push(presentation);
+    }
+
+    Ok(AuthorizationResponse {
+      vp_token: if single {
+        OneOrMany::One(vp_token.remove(0))
+      } else {
+        OneOrMany::Many(vp_token)
+      },
+      presentation_submission: PresentationSubmission {
+        id: self.submission_id,
+        definition_id: definition.id.clone(),
+        descriptor_map,
+      },
+      state: self.request.state.clone(),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn form_urlencoded_roundtrip() {
+    let definition = serde_json::from_value(json!({ "id": "pd-1", "input_descriptors": [] })).unwrap();
+    let request = AuthorizationRequest::new("verifier-42", "nonce", definition).state("af0ifjsldkj");
+    let response = AuthorizationResponseBuilder::new(&request, "submission-1")
+      .jwt_vp(&Jwt::new("a.b.c".to_owned()), [("degree", 0), ("id_card", 1)])
+      .jwt_vp(&Jwt::new("d.e.f".to_owned()), [("address", 0)])
+      .build()
+      .unwrap();
+
+    assert_eq!(response.presentation("$[1]").unwrap(), "d.e.f");
+    assert!(response.presentation("$").is_err());
+    let entry = &response.presentation_submission.descriptor_map[1];
+    assert_eq!((entry.id.as_str(), entry.path.as_str()), ("id_card", "$[0]"));
+    assert_eq!(entry.path_nested.as_ref().unwrap().path, "$.vp.verifiableCredential[1]");
+
+    let body = response.to_form_urlencoded().unwrap();
+    assert_eq!(AuthorizationResponse::from_form_urlencoded(&body).unwrap(), response);
+  }
+}
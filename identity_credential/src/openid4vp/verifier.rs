@@ -0,0 +1,329 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::JwsVerifierFn;
+use identity_verification::jws::VerificationInput;
+use sd_jwt::Hasher;
+use sd_jwt::SdJwt;
+use serde_json::Value;
+
+use super::response::presentation_holder;
+use super::response::JWT_VP_JSON;
+use super::AuthorizationRequest;
+use super::AuthorizationResponse;
+use super::Error;
+use super::Result;
+use crate::credential::Jwt;
+use crate::presentation_exchange::CandidateCredential;
+use crate::presentation_exchange::ClaimFormat;
+use crate::presentation_exchange::DescriptorMapEntry;
+use crate::presentation_exchange::PresentationDefinition;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtPresentationValidationOptions;
+use crate::validator::JwtPresentationValidator;
+use crate::validator::KeyBindingJwtValidationOptions;
+use crate::validator::SdJwtCredentialValidator;
+use crate::validator::SdJwtPresentationValidationOptions;
+use crate::validator::SubjectHolderRelationship;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Criteria for validating an [`AuthorizationResponse`] through [`AuthorizationResponseValidator::validate`].
+///
+/// The request's `nonce` and client identifier are always enforced on top of these options.
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
+pub struct AuthorizationResponseValidationOptions {
+  /// Options for validating the submitted credentials.
+  pub credential: JwtCredentialValidationOptions,
+  /// Options for validating JWT presentations.
+  pub presentation: JwtPresentationValidationOptions,
+  /// Options for validating the KB-JWT of SD-JWTs.
+  pub key_binding: KeyBindingJwtValidationOptions,
+  /// Declares how the credentials' subject must relate to their holder. Not checked if not set.
+  pub subject_holder_relationship: Option<SubjectHolderRelationship>,
+}
+
+impl AuthorizationResponseValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the options for validating the submitted credentials.
+  pub fn credential_validation_options(mut self, options: JwtCredentialValidationOptions) -> Self {
+    self.credential = options;
+    self
+  }
+
+  /// Sets the options for validating JWT presentations.
+  pub fn presentation_validation_options(mut self, options: JwtPresentationValidationOptions) -> Self {
+    self.presentation = options;
+    self
+  }
+
+  /// Sets the options for validating the KB-JWT of SD-JWTs.
+  pub fn key_binding_validation_options(mut self, options: KeyBindingJwtValidationOptions) -> Self {
+    self.key_binding = options;
+    self
+  }
+
+  /// Declares how the credentials' subject must relate to their holder.
+  pub fn subject_holder_relationship(mut self, relationship: SubjectHolderRelationship) -> Self {
+    self.subject_holder_relationship = Some(relationship);
+    self
+  }
+}
+
+/// A credential submitted in a valid [`AuthorizationResponse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct VerifiedCredential {
+  /// Identifier of the input descriptor the credential satisfies.
+  pub descriptor_id: String,
+  /// The claim format of the credential.
+  pub format: ClaimFormat,
+  /// The DID of the holder who presented the credential.
+  pub holder: CoreDID,
+  /// The claims of the credential, i.e. its disclosed claims for SD-JWTs and its JWT claims set otherwise.
+  pub claims: Value,
+}
+
+/// A type validating the [`AuthorizationResponse`]s of Wallets.
+#[non_exhaustive]
+pub struct AuthorizationResponseValidator<V: JwsVerifier>(SdJwtCredentialValidator<V>);
+
+impl<V: JwsVerifier> AuthorizationResponseValidator<V> {
+  /// Creates a new [`AuthorizationResponseValidator`] that delegates cryptographic signature verification to the
+  /// given `signature_verifier` and SD-JWT decoding to the given `hasher`.
+  pub fn new<H: Hasher + 'static>(signature_verifier: V, hasher: H) -> Self {
+    Self(SdJwtCredentialValidator::new(signature_verifier, hasher))
+  }
+
+  /// Validates `response` against the `request` it answers.
+  ///
+  /// The following properties are validated:
+  /// 1. the response echoes the request's `state`;
+  /// 2. the presentation submission answers the request's presentation definition and, unless the definition has
+  ///    submission requirements, covers each of its input descriptors;
+  /// 3. each submitted presentation is bound to the request's `nonce` and client identifier and signed by its holder,
+  ///    whose DID Document must be among `holders` - see [`AuthorizationResponse::holders`];
+  /// 4. each submitted credential is valid according to `options` and issued by one of `trusted_issuers`;
+  /// 5. each submitted credential satisfies the input descriptor it is submitted for.
+  ///
+  /// # Warning
+  /// The caller must ensure that the DID Documents of the trusted issuers and of the holders are up-to-date.
+  ///
+  /// # Errors
+  /// The first failing check is returned.
+  pub fn validate<DOC, HDOC>(
+    &self,
+    response: &AuthorizationResponse,
+    request: &AuthorizationRequest,
+    trusted_issuers: &[DOC],
+    holders: &[HDOC],
+    options: &AuthorizationResponseValidationOptions,
+  ) -> Result<Vec<VerifiedCredential>>
+  where
+    DOC: AsRef<CoreDocument>,
+    HDOC: AsRef<CoreDocument>,
+  {
+    if response.state != request.state {
+      return Err(Error::InvalidResponse("state doesn't match the request".to_owned()));
+    }
+    let definition = request
+      .presentation_definition
+      .as_ref()
+      .ok_or_else(|| Error::InvalidRequest("missing presentation_definition".to_owned()))?;
+    let submission = &response.presentation_submission;
+    if submission.definition_id != definition.id {
+      return Err(Error::InvalidResponse(format!(
+        "the submission answers presentation definition \"{}\" instead of \"{}\"",
+        submission.definition_id, definition.id
+      )));
+    }
+    if definition.submission_requirements.is_empty() {
+      if let Some(descriptor) = definition
+        .input_descriptors
+        .iter()
+        .find(|descriptor| !submission.descriptor_map.iter().any(|entry| entry.id == descriptor.id))
+      {
+        return Err(Error::InvalidResponse(format!(
+          "no credential submitted for input descriptor \"{}\"",
+          descriptor.id
+        )));
+      }
+    }
+
+    submission
+      .descriptor_map
+      .iter()
+      .map(|entry| {
+        let presentation = response.presentation(&entry.path)?;
+        self
+          .validate_entry(
+            entry,
+            presentation,
+            definition,
+            request,
+            trusted_issuers,
+            holders,
+            options,
+          )
+          .map_err(|source| match source.downcast::<Error>() {
+            Ok(error) => *error,
+            Err(source) => Error::InvalidPresentation {
+              descriptor_id: entry.id.clone(),
+              source,
+            },
+          })
+      })
+      .collect()
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  fn validate_entry<DOC, HDOC>(
+    &self,
+    entry: &DescriptorMapEntry,
+    presentation: &str,
+    definition: &PresentationDefinition,
+    request: &AuthorizationRequest,
+    trusted_issuers: &[DOC],
+    holders: &[HDOC],
+    options: &AuthorizationResponseValidationOptions,
+  ) -> std::result::Result<VerifiedCredential, BoxError>
+  where
+    DOC: AsRef<CoreDocument>,
+    HDOC: AsRef<CoreDocument>,
+  {
+    let holder = presentation_holder(&entry.format, presentation)?;
+    let holder_document = find_document(holders, &holder)?;
+
+    let candidate = match ClaimFormat::from(entry.format.as_str()) {
+      ClaimFormat::VcSdJwt => {
+        let sd_jwt = SdJwt::parse(presentation)?;
+        let mut validation_options = SdJwtPresentationValidationOptions::new()
+          .credential_validation_options(options.credential.clone())
+          .key_binding_validation_options(
+            options
+              .key_binding
+              .clone()
+              .nonce(request.nonce.clone())
+              .aud(request.client_id.clone()),
+          )
+          .require_key_binding(true);
+        validation_options.subject_holder_relationship = options.subject_holder_relationship;
+        self.0.validate_presentation::<DOC, HDOC, Object>(
+          &sd_jwt,
+          trusted_issuers,
+          holder_document,
+          &validation_options,
+          FailFast::FirstError,
+        )?;
+
+        CandidateCredential::sd_jwt(&sd_jwt, self.0.hasher())?
+      }
+      ClaimFormat::Other(format) if format == JWT_VP_JSON => {
+        let credential =
+          self.validate_jwt_vp(entry, presentation, request, trusted_issuers, holder_document, options)?;
+        CandidateCredential::jwt_vc(credential)?
+      }
+      _ => return Err(Error::UnsupportedFormat(entry.format.clone()).into()),
+    };
+
+    let matched = definition
+      .evaluate(std::slice::from_ref(&candidate))?
+      .into_iter()
+      .any(|m| m.descriptor_id == entry.id && !m.credentials.is_empty());
+    if !matched {
+      return Err(crate::presentation_exchange::Error::UnsatisfiedInputDescriptor(entry.id.clone()).into());
+    }
+
+    Ok(VerifiedCredential {
+      descriptor_id: entry.id.clone(),
+      format: candidate.format().clone(),
+      holder,
+      claims: candidate.claims().clone(),
+    })
+  }
+
+  /// Validates a JWT presentation and the credential `entry` points to within it, returning the latter.
+  fn validate_jwt_vp<DOC, HDOC>(
+    &self,
+    entry: &DescriptorMapEntry,
+    presentation: &str,
+    request: &AuthorizationRequest,
+    trusted_issuers: &[DOC],
+    holder_document: &HDOC,
+    options: &AuthorizationResponseValidationOptions,
+  ) -> std::result::Result<Jwt, BoxError>
+  where
+    DOC: AsRef<CoreDocument>,
+    HDOC: AsRef<CoreDocument>,
+  {
+    // Both validators take ownership of their verifier: share ours through a copyable closure.
+    let verify = |input: VerificationInput, key: &Jwk| self.0.verifier().verify(input, key);
+
+    let mut presentation_options = options.presentation.clone();
+    presentation_options.presentation_verifier_options = presentation_options
+      .presentation_verifier_options
+      .nonce(request.nonce.clone());
+    let decoded = JwtPresentationValidator::with_signature_verifier(JwsVerifierFn::from(verify))
+      .validate::<HDOC, Jwt, Object>(
+        &Jwt::new(presentation.to_owned()),
+        holder_document,
+        &presentation_options,
+      )?;
+    if decoded.aud.as_ref().map(AsRef::<str>::as_ref) != Some(request.client_id.as_str()) {
+      return Err(Error::InvalidResponse("the presentation's audience is not the Verifier".to_owned()).into());
+    }
+
+    let index = entry
+      .path_nested
+      .as_ref()
+      .filter(|nested| ClaimFormat::from(nested.format.as_str()) == ClaimFormat::JwtVcJson)
+      .and_then(|nested| nested.path.strip_prefix("$.vp.verifiableCredential["))
+      .and_then(|path| path.strip_suffix(']'))
+      .and_then(|index| index.parse::<usize>().ok())
+      .ok_or("unsupported nested path")?;
+    let credential = decoded
+      .presentation
+      .verifiable_credential
+      .get(index)
+      .ok_or("no credential found at the nested path")?
+      .clone();
+
+    let issuer = JwtCredentialValidatorUtils::extract_issuer_from_jwt::<CoreDID>(&credential)?;
+    let issuer_document = find_document(trusted_issuers, &issuer)?;
+    let mut credential_options = options.credential.clone();
+    if let Some(relationship) = options.subject_holder_relationship {
+      let holder_url = Url::from(holder_document.as_ref().id().to_url());
+      credential_options = credential_options.subject_holder_relationship(holder_url, relationship);
+    }
+    JwtCredentialValidator::with_signature_verifier(JwsVerifierFn::from(verify)).validate::<DOC, Object>(
+      &credential,
+      issuer_document,
+      &credential_options,
+      FailFast::FirstError,
+    )?;
+
+    Ok(credential)
+  }
+}
+
+/// Returns the DID Document of `did` among `documents`.
+fn find_document<'d, DOC: AsRef<CoreDocument>>(documents: &'d [DOC], did: &CoreDID) -> Result<&'d DOC> {
+  documents
+    .iter()
+    .find(|document| document.as_ref().id() == did)
+    .ok_or_else(|| Error::MissingDocument(did.to_string()))
+}
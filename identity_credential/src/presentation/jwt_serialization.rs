@@ -211,6 +211,8 @@ where
 #[serde(bound(deserialize = "C: serde::de::DeserializeOwned, T: serde::de::DeserializeOwned"))]
 pub(crate) struct JwtPresentationV2Claims<C, T> {
   pub(crate) iat: Option<i64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) nbf: Option<i64>,
   pub(crate) exp: Option<i64>,
   pub(crate) aud: Option<StringOrUrl>,
   #[serde(flatten)]
@@ -224,6 +226,7 @@ impl<C, T> JwtPresentationV2Claims<C, T> {
     Self {
       vp,
       iat: options.issuance_date.map(|ts| ts.to_unix()),
+      nbf: None,
       exp: options.expiration_date.map(|ts| ts.to_unix()),
       aud: options.audience.clone(),
       custom: options.custom_claims.clone(),
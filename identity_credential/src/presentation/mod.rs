@@ -13,12 +13,18 @@ mod jwt_presentation_options;
 mod jwt_serialization;
 mod presentation;
 mod presentation_builder;
+mod presentation_v2_builder;
+#[cfg(feature = "presentation-refresh")]
+mod presentation_refresh;
 
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jwp_presentation_builder::SelectiveDisclosurePresentation;
 pub use self::jwt_presentation_options::JwtPresentationOptions;
 pub use self::presentation::Presentation;
 pub use self::presentation_builder::PresentationBuilder;
+pub use self::presentation_v2_builder::PresentationV2Builder;
+#[cfg(feature = "presentation-refresh")]
+pub use self::presentation_refresh::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use jwp_presentation_options::JwpPresentationOptions;
 
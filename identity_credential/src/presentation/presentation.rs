@@ -14,6 +14,7 @@ use identity_core::common::OneOrMany;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 use identity_core::convert::ToJson;
+use identity_core::convert::VersionedSchema;
 use serde::de::DeserializeOwned;
 
 use crate::credential::Credential;
@@ -180,6 +181,15 @@ where
   }
 }
 
+impl<C, T> VersionedSchema for Presentation<C, T>
+where
+  T: Serialize + DeserializeOwned,
+  C: Serialize + DeserializeOwned,
+{
+  const KIND: &'static str = "Presentation";
+  const VERSION: u16 = 1;
+}
+
 impl<T> Display for Presentation<T>
 where
   T: Serialize,
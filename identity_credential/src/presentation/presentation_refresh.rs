@@ -0,0 +1,445 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Duration;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+#[cfg(feature = "status-list-2021")]
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_verification::jws::Decoder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::credential::Credential;
+use crate::credential::CredentialJwtClaims;
+use crate::credential::Jwt;
+#[cfg(feature = "status-list-2021")]
+use crate::revocation::status_list_2021::StatusList2021Credential;
+use crate::validator::SuspensionCheck;
+
+use super::PresentationBuilder;
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// The reason a credential is considered stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum StaleReason {
+  /// The credential has expired.
+  Expired,
+  /// The credential expires within [`CredentialRefreshOptions::min_remaining_validity`].
+  ExpiresSoon,
+  /// The credential has been revoked according to its status list.
+  Revoked,
+  /// The credential has been suspended according to its status list.
+  Suspended,
+}
+
+/// Options declaring when the credentials of a [`PresentationBuilder`] are considered stale by
+/// [`PresentationBuilder::refresh_credentials`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct CredentialRefreshOptions {
+  /// Credentials expiring within this duration are considered stale.
+  ///
+  /// Default: only expired credentials are considered stale.
+  pub min_remaining_validity: Option<Duration>,
+  /// Whether suspended credentials are considered stale.
+  ///
+  /// Default: [`SuspensionCheck::FailOnSuspended`], i.e. suspended credentials are refreshed.
+  pub suspension: SuspensionCheck,
+  /// Whether stale credentials for which no replacement could be obtained are kept in the presentation, rather
+  /// than failing with [`PresentationRefreshError::NotRefreshed`].
+  ///
+  /// Default: `false`.
+  pub keep_stale: bool,
+}
+
+impl CredentialRefreshOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Consider credentials expiring within `duration` stale.
+  pub fn min_remaining_validity(mut self, duration: Duration) -> Self {
+    self.min_remaining_validity = Some(duration);
+    self
+  }
+
+  /// Declare whether suspended credentials are considered stale.
+  pub fn suspension(mut self, suspension: SuspensionCheck) -> Self {
+    self.suspension = suspension;
+    self
+  }
+
+  /// Declare whether stale credentials without replacement are kept in the presentation.
+  pub fn keep_stale(mut self, keep_stale: bool) -> Self {
+    self.keep_stale = keep_stale;
+    self
+  }
+}
+
+/// Obtains replacements for stale credentials, e.g. from their `refreshService` or by requesting a new
+/// credential from their issuer through OpenID for Verifiable Credential Issuance.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait CredentialRefresher {
+  /// Obtains a replacement for `jwt`, whose decoded form is `credential`, that was found stale for `reason`.
+  ///
+  /// Returns `Ok(None)` if no replacement can be obtained.
+  async fn refresh(&self, jwt: &Jwt, credential: &Credential, reason: StaleReason) -> Result<Option<Jwt>, BoxedError>;
+
+  /// Fetches the status list credential published at `url`, used to re-check the status of credentials
+  /// referencing it.
+  ///
+  /// The default implementation returns `Ok(None)`, skipping status checks.
+  #[cfg(feature = "status-list-2021")]
+  async fn status_list(&self, url: &Url) -> Result<Option<StatusList2021Credential>, BoxedError> {
+    let _ = url;
+    Ok(None)
+  }
+}
+
+/// A credential of a presentation that has been replaced by [`PresentationBuilder::refresh_credentials`].
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct ReplacedCredential {
+  /// The position of the credential among the credentials of the presentation.
+  pub index: usize,
+  /// Why the credential was replaced.
+  pub reason: StaleReason,
+  /// The replaced credential.
+  pub previous: Jwt,
+}
+
+/// The outcome of [`PresentationBuilder::refresh_credentials`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct PresentationRefreshReport {
+  /// The credentials that have been replaced.
+  pub replaced: Vec<ReplacedCredential>,
+  /// The positions of the stale credentials that have been kept for lack of a replacement, see
+  /// [`CredentialRefreshOptions::keep_stale`].
+  pub kept_stale: Vec<(usize, StaleReason)>,
+}
+
+impl PresentationRefreshReport {
+  /// Returns whether all credentials were fresh.
+  pub fn is_unchanged(&self) -> bool {
+    self.replaced.is_empty() && self.kept_stale.is_empty()
+  }
+}
+
+/// An error caused by a failure to refresh the credentials of a presentation.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum PresentationRefreshError {
+  /// Caused by a credential that could not be decoded.
+  #[error("could not decode credential {index}")]
+  Decoding {
+    /// The position of the credential.
+    index: usize,
+    /// The cause of the error.
+    #[source]
+    source: BoxedError,
+  },
+  /// Caused by a failure to fetch the status list referenced by a credential.
+  #[cfg(feature = "status-list-2021")]
+  #[error("could not fetch the status list of credential {index}")]
+  StatusList {
+    /// The position of the credential.
+    index: usize,
+    /// The cause of the error.
+    #[source]
+    source: BoxedError,
+  },
+  /// Caused by a [`CredentialRefresher`] failing to refresh a credential.
+  #[error("could not refresh credential {index}")]
+  Refresh {
+    /// The position of the credential.
+    index: usize,
+    /// The cause of the error.
+    #[source]
+    source: BoxedError,
+  },
+  /// Caused by a stale credential for which no replacement could be obtained.
+  #[error("credential {index} is stale ({reason:?}) and could not be refreshed")]
+  NotRefreshed {
+    /// The position of the credential.
+    index: usize,
+    /// Why the credential is stale.
+    reason: StaleReason,
+  },
+  /// Caused by a replacement that is itself stale.
+  #[error("the replacement of credential {index} is stale ({reason:?})")]
+  StaleReplacement {
+    /// The position of the credential.
+    index: usize,
+    /// Why the replacement is stale.
+    reason: StaleReason,
+  },
+}
+
+impl<T> PresentationBuilder<Jwt, T> {
+  /// Replaces the stale credentials of the presentation with fresh ones obtained from `refresher`, returning the
+  /// updated builder along with a report of the replaced credentials.
+  ///
+  /// A credential is stale if it has expired or expires within
+  /// [`CredentialRefreshOptions::min_remaining_validity`], or - if it references a `StatusList2021` status list
+  /// and `refresher` provides it - if it has been revoked or suspended. Replacements are subject to the same
+  /// checks.
+  ///
+  /// # Warning
+  /// Neither the credentials nor their replacements are validated: verifiers must still validate the credentials
+  /// of the presentation, e.g. with
+  /// [`JwtCredentialValidator::validate`](crate::validator::JwtCredentialValidator::validate).
+  pub async fn refresh_credentials<R>(
+    mut self,
+    refresher: &R,
+    options: &CredentialRefreshOptions,
+  ) -> Result<(Self, PresentationRefreshReport), PresentationRefreshError>
+  where
+    R: CredentialRefresher + ?Sized,
+  {
+    let mut report = PresentationRefreshReport::default();
+
+    for index in 0..self.credentials.len() {
+      let credential: Credential = decode(&self.credentials[index], index)?;
+      let Some(reason) = staleness(&credential, refresher, options, index).await? else {
+        continue;
+      };
+
+      let replacement: Option<Jwt> = refresher
+        .refresh(&self.credentials[index], &credential, reason)
+        .await
+        .map_err(|source| PresentationRefreshError::Refresh { index, source })?;
+      match replacement {
+        Some(replacement) => {
+          let fresh: Credential = decode(&replacement, index)?;
+          if let Some(reason) = staleness(&fresh, refresher, options, index).await? {
+            return Err(PresentationRefreshError::StaleReplacement { index, reason });
+          }
+          let previous: Jwt = std::mem::replace(&mut self.credentials[index], replacement);
+          report.replaced.push(ReplacedCredential {
+            index,
+            reason,
+            previous,
+          });
+        }
+        None if options.keep_stale => report.kept_stale.push((index, reason)),
+        None => return Err(PresentationRefreshError::NotRefreshed { index, reason }),
+      }
+    }
+
+    Ok((self, report))
+  }
+}
+
+/// Decodes the credential at position `index` without verifying its signature.
+fn decode(jwt: &Jwt, index: usize) -> Result<Credential, PresentationRefreshError> {
+  let decoding_error = |source: BoxedError| PresentationRefreshError::Decoding { index, source };
+  let decoded = Decoder::new()
+    .decode_compact_serialization(jwt.as_str().as_bytes(), None)
+    .map_err(|err| decoding_error(err.into()))?;
+  CredentialJwtClaims::<'_, Object>::from_json_slice(decoded.claims())
+    .map_err(|err| decoding_error(err.into()))?
+    .try_into_credential()
+    .map_err(|err| decoding_error(err.into()))
+}
+
+/// Returns why `credential` is stale, if it is.
+#[cfg_attr(not(feature = "status-list-2021"), allow(unused_variables))]
+async fn staleness<R>(
+  credential: &Credential,
+  refresher: &R,
+  options: &CredentialRefreshOptions,
+  index: usize,
+) -> Result<Option<StaleReason>, PresentationRefreshError>
+where
+  R: CredentialRefresher + ?Sized,
+{
+  #[cfg(feature = "status-list-2021")]
+  if let Some(reason) = status_staleness(credential, refresher, options, index).await? {
+    return Ok(Some(reason));
+  }
+
+  let Some(expiration_date) = credential.expiration_date else {
+    return Ok(None);
+  };
+  let now: Timestamp = Timestamp::now_utc();
+  if expiration_date <= now {
+    return Ok(Some(StaleReason::Expired));
+  }
+  let expires_soon: bool = options
+    .min_remaining_validity
+    .and_then(|validity| now.checked_add(validity))
+    .is_some_and(|threshold| expiration_date <= threshold);
+
+  Ok(expires_soon.then_some(StaleReason::ExpiresSoon))
+}
+
+/// Re-checks the status of `credential` against the status list provided by `refresher`, if any.
+#[cfg(feature = "status-list-2021")]
+async fn status_staleness<R>(
+  credential: &Credential,
+  refresher: &R,
+  options: &CredentialRefreshOptions,
+  index: usize,
+) -> Result<Option<StaleReason>, PresentationRefreshError>
+where
+  R: CredentialRefresher + ?Sized,
+{
+  use crate::revocation::status_list_2021::CredentialStatus;
+  use crate::revocation::status_list_2021::StatusList2021Entry;
+
+  let Some(entry) = credential
+    .credential_status
+    .as_ref()
+    .and_then(|status| StatusList2021Entry::try_from(status).ok())
+  else {
+    return Ok(None);
+  };
+  let status_list_error = |source: BoxedError| PresentationRefreshError::StatusList { index, source };
+  let Some(status_list) = refresher
+    .status_list(entry.status_list_credential())
+    .await
+    .map_err(status_list_error)?
+  else {
+    return Ok(None);
+  };
+  if status_list.id() != Some(entry.status_list_credential()) || status_list.purpose() != entry.purpose() {
+    return Err(status_list_error(
+      "the status list does not match the status of the credential".into(),
+    ));
+  }
+
+  match status_list
+    .entry(entry.index())
+    .map_err(|err| status_list_error(err.into()))?
+  {
+    CredentialStatus::Revoked => Ok(Some(StaleReason::Revoked)),
+    CredentialStatus::Suspended if options.suspension == SuspensionCheck::FailOnSuspended => {
+      Ok(Some(StaleReason::Suspended))
+    }
+    CredentialStatus::Suspended | CredentialStatus::Valid => Ok(None),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use identity_core::common::Url;
+  use identity_did::DID;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+
+  fn issue(expiration_date: Timestamp) -> Jwt {
+    let (document, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .issuance_date(Timestamp::from_unix(0).unwrap())
+      .expiration_date(expiration_date)
+      .build()
+      .unwrap();
+    sign_credential_jwt(&credential, &document, &fragment, &secret_key)
+  }
+
+  fn in_days(days: i64) -> Timestamp {
+    Timestamp::from_unix(Timestamp::now_utc().to_unix() + days * 24 * 60 * 60).unwrap()
+  }
+
+  /// Reissues credentials valid for another year, recording the reasons they were refreshed for.
+  #[derive(Default)]
+  struct Reissuer {
+    reasons: Mutex<Vec<StaleReason>>,
+    unavailable: bool,
+  }
+
+  #[async_trait]
+  impl CredentialRefresher for Reissuer {
+    async fn refresh(&self, _: &Jwt, _: &Credential, reason: StaleReason) -> Result<Option<Jwt>, BoxedError> {
+      self.reasons.lock().unwrap().push(reason);
+      Ok((!self.unavailable).then(|| issue(in_days(365))))
+    }
+  }
+
+  fn builder(credentials: impl IntoIterator<Item = Jwt>) -> PresentationBuilder<Jwt, Object> {
+    credentials.into_iter().fold(
+      PresentationBuilder::new(Url::parse("did:example:holder").unwrap(), Object::new()),
+      |builder, credential| builder.credential(credential),
+    )
+  }
+
+  #[tokio::test]
+  async fn stale_credentials_are_replaced() {
+    let fresh: Jwt = issue(in_days(365));
+    let expired: Jwt = issue(in_days(-1));
+    let expiring: Jwt = issue(in_days(1));
+    let refresher = Reissuer::default();
+
+    let (builder, report) = builder([fresh.clone(), expired.clone(), expiring.clone()])
+      .refresh_credentials(
+        &refresher,
+        &CredentialRefreshOptions::new().min_remaining_validity(Duration::days(7)),
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(
+      *refresher.reasons.lock().unwrap(),
+      [StaleReason::Expired, StaleReason::ExpiresSoon]
+    );
+    assert_eq!(
+      report
+        .replaced
+        .iter()
+        .map(|replaced| (replaced.index, replaced.reason, &replaced.previous))
+        .collect::<Vec<_>>(),
+      [
+        (1, StaleReason::Expired, &expired),
+        (2, StaleReason::ExpiresSoon, &expiring)
+      ]
+    );
+    assert_eq!(builder.credentials[0], fresh);
+    assert_ne!(builder.credentials[1], expired);
+    assert_ne!(builder.credentials[2], expiring);
+  }
+
+  #[tokio::test]
+  async fn unrefreshable_credentials_fail_unless_kept() {
+    let expired: Jwt = issue(in_days(-1));
+    let refresher = Reissuer {
+      unavailable: true,
+      ..Default::default()
+    };
+
+    let error = builder([expired.clone()])
+      .refresh_credentials(&refresher, &CredentialRefreshOptions::new())
+      .await
+      .unwrap_err();
+    assert!(matches!(
+      error,
+      PresentationRefreshError::NotRefreshed {
+        index: 0,
+        reason: StaleReason::Expired
+      }
+    ));
+
+    let (builder, report) = builder([expired.clone()])
+      .refresh_credentials(&refresher, &CredentialRefreshOptions::new().keep_stale(true))
+      .await
+      .unwrap();
+    assert_eq!(report.kept_stale, [(0, StaleReason::Expired)]);
+    assert_eq!(builder.credentials, [expired]);
+  }
+}
@@ -0,0 +1,198 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Context;
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use itertools::Itertools;
+
+use crate::credential::CredentialV2;
+use crate::credential::EnvelopedVc;
+use crate::credential::JwtVcV2;
+use crate::credential::Policy;
+use crate::error::Result;
+
+use super::Presentation;
+
+/// A `PresentationV2Builder` is used to create a [VC Data Model 2.0](https://www.w3.org/TR/vc-data-model-2.0/)
+/// [Presentation] whose credentials are [enveloped](https://www.w3.org/TR/vc-data-model-2.0/#enveloped-verifiable-credentials).
+///
+/// Unlike [`PresentationBuilder`](super::PresentationBuilder), the base context of VC Data Model 2.0 is always the
+/// first context of the built presentation, regardless of the contexts that have been added.
+///
+/// # Example
+/// ```
+/// # use identity_core::common::Object;
+/// # use identity_core::common::Url;
+/// # use identity_credential::credential::EnvelopedVc;
+/// # use identity_credential::credential::VcDataUrl;
+/// # use identity_credential::presentation::Presentation;
+/// # use identity_credential::presentation::PresentationV2Builder;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let credential = EnvelopedVc::new(VcDataUrl::parse(
+///   "data:application/vc+jwt,eyJraWQiO...zhwGfQ",
+/// )?);
+/// let presentation: Presentation<EnvelopedVc> =
+///   PresentationV2Builder::new(Url::parse("did:example:holder")?, Object::new())
+///     .credential(credential)
+///     .build()?;
+///
+/// assert!(presentation.is_v2());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PresentationV2Builder<T = Object> {
+  context: Vec<Context>,
+  id: Option<Url>,
+  types: Vec<String>,
+  credentials: Vec<EnvelopedVc>,
+  holder: Url,
+  terms_of_use: Vec<Policy>,
+  properties: T,
+}
+
+impl<T> PresentationV2Builder<T> {
+  /// Creates a new `PresentationV2Builder`.
+  pub fn new(holder: Url, properties: T) -> Self {
+    Self {
+      context: Vec::new(),
+      id: None,
+      types: vec![Presentation::<EnvelopedVc, T>::base_type().into()],
+      credentials: Vec::new(),
+      holder,
+      terms_of_use: Vec::new(),
+      properties,
+    }
+  }
+
+  /// Adds a value to the `context` set.
+  #[must_use]
+  pub fn context(mut self, value: impl Into<Context>) -> Self {
+    self.context.push(value.into());
+    self
+  }
+
+  /// Sets the unique identifier of the presentation.
+  #[must_use]
+  pub fn id(mut self, value: Url) -> Self {
+    self.id = Some(value);
+    self
+  }
+
+  /// Adds a value to the `type` set.
+  #[must_use]
+  pub fn type_(mut self, value: impl Into<String>) -> Self {
+    self.types.push(value.into());
+    self
+  }
+
+  /// Adds an enveloped credential to the `verifiableCredential` set.
+  #[must_use]
+  pub fn credential(mut self, value: EnvelopedVc) -> Self {
+    self.credentials.push(value);
+    self
+  }
+
+  /// Envelopes the given JWT credential as `application/vc+jwt` and adds it to the `verifiableCredential` set.
+  #[must_use]
+  pub fn jwt_credential(self, value: JwtVcV2) -> Self {
+    self.credential(value.into_enveloped_vc())
+  }
+
+  /// Adds a value to the `termsOfUse` set.
+  #[must_use]
+  pub fn terms_of_use(mut self, value: Policy) -> Self {
+    self.terms_of_use.push(value);
+    self
+  }
+
+  /// Returns a new VC Data Model 2.0 `Presentation` based on the `PresentationV2Builder` configuration.
+  pub fn build(self) -> Result<Presentation<EnvelopedVc, T>> {
+    let context: Vec<Context> = std::iter::once(CredentialV2::<()>::base_context().clone())
+      .chain(self.context)
+      .unique()
+      .collect();
+
+    let presentation = Presentation {
+      context: context.into(),
+      id: self.id,
+      types: self.types.into_iter().unique().collect_vec().into(),
+      verifiable_credential: self.credentials,
+      holder: self.holder,
+      refresh_service: Default::default(),
+      terms_of_use: self.terms_of_use.into(),
+      properties: self.properties,
+      proof: None,
+    };
+    presentation.check_structure()?;
+
+    Ok(presentation)
+  }
+}
+
+impl PresentationV2Builder<Object> {
+  /// Adds a new custom property.
+  #[must_use]
+  pub fn property<K, V>(mut self, key: K, value: V) -> Self
+  where
+    K: Into<String>,
+    V: Into<Value>,
+  {
+    self.properties.insert(key.into(), value.into());
+    self
+  }
+
+  /// Adds a series of custom properties.
+  #[must_use]
+  pub fn properties<K, V, I>(mut self, iter: I) -> Self
+  where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<Value>,
+  {
+    self
+      .properties
+      .extend(iter.into_iter().map(|(k, v)| (k.into(), v.into())));
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::ToJson;
+
+  use crate::credential::VcDataUrl;
+
+  use super::*;
+
+  fn enveloped_vc() -> EnvelopedVc {
+    EnvelopedVc::new(VcDataUrl::parse("data:application/vc+jwt,eyJraWQiO...zhwGfQ").unwrap())
+  }
+
+  #[test]
+  fn base_context_is_always_first() {
+    let presentation: Presentation<EnvelopedVc> =
+      PresentationV2Builder::new(Url::parse("did:test:abc1").unwrap(), Object::new())
+        .context(Url::parse("https://www.w3.org/ns/credentials/examples/v2").unwrap())
+        .context(CredentialV2::<()>::base_context().clone())
+        .type_("ExamplePresentation")
+        .credential(enveloped_vc())
+        .build()
+        .unwrap();
+
+    assert!(presentation.is_v2());
+    assert_eq!(presentation.context.len(), 2);
+    assert_eq!(
+      presentation.types.as_slice(),
+      ["VerifiablePresentation", "ExamplePresentation"]
+    );
+
+    let json = presentation.to_json_value().unwrap();
+    assert_eq!(
+      json["verifiableCredential"][0]["type"],
+      serde_json::json!("EnvelopedVerifiableCredential")
+    );
+  }
+}
@@ -0,0 +1,236 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A Verifier's requirements on the credentials a Holder must present, as defined in
+/// [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#presentation-definition).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresentationDefinition {
+  /// Identifier of this definition.
+  pub id: String,
+  /// Human-friendly name of this definition.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Purpose for which this definition's data is requested.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// Claim formats - e.g. `jwt_vc_json` or `dc+sd-jwt` - accepted by the Verifier, together with their supported
+  /// algorithms. Every format is accepted when unset.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub format: Option<Object>,
+  /// The credentials requested by the Verifier.
+  pub input_descriptors: Vec<InputDescriptor>,
+  /// How the input descriptors can be combined, if not all of them must be satisfied.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub submission_requirements: Vec<SubmissionRequirement>,
+}
+
+impl PresentationDefinition {
+  /// Creates a new [`PresentationDefinition`] requesting a credential for each of the given `input_descriptors`.
+  pub fn new(id: impl Into<String>, input_descriptors: Vec<InputDescriptor>) -> Self {
+    Self {
+      id: id.into(),
+      name: None,
+      purpose: None,
+      format: None,
+      input_descriptors,
+      submission_requirements: Vec::new(),
+    }
+  }
+}
+
+/// Requirements on a single credential, as defined in
+/// [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#input-descriptor-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputDescriptor {
+  /// Identifier of this descriptor, unique within its [`PresentationDefinition`].
+  pub id: String,
+  /// Human-friendly name of this descriptor.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Purpose for which this descriptor's data is requested.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// Claim formats accepted for this descriptor, overriding the ones of the [`PresentationDefinition`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub format: Option<Object>,
+  /// Groups this descriptor belongs to, referenced by [`SubmissionRequirement::from`].
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub group: Vec<String>,
+  /// Constraints a credential must satisfy.
+  #[serde(default)]
+  pub constraints: Constraints,
+}
+
+impl InputDescriptor {
+  /// Creates a new [`InputDescriptor`] with the given `id` and `constraints`.
+  pub fn new(id: impl Into<String>, constraints: Constraints) -> Self {
+    Self {
+      id: id.into(),
+      name: None,
+      purpose: None,
+      format: None,
+      group: Vec::new(),
+      constraints,
+    }
+  }
+}
+
+/// Constraints of an [`InputDescriptor`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constraints {
+  /// Whether the Holder must only disclose the claims referenced by [`Constraints::fields`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub limit_disclosure: Option<LimitDisclosure>,
+  /// Claims a credential must contain.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub fields: Vec<Field>,
+}
+
+/// Value of [`Constraints::limit_disclosure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LimitDisclosure {
+  /// Only the claims referenced by the descriptor's fields may be disclosed.
+  Required,
+  /// Only the claims referenced by the descriptor's fields should be disclosed.
+  Preferred,
+}
+
+/// A claim requested by an [`InputDescriptor`], as defined in
+/// [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#input-descriptor-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Field {
+  /// Identifier of this field.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// JSONPath expressions locating the claim, tried in order.
+  pub path: Vec<String>,
+  /// Human-friendly name of this field.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Purpose for which this field's claim is requested.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// A JSON Schema the claim's value must satisfy.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub filter: Option<Value>,
+  /// Whether a credential lacking this claim still satisfies the descriptor.
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub optional: bool,
+  /// Whether the Verifier intends to retain the claim's value.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub intent_to_retain: Option<bool>,
+}
+
+impl Field {
+  /// Creates a new mandatory [`Field`] located by the given JSONPath expressions.
+  pub fn new<P>(path: impl IntoIterator<Item = P>) -> Self
+  where
+    P: Into<String>,
+  {
+    Self {
+      id: None,
+      path: path.into_iter().map(Into::into).collect(),
+      name: None,
+      purpose: None,
+      filter: None,
+      optional: false,
+      intent_to_retain: None,
+    }
+  }
+
+  /// Sets the JSON Schema the claim's value must satisfy.
+  #[must_use]
+  pub fn filter(mut self, filter: Value) -> Self {
+    self.filter = Some(filter);
+    self
+  }
+
+  /// Marks this field as optional.
+  #[must_use]
+  pub fn optional(mut self) -> Self {
+    self.optional = true;
+    self
+  }
+}
+
+/// A rule on how [`InputDescriptor`]s can be combined, as defined in
+/// [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#submission-requirements).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionRequirement {
+  /// Human-friendly name of this requirement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Purpose of this requirement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// Whether all or some of the referenced descriptors must be satisfied.
+  pub rule: SubmissionRule,
+  /// Exact number of descriptors or nested requirements to satisfy with [`SubmissionRule::Pick`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub count: Option<usize>,
+  /// Minimum number of descriptors or nested requirements to satisfy with [`SubmissionRule::Pick`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min: Option<usize>,
+  /// Maximum number of descriptors or nested requirements to satisfy with [`SubmissionRule::Pick`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max: Option<usize>,
+  /// Group of the descriptors this requirement applies to. Mutually exclusive with `from_nested`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub from: Option<String>,
+  /// Nested requirements this requirement applies to. Mutually exclusive with `from`.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub from_nested: Vec<SubmissionRequirement>,
+}
+
+/// Value of [`SubmissionRequirement::rule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionRule {
+  /// Every referenced descriptor or nested requirement must be satisfied.
+  All,
+  /// Some of the referenced descriptors or nested requirements must be satisfied.
+  Pick,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn deserialize_spec_example() {
+    let definition: PresentationDefinition = serde_json::from_value(json!({
+      "id": "32f54163-7166-48f1-93d8-ff217bdb0653",
+      "submission_requirements": [{ "name": "Citizenship", "rule": "pick", "count": 1, "from": "A" }],
+      "input_descriptors": [{
+        "id": "citizenship_input_1",
+        "name": "EU Driver's License",
+        "group": ["A"],
+        "constraints": {
+          "limit_disclosure": "required",
+          "fields": [{
+            "path": ["$.credentialSubject.dob", "$.vc.credentialSubject.dob"],
+            "filter": { "type": "string", "format": "date" }
+          }]
+        }
+      }]
+    }))
+    .unwrap();
+
+    let descriptor = &definition.input_descriptors[0];
+    assert_eq!(descriptor.constraints.limit_disclosure, Some(LimitDisclosure::Required));
+    assert_eq!(descriptor.constraints.fields[0].path.len(), 2);
+    assert_eq!(definition.submission_requirements[0].rule, SubmissionRule::Pick);
+    assert_eq!(
+      serde_json::from_value::<PresentationDefinition>(serde_json::to_value(&definition).unwrap()).unwrap(),
+      definition
+    );
+  }
+}
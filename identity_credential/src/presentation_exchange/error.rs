@@ -0,0 +1,34 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Errors that may occur when evaluating credentials against a presentation definition.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+  /// A field's path is not a supported JSONPath expression.
+  #[error("invalid JSONPath expression `{path}`: {reason}")]
+  InvalidJsonPath {
+    /// The invalid expression.
+    path: String,
+    /// Why the expression is invalid.
+    reason: String,
+  },
+  /// A field's filter is not a valid JSON Schema.
+  #[error("invalid field filter: {0}")]
+  InvalidFilter(String),
+  /// A submission requirement is malformed, e.g. it references neither a group nor nested requirements.
+  #[error("invalid submission requirement: {0}")]
+  InvalidSubmissionRequirement(String),
+  /// A credential's claims couldn't be extracted.
+  #[error("invalid credential")]
+  InvalidCredential(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// None of the given credentials satisfies the input descriptor with the given identifier.
+  #[error("no credential satisfies input descriptor \"{0}\"")]
+  UnsatisfiedInputDescriptor(String),
+  /// The given credentials don't satisfy the definition's submission requirements.
+  #[error("submission requirement{} not satisfied", .0.as_deref().map(|name| format!(" \"{name}\"")).unwrap_or_default())]
+  UnsatisfiedSubmissionRequirement(Option<String>),
+}
+
+/// Either a value of type `T` or an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,142 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::Value;
+
+use super::Error;
+use super::Result;
+
+/// A single step of a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+  /// Member of an object.
+  Name(String),
+  /// Element of an array.
+  Index(usize),
+  /// Every member of an object or every element of an array.
+  Wildcard,
+}
+
+/// The subset of [JSONPath](https://www.rfc-editor.org/rfc/rfc9535) used by input descriptor fields in practice:
+/// the root `$` followed by any number of dot-notated (`.name`, `.*`) or bracket-notated (`['name']`, `[0]`, `[*]`)
+/// segments. Descendant segments (`..`), slices and filter expressions are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JsonPath(Vec<Segment>);
+
+impl JsonPath {
+  pub(crate) fn parse(path: &str) -> Result<Self> {
+    let invalid = |reason: &str| Error::InvalidJsonPath {
+      path: path.to_owned(),
+      reason: reason.to_owned(),
+    };
+
+    let mut input = path.strip_prefix('$').ok_or_else(|| invalid("missing root `$`"))?;
+    let mut segments = Vec::new();
+    while !input.is_empty() {
+      if input.starts_with("..") {
+        return Err(invalid("descendant segments are not supported"));
+      } else if let Some(rest) = input.strip_prefix('.') {
+        let end = rest.find(['.', '[']).unwrap_or(rest.len());
+        let name = &rest[..end];
+        match name {
+          "" => return Err(invalid("empty member name")),
+          "*" => segments.push(Segment::Wildcard),
+          name => segments.push(Segment::Name(name.to_owned())),
+        }
+        input = &rest[end..];
+      } else if let Some(rest) = input.strip_prefix('[') {
+        let (segment, rest) = if let Some(quote) = rest.chars().next().filter(|c| *c == '\'' || *c == '"') {
+          let rest = &rest[1..];
+          let end = rest.find(quote).ok_or_else(|| invalid("unterminated string"))?;
+          (Segment::Name(rest[..end].to_owned()), &rest[end + 1..])
+        } else {
+          let end = rest.find(']').ok_or_else(|| invalid("unterminated bracket"))?;
+          let segment = match rest[..end].trim() {
+            "*" => Segment::Wildcard,
+            index => Segment::Index(index.parse().map_err(|_| invalid("unsupported bracket selector"))?),
+          };
+          (segment, &rest[end..])
+        };
+        segments.push(segment);
+        input = rest.strip_prefix(']').ok_or_else(|| invalid("unterminated bracket"))?;
+      } else {
+        return Err(invalid("expected `.` or `[`"));
+      }
+    }
+
+    Ok(Self(segments))
+  }
+
+  /// Returns the values selected by this path in `root`, each together with its location as a JSON pointer.
+  pub(crate) fn select<'v>(&self, root: &'v Value) -> Vec<(String, &'v Value)> {
+    let mut selected = vec![(String::new(), root)];
+    for segment in &self.0 {
+      selected = selected
+        .into_iter()
+        .flat_map(|(pointer, value)| Self::step(segment, pointer, value))
+        .collect();
+    }
+
+    selected
+  }
+
+  fn step<'v>(segment: &Segment, pointer: String, value: &'v Value) -> Vec<(String, &'v Value)> {
+    let child = |key: &str| format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+    match (segment, value) {
+      (Segment::Name(name), Value::Object(object)) => object
+        .get(name)
+        .map(|value| vec![(child(name), value)])
+        .unwrap_or_default(),
+      (Segment::Index(idx), Value::Array(array)) => array
+        .get(*idx)
+        .map(|value| vec![(child(&idx.to_string()), value)])
+        .unwrap_or_default(),
+      (Segment::Wildcard, Value::Object(object)) => object.iter().map(|(key, value)| (child(key), value)).collect(),
+      (Segment::Wildcard, Value::Array(array)) => array
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| (child(&idx.to_string()), value))
+        .collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn select_values_and_pointers() {
+    let root = json!({
+      "vc": { "credentialSubject": { "given/name": "Alice", "degrees": [{ "type": "BSc" }, { "type": "MSc" }] } }
+    });
+
+    let path = JsonPath::parse("$.vc.credentialSubject['given/name']").unwrap();
+    assert_eq!(
+      path.select(&root),
+      vec![("/vc/credentialSubject/given~1name".to_owned(), &json!("Alice"))]
+    );
+
+    let path = JsonPath::parse("$.vc.credentialSubject.degrees[*].type").unwrap();
+    let selected: Vec<_> = path.select(&root).into_iter().map(|(pointer, _)| pointer).collect();
+    assert_eq!(
+      selected,
+      [
+        "/vc/credentialSubject/degrees/0/type",
+        "/vc/credentialSubject/degrees/1/type"
+      ]
+    );
+
+    assert!(JsonPath::parse("$.vc.missing[3]").unwrap().select(&root).is_empty());
+  }
+
+  #[test]
+  fn unsupported_paths_are_rejected() {
+    for path in ["vc.type", "$..type", "$.vc[?(@.type)]", "$.vc[0:2]", "$.vc['type", "$."] {
+      assert!(JsonPath::parse(path).is_err(), "{path}");
+    }
+  }
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Support for [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/).
+//!
+//! A Verifier describes the credentials it requests with a [`PresentationDefinition`]. A Holder evaluates its
+//! credentials - wrapped in [`CandidateCredential`]s - against it with [`PresentationDefinition::evaluate`], and
+//! selects the ones to present together with the [`PresentationSubmission`] describing them with
+//! [`PresentationDefinition::submit`].
+
+mod definition;
+mod error;
+mod evaluation;
+mod json_path;
+mod submission;
+
+pub use definition::*;
+pub use error::*;
+pub use evaluation::*;
+pub use submission::*;
@@ -0,0 +1,50 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Describes how the credentials submitted by a Holder satisfy a
+/// [`PresentationDefinition`](super::PresentationDefinition), as defined in [DIF Presentation Exchange 2.0](https://identity.foundation/presentation-exchange/spec/v2.0.0/#presentation-submission).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresentationSubmission {
+  /// Identifier of this submission.
+  pub id: String,
+  /// Identifier of the satisfied [`PresentationDefinition`](super::PresentationDefinition).
+  pub definition_id: String,
+  /// Location of the credential satisfying each input descriptor.
+  pub descriptor_map: Vec<DescriptorMapEntry>,
+}
+
+/// Location of the credential satisfying an [`InputDescriptor`](super::InputDescriptor).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DescriptorMapEntry {
+  /// Identifier of the satisfied input descriptor.
+  pub id: String,
+  /// Claim format of the object found at `path`.
+  pub format: String,
+  /// JSONPath expression locating the object within the submitted data.
+  pub path: String,
+  /// Location of the credential within the object found at `path`, e.g. within a verifiable presentation.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub path_nested: Option<Box<DescriptorMapEntry>>,
+}
+
+impl DescriptorMapEntry {
+  /// Creates a new [`DescriptorMapEntry`].
+  pub fn new(id: impl Into<String>, format: impl Into<String>, path: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      format: format.into(),
+      path: path.into(),
+      path_nested: None,
+    }
+  }
+
+  /// Sets the location of the credential within the object found at this entry's path.
+  #[must_use]
+  pub fn path_nested(mut self, nested: DescriptorMapEntry) -> Self {
+    self.path_nested = Some(Box::new(nested));
+    self
+  }
+}
@@ -0,0 +1,397 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [European Learning Model](https://europa.eu/europass/elm-browser/) credential profile, used by European
+//! Digital Credentials for Learning.
+
+use std::collections::BTreeMap;
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::check_issuer_type;
+use super::check_non_empty;
+use super::check_type;
+use super::CredentialProfile;
+use super::ProfileError;
+use crate::credential::CredentialV2;
+
+/// The European Digital Credentials application profile JSON-LD context.
+pub const ELM_CONTEXT: &str = "http://data.europa.eu/snb/model/context/edc-ap";
+
+/// The types of the claims a [`Person`] may hold.
+const CLAIM_TYPES: &[&str] = &[
+  "LearningAchievement",
+  "LearningActivity",
+  "LearningAssessment",
+  "LearningEntitlement",
+];
+
+/// A text in one or more languages, mapping language tags - e.g. `en` - to the text in that language.
+pub type LangString = BTreeMap<String, String>;
+
+/// The [European Digital Credential](https://europa.eu/europass/elm-browser/documentation/rdf/ap/edc/documentation/edc-ap.html)
+/// profile, whose subject is a [`Person`].
+///
+/// On top of the subject's rules, a European Digital Credential must be issued by an `Organisation` object.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct EuropeanDigitalCredential;
+
+impl CredentialProfile for EuropeanDigitalCredential {
+  type Subject = Person;
+
+  const CONTEXTS: &'static [&'static str] = &[ELM_CONTEXT];
+  const TYPES: &'static [&'static str] = &["EuropeanDigitalCredential"];
+
+  fn check_subject(person: &Person) -> Result<(), ProfileError> {
+    check_type(&person.types, "Person", "")?;
+    for (name, pointer) in [
+      (&person.given_name, "/givenName"),
+      (&person.family_name, "/familyName"),
+      (&person.full_name, "/fullName"),
+    ] {
+      if let Some(name) = name {
+        check_lang_string(name, pointer)?;
+      }
+    }
+    if person.has_claim.is_empty() {
+      return Err(ProfileError::violation("/hasClaim", "must contain at least one claim"));
+    }
+
+    for (index, claim) in person.has_claim.iter().enumerate() {
+      let pointer = format!("/hasClaim/{index}");
+      match claim {
+        LearningClaim::Achievement(achievement) => check_achievement(achievement, &pointer)?,
+        LearningClaim::Other(claim) => {
+          let types: OneOrMany<String> = claim
+            .get("type")
+            .cloned()
+            .and_then(|types| serde_json::from_value(types).ok())
+            .unwrap_or_default();
+          if types.iter().any(|type_| type_ == "LearningAchievement") {
+            return Err(ProfileError::violation(pointer, "malformed `LearningAchievement`"));
+          }
+          if !types.iter().any(|type_| CLAIM_TYPES.contains(&type_.as_str())) {
+            return Err(ProfileError::violation(
+              format!("{pointer}/type"),
+              format!("must contain one of {}", CLAIM_TYPES.join(", ")),
+            ));
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  fn check_credential(credential: &CredentialV2) -> Result<(), ProfileError> {
+    check_issuer_type(&credential.issuer, "Organisation")
+  }
+}
+
+fn check_lang_string(text: &LangString, pointer: &str) -> Result<(), ProfileError> {
+  if text.is_empty() {
+    return Err(ProfileError::violation(pointer, "must contain at least one language"));
+  }
+  for (language, text) in text {
+    check_non_empty(text, &format!("{pointer}/{language}"))?;
+  }
+
+  Ok(())
+}
+
+fn check_achievement(achievement: &LearningAchievement, pointer: &str) -> Result<(), ProfileError> {
+  check_type(&achievement.types, "LearningAchievement", pointer)?;
+  check_lang_string(&achievement.title, &format!("{pointer}/title"))?;
+
+  let awarded_by = &achievement.awarded_by;
+  check_type(&awarded_by.types, "AwardingProcess", &format!("{pointer}/awardedBy"))?;
+  if awarded_by.awarding_body.is_empty() {
+    return Err(ProfileError::violation(
+      format!("{pointer}/awardedBy/awardingBody"),
+      "must contain at least one organisation",
+    ));
+  }
+  for (index, organisation) in awarded_by.awarding_body.iter().enumerate() {
+    let pointer = format!("{pointer}/awardedBy/awardingBody/{index}");
+    check_type(&organisation.types, "Organisation", &pointer)?;
+    check_lang_string(&organisation.legal_name, &format!("{pointer}/legalName"))?;
+  }
+
+  if let Some(specification) = &achievement.specified_by {
+    let pointer = format!("{pointer}/specifiedBy");
+    if !specification
+      .types
+      .iter()
+      .any(|type_| type_ == "LearningAchievementSpecification" || type_ == "Qualification")
+    {
+      return Err(ProfileError::violation(
+        format!("{pointer}/type"),
+        "must contain `LearningAchievementSpecification` or `Qualification`",
+      ));
+    }
+    check_lang_string(&specification.title, &format!("{pointer}/title"))?;
+  }
+
+  Ok(())
+}
+
+/// The holder of a European Digital Credential.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+  /// Identifier of the person.
+  pub id: Url,
+  /// Types of the person, which must contain `Person`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The given name of the person.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub given_name: Option<LangString>,
+  /// The family name of the person.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub family_name: Option<LangString>,
+  /// The full name of the person.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub full_name: Option<LangString>,
+  /// The date of birth of the person.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub date_of_birth: Option<Timestamp>,
+  /// The claims - achievements, activities, assessments and entitlements - the credential makes about the person.
+  pub has_claim: Vec<LearningClaim>,
+  /// Miscellaneous properties.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl Person {
+  /// Creates a new [`Person`] identified by `id`, holding `claims`.
+  pub fn new(id: Url, claims: Vec<LearningClaim>) -> Self {
+    Self {
+      id,
+      types: OneOrMany::One("Person".to_owned()),
+      given_name: None,
+      family_name: None,
+      full_name: None,
+      date_of_birth: None,
+      has_claim: claims,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// A claim made about a [`Person`].
+///
+/// Only learning achievements are typed. The other claims - learning activities, assessments and entitlements - are
+/// kept as plain objects.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LearningClaim {
+  /// A learning achievement.
+  Achievement(LearningAchievement),
+  /// Any other claim.
+  Other(Object),
+}
+
+impl From<LearningAchievement> for LearningClaim {
+  fn from(achievement: LearningAchievement) -> Self {
+    Self::Achievement(achievement)
+  }
+}
+
+/// The acquisition of knowledge, skills or responsibility and autonomy, e.g. a diploma.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LearningAchievement {
+  /// Identifier of the achievement.
+  pub id: Url,
+  /// Types of the achievement, which must contain `LearningAchievement`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The title of the achievement.
+  pub title: LangString,
+  /// A description of the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub description: Option<LangString>,
+  /// The process through which the achievement was awarded.
+  pub awarded_by: AwardingProcess,
+  /// The specification of the achievement, e.g. a qualification.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub specified_by: Option<LearningAchievementSpecification>,
+  /// Miscellaneous properties.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl LearningAchievement {
+  /// Creates a new [`LearningAchievement`] titled `title`, awarded through `awarded_by`.
+  pub fn new(id: Url, title: LangString, awarded_by: AwardingProcess) -> Self {
+    Self {
+      id,
+      types: OneOrMany::One("LearningAchievement".to_owned()),
+      title,
+      description: None,
+      awarded_by,
+      specified_by: None,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// The process through which a [`LearningAchievement`] is awarded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AwardingProcess {
+  /// Identifier of the process.
+  pub id: Url,
+  /// Types of the process, which must contain `AwardingProcess`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The organisations awarding the achievement.
+  pub awarding_body: OneOrMany<Organisation>,
+  /// When the achievement was awarded.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub awarding_date: Option<Timestamp>,
+  /// Miscellaneous properties.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl AwardingProcess {
+  /// Creates a new [`AwardingProcess`] run by `awarding_body`.
+  pub fn new(id: Url, awarding_body: Organisation) -> Self {
+    Self {
+      id,
+      types: OneOrMany::One("AwardingProcess".to_owned()),
+      awarding_body: OneOrMany::One(awarding_body),
+      awarding_date: None,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// An organisation, e.g. a university.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Organisation {
+  /// Identifier of the organisation.
+  pub id: Url,
+  /// Types of the organisation, which must contain `Organisation`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The legal name of the organisation.
+  pub legal_name: LangString,
+  /// Miscellaneous properties, e.g. the organisation's location or registration.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl Organisation {
+  /// Creates a new [`Organisation`] named `legal_name`.
+  pub fn new(id: Url, legal_name: LangString) -> Self {
+    Self {
+      id,
+      types: OneOrMany::One("Organisation".to_owned()),
+      legal_name,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// The specification of a [`LearningAchievement`], e.g. a qualification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LearningAchievementSpecification {
+  /// Identifier of the specification.
+  pub id: Url,
+  /// Types of the specification, which must contain `LearningAchievementSpecification` or `Qualification`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The title of the specification.
+  pub title: LangString,
+  /// Miscellaneous properties, e.g. the specification's EQF level or credit points.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Issuer;
+
+  fn english(text: &str) -> LangString {
+    LangString::from([("en".to_owned(), text.to_owned())])
+  }
+
+  fn university() -> Organisation {
+    Organisation::new(
+      Url::parse("did:example:university").unwrap(),
+      english("Example University"),
+    )
+  }
+
+  fn person() -> Person {
+    let achievement = LearningAchievement::new(
+      Url::parse("urn:epass:learningAchievement:1").unwrap(),
+      english("Master of Science in Computer Science"),
+      AwardingProcess::new(Url::parse("urn:epass:awardingProcess:1").unwrap(), university()),
+    );
+    let entitlement = json!({
+      "id": "urn:epass:learningEntitlement:1",
+      "type": "LearningEntitlement",
+      "title": { "en": "Right to enroll in a PhD programme" }
+    });
+
+    Person::new(
+      Url::parse("did:example:student").unwrap(),
+      vec![achievement.into(), serde_json::from_value(entitlement).unwrap()],
+    )
+  }
+
+  fn builder() -> CredentialBuilder {
+    let issuer: Issuer = serde_json::to_value(university())
+      .and_then(serde_json::from_value)
+      .unwrap();
+    CredentialBuilder::default().issuer(issuer)
+  }
+
+  #[test]
+  fn build_european_digital_credential() {
+    let credential = EuropeanDigitalCredential::build(&person(), builder()).unwrap();
+
+    let subjects = EuropeanDigitalCredential::subjects(&credential).unwrap();
+    assert_eq!(subjects, [person()]);
+    assert!(matches!(subjects[0].has_claim[0], LearningClaim::Achievement(_)));
+    assert!(matches!(subjects[0].has_claim[1], LearningClaim::Other(_)));
+  }
+
+  #[test]
+  fn european_digital_credential_violations() {
+    let mut person = person();
+    let LearningClaim::Achievement(achievement) = &mut person.has_claim[0] else {
+      unreachable!()
+    };
+    achievement.awarded_by.awarding_body = OneOrMany::One(Organisation::new(
+      Url::parse("did:example:university").unwrap(),
+      LangString::new(),
+    ));
+    assert!(matches!(
+      EuropeanDigitalCredential::build(&person, builder()),
+      Err(ProfileError::Violation { pointer, .. }) if pointer == "/credentialSubject/hasClaim/0/awardedBy/awardingBody/0/legalName"
+    ));
+
+    person.has_claim.clear();
+    assert!(matches!(
+      EuropeanDigitalCredential::build(&person, builder()),
+      Err(ProfileError::Violation { pointer, .. }) if pointer == "/credentialSubject/hasClaim"
+    ));
+  }
+}
@@ -0,0 +1,49 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// An error caused by a credential that doesn't conform to a [`CredentialProfile`](super::CredentialProfile).
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum ProfileError {
+  /// Caused by a context required by the profile that is missing from the credential.
+  #[error("missing required context `{0}`")]
+  MissingContext(String),
+  /// Caused by a type required by the profile that is missing from the credential.
+  #[error("missing required type `{0}`")]
+  MissingType(String),
+  /// Caused by a credential subject that cannot be (de)serialized as the profile's subject type.
+  #[error("malformed credential subject")]
+  MalformedSubject(#[source] serde_json::Error),
+  /// Caused by a property of the credential that doesn't satisfy one of the profile's rules.
+  #[error("`{pointer}` violates profile rule: {reason}")]
+  Violation {
+    /// JSON pointer to the offending property.
+    pointer: String,
+    /// A description of the violated rule.
+    reason: String,
+  },
+  /// Caused by a failure to build the credential.
+  #[error("invalid credential")]
+  Credential(#[source] crate::Error),
+}
+
+impl ProfileError {
+  /// Creates a new [`ProfileError::Violation`].
+  pub(crate) fn violation(pointer: impl Into<String>, reason: impl Into<String>) -> Self {
+    Self::Violation {
+      pointer: pointer.into(),
+      reason: reason.into(),
+    }
+  }
+
+  /// Prefixes the pointer of a [`ProfileError::Violation`] with `prefix`.
+  pub(crate) fn within(self, prefix: &str) -> Self {
+    match self {
+      Self::Violation { pointer, reason } => Self::Violation {
+        pointer: format!("{prefix}{pointer}"),
+        reason,
+      },
+      error => error,
+    }
+  }
+}
@@ -0,0 +1,19 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Credential profiles: typed credential subjects, together with the contexts, types and validation rules required
+//! by well-known credential formats built on the [VC Data Model 2.0](https://www.w3.org/TR/vc-data-model-2.0/).
+//!
+//! A [`CredentialProfile`] builds [`CredentialV2`](crate::credential::CredentialV2)s carrying its contexts and types,
+//! and validates received credentials against its rules. The following profiles are available:
+//! - [`open_badges::OpenBadgeCredential`], for [Open Badges 3.0](https://www.imsglobal.org/spec/ob/v3p0/);
+//! - [`elm::EuropeanDigitalCredential`], for the [European Learning Model](https://europa.eu/europass/elm-browser/)
+//!   credentials issued through Europass.
+
+pub mod elm;
+mod error;
+pub mod open_badges;
+mod profile;
+
+pub use error::*;
+pub use profile::*;
@@ -0,0 +1,500 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [Open Badges 3.0](https://www.imsglobal.org/spec/ob/v3p0/) credential profile.
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::check_issuer_type;
+use super::check_non_empty;
+use super::check_type;
+use super::CredentialProfile;
+use super::ProfileError;
+use crate::credential::CredentialV2;
+
+/// The Open Badges 3.0 JSON-LD context.
+pub const OPEN_BADGES_CONTEXT: &str = "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.3.json";
+
+/// The [Open Badges 3.0 credential](https://www.imsglobal.org/spec/ob/v3p0/#achievementcredential) profile, whose
+/// subject is an [`AchievementSubject`].
+///
+/// On top of the subject's rules, an Open Badge credential must have an `id`, a `name`, and an issuer described
+/// as a `Profile` object.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct OpenBadgeCredential;
+
+impl CredentialProfile for OpenBadgeCredential {
+  type Subject = AchievementSubject;
+
+  const CONTEXTS: &'static [&'static str] = &[OPEN_BADGES_CONTEXT];
+  const TYPES: &'static [&'static str] = &["OpenBadgeCredential"];
+
+  fn check_subject(subject: &AchievementSubject) -> Result<(), ProfileError> {
+    check_type(&subject.types, "AchievementSubject", "")?;
+    if subject.id.is_none() && subject.identifier.is_empty() {
+      return Err(ProfileError::violation(
+        "/id",
+        "either `id` or `identifier` must be set",
+      ));
+    }
+    for (index, identifier) in subject.identifier.iter().enumerate() {
+      let pointer = format!("/identifier/{index}");
+      check_type(&identifier.types, "IdentityObject", &pointer)?;
+      check_non_empty(&identifier.identity_hash, &format!("{pointer}/identityHash"))?;
+    }
+    if let (Some(start), Some(end)) = (subject.activity_start_date, subject.activity_end_date) {
+      if start > end {
+        return Err(ProfileError::violation(
+          "/activityEndDate",
+          "must not precede `activityStartDate`",
+        ));
+      }
+    }
+    for (index, result) in subject.result.iter().enumerate() {
+      check_type(&result.types, "Result", &format!("/result/{index}"))?;
+    }
+    if let Some(image) = &subject.image {
+      check_type(&image.types, "Image", "/image")?;
+    }
+
+    check_achievement(&subject.achievement)
+  }
+
+  fn check_credential(credential: &CredentialV2) -> Result<(), ProfileError> {
+    if credential.id.is_none() {
+      return Err(ProfileError::violation("/id", "must be set"));
+    }
+    match credential.properties.get("name") {
+      Some(serde_json::Value::String(name)) => check_non_empty(name, "/name")?,
+      _ => return Err(ProfileError::violation("/name", "must be a string")),
+    }
+
+    check_issuer_type(&credential.issuer, "Profile")
+  }
+}
+
+fn check_achievement(achievement: &Achievement) -> Result<(), ProfileError> {
+  check_type(&achievement.types, "Achievement", "/achievement")?;
+  check_non_empty(&achievement.name, "/achievement/name")?;
+  check_non_empty(&achievement.description, "/achievement/description")?;
+  if achievement.criteria.id.is_none() && achievement.criteria.narrative.is_none() {
+    return Err(ProfileError::violation(
+      "/achievement/criteria",
+      "either `id` or `narrative` must be set",
+    ));
+  }
+  for (index, alignment) in achievement.alignment.iter().enumerate() {
+    let pointer = format!("/achievement/alignment/{index}");
+    check_type(&alignment.types, "Alignment", &pointer)?;
+    check_non_empty(&alignment.target_name, &format!("{pointer}/targetName"))?;
+  }
+  if let Some(image) = &achievement.image {
+    check_type(&image.types, "Image", "/achievement/image")?;
+  }
+
+  Ok(())
+}
+
+/// The recipient of an Open Badge and the achievement they are awarded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementSubject {
+  /// Identifier of the recipient. Either this or [`AchievementSubject::identifier`] must be set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<Url>,
+  /// Types of the subject, which must contain `AchievementSubject`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The awarded achievement.
+  pub achievement: Achievement,
+  /// Other identifiers of the recipient, e.g. a hashed email address.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub identifier: Vec<IdentityObject>,
+  /// When the recipient started the activity leading to the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub activity_start_date: Option<Timestamp>,
+  /// When the recipient completed the activity leading to the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub activity_end_date: Option<Timestamp>,
+  /// Credits earned by the recipient.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub credits_earned: Option<f64>,
+  /// The license number issued with the achievement, if any.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub license_number: Option<String>,
+  /// A narrative of how the recipient met the achievement's criteria.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub narrative: Option<String>,
+  /// The results obtained by the recipient.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub result: Vec<AchievementResult>,
+  /// The role of the recipient, e.g. `Team lead`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub role: Option<String>,
+  /// The academic term in which the achievement was earned.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub term: Option<String>,
+  /// An image representing the recipient's achievement, e.g. a baked badge.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub image: Option<Image>,
+  /// Miscellaneous properties.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl AchievementSubject {
+  /// Creates a new [`AchievementSubject`] awarding `achievement` to the recipient identified by `id`.
+  pub fn new(id: Url, achievement: Achievement) -> Self {
+    Self {
+      id: Some(id),
+      types: OneOrMany::One("AchievementSubject".to_owned()),
+      achievement,
+      identifier: Vec::new(),
+      activity_start_date: None,
+      activity_end_date: None,
+      credits_earned: None,
+      license_number: None,
+      narrative: None,
+      result: Vec::new(),
+      role: None,
+      term: None,
+      image: None,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// An accomplishment that can be awarded through an Open Badge.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Achievement {
+  /// Identifier of the achievement.
+  pub id: Url,
+  /// Types of the achievement, which must contain `Achievement`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The name of the achievement.
+  pub name: String,
+  /// A short description of the achievement.
+  pub description: String,
+  /// The criteria for earning the achievement.
+  pub criteria: Criteria,
+  /// The type of achievement, e.g. `Badge`, `Certificate` or `Degree`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub achievement_type: Option<String>,
+  /// Frameworks the achievement is aligned with.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub alignment: Vec<Alignment>,
+  /// The profile of the achievement's creator, when different from the credential issuer.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub creator: Option<Object>,
+  /// Credits available for the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub credits_available: Option<f64>,
+  /// Category of the achievement within an educational framework, e.g. `Computer Science`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub field_of_study: Option<String>,
+  /// The code used by the issuer to refer to the achievement, e.g. a course code.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub human_code: Option<String>,
+  /// An image representing the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub image: Option<Image>,
+  /// The language of the achievement, as a BCP 47 tag.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub in_language: Option<String>,
+  /// Name given to the focus, concentration, or specific area of study.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub specialization: Option<String>,
+  /// Tags describing the achievement.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub tag: Vec<String>,
+  /// The version of the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  /// Miscellaneous properties.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl Achievement {
+  /// Creates a new [`Achievement`].
+  pub fn new(id: Url, name: impl Into<String>, description: impl Into<String>, criteria: Criteria) -> Self {
+    Self {
+      id,
+      types: OneOrMany::One("Achievement".to_owned()),
+      name: name.into(),
+      description: description.into(),
+      criteria,
+      achievement_type: None,
+      alignment: Vec::new(),
+      creator: None,
+      credits_available: None,
+      field_of_study: None,
+      human_code: None,
+      image: None,
+      in_language: None,
+      specialization: None,
+      tag: Vec::new(),
+      version: None,
+      properties: Object::new(),
+    }
+  }
+}
+
+/// The criteria for earning an [`Achievement`]. At least one of its properties must be set.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Criteria {
+  /// URL of a page describing the criteria.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<Url>,
+  /// A narrative of what is needed to earn the achievement.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub narrative: Option<String>,
+}
+
+impl Criteria {
+  /// Creates new [`Criteria`] described by `narrative`.
+  pub fn narrative(narrative: impl Into<String>) -> Self {
+    Self {
+      id: None,
+      narrative: Some(narrative.into()),
+    }
+  }
+}
+
+/// An image, e.g. a badge picture.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Image {
+  /// URL or data URL of the image.
+  pub id: Url,
+  /// Types of the image, which must contain `Image`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// The caption of the image.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub caption: Option<String>,
+}
+
+impl Image {
+  /// Creates a new [`Image`] located at `id`.
+  pub fn new(id: Url) -> Self {
+    Self {
+      id,
+      types: OneOrMany::One("Image".to_owned()),
+      caption: None,
+    }
+  }
+}
+
+/// An alignment of an [`Achievement`] with a node of an educational framework.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alignment {
+  /// Types of the alignment, which must contain `Alignment`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// Name of the framework node.
+  pub target_name: String,
+  /// URL of the framework node.
+  pub target_url: Url,
+  /// Description of the framework node.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub target_description: Option<String>,
+  /// Identifier of the framework node within the framework.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub target_code: Option<String>,
+  /// Name of the framework.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub target_framework: Option<String>,
+  /// Type of the framework node, e.g. `ceasn:Competency`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub target_type: Option<String>,
+}
+
+impl Alignment {
+  /// Creates a new [`Alignment`] with the framework node named `target_name`, located at `target_url`.
+  pub fn new(target_name: impl Into<String>, target_url: Url) -> Self {
+    Self {
+      types: OneOrMany::One("Alignment".to_owned()),
+      target_name: target_name.into(),
+      target_url,
+      target_description: None,
+      target_code: None,
+      target_framework: None,
+      target_type: None,
+    }
+  }
+}
+
+/// An identifier of an [`AchievementSubject`], possibly hashed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityObject {
+  /// Types of the identifier, which must contain `IdentityObject`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// Whether `identity_hash` is hashed.
+  pub hashed: bool,
+  /// The identifier, or its hash - e.g. `sha256$<hex>` - when `hashed` is set.
+  pub identity_hash: String,
+  /// The type of the identifier, e.g. `emailAddress`.
+  pub identity_type: String,
+  /// The salt used to hash the identifier.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub salt: Option<String>,
+}
+
+impl IdentityObject {
+  /// Creates a new plain [`IdentityObject`] of type `identity_type`.
+  pub fn new(identity_type: impl Into<String>, identity: impl Into<String>) -> Self {
+    Self {
+      types: OneOrMany::One("IdentityObject".to_owned()),
+      hashed: false,
+      identity_hash: identity.into(),
+      identity_type: identity_type.into(),
+      salt: None,
+    }
+  }
+
+  /// Creates a new [`IdentityObject`] of type `identity_type` from the hash of the identifier, salted with `salt`.
+  pub fn hashed(identity_type: impl Into<String>, hash: impl Into<String>, salt: Option<String>) -> Self {
+    Self {
+      hashed: true,
+      salt,
+      ..Self::new(identity_type, hash)
+    }
+  }
+}
+
+/// A result obtained by the recipient of an [`Achievement`], e.g. a grade.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementResult {
+  /// Types of the result, which must contain `Result`.
+  #[serde(rename = "type")]
+  pub types: OneOrMany<String>,
+  /// Reference to the result description of the achievement this result is about.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub result_description: Option<Url>,
+  /// Reference to the achieved level of the achievement's rubric.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub achieved_level: Option<Url>,
+  /// Frameworks the result is aligned with.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub alignment: Vec<Alignment>,
+  /// The status of the achievement, e.g. `Completed`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub status: Option<String>,
+  /// The value of the result, e.g. `A+`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub value: Option<String>,
+}
+
+impl AchievementResult {
+  /// Creates a new [`AchievementResult`] with the given `value`.
+  pub fn new(value: impl Into<String>) -> Self {
+    Self {
+      types: OneOrMany::One("Result".to_owned()),
+      result_description: None,
+      achieved_level: None,
+      alignment: Vec::new(),
+      status: None,
+      value: Some(value.into()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Context;
+  use identity_core::common::Object;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Issuer;
+
+  fn issuer() -> Issuer {
+    serde_json::from_value(json!({
+      "id": "did:example:university",
+      "type": ["Profile"],
+      "name": "Example University"
+    }))
+    .unwrap()
+  }
+
+  fn subject() -> AchievementSubject {
+    let achievement = Achievement::new(
+      Url::parse("https://example.edu/achievements/rust-101").unwrap(),
+      "Rust 101",
+      "Introduction to Rust programming.",
+      Criteria::narrative("Complete all the exercises."),
+    );
+    let mut subject = AchievementSubject::new(Url::parse("did:example:student").unwrap(), achievement);
+    subject.result.push(AchievementResult::new("A"));
+    subject
+  }
+
+  fn builder() -> CredentialBuilder {
+    CredentialBuilder::<Object>::default()
+      .id(Url::parse("https://example.edu/credentials/3732").unwrap())
+      .issuer(issuer())
+      .property("name", "Rust 101 badge")
+  }
+
+  #[test]
+  fn build_open_badge() {
+    let credential = OpenBadgeCredential::build(&subject(), builder()).unwrap();
+
+    assert!(matches!(credential.context.get(1), Some(Context::Url(url)) if url.as_str() == OPEN_BADGES_CONTEXT));
+    assert_eq!(
+      credential.types.iter().map(String::as_str).collect::<Vec<_>>(),
+      ["VerifiableCredential", "OpenBadgeCredential"]
+    );
+    assert_eq!(OpenBadgeCredential::subjects(&credential).unwrap(), [subject()]);
+  }
+
+  #[test]
+  fn open_badge_violations() {
+    let mut no_criteria = subject();
+    no_criteria.achievement.criteria = Criteria::default();
+    assert!(matches!(
+      OpenBadgeCredential::build(&no_criteria, builder()),
+      Err(ProfileError::Violation { pointer, .. }) if pointer == "/credentialSubject/achievement/criteria"
+    ));
+
+    let mut anonymous = subject();
+    anonymous.id = None;
+    assert!(matches!(
+      OpenBadgeCredential::build(&anonymous, builder()),
+      Err(ProfileError::Violation { pointer, .. }) if pointer == "/credentialSubject/id"
+    ));
+    anonymous
+      .identifier
+      .push(IdentityObject::new("emailAddress", "student@example.edu"));
+    assert!(OpenBadgeCredential::build(&anonymous, builder()).is_ok());
+
+    let url_issuer = builder().issuer(Url::parse("did:example:university").unwrap());
+    assert!(matches!(
+      OpenBadgeCredential::build(&subject(), url_issuer),
+      Err(ProfileError::Violation { pointer, .. }) if pointer == "/issuer"
+    ));
+
+    let credential = CredentialBuilder::<Object>::default()
+      .issuer(issuer())
+      .subject(OpenBadgeCredential::to_subject(&subject()).unwrap())
+      .build_v2()
+      .unwrap();
+    assert!(matches!(
+      OpenBadgeCredential::validate(&credential),
+      Err(ProfileError::MissingContext(context)) if context == OPEN_BADGES_CONTEXT
+    ));
+  }
+}
@@ -0,0 +1,169 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Context;
+use identity_core::common::OneOrMany;
+use identity_core::common::Url;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::credential::CredentialBuilder;
+use crate::credential::CredentialV2;
+use crate::credential::Issuer;
+use crate::credential::Subject;
+
+use super::ProfileError;
+
+type Result<T, E = ProfileError> = std::result::Result<T, E>;
+
+/// A credential format built on [`CredentialV2`], defining the shape of its credential subjects and the contexts,
+/// types and rules its credentials must conform to.
+pub trait CredentialProfile {
+  /// The credential subject of this profile's credentials.
+  type Subject: Serialize + DeserializeOwned;
+
+  /// Contexts required by this profile, in addition to the VC Data Model 2.0 base context.
+  const CONTEXTS: &'static [&'static str];
+
+  /// Types required by this profile, in addition to `VerifiableCredential`.
+  const TYPES: &'static [&'static str];
+
+  /// Checks the profile's rules on a credential subject.
+  ///
+  /// Pointers of the returned [`ProfileError::Violation`]s are relative to the subject.
+  fn check_subject(subject: &Self::Subject) -> Result<()>;
+
+  /// Checks the profile's rules on the properties of a credential that are not part of its subjects.
+  fn check_credential(_credential: &CredentialV2) -> Result<()> {
+    Ok(())
+  }
+
+  /// Builds a credential about `subject`, adding this profile's contexts and types to the ones already in `builder`.
+  ///
+  /// The issuer, validity period and additional properties are taken from `builder`, which must not contain other
+  /// subjects. The resulting credential is validated against this profile.
+  fn build(subject: &Self::Subject, mut builder: CredentialBuilder) -> Result<CredentialV2> {
+    for context in Self::CONTEXTS {
+      let context = Url::parse(*context)
+        .map(Context::Url)
+        .map_err(|_| ProfileError::violation("/@context", format!("invalid context `{context}`")))?;
+      if !builder.context.contains(&context) {
+        builder = builder.context(context);
+      }
+    }
+    for type_ in Self::TYPES {
+      if !builder.types.iter().any(|t| t == type_) {
+        builder = builder.type_(*type_);
+      }
+    }
+
+    let credential = builder
+      .subject(Self::to_subject(subject)?)
+      .build_v2()
+      .map_err(ProfileError::Credential)?;
+    Self::validate(&credential)?;
+
+    Ok(credential)
+  }
+
+  /// Validates `credential` against this profile.
+  fn validate(credential: &CredentialV2) -> Result<()> {
+    for context in Self::CONTEXTS {
+      if !credential
+        .context
+        .iter()
+        .any(|c| matches!(c, Context::Url(url) if url.as_str() == *context))
+      {
+        return Err(ProfileError::MissingContext((*context).to_owned()));
+      }
+    }
+    for type_ in Self::TYPES {
+      if !credential.types.iter().any(|t| t == type_) {
+        return Err(ProfileError::MissingType((*type_).to_owned()));
+      }
+    }
+
+    for (pointer, subject) in subject_pointers(&credential.credential_subject) {
+      let subject = Self::from_subject(subject)?;
+      Self::check_subject(&subject).map_err(|e| e.within(&pointer))?;
+    }
+
+    Self::check_credential(credential)
+  }
+
+  /// Returns the subjects of `credential`, without validating them.
+  fn subjects(credential: &CredentialV2) -> Result<Vec<Self::Subject>> {
+    credential.credential_subject.iter().map(Self::from_subject).collect()
+  }
+
+  /// Converts a profile subject into a credential [`Subject`].
+  fn to_subject(subject: &Self::Subject) -> Result<Subject> {
+    serde_json::to_value(subject)
+      .and_then(serde_json::from_value)
+      .map_err(ProfileError::MalformedSubject)
+  }
+
+  /// Converts a credential [`Subject`] into a profile subject.
+  fn from_subject(subject: &Subject) -> Result<Self::Subject> {
+    serde_json::to_value(subject)
+      .and_then(serde_json::from_value)
+      .map_err(ProfileError::MalformedSubject)
+  }
+}
+
+/// Pairs each subject with its JSON pointer within the credential.
+fn subject_pointers(subjects: &OneOrMany<Subject>) -> Vec<(String, &Subject)> {
+  match subjects {
+    OneOrMany::One(subject) => vec![("/credentialSubject".to_owned(), subject)],
+    OneOrMany::Many(subjects) => subjects
+      .iter()
+      .enumerate()
+      .map(|(index, subject)| (format!("/credentialSubject/{index}"), subject))
+      .collect(),
+  }
+}
+
+/// Checks that `types` contains `expected`.
+pub(crate) fn check_type(types: &OneOrMany<String>, expected: &str, pointer: &str) -> Result<()> {
+  if types.iter().any(|type_| type_ == expected) {
+    Ok(())
+  } else {
+    Err(ProfileError::violation(
+      format!("{pointer}/type"),
+      format!("must contain `{expected}`"),
+    ))
+  }
+}
+
+/// Checks that `value` is not empty.
+pub(crate) fn check_non_empty(value: &str, pointer: &str) -> Result<()> {
+  if value.trim().is_empty() {
+    Err(ProfileError::violation(pointer, "must not be empty"))
+  } else {
+    Ok(())
+  }
+}
+
+/// Checks that the credential's issuer is an object whose `type` contains `expected`.
+pub(crate) fn check_issuer_type(issuer: &Issuer, expected: &str) -> Result<()> {
+  let Issuer::Obj(issuer) = issuer else {
+    return Err(ProfileError::violation(
+      "/issuer",
+      format!("must be a `{expected}` object"),
+    ));
+  };
+
+  let is_expected = match issuer.properties.get("type") {
+    Some(serde_json::Value::String(type_)) => type_ == expected,
+    Some(serde_json::Value::Array(types)) => types.iter().any(|type_| type_ == expected),
+    _ => false,
+  };
+  if is_expected {
+    Ok(())
+  } else {
+    Err(ProfileError::violation(
+      "/issuer/type",
+      format!("must contain `{expected}`"),
+    ))
+  }
+}
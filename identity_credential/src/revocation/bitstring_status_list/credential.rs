@@ -0,0 +1,407 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use identity_core::common::Context;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::credential::CredentialBuilder;
+use crate::credential::CredentialV2;
+use crate::credential::Issuer;
+use crate::credential::Proof;
+use crate::credential::Status;
+use crate::credential::Subject;
+use crate::revocation::status_list_2021::CredentialStatus;
+use crate::revocation::status_list_2021::StatusPurpose;
+
+use super::BitstringStatusList;
+use super::BitstringStatusListEntry;
+use super::BitstringStatusListError;
+
+/// The type of a `BitstringStatusListCredential`.
+pub const BITSTRING_STATUS_LIST_CREDENTIAL_TYPE: &str = "BitstringStatusListCredential";
+const CREDENTIAL_SUBJECT_TYPE: &str = "BitstringStatusList";
+
+/// [Error](std::error::Error) type that represents the possible errors that can be
+/// encountered when dealing with [`BitstringStatusListCredential`]s.
+#[derive(Clone, Debug, Error, strum::IntoStaticStr, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BitstringStatusListCredentialError {
+  /// The provided credential has more than one `credentialSubject`.
+  #[error("a BitstringStatusListCredential may only have one credentialSubject")]
+  MultipleCredentialSubject,
+  /// The provided credential has an invalid property.
+  #[error("invalid property \"{0}\"")]
+  InvalidProperty(&'static str),
+  /// The provided credential doesn't have a mandatory property.
+  #[error("missing property \"{0}\"")]
+  MissingProperty(&'static str),
+  /// Inner status list failures.
+  #[error(transparent)]
+  StatusList(#[from] BitstringStatusListError),
+  /// Missing status list credential id.
+  #[error("cannot set the status of a credential without the status list credential's \"id\"")]
+  Unreferenceable,
+  /// Credentials cannot be unrevoked.
+  #[error("a previously revoked credential cannot be unrevoked")]
+  UnreversibleRevocation,
+}
+
+/// A parsed [BitstringStatusListCredential](https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistcredential).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "CredentialV2", into = "CredentialV2")]
+pub struct BitstringStatusListCredential {
+  inner: CredentialV2,
+  subject: BitstringStatusListSubject,
+}
+
+impl Display for BitstringStatusListCredential {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.clone().into_inner())
+  }
+}
+
+impl From<BitstringStatusListCredential> for CredentialV2 {
+  fn from(value: BitstringStatusListCredential) -> Self {
+    value.into_inner()
+  }
+}
+
+impl Deref for BitstringStatusListCredential {
+  type Target = CredentialV2;
+  fn deref(&self) -> &Self::Target {
+    &self.inner
+  }
+}
+
+impl TryFrom<CredentialV2> for BitstringStatusListCredential {
+  type Error = BitstringStatusListCredentialError;
+  fn try_from(mut credential: CredentialV2) -> Result<Self, Self::Error> {
+    if !credential
+      .types
+      .iter()
+      .any(|type_| type_ == BITSTRING_STATUS_LIST_CREDENTIAL_TYPE)
+    {
+      return Err(BitstringStatusListCredentialError::InvalidProperty("type"));
+    }
+    let subject = BitstringStatusListSubject::try_from_credential(&mut credential)?;
+
+    Ok(Self {
+      inner: credential,
+      subject,
+    })
+  }
+}
+
+impl BitstringStatusListCredential {
+  /// Returns the inner "raw" [`CredentialV2`].
+  pub fn into_inner(self) -> CredentialV2 {
+    let Self { mut inner, subject } = self;
+    inner.credential_subject = OneOrMany::One(subject.into());
+    inner
+  }
+
+  /// Returns the purpose of this status list.
+  pub fn purpose(&self) -> StatusPurpose {
+    self.subject.status_purpose
+  }
+
+  fn status_list(&self) -> Result<BitstringStatusList, BitstringStatusListError> {
+    BitstringStatusList::try_from_encoded_str(&self.subject.encoded_list)
+  }
+
+  /// Sets the status of `credential`, mapping it to the `index`-th entry of this status list.
+  ///
+  /// ## Note:
+  /// - A revoked credential cannot ever be unrevoked and will lead to a
+  ///   [`BitstringStatusListCredentialError::UnreversibleRevocation`].
+  /// - Trying to set `revoked_or_suspended` to `false` for an already valid credential will have no impact.
+  pub fn set_credential_status<T>(
+    &mut self,
+    credential: &mut CredentialV2<T>,
+    index: usize,
+    revoked_or_suspended: bool,
+  ) -> Result<BitstringStatusListEntry, BitstringStatusListCredentialError> {
+    let status_list_url = self
+      .inner
+      .id
+      .clone()
+      .ok_or(BitstringStatusListCredentialError::Unreferenceable)?;
+    let entry = BitstringStatusListEntry::new(status_list_url, self.purpose(), index, None);
+
+    self.set_entry(index, revoked_or_suspended)?;
+    credential.credential_status = Some(Status::from(entry.clone()));
+
+    Ok(entry)
+  }
+
+  /// Sets the `index`-th entry to `value`.
+  pub fn set_entry(&mut self, index: usize, value: bool) -> Result<(), BitstringStatusListCredentialError> {
+    let mut status_list = self.status_list()?;
+    if self.purpose() == StatusPurpose::Revocation && !value && status_list.get(index)? {
+      return Err(BitstringStatusListCredentialError::UnreversibleRevocation);
+    }
+    status_list.set(index, value)?;
+    self.subject.encoded_list = status_list.into_encoded_str();
+
+    Ok(())
+  }
+
+  /// Returns the status of the `index-th` entry.
+  pub fn entry(&self, index: usize) -> Result<CredentialStatus, BitstringStatusListCredentialError> {
+    let status_list = self.status_list()?;
+    Ok(match (self.purpose(), status_list.get(index)?) {
+      (StatusPurpose::Revocation, true) => CredentialStatus::Revoked,
+      (StatusPurpose::Suspension, true) => CredentialStatus::Suspended,
+      _ => CredentialStatus::Valid,
+    })
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct BitstringStatusListSubject {
+  id: Option<Url>,
+  status_purpose: StatusPurpose,
+  encoded_list: String,
+}
+
+impl From<BitstringStatusListSubject> for Subject {
+  fn from(value: BitstringStatusListSubject) -> Self {
+    let properties = [
+      ("type".to_owned(), Value::String(CREDENTIAL_SUBJECT_TYPE.to_owned())),
+      (
+        "statusPurpose".to_owned(),
+        Value::String(value.status_purpose.to_string()),
+      ),
+      ("encodedList".to_owned(), Value::String(value.encoded_list)),
+    ]
+    .into_iter()
+    .collect();
+
+    if let Some(id) = value.id {
+      Subject::with_id_and_properties(id, properties)
+    } else {
+      Subject::with_properties(properties)
+    }
+  }
+}
+
+impl BitstringStatusListSubject {
+  /// Parses the status list subject out of a credential, without copying.
+  fn try_from_credential(credential: &mut CredentialV2) -> Result<Self, BitstringStatusListCredentialError> {
+    let OneOrMany::One(mut subject) = std::mem::take(&mut credential.credential_subject) else {
+      return Err(BitstringStatusListCredentialError::MultipleCredentialSubject);
+    };
+    match subject.properties.get("type") {
+      Some(subject_type) if subject_type.as_str() == Some(CREDENTIAL_SUBJECT_TYPE) => (),
+      Some(_) => {
+        return Err(BitstringStatusListCredentialError::InvalidProperty(
+          "credentialSubject.type",
+        ))
+      }
+      None => {
+        return Err(BitstringStatusListCredentialError::MissingProperty(
+          "credentialSubject.type",
+        ))
+      }
+    }
+    let status_purpose = subject
+      .properties
+      .get("statusPurpose")
+      .ok_or(BitstringStatusListCredentialError::MissingProperty(
+        "credentialSubject.statusPurpose",
+      ))?
+      .as_str()
+      .and_then(|purpose| StatusPurpose::from_str(purpose).ok())
+      .ok_or(BitstringStatusListCredentialError::InvalidProperty(
+        "credentialSubject.statusPurpose",
+      ))?;
+    let encoded_list = match subject.properties.get_mut("encodedList") {
+      Some(Value::String(encoded_list)) => std::mem::take(encoded_list),
+      Some(_) => {
+        return Err(BitstringStatusListCredentialError::InvalidProperty(
+          "credentialSubject.encodedList",
+        ))
+      }
+      None => {
+        return Err(BitstringStatusListCredentialError::MissingProperty(
+          "credentialSubject.encodedList",
+        ))
+      }
+    };
+
+    Ok(Self {
+      id: subject.id,
+      status_purpose,
+      encoded_list,
+    })
+  }
+}
+
+/// Builder type for [`BitstringStatusListCredential`].
+#[derive(Debug, Default)]
+pub struct BitstringStatusListCredentialBuilder {
+  inner_builder: CredentialBuilder,
+  credential_subject: BitstringStatusListSubject,
+}
+
+impl BitstringStatusListCredentialBuilder {
+  /// Creates a new [`BitstringStatusListCredentialBuilder`] from a [`BitstringStatusList`].
+  pub fn new(status_list: BitstringStatusList) -> Self {
+    let credential_subject = BitstringStatusListSubject {
+      encoded_list: status_list.into_encoded_str(),
+      ..Default::default()
+    };
+    Self {
+      credential_subject,
+      ..Default::default()
+    }
+  }
+
+  /// Sets the credential's `id`, i.e. the URL the status list credential is published at.
+  pub fn id(mut self, id: Url) -> Self {
+    self.inner_builder.id = Some(id);
+    self
+  }
+
+  /// Sets `credentialSubject.statusPurpose`.
+  pub const fn purpose(mut self, purpose: StatusPurpose) -> Self {
+    self.credential_subject.status_purpose = purpose;
+    self
+  }
+
+  /// Sets `credentialSubject.id`.
+  pub fn subject_id(mut self, id: Url) -> Self {
+    self.credential_subject.id = Some(id);
+    self
+  }
+
+  /// Sets `validUntil`.
+  pub const fn valid_until(mut self, time: Timestamp) -> Self {
+    self.inner_builder.expiration_date = Some(time);
+    self
+  }
+
+  /// Sets `issuer`.
+  pub fn issuer(mut self, issuer: Issuer) -> Self {
+    self.inner_builder.issuer = Some(issuer);
+    self
+  }
+
+  /// Adds a `@context` entry.
+  pub fn context(mut self, ctx: Context) -> Self {
+    self.inner_builder.context.push(ctx);
+    self
+  }
+
+  /// Adds a `type` entry.
+  pub fn add_type(mut self, type_: String) -> Self {
+    self.inner_builder.types.push(type_);
+    self
+  }
+
+  /// Adds a credential proof.
+  pub fn proof(mut self, proof: Proof) -> Self {
+    self.inner_builder.proof = Some(proof);
+    self
+  }
+
+  /// Consumes this [`BitstringStatusListCredentialBuilder`] into a [`BitstringStatusListCredential`].
+  pub fn build(self) -> Result<BitstringStatusListCredential, crate::Error> {
+    let subject: Subject = self.credential_subject.clone().into();
+    self
+      .inner_builder
+      .type_(BITSTRING_STATUS_LIST_CREDENTIAL_TYPE)
+      .issuance_date(Timestamp::now_utc())
+      .subject(subject)
+      .build_v2()
+      .map(|mut credential| {
+        credential.credential_subject = OneOrMany::default();
+        BitstringStatusListCredential {
+          inner: credential,
+          subject: self.credential_subject,
+        }
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Example from the Bitstring Status List specification.
+  const BITSTRING_STATUS_LIST_CREDENTIAL_SAMPLE: &str = r#"
+{
+  "@context": ["https://www.w3.org/ns/credentials/v2"],
+  "id": "https://example.com/credentials/status/3",
+  "type": ["VerifiableCredential", "BitstringStatusListCredential"],
+  "issuer": "did:example:12345",
+  "validFrom": "2021-04-05T14:27:40Z",
+  "credentialSubject": {
+    "id": "https://example.com/status/3#list",
+    "type": "BitstringStatusList",
+    "statusPurpose": "revocation",
+    "encodedList": "uH4sIAAAAAAAAA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AYbSVKsAQAAA"
+  }
+}
+  "#;
+
+  fn status_list_credential(purpose: StatusPurpose) -> BitstringStatusListCredential {
+    let url = Url::parse("https://example.com/credentials/status/3").unwrap();
+    BitstringStatusListCredentialBuilder::new(BitstringStatusList::default())
+      .id(url.clone())
+      .issuer(Issuer::Url(url))
+      .purpose(purpose)
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn bitstring_status_list_credential_deserialization_works() {
+    let credential = serde_json::from_str::<BitstringStatusListCredential>(BITSTRING_STATUS_LIST_CREDENTIAL_SAMPLE)
+      .expect("Failed to deserialize");
+    assert_eq!(credential.purpose(), StatusPurpose::Revocation);
+    assert_eq!(credential.entry(94567).unwrap(), CredentialStatus::Valid);
+
+    let roundtrip = serde_json::to_value(&credential).unwrap();
+    let expected: Value = serde_json::from_str(BITSTRING_STATUS_LIST_CREDENTIAL_SAMPLE).unwrap();
+    assert_eq!(roundtrip["credentialSubject"], expected["credentialSubject"]);
+  }
+
+  #[test]
+  fn status_list_2021_credentials_are_rejected() {
+    let credential = BITSTRING_STATUS_LIST_CREDENTIAL_SAMPLE.replace("\"BitstringStatusList", "\"StatusList2021");
+    assert!(serde_json::from_str::<BitstringStatusListCredential>(&credential).is_err());
+  }
+
+  #[test]
+  fn revoked_credential_cannot_be_unrevoked() {
+    let mut status_list_credential = status_list_credential(StatusPurpose::Revocation);
+
+    status_list_credential.set_entry(420, true).unwrap();
+    assert_eq!(status_list_credential.entry(420).unwrap(), CredentialStatus::Revoked);
+    assert_eq!(
+      status_list_credential.set_entry(420, false),
+      Err(BitstringStatusListCredentialError::UnreversibleRevocation)
+    );
+  }
+
+  #[test]
+  fn suspended_credential_can_be_unsuspended() {
+    let mut status_list_credential = status_list_credential(StatusPurpose::Suspension);
+
+    status_list_credential.set_entry(420, true).unwrap();
+    assert_eq!(status_list_credential.entry(420).unwrap(), CredentialStatus::Suspended);
+    status_list_credential.set_entry(420, false).unwrap();
+    assert_eq!(status_list_credential.entry(420).unwrap(), CredentialStatus::Valid);
+  }
+}
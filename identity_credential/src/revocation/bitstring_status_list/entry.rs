@@ -0,0 +1,145 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::credential::Status;
+use crate::revocation::status_list_2021::StatusPurpose;
+
+const CREDENTIAL_STATUS_TYPE: &str = "BitstringStatusListEntry";
+
+fn deserialize_status_entry_type<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let type_ = String::deserialize(deserializer)?;
+  if type_ == CREDENTIAL_STATUS_TYPE {
+    Ok(type_)
+  } else {
+    Err(serde::de::Error::custom(format!("not \"{CREDENTIAL_STATUS_TYPE}\"")))
+  }
+}
+
+/// Serialize usize as string.
+fn serialize_number_as_string<S>(value: &usize, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: serde::Serializer,
+{
+  serializer.serialize_str(&value.to_string())
+}
+
+/// [BitstringStatusListEntry](https://www.w3.org/TR/vc-bitstring-status-list/#bitstringstatuslistentry)
+/// implementation.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BitstringStatusListEntry {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  id: Option<Url>,
+  #[serde(rename = "type", deserialize_with = "deserialize_status_entry_type")]
+  type_: String,
+  status_purpose: StatusPurpose,
+  #[serde(
+    deserialize_with = "serde_aux::prelude::deserialize_number_from_string",
+    serialize_with = "serialize_number_as_string"
+  )]
+  status_list_index: usize,
+  status_list_credential: Url,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  status_size: Option<usize>,
+}
+
+impl TryFrom<&Status> for BitstringStatusListEntry {
+  type Error = serde_json::Error;
+  fn try_from(status: &Status) -> Result<Self, Self::Error> {
+    let json_status = serde_json::to_value(status)?;
+    serde_json::from_value(json_status)
+  }
+}
+
+impl From<BitstringStatusListEntry> for Status {
+  fn from(entry: BitstringStatusListEntry) -> Self {
+    let json_status = serde_json::to_value(entry).unwrap(); // Safety: shouldn't go out of memory
+    serde_json::from_value(json_status).unwrap() // Safety: `BitstringStatusListEntry` is a credential status
+  }
+}
+
+impl BitstringStatusListEntry {
+  /// Creates a new [`BitstringStatusListEntry`] pointing to the `index`-th entry of the status list credential
+  /// published at `status_list`.
+  pub fn new(status_list: Url, purpose: StatusPurpose, index: usize, id: Option<Url>) -> Self {
+    Self {
+      id,
+      type_: CREDENTIAL_STATUS_TYPE.to_owned(),
+      status_purpose: purpose,
+      status_list_index: index,
+      status_list_credential: status_list,
+      status_size: None,
+    }
+  }
+
+  /// Returns this `credentialStatus`'s `id`, if any.
+  pub const fn id(&self) -> Option<&Url> {
+    self.id.as_ref()
+  }
+
+  /// Returns the purpose of this entry.
+  pub const fn purpose(&self) -> StatusPurpose {
+    self.status_purpose
+  }
+
+  /// Returns the index of this entry.
+  pub const fn index(&self) -> usize {
+    self.status_list_index
+  }
+
+  /// Returns the referenced [`BitstringStatusListCredential`](super::BitstringStatusListCredential)'s [`Url`].
+  pub const fn status_list_credential(&self) -> &Url {
+    &self.status_list_credential
+  }
+
+  /// Returns the size, in bits, of this entry's status. Defaults to `1`.
+  pub fn status_size(&self) -> usize {
+    self.status_size.unwrap_or(1)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn entry_serialization_roundtrip() {
+    let json = serde_json::json!({
+      "id": "https://example.com/credentials/status/3#94567",
+      "type": "BitstringStatusListEntry",
+      "statusPurpose": "revocation",
+      "statusListIndex": "94567",
+      "statusListCredential": "https://example.com/credentials/status/3"
+    });
+    let entry = serde_json::from_value::<BitstringStatusListEntry>(json.clone()).unwrap();
+    assert_eq!(
+      entry,
+      BitstringStatusListEntry::new(
+        Url::parse("https://example.com/credentials/status/3").unwrap(),
+        StatusPurpose::Revocation,
+        94567,
+        Url::parse("https://example.com/credentials/status/3#94567").ok(),
+      )
+    );
+    assert_eq!(entry.status_size(), 1);
+    assert_eq!(serde_json::to_value(&entry).unwrap(), json);
+  }
+
+  #[test]
+  fn deserializing_wrong_status_type_fails() {
+    let status = serde_json::json!({
+      "type": "StatusList2021Entry",
+      "statusPurpose": "revocation",
+      "statusListIndex": "94567",
+      "statusListCredential": "https://example.com/credentials/status/3"
+    });
+    assert!(serde_json::from_value::<BitstringStatusListEntry>(status).is_err());
+  }
+}
@@ -0,0 +1,13 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [Bitstring Status List](https://www.w3.org/TR/vc-bitstring-status-list/), the status list
+//! mechanism of the VC Data Model 2.0.
+
+mod credential;
+mod entry;
+mod status_list;
+
+pub use credential::*;
+pub use entry::*;
+pub use status_list::*;
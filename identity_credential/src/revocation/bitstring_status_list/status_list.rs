@@ -0,0 +1,206 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::io::Read;
+use std::io::Write;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use thiserror::Error;
+
+const MINIMUM_LIST_SIZE: usize = 16 * 1024 * 8;
+/// The maximum number of entries, i.e. 16MiB once decompressed, bounding the memory a crafted `encodedList` -
+/// which GZIP compresses extremely well - can make a verifier allocate.
+const MAXIMUM_LIST_SIZE: usize = 16 * 1024 * 1024 * 8;
+
+/// [`std::error::Error`] type for [`BitstringStatusList`]'s operations.
+#[derive(Debug, Error, PartialEq, Eq, Clone, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum BitstringStatusListError {
+  /// Requested entry is not in the list.
+  #[error("the requested entry is not in the list")]
+  IndexOutOfBounds,
+  /// Improperly encoded status list.
+  #[error("\"{0}\" is not a valid encoded bitstring status list")]
+  InvalidEncoding(String),
+  /// Invalid list size.
+  #[error("a bitstring status list must have at least {MINIMUM_LIST_SIZE} entries")]
+  InvalidListSize,
+  /// The list exceeds the maximum supported size.
+  #[error("a bitstring status list must have at most {MAXIMUM_LIST_SIZE} entries")]
+  ListTooLarge,
+}
+
+/// A bitstring status list, as described in [W3C's Bitstring Status List](https://www.w3.org/TR/vc-bitstring-status-list/).
+///
+/// Each entry is a single bit: entries with a `statusSize` greater than 1 are not supported.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct BitstringStatusList(Box<[u8]>);
+
+impl Default for BitstringStatusList {
+  fn default() -> Self {
+    BitstringStatusList::new(MINIMUM_LIST_SIZE).unwrap()
+  }
+}
+
+impl BitstringStatusList {
+  /// Returns a new zero-filled [`BitstringStatusList`] that can hold `num_entries` credential statuses.
+  ///
+  /// ## Notes:
+  /// - The actual length of the list will be rounded up to the closest multiple of 8 to accommodate for byte sizes.
+  /// - `num_entries` must be at least 131,072 which corresponds to a size of 16KB, and at most 134,217,728 which
+  ///   corresponds to a size of 16MiB.
+  pub fn new(num_entries: usize) -> Result<Self, BitstringStatusListError> {
+    if num_entries < MINIMUM_LIST_SIZE {
+      return Err(BitstringStatusListError::InvalidListSize);
+    }
+    if num_entries > MAXIMUM_LIST_SIZE {
+      return Err(BitstringStatusListError::ListTooLarge);
+    }
+
+    let size = (num_entries + 7) / 8;
+    Ok(BitstringStatusList(vec![0; size].into_boxed_slice()))
+  }
+
+  /// Returns the number of entries.
+  #[allow(clippy::len_without_is_empty)]
+  pub const fn len(&self) -> usize {
+    self.0.len() * 8
+  }
+
+  /// Returns the status of the `index`-th entry, if it exists.
+  ///
+  /// The first entry is the left-most bit of the bitstring.
+  pub fn get(&self, index: usize) -> Result<bool, BitstringStatusListError> {
+    if index >= self.len() {
+      return Err(BitstringStatusListError::IndexOutOfBounds);
+    }
+    let (byte, bit) = (index / 8, index % 8);
+    Ok(self.0[byte] & (0b1000_0000 >> bit) != 0)
+  }
+
+  /// Sets the status of the `index`-th entry to `value`.
+  pub fn set(&mut self, index: usize, value: bool) -> Result<(), BitstringStatusListError> {
+    if index >= self.len() {
+      return Err(BitstringStatusListError::IndexOutOfBounds);
+    }
+    let (byte, bit) = (index / 8, index % 8);
+    if value {
+      self.0[byte] |= 0b1000_0000 >> bit;
+    } else {
+      self.0[byte] &= !(0b1000_0000 >> bit);
+    }
+    Ok(())
+  }
+
+  /// Attempts to parse a [`BitstringStatusList`] from the multibase-encoded, base64url, GZIP-compressed string
+  /// found in a status list credential's `encodedList`, following the
+  /// [bitstring expansion algorithm](https://www.w3.org/TR/vc-bitstring-status-list/#bitstring-expansion-algorithm).
+  pub fn try_from_encoded_str(s: &str) -> Result<Self, BitstringStatusListError> {
+    let invalid_encoding = || BitstringStatusListError::InvalidEncoding(s.to_owned());
+    // The specification mandates the base64url multibase encoding, identified by the `u` prefix.
+    if !s.starts_with('u') {
+      return Err(invalid_encoding());
+    }
+    let compressed_status_list = BaseEncoding::decode_multibase(s).map_err(|_| invalid_encoding())?;
+
+    // Inflate at most one byte past the maximum size, to detect oversized lists without decompressing them fully.
+    let mut status_list = vec![];
+    GzDecoder::new(&compressed_status_list[..])
+      .take((MAXIMUM_LIST_SIZE / 8 + 1) as u64)
+      .read_to_end(&mut status_list)
+      .map_err(|_| invalid_encoding())?;
+    if status_list.len() * 8 > MAXIMUM_LIST_SIZE {
+      return Err(BitstringStatusListError::ListTooLarge);
+    }
+    if status_list.len() * 8 < MINIMUM_LIST_SIZE {
+      return Err(BitstringStatusListError::InvalidListSize);
+    }
+
+    Ok(BitstringStatusList(status_list.into_boxed_slice()))
+  }
+
+  /// Encodes this [`BitstringStatusList`] into its string representation, following the
+  /// [bitstring generation algorithm](https://www.w3.org/TR/vc-bitstring-status-list/#bitstring-generation-algorithm).
+  pub fn into_encoded_str(self) -> String {
+    let compressed_status_list = {
+      let mut compressor = GzEncoder::new(vec![], Compression::best());
+      compressor.write_all(&self.0).unwrap();
+      compressor.finish().unwrap()
+    };
+
+    BaseEncoding::encode_multibase(&compressed_status_list, Some(Base::Base64Url))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn status_list_entry_access() {
+    let mut status_list = BitstringStatusList::default();
+    status_list.set(0, true).unwrap();
+    assert_eq!(status_list.0[0], 0b1000_0000);
+    status_list.set(131071, true).unwrap();
+    assert!(status_list.get(131071).unwrap());
+    assert_eq!(
+      status_list.set(131072, true),
+      Err(BitstringStatusListError::IndexOutOfBounds)
+    );
+
+    status_list.set(0, false).unwrap();
+    status_list.set(131071, false).unwrap();
+    assert_eq!(status_list, BitstringStatusList::default());
+  }
+
+  #[test]
+  fn status_list_too_short_fails() {
+    assert_eq!(
+      BitstringStatusList::new(100),
+      Err(BitstringStatusListError::InvalidListSize)
+    );
+  }
+
+  #[test]
+  fn status_list_encode_decode() {
+    let mut status_list = BitstringStatusList::default();
+    status_list.set(42, true).unwrap();
+    status_list.set(4200, true).unwrap();
+    let encoded = status_list.clone().into_encoded_str();
+    assert!(encoded.starts_with('u'));
+    assert_eq!(
+      BitstringStatusList::try_from_encoded_str(&encoded).unwrap(),
+      status_list
+    );
+  }
+
+  #[test]
+  fn status_list_too_large_fails() {
+    assert_eq!(
+      BitstringStatusList::new(MAXIMUM_LIST_SIZE + 1),
+      Err(BitstringStatusListError::ListTooLarge)
+    );
+
+    // A few KiB of GZIP inflating past the maximum size.
+    let mut compressor = GzEncoder::new(vec![], Compression::best());
+    compressor.write_all(&vec![0; MAXIMUM_LIST_SIZE / 8 + 1]).unwrap();
+    let encoded = BaseEncoding::encode_multibase(&compressor.finish().unwrap(), Some(Base::Base64Url));
+    assert_eq!(
+      BitstringStatusList::try_from_encoded_str(&encoded),
+      Err(BitstringStatusListError::ListTooLarge)
+    );
+  }
+
+  #[test]
+  fn status_list_without_multibase_prefix_fails() {
+    let encoded = BitstringStatusList::default().into_encoded_str();
+    assert!(matches!(
+      BitstringStatusList::try_from_encoded_str(&encoded[1..]),
+      Err(BitstringStatusListError::InvalidEncoding(_))
+    ));
+  }
+}
@@ -114,7 +114,7 @@ impl StatusList2021Credential {
     self.subject.status_purpose
   }
 
-  fn status_list(&self) -> Result<StatusList2021, StatusListError> {
+  pub(super) fn status_list(&self) -> Result<StatusList2021, StatusListError> {
     StatusList2021::try_from_encoded_str(&self.subject.encoded_list)
   }
 
@@ -158,6 +158,35 @@ impl StatusList2021Credential {
     Ok(())
   }
 
+  /// Sets many entries of this status list at once, returning the entries whose value changed.
+  ///
+  /// Updates are applied atomically: if any of them fails - e.g. because it would unrevoke a
+  /// revoked credential - the status list is left untouched.
+  pub fn update_entries<U>(&mut self, updates: U) -> Result<Vec<StatusListChange>, StatusList2021CredentialError>
+  where
+    U: IntoIterator<Item = (usize, bool)>,
+  {
+    let mut status_list = self.status_list()?;
+    let mut changes = Vec::new();
+    for (index, value) in updates {
+      let previous = status_list.get(index)?;
+      if self.purpose() == StatusPurpose::Revocation && !value && previous {
+        return Err(StatusList2021CredentialError::UnreversibleRevocation);
+      }
+      if previous != value {
+        status_list.set(index, value)?;
+        changes.push(StatusListChange {
+          index,
+          previous,
+          current: value,
+        });
+      }
+    }
+    self.subject.encoded_list = status_list.into_encoded_str();
+
+    Ok(changes)
+  }
+
   /// Sets the `index`-th entry to `value`
   pub(crate) fn set_entry(&mut self, index: usize, value: bool) -> Result<(), StatusList2021CredentialError> {
     let mut status_list = self.status_list()?;
@@ -201,6 +230,17 @@ impl MutStatusList {
   }
 }
 
+/// A change to an entry of a [`StatusList2021Credential`], as returned by [`StatusList2021Credential::update_entries`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StatusListChange {
+  /// The index of the changed entry.
+  pub index: usize,
+  /// The value of the entry before the change.
+  pub previous: bool,
+  /// The value of the entry after the change.
+  pub current: bool,
+}
+
 /// The status of a credential referenced inside a [`StatusList2021Credential`]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum CredentialStatus {
@@ -486,4 +526,42 @@ mod tests {
     status_list_credential.set_entry(420, true).unwrap();
     assert!(status_list_credential.set_entry(420, false).is_ok());
   }
+
+  #[test]
+  fn bulk_updates_are_atomic() {
+    let url = Url::parse("http://example.com").unwrap();
+    let mut status_list_credential = StatusList2021CredentialBuilder::new(StatusList2021::default())
+      .issuer(Issuer::Url(url.clone()))
+      .purpose(StatusPurpose::Revocation)
+      .subject_id(url)
+      .build()
+      .unwrap();
+
+    let changes = status_list_credential
+      .update_entries([(1, true), (2, true), (3, false)])
+      .unwrap();
+    assert_eq!(
+      changes,
+      vec![
+        StatusListChange {
+          index: 1,
+          previous: false,
+          current: true
+        },
+        StatusListChange {
+          index: 2,
+          previous: false,
+          current: true
+        },
+      ]
+    );
+
+    let encoded_list = status_list_credential.subject.encoded_list.clone();
+    assert_eq!(
+      status_list_credential.update_entries([(4, true), (1, false)]),
+      Err(StatusList2021CredentialError::UnreversibleRevocation)
+    );
+    assert_eq!(status_list_credential.subject.encoded_list, encoded_list);
+    assert_eq!(status_list_credential.entry(4).unwrap(), CredentialStatus::Valid);
+  }
 }
@@ -0,0 +1,508 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::IndexAllocatorError;
+use super::IndexAllocatorErrorKind;
+
+/// Result of index allocation operations.
+pub type IndexAllocatorResult<T> = Result<T, IndexAllocatorError>;
+
+/// Hands out unique status list entries to issued credentials.
+///
+/// Implementations must guarantee that an entry is never handed out twice, even across restarts,
+/// which implies that an allocation must be persisted before it is returned. `identity_storage` provides
+/// implementations backed by a file and by memory.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait IndexAllocator: allocator_sub_trait::IndexAllocatorSendSyncMaybe {
+  /// Allocates a status list entry for the credential identified by `credential_id`.
+  ///
+  /// Allocating an entry for a credential that already has one returns the existing entry.
+  /// If the current status list is exhausted a new one is started and
+  /// [`IndexAllocation::new_status_list`] is set, signaling that the new list must be created and published.
+  async fn allocate(&self, credential_id: &str) -> IndexAllocatorResult<IndexAllocation>;
+
+  /// Returns the status list entry allocated for the credential identified by `credential_id`, if any.
+  async fn get_index(&self, credential_id: &str) -> IndexAllocatorResult<Option<StatusListIndex>>;
+
+  /// Returns the usage of every status list managed by this allocator, from the oldest to the current one.
+  async fn status_lists(&self) -> IndexAllocatorResult<Vec<StatusListUsage>>;
+}
+
+#[cfg(target_arch = "wasm32")]
+mod allocator_sub_trait {
+  pub trait IndexAllocatorSendSyncMaybe {}
+  impl<S: super::IndexAllocator> IndexAllocatorSendSyncMaybe for S {}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod allocator_sub_trait {
+  pub trait IndexAllocatorSendSyncMaybe: Send + Sync {}
+  impl<S: Send + Sync + super::IndexAllocator> IndexAllocatorSendSyncMaybe for S {}
+}
+
+/// An entry of a status list.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusListIndex {
+  /// The identifier of the status list.
+  pub status_list: Url,
+  /// The index of the entry in the status list.
+  pub index: usize,
+}
+
+/// The outcome of [`IndexAllocator::allocate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexAllocation {
+  /// The allocated entry.
+  pub index: StatusListIndex,
+  /// Whether the allocation started a new status list, that must be created before the entry is used.
+  pub new_status_list: bool,
+}
+
+/// How many entries of a status list have been allocated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusListUsage {
+  /// The identifier of the status list.
+  pub id: Url,
+  /// The number of entries of the status list.
+  pub capacity: usize,
+  /// The number of allocated entries.
+  pub allocated: usize,
+}
+
+impl StatusListUsage {
+  /// Returns whether all entries of the status list have been allocated.
+  pub fn is_exhausted(&self) -> bool {
+    self.allocated >= self.capacity
+  }
+
+  /// Returns the number of entries that can still be allocated.
+  pub fn remaining(&self) -> usize {
+    self.capacity.saturating_sub(self.allocated)
+  }
+}
+
+/// Configuration of the status lists managed by an [`IndexAllocator`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexAllocatorConfig {
+  /// The URL status list identifiers are derived from: the `n`-th status list is identified by
+  /// `base_url` joined with `n`, e.g. `https://example.com/status/3` for base URL `https://example.com/status/`.
+  pub base_url: Url,
+  /// The number of entries of each status list.
+  pub capacity: usize,
+}
+
+impl IndexAllocatorConfig {
+  /// The default number of entries of a status list, which is the minimum size mandated by the
+  /// StatusList2021 specification.
+  pub const DEFAULT_CAPACITY: usize = 16 * 1024 * 8;
+
+  /// Creates a new [`IndexAllocatorConfig`] for status lists of [`Self::DEFAULT_CAPACITY`] entries.
+  pub fn new(base_url: Url) -> Self {
+    Self {
+      base_url,
+      capacity: Self::DEFAULT_CAPACITY,
+    }
+  }
+
+  /// Sets the number of entries of each status list.
+  #[must_use]
+  pub fn capacity(mut self, capacity: usize) -> Self {
+    self.capacity = capacity;
+    self
+  }
+
+  fn status_list_id(&self, sequence_number: usize) -> IndexAllocatorResult<Url> {
+    self.base_url.join(sequence_number.to_string()).map_err(|err| {
+      IndexAllocatorError::new(IndexAllocatorErrorKind::InvalidConfiguration)
+        .with_custom_message("cannot derive the identifier of a new status list from the base URL")
+        .with_source(err)
+    })
+  }
+}
+
+/// The state of an [`IndexAllocator`], shared by the implementations provided by `identity_storage`.
+///
+/// Entries are drawn at random among the free entries of the current status list, so that their indices reveal
+/// nothing about the order in which credentials were issued. When the current status list is exhausted the next one is
+/// started.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "PersistedIndexAllocatorState", into = "PersistedIndexAllocatorState")]
+pub struct IndexAllocatorState {
+  config: IndexAllocatorConfig,
+  status_lists: Vec<StatusListUsage>,
+  allocations: BTreeMap<String, StatusListIndex>,
+  /// The allocated entries of the current status list, derived from `allocations`.
+  current_entries: BTreeSet<usize>,
+}
+
+/// The serialized form of an [`IndexAllocatorState`], which omits the data derived from the allocations.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersistedIndexAllocatorState {
+  config: IndexAllocatorConfig,
+  status_lists: Vec<StatusListUsage>,
+  allocations: BTreeMap<String, StatusListIndex>,
+}
+
+impl IndexAllocatorState {
+  /// Creates a new state, with no entry allocated, for status lists configured by `config`.
+  pub fn new(config: IndexAllocatorConfig) -> IndexAllocatorResult<Self> {
+    if config.capacity == 0 {
+      return Err(
+        IndexAllocatorError::new(IndexAllocatorErrorKind::InvalidConfiguration)
+          .with_custom_message("status lists must have at least one entry"),
+      );
+    }
+    config.status_list_id(0)?;
+
+    Ok(Self {
+      config,
+      status_lists: Vec::new(),
+      allocations: BTreeMap::new(),
+      current_entries: BTreeSet::new(),
+    })
+  }
+
+  /// Returns the configuration of this state.
+  pub fn config(&self) -> &IndexAllocatorConfig {
+    &self.config
+  }
+
+  /// Allocates a status list entry for `credential_id` - see [`IndexAllocator::allocate`].
+  pub fn allocate(&mut self, credential_id: &str) -> IndexAllocatorResult<IndexAllocation> {
+    if let Some(index) = self.allocations.get(credential_id) {
+      return Ok(IndexAllocation {
+        index: index.clone(),
+        new_status_list: false,
+      });
+    }
+
+    let allocation = self.draw()?;
+    self.record(credential_id, allocation.index.clone())?;
+
+    Ok(allocation)
+  }
+
+  /// Draws, without allocating it, a random free entry, starting a new status list if the current one is exhausted.
+  ///
+  /// The drawn entry is allocated by passing it to [`IndexAllocatorState::record`], which allows implementations to
+  /// persist an allocation before updating their state.
+  pub fn draw(&self) -> IndexAllocatorResult<IndexAllocation> {
+    let (index, new_status_list) = match self.next_status_list_id()? {
+      Some(id) => (
+        StatusListIndex {
+          status_list: id,
+          index: random_below(self.config.capacity)?,
+        },
+        true,
+      ),
+      None => {
+        let current = self
+          .status_lists
+          .last()
+          .expect("the current status list isn't exhausted");
+        let n = random_below(current.remaining())?;
+        (
+          StatusListIndex {
+            status_list: current.id.clone(),
+            index: nth_free_index(&self.current_entries, n),
+          },
+          false,
+        )
+      }
+    };
+
+    Ok(IndexAllocation { index, new_status_list })
+  }
+
+  /// Records that `index` has been allocated to `credential_id`, e.g. when it was drawn by
+  /// [`IndexAllocatorState::draw`] or when replaying allocations persisted by a state with the same configuration.
+  ///
+  /// Recording an allocation that is already part of this state has no effect.
+  ///
+  /// # Errors
+  /// Fails if `credential_id` was allocated another entry, or if `index` isn't a free entry of the current status
+  /// list - or of the next one, if the current status list is exhausted.
+  pub fn record(&mut self, credential_id: &str, index: StatusListIndex) -> IndexAllocatorResult<()> {
+    let inconsistent = |message: &'static str| {
+      IndexAllocatorError::new(IndexAllocatorErrorKind::InconsistentState).with_custom_message(message)
+    };
+
+    if let Some(allocated) = self.allocations.get(credential_id) {
+      return if allocated == &index {
+        Ok(())
+      } else {
+        Err(inconsistent("the credential was allocated another entry"))
+      };
+    }
+    if index.index >= self.config.capacity {
+      return Err(inconsistent("the entry is out of the bounds of the status list"));
+    }
+
+    match self.next_status_list_id()? {
+      Some(id) if id == index.status_list => {
+        self.status_lists.push(StatusListUsage {
+          id,
+          capacity: self.config.capacity,
+          allocated: 0,
+        });
+        self.current_entries.clear();
+      }
+      None if self.status_lists.last().map(|current| &current.id) == Some(&index.status_list) => {
+        if self.current_entries.contains(&index.index) {
+          return Err(inconsistent("the entry was already allocated"));
+        }
+      }
+      _ => return Err(inconsistent("the entry doesn't belong to the current status list")),
+    }
+
+    self
+      .status_lists
+      .last_mut()
+      .expect("a current status list exists")
+      .allocated += 1;
+    self.current_entries.insert(index.index);
+    self.allocations.insert(credential_id.to_owned(), index);
+
+    Ok(())
+  }
+
+  /// Returns the entry allocated for `credential_id`, if any.
+  pub fn get_index(&self, credential_id: &str) -> Option<&StatusListIndex> {
+    self.allocations.get(credential_id)
+  }
+
+  /// Returns the usage of every status list, from the oldest to the current one.
+  pub fn status_lists(&self) -> &[StatusListUsage] {
+    &self.status_lists
+  }
+
+  /// Checks that this state could have been produced by [`IndexAllocatorState::allocate`], e.g. after
+  /// loading it from an untrusted source.
+  pub fn check_consistency(&self) -> IndexAllocatorResult<()> {
+    let inconsistent = |message: &'static str| {
+      Err(IndexAllocatorError::new(IndexAllocatorErrorKind::InconsistentState).with_custom_message(message))
+    };
+
+    for (sequence_number, usage) in self.status_lists.iter().enumerate() {
+      if usage.capacity != self.config.capacity || usage.id != self.config.status_list_id(sequence_number)? {
+        return inconsistent("a status list doesn't match the configuration");
+      }
+      if usage.allocated > usage.capacity {
+        return inconsistent("a status list has more allocated entries than its capacity");
+      }
+      if sequence_number + 1 < self.status_lists.len() && !usage.is_exhausted() {
+        return inconsistent("only the current status list may have free entries");
+      }
+    }
+
+    let mut allocated: BTreeMap<&Url, BTreeSet<usize>> = BTreeMap::new();
+    for index in self.allocations.values() {
+      if index.index >= self.config.capacity || !allocated.entry(&index.status_list).or_default().insert(index.index) {
+        return inconsistent("an entry was allocated more than once or is out of bounds");
+      }
+    }
+    for usage in &self.status_lists {
+      let entries = allocated.remove(&usage.id).unwrap_or_default();
+      if entries.len() != usage.allocated {
+        return inconsistent("the allocated entries don't match the status list usage");
+      }
+    }
+    if !allocated.is_empty() {
+      return inconsistent("an entry was allocated in an unknown status list");
+    }
+
+    Ok(())
+  }
+
+  /// Returns the identifier of the status list to start if the current one is exhausted.
+  fn next_status_list_id(&self) -> IndexAllocatorResult<Option<Url>> {
+    if self.status_lists.last().map_or(true, StatusListUsage::is_exhausted) {
+      self.config.status_list_id(self.status_lists.len()).map(Some)
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+impl TryFrom<PersistedIndexAllocatorState> for IndexAllocatorState {
+  type Error = IndexAllocatorError;
+
+  fn try_from(persisted: PersistedIndexAllocatorState) -> Result<Self, Self::Error> {
+    let PersistedIndexAllocatorState {
+      config,
+      status_lists,
+      allocations,
+    } = persisted;
+    let mut state = Self {
+      config,
+      status_lists,
+      allocations,
+      current_entries: BTreeSet::new(),
+    };
+    state.check_consistency()?;
+
+    if let Some(current) = state.status_lists.last() {
+      state.current_entries = state
+        .allocations
+        .values()
+        .filter(|index| index.status_list == current.id)
+        .map(|index| index.index)
+        .collect();
+    }
+
+    Ok(state)
+  }
+}
+
+impl From<IndexAllocatorState> for PersistedIndexAllocatorState {
+  fn from(state: IndexAllocatorState) -> Self {
+    Self {
+      config: state.config,
+      status_lists: state.status_lists,
+      allocations: state.allocations,
+    }
+  }
+}
+
+/// Returns the `n`-th (0-based) index that is not in `allocated`.
+fn nth_free_index(allocated: &BTreeSet<usize>, n: usize) -> usize {
+  // Every allocated index up to the candidate shifts it by one.
+  let mut index = n;
+  for &allocated in allocated {
+    if allocated > index {
+      break;
+    }
+    index += 1;
+  }
+
+  index
+}
+
+/// Returns a random number in `0..bound`.
+fn random_below(bound: usize) -> IndexAllocatorResult<usize> {
+  let mut bytes = [0; 8];
+  crypto::utils::rand::fill(&mut bytes).map_err(|err| {
+    IndexAllocatorError::new(IndexAllocatorErrorKind::Unspecified)
+      .with_custom_message(format!("failed to draw a random entry: {err}"))
+  })?;
+  // Status lists are far smaller than `u64::MAX`, which makes the modulo bias negligible.
+  Ok((u64::from_le_bytes(bytes) % bound as u64) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use super::*;
+
+  fn state(capacity: usize) -> IndexAllocatorState {
+    let config = IndexAllocatorConfig::new(Url::parse("https://example.com/status/").unwrap()).capacity(capacity);
+    IndexAllocatorState::new(config).unwrap()
+  }
+
+  #[test]
+  fn allocation_rolls_over_to_new_status_lists() {
+    let mut state = state(2);
+
+    let first = state.allocate("credential-1").unwrap();
+    assert!(first.new_status_list);
+    assert_eq!(first.index.status_list.as_str(), "https://example.com/status/0");
+
+    let second = state.allocate("credential-2").unwrap();
+    assert!(!second.new_status_list);
+    assert_eq!(first.index.index + second.index.index, 1);
+    assert!(state.status_lists()[0].is_exhausted());
+
+    let third = state.allocate("credential-3").unwrap();
+    assert!(third.new_status_list);
+    assert_eq!(third.index.status_list.as_str(), "https://example.com/status/1");
+    assert!(third.index.index < 2);
+    assert_eq!(state.status_lists()[1].remaining(), 1);
+
+    state.check_consistency().unwrap();
+  }
+
+  #[test]
+  fn entries_are_drawn_at_random() {
+    let mut state = state(1024);
+    let indices: Vec<usize> = (0..16)
+      .map(|n| state.allocate(&format!("credential-{n}")).unwrap().index.index)
+      .collect();
+
+    assert_ne!(indices, (0..16).collect::<Vec<_>>());
+    assert_eq!(indices.iter().collect::<HashSet<_>>().len(), 16);
+
+    let free: Vec<usize> = (0..4).map(|n| nth_free_index(&[1, 3].into(), n)).collect();
+    assert_eq!(free, [0, 2, 4, 5]);
+  }
+
+  #[test]
+  fn allocation_is_idempotent() {
+    let mut state = state(10);
+    let first = state.allocate("credential-1").unwrap();
+    let again = state.allocate("credential-1").unwrap();
+
+    assert_eq!(first.index, again.index);
+    assert!(!again.new_status_list);
+    assert_eq!(state.status_lists()[0].allocated, 1);
+    assert_eq!(state.get_index("credential-1"), Some(&first.index));
+    assert_eq!(state.get_index("credential-2"), None);
+  }
+
+  #[test]
+  fn recorded_allocations_can_be_replayed() {
+    let mut state = state(10);
+    let allocation = state.allocate("credential-1").unwrap();
+
+    let mut replayed = self::state(10);
+    replayed.record("credential-1", allocation.index.clone()).unwrap();
+    replayed.record("credential-1", allocation.index.clone()).unwrap();
+    assert_eq!(replayed, state);
+
+    assert!(replayed.record("credential-2", allocation.index.clone()).is_err());
+    let mut foreign = allocation.index;
+    foreign.status_list = Url::parse("https://example.com/status/1").unwrap();
+    assert!(replayed.record("credential-2", foreign).is_err());
+  }
+
+  #[test]
+  fn invalid_states_are_detected() {
+    assert!(IndexAllocatorState::new(
+      IndexAllocatorConfig::new(Url::parse("https://example.com/status/").unwrap()).capacity(0)
+    )
+    .is_err());
+
+    let mut state = state(10);
+    state.allocate("credential-1").unwrap();
+    state.allocate("credential-2").unwrap();
+
+    let mut reused = state.clone();
+    reused.allocations.get_mut("credential-2").unwrap().index = state.allocations["credential-1"].index;
+    assert!(matches!(
+      reused.check_consistency().unwrap_err().kind(),
+      IndexAllocatorErrorKind::InconsistentState
+    ));
+
+    let mut rewound = state.clone();
+    rewound.status_lists[0].allocated = 1;
+    assert!(rewound.check_consistency().is_err());
+
+    let json = serde_json::to_value(&state).unwrap();
+    assert_eq!(serde_json::from_value::<IndexAllocatorState>(json).unwrap(), state);
+    let json = serde_json::to_value(&rewound).unwrap();
+    assert!(serde_json::from_value::<IndexAllocatorState>(json).is_err());
+  }
+}
@@ -0,0 +1,66 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use identity_core::common::SingleStructError;
+
+/// Error type for index allocation operations.
+pub type IndexAllocatorError = SingleStructError<IndexAllocatorErrorKind>;
+
+/// The cause of the failed index allocation operation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum IndexAllocatorErrorKind {
+  /// Indicates that the allocator's configuration is invalid, e.g. it doesn't allow a single entry per list
+  /// or the identifier of a new status list cannot be derived from it.
+  InvalidConfiguration,
+
+  /// Indicates that the persisted state of the allocator doesn't match its configuration or is corrupted.
+  InconsistentState,
+
+  /// Indicates that the storage backing the allocator is unavailable for an unpredictable amount of time.
+  Unavailable,
+
+  /// Indicates an unsuccessful I/O operation that may be retried, such as a temporary connection failure or timeouts.
+  ///
+  /// Returning this error signals to the caller that the operation may be retried with a chance of success.
+  /// No index is handed out by an operation failing with this error.
+  RetryableIOFailure,
+
+  /// Indicates a failure to serialize or deserialize.
+  SerializationError,
+
+  /// Indicates that something went wrong, but it is unclear whether the reason matches any of the other variants.
+  ///
+  /// When using this variant one may want to attach additional context to the corresponding [`IndexAllocatorError`].
+  /// See [`IndexAllocatorError::with_custom_message`](IndexAllocatorError::with_custom_message()) and
+  /// [`IndexAllocatorError::with_source`](IndexAllocatorError::with_source()).
+  Unspecified,
+}
+
+impl IndexAllocatorErrorKind {
+  /// Returns the string representation of the error.
+  pub const fn as_str(&self) -> &str {
+    match self {
+      Self::InvalidConfiguration => "invalid index allocator configuration",
+      Self::InconsistentState => "index allocator state is inconsistent",
+      Self::Unavailable => "index allocator storage unavailable",
+      Self::RetryableIOFailure => "index allocation was unsuccessful because of an I/O failure",
+      Self::SerializationError => "(de)serialization error",
+      Self::Unspecified => "index allocation failed",
+    }
+  }
+}
+
+impl AsRef<str> for IndexAllocatorErrorKind {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl Display for IndexAllocatorErrorKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
@@ -0,0 +1,382 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use thiserror::Error;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+
+use super::CredentialStatus;
+use super::IndexAllocator;
+use super::IndexAllocatorError;
+use super::StatusList2021;
+use super::StatusList2021Credential;
+use super::StatusList2021CredentialBuilder;
+use super::StatusList2021CredentialError;
+use super::StatusList2021Entry;
+use super::StatusListIndex;
+use super::StatusPurpose;
+
+/// Errors that can occur when managing a status list through a [`StatusListManager`].
+#[derive(Debug, Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum StatusListManagerError {
+  /// The credential has no `id` to allocate an entry to.
+  #[error("the credential has no id to allocate an entry to")]
+  MissingCredentialId,
+  /// No entry has been allocated to the credential.
+  #[error("no entry has been allocated to credential {0}")]
+  UnallocatedCredential(String),
+  /// The entry allocated to the credential belongs to another status list, e.g. because the managed one is exhausted.
+  #[error("the entry allocated to the credential belongs to status list {}", .0.status_list)]
+  OtherStatusList(StatusListIndex),
+  /// The allocated entry is out of the bounds of the managed status list.
+  #[error("entry {0} is out of the bounds of the status list")]
+  OutOfBounds(usize),
+  /// The operation is not supported by a status list with this purpose.
+  #[error("the operation is not supported by a status list for {0}")]
+  PurposeMismatch(StatusPurpose),
+  /// The index allocator failed to allocate or look up an entry.
+  #[error("failed to allocate the entry")]
+  Allocator(#[source] IndexAllocatorError),
+  /// Failed to update the status list credential.
+  #[error(transparent)]
+  StatusList(#[from] StatusList2021CredentialError),
+}
+
+/// Issuer-side management of a [`StatusList2021Credential`]: allocates its entries to issued credentials and changes
+/// their status, keeping track of whether the status list credential must be signed and published again.
+///
+/// Entries are handed out by an [`IndexAllocator`], which persists the allocations and draws them at random among the
+/// free entries, so that their indices reveal nothing about the order in which credentials were issued. The managed
+/// status list must be identified by the [`StatusListIndex::status_list`] the allocator hands out entries of; once it
+/// is exhausted the allocator rolls over to the next status list, which must be managed by a new manager.
+///
+/// # Example
+/// ```ignore
+/// let mut manager = StatusListManager::create(status_list_url, issuer, StatusPurpose::Revocation, allocator)?;
+/// let entry = manager.allocate(&mut credential).await?;
+/// // ... later on.
+/// manager.revoke(credential_id).await?;
+/// // Re-sign the status list, e.g. through `identity_storage`'s `StatusListManagerExt`.
+/// ```
+#[derive(Debug)]
+pub struct StatusListManager<A> {
+  status_list: StatusList2021Credential,
+  allocator: A,
+  pending_changes: bool,
+}
+
+impl<A: IndexAllocator> StatusListManager<A> {
+  /// Creates a new [`StatusListManager`] managing `status_list`, whose entries are allocated by `allocator`.
+  ///
+  /// `status_list` is assumed to be signed and published as is.
+  pub fn new(status_list: StatusList2021Credential, allocator: A) -> Self {
+    Self {
+      status_list,
+      allocator,
+      pending_changes: false,
+    }
+  }
+
+  /// Creates a new [`StatusListManager`] managing a new, empty, status list credential with the given purpose, to be
+  /// published at `id` by `issuer`, whose entries are allocated by `allocator`.
+  pub fn create(id: Url, issuer: Issuer, purpose: StatusPurpose, allocator: A) -> Result<Self, crate::Error> {
+    let status_list = StatusList2021CredentialBuilder::new(StatusList2021::default())
+      .subject_id(id)
+      .issuer(issuer)
+      .purpose(purpose)
+      .build()?;
+
+    Ok(Self {
+      status_list,
+      allocator,
+      // A new status list credential has never been signed.
+      pending_changes: true,
+    })
+  }
+
+  /// Returns the managed status list credential.
+  pub fn status_list_credential(&self) -> &StatusList2021Credential {
+    &self.status_list
+  }
+
+  /// Returns the allocator handing out the entries of the managed status list.
+  pub fn allocator(&self) -> &A {
+    &self.allocator
+  }
+
+  /// Returns the purpose of the managed status list.
+  pub fn purpose(&self) -> StatusPurpose {
+    self.status_list.purpose()
+  }
+
+  /// Returns whether the status list credential changed since it was last signed.
+  pub fn has_pending_changes(&self) -> bool {
+    self.pending_changes
+  }
+
+  /// Allocates an entry of the status list to `credential`, identified by its `id`, setting its `credentialStatus`.
+  ///
+  /// Allocating an entry to a credential that already has one returns the existing entry.
+  ///
+  /// # Errors
+  /// Fails with [`StatusListManagerError::OtherStatusList`] if the allocator handed out an entry of another status
+  /// list: `credential` must then be allocated its entry by the manager of that status list.
+  pub async fn allocate(&self, credential: &mut Credential) -> Result<StatusList2021Entry, StatusListManagerError> {
+    let credential_id = credential
+      .id
+      .as_ref()
+      .ok_or(StatusListManagerError::MissingCredentialId)?;
+    let allocation = self
+      .allocator
+      .allocate(credential_id.as_str())
+      .await
+      .map_err(StatusListManagerError::Allocator)?;
+    let index = self.check_entry(allocation.index)?;
+    let status_list = self
+      .status_list
+      .id()
+      .cloned()
+      .ok_or(StatusList2021CredentialError::Unreferenceable)?;
+
+    // The status of a free entry has never been changed, so the list itself doesn't need to be updated.
+    let entry = StatusList2021Entry::new(status_list, self.purpose(), index, None);
+    credential.credential_status = Some(entry.clone().into());
+
+    Ok(entry)
+  }
+
+  /// Revokes the credential identified by `credential_id`.
+  ///
+  /// Only supported by revocation lists.
+  pub async fn revoke(&mut self, credential_id: &str) -> Result<(), StatusListManagerError> {
+    self.set(StatusPurpose::Revocation, credential_id, true).await
+  }
+
+  /// Suspends the credential identified by `credential_id`.
+  ///
+  /// Only supported by suspension lists.
+  pub async fn suspend(&mut self, credential_id: &str) -> Result<(), StatusListManagerError> {
+    self.set(StatusPurpose::Suspension, credential_id, true).await
+  }
+
+  /// Lifts the suspension of the credential identified by `credential_id`.
+  ///
+  /// Only supported by suspension lists.
+  pub async fn reinstate(&mut self, credential_id: &str) -> Result<(), StatusListManagerError> {
+    self.set(StatusPurpose::Suspension, credential_id, false).await
+  }
+
+  /// Returns the status of the credential identified by `credential_id`.
+  pub async fn status(&self, credential_id: &str) -> Result<CredentialStatus, StatusListManagerError> {
+    let index = self.allocated_entry(credential_id).await?;
+    Ok(self.status_list.entry(index)?)
+  }
+
+  /// Sets the issuance date of the status list credential to `issuance_date`, and records that it has been signed.
+  ///
+  /// To be called once the status list credential, with issuance date `issuance_date`, has been signed.
+  pub fn mark_signed(&mut self, issuance_date: Timestamp) -> Result<(), StatusListManagerError> {
+    let mut credential = self.status_list.clone().into_inner();
+    credential.issuance_date = issuance_date;
+    self.status_list = StatusList2021Credential::try_from(credential)?;
+    self.pending_changes = false;
+
+    Ok(())
+  }
+
+  async fn set(
+    &mut self,
+    purpose: StatusPurpose,
+    credential_id: &str,
+    value: bool,
+  ) -> Result<(), StatusListManagerError> {
+    if self.purpose() != purpose {
+      return Err(StatusListManagerError::PurposeMismatch(self.purpose()));
+    }
+    let index = self.allocated_entry(credential_id).await?;
+
+    let changes = self.status_list.update_entries([(index, value)])?;
+    self.pending_changes |= !changes.is_empty();
+
+    Ok(())
+  }
+
+  /// Returns the index of the entry of the managed status list allocated to the credential identified by
+  /// `credential_id`.
+  async fn allocated_entry(&self, credential_id: &str) -> Result<usize, StatusListManagerError> {
+    let index = self
+      .allocator
+      .get_index(credential_id)
+      .await
+      .map_err(StatusListManagerError::Allocator)?
+      .ok_or_else(|| StatusListManagerError::UnallocatedCredential(credential_id.to_owned()))?;
+
+    self.check_entry(index)
+  }
+
+  /// Checks that `index` is an entry of the managed status list, returning its index in the list.
+  fn check_entry(&self, index: StatusListIndex) -> Result<usize, StatusListManagerError> {
+    if self.status_list.id() != Some(&index.status_list) {
+      return Err(StatusListManagerError::OtherStatusList(index));
+    }
+    let len = self
+      .status_list
+      .status_list()
+      .map_err(StatusList2021CredentialError::from)?
+      .len();
+    if index.index >= len {
+      return Err(StatusListManagerError::OutOfBounds(index.index));
+    }
+
+    Ok(index.index)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use async_trait::async_trait;
+
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::revocation::status_list_2021::IndexAllocation;
+  use crate::revocation::status_list_2021::IndexAllocatorConfig;
+  use crate::revocation::status_list_2021::IndexAllocatorResult;
+  use crate::revocation::status_list_2021::IndexAllocatorState;
+  use crate::revocation::status_list_2021::StatusListUsage;
+
+  use super::*;
+
+  const STATUS_LIST_URL: &str = "https://example.com/status/0";
+
+  struct TestAllocator(Mutex<IndexAllocatorState>);
+
+  impl TestAllocator {
+    fn new(capacity: usize) -> Self {
+      let config = IndexAllocatorConfig::new(Url::parse("https://example.com/status/").unwrap()).capacity(capacity);
+      Self(Mutex::new(IndexAllocatorState::new(config).unwrap()))
+    }
+  }
+
+  #[async_trait]
+  impl IndexAllocator for TestAllocator {
+    async fn allocate(&self, credential_id: &str) -> IndexAllocatorResult<IndexAllocation> {
+      self.0.lock().unwrap().allocate(credential_id)
+    }
+
+    async fn get_index(&self, credential_id: &str) -> IndexAllocatorResult<Option<StatusListIndex>> {
+      Ok(self.0.lock().unwrap().get_index(credential_id).cloned())
+    }
+
+    async fn status_lists(&self) -> IndexAllocatorResult<Vec<StatusListUsage>> {
+      Ok(self.0.lock().unwrap().status_lists().to_vec())
+    }
+  }
+
+  fn credential(n: usize) -> Credential {
+    CredentialBuilder::default()
+      .id(Url::parse(format!("https://example.com/credentials/{n}")).unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap()
+  }
+
+  fn manager(purpose: StatusPurpose, capacity: usize) -> StatusListManager<TestAllocator> {
+    StatusListManager::create(
+      Url::parse(STATUS_LIST_URL).unwrap(),
+      Issuer::Url(Url::parse("did:example:issuer").unwrap()),
+      purpose,
+      TestAllocator::new(capacity),
+    )
+    .unwrap()
+  }
+
+  #[tokio::test]
+  async fn entries_are_allocated_by_the_allocator() {
+    let manager = manager(StatusPurpose::Revocation, IndexAllocatorConfig::DEFAULT_CAPACITY);
+    let mut credentials: Vec<Credential> = (0..16).map(credential).collect();
+
+    let mut indices = Vec::new();
+    for credential in &mut credentials {
+      indices.push(manager.allocate(credential).await.unwrap().index());
+    }
+    assert_ne!(indices, (0..16).collect::<Vec<_>>());
+    assert_eq!(indices.iter().collect::<std::collections::HashSet<_>>().len(), 16);
+    assert_eq!(
+      StatusList2021Entry::try_from(credentials[1].credential_status.as_ref().unwrap())
+        .unwrap()
+        .index(),
+      indices[1]
+    );
+    assert_eq!(manager.allocate(&mut credentials[1]).await.unwrap().index(), indices[1]);
+    assert_eq!(manager.allocator().status_lists().await.unwrap()[0].allocated, 16);
+
+    let mut anonymous = credential(16);
+    anonymous.id = None;
+    assert!(matches!(
+      manager.allocate(&mut anonymous).await,
+      Err(StatusListManagerError::MissingCredentialId)
+    ));
+  }
+
+  #[tokio::test]
+  async fn entries_of_other_status_lists_are_rejected() {
+    let manager = manager(StatusPurpose::Revocation, 1);
+    manager.allocate(&mut credential(0)).await.unwrap();
+
+    let mut credential = credential(1);
+    match manager.allocate(&mut credential).await {
+      Err(StatusListManagerError::OtherStatusList(index)) => {
+        assert_eq!(index.status_list.as_str(), "https://example.com/status/1")
+      }
+      result => panic!("unexpected result: {result:?}"),
+    }
+    assert!(credential.credential_status.is_none());
+  }
+
+  #[tokio::test]
+  async fn revocation_tracks_pending_changes() {
+    let mut manager = manager(StatusPurpose::Revocation, IndexAllocatorConfig::DEFAULT_CAPACITY);
+    let mut credential = credential(0);
+    manager.allocate(&mut credential).await.unwrap();
+    let credential_id = credential.id.as_ref().unwrap().as_str();
+    manager.mark_signed(Timestamp::now_utc()).unwrap();
+    assert!(!manager.has_pending_changes());
+
+    assert!(matches!(
+      manager.revoke("https://example.com/credentials/1").await,
+      Err(StatusListManagerError::UnallocatedCredential(_))
+    ));
+    assert!(matches!(
+      manager.suspend(credential_id).await,
+      Err(StatusListManagerError::PurposeMismatch(StatusPurpose::Revocation))
+    ));
+    assert!(!manager.has_pending_changes());
+
+    manager.revoke(credential_id).await.unwrap();
+    assert!(manager.has_pending_changes());
+    assert_eq!(manager.status(credential_id).await.unwrap(), CredentialStatus::Revoked);
+  }
+
+  #[tokio::test]
+  async fn suspended_credentials_can_be_reinstated() {
+    let mut manager = manager(StatusPurpose::Suspension, IndexAllocatorConfig::DEFAULT_CAPACITY);
+    let mut credential = credential(0);
+    manager.allocate(&mut credential).await.unwrap();
+    let credential_id = credential.id.as_ref().unwrap().as_str();
+
+    manager.suspend(credential_id).await.unwrap();
+    assert_eq!(
+      manager.status(credential_id).await.unwrap(),
+      CredentialStatus::Suspended
+    );
+    manager.reinstate(credential_id).await.unwrap();
+    assert_eq!(manager.status(credential_id).await.unwrap(), CredentialStatus::Valid);
+  }
+}
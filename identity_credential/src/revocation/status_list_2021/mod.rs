@@ -6,8 +6,14 @@
 /// Implementation of [StatusList2021Credential](https://www.w3.org/TR/2023/WD-vc-status-list-20230427/#statuslist2021credential).
 mod credential;
 mod entry;
+mod index_allocator;
+mod index_allocator_error;
+mod manager;
 mod status_list;
 
 pub use credential::*;
 pub use entry::*;
+pub use index_allocator::*;
+pub use index_allocator_error::*;
+pub use manager::*;
 pub use status_list::*;
@@ -129,6 +129,7 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
   ///
   /// ## Notes
   /// - `path` indicates the pointer to the value that will be concealed using the syntax of [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901).
+  ///   A [`ClaimPointer`](super::metadata::ClaimPointer) can be used as well.
   ///
   /// ## Example
   /// ```rust
@@ -148,8 +149,8 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
   ///   .make_concealable("/claim1/abc").unwrap() //"abc": true
   ///   .make_concealable("/claim2/0").unwrap(); //conceals "val_1"
   /// ```
-  pub fn make_concealable(mut self, path: &str) -> Result<Self> {
-    self.inner_builder = self.inner_builder.make_concealable(path)?;
+  pub fn make_concealable(mut self, path: impl AsRef<str>) -> Result<Self> {
+    self.inner_builder = self.inner_builder.make_concealable(path.as_ref())?;
     Ok(self)
   }
 
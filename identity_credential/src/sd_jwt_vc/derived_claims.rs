@@ -0,0 +1,167 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use identity_core::common::Timestamp;
+use sd_jwt::JsonObject;
+use serde_json::Value;
+
+use super::Error;
+use super::Result;
+use super::SdJwtVcPresentationBuilder;
+
+/// Name of the claim derived claims are computed from.
+pub const BIRTHDATE_CLAIM: &str = "birthdate";
+/// Name of the object claim holding the issuer's `age_equal_or_over` attestations.
+pub const AGE_EQUAL_OR_OVER_CLAIM: &str = "age_equal_or_over";
+/// Name of the claim holding the holder's age, in years.
+pub const AGE_IN_YEARS_CLAIM: &str = "age_in_years";
+/// Name of the claim holding the holder's year of birth.
+pub const AGE_BIRTH_YEAR_CLAIM: &str = "age_birth_year";
+
+/// A claim an issuer precomputed from another claim - e.g. `age_equal_or_over/18` from `birthdate` - so that a
+/// holder can present the former without disclosing the latter.
+///
+/// Claim names follow the age verification claims of the SD-JWT VC based PID credential.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DerivedClaim {
+  /// Whether the holder is at least the given number of years old, stored as `age_equal_or_over/<age>`.
+  AgeEqualOrOver(u32),
+  /// The holder's age, in years.
+  AgeInYears,
+  /// The holder's year of birth.
+  AgeBirthYear,
+}
+
+impl DerivedClaim {
+  /// Returns the JSON pointer to this claim.
+  pub fn path(&self) -> String {
+    match self {
+      Self::AgeEqualOrOver(age) => format!("/{AGE_EQUAL_OR_OVER_CLAIM}/{age}"),
+      Self::AgeInYears => format!("/{AGE_IN_YEARS_CLAIM}"),
+      Self::AgeBirthYear => format!("/{AGE_BIRTH_YEAR_CLAIM}"),
+    }
+  }
+
+  /// Returns the JSON pointer to the claim this claim is derived from.
+  pub fn source_path(&self) -> String {
+    format!("/{BIRTHDATE_CLAIM}")
+  }
+
+  /// Computes the age verification claims of a holder born on `birthdate`, as of `at`, attesting whether they are
+  /// at least as old as each of `age_thresholds`.
+  ///
+  /// The returned claims are meant to be merged into an SD-JWT VC's claims by its issuer, each of them being made
+  /// concealable - see [`DerivedClaim::path`] - so that holders can choose which to present.
+  pub fn age_claims(birthdate: Timestamp, age_thresholds: &[u32], at: Timestamp) -> JsonObject {
+    let (birth_year, birth_month, birth_day) = date_of(birthdate);
+    let (year, month, day) = date_of(at);
+    let had_birthday = (month, day) >= (birth_month, birth_day);
+    let age = u32::try_from(year - birth_year - i64::from(!had_birthday)).unwrap_or(0);
+
+    let age_equal_or_over: JsonObject = age_thresholds
+      .iter()
+      .map(|threshold| (threshold.to_string(), Value::Bool(age >= *threshold)))
+      .collect();
+
+    let mut claims = JsonObject::new();
+    claims.insert(AGE_EQUAL_OR_OVER_CLAIM.to_owned(), Value::Object(age_equal_or_over));
+    claims.insert(AGE_IN_YEARS_CLAIM.to_owned(), age.into());
+    claims.insert(AGE_BIRTH_YEAR_CLAIM.to_owned(), birth_year.into());
+
+    claims
+  }
+
+  /// Finds all derived claims contained in `claims`, together with their values.
+  pub(crate) fn find_all(claims: &JsonObject) -> Vec<(Self, Value)> {
+    let mut found = Vec::new();
+    if let Some(Value::Object(attestations)) = claims.get(AGE_EQUAL_OR_OVER_CLAIM) {
+      for (age, value) in attestations {
+        if let Ok(age) = age.parse() {
+          found.push((Self::AgeEqualOrOver(age), value.clone()));
+        }
+      }
+    }
+    if let Some(value) = claims.get(AGE_IN_YEARS_CLAIM) {
+      found.push((Self::AgeInYears, value.clone()));
+    }
+    if let Some(value) = claims.get(AGE_BIRTH_YEAR_CLAIM) {
+      found.push((Self::AgeBirthYear, value.clone()));
+    }
+
+    found
+  }
+}
+
+impl Display for DerivedClaim {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.path())
+  }
+}
+
+/// Returns the year, month and day of `timestamp`.
+fn date_of(timestamp: Timestamp) -> (i64, u32, u32) {
+  // RFC 3339 timestamps always start with `YYYY-MM-DD`.
+  let rfc3339 = timestamp.to_rfc3339();
+  let field = |range: std::ops::Range<usize>| rfc3339[range].parse::<u32>().expect("valid RFC 3339 date");
+  (i64::from(field(0..4)), field(5..7), field(8..10))
+}
+
+impl SdJwtVcPresentationBuilder {
+  /// Returns the derived claims the issuer included in the token, together with their values.
+  pub fn derived_claims(&self) -> &[(DerivedClaim, Value)] {
+    &self.derived_claims
+  }
+
+  /// Presents `claim` in place of the claim it is derived from: the source claim and all other derived claims are
+  /// concealed, while `claim` is left as is.
+  ///
+  /// ## Errors
+  /// - [`Error::UnavailableDerivedClaim`] if the issuer didn't include `claim` in the token.
+  /// - [`Error::SdJwt`] if the source claim or another derived claim is not selectively disclosable, as it could not be
+  ///   withheld.
+  pub fn present_derived(mut self, claim: DerivedClaim) -> Result<Self> {
+    if !self.derived_claims.iter().any(|(derived, _)| *derived == claim) {
+      return Err(Error::UnavailableDerivedClaim(claim));
+    }
+
+    let others: Vec<DerivedClaim> = self
+      .derived_claims
+      .iter()
+      .map(|(derived, _)| *derived)
+      .filter(|derived| *derived != claim)
+      .collect();
+    for other in others {
+      self = self.conceal(other.path())?;
+    }
+    if self.has_derivation_source {
+      self = self.conceal(claim.source_path())?;
+    }
+
+    Ok(self)
+  }
+
+  /// Selects, among the issuer's `age_equal_or_over` attestations, the one that best proves the holder is at least
+  /// `age` years old, and presents it through [`Self::present_derived`].
+  ///
+  /// An attestation for `age` itself is preferred. Otherwise, the lowest positive attestation for an older age is
+  /// used, as it implies the requested one.
+  ///
+  /// ## Errors
+  /// [`Error::UnavailableDerivedClaim`] if no attestation proves the holder is at least `age` years old.
+  pub fn present_age_equal_or_over(self, age: u32) -> Result<Self> {
+    let selected = self
+      .derived_claims
+      .iter()
+      .filter_map(|(claim, value)| match claim {
+        DerivedClaim::AgeEqualOrOver(threshold) if *threshold >= age && value == &Value::Bool(true) => Some(*threshold),
+        _ => None,
+      })
+      .min()
+      .ok_or(Error::UnavailableDerivedClaim(DerivedClaim::AgeEqualOrOver(age)))?;
+
+    self.present_derived(DerivedClaim::AgeEqualOrOver(selected))
+  }
+}
@@ -27,6 +27,9 @@ pub enum Error {
   /// A low level SD-JWT error.
   #[error(transparent)]
   SdJwt(#[from] sd_jwt::Error),
+  /// The token's disclosures could not be matched with its digests.
+  #[error(transparent)]
+  Disclosure(#[from] crate::validator::SdJwtDisclosureError),
   /// Value of header parameter `typ` is not valid.
   #[error("invalid \"typ\" value; expected \"vc+sd-jwt\" (or a superset) but found \"{0}\"")]
   InvalidJoseType(String),
@@ -48,9 +51,21 @@ pub enum Error {
   /// Credential validation failed.
   #[error("credential validation failed: {0}")]
   Validation(#[source] anyhow::Error),
+  /// A derived claim is not part of the SD-JWT VC.
+  #[error("derived claim \"{0}\" was not provided by the issuer")]
+  UnavailableDerivedClaim(super::DerivedClaim),
   /// SD-JWT VC signature verification failed.
   #[error("verification failed: {0}")]
   Verification(#[source] anyhow::Error),
+  /// Invalid status list or status list token.
+  #[error("invalid status list: {0}")]
+  InvalidStatusList(#[source] anyhow::Error),
+  /// Invalid X.509 certificate chain, e.g. an `x5c` header that does not lead to a trust anchor.
+  #[error("invalid X.509 certificate chain: {0}")]
+  InvalidCertificateChain(#[source] anyhow::Error),
+  /// The token's status, as recorded in its status list, is not valid.
+  #[error("token has status \"{0}\"")]
+  TokenStatus(super::TokenStatus),
 }
 
 /// Either a value of type `T` or an [`Error`].
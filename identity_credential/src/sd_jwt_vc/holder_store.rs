@@ -0,0 +1,261 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::RwLock;
+
+use identity_core::common::Timestamp;
+
+use super::SdJwtVc;
+
+/// Identifier of a credential stored in a [`SdJwtVcHolderStore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StoredCredentialId(u64);
+
+impl Display for StoredCredentialId {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+/// The outcome of storing a credential in a [`SdJwtVcHolderStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StoreOutcome {
+  /// Identifier of the stored credential.
+  pub id: StoredCredentialId,
+  /// Whether the very same credential was already stored, in which case nothing changed.
+  pub duplicate: bool,
+  /// Credentials that were the latest of their lineage and are now superseded by the stored one.
+  pub superseded: Vec<StoredCredentialId>,
+  /// The credential superseding the stored one, when a more recent credential of the same lineage was already
+  /// stored.
+  pub superseded_by: Option<StoredCredentialId>,
+}
+
+/// A holder-side store of [`SdJwtVc`]s that tracks which credentials supersede which.
+///
+/// Credentials sharing the same `vct`, `iss` and `sub` claims form a lineage, in which each credential is superseded
+/// by the next more recent one - according to its `iat` claim, or the order credentials were stored in when it's
+/// equal. A credential missing `iat` is ordered right after the credential of its lineage stored before it.
+/// Credentials missing `iss` or `sub` form lineages with those missing the same claims.
+///
+/// Wallets can present only the [latest](SdJwtVcHolderStore::latest) credentials, walk a credential's
+/// [lineage](SdJwtVcHolderStore::lineage), and [prune](SdJwtVcHolderStore::prune_superseded) superseded ones.
+#[derive(Debug, Default)]
+pub struct SdJwtVcHolderStore {
+  inner: RwLock<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+  next_id: u64,
+  entries: BTreeMap<StoredCredentialId, Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+  credential: SdJwtVc,
+  lineage: LineageKey,
+  issued_at: Option<Timestamp>,
+  superseded_by: Option<StoredCredentialId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LineageKey {
+  vct: String,
+  iss: Option<String>,
+  sub: Option<String>,
+}
+
+impl LineageKey {
+  fn of(credential: &SdJwtVc) -> Self {
+    let claims = credential.claims();
+    Self {
+      vct: claims.vct.clone(),
+      iss: claims.iss.as_ref().map(ToString::to_string),
+      sub: claims.sub.as_ref().map(ToString::to_string),
+    }
+  }
+}
+
+impl Inner {
+  /// Returns the credentials of `lineage`, from the oldest to the latest.
+  fn members(&self, lineage: &LineageKey) -> Vec<(Option<Timestamp>, StoredCredentialId)> {
+    // Entries are iterated in the order they were stored in: a credential missing `iat` takes the issuance date of
+    // the credential stored before it, so that it's ordered right after it.
+    let mut issued_at: Option<Timestamp> = None;
+    let mut members: Vec<(Option<Timestamp>, StoredCredentialId)> = self
+      .entries
+      .iter()
+      .filter(|(_, entry)| &entry.lineage == lineage)
+      .map(|(id, entry)| {
+        issued_at = entry.issued_at.or(issued_at);
+        (issued_at, *id)
+      })
+      .collect();
+    members.sort();
+
+    members
+  }
+
+  /// Relinks all credentials of `lineage`, returning the credentials that became superseded.
+  fn relink(&mut self, lineage: &LineageKey) -> Vec<StoredCredentialId> {
+    let members = self.members(lineage);
+    let mut newly_superseded = Vec::new();
+    let successors = members.iter().skip(1).map(|(_, id)| Some(*id)).chain([None]);
+    for ((_, id), successor) in members.iter().zip(successors) {
+      let entry = self.entries.get_mut(id).expect("member of the lineage");
+      if entry.superseded_by.is_none() && successor.is_some() {
+        newly_superseded.push(*id);
+      }
+      entry.superseded_by = successor;
+    }
+
+    newly_superseded
+  }
+}
+
+impl SdJwtVcHolderStore {
+  /// Creates a new, empty [`SdJwtVcHolderStore`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Stores `credential`, marking the credentials of its lineage it supersedes as superseded.
+  ///
+  /// Storing a credential that is already stored is a no-op returning the identifier of the stored copy.
+  pub fn insert(&self, credential: SdJwtVc) -> StoreOutcome {
+    let mut inner = self.inner.write().expect("holder store lock poisoned");
+
+    if let Some((id, entry)) = inner.entries.iter().find(|(_, entry)| entry.credential == credential) {
+      return StoreOutcome {
+        id: *id,
+        duplicate: true,
+        superseded: vec![],
+        superseded_by: entry.superseded_by,
+      };
+    }
+
+    let id = StoredCredentialId(inner.next_id);
+    inner.next_id += 1;
+    let lineage = LineageKey::of(&credential);
+    let issued_at = credential.claims().iat;
+    inner.entries.insert(
+      id,
+      Entry {
+        credential,
+        lineage: lineage.clone(),
+        issued_at,
+        superseded_by: None,
+      },
+    );
+
+    let mut superseded = inner.relink(&lineage);
+    let superseded_by = inner.entries[&id].superseded_by;
+    superseded.retain(|superseded_id| *superseded_id != id);
+
+    StoreOutcome {
+      id,
+      duplicate: false,
+      superseded,
+      superseded_by,
+    }
+  }
+
+  /// Returns the credential with the given identifier.
+  pub fn get(&self, id: StoredCredentialId) -> Option<SdJwtVc> {
+    let inner = self.inner.read().expect("holder store lock poisoned");
+    inner.entries.get(&id).map(|entry| entry.credential.clone())
+  }
+
+  /// Returns whether the credential with the given identifier is superseded.
+  pub fn is_superseded(&self, id: StoredCredentialId) -> bool {
+    self.superseded_by(id).is_some()
+  }
+
+  /// Returns the credential directly superseding the credential with the given identifier, if any.
+  pub fn superseded_by(&self, id: StoredCredentialId) -> Option<StoredCredentialId> {
+    let inner = self.inner.read().expect("holder store lock poisoned");
+    inner.entries.get(&id).and_then(|entry| entry.superseded_by)
+  }
+
+  /// Returns the credentials the credential with the given identifier directly supersedes.
+  pub fn supersedes(&self, id: StoredCredentialId) -> Vec<StoredCredentialId> {
+    let inner = self.inner.read().expect("holder store lock poisoned");
+    inner
+      .entries
+      .iter()
+      .filter(|(_, entry)| entry.superseded_by == Some(id))
+      .map(|(id, _)| *id)
+      .collect()
+  }
+
+  /// Returns the lineage of the credential with the given identifier, from the oldest to the latest credential.
+  ///
+  /// The lineage is empty if no such credential is stored.
+  pub fn lineage(&self, id: StoredCredentialId) -> Vec<StoredCredentialId> {
+    let inner = self.inner.read().expect("holder store lock poisoned");
+    let Some(lineage) = inner.entries.get(&id).map(|entry| &entry.lineage) else {
+      return vec![];
+    };
+
+    inner.members(lineage).into_iter().map(|(_, id)| id).collect()
+  }
+
+  /// Returns the supersession graph of the store, as the edges from each superseded credential to the credential
+  /// directly superseding it.
+  pub fn supersession_graph(&self) -> BTreeMap<StoredCredentialId, StoredCredentialId> {
+    let inner = self.inner.read().expect("holder store lock poisoned");
+    inner
+      .entries
+      .iter()
+      .filter_map(|(id, entry)| entry.superseded_by.map(|successor| (*id, successor)))
+      .collect()
+  }
+
+  /// Returns the credentials that are not superseded, i.e. the latest credential of each lineage.
+  pub fn latest(&self) -> Vec<(StoredCredentialId, SdJwtVc)> {
+    let inner = self.inner.read().expect("holder store lock poisoned");
+    inner
+      .entries
+      .iter()
+      .filter(|(_, entry)| entry.superseded_by.is_none())
+      .map(|(id, entry)| (*id, entry.credential.clone()))
+      .collect()
+  }
+
+  /// Removes the credential with the given identifier, relinking the rest of its lineage.
+  pub fn remove(&self, id: StoredCredentialId) -> Option<SdJwtVc> {
+    let mut inner = self.inner.write().expect("holder store lock poisoned");
+    let entry = inner.entries.remove(&id)?;
+    inner.relink(&entry.lineage);
+
+    Some(entry.credential)
+  }
+
+  /// Removes all superseded credentials, returning their identifiers.
+  pub fn prune_superseded(&self) -> Vec<StoredCredentialId> {
+    let mut inner = self.inner.write().expect("holder store lock poisoned");
+    let pruned: Vec<StoredCredentialId> = inner
+      .entries
+      .iter()
+      .filter(|(_, entry)| entry.superseded_by.is_some())
+      .map(|(id, _)| *id)
+      .collect();
+    inner.entries.retain(|_, entry| entry.superseded_by.is_none());
+
+    pruned
+  }
+
+  /// Returns the number of stored credentials, including superseded ones.
+  pub fn len(&self) -> usize {
+    self.inner.read().expect("holder store lock poisoned").entries.len()
+  }
+
+  /// Returns whether the store is empty.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
@@ -0,0 +1,242 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use anyhow::anyhow;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkSet;
+
+use super::metadata::IssuerMetadata;
+use super::metadata::Jwks;
+use super::Error;
+use super::Resolver;
+use super::Result;
+use super::SdJwtVc;
+
+/// How long issuers' JWK Sets are cached for, unless configured otherwise.
+pub const DEFAULT_ISSUER_KEYS_MAX_AGE: Duration = Duration::hours(1);
+/// How many issuers' JWK Sets are cached at most, unless configured otherwise.
+pub const DEFAULT_ISSUER_KEYS_MAX_ENTRIES: usize = 1_024;
+/// How long a cached JWK Set is kept before a token with an unknown `kid` can trigger its refresh, unless configured
+/// otherwise.
+pub const DEFAULT_ISSUER_KEYS_MIN_REFRESH_INTERVAL: Duration = Duration::minutes(1);
+
+/// Resolves the public keys of SD-JWT VC issuers identified by an HTTPS URL, through their
+/// [JWT VC Issuer Metadata](https://www.ietf.org/archive/id/draft-ietf-oauth-sd-jwt-vc-13.html#name-jwt-vc-issuer-metadata)
+/// published at `/.well-known/jwt-vc-issuer`.
+///
+/// HTTP requests are delegated to a [`Resolver`] - the transport -, which must report missing resources with
+/// [`resolver::Error::NotFound`](super::resolver::Error::NotFound). Issuers' JWK Sets are cached for a configurable
+/// amount of time; a cached JWK Set that misses the requested key is refreshed once, so that key rotations are picked
+/// up early.
+///
+/// Since issuers are taken from the tokens being validated, the cache holds a bounded number of JWK Sets - evicting
+/// expired ones first, then the ones fetched the longest time ago - and a JWK Set is refreshed because of an unknown
+/// `kid` at most once per [minimum refresh interval](JwtVcIssuerResolver::with_min_refresh_interval).
+#[derive(Debug)]
+pub struct JwtVcIssuerResolver<R> {
+  transport: R,
+  max_age: Duration,
+  max_entries: usize,
+  min_refresh_interval: Duration,
+  cache: RwLock<HashMap<Url, CachedJwks>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedJwks {
+  jwks: JwkSet,
+  fetched_at: Timestamp,
+  expires_at: Timestamp,
+}
+
+impl<R> JwtVcIssuerResolver<R>
+where
+  R: Resolver<Url, Vec<u8>>,
+{
+  /// Creates a new [`JwtVcIssuerResolver`] fetching issuers' metadata and JWK Sets through `transport`.
+  pub fn new(transport: R) -> Self {
+    Self {
+      transport,
+      max_age: DEFAULT_ISSUER_KEYS_MAX_AGE,
+      max_entries: DEFAULT_ISSUER_KEYS_MAX_ENTRIES,
+      min_refresh_interval: DEFAULT_ISSUER_KEYS_MIN_REFRESH_INTERVAL,
+      cache: RwLock::default(),
+    }
+  }
+
+  /// Caches issuers' JWK Sets for `max_age`. A zero `max_age` disables caching.
+  pub fn with_max_age(mut self, max_age: Duration) -> Self {
+    self.max_age = max_age;
+    self
+  }
+
+  /// Caches the JWK Sets of at most `max_entries` issuers. A zero `max_entries` disables caching.
+  pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+    self.max_entries = max_entries;
+    self
+  }
+
+  /// Refreshes a cached JWK Set that misses the requested key only if it was fetched at least `min_refresh_interval`
+  /// ago.
+  pub fn with_min_refresh_interval(mut self, min_refresh_interval: Duration) -> Self {
+    self.min_refresh_interval = min_refresh_interval;
+    self
+  }
+
+  /// Returns the transport used to fetch issuers' metadata.
+  pub fn transport(&self) -> &R {
+    &self.transport
+  }
+
+  /// Fetches the metadata of `issuer`, checking that they are about `issuer`.
+  ///
+  /// # Errors
+  /// - [`Error::InvalidIssuerMetadata`] if `issuer` is not an HTTPS URL or if the metadata are malformed or about
+  ///   another issuer.
+  /// - [`Error::Resolution`] if the metadata could not be fetched.
+  pub async fn issuer_metadata(&self, issuer: &Url) -> Result<IssuerMetadata> {
+    check_https(issuer)?;
+    let metadata_url = IssuerMetadata::well_known_url(issuer);
+    let bytes = self
+      .transport
+      .resolve(&metadata_url)
+      .await
+      .map_err(|e| Error::Resolution {
+        input: metadata_url.to_string(),
+        source: e,
+      })?;
+    let metadata: IssuerMetadata =
+      serde_json::from_slice(&bytes).map_err(|e| Error::InvalidIssuerMetadata(e.into()))?;
+    if &metadata.issuer != issuer {
+      return Err(Error::InvalidIssuerMetadata(anyhow!(
+        "expected issuer \"{issuer}\", but found \"{}\"",
+        metadata.issuer
+      )));
+    }
+
+    Ok(metadata)
+  }
+
+  /// Returns the JWK Set of `issuer`, from the cache if available.
+  pub async fn issuer_jwks(&self, issuer: &Url) -> Result<JwkSet> {
+    if let Some(cached) = self.cached(issuer) {
+      return Ok(cached.jwks);
+    }
+
+    let jwks = match self.issuer_metadata(issuer).await?.jwks {
+      Jwks::Object(jwks) => jwks,
+      Jwks::Uri(jwks_uri) => {
+        check_https(&jwks_uri)?;
+        let bytes = self.transport.resolve(&jwks_uri).await.map_err(|e| Error::Resolution {
+          input: jwks_uri.to_string(),
+          source: e,
+        })?;
+        serde_json::from_slice(&bytes).map_err(|e| Error::InvalidIssuerMetadata(e.into()))?
+      }
+    };
+
+    let fetched_at = Timestamp::now_utc();
+    if let Some(expires_at) = fetched_at
+      .checked_add(self.max_age)
+      .filter(|_| self.max_age != Duration::seconds(0) && self.max_entries != 0)
+    {
+      let mut cache = self.cache.write().expect("issuer keys cache lock poisoned");
+      if !cache.contains_key(issuer) && cache.len() >= self.max_entries {
+        cache.retain(|_, cached| cached.expires_at > fetched_at);
+      }
+      while !cache.contains_key(issuer) && cache.len() >= self.max_entries {
+        let evicted = cache
+          .iter()
+          .min_by_key(|(_, cached)| cached.fetched_at)
+          .map(|(issuer, _)| issuer.clone())
+          .expect("the cache is not empty");
+        cache.remove(&evicted);
+      }
+      cache.insert(
+        issuer.clone(),
+        CachedJwks {
+          jwks: jwks.clone(),
+          fetched_at,
+          expires_at,
+        },
+      );
+    }
+
+    Ok(jwks)
+  }
+
+  /// Returns the public key `sd_jwt_vc` was signed with, i.e. the key of its issuer - identified by its `iss` claim -
+  /// whose `kid` matches the token's `kid` header.
+  ///
+  /// A token without a `kid` header is accepted only if its issuer has a single key.
+  pub async fn issuer_jwk(&self, sd_jwt_vc: &SdJwtVc) -> Result<Jwk> {
+    let issuer = sd_jwt_vc.claims().iss.as_ref().ok_or(Error::MissingClaim("iss"))?;
+    let kid = sd_jwt_vc.headers().get("kid").and_then(|kid| kid.as_str());
+
+    let cached_at = self.cached(issuer).map(|cached| cached.fetched_at);
+    if let Some(jwk) = find_key(&self.issuer_jwks(issuer).await?, kid) {
+      return Ok(jwk);
+    }
+    let refresh_allowed = cached_at
+      .and_then(|fetched_at| fetched_at.checked_add(self.min_refresh_interval))
+      .is_some_and(|refreshable_at| refreshable_at <= Timestamp::now_utc());
+    if refresh_allowed {
+      // The issuer might have rotated its keys since they were cached.
+      self.invalidate(issuer);
+      if let Some(jwk) = find_key(&self.issuer_jwks(issuer).await?, kid) {
+        return Ok(jwk);
+      }
+    }
+
+    Err(Error::Verification(match kid {
+      Some(kid) => anyhow!("missing key \"{kid}\" in the JWK set of \"{issuer}\""),
+      None => anyhow!("missing header `kid` and \"{issuer}\" has more than one key"),
+    }))
+  }
+
+  /// Removes the cached JWK Set of `issuer`, if any.
+  pub fn invalidate(&self, issuer: &Url) {
+    self
+      .cache
+      .write()
+      .expect("issuer keys cache lock poisoned")
+      .remove(issuer);
+  }
+
+  /// Removes all cached JWK Sets.
+  pub fn clear(&self) {
+    self.cache.write().expect("issuer keys cache lock poisoned").clear();
+  }
+
+  fn cached(&self, issuer: &Url) -> Option<CachedJwks> {
+    let now = Timestamp::now_utc();
+    self
+      .cache
+      .read()
+      .expect("issuer keys cache lock poisoned")
+      .get(issuer)
+      .filter(|cached| cached.expires_at > now)
+      .cloned()
+  }
+}
+
+fn check_https(url: &Url) -> Result<()> {
+  if url.scheme() == "https" {
+    Ok(())
+  } else {
+    Err(Error::InvalidIssuerMetadata(anyhow!("\"{url}\" is not an HTTPS URL")))
+  }
+}
+
+fn find_key(jwks: &JwkSet, kid: Option<&str>) -> Option<Jwk> {
+  match kid {
+    Some(kid) => jwks.iter().find(|jwk| jwk.kid() == Some(kid)).cloned(),
+    None if jwks.len() == 1 => jwks.iter().next().cloned(),
+    None => None,
+  }
+}
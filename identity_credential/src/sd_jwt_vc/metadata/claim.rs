@@ -68,6 +68,20 @@ impl ClaimMetadata {
 pub struct ClaimPath(Vec<ClaimPathSegment>);
 
 impl ClaimPath {
+  /// Returns the [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) addressing the same claim
+  /// as this path, or `None` if this path selects all the elements of an array.
+  pub fn to_json_pointer(&self) -> Option<String> {
+    self.iter().try_fold(String::new(), |mut pointer, segment| {
+      pointer.push('/');
+      match segment {
+        ClaimPathSegment::Name(name) => pointer.push_str(&name.replace('~', "~0").replace('/', "~1")),
+        ClaimPathSegment::Position(i) => pointer.push_str(&i.to_string()),
+        ClaimPathSegment::All => return None,
+      }
+      Some(pointer)
+    })
+  }
+
   fn reverse_index<'v>(&self, value: &'v Value) -> anyhow::Result<OneOrManyValue<'v>> {
     let mut segments = self.iter();
     let first_segment = segments.next().context("empty claim path")?;
@@ -289,4 +303,17 @@ mod tests {
     assert_eq!(degree_types.next().unwrap().as_str(), Some("Master of Science"));
     assert_eq!(degree_types.next(), None);
   }
+
+  #[test]
+  fn claim_path_to_json_pointer() {
+    let city_path = serde_json::from_value::<ClaimPath>(json!(["address", "city"])).unwrap();
+    let first_degree_path = serde_json::from_value::<ClaimPath>(json!(["degrees", 0])).unwrap();
+    let escaped_path = serde_json::from_value::<ClaimPath>(json!(["a/b", "c~d"])).unwrap();
+    let degrees_types_path = serde_json::from_value::<ClaimPath>(json!(["degrees", null, "type"])).unwrap();
+
+    assert_eq!(city_path.to_json_pointer().as_deref(), Some("/address/city"));
+    assert_eq!(first_degree_path.to_json_pointer().as_deref(), Some("/degrees/0"));
+    assert_eq!(escaped_path.to_json_pointer().as_deref(), Some("/a~1b/c~0d"));
+    assert_eq!(degrees_types_path.to_json_pointer(), None);
+  }
 }
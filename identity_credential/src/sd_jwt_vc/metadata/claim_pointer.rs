@@ -0,0 +1,276 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Write as _;
+
+use anyhow::anyhow;
+
+use crate::sd_jwt_vc::Error;
+use crate::sd_jwt_vc::Result;
+
+use super::ClaimDisclosability;
+use super::ClaimMetadata;
+use super::ClaimPathSegment;
+use super::TypeMetadata;
+
+/// A JSON pointer to a claim of an SD-JWT VC, together with the claim's disclosability policy.
+///
+/// [`ClaimPointer`]s are usually generated from a credential's [`TypeMetadata`] through
+/// [`ClaimPointersGenerator`], and can be passed anywhere a JSON pointer is expected -
+/// e.g. [`SdJwtVcPresentationBuilder::conceal`](crate::sd_jwt_vc::SdJwtVcPresentationBuilder::conceal).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClaimPointer {
+  pointer: &'static str,
+  sd: ClaimDisclosability,
+  mandatory: bool,
+}
+
+impl ClaimPointer {
+  /// Creates a new [`ClaimPointer`].
+  pub const fn new(pointer: &'static str, sd: ClaimDisclosability, mandatory: bool) -> Self {
+    Self { pointer, sd, mandatory }
+  }
+
+  /// Returns the JSON pointer of the claim.
+  pub const fn pointer(&self) -> &'static str {
+    self.pointer
+  }
+
+  /// Returns whether the claim is selectively disclosable.
+  pub const fn sd(&self) -> ClaimDisclosability {
+    self.sd
+  }
+
+  /// Returns whether the claim must be present in the issued credential.
+  pub const fn mandatory(&self) -> bool {
+    self.mandatory
+  }
+
+  /// Returns whether the claim may be concealed, i.e. whether it isn't marked as never selectively disclosable.
+  pub const fn is_concealable(&self) -> bool {
+    !matches!(self.sd, ClaimDisclosability::Never)
+  }
+}
+
+impl AsRef<str> for ClaimPointer {
+  fn as_ref(&self) -> &str {
+    self.pointer
+  }
+}
+
+impl Display for ClaimPointer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.pointer)
+  }
+}
+
+/// Generates Rust source code containing a [`ClaimPointer`] constant for every claim described by a [`TypeMetadata`].
+///
+/// The generator is meant to be used in build scripts, so that claims can be referenced through
+/// compile-time checked constants rather than stringly typed JSON pointers.
+/// Claims whose path selects all the elements of an array (i.e. contains a `null` segment) cannot
+/// be expressed as a JSON pointer and are skipped.
+///
+/// # Example
+/// ```ignore
+/// // build.rs
+/// let metadata: TypeMetadata = serde_json::from_str(&std::fs::read_to_string("pid_type_metadata.json")?)?;
+/// let code = ClaimPointersGenerator::new(&metadata).module_name("pid").generate()?;
+/// std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR")?).join("pid.rs"), code)?;
+///
+/// // lib.rs
+/// include!(concat!(env!("OUT_DIR"), "/pid.rs"));
+///
+/// let presentation = SdJwtVcPresentationBuilder::new(token, &hasher)?
+///   .conceal(pid::ADDRESS_STREET_ADDRESS)?
+///   .finish();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClaimPointersGenerator<'m> {
+  metadata: &'m TypeMetadata,
+  module_name: String,
+  crate_path: String,
+}
+
+impl<'m> ClaimPointersGenerator<'m> {
+  /// Creates a new [`ClaimPointersGenerator`] for the claims of `metadata`.
+  pub fn new(metadata: &'m TypeMetadata) -> Self {
+    Self {
+      metadata,
+      module_name: "paths".to_owned(),
+      crate_path: "::identity_credential::sd_jwt_vc::metadata".to_owned(),
+    }
+  }
+
+  /// Sets the name of the generated module. Defaults to `paths`.
+  pub fn module_name(mut self, module_name: impl Into<String>) -> Self {
+    self.module_name = module_name.into();
+    self
+  }
+
+  /// Sets the path to this module used by the generated code.
+  /// Defaults to `::identity_credential::sd_jwt_vc::metadata`.
+  pub fn crate_path(mut self, crate_path: impl Into<String>) -> Self {
+    self.crate_path = crate_path.into();
+    self
+  }
+
+  /// Returns the generated Rust source code.
+  ///
+  /// # Errors
+  /// Fails if the module name is not a valid Rust identifier or if two claims map to the same constant name.
+  pub fn generate(&self) -> Result<String> {
+    if !is_identifier(&self.module_name) {
+      return Err(Error::InvalidTypeMetadata(anyhow!(
+        "\"{}\" is not a valid module name",
+        self.module_name
+      )));
+    }
+
+    let mut constants = BTreeSet::new();
+    let mut code = String::new();
+    if let Some(name) = self.metadata.name() {
+      writeln!(code, "/// Claims of SD-JWT VC type \"{}\".", name.escape_default()).unwrap();
+    }
+    writeln!(code, "#[allow(dead_code)]").unwrap();
+    writeln!(code, "pub mod {} {{", self.module_name).unwrap();
+    writeln!(code, "  use {}::ClaimDisclosability;", self.crate_path).unwrap();
+    writeln!(code, "  use {}::ClaimPointer;", self.crate_path).unwrap();
+
+    for claim in self.metadata.claim_metadata() {
+      let Some(pointer) = claim.path.to_json_pointer() else {
+        continue;
+      };
+      let constant = constant_name(&claim.path);
+      if !constants.insert(constant.clone()) {
+        return Err(Error::InvalidTypeMetadata(anyhow!(
+          "more than one claim maps to constant `{constant}`"
+        )));
+      }
+
+      writeln!(code).unwrap();
+      let doc = format!("Claim `{}`{}.", claim.path, label(claim)).replace(['\n', '\r'], " ");
+      writeln!(code, "  /// {doc}").unwrap();
+      writeln!(
+        code,
+        "  pub const {constant}: ClaimPointer = ClaimPointer::new({pointer:?}, ClaimDisclosability::{:?}, {});",
+        claim.sd.unwrap_or_default(),
+        claim.mandatory.unwrap_or_default(),
+      )
+      .unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+
+    Ok(code)
+  }
+}
+
+fn label(claim: &ClaimMetadata) -> String {
+  claim
+    .display
+    .first()
+    .map(|display| format!(" - {}", display.label))
+    .unwrap_or_default()
+}
+
+/// Turns a claim path into a `SCREAMING_SNAKE_CASE` identifier, e.g. `["address", "street"]` -> `ADDRESS_STREET`.
+fn constant_name(path: &[ClaimPathSegment]) -> String {
+  let mut name = path
+    .iter()
+    .map(|segment| match segment {
+      ClaimPathSegment::Name(name) => name
+        .chars()
+        .map(|c| {
+          if c.is_ascii_alphanumeric() {
+            c.to_ascii_uppercase()
+          } else {
+            '_'
+          }
+        })
+        .collect(),
+      ClaimPathSegment::Position(i) => i.to_string(),
+      ClaimPathSegment::All => "ALL".to_owned(),
+    })
+    .collect::<Vec<String>>()
+    .join("_");
+  if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+    name.insert(0, '_');
+  }
+
+  name
+}
+
+fn is_identifier(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    && name != "_"
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn metadata() -> TypeMetadata {
+    serde_json::from_value(json!({
+      "name": "Betelgeuse Education Credential",
+      "claims": [
+        { "path": ["name"], "sd": "never", "mandatory": true },
+        {
+          "path": ["address", "street_address"],
+          "sd": "always",
+          "display": [{ "locale": "en-US", "label": "Street Address" }]
+        },
+        { "path": ["degrees", 0] },
+        { "path": ["degrees", null, "type"] }
+      ]
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn generates_claim_pointers() {
+    let code = ClaimPointersGenerator::new(&metadata())
+      .module_name("education")
+      .generate()
+      .unwrap();
+
+    assert!(code.contains("pub mod education {"));
+    assert!(
+      code.contains(r#"pub const NAME: ClaimPointer = ClaimPointer::new("/name", ClaimDisclosability::Never, true);"#)
+    );
+    assert!(code.contains("/// Claim `[\"address\", \"street_address\"]` - Street Address."));
+    assert!(code.contains(
+      r#"pub const ADDRESS_STREET_ADDRESS: ClaimPointer = ClaimPointer::new("/address/street_address", ClaimDisclosability::Always, false);"#
+    ));
+    assert!(code.contains(
+      r#"pub const DEGREES_0: ClaimPointer = ClaimPointer::new("/degrees/0", ClaimDisclosability::Allowed, false);"#
+    ));
+    assert!(!code.contains("DEGREES_ALL_TYPE"));
+  }
+
+  #[test]
+  fn invalid_module_name_is_rejected() {
+    let metadata = metadata();
+    assert!(ClaimPointersGenerator::new(&metadata)
+      .module_name("my-claims")
+      .generate()
+      .is_err());
+  }
+
+  #[test]
+  fn claim_pointer_policy() {
+    const STREET: ClaimPointer = ClaimPointer::new("/address/street", ClaimDisclosability::Always, false);
+    const NAME: ClaimPointer = ClaimPointer::new("/name", ClaimDisclosability::Never, true);
+
+    assert!(STREET.is_concealable());
+    assert!(!NAME.is_concealable());
+    assert_eq!(STREET.as_ref(), "/address/street");
+  }
+}
@@ -27,6 +27,16 @@ pub struct IssuerMetadata {
 }
 
 impl IssuerMetadata {
+  /// Returns the URL the metadata of `issuer` are published at, i.e. `issuer` with
+  /// [`WELL_KNOWN_VC_ISSUER`] inserted between its origin and its path.
+  pub fn well_known_url(issuer: &Url) -> Url {
+    let origin = issuer.origin().ascii_serialization();
+    let path = issuer.path();
+    format!("{origin}{WELL_KNOWN_VC_ISSUER}{path}")
+      .parse()
+      .expect("valid URL")
+  }
+
   /// Checks the validity of this [`IssuerMetadata`].
   /// [`IssuerMetadata::issuer`] must match `sd_jwt_vc`'s iss claim's value.
   pub fn validate(&self, sd_jwt_vc: &SdJwtVc) -> Result<(), Error> {
@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod claim;
+mod claim_pointer;
 mod display;
 mod integrity;
 mod issuer;
 mod vc_type;
 
 pub use claim::*;
+pub use claim_pointer::*;
 pub use display::*;
 pub use integrity::*;
 pub use issuer::*;
@@ -3,23 +3,39 @@
 
 mod builder;
 mod claims;
+mod derived_claims;
 mod error;
+mod holder_store;
+mod issuer_resolver;
 /// Additional metadata defined by the SD-JWT VC specification
 /// such as issuer's metadata and credential type metadata.
 pub mod metadata;
+/// The EUDI Person Identification Data (PID) SD-JWT VC profile.
+pub mod pid;
 mod presentation;
 /// Resolver trait.
 pub mod resolver;
 mod status;
+mod status_list_token;
 #[cfg(test)]
 pub(crate) mod tests;
 mod token;
+mod validator;
+#[cfg(feature = "x509")]
+mod x509;
 
 pub use builder::*;
 pub use claims::*;
+pub use derived_claims::*;
 pub use error::Error;
 pub use error::Result;
+pub use holder_store::*;
+pub use issuer_resolver::*;
 pub use presentation::*;
 pub use resolver::Resolver;
 pub use status::*;
+pub use status_list_token::*;
 pub use token::*;
+pub use validator::*;
+#[cfg(feature = "x509")]
+pub use x509::*;
@@ -0,0 +1,501 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The EUDI Person Identification Data (PID) attestation, encoded as an SD-JWT VC as mandated by the
+//! [PID Rulebook](https://github.com/eu-digital-identity-wallet/eudi-doc-architecture-and-reference-framework/blob/main/docs/annexes/annex-3/annex-3.01-pid-rulebook.md)
+//! of the EUDI Wallet Architecture and Reference Framework (ARF).
+
+use identity_core::common::Timestamp;
+use sd_jwt::Hasher;
+use sd_jwt::JsonObject;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::metadata::ClaimDisclosability;
+use super::metadata::ClaimMetadata;
+use super::metadata::ClaimPointer;
+use super::metadata::TypeMetadata;
+use super::DerivedClaim;
+use super::Error;
+use super::Result;
+use super::SdJwtVc;
+use super::SdJwtVcBuilder;
+use super::AGE_EQUAL_OR_OVER_CLAIM;
+
+/// The `vct` of PIDs.
+pub const PID_VCT: &str = "urn:eudi:pid:1";
+/// The `vct` of PIDs issued following earlier versions of the PID Rulebook.
+pub const LEGACY_PID_VCT: &str = "urn:eu.europa.ec.eudi:pid:1";
+/// Prefix of the `vct` of national PID extensions, e.g. `urn:eudi:pid:de:1`.
+const NATIONAL_PID_VCT_PREFIX: &str = "urn:eudi:pid:";
+
+const ALWAYS: ClaimDisclosability = ClaimDisclosability::Always;
+const ALLOWED: ClaimDisclosability = ClaimDisclosability::Allowed;
+
+/// The current last name(s) or surname(s) of the user.
+pub const FAMILY_NAME: ClaimPointer = ClaimPointer::new("/family_name", ALWAYS, true);
+/// The current first name(s), including middle name(s), of the user.
+pub const GIVEN_NAME: ClaimPointer = ClaimPointer::new("/given_name", ALWAYS, true);
+/// The day, month and year on which the user was born.
+pub const BIRTHDATE: ClaimPointer = ClaimPointer::new("/birthdate", ALWAYS, true);
+/// The place where the user was born.
+pub const PLACE_OF_BIRTH: ClaimPointer = ClaimPointer::new("/place_of_birth", ALWAYS, true);
+/// The locality where the user was born.
+pub const PLACE_OF_BIRTH_LOCALITY: ClaimPointer = ClaimPointer::new("/place_of_birth/locality", ALWAYS, false);
+/// The region where the user was born.
+pub const PLACE_OF_BIRTH_REGION: ClaimPointer = ClaimPointer::new("/place_of_birth/region", ALWAYS, false);
+/// The country where the user was born.
+pub const PLACE_OF_BIRTH_COUNTRY: ClaimPointer = ClaimPointer::new("/place_of_birth/country", ALWAYS, false);
+/// The nationalities of the user.
+pub const NATIONALITIES: ClaimPointer = ClaimPointer::new("/nationalities", ALWAYS, true);
+/// The address where the user currently resides.
+pub const ADDRESS: ClaimPointer = ClaimPointer::new("/address", ALWAYS, false);
+/// The full address where the user currently resides.
+pub const ADDRESS_FORMATTED: ClaimPointer = ClaimPointer::new("/address/formatted", ALWAYS, false);
+/// The street of the address where the user currently resides.
+pub const ADDRESS_STREET_ADDRESS: ClaimPointer = ClaimPointer::new("/address/street_address", ALWAYS, false);
+/// The house number of the address where the user currently resides.
+pub const ADDRESS_HOUSE_NUMBER: ClaimPointer = ClaimPointer::new("/address/house_number", ALWAYS, false);
+/// The postal code of the address where the user currently resides.
+pub const ADDRESS_POSTAL_CODE: ClaimPointer = ClaimPointer::new("/address/postal_code", ALWAYS, false);
+/// The locality of the address where the user currently resides.
+pub const ADDRESS_LOCALITY: ClaimPointer = ClaimPointer::new("/address/locality", ALWAYS, false);
+/// The region of the address where the user currently resides.
+pub const ADDRESS_REGION: ClaimPointer = ClaimPointer::new("/address/region", ALWAYS, false);
+/// The country of the address where the user currently resides.
+pub const ADDRESS_COUNTRY: ClaimPointer = ClaimPointer::new("/address/country", ALWAYS, false);
+/// A value assigned to the user by the issuing country's administration.
+pub const PERSONAL_ADMINISTRATIVE_NUMBER: ClaimPointer =
+  ClaimPointer::new("/personal_administrative_number", ALWAYS, false);
+/// A facial image of the user.
+pub const PICTURE: ClaimPointer = ClaimPointer::new("/picture", ALWAYS, false);
+/// The last name(s) or surname(s) of the user at the time of birth.
+pub const BIRTH_FAMILY_NAME: ClaimPointer = ClaimPointer::new("/birth_family_name", ALWAYS, false);
+/// The first name(s), including middle name(s), of the user at the time of birth.
+pub const BIRTH_GIVEN_NAME: ClaimPointer = ClaimPointer::new("/birth_given_name", ALWAYS, false);
+/// The sex of the user, as an ISO/IEC 5218 code.
+pub const SEX: ClaimPointer = ClaimPointer::new("/sex", ALWAYS, false);
+/// The electronic mail address of the user.
+pub const EMAIL: ClaimPointer = ClaimPointer::new("/email", ALWAYS, false);
+/// The mobile telephone number of the user.
+pub const PHONE_NUMBER: ClaimPointer = ClaimPointer::new("/phone_number", ALWAYS, false);
+/// The date after which the PID is no longer valid.
+pub const DATE_OF_EXPIRY: ClaimPointer = ClaimPointer::new("/date_of_expiry", ALWAYS, true);
+/// The authority that issued the PID.
+pub const ISSUING_AUTHORITY: ClaimPointer = ClaimPointer::new("/issuing_authority", ALWAYS, true);
+/// The country of the PID provider.
+pub const ISSUING_COUNTRY: ClaimPointer = ClaimPointer::new("/issuing_country", ALWAYS, true);
+/// A number for the PID, assigned by its provider.
+pub const DOCUMENT_NUMBER: ClaimPointer = ClaimPointer::new("/document_number", ALWAYS, false);
+/// The subdivision of the issuing country the PID was issued in.
+pub const ISSUING_JURISDICTION: ClaimPointer = ClaimPointer::new("/issuing_jurisdiction", ALWAYS, false);
+/// The date the PID was issued on.
+pub const DATE_OF_ISSUANCE: ClaimPointer = ClaimPointer::new("/date_of_issuance", ALWAYS, false);
+/// Whether the user is at least a given number of years old, for each attested age.
+pub const AGE_EQUAL_OR_OVER: ClaimPointer = ClaimPointer::new("/age_equal_or_over", ALLOWED, false);
+/// The age of the user, in years.
+pub const AGE_IN_YEARS: ClaimPointer = ClaimPointer::new("/age_in_years", ALWAYS, false);
+/// The year the user was born in.
+pub const AGE_BIRTH_YEAR: ClaimPointer = ClaimPointer::new("/age_birth_year", ALWAYS, false);
+
+/// All the claims of a PID.
+pub const CLAIMS: &[ClaimPointer] = &[
+  FAMILY_NAME,
+  GIVEN_NAME,
+  BIRTHDATE,
+  PLACE_OF_BIRTH,
+  PLACE_OF_BIRTH_LOCALITY,
+  PLACE_OF_BIRTH_REGION,
+  PLACE_OF_BIRTH_COUNTRY,
+  NATIONALITIES,
+  ADDRESS,
+  ADDRESS_FORMATTED,
+  ADDRESS_STREET_ADDRESS,
+  ADDRESS_HOUSE_NUMBER,
+  ADDRESS_POSTAL_CODE,
+  ADDRESS_LOCALITY,
+  ADDRESS_REGION,
+  ADDRESS_COUNTRY,
+  PERSONAL_ADMINISTRATIVE_NUMBER,
+  PICTURE,
+  BIRTH_FAMILY_NAME,
+  BIRTH_GIVEN_NAME,
+  SEX,
+  EMAIL,
+  PHONE_NUMBER,
+  DATE_OF_EXPIRY,
+  ISSUING_AUTHORITY,
+  ISSUING_COUNTRY,
+  DOCUMENT_NUMBER,
+  ISSUING_JURISDICTION,
+  DATE_OF_ISSUANCE,
+  AGE_EQUAL_OR_OVER,
+  AGE_IN_YEARS,
+  AGE_BIRTH_YEAR,
+];
+
+/// Claims registered by SD-JWT and SD-JWT VC, which are not part of a [`Pid`].
+const REGISTERED_CLAIMS: &[&str] = &[
+  "iss",
+  "nbf",
+  "exp",
+  "iat",
+  "vct",
+  "vct#integrity",
+  "sub",
+  "status",
+  "cnf",
+  "_sd_alg",
+];
+
+/// Returns whether `vct` identifies a PID, i.e. is [`PID_VCT`], [`LEGACY_PID_VCT`] or the type of a national
+/// extension of the PID, e.g. `urn:eudi:pid:de:1`.
+pub fn is_pid_vct(vct: &str) -> bool {
+  if vct == PID_VCT || vct == LEGACY_PID_VCT {
+    return true;
+  }
+  vct
+    .strip_prefix(NATIONAL_PID_VCT_PREFIX)
+    .and_then(|vct| vct.split_once(':'))
+    .is_some_and(|(country, version)| {
+      country.len() == 2 && country.bytes().all(|c| c.is_ascii_lowercase()) && version.parse::<u32>().is_ok()
+    })
+}
+
+/// Returns the [`TypeMetadata`] of PIDs, describing which claims are mandatory and which must be selectively
+/// disclosable.
+pub fn type_metadata() -> TypeMetadata {
+  let claims = CLAIMS
+    .iter()
+    .map(|claim| {
+      let path: Vec<&str> = claim.pointer().split('/').skip(1).collect();
+      ClaimMetadata {
+        path: serde_json::from_value(path.into()).expect("valid claim path"),
+        display: Vec::new(),
+        mandatory: Some(claim.mandatory()),
+        sd: Some(claim.sd()),
+        svg_id: None,
+      }
+    })
+    .collect();
+
+  TypeMetadata {
+    name: Some("EUDI Person Identification Data".to_owned()),
+    description: None,
+    extends: None,
+    extends_integrity: None,
+    schema: None,
+    display: Vec::new(),
+    claims,
+  }
+}
+
+/// Checks that `sd_jwt_vc` is a PID, i.e. that its `vct` identifies a PID - see [`is_pid_vct`] - and that the
+/// issuer made each of its claims selectively disclosable as mandated by the PID Rulebook.
+///
+/// Holders should perform this check on the PIDs they receive, verifiers on the PIDs presented to them.
+pub fn check_pid(sd_jwt_vc: &SdJwtVc) -> Result<()> {
+  let vct = &sd_jwt_vc.claims().vct;
+  if !is_pid_vct(vct) {
+    return Err(Error::InvalidClaimValue {
+      name: "vct",
+      expected: "the type of a PID",
+      found: vct.as_str().into(),
+    });
+  }
+
+  sd_jwt_vc.validate_claims_disclosability(type_metadata().claim_metadata())
+}
+
+/// The Person Identification Data of a user.
+///
+/// Dates are full-dates, i.e. `YYYY-MM-DD`, and countries ISO 3166-1 alpha-2 codes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pid {
+  /// See [`FAMILY_NAME`].
+  pub family_name: String,
+  /// See [`GIVEN_NAME`].
+  pub given_name: String,
+  /// See [`BIRTHDATE`].
+  pub birthdate: String,
+  /// See [`PLACE_OF_BIRTH`].
+  pub place_of_birth: PlaceOfBirth,
+  /// See [`NATIONALITIES`].
+  pub nationalities: Vec<String>,
+  /// See [`ADDRESS`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub address: Option<Address>,
+  /// See [`PERSONAL_ADMINISTRATIVE_NUMBER`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub personal_administrative_number: Option<String>,
+  /// See [`PICTURE`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub picture: Option<String>,
+  /// See [`BIRTH_FAMILY_NAME`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub birth_family_name: Option<String>,
+  /// See [`BIRTH_GIVEN_NAME`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub birth_given_name: Option<String>,
+  /// See [`SEX`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sex: Option<u8>,
+  /// See [`EMAIL`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub email: Option<String>,
+  /// See [`PHONE_NUMBER`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub phone_number: Option<String>,
+  /// Administrative data about the PID itself.
+  #[serde(flatten)]
+  pub administrative_data: PidAdministrativeData,
+  /// Additional claims, e.g. age verification claims or claims of national extensions.
+  #[serde(flatten)]
+  pub properties: JsonObject,
+}
+
+impl Pid {
+  /// Creates a new [`Pid`] from its mandatory claims.
+  pub fn new(
+    family_name: impl Into<String>,
+    given_name: impl Into<String>,
+    birthdate: impl Into<String>,
+    place_of_birth: PlaceOfBirth,
+    nationalities: Vec<String>,
+    administrative_data: PidAdministrativeData,
+  ) -> Self {
+    Self {
+      family_name: family_name.into(),
+      given_name: given_name.into(),
+      birthdate: birthdate.into(),
+      place_of_birth,
+      nationalities,
+      address: None,
+      personal_administrative_number: None,
+      picture: None,
+      birth_family_name: None,
+      birth_given_name: None,
+      sex: None,
+      email: None,
+      phone_number: None,
+      administrative_data,
+      properties: JsonObject::new(),
+    }
+  }
+
+  /// Checks that this PID's mandatory claims are set and that its dates and countries are well formed.
+  pub fn check(&self) -> Result<()> {
+    let data = &self.administrative_data;
+    for (name, value) in [
+      ("family_name", &self.family_name),
+      ("given_name", &self.given_name),
+      ("issuing_authority", &data.issuing_authority),
+    ] {
+      if value.trim().is_empty() {
+        return Err(Error::MissingClaim(name));
+      }
+    }
+    if self.nationalities.is_empty() {
+      return Err(Error::MissingClaim("nationalities"));
+    }
+    let place_of_birth = &self.place_of_birth;
+    if place_of_birth.locality.is_none() && place_of_birth.region.is_none() && place_of_birth.country.is_none() {
+      return Err(Error::MissingClaim("place_of_birth"));
+    }
+
+    check_date("birthdate", &self.birthdate)?;
+    check_date("date_of_expiry", &data.date_of_expiry)?;
+    if let Some(date_of_issuance) = &data.date_of_issuance {
+      check_date("date_of_issuance", date_of_issuance)?;
+    }
+    check_country("issuing_country", &data.issuing_country)?;
+    for nationality in &self.nationalities {
+      check_country("nationalities", nationality)?;
+    }
+    if let Some(country) = &place_of_birth.country {
+      check_country("place_of_birth", country)?;
+    }
+    if let Some(country) = self.address.as_ref().and_then(|address| address.country.as_ref()) {
+      check_country("address", country)?;
+    }
+
+    Ok(())
+  }
+
+  /// Returns an [`SdJwtVcBuilder`] for a PID SD-JWT VC carrying this PID, issued at `issued_at`.
+  ///
+  /// Every claim is made selectively disclosable - including nested ones, e.g. each nationality - as mandated by
+  /// the PID Rulebook. Age verification claims are added for each of `age_thresholds` - see
+  /// [`DerivedClaim::age_claims`] -, unless empty.
+  ///
+  /// The issuer, key binding, expiration and status must still be set on the returned builder.
+  pub fn sd_jwt_vc_builder(&self, age_thresholds: &[u32], issued_at: Timestamp) -> Result<SdJwtVcBuilder> {
+    self.check()?;
+
+    let Value::Object(mut object) = serde_json::to_value(self).expect("a PID is a JSON object") else {
+      unreachable!("a PID serializes to a JSON object");
+    };
+    if !age_thresholds.is_empty() {
+      let birthdate = date_to_timestamp(&self.birthdate).expect("checked date");
+      object.extend(DerivedClaim::age_claims(birthdate, age_thresholds, issued_at));
+    }
+
+    // Nested claims must be concealed before their parent.
+    let mut pointers: Vec<String> = Vec::new();
+    for index in 0..self.nationalities.len() {
+      pointers.push(format!("{}/{index}", NATIONALITIES.pointer()));
+    }
+    if let Some(Value::Object(ages)) = object.get(AGE_EQUAL_OR_OVER_CLAIM) {
+      pointers.extend(ages.keys().map(|age| format!("{}/{age}", AGE_EQUAL_OR_OVER.pointer())));
+    }
+    let mut claims: Vec<&ClaimPointer> = CLAIMS.iter().filter(|claim| claim.is_concealable()).collect();
+    claims.sort_by_key(|claim| std::cmp::Reverse(claim.pointer().matches('/').count()));
+    let object_value = Value::Object(object);
+    pointers.extend(
+      claims
+        .into_iter()
+        .filter(|claim| object_value.pointer(claim.pointer()).is_some())
+        .map(|claim| claim.pointer().to_owned()),
+    );
+
+    let builder = SdJwtVcBuilder::new(object_value)?
+      .vct(PID_VCT.to_owned())
+      .iat(issued_at);
+    pointers
+      .iter()
+      .try_fold(builder, |builder, pointer| builder.make_concealable(pointer))
+  }
+
+  /// Extracts the [`Pid`] carried by the fully disclosed `sd_jwt_vc`, after checking it with [`check_pid`].
+  ///
+  /// This is meant for holders receiving their PID: the PIDs presented to verifiers usually don't disclose every
+  /// mandatory claim.
+  pub fn from_sd_jwt_vc(sd_jwt_vc: &SdJwtVc, hasher: &dyn Hasher) -> Result<Self> {
+    check_pid(sd_jwt_vc)?;
+
+    let mut claims = sd_jwt_vc.clone().into_disclosed_object(hasher)?;
+    for claim in REGISTERED_CLAIMS {
+      claims.remove(*claim);
+    }
+    let claims = Value::Object(claims);
+    if let Some(claim) = CLAIMS
+      .iter()
+      .find(|claim| claim.mandatory() && claims.pointer(claim.pointer()).is_none())
+    {
+      return Err(Error::MissingClaim(&claim.pointer()[1..]));
+    }
+
+    let pid: Self = serde_json::from_value(claims).map_err(|e| Error::Validation(e.into()))?;
+    pid.check()?;
+
+    Ok(pid)
+  }
+}
+
+/// Administrative data about a [`Pid`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PidAdministrativeData {
+  /// See [`DATE_OF_EXPIRY`].
+  pub date_of_expiry: String,
+  /// See [`ISSUING_AUTHORITY`].
+  pub issuing_authority: String,
+  /// See [`ISSUING_COUNTRY`].
+  pub issuing_country: String,
+  /// See [`DOCUMENT_NUMBER`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub document_number: Option<String>,
+  /// See [`ISSUING_JURISDICTION`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub issuing_jurisdiction: Option<String>,
+  /// See [`DATE_OF_ISSUANCE`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub date_of_issuance: Option<String>,
+}
+
+impl PidAdministrativeData {
+  /// Creates a new [`PidAdministrativeData`] for a PID issued by `issuing_authority` of `issuing_country`, valid until
+  /// `date_of_expiry`.
+  pub fn new(
+    issuing_authority: impl Into<String>,
+    issuing_country: impl Into<String>,
+    date_of_expiry: impl Into<String>,
+  ) -> Self {
+    Self {
+      date_of_expiry: date_of_expiry.into(),
+      issuing_authority: issuing_authority.into(),
+      issuing_country: issuing_country.into(),
+      document_number: None,
+      issuing_jurisdiction: None,
+      date_of_issuance: None,
+    }
+  }
+}
+
+/// The place where a user was born. At least one of its fields must be set.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaceOfBirth {
+  /// See [`PLACE_OF_BIRTH_LOCALITY`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub locality: Option<String>,
+  /// See [`PLACE_OF_BIRTH_REGION`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub region: Option<String>,
+  /// See [`PLACE_OF_BIRTH_COUNTRY`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+}
+
+/// The address where a user currently resides.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Address {
+  /// See [`ADDRESS_FORMATTED`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub formatted: Option<String>,
+  /// See [`ADDRESS_STREET_ADDRESS`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub street_address: Option<String>,
+  /// See [`ADDRESS_HOUSE_NUMBER`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub house_number: Option<String>,
+  /// See [`ADDRESS_POSTAL_CODE`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub postal_code: Option<String>,
+  /// See [`ADDRESS_LOCALITY`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub locality: Option<String>,
+  /// See [`ADDRESS_REGION`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub region: Option<String>,
+  /// See [`ADDRESS_COUNTRY`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub country: Option<String>,
+}
+
+fn date_to_timestamp(date: &str) -> Option<Timestamp> {
+  Timestamp::parse(&format!("{date}T00:00:00Z")).ok()
+}
+
+fn check_date(name: &'static str, date: &str) -> Result<()> {
+  if date.len() == 10 && date_to_timestamp(date).is_some() {
+    Ok(())
+  } else {
+    Err(Error::InvalidClaimValue {
+      name,
+      expected: "a full-date (YYYY-MM-DD)",
+      found: date.into(),
+    })
+  }
+}
+
+fn check_country(name: &'static str, country: &str) -> Result<()> {
+  if country.len() == 2 && country.bytes().all(|c| c.is_ascii_uppercase()) {
+    Ok(())
+  } else {
+    Err(Error::InvalidClaimValue {
+      name,
+      expected: "an ISO 3166-1 alpha-2 country code",
+      found: country.into(),
+    })
+  }
+}
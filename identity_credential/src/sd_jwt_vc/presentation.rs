@@ -1,6 +1,7 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use super::DerivedClaim;
 use super::Error;
 use super::Result;
 use super::SdJwtVc;
@@ -16,11 +17,17 @@ use sd_jwt::SdJwtPresentationBuilder;
 pub struct SdJwtVcPresentationBuilder {
   vc_claims: SdJwtVcClaims,
   builder: SdJwtPresentationBuilder,
+  pub(crate) derived_claims: Vec<(DerivedClaim, serde_json::Value)>,
+  pub(crate) has_derivation_source: bool,
 }
 
 impl SdJwtVcPresentationBuilder {
   /// Prepare a presentation for a given [`SdJwtVc`].
   pub fn new(token: SdJwtVc, hasher: &dyn Hasher) -> Result<Self> {
+    let disclosed_claims = token.clone().into_disclosed_object(hasher)?;
+    let derived_claims = DerivedClaim::find_all(&disclosed_claims);
+    let has_derivation_source = disclosed_claims.contains_key(super::BIRTHDATE_CLAIM);
+
     let SdJwtVc {
       mut sd_jwt,
       parsed_claims: mut vc_claims,
@@ -31,15 +38,22 @@ impl SdJwtVcPresentationBuilder {
     std::mem::swap(sd_jwt.claims_mut(), &mut vc_claims.sd_jwt_claims);
     let builder = sd_jwt.into_presentation(hasher).map_err(Error::SdJwt)?;
 
-    Ok(Self { vc_claims, builder })
+    Ok(Self {
+      vc_claims,
+      builder,
+      derived_claims,
+      has_derivation_source,
+    })
   }
   /// Removes the disclosure for the property at `path`, conceiling it.
   ///
+  /// `path` is either a JSON pointer or a [`ClaimPointer`](super::metadata::ClaimPointer).
+  ///
   /// ## Notes
   /// - When concealing a claim more than one disclosure may be removed: the disclosure for the claim itself and the
   ///   disclosures for any concealable sub-claim.
-  pub fn conceal(mut self, path: &str) -> Result<Self> {
-    self.builder = self.builder.conceal(path).map_err(Error::SdJwt)?;
+  pub fn conceal(mut self, path: impl AsRef<str>) -> Result<Self> {
+    self.builder = self.builder.conceal(path.as_ref()).map_err(Error::SdJwt)?;
     Ok(self)
   }
 
@@ -58,8 +72,8 @@ impl SdJwtVcPresentationBuilder {
   ///   `path` together with *all* its parents that are disclosable values themselves.
   /// - By default *all* disclosable claims are disclosed, therefore this method can only be used to *undo* any
   ///   concealment operations previously performed by either [Self::conceal] or [Self::conceal_all].
-  pub fn disclose(mut self, path: &str) -> Result<Self> {
-    self.builder = self.builder.disclose(path).map_err(Error::SdJwt)?;
+  pub fn disclose(mut self, path: impl AsRef<str>) -> Result<Self> {
+    self.builder = self.builder.disclose(path.as_ref()).map_err(Error::SdJwt)?;
     Ok(self)
   }
 
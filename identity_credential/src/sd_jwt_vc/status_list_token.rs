@@ -0,0 +1,385 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::io::Read as _;
+use std::io::Write as _;
+
+use anyhow::anyhow;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::Decoder;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jwu::decode_b64;
+use identity_verification::jwu::encode_b64;
+use sd_jwt::Hasher;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::Error;
+use super::Resolver;
+use super::Result;
+use super::SdJwtVc;
+use super::StatusListRef;
+use super::StatusMechanism;
+use crate::validator::JwtCredentialValidator as JwsUtils;
+use crate::validator::StatusCheck;
+use crate::validator::SuspensionCheck;
+
+/// JOSE header `typ`'s value of a status list token.
+pub const STATUS_LIST_TOKEN_TYP: &str = "statuslist+jwt";
+
+/// The status of a referenced token, as recorded in a [`StatusList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TokenStatus {
+  /// The token is valid.
+  Valid,
+  /// The token has been revoked.
+  Invalid,
+  /// The token has been temporarily suspended.
+  Suspended,
+  /// An application specific or not yet registered status.
+  Other(u8),
+}
+
+impl From<u8> for TokenStatus {
+  fn from(value: u8) -> Self {
+    match value {
+      0x00 => Self::Valid,
+      0x01 => Self::Invalid,
+      0x02 => Self::Suspended,
+      other => Self::Other(other),
+    }
+  }
+}
+
+impl From<TokenStatus> for u8 {
+  fn from(value: TokenStatus) -> Self {
+    match value {
+      TokenStatus::Valid => 0x00,
+      TokenStatus::Invalid => 0x01,
+      TokenStatus::Suspended => 0x02,
+      TokenStatus::Other(other) => other,
+    }
+  }
+}
+
+impl Display for TokenStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Valid => f.write_str("valid"),
+      Self::Invalid => f.write_str("invalid"),
+      Self::Suspended => f.write_str("suspended"),
+      Self::Other(status) => write!(f, "0x{status:02x}"),
+    }
+  }
+}
+
+/// A compressed list of token statuses, as described in
+/// [OAuth Token Status List](https://datatracker.ietf.org/doc/html/draft-ietf-oauth-status-list).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusList {
+  /// Number of bits used to encode each status: 1, 2, 4 or 8.
+  pub bits: u8,
+  /// The base64url-encoded, ZLIB-compressed, status bytes.
+  pub lst: String,
+}
+
+impl StatusList {
+  /// Encodes `statuses` into a new [`StatusList`], using `bits` bits for each of them.
+  ///
+  /// # Errors
+  /// Fails if `bits` is not 1, 2, 4 or 8, or if a status doesn't fit in `bits` bits.
+  pub fn new(bits: u8, statuses: &[u8]) -> Result<Self> {
+    check_bits(bits)?;
+    let per_byte = usize::from(8 / bits);
+    let mut bytes = vec![0u8; (statuses.len() + per_byte - 1) / per_byte];
+    for (idx, status) in statuses.iter().enumerate() {
+      if u16::from(*status) >= 1 << bits {
+        return Err(Error::InvalidStatusList(anyhow!(
+          "status {status} at index {idx} doesn't fit in {bits} bits"
+        )));
+      }
+      bytes[idx / per_byte] |= status << ((idx % per_byte) as u8 * bits);
+    }
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(&bytes).expect("writing to a Vec cannot fail");
+    let compressed = encoder.finish().expect("writing to a Vec cannot fail");
+
+    Ok(Self {
+      bits,
+      lst: encode_b64(compressed),
+    })
+  }
+
+  /// Returns the status at index `idx`.
+  ///
+  /// # Errors
+  /// Fails if the list cannot be decoded or if `idx` is out of its bounds.
+  pub fn get(&self, idx: usize) -> Result<TokenStatus> {
+    self.decode().and_then(|bytes| self.get_in(&bytes, idx))
+  }
+
+  fn decode(&self) -> Result<Vec<u8>> {
+    check_bits(self.bits)?;
+    let compressed = decode_b64(&self.lst).map_err(|e| Error::InvalidStatusList(e.into()))?;
+    let mut bytes = Vec::new();
+    ZlibDecoder::new(compressed.as_slice())
+      .read_to_end(&mut bytes)
+      .map_err(|e| Error::InvalidStatusList(e.into()))?;
+
+    Ok(bytes)
+  }
+
+  fn get_in(&self, bytes: &[u8], idx: usize) -> Result<TokenStatus> {
+    // Statuses are packed starting from the least significant bits of each byte.
+    let per_byte = usize::from(8 / self.bits);
+    let byte = bytes
+      .get(idx / per_byte)
+      .ok_or_else(|| Error::InvalidStatusList(anyhow!("index {idx} is out of the status list's bounds")))?;
+    let shift = (idx % per_byte) as u8 * self.bits;
+    let mask = (((1u16 << self.bits) - 1) as u8) << shift;
+
+    Ok(TokenStatus::from((byte & mask) >> shift))
+  }
+}
+
+fn check_bits(bits: u8) -> Result<()> {
+  if matches!(bits, 1 | 2 | 4 | 8) {
+    Ok(())
+  } else {
+    Err(Error::InvalidStatusList(anyhow!(
+      "invalid `bits` value {bits}; expected 1, 2, 4 or 8"
+    )))
+  }
+}
+
+/// The claims of a [`StatusListToken`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StatusListTokenClaims {
+  /// URI of the status list; must match the `uri` of the referencing tokens' status claim.
+  pub sub: Url,
+  /// When the token was issued, as a UNIX timestamp.
+  pub iat: i64,
+  /// When the token expires, as a UNIX timestamp.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub exp: Option<i64>,
+  /// For how long, in seconds, the token may be cached before being fetched again.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ttl: Option<u64>,
+  /// The status list.
+  pub status_list: StatusList,
+}
+
+/// A JWT carrying a [`StatusList`], as referenced by the `status` claim of an [`SdJwtVc`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusListToken {
+  jwt: String,
+  claims: StatusListTokenClaims,
+}
+
+impl StatusListToken {
+  /// Parses a [`StatusListToken`] from its compact JWS serialization, without verifying its signature.
+  ///
+  /// # Errors
+  /// Fails if `jwt` is not a JWT with `typ` [`STATUS_LIST_TOKEN_TYP`] and valid status list token claims.
+  pub fn parse(jwt: &str) -> Result<Self> {
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jwt.as_bytes(), None)
+      .map_err(|e| Error::InvalidStatusList(e.into()))?;
+    let typ = decoded.protected_header().and_then(|header| header.typ());
+    if typ != Some(STATUS_LIST_TOKEN_TYP) {
+      return Err(Error::InvalidStatusList(anyhow!(
+        "invalid \"typ\" value; expected \"{STATUS_LIST_TOKEN_TYP}\" but found {typ:?}"
+      )));
+    }
+    let claims = serde_json::from_slice(decoded.claims()).map_err(|e| Error::InvalidStatusList(e.into()))?;
+
+    Ok(Self {
+      jwt: jwt.to_owned(),
+      claims,
+    })
+  }
+
+  /// Returns this token's claims.
+  pub fn claims(&self) -> &StatusListTokenClaims {
+    &self.claims
+  }
+
+  /// Returns this token's compact JWS serialization.
+  pub fn as_str(&self) -> &str {
+    &self.jwt
+  }
+
+  /// Verifies this token's signature.
+  pub fn verify_signature<V: JwsVerifier>(&self, jws_verifier: &V, jwk: &Jwk) -> Result<()> {
+    let decoded = JwsUtils::<V>::decode(&self.jwt).map_err(|e| Error::Verification(e.into()))?;
+    JwsUtils::<V>::verify_signature_raw(decoded, jwk, jws_verifier)
+      .map_err(|e| Error::Verification(e.into()))
+      .and(Ok(()))
+  }
+
+  /// Returns whether this token expired as of `at`.
+  pub fn is_expired(&self, at: Timestamp) -> bool {
+    self.claims.exp.is_some_and(|exp| exp <= at.to_unix())
+  }
+
+  /// Returns the status `reference` points to.
+  ///
+  /// # Errors
+  /// Fails if `reference` points to another status list, or to an entry that is not in this one.
+  pub fn status(&self, reference: &StatusListRef) -> Result<TokenStatus> {
+    if reference.uri != self.claims.sub {
+      return Err(Error::InvalidStatusList(anyhow!(
+        "expected the status list \"{}\" but got \"{}\"",
+        reference.uri,
+        self.claims.sub
+      )));
+    }
+    self.claims.status_list.get(reference.idx)
+  }
+}
+
+/// Options for [`SdJwtVc::validate_with_options`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SdJwtVcValidationOptions {
+  /// How to handle the token's `status` claim. Defaults to [`StatusCheck::Strict`].
+  pub status_check: StatusCheck,
+  /// How to handle suspended tokens. Defaults to [`SuspensionCheck::FailOnSuspended`].
+  pub suspension_check: SuspensionCheck,
+}
+
+impl SdJwtVcValidationOptions {
+  /// Sets how to handle the token's `status` claim.
+  pub fn status_check(mut self, status_check: StatusCheck) -> Self {
+    self.status_check = status_check;
+    self
+  }
+
+  /// Sets how to handle suspended tokens.
+  pub fn suspension_check(mut self, suspension_check: SuspensionCheck) -> Self {
+    self.suspension_check = suspension_check;
+    self
+  }
+}
+
+impl SdJwtVc {
+  /// Fetches the [`StatusListToken`] referenced by this token's `status` claim, if any.
+  ///
+  /// ## Notes
+  /// The returned token's signature is not verified.
+  pub async fn status_list_token<R>(&self, resolver: &R) -> Result<Option<StatusListToken>>
+  where
+    R: Resolver<Url, Vec<u8>>,
+  {
+    let Some(StatusMechanism::StatusList(reference)) = self.claims().status.as_ref().map(|status| &status.0) else {
+      return Ok(None);
+    };
+    let raw = resolver.resolve(&reference.uri).await.map_err(|e| Error::Resolution {
+      input: reference.uri.to_string(),
+      source: e,
+    })?;
+    let jwt = std::str::from_utf8(&raw).map_err(|e| Error::InvalidStatusList(e.into()))?;
+
+    StatusListToken::parse(jwt.trim()).map(Some)
+  }
+
+  /// Checks this token's status through the status list referenced by its `status` claim, which must be signed
+  /// with the same key as this token.
+  ///
+  /// Fails with [`Error::TokenStatus`] if the token is not valid, unless it is suspended and `suspension_check` is
+  /// [`SuspensionCheck::AllowSuspended`]. Tokens without a `status` claim are considered valid; tokens with an
+  /// unsupported status mechanism are only accepted with [`StatusCheck::SkipUnsupported`].
+  pub async fn check_status<R, V>(
+    &self,
+    resolver: &R,
+    jws_verifier: &V,
+    options: &SdJwtVcValidationOptions,
+  ) -> Result<()>
+  where
+    R: Resolver<Url, Vec<u8>>,
+    V: JwsVerifier,
+  {
+    let reference = match self.claims().status.as_ref().map(|status| &status.0) {
+      _ if options.status_check == StatusCheck::SkipAll => return Ok(()),
+      None => return Ok(()),
+      Some(StatusMechanism::StatusList(reference)) => reference,
+      Some(StatusMechanism::Custom(_)) if options.status_check == StatusCheck::SkipUnsupported => return Ok(()),
+      Some(StatusMechanism::Custom(mechanism)) => {
+        return Err(Error::Validation(anyhow!("unsupported status mechanism {mechanism}")))
+      }
+    };
+
+    let status_list_token = self
+      .status_list_token(resolver)
+      .await?
+      .expect("the token references a status list");
+    let jwk = self.issuer_jwk(resolver).await?;
+    status_list_token.verify_signature(jws_verifier, &jwk)?;
+    if status_list_token.is_expired(Timestamp::now_utc()) {
+      return Err(Error::InvalidStatusList(anyhow!("the status list token has expired")));
+    }
+
+    match status_list_token.status(reference)? {
+      TokenStatus::Valid => Ok(()),
+      TokenStatus::Suspended if options.suspension_check == SuspensionCheck::AllowSuspended => Ok(()),
+      status => Err(Error::TokenStatus(status)),
+    }
+  }
+
+  /// Same as [`SdJwtVc::validate`], additionally checking this token's status according to `options` - see
+  /// [`SdJwtVc::check_status`].
+  pub async fn validate_with_options<R, V>(
+    &self,
+    resolver: &R,
+    jws_verifier: &V,
+    hasher: &dyn Hasher,
+    options: &SdJwtVcValidationOptions,
+  ) -> Result<()>
+  where
+    R: Resolver<Url, Vec<u8>>,
+    R: Resolver<String, Vec<u8>>,
+    R: Resolver<Url, Value>,
+    V: JwsVerifier,
+  {
+    self.validate(resolver, jws_verifier, hasher).await?;
+    self.check_status(resolver, jws_verifier, options).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn statuses_are_packed_from_the_least_significant_bits() {
+    let statuses = [1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 1, 0, 1];
+    let status_list = StatusList::new(1, &statuses).unwrap();
+    assert_eq!(status_list.decode().unwrap(), [0xb9, 0xa3]);
+    for (idx, status) in statuses.into_iter().enumerate() {
+      assert_eq!(u8::from(status_list.get(idx).unwrap()), status);
+    }
+    assert!(status_list.get(16).is_err());
+  }
+
+  #[test]
+  fn multi_bit_statuses() {
+    let statuses = [1, 2, 0, 3, 0, 1];
+    let status_list = StatusList::new(2, &statuses).unwrap();
+    assert_eq!(status_list.decode().unwrap(), [0xc9, 0x04]);
+    assert_eq!(status_list.get(1).unwrap(), TokenStatus::Suspended);
+    assert_eq!(status_list.get(3).unwrap(), TokenStatus::Other(3));
+
+    assert!(StatusList::new(2, &[4]).is_err());
+    assert!(StatusList::new(3, &[0]).is_err());
+  }
+}
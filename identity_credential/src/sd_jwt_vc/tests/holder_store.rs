@@ -0,0 +1,127 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use serde_json::json;
+
+use crate::sd_jwt_vc::SdJwtVc;
+use crate::sd_jwt_vc::SdJwtVcBuilder;
+use crate::sd_jwt_vc::SdJwtVcHolderStore;
+
+use super::TestSigner;
+
+async fn credential(sub: &str, issued_days_ago: i64) -> SdJwtVc {
+  SdJwtVcBuilder::new(json!({ "degree": "BSc" }))
+    .unwrap()
+    .header("kid", "key1")
+    .vct("https://example.com/education_credential".parse::<Url>().unwrap())
+    .iss("https://example.com".parse().unwrap())
+    .sub(sub.to_owned())
+    .iat(
+      Timestamp::now_utc()
+        .checked_sub(Duration::days(issued_days_ago))
+        .unwrap(),
+    )
+    .finish(&TestSigner, "HS256")
+    .await
+    .unwrap()
+}
+
+async fn undated_credential(sub: &str, degree: &str) -> SdJwtVc {
+  SdJwtVcBuilder::new(json!({ "degree": degree }))
+    .unwrap()
+    .header("kid", "key1")
+    .vct("https://example.com/education_credential".parse::<Url>().unwrap())
+    .iss("https://example.com".parse().unwrap())
+    .sub(sub.to_owned())
+    .finish(&TestSigner, "HS256")
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn newer_credentials_supersede_older_ones() {
+  let store = SdJwtVcHolderStore::new();
+
+  let old = store.insert(credential("did:example:alice", 2).await);
+  assert!(old.superseded.is_empty());
+  assert_eq!(old.superseded_by, None);
+
+  let new = store.insert(credential("did:example:alice", 1).await);
+  assert_eq!(new.superseded, vec![old.id]);
+  assert!(store.is_superseded(old.id));
+  assert_eq!(store.superseded_by(old.id), Some(new.id));
+  assert_eq!(store.supersedes(new.id), vec![old.id]);
+
+  // Another subject's credential belongs to another lineage.
+  let other = store.insert(credential("did:example:bob", 3).await);
+  assert!(other.superseded.is_empty());
+
+  let latest: Vec<_> = store.latest().into_iter().map(|(id, _)| id).collect();
+  assert_eq!(latest, vec![new.id, other.id]);
+  assert_eq!(store.lineage(old.id), vec![old.id, new.id]);
+  assert_eq!(
+    store.supersession_graph().into_iter().collect::<Vec<_>>(),
+    vec![(old.id, new.id)]
+  );
+}
+
+#[tokio::test]
+async fn older_credentials_stored_late_are_superseded_right_away() {
+  let store = SdJwtVcHolderStore::new();
+
+  let newest = store.insert(credential("did:example:alice", 1).await);
+  let oldest = store.insert(credential("did:example:alice", 3).await);
+  assert_eq!(oldest.superseded_by, Some(newest.id));
+  assert!(oldest.superseded.is_empty());
+
+  let middle = store.insert(credential("did:example:alice", 2).await);
+  assert_eq!(middle.superseded_by, Some(newest.id));
+  assert_eq!(store.lineage(newest.id), vec![oldest.id, middle.id, newest.id]);
+}
+
+#[tokio::test]
+async fn credentials_missing_iat_are_ordered_by_insertion() {
+  let store = SdJwtVcHolderStore::new();
+
+  let first = store.insert(undated_credential("did:example:alice", "BSc").await);
+  let dated = store.insert(credential("did:example:alice", 2).await);
+  assert_eq!(dated.superseded, vec![first.id]);
+
+  let undated = store.insert(undated_credential("did:example:alice", "MSc").await);
+  assert_eq!(undated.superseded, vec![dated.id]);
+
+  // A credential with `iat` is still ordered by it.
+  let older = store.insert(credential("did:example:alice", 3).await);
+  assert_eq!(older.superseded_by, Some(dated.id));
+  assert_eq!(store.lineage(first.id), vec![first.id, older.id, dated.id, undated.id]);
+}
+
+#[tokio::test]
+async fn duplicates_are_not_stored_twice() {
+  let store = SdJwtVcHolderStore::new();
+  let credential = credential("did:example:alice", 1).await;
+
+  let first = store.insert(credential.clone());
+  let second = store.insert(credential);
+  assert!(second.duplicate);
+  assert_eq!(second.id, first.id);
+  assert_eq!(store.len(), 1);
+}
+
+#[tokio::test]
+async fn pruning_removes_superseded_credentials() {
+  let store = SdJwtVcHolderStore::new();
+  let old = store.insert(credential("did:example:alice", 2).await);
+  let new = store.insert(credential("did:example:alice", 1).await);
+
+  assert_eq!(store.prune_superseded(), vec![old.id]);
+  assert!(store.get(old.id).is_none());
+  assert_eq!(store.lineage(new.id), vec![new.id]);
+  assert!(store.supersession_graph().is_empty());
+
+  store.remove(new.id);
+  assert!(store.is_empty());
+}
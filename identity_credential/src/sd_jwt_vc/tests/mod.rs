@@ -21,8 +21,13 @@ use serde_json::Value;
 use super::resolver;
 use super::Resolver;
 
+mod holder_store;
+mod pid;
 mod presentation;
+mod status;
 mod validation;
+#[cfg(feature = "x509")]
+mod x509;
 
 pub(crate) const ISSUER_SECRET: &[u8] = b"0123456789ABCDEF0123456789ABCDEF";
 
@@ -0,0 +1,109 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use sd_jwt::Sha256Hasher;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::sd_jwt_vc::pid;
+use crate::sd_jwt_vc::pid::Address;
+use crate::sd_jwt_vc::pid::Pid;
+use crate::sd_jwt_vc::pid::PidAdministrativeData;
+use crate::sd_jwt_vc::pid::PlaceOfBirth;
+use crate::sd_jwt_vc::tests::TestSigner;
+use crate::sd_jwt_vc::DerivedClaim;
+use crate::sd_jwt_vc::Error;
+use crate::sd_jwt_vc::SdJwtVcBuilder;
+
+fn pid() -> Pid {
+  let place_of_birth = PlaceOfBirth {
+    locality: Some("Berlin".to_owned()),
+    country: Some("DE".to_owned()),
+    ..Default::default()
+  };
+  let mut pid = Pid::new(
+    "Mustermann",
+    "Erika",
+    "1964-08-12",
+    place_of_birth,
+    vec!["DE".to_owned()],
+    PidAdministrativeData::new("DE", "DE", "2035-01-01"),
+  );
+  pid.address = Some(Address {
+    street_address: Some("Heidestraße".to_owned()),
+    house_number: Some("17".to_owned()),
+    locality: Some("Köln".to_owned()),
+    country: Some("DE".to_owned()),
+    ..Default::default()
+  });
+
+  pid
+}
+
+#[tokio::test]
+async fn pid_issuance_roundtrip() -> anyhow::Result<()> {
+  let issued_at = Timestamp::parse("2025-01-01T00:00:00Z")?;
+  let sd_jwt_vc = pid()
+    .sd_jwt_vc_builder(&[18, 65], issued_at)?
+    .iss("https://pid-provider.example.com".parse()?)
+    .finish(&TestSigner, "HS256")
+    .await?;
+
+  assert_eq!(sd_jwt_vc.claims().vct, pid::PID_VCT);
+  // Every PID claim is selectively disclosable.
+  assert!(!sd_jwt_vc.claims().contains_key("family_name"));
+  assert!(!sd_jwt_vc.claims().contains_key("address"));
+  pid::check_pid(&sd_jwt_vc)?;
+
+  let received = Pid::from_sd_jwt_vc(&sd_jwt_vc, &Sha256Hasher)?;
+  assert_eq!(received.properties.get("age_in_years"), Some(&json!(60)));
+  assert_eq!(
+    received.properties.get("age_equal_or_over"),
+    Some(&json!({ "18": true, "65": false }))
+  );
+  let mut expected = received.clone();
+  expected.properties.clear();
+  assert_eq!(expected, pid());
+
+  // A holder can prove being of age without disclosing its birthdate.
+  let (presentation, _) = sd_jwt_vc
+    .into_presentation(&Sha256Hasher)?
+    .present_derived(DerivedClaim::AgeEqualOrOver(18))?
+    .finish();
+  let disclosed = presentation.into_disclosed_object(&Sha256Hasher)?;
+  assert!(!disclosed.contains_key("birthdate"));
+  assert_eq!(disclosed.get("age_equal_or_over"), Some(&json!({ "18": true })));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn non_compliant_pids_are_rejected() -> anyhow::Result<()> {
+  let mut invalid = pid();
+  invalid.nationalities = vec!["Germany".to_owned()];
+  assert!(matches!(
+    invalid.sd_jwt_vc_builder(&[], Timestamp::now_utc()),
+    Err(Error::InvalidClaimValue {
+      name: "nationalities",
+      ..
+    })
+  ));
+
+  // A PID whose family name is not selectively disclosable.
+  let Value::Object(claims) = serde_json::to_value(pid())? else {
+    unreachable!()
+  };
+  let sd_jwt_vc = SdJwtVcBuilder::new(claims)?
+    .vct(pid::PID_VCT.to_owned())
+    .iss("https://pid-provider.example.com".parse()?)
+    .make_concealable("/given_name")?
+    .finish(&TestSigner, "HS256")
+    .await?;
+  assert!(matches!(pid::check_pid(&sd_jwt_vc), Err(Error::Validation(_))));
+
+  assert!(pid::is_pid_vct("urn:eudi:pid:de:1"));
+  assert!(!pid::is_pid_vct("https://example.com/pid"));
+
+  Ok(())
+}
@@ -7,6 +7,7 @@ use sd_jwt::Sha256Hasher;
 use serde_json::json;
 
 use crate::sd_jwt_vc::tests::TestSigner;
+use crate::sd_jwt_vc::DerivedClaim;
 use crate::sd_jwt_vc::SdJwtVcBuilder;
 
 #[tokio::test]
@@ -46,3 +47,42 @@ async fn test_sd_jwt_presentation_builder() -> anyhow::Result<()> {
 
   Ok(())
 }
+
+#[tokio::test]
+async fn derived_claims_are_presented_in_place_of_their_source() -> anyhow::Result<()> {
+  let birthdate = Timestamp::parse("2000-06-15T00:00:00Z")?;
+  let now = Timestamp::parse("2020-06-14T00:00:00Z")?;
+  let mut claims = DerivedClaim::age_claims(birthdate, &[16, 18, 21], now);
+  claims.insert("birthdate".to_owned(), "2000-06-15".into());
+  assert_eq!(claims["age_in_years"], 19);
+
+  let credential = SdJwtVcBuilder::new(claims)?
+    .header("kid", "key1")
+    .vct("https://example.com/pid".parse::<Url>()?)
+    .iat(Timestamp::now_utc())
+    .iss("https://example.com".parse()?)
+    .make_concealable("/birthdate")?
+    .make_concealable("/age_equal_or_over/16")?
+    .make_concealable("/age_equal_or_over/18")?
+    .make_concealable("/age_equal_or_over/21")?
+    .make_concealable("/age_in_years")?
+    .make_concealable("/age_birth_year")?
+    .finish(&TestSigner, "HS256")
+    .await?;
+
+  let builder = credential.into_presentation(&Sha256Hasher)?;
+  assert_eq!(builder.derived_claims().len(), 5);
+
+  // No attestation proves the holder is 21, as they are not.
+  assert!(builder.clone().present_age_equal_or_over(21).is_err());
+
+  // Asking for 17 selects the attestation for 18, as it implies the former.
+  let (presentation, _) = builder.present_age_equal_or_over(17)?.finish();
+  let disclosed = presentation.into_disclosed_object(&Sha256Hasher)?;
+  assert_eq!(disclosed["age_equal_or_over"], json!({ "18": true }));
+  assert!(!disclosed.contains_key("birthdate"));
+  assert!(!disclosed.contains_key("age_in_years"));
+  assert!(!disclosed.contains_key("age_birth_year"));
+
+  Ok(())
+}
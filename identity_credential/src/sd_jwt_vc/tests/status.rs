@@ -0,0 +1,134 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_verification::jwk::JwkSet;
+use sd_jwt::JsonObject;
+use sd_jwt::JwsSigner;
+use serde_json::json;
+
+use crate::sd_jwt_vc::metadata::IssuerMetadata;
+use crate::sd_jwt_vc::metadata::Jwks;
+use crate::sd_jwt_vc::Error;
+use crate::sd_jwt_vc::SdJwtVc;
+use crate::sd_jwt_vc::SdJwtVcBuilder;
+use crate::sd_jwt_vc::SdJwtVcValidationOptions;
+use crate::sd_jwt_vc::Status;
+use crate::sd_jwt_vc::StatusList;
+use crate::sd_jwt_vc::StatusListRef;
+use crate::sd_jwt_vc::StatusMechanism;
+use crate::sd_jwt_vc::TokenStatus;
+use crate::sd_jwt_vc::STATUS_LIST_TOKEN_TYP;
+use crate::validator::StatusCheck;
+use crate::validator::SuspensionCheck;
+
+use super::TestJwsVerifier;
+use super::TestResolver;
+use super::TestSigner;
+
+const STATUS_LIST_URI: &str = "https://example.com/statuslists/1";
+
+async fn status_list_token(exp: Option<i64>) -> Vec<u8> {
+  let header = json!({ "alg": "HS256", "typ": STATUS_LIST_TOKEN_TYP, "kid": "key1" });
+  let mut claims = json!({
+    "sub": STATUS_LIST_URI,
+    "iat": Timestamp::now_utc().to_unix(),
+    // Index 0 is valid, 1 is revoked and 2 is suspended.
+    "status_list": StatusList::new(2, &[0, 1, 2]).unwrap(),
+  });
+  if let Some(exp) = exp {
+    claims["exp"] = exp.into();
+  }
+  let as_object = |value: serde_json::Value| serde_json::from_value::<JsonObject>(value).unwrap();
+
+  TestSigner.sign(&as_object(header), &as_object(claims)).await.unwrap()
+}
+
+async fn test_resolver(exp: Option<i64>) -> TestResolver {
+  let mut jwk_set = JwkSet::new();
+  jwk_set.add(super::signer_secret_jwk());
+  let issuer_metadata = IssuerMetadata {
+    issuer: "https://example.com".parse().unwrap(),
+    jwks: Jwks::Object(jwk_set),
+  };
+
+  let mut resolver = TestResolver::new();
+  resolver.insert_resource("https://example.com/.well-known/jwt-vc-issuer/", issuer_metadata);
+  resolver
+    .0
+    .insert(STATUS_LIST_URI.to_owned(), status_list_token(exp).await);
+
+  resolver
+}
+
+async fn sd_jwt_vc(idx: usize) -> SdJwtVc {
+  SdJwtVcBuilder::new(json!({ "name": "John Doe" }))
+    .unwrap()
+    .header("kid", "key1")
+    .vct("https://example.com/education_credential".parse::<Url>().unwrap())
+    .iat(Timestamp::now_utc())
+    .iss("https://example.com".parse().unwrap())
+    .status(Status(StatusMechanism::StatusList(StatusListRef {
+      uri: STATUS_LIST_URI.parse().unwrap(),
+      idx,
+    })))
+    .finish(&TestSigner, "HS256")
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn token_status_is_checked() -> anyhow::Result<()> {
+  let resolver = test_resolver(None).await;
+  let options = SdJwtVcValidationOptions::default();
+
+  let status_list_token = sd_jwt_vc(0).await.status_list_token(&resolver).await?.unwrap();
+  assert_eq!(status_list_token.claims().sub.as_str(), STATUS_LIST_URI);
+
+  sd_jwt_vc(0)
+    .await
+    .check_status(&resolver, &TestJwsVerifier, &options)
+    .await?;
+  let revoked = sd_jwt_vc(1).await;
+  let err = revoked
+    .check_status(&resolver, &TestJwsVerifier, &options)
+    .await
+    .unwrap_err();
+  assert!(matches!(err, Error::TokenStatus(TokenStatus::Invalid)));
+  revoked
+    .check_status(&resolver, &TestJwsVerifier, &options.status_check(StatusCheck::SkipAll))
+    .await?;
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn suspended_tokens_can_be_allowed() -> anyhow::Result<()> {
+  let resolver = test_resolver(None).await;
+  let suspended = sd_jwt_vc(2).await;
+
+  let err = suspended
+    .check_status(&resolver, &TestJwsVerifier, &SdJwtVcValidationOptions::default())
+    .await
+    .unwrap_err();
+  assert!(matches!(err, Error::TokenStatus(TokenStatus::Suspended)));
+
+  let options = SdJwtVcValidationOptions::default().suspension_check(SuspensionCheck::AllowSuspended);
+  suspended.check_status(&resolver, &TestJwsVerifier, &options).await?;
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn expired_status_list_tokens_are_rejected() -> anyhow::Result<()> {
+  let resolver = test_resolver(Some(Timestamp::now_utc().to_unix() - 60)).await;
+  let err = sd_jwt_vc(0)
+    .await
+    .check_status(&resolver, &TestJwsVerifier, &SdJwtVcValidationOptions::default())
+    .await
+    .unwrap_err();
+  assert!(matches!(err, Error::InvalidStatusList(_)));
+
+  Ok(())
+}
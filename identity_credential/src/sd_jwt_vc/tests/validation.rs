@@ -1,6 +1,11 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use async_trait::async_trait;
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_verification::jwk::JwkSet;
@@ -10,9 +15,14 @@ use serde_json::json;
 use crate::sd_jwt_vc::metadata::IssuerMetadata;
 use crate::sd_jwt_vc::metadata::Jwks;
 use crate::sd_jwt_vc::metadata::TypeMetadata;
+use crate::sd_jwt_vc::resolver;
 use crate::sd_jwt_vc::tests::TestJwsVerifier;
 use crate::sd_jwt_vc::Error;
+use crate::sd_jwt_vc::JwtVcIssuerResolver;
+use crate::sd_jwt_vc::Resolver;
 use crate::sd_jwt_vc::SdJwtVcBuilder;
+use crate::sd_jwt_vc::SdJwtVcValidationOptions;
+use crate::sd_jwt_vc::SdJwtVcValidator;
 
 use super::TestResolver;
 use super::TestSigner;
@@ -90,6 +100,141 @@ async fn validation_of_invalid_token_fails() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn validation_through_issuer_metadata_works() -> anyhow::Result<()> {
+  let issued_at = Timestamp::now_utc();
+  let expires_at = issued_at.checked_add(Duration::hours(1)).unwrap();
+  let sd_jwt_credential = SdJwtVcBuilder::new(json!({ "name": "John Doe" }))?
+    .header("kid", "key1")
+    .vct("https://example.com/education_credential".parse::<Url>()?)
+    .iat(issued_at)
+    .exp(expires_at)
+    .iss("https://example.com".parse()?)
+    .make_concealable("/name")?
+    .finish(&TestSigner, "HS256")
+    .await?;
+
+  let validator = SdJwtVcValidator::new(TestJwsVerifier, JwtVcIssuerResolver::new(test_resolver()));
+  let claims = validator
+    .validate(
+      &sd_jwt_credential,
+      &Sha256Hasher::new(),
+      &SdJwtVcValidationOptions::new(),
+    )
+    .await?;
+  assert_eq!(claims.get("name"), Some(&json!("John Doe")));
+
+  // The issuer's keys are now cached.
+  assert!(validator
+    .issuer_resolver()
+    .issuer_jwks(&"https://example.com".parse()?)
+    .await
+    .is_ok());
+
+  let after_expiration = SdJwtVcValidationOptions::new().at(expires_at.checked_add(Duration::seconds(1)).unwrap());
+  let error = validator
+    .validate(&sd_jwt_credential, &Sha256Hasher::new(), &after_expiration)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, Error::Validation(_)));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn issuer_metadata_of_non_https_issuers_are_rejected() -> anyhow::Result<()> {
+  let resolver = JwtVcIssuerResolver::new(test_resolver());
+  let error = resolver
+    .issuer_metadata(&"http://example.com".parse()?)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, Error::InvalidIssuerMetadata(_)));
+
+  Ok(())
+}
+
+/// A transport counting the requests it serves.
+struct CountingResolver {
+  inner: TestResolver,
+  requests: AtomicUsize,
+}
+
+impl CountingResolver {
+  fn new(inner: TestResolver) -> Self {
+    Self {
+      inner,
+      requests: AtomicUsize::new(0),
+    }
+  }
+
+  fn requests(&self) -> usize {
+    self.requests.load(Ordering::SeqCst)
+  }
+}
+
+#[async_trait]
+impl Resolver<Url, Vec<u8>> for CountingResolver {
+  async fn resolve(&self, id: &Url) -> Result<Vec<u8>, resolver::Error> {
+    self.requests.fetch_add(1, Ordering::SeqCst);
+    Resolver::<Url, Vec<u8>>::resolve(&self.inner, id).await
+  }
+}
+
+#[tokio::test]
+async fn unknown_kids_only_refresh_issuer_keys_once_per_interval() -> anyhow::Result<()> {
+  let sd_jwt_credential = SdJwtVcBuilder::new(json!({ "name": "John Doe" }))?
+    .header("kid", "unknown_key")
+    .vct("https://example.com/education_credential".parse::<Url>()?)
+    .iat(Timestamp::now_utc())
+    .iss("https://example.com".parse()?)
+    .finish(&TestSigner, "HS256")
+    .await?;
+
+  let resolver = JwtVcIssuerResolver::new(CountingResolver::new(test_resolver()));
+  for _ in 0..3 {
+    assert!(matches!(
+      resolver.issuer_jwk(&sd_jwt_credential).await,
+      Err(Error::Verification(_))
+    ));
+  }
+  assert_eq!(resolver.transport().requests(), 1);
+
+  let resolver =
+    JwtVcIssuerResolver::new(CountingResolver::new(test_resolver())).with_min_refresh_interval(Duration::seconds(0));
+  for _ in 0..3 {
+    assert!(resolver.issuer_jwk(&sd_jwt_credential).await.is_err());
+  }
+  // The first call fetches the keys, the others refresh them once each.
+  assert_eq!(resolver.transport().requests(), 5);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn issuer_keys_cache_is_bounded() -> anyhow::Result<()> {
+  let mut test_resolver = test_resolver();
+  let mut other_issuer_metadata = issuer_metadata();
+  other_issuer_metadata.issuer = "https://other.example.com".parse()?;
+  test_resolver.insert_resource(
+    IssuerMetadata::well_known_url(&other_issuer_metadata.issuer),
+    &other_issuer_metadata,
+  );
+  let issuer: Url = "https://example.com".parse()?;
+  let other_issuer = other_issuer_metadata.issuer.clone();
+
+  let resolver = JwtVcIssuerResolver::new(CountingResolver::new(test_resolver)).with_max_entries(1);
+  resolver.issuer_jwks(&issuer).await?;
+  resolver.issuer_jwks(&issuer).await?;
+  assert_eq!(resolver.transport().requests(), 1);
+
+  // Caching the keys of another issuer evicts the first one's.
+  resolver.issuer_jwks(&other_issuer).await?;
+  resolver.issuer_jwks(&issuer).await?;
+  assert_eq!(resolver.transport().requests(), 3);
+
+  Ok(())
+}
+
 fn vc_metadata() -> TypeMetadata {
   serde_json::from_str(
     r#"{
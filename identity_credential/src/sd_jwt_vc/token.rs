@@ -10,7 +10,6 @@ use super::metadata::ClaimMetadata;
 use super::metadata::IssuerMetadata;
 use super::metadata::Jwks;
 use super::metadata::TypeMetadata;
-use super::metadata::WELL_KNOWN_VC_ISSUER;
 use super::resolver::Error as ResolverErr;
 use super::Error;
 use super::Resolver;
@@ -18,7 +17,9 @@ use super::Result;
 use super::SdJwtVcPresentationBuilder;
 use crate::validator::JwtCredentialValidator as JwsUtils;
 use crate::validator::KeyBindingJwtValidationOptions;
+use crate::validator::SdJwtDisclosureStats;
 use anyhow::anyhow;
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::ToJson as _;
@@ -91,6 +92,14 @@ impl SdJwtVc {
     SdJwt::from(self).into_disclosed_object(hasher).map_err(Error::SdJwt)
   }
 
+  /// Returns statistics about this [`SdJwtVc`]'s digests and disclosures, e.g. how many claims are concealed.
+  /// ## Errors
+  /// [`Error::Disclosure`] is returned if `hasher` doesn't match the token's `_sd_alg`, or if any disclosure doesn't
+  /// match one of the token's digests.
+  pub fn disclosure_stats(&self, hasher: &dyn Hasher) -> Result<SdJwtDisclosureStats> {
+    SdJwtDisclosureStats::from_parts(&self.parsed_claims, self.disclosures(), hasher).map_err(Error::Disclosure)
+  }
+
   /// Retrieves this SD-JWT VC's issuer's metadata by querying its default location.
   /// ## Notes
   /// This method doesn't perform any validation of the retrieved [`IssuerMetadata`]
@@ -103,11 +112,7 @@ impl SdJwtVc {
     let Some(iss) = self.claims().iss.as_ref() else {
       return Ok(None);
     };
-    let metadata_url = {
-      let origin = iss.origin().ascii_serialization();
-      let path = iss.path();
-      format!("{origin}{WELL_KNOWN_VC_ISSUER}{path}").parse().unwrap()
-    };
+    let metadata_url = IssuerMetadata::well_known_url(iss);
     match resolver.resolve(&metadata_url).await {
       Err(ResolverErr::NotFound(_)) => Ok(None),
       Err(e) => Err(Error::Resolution {
@@ -237,6 +242,7 @@ impl SdJwtVc {
   ///
   /// This method checks:
   /// - JWS signature
+  /// - disclosures, which must all match one of the token's digests
   /// - credential's type
   /// - claims' disclosability
   pub async fn validate<R, V>(&self, resolver: &R, jws_verifier: &V, hasher: &dyn Hasher) -> Result<()>
@@ -251,6 +257,9 @@ impl SdJwtVc {
     let jwk = self.issuer_jwk(resolver).await?;
     self.verify_signature(jws_verifier, &jwk)?;
 
+    // Disclosures.
+    self.disclosure_stats(hasher)?;
+
     // Credential type.
     // Fetch type metadata. Skip integrity check.
     let fully_disclosed_token = self.clone().into_disclosed_object(hasher).map(Value::Object)?;
@@ -324,6 +333,9 @@ impl SdJwtVc {
       aud,
       earliest_issuance_date,
       latest_issuance_date,
+      max_age,
+      require_not_before,
+      leeway,
       ..
     } = options;
 
@@ -348,6 +360,36 @@ impl SdJwtVc {
       return Err(Error::Validation(anyhow!("this KB-JWT has been created in the future")));
     }
 
+    if let Some(max_age) = max_age {
+      if issuance_date
+        .checked_add(Duration::seconds(*max_age))
+        .is_some_and(|max_age_expiry| max_age_expiry < Timestamp::now_utc())
+      {
+        return Err(Error::Validation(anyhow!(
+          "this KB-JWT has been created more than {max_age} seconds ago"
+        )));
+      }
+    }
+
+    match kb_jwt.claims().properties.get("nbf") {
+      Some(nbf) => {
+        let not_before = nbf
+          .as_i64()
+          .and_then(|nbf| Timestamp::from_unix(nbf).ok())
+          .ok_or_else(|| Error::Validation(anyhow!("invalid `nbf` value")))?;
+        let leeway = leeway.unwrap_or(Duration::seconds(0));
+        if not_before.checked_sub(leeway).unwrap_or(not_before) > Timestamp::now_utc() {
+          return Err(Error::Validation(anyhow!("this KB-JWT is not yet valid")));
+        }
+      }
+      None if *require_not_before => {
+        return Err(Error::Validation(anyhow!(
+          "this KB-JWT is missing the required `nbf` claim"
+        )));
+      }
+      None => (),
+    }
+
     if let Some(nonce) = nonce {
       if nonce != &kb_jwt.claims().nonce {
         return Err(Error::Validation(anyhow!("invalid KB-JWT's nonce: expected {nonce}")));
@@ -0,0 +1,132 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::anyhow;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use sd_jwt::Hasher;
+use sd_jwt::JsonObject;
+use sd_jwt::RequiredKeyBinding;
+
+use super::Error;
+use super::JwtVcIssuerResolver;
+use super::Resolver;
+use super::Result;
+use super::SdJwtVc;
+use crate::validator::KeyBindingJwtValidationOptions;
+
+/// Criteria for validating an [`SdJwtVc`] through [`SdJwtVcValidator::validate`].
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
+pub struct SdJwtVcValidationOptions {
+  /// The point in time the token's validity period is checked against. Defaults to the current time.
+  pub at: Option<Timestamp>,
+  /// Tolerance for clock skew between the issuer and the validator, applied to `nbf` and `exp`.
+  pub leeway: Option<Duration>,
+  /// Options for validating the token's KB-JWT. Key binding is not checked if not set.
+  pub key_binding: Option<KeyBindingJwtValidationOptions>,
+}
+
+impl SdJwtVcValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Checks the token's validity period against `at` instead of the current time.
+  pub fn at(mut self, at: Timestamp) -> Self {
+    self.at = Some(at);
+    self
+  }
+
+  /// Tolerates a clock skew of `leeway` between the issuer and the validator.
+  pub fn leeway(mut self, leeway: Duration) -> Self {
+    self.leeway = Some(leeway);
+    self
+  }
+
+  /// Requires the token to be bound to its holder through a KB-JWT valid according to `options`.
+  pub fn key_binding(mut self, options: KeyBindingJwtValidationOptions) -> Self {
+    self.key_binding = Some(options);
+    self
+  }
+}
+
+/// A type validating [`SdJwtVc`]s whose issuer is identified by an HTTPS URL, resolving the issuer's keys through its
+/// JWT VC Issuer Metadata.
+#[derive(Debug)]
+pub struct SdJwtVcValidator<V, R> {
+  verifier: V,
+  issuer_resolver: JwtVcIssuerResolver<R>,
+}
+
+impl<V, R> SdJwtVcValidator<V, R>
+where
+  V: JwsVerifier,
+  R: Resolver<Url, Vec<u8>>,
+{
+  /// Creates a new [`SdJwtVcValidator`] that delegates cryptographic signature verification to `verifier` and the
+  /// resolution of issuers' keys to `issuer_resolver`.
+  pub fn new(verifier: V, issuer_resolver: JwtVcIssuerResolver<R>) -> Self {
+    Self {
+      verifier,
+      issuer_resolver,
+    }
+  }
+
+  /// Returns the [`JwtVcIssuerResolver`] issuers' keys are resolved with, e.g. to invalidate its cache.
+  pub fn issuer_resolver(&self) -> &JwtVcIssuerResolver<R> {
+    &self.issuer_resolver
+  }
+
+  /// Validates `sd_jwt_vc`, returning its disclosed claims.
+  ///
+  /// The following properties are validated:
+  /// 1. the token's signature, against the key of its issuer - see [`JwtVcIssuerResolver::issuer_jwk`];
+  /// 2. the token's disclosures, which must all match one of its digests;
+  /// 3. the token's validity period, i.e. its `nbf` and `exp` claims;
+  /// 4. the token's KB-JWT, against the JWK of its `cnf` claim, if `options.key_binding` is set.
+  ///
+  /// The credential's type and status are not checked.
+  pub async fn validate(
+    &self,
+    sd_jwt_vc: &SdJwtVc,
+    hasher: &dyn Hasher,
+    options: &SdJwtVcValidationOptions,
+  ) -> Result<JsonObject> {
+    let issuer_jwk = self.issuer_resolver.issuer_jwk(sd_jwt_vc).await?;
+    sd_jwt_vc.verify_signature(&self.verifier, &issuer_jwk)?;
+    sd_jwt_vc.disclosure_stats(hasher)?;
+
+    let at = options.at.unwrap_or_else(Timestamp::now_utc);
+    let leeway = options.leeway.unwrap_or(Duration::seconds(0));
+    let claims = sd_jwt_vc.claims();
+    if let Some(nbf) = claims.nbf {
+      if nbf.checked_sub(leeway).unwrap_or(nbf) > at {
+        return Err(Error::Validation(anyhow!("the token is not yet valid")));
+      }
+    }
+    if let Some(exp) = claims.exp {
+      if exp.checked_add(leeway).unwrap_or(exp) <= at {
+        return Err(Error::Validation(anyhow!("the token has expired")));
+      }
+    }
+
+    if let Some(key_binding_options) = &options.key_binding {
+      let Some(RequiredKeyBinding::Jwk(holder_jwk)) = sd_jwt_vc.required_key_bind() else {
+        return Err(Error::Validation(anyhow!("the token is not bound to a holder's JWK")));
+      };
+      let holder_jwk: Jwk = serde_json::from_value(holder_jwk.clone().into())
+        .map_err(|e| Error::Validation(anyhow!("invalid `cnf` JWK: {e}")))?;
+      if sd_jwt_vc.key_binding_jwt().is_none() {
+        return Err(Error::Validation(anyhow!("missing KB-JWT")));
+      }
+      sd_jwt_vc.validate_key_binding(&self.verifier, &holder_jwk, hasher, key_binding_options)?;
+    }
+
+    sd_jwt_vc.clone().into_disclosed_object(hasher)
+  }
+}
@@ -0,0 +1,392 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::anyhow;
+use anyhow::Context as _;
+use identity_core::common::Timestamp;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsEc;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu::encode_b64;
+use x509_cert::der::asn1::ObjectIdentifier;
+use x509_cert::der::asn1::SequenceOf;
+use x509_cert::der::asn1::UintRef;
+use x509_cert::der::oid::AssociatedOid;
+use x509_cert::der::Decode;
+use x509_cert::der::Encode;
+use x509_cert::ext::pkix::name::GeneralName;
+use x509_cert::ext::pkix::BasicConstraints;
+use x509_cert::ext::pkix::KeyUsage;
+use x509_cert::ext::pkix::SubjectAltName;
+use x509_cert::Certificate;
+
+use super::Error;
+use super::Result;
+use super::SdJwtVc;
+
+const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const ID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+/// Supported EC curves: their OID, JWK name and field size in bytes.
+const EC_CURVES: [(ObjectIdentifier, &str, usize); 4] = [
+  (ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7"), "P-256", 32),
+  (ObjectIdentifier::new_unwrap("1.3.132.0.34"), "P-384", 48),
+  (ObjectIdentifier::new_unwrap("1.3.132.0.35"), "P-521", 66),
+  (ObjectIdentifier::new_unwrap("1.3.132.0.10"), "secp256k1", 32),
+];
+const ECDSA_WITH_SHA256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+const ECDSA_WITH_SHA384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+const ECDSA_WITH_SHA512: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.4");
+
+/// A set of trusted root certificates, against which [`X5cChain`]s are validated.
+#[derive(Debug, Clone, Default)]
+pub struct X509TrustAnchors {
+  certificates: Vec<Certificate>,
+}
+
+impl X509TrustAnchors {
+  /// Creates a new, empty [`X509TrustAnchors`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Trusts the DER-encoded certificate `der`.
+  pub fn add_der(&mut self, der: &[u8]) -> Result<()> {
+    let certificate = Certificate::from_der(der).map_err(|e| Error::InvalidCertificateChain(e.into()))?;
+    self.certificates.push(certificate);
+
+    Ok(())
+  }
+
+  /// Returns the number of trusted certificates.
+  pub fn len(&self) -> usize {
+    self.certificates.len()
+  }
+
+  /// Returns whether no certificate is trusted.
+  pub fn is_empty(&self) -> bool {
+    self.certificates.is_empty()
+  }
+}
+
+/// An X.509 certificate chain, as found in a JWS `x5c` header: the certificate of the signing key comes first, and
+/// each following certificate certifies the previous one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct X5cChain(Vec<Certificate>);
+
+impl X5cChain {
+  /// Parses the value of an `x5c` header, i.e. a non-empty list of base64 - not base64url - encoded DER certificates.
+  pub fn from_x5c<S: AsRef<str>>(x5c: &[S]) -> Result<Self> {
+    if x5c.is_empty() {
+      return Err(Error::InvalidCertificateChain(anyhow!("empty chain")));
+    }
+    x5c
+      .iter()
+      .enumerate()
+      .map(|(i, certificate)| {
+        let der = BaseEncoding::decode(certificate.as_ref(), Base::Base64Pad)
+          .with_context(|| format!("certificate {i} is not base64-encoded"))?;
+        Certificate::from_der(&der).with_context(|| format!("certificate {i} is not a valid DER certificate"))
+      })
+      .collect::<anyhow::Result<Vec<_>>>()
+      .map(Self)
+      .map_err(Error::InvalidCertificateChain)
+  }
+
+  /// Returns the certificate of the signing key.
+  pub fn leaf(&self) -> &Certificate {
+    &self.0[0]
+  }
+
+  /// Returns the certificates of the chain, starting with [`Self::leaf`].
+  pub fn certificates(&self) -> &[Certificate] {
+    &self.0
+  }
+
+  /// Returns the public key certified by [`Self::leaf`].
+  ///
+  /// Only EC (P-256, P-384, P-521 and secp256k1) and Ed25519 keys are supported.
+  pub fn leaf_jwk(&self) -> Result<Jwk> {
+    public_key_jwk(self.leaf()).map_err(Error::InvalidCertificateChain)
+  }
+
+  /// Returns the subject alternative names of [`Self::leaf`] of type `uniformResourceIdentifier` and `dNSName`.
+  pub fn leaf_alt_names(&self) -> Result<(Vec<String>, Vec<String>)> {
+    let mut uris = Vec::new();
+    let mut dns_names = Vec::new();
+    if let Some(alt_names) = extension::<SubjectAltName>(self.leaf()).map_err(Error::InvalidCertificateChain)? {
+      for name in alt_names.0 {
+        match name {
+          GeneralName::UniformResourceIdentifier(uri) => uris.push(uri.to_string()),
+          GeneralName::DnsName(dns_name) => dns_names.push(dns_name.to_string()),
+          _ => (),
+        }
+      }
+    }
+
+    Ok((uris, dns_names))
+  }
+
+  /// Validates this chain against `trust_anchors` at time `at`.
+  ///
+  /// Each certificate must be valid at `at` and signed by the next one - or, for the last one, by one of
+  /// `trust_anchors` unless it is a trust anchor itself. Issuing certificates must be CA certificates allowed to sign
+  /// certificates and whose path length constraint is honored, and the leaf's key usage must allow digital signatures.
+  /// Certificates with unsupported critical extensions - including extended key usages, which aren't enforced - are
+  /// rejected. Revocation is not checked.
+  pub fn validate<V: JwsVerifier>(&self, verifier: &V, trust_anchors: &X509TrustAnchors, at: Timestamp) -> Result<()> {
+    self
+      .validate_inner(verifier, trust_anchors, at)
+      .map_err(Error::InvalidCertificateChain)
+  }
+
+  fn validate_inner<V: JwsVerifier>(
+    &self,
+    verifier: &V,
+    trust_anchors: &X509TrustAnchors,
+    at: Timestamp,
+  ) -> anyhow::Result<()> {
+    check_leaf(self.leaf()).context("invalid leaf certificate")?;
+    for (i, certificate) in self.0.iter().enumerate() {
+      check_certificate(certificate, at).with_context(|| format!("invalid certificate {i}"))?;
+      if let Some(issuer) = self.0.get(i + 1) {
+        check_issued_by(verifier, certificate, issuer, i)
+          .with_context(|| format!("certificate {i} is not certified by certificate {}", i + 1))?;
+      }
+    }
+
+    let last = self.0.last().expect("a chain is never empty");
+    if trust_anchors.certificates.contains(last) {
+      return Ok(());
+    }
+    trust_anchors
+      .certificates
+      .iter()
+      .filter(|anchor| anchor.tbs_certificate.subject == last.tbs_certificate.issuer)
+      .find(|anchor| {
+        check_certificate(anchor, at).is_ok() && check_issued_by(verifier, last, anchor, self.0.len() - 1).is_ok()
+      })
+      .map(|_| ())
+      .ok_or_else(|| anyhow!("the chain does not lead to a trust anchor"))
+  }
+}
+
+impl SdJwtVc {
+  /// Returns the certificate chain of this token's `x5c` header, if any.
+  pub fn x5c_chain(&self) -> Result<Option<X5cChain>> {
+    let Some(x5c) = self.headers().get("x5c") else {
+      return Ok(None);
+    };
+    let x5c: Vec<String> = serde_json::from_value(x5c.clone())
+      .map_err(|_| Error::InvalidCertificateChain(anyhow!("`x5c` is not an array of strings")))?;
+
+    X5cChain::from_x5c(&x5c).map(Some)
+  }
+
+  /// Verifies this token's signature against the key certified by its `x5c` certificate chain, returning the chain.
+  ///
+  /// The chain is validated against `trust_anchors` at time `at` - see [`X5cChain::validate`] - and its leaf
+  /// certificate must name the token's issuer: its `iss` claim must match either one of the certificate's
+  /// `uniformResourceIdentifier` subject alternative names, or - for HTTPS issuers - one of its `dNSName`s.
+  pub fn verify_x5c<V: JwsVerifier>(
+    &self,
+    verifier: &V,
+    trust_anchors: &X509TrustAnchors,
+    at: Timestamp,
+  ) -> Result<X5cChain> {
+    let chain = self
+      .x5c_chain()?
+      .ok_or_else(|| Error::Verification(anyhow!("missing header `x5c`")))?;
+    chain.validate(verifier, trust_anchors, at)?;
+
+    let iss = self.claims().iss.as_ref().ok_or(Error::MissingClaim("iss"))?;
+    let (uris, dns_names) = chain.leaf_alt_names()?;
+    let host = iss.host_str().filter(|_| iss.scheme() == "https");
+    let names_issuer = uris
+      .iter()
+      .any(|uri| uri.trim_end_matches('/') == iss.as_str().trim_end_matches('/'))
+      || host.is_some_and(|host| dns_names.iter().any(|dns_name| dns_name.eq_ignore_ascii_case(host)));
+    if !names_issuer {
+      return Err(Error::Verification(anyhow!(
+        "the leaf certificate is not issued to \"{iss}\""
+      )));
+    }
+
+    self.verify_signature(verifier, &chain.leaf_jwk()?)?;
+
+    Ok(chain)
+  }
+}
+
+/// Checks `certificate`'s validity period and critical extensions.
+fn check_certificate(certificate: &Certificate, at: Timestamp) -> anyhow::Result<()> {
+  let validity = &certificate.tbs_certificate.validity;
+  let not_before = validity.not_before.to_unix_duration().as_secs() as i64;
+  let not_after = validity.not_after.to_unix_duration().as_secs() as i64;
+  if at.to_unix() < not_before || at.to_unix() > not_after {
+    return Err(anyhow!("the certificate is not valid at {at}"));
+  }
+
+  let supported = [BasicConstraints::OID, KeyUsage::OID, SubjectAltName::OID];
+  let extensions = certificate.tbs_certificate.extensions.iter().flatten();
+  if let Some(unsupported) = extensions
+    .filter(|ext| ext.critical)
+    .find(|ext| !supported.contains(&ext.extn_id))
+  {
+    return Err(anyhow!("unsupported critical extension {}", unsupported.extn_id));
+  }
+
+  Ok(())
+}
+
+/// Checks that `leaf`'s key may be used to verify signatures.
+fn check_leaf(leaf: &Certificate) -> anyhow::Result<()> {
+  match extension::<KeyUsage>(leaf)? {
+    Some(usage) if usage.digital_signature() => Ok(()),
+    Some(_) => Err(anyhow!("the key usage doesn't allow digital signatures")),
+    None => Err(anyhow!("the key usage is missing")),
+  }
+}
+
+/// Checks that `issuer` is a CA that signed `certificate`, with `intermediates` CA certificates in between `issuer`
+/// and the leaf.
+fn check_issued_by<V: JwsVerifier>(
+  verifier: &V,
+  certificate: &Certificate,
+  issuer: &Certificate,
+  intermediates: usize,
+) -> anyhow::Result<()> {
+  if certificate.tbs_certificate.issuer != issuer.tbs_certificate.subject {
+    return Err(anyhow!("issuer name mismatch"));
+  }
+  match extension::<BasicConstraints>(issuer)? {
+    Some(constraints) if constraints.ca => {
+      if constraints
+        .path_len_constraint
+        .is_some_and(|max| intermediates > usize::from(max))
+      {
+        return Err(anyhow!("the issuer's path length constraint is exceeded"));
+      }
+    }
+    _ => return Err(anyhow!("the issuer is not a CA")),
+  }
+  if extension::<KeyUsage>(issuer)?.is_some_and(|usage| !usage.key_cert_sign()) {
+    return Err(anyhow!("the issuer's key cannot sign certificates"));
+  }
+
+  let public_key = public_key_jwk(issuer)?;
+  let input = VerificationInput {
+    alg: signature_algorithm(certificate, &public_key)?,
+    signing_input: certificate.tbs_certificate.to_der()?.into(),
+    decoded_signature: jws_signature(certificate, &public_key)?.into(),
+  };
+  verifier
+    .verify(input, &public_key)
+    .map_err(|e| anyhow!("invalid signature: {e}"))
+}
+
+/// Returns the extension `T` of `certificate`, if present.
+fn extension<T>(certificate: &Certificate) -> anyhow::Result<Option<T>>
+where
+  T: AssociatedOid + for<'a> Decode<'a>,
+{
+  certificate
+    .tbs_certificate
+    .extensions
+    .iter()
+    .flatten()
+    .find(|ext| ext.extn_id == T::OID)
+    .map(|ext| T::from_der(ext.extn_value.as_bytes()))
+    .transpose()
+    .with_context(|| format!("invalid extension {}", T::OID))
+}
+
+/// Returns the public key of `certificate` as a JWK.
+fn public_key_jwk(certificate: &Certificate) -> anyhow::Result<Jwk> {
+  let spki = &certificate.tbs_certificate.subject_public_key_info;
+  let key = spki
+    .subject_public_key
+    .as_bytes()
+    .ok_or_else(|| anyhow!("invalid public key encoding"))?;
+
+  let algorithm = spki.algorithm.oid;
+  if algorithm == ID_EC_PUBLIC_KEY {
+    let curve = spki
+      .algorithm
+      .parameters
+      .as_ref()
+      .and_then(|parameters| parameters.decode_as::<ObjectIdentifier>().ok())
+      .ok_or_else(|| anyhow!("missing EC curve"))?;
+    let (_, crv, size) = EC_CURVES
+      .into_iter()
+      .find(|(oid, _, _)| *oid == curve)
+      .ok_or_else(|| anyhow!("unsupported EC curve {curve}"))?;
+    let Some((0x04, coordinates)) = key.split_first() else {
+      return Err(anyhow!("only uncompressed EC points are supported"));
+    };
+    if coordinates.len() != 2 * size {
+      return Err(anyhow!("invalid {crv} public key"));
+    }
+    let mut params = JwkParamsEc::new();
+    params.crv = crv.to_owned();
+    params.x = encode_b64(&coordinates[..size]);
+    params.y = encode_b64(&coordinates[size..]);
+
+    Ok(Jwk::from_params(params))
+  } else if algorithm == ID_ED25519 {
+    let mut params = JwkParamsOkp::new();
+    params.crv = "Ed25519".to_owned();
+    params.x = encode_b64(key);
+
+    Ok(Jwk::from_params(params))
+  } else {
+    Err(anyhow!("unsupported public key algorithm {algorithm}"))
+  }
+}
+
+/// Returns the JWS algorithm equivalent to `certificate`'s signature algorithm, for an issuer key `public_key`.
+fn signature_algorithm(certificate: &Certificate, public_key: &Jwk) -> anyhow::Result<JwsAlgorithm> {
+  let algorithm = certificate.signature_algorithm.oid;
+  let crv = public_key.try_ec_params().map(|params| params.crv.as_str()).ok();
+  match crv {
+    Some("secp256k1") if algorithm == ECDSA_WITH_SHA256 => Ok(JwsAlgorithm::ES256K),
+    Some("P-256") if algorithm == ECDSA_WITH_SHA256 => Ok(JwsAlgorithm::ES256),
+    Some("P-384") if algorithm == ECDSA_WITH_SHA384 => Ok(JwsAlgorithm::ES384),
+    Some("P-521") if algorithm == ECDSA_WITH_SHA512 => Ok(JwsAlgorithm::ES512),
+    None if algorithm == ID_ED25519 => Ok(JwsAlgorithm::EdDSA),
+    _ => Err(anyhow!(
+      "unsupported signature algorithm {algorithm} for the issuer's key"
+    )),
+  }
+}
+
+/// Returns `certificate`'s signature in its JWS encoding, i.e. turning DER-encoded ECDSA signatures into `r || s`.
+fn jws_signature(certificate: &Certificate, public_key: &Jwk) -> anyhow::Result<Vec<u8>> {
+  let signature = certificate
+    .signature
+    .as_bytes()
+    .ok_or_else(|| anyhow!("invalid signature encoding"))?;
+  let Ok(params) = public_key.try_ec_params() else {
+    return Ok(signature.to_vec());
+  };
+
+  let (_, _, size) = EC_CURVES
+    .into_iter()
+    .find(|(_, crv, _)| *crv == params.crv)
+    .ok_or_else(|| anyhow!("unsupported EC curve {}", params.crv))?;
+  let integers = SequenceOf::<UintRef<'_>, 2>::from_der(signature)?;
+  let mut jws_signature = vec![0; 2 * size];
+  for (i, integer) in integers.iter().enumerate() {
+    let bytes = integer.as_bytes();
+    if bytes.len() > size {
+      return Err(anyhow!("invalid ECDSA signature"));
+    }
+    jws_signature[(i + 1) * size - bytes.len()..(i + 1) * size].copy_from_slice(bytes);
+  }
+
+  Ok(jws_signature)
+}
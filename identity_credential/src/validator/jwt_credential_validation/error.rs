@@ -46,6 +46,12 @@ pub enum JwtValidationError {
   /// Indicates that the issuance date of the credential or presentation is not considered valid.
   #[error("issuance date is in the future or later than required")]
   IssuanceDate,
+  /// Indicates that the presentation was issued longer ago than the maximum age allowed by the validation options.
+  #[error("the presentation is older than the maximum age of {0} seconds")]
+  MaxAgeExceeded(u32),
+  /// Indicates that a claim required by the validation options is missing.
+  #[error("missing required `{0}` claim")]
+  MissingClaim(&'static str),
   /// Indicates that the credential's (resp. presentation's) signature could not be verified using
   /// the issuer's (resp. holder's) DID Document.
   #[error("could not verify the {signer_ctx}'s signature; {source}")]
@@ -76,6 +82,17 @@ pub enum JwtValidationError {
   #[non_exhaustive]
   DocumentMismatch(SignerContext),
 
+  /// Indicates that a header parameter of the JWS differs from the one required by its profile, see
+  /// [`JwtHeaderProfile`](crate::validator::JwtHeaderProfile).
+  #[error("the `{parameter}` header parameter is {found:?}, but `{expected}` is required")]
+  HeaderProfileMismatch {
+    /// The name of the header parameter.
+    parameter: &'static str,
+    /// The required value.
+    expected: String,
+    /// The actual value, if any.
+    found: Option<String>,
+  },
   /// Indicates that the structure of the [Credential](crate::credential::Credential) is not semantically
   /// correct.
   #[error("the credential's structure is not semantically correct")]
@@ -158,3 +175,37 @@ impl Display for CompoundCredentialValidationError {
 }
 
 impl std::error::Error for CompoundCredentialValidationError {}
+
+/// An error caused by a failure to validate a link of a credential chain.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum CredentialChainLinkError {
+  /// Indicates that the credential could not be decoded.
+  #[error("could not decode credential")]
+  Decoding(#[source] JwtValidationError),
+  /// Indicates that the DID Document of the credential's issuer was not provided.
+  #[error("missing DID document of issuer {0}")]
+  MissingIssuerDocument(identity_did::CoreDID),
+  /// Indicates that the first credential of the chain is not issued by a trusted root issuer.
+  #[error("credential is issued by untrusted root {0}")]
+  UntrustedRoot(identity_did::CoreDID),
+  /// Indicates that the credential is not issued by the subject of the previous credential in the chain.
+  #[error("credential is issued by {found} instead of {expected}, the subject of the previous credential")]
+  BrokenLink {
+    /// The subject of the previous credential.
+    expected: identity_did::CoreDID,
+    /// The issuer of the credential.
+    found: identity_did::CoreDID,
+  },
+  /// Indicates that the subject of a credential that is followed by another one does not identify an issuer,
+  /// i.e. the credential does not have exactly one subject whose `id` is a DID.
+  #[error("credential subject does not identify an issuer")]
+  InvalidSubject,
+  /// Indicates that the previous credential in the chain could not be validated, hence the issuer of this credential
+  /// is not vouched for.
+  #[error("the previous credential in the chain is invalid")]
+  InvalidPredecessor,
+  /// Indicates that the credential could not be validated.
+  #[error("invalid credential")]
+  InvalidCredential(#[source] CompoundCredentialValidationError),
+}
@@ -0,0 +1,392 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::verifiable::JwsVerificationOptions;
+#[cfg(feature = "x509")]
+use identity_verification::jws::JwsHeader;
+use identity_verification::jws::JwsValidationItem;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::JwtValidationError;
+use super::SignerContext;
+use crate::credential::Issuer;
+#[cfg(feature = "x509")]
+use crate::sd_jwt_vc::X5cChain;
+
+/// A place in a JWT credential hinting at the DID of its issuer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum IssuerHint {
+  /// The `kid` parameter of the protected header, expressed as an absolute DID URL.
+  ///
+  /// [`JwsVerificationOptions::method_id`], when set, takes the place of `kid`.
+  Kid,
+  /// The `iss` claim, or the `issuer` property for credentials that are not encoded as JWT claims.
+  Iss,
+  /// The DID listed among the `uniformResourceIdentifier` subject alternative names of the first certificate of
+  /// the `x5c` header parameter.
+  ///
+  /// The certificate chain is not validated: like the other hints, the certificate only locates the issuer, whose
+  /// DID Document the signature is verified against. See `SdJwtVc::verify_x5c` to trust a certificate chain instead.
+  #[cfg(feature = "x509")]
+  X5c,
+}
+
+/// How strictly the [`IssuerHint`]s of a JWT credential are checked when locating its issuer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum IssuerConsistency {
+  /// Every hint listed in [`IssuerLocationOptions::precedence`] must be present.
+  #[default]
+  Strict,
+  /// Hints listed in [`IssuerLocationOptions::precedence`] may be missing, as long as at least one is present.
+  Lenient,
+}
+
+/// Declares how the issuer of a JWT credential - and the verification method used to sign it - is located.
+///
+/// The issuer's DID is taken from the first hint in [`IssuerLocationOptions::precedence`] that is present.
+/// Hints never contradict each other: all present hints must agree, and the verification method referenced
+/// by `kid` must belong to the issuer. A `kid` that is a relative DID URL (e.g. `#key-1`) is resolved against
+/// the located issuer's DID.
+///
+/// The default mirrors the historical behavior: `kid` must be an absolute DID URL identifying the issuer.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerLocationOptions {
+  /// The hints consulted to locate the issuer, in order of precedence.
+  ///
+  /// Default: `[IssuerHint::Kid]`.
+  pub precedence: Vec<IssuerHint>,
+  /// Strictness of the checks carried out on the hints.
+  ///
+  /// Default: [`IssuerConsistency::Strict`].
+  #[serde(default)]
+  pub consistency: IssuerConsistency,
+}
+
+impl Default for IssuerLocationOptions {
+  fn default() -> Self {
+    Self {
+      precedence: vec![IssuerHint::Kid],
+      consistency: IssuerConsistency::default(),
+    }
+  }
+}
+
+impl IssuerLocationOptions {
+  /// Creates a new [`IssuerLocationOptions`] consulting the given hints, in order of precedence.
+  pub fn new(precedence: impl IntoIterator<Item = IssuerHint>) -> Self {
+    Self {
+      precedence: precedence.into_iter().collect(),
+      consistency: IssuerConsistency::default(),
+    }
+  }
+
+  /// Sets the strictness of the checks carried out on the hints.
+  pub fn consistency(mut self, consistency: IssuerConsistency) -> Self {
+    self.consistency = consistency;
+    self
+  }
+
+  /// Returns the DID URL of the verification method that signed `jws`, after checking that it belongs to the
+  /// issuer located through the configured hints.
+  pub(crate) fn locate_method(
+    &self,
+    jws: &JwsValidationItem<'_>,
+    options: &JwsVerificationOptions,
+  ) -> Result<DIDUrl, JwtValidationError> {
+    let kid: Option<Kid> = match &options.method_id {
+      Some(method_id) => Some(Kid::Absolute(method_id.clone())),
+      None => jws
+        .protected_header()
+        .and_then(|header| header.kid())
+        .map(Kid::parse)
+        .transpose()?,
+    };
+
+    let mut issuer: Option<CoreDID> = None;
+    for hint in &self.precedence {
+      let hinted_did: Option<CoreDID> = match hint {
+        IssuerHint::Kid => kid.as_ref().and_then(Kid::did).cloned(),
+        IssuerHint::Iss => iss_claim(jws.claims())?,
+        #[cfg(feature = "x509")]
+        IssuerHint::X5c => x5c_did(jws.protected_header())?,
+      };
+
+      let Some(hinted_did) = hinted_did else {
+        if self.consistency == IssuerConsistency::Strict {
+          return Err(missing_hint(*hint, kid.is_some()));
+        }
+        continue;
+      };
+      match &issuer {
+        Some(issuer) if issuer != &hinted_did => {
+          return Err(JwtValidationError::IdentifierMismatch {
+            signer_ctx: SignerContext::Issuer,
+          });
+        }
+        Some(_) => (),
+        None => issuer = Some(hinted_did),
+      }
+    }
+
+    let method_id: DIDUrl = match (kid, issuer) {
+      (Some(Kid::Absolute(method_id)), Some(issuer)) if method_id.did() != &issuer => {
+        return Err(JwtValidationError::IdentifierMismatch {
+          signer_ctx: SignerContext::Issuer,
+        });
+      }
+      (Some(Kid::Absolute(method_id)), _) => method_id,
+      (Some(Kid::Relative(kid)), Some(issuer)) => {
+        issuer
+          .to_url()
+          .join(&kid)
+          .map_err(|err| JwtValidationError::MethodDataLookupError {
+            source: Some(err.into()),
+            message: "could not resolve kid against the issuer's DID",
+            signer_ctx: SignerContext::Issuer,
+          })?
+      }
+      (Some(Kid::Relative(_)), None) => {
+        return Err(JwtValidationError::MethodDataLookupError {
+          source: None,
+          message: "could not locate the issuer a relative kid refers to",
+          signer_ctx: SignerContext::Issuer,
+        });
+      }
+      (None, _) => return Err(missing_hint(IssuerHint::Kid, false)),
+    };
+
+    Ok(method_id)
+  }
+}
+
+/// The `kid` of a JWS, either an absolute or a relative DID URL.
+enum Kid {
+  Absolute(DIDUrl),
+  Relative(String),
+}
+
+impl Kid {
+  fn parse(kid: &str) -> Result<Self, JwtValidationError> {
+    match DIDUrl::parse(kid) {
+      Ok(method_id) => Ok(Self::Absolute(method_id)),
+      Err(_) if kid.starts_with(['#', '/', '?']) => Ok(Self::Relative(kid.to_owned())),
+      Err(err) => Err(JwtValidationError::MethodDataLookupError {
+        source: Some(err.into()),
+        message: "could not parse kid as a DID Url",
+        signer_ctx: SignerContext::Issuer,
+      }),
+    }
+  }
+
+  fn did(&self) -> Option<&CoreDID> {
+    match self {
+      Self::Absolute(method_id) => Some(method_id.did()),
+      Self::Relative(_) => None,
+    }
+  }
+}
+
+fn missing_hint(hint: IssuerHint, kid_present: bool) -> JwtValidationError {
+  let message: &'static str = match hint {
+    IssuerHint::Kid if kid_present => "kid is not an absolute DID Url",
+    IssuerHint::Kid => "could not extract kid from protected header",
+    IssuerHint::Iss => "could not extract the issuer from the claims",
+    #[cfg(feature = "x509")]
+    IssuerHint::X5c => "could not extract the issuer from the x5c certificate",
+  };
+
+  JwtValidationError::MethodDataLookupError {
+    source: None,
+    message,
+    signer_ctx: SignerContext::Issuer,
+  }
+}
+
+/// Extracts the issuer's DID from the (not yet verified) claims of a JWS.
+fn iss_claim(claims: &[u8]) -> Result<Option<CoreDID>, JwtValidationError> {
+  #[derive(Deserialize)]
+  struct IssuerClaims {
+    iss: Option<String>,
+    issuer: Option<Issuer>,
+  }
+
+  let Ok(IssuerClaims { iss, issuer }) = serde_json::from_slice::<IssuerClaims>(claims) else {
+    return Ok(None);
+  };
+  let Some(iss) = iss.or_else(|| issuer.map(|issuer| issuer.url().to_string())) else {
+    return Ok(None);
+  };
+
+  CoreDID::parse(iss)
+    .map(Some)
+    .map_err(|err| JwtValidationError::SignerUrl {
+      signer_ctx: SignerContext::Issuer,
+      source: err.into(),
+    })
+}
+
+/// Extracts the issuer's DID from the subject alternative names of the first certificate of the (not yet verified)
+/// `x5c` header parameter.
+#[cfg(feature = "x509")]
+fn x5c_did(header: Option<&JwsHeader>) -> Result<Option<CoreDID>, JwtValidationError> {
+  let invalid_x5c =
+    |source: Option<Box<dyn std::error::Error + Send + Sync>>, message| JwtValidationError::MethodDataLookupError {
+      source,
+      message,
+      signer_ctx: SignerContext::Issuer,
+    };
+
+  let Some(x5c) = header.and_then(|header| header.x5c()) else {
+    return Ok(None);
+  };
+  let (uris, _) = X5cChain::from_x5c(x5c)
+    .and_then(|chain| chain.leaf_alt_names())
+    .map_err(|err| invalid_x5c(Some(err.into()), "could not parse the x5c certificate"))?;
+
+  let mut dids = uris.iter().filter_map(|uri| CoreDID::parse(uri).ok());
+  match (dids.next(), dids.next()) {
+    (Some(_), Some(_)) => Err(invalid_x5c(None, "the x5c certificate names more than one DID")),
+    (did, _) => Ok(did),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::convert::ToJson;
+  use identity_verification::jws::Decoder;
+  use identity_verification::jws::JwsAlgorithm;
+  use identity_verification::jws::JwsHeader;
+  use identity_verification::jwu::encode_b64;
+
+  use super::*;
+
+  const ISSUER: &str = "did:example:issuer";
+
+  /// Returns an unsigned JWS, sufficient for locating the issuer.
+  fn jws(kid: Option<&str>, iss: Option<&str>, x5c: Option<&str>) -> String {
+    let mut header = JwsHeader::new();
+    header.set_alg(JwsAlgorithm::EdDSA);
+    if let Some(kid) = kid {
+      header.set_kid(kid);
+    }
+    if let Some(x5c) = x5c {
+      header.set_x5c([x5c]);
+    }
+    let mut claims = Object::new();
+    if let Some(iss) = iss {
+      claims.insert("iss".to_owned(), iss.into());
+    }
+
+    format!(
+      "{}.{}.{}",
+      encode_b64(header.to_json().unwrap()),
+      encode_b64(claims.to_json().unwrap()),
+      encode_b64("signature"),
+    )
+  }
+
+  fn locate(
+    location: &IssuerLocationOptions,
+    kid: Option<&str>,
+    iss: Option<&str>,
+  ) -> Result<DIDUrl, JwtValidationError> {
+    locate_with_x5c(location, kid, iss, None)
+  }
+
+  fn locate_with_x5c(
+    location: &IssuerLocationOptions,
+    kid: Option<&str>,
+    iss: Option<&str>,
+    x5c: Option<&str>,
+  ) -> Result<DIDUrl, JwtValidationError> {
+    let jws = jws(kid, iss, x5c);
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .unwrap();
+    location.locate_method(&decoded, &JwsVerificationOptions::default())
+  }
+
+  #[test]
+  fn default_requires_absolute_kid() {
+    let location = IssuerLocationOptions::default();
+    let kid = format!("{ISSUER}#key-1");
+
+    assert_eq!(locate(&location, Some(&kid), None).unwrap().to_string(), kid);
+    assert!(matches!(
+      locate(&location, Some("#key-1"), Some(ISSUER)).unwrap_err(),
+      JwtValidationError::MethodDataLookupError { .. }
+    ));
+    assert!(matches!(
+      locate(&location, None, Some(ISSUER)).unwrap_err(),
+      JwtValidationError::MethodDataLookupError { .. }
+    ));
+  }
+
+  #[test]
+  fn relative_kid_is_resolved_against_iss() {
+    let location =
+      IssuerLocationOptions::new([IssuerHint::Iss, IssuerHint::Kid]).consistency(IssuerConsistency::Lenient);
+
+    assert_eq!(
+      locate(&location, Some("#key-1"), Some(ISSUER)).unwrap().to_string(),
+      format!("{ISSUER}#key-1")
+    );
+    assert!(locate(&location, Some("#key-1"), None).is_err());
+
+    let strict = IssuerLocationOptions::new([IssuerHint::Iss, IssuerHint::Kid]);
+    assert!(locate(&strict, Some("#key-1"), Some(ISSUER)).is_err());
+    assert!(locate(&strict, Some(&format!("{ISSUER}#key-1")), Some(ISSUER)).is_ok());
+  }
+
+  #[test]
+  fn conflicting_hints_are_rejected() {
+    let kid = "did:example:other#key-1";
+    for location in [
+      IssuerLocationOptions::new([IssuerHint::Kid, IssuerHint::Iss]),
+      IssuerLocationOptions::new([IssuerHint::Iss]).consistency(IssuerConsistency::Lenient),
+    ] {
+      assert!(matches!(
+        locate(&location, Some(kid), Some(ISSUER)).unwrap_err(),
+        JwtValidationError::IdentifierMismatch { .. }
+      ));
+    }
+  }
+
+  #[cfg(feature = "x509")]
+  #[test]
+  fn relative_kid_is_resolved_against_x5c() {
+    // Self-signed Ed25519 certificates whose subject alternative names are "https://issuer.example.com" and,
+    // for the first one only, "did:example:issuer".
+    const CERT_WITH_DID: &str = "MIIBFzCByqADAgECAgEBMAUGAytlcDAWMRQwEgYDVQQDDAtUZXN0IElzc3VlcjAeFw0yNTAxMDEwMDAwMDBaFw00NTAxMDEwMDAwMDBaMBYxFDASBgNVBAMMC1Rlc3QgSXNzdWVyMCowBQYDK2VwAyEAA6EHv/POEL4dcN0Y50vAmWfk1jCbpQ1fHdyGZBJVMbijPTA7MDkGA1UdEQQyMDCGGmh0dHBzOi8vaXNzdWVyLmV4YW1wbGUuY29thhJkaWQ6ZXhhbXBsZTppc3N1ZXIwBQYDK2VwA0EAYgppn91SXp0XpXmsbVnTYa6Rf3BGyh/zIPbwcrYLgtcKhgWUNuy2vpZ7HylHyAS9rruz3zWNntNNubvpQ/swAA==";
+    const CERT_WITHOUT_DID: &str = "MIIBAzCBtqADAgECAgEBMAUGAytlcDAWMRQwEgYDVQQDDAtUZXN0IElzc3VlcjAeFw0yNTAxMDEwMDAwMDBaFw00NTAxMDEwMDAwMDBaMBYxFDASBgNVBAMMC1Rlc3QgSXNzdWVyMCowBQYDK2VwAyEAA6EHv/POEL4dcN0Y50vAmWfk1jCbpQ1fHdyGZBJVMbijKTAnMCUGA1UdEQQeMByGGmh0dHBzOi8vaXNzdWVyLmV4YW1wbGUuY29tMAUGAytlcANBANwNcHLAcdoDPqEunX7TuU9lkOWOEeSdx1JTaRVRVEzV9Vlcdp1bf033FvgFdhvGdbPdrkcoFVVdO5AKaX0WzgE=";
+    let location =
+      IssuerLocationOptions::new([IssuerHint::X5c, IssuerHint::Kid]).consistency(IssuerConsistency::Lenient);
+
+    assert_eq!(
+      locate_with_x5c(&location, Some("#key-1"), None, Some(CERT_WITH_DID))
+        .unwrap()
+        .to_string(),
+      format!("{ISSUER}#key-1")
+    );
+    assert!(locate_with_x5c(&location, Some("#key-1"), None, Some(CERT_WITHOUT_DID)).is_err());
+    assert!(matches!(
+      locate_with_x5c(&location, Some("did:example:other#key-1"), None, Some(CERT_WITH_DID)).unwrap_err(),
+      JwtValidationError::IdentifierMismatch { .. }
+    ));
+    assert!(matches!(
+      locate_with_x5c(&location, Some("#key-1"), None, Some("not a certificate")).unwrap_err(),
+      JwtValidationError::MethodDataLookupError { .. }
+    ));
+  }
+}
@@ -0,0 +1,334 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+
+use super::CompoundCredentialValidationError;
+use super::DecodedJwtCredential;
+use super::JwtCredentialValidationOptions;
+use super::JwtCredentialValidator;
+use super::JwtCredentialValidatorUtils;
+use super::JwtValidationError;
+use super::SignerContext;
+use crate::credential::Jwt;
+use crate::validator::FailFast;
+
+/// Declares when [`JwtCredentialValidator::validate_batch`] stops validating credentials.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BatchPolicy {
+  /// Stop as soon as a credential is found to be invalid, skipping the credentials whose validation has not
+  /// started yet. Each invalid credential only reports its first error.
+  StopOnFirstFailure,
+  /// Validate every credential and report all errors of each invalid one.
+  #[default]
+  FullReport,
+}
+
+impl BatchPolicy {
+  fn fail_fast(self) -> FailFast {
+    match self {
+      Self::StopOnFirstFailure => FailFast::FirstError,
+      Self::FullReport => FailFast::AllErrors,
+    }
+  }
+}
+
+/// The outcome of the validation of a single credential of a batch.
+#[derive(Debug)]
+pub enum BatchValidationOutcome<T> {
+  /// The credential is valid.
+  Valid(DecodedJwtCredential<T>),
+  /// The credential is invalid.
+  Invalid(CompoundCredentialValidationError),
+  /// The credential was not validated because an earlier failure stopped the batch, see
+  /// [`BatchPolicy::StopOnFirstFailure`].
+  Skipped,
+}
+
+impl<T> BatchValidationOutcome<T> {
+  /// Returns whether the credential is valid.
+  pub fn is_valid(&self) -> bool {
+    matches!(self, Self::Valid(_))
+  }
+}
+
+/// The outcome of the validation of a batch of credentials, see [`JwtCredentialValidator::validate_batch`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct BatchValidation<T> {
+  /// The outcome of each credential, in the order they were given.
+  pub outcomes: Vec<BatchValidationOutcome<T>>,
+}
+
+impl<T> BatchValidation<T> {
+  /// Returns whether all credentials of the batch are valid.
+  pub fn is_valid(&self) -> bool {
+    self.outcomes.iter().all(BatchValidationOutcome::is_valid)
+  }
+
+  /// Returns the invalid credentials, together with their position in the batch.
+  pub fn failures(&self) -> impl Iterator<Item = (usize, &CompoundCredentialValidationError)> {
+    self
+      .outcomes
+      .iter()
+      .enumerate()
+      .filter_map(|(index, outcome)| match outcome {
+        BatchValidationOutcome::Invalid(error) => Some((index, error)),
+        _ => None,
+      })
+  }
+
+  /// Returns the number of credentials that were not validated.
+  pub fn skipped(&self) -> usize {
+    self
+      .outcomes
+      .iter()
+      .filter(|outcome| matches!(outcome, BatchValidationOutcome::Skipped))
+      .count()
+  }
+
+  /// Returns the validated credentials in the order they were given, or the errors of all invalid credentials
+  /// together with their position.
+  ///
+  /// Skipped credentials are left out of both.
+  pub fn into_result(self) -> Result<Vec<DecodedJwtCredential<T>>, Vec<(usize, CompoundCredentialValidationError)>> {
+    let mut credentials: Vec<DecodedJwtCredential<T>> = Vec::with_capacity(self.outcomes.len());
+    let mut errors: Vec<(usize, CompoundCredentialValidationError)> = Vec::new();
+    for (index, outcome) in self.outcomes.into_iter().enumerate() {
+      match outcome {
+        BatchValidationOutcome::Valid(credential) => credentials.push(credential),
+        BatchValidationOutcome::Invalid(error) => errors.push((index, error)),
+        BatchValidationOutcome::Skipped => (),
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(credentials)
+    } else {
+      Err(errors)
+    }
+  }
+}
+
+impl<V: JwsVerifier + Sync> JwtCredentialValidator<V> {
+  /// Decodes and validates a batch of [`Credential`](crate::credential::Credential)s issued as JWTs, as
+  /// [`JwtCredentialValidator::validate`] does for a single one.
+  ///
+  /// * `credential_jwts`: the credentials to validate.
+  /// * `trusted_issuers`: DID Documents of the issuers trusted to issue the credentials. Each issuer is looked up once,
+  ///   however many credentials it issued, and only the first document of an issuer listed more than once is used.
+  /// * `options`: validation options applied to every credential.
+  /// * `policy`: whether to stop at the first invalid credential or to report the outcome of every credential.
+  ///
+  /// Credentials are verified concurrently, except on `wasm32` targets.
+  ///
+  /// # Warning
+  /// The same additional checks described for [`JwtCredentialValidator::validate`] must be carried out on every
+  /// valid credential.
+  pub fn validate_batch<DOC, T>(
+    &self,
+    credential_jwts: &[Jwt],
+    trusted_issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    policy: BatchPolicy,
+  ) -> BatchValidation<T>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned + Send,
+    DOC: AsRef<CoreDocument> + Sync,
+  {
+    let mut issuers: HashMap<&CoreDID, &CoreDocument> = HashMap::with_capacity(trusted_issuers.len());
+    for document in trusted_issuers {
+      issuers.entry(document.as_ref().id()).or_insert(document.as_ref());
+    }
+
+    let fail_fast: FailFast = policy.fail_fast();
+    let stopped = AtomicBool::new(false);
+    let outcomes: Vec<BatchValidationOutcome<T>> = map_concurrently(credential_jwts, |credential| {
+      if stopped.load(Ordering::Relaxed) {
+        return BatchValidationOutcome::Skipped;
+      }
+
+      match self.validate_with_issuers(credential, &issuers, options, fail_fast) {
+        Ok(decoded) => BatchValidationOutcome::Valid(decoded),
+        Err(error) => {
+          if policy == BatchPolicy::StopOnFirstFailure {
+            stopped.store(true, Ordering::Relaxed);
+          }
+          BatchValidationOutcome::Invalid(error)
+        }
+      }
+    });
+
+    BatchValidation { outcomes }
+  }
+
+  fn validate_with_issuers<T>(
+    &self,
+    credential: &Jwt,
+    issuers: &HashMap<&CoreDID, &CoreDocument>,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential).map_err(|err| {
+      CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      }
+    })?;
+    let document: &CoreDocument = issuers.get(&issuer).ok_or_else(|| CompoundCredentialValidationError {
+      validation_errors: [JwtValidationError::DocumentMismatch(SignerContext::Issuer)].into(),
+    })?;
+
+    self.validate(credential, document, options, fail_fast)
+  }
+}
+
+/// Applies `f` to every item, spreading the work over the available cores, and returns the outputs in the order of
+/// the items.
+#[cfg(not(target_arch = "wasm32"))]
+fn map_concurrently<I, O, F>(items: &[I], f: F) -> Vec<O>
+where
+  I: Sync,
+  O: Send,
+  F: Fn(&I) -> O + Sync,
+{
+  use std::sync::atomic::AtomicUsize;
+
+  let workers: usize = std::thread::available_parallelism()
+    .map_or(1, std::num::NonZeroUsize::get)
+    .min(items.len());
+  if workers <= 1 {
+    return items.iter().map(f).collect();
+  }
+
+  let next = AtomicUsize::new(0);
+  let (next, f) = (&next, &f);
+  let mut outputs: Vec<(usize, O)> = std::thread::scope(|scope| {
+    let handles: Vec<_> = (0..workers)
+      .map(|_| {
+        scope.spawn(move || {
+          let mut outputs: Vec<(usize, O)> = Vec::new();
+          loop {
+            let index: usize = next.fetch_add(1, Ordering::Relaxed);
+            let Some(item) = items.get(index) else {
+              break outputs;
+            };
+            outputs.push((index, f(item)));
+          }
+        })
+      })
+      .collect();
+
+    handles
+      .into_iter()
+      .flat_map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+      .collect()
+  });
+
+  outputs.sort_unstable_by_key(|(index, _)| *index);
+  outputs.into_iter().map(|(_, output)| output).collect()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn map_concurrently<I, O, F>(items: &[I], f: F) -> Vec<O>
+where
+  F: Fn(&I) -> O,
+{
+  items.iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_did::DID;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+
+  fn issue(issuer: &(CoreDocument, SecretKey, String)) -> Jwt {
+    let (document, secret_key, fragment) = issuer;
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    sign_credential_jwt(&credential, document, fragment, secret_key)
+  }
+
+  fn validate(credentials: &[Jwt], issuers: &[&CoreDocument], policy: BatchPolicy) -> BatchValidation<Object> {
+    JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default()).validate_batch(
+      credentials,
+      issuers,
+      &JwtCredentialValidationOptions::default(),
+      policy,
+    )
+  }
+
+  #[test]
+  fn batch_of_valid_credentials() {
+    let alice = generate_jwk_document_with_keys();
+    let bob = generate_jwk_document_with_keys();
+    let credentials: Vec<Jwt> = (0..16)
+      .map(|i| if i % 2 == 0 { issue(&alice) } else { issue(&bob) })
+      .collect();
+
+    let validation = validate(&credentials, &[&alice.0, &bob.0, &alice.0], BatchPolicy::FullReport);
+    assert!(validation.is_valid());
+    let credentials = validation.into_result().unwrap();
+    assert_eq!(credentials.len(), 16);
+    assert_eq!(credentials[1].credential.issuer.url().as_str(), bob.0.id().as_str());
+  }
+
+  #[test]
+  fn full_report_reports_every_failure() {
+    let trusted = generate_jwk_document_with_keys();
+    let untrusted = generate_jwk_document_with_keys();
+    let credentials = [issue(&trusted), issue(&untrusted), issue(&trusted), issue(&untrusted)];
+
+    let validation = validate(&credentials, &[&trusted.0], BatchPolicy::FullReport);
+    assert!(!validation.is_valid());
+    assert_eq!(validation.skipped(), 0);
+    let failures: Vec<usize> = validation.failures().map(|(index, _)| index).collect();
+    assert_eq!(failures, [1, 3]);
+    assert!(matches!(
+      validation.failures().next().unwrap().1.validation_errors.as_slice(),
+      [JwtValidationError::DocumentMismatch(SignerContext::Issuer)]
+    ));
+  }
+
+  #[test]
+  fn stop_on_first_failure_skips_remaining_credentials() {
+    let trusted = generate_jwk_document_with_keys();
+    let untrusted = generate_jwk_document_with_keys();
+    let credentials: Vec<Jwt> = std::iter::once(issue(&untrusted))
+      .chain((0..32).map(|_| issue(&trusted)))
+      .collect();
+
+    let validation = validate(&credentials, &[&trusted.0], BatchPolicy::StopOnFirstFailure);
+    assert!(!validation.is_valid());
+    // Credentials validated concurrently with the failing one are still reported.
+    assert!(validation.outcomes[1..].iter().all(|outcome| matches!(
+      outcome,
+      BatchValidationOutcome::Valid(_) | BatchValidationOutcome::Skipped
+    )));
+    let errors = validation.into_result().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, 0);
+    assert_eq!(errors[0].1.validation_errors.len(), 1);
+  }
+}
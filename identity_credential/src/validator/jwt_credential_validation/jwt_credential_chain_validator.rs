@@ -0,0 +1,312 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+
+use super::CredentialChainLinkError;
+use super::DecodedJwtCredential;
+use super::JwtCredentialValidationOptions;
+use super::JwtCredentialValidator;
+use super::JwtCredentialValidatorUtils;
+use crate::credential::Credential;
+use crate::credential::Jwt;
+use crate::validator::FailFast;
+
+/// A validator for chains of credentials where the subject of each credential is the issuer of the next one,
+/// e.g. an organization accrediting a department that issues employee badges.
+pub struct JwtCredentialChainValidator<V: JwsVerifier> {
+  validator: JwtCredentialValidator<V>,
+}
+
+impl<V: JwsVerifier> JwtCredentialChainValidator<V> {
+  /// Create a new [`JwtCredentialChainValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self {
+      validator: JwtCredentialValidator::with_signature_verifier(signature_verifier),
+    }
+  }
+
+  /// Validates every credential of `chain`, ordered from the credential issued by the root issuer onwards.
+  ///
+  /// * `chain`: the credentials to validate, where the subject of each credential issues the next one.
+  /// * `trusted_roots`: the issuers trusted to issue the first credential of the chain.
+  /// * `issuers`: DID Documents of the issuers of all credentials in `chain`.
+  /// * `options`: validation options applied to every credential of the chain.
+  /// * `fail_fast`: whether validation of a single credential stops at its first error.
+  ///
+  /// Every link is evaluated, even after a previous one failed, and the outcome of each is reported in the returned
+  /// [`CredentialChainValidation`]. A link is valid only if all of its predecessors are.
+  pub fn validate<DOC, T>(
+    &self,
+    chain: &[Jwt],
+    trusted_roots: &[CoreDID],
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> CredentialChainValidation<T>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let mut links: Vec<CredentialChainLink<T>> = Vec::with_capacity(chain.len());
+    // The issuer expected for the next link: `None` if the previous link is invalid.
+    let mut expected_issuer: Option<CoreDID> = None;
+
+    for (index, credential) in chain.iter().enumerate() {
+      let is_last: bool = index + 1 == chain.len();
+
+      let issuer: Option<CoreDID> = JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential).ok();
+      let outcome = self.validate_link(
+        credential,
+        index,
+        expected_issuer.as_ref(),
+        trusted_roots,
+        issuers,
+        options,
+        fail_fast,
+      );
+
+      let subject: Option<CoreDID> = outcome
+        .as_ref()
+        .ok()
+        .and_then(|decoded| subject_did(&decoded.credential));
+      let outcome = match outcome {
+        Ok(_) if subject.is_none() && !is_last => Err(CredentialChainLinkError::InvalidSubject),
+        outcome => outcome,
+      };
+
+      expected_issuer = if outcome.is_ok() { subject.clone() } else { None };
+      links.push(CredentialChainLink {
+        index,
+        issuer,
+        subject,
+        outcome,
+      });
+    }
+
+    CredentialChainValidation { links }
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  fn validate_link<DOC, T>(
+    &self,
+    credential: &Jwt,
+    index: usize,
+    expected_issuer: Option<&CoreDID>,
+    trusted_roots: &[CoreDID],
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CredentialChainLinkError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let issuer: CoreDID =
+      JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential).map_err(CredentialChainLinkError::Decoding)?;
+
+    match (index, expected_issuer) {
+      (0, _) if !trusted_roots.contains(&issuer) => return Err(CredentialChainLinkError::UntrustedRoot(issuer)),
+      (0, _) => (),
+      (_, Some(expected)) if *expected != issuer => {
+        return Err(CredentialChainLinkError::BrokenLink {
+          expected: expected.clone(),
+          found: issuer,
+        })
+      }
+      (_, Some(_)) => (),
+      (_, None) => return Err(CredentialChainLinkError::InvalidPredecessor),
+    }
+
+    let document: &DOC = issuers
+      .iter()
+      .find(|document| *document.as_ref().id() == issuer)
+      .ok_or_else(|| CredentialChainLinkError::MissingIssuerDocument(issuer.clone()))?;
+
+    self
+      .validator
+      .validate(credential, document, options, fail_fast)
+      .map_err(CredentialChainLinkError::InvalidCredential)
+  }
+}
+
+/// Returns the DID identifying the only subject of `credential`, if any.
+fn subject_did<T>(credential: &Credential<T>) -> Option<CoreDID> {
+  match credential.credential_subject.as_slice() {
+    [subject] => subject.id.as_ref().and_then(|id| CoreDID::parse(id.as_str()).ok()),
+    _ => None,
+  }
+}
+
+/// The outcome of the validation of a single credential of a chain.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CredentialChainLink<T> {
+  /// Position of the credential in the chain.
+  pub index: usize,
+  /// The issuer of the credential, if it could be decoded.
+  pub issuer: Option<CoreDID>,
+  /// The DID identifying the subject of the credential, if the credential is valid and has one.
+  pub subject: Option<CoreDID>,
+  /// The validated credential, or the reason why it is invalid.
+  pub outcome: Result<DecodedJwtCredential<T>, CredentialChainLinkError>,
+}
+
+impl<T> CredentialChainLink<T> {
+  /// Returns whether the credential is valid.
+  pub fn is_valid(&self) -> bool {
+    self.outcome.is_ok()
+  }
+}
+
+/// The outcome of the validation of a credential chain, see [`JwtCredentialChainValidator::validate`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CredentialChainValidation<T> {
+  /// The outcome of each link, in the order of the chain.
+  pub links: Vec<CredentialChainLink<T>>,
+}
+
+impl<T> CredentialChainValidation<T> {
+  /// Returns whether the chain is non-empty and all of its credentials are valid.
+  pub fn is_valid(&self) -> bool {
+    !self.links.is_empty() && self.links.iter().all(CredentialChainLink::is_valid)
+  }
+
+  /// Returns the first link that failed validation, if any.
+  pub fn first_failure(&self) -> Option<&CredentialChainLink<T>> {
+    self.links.iter().find(|link| !link.is_valid())
+  }
+
+  /// Returns the last credential of the chain - e.g. the employee badge - if the whole chain is valid.
+  pub fn leaf(&self) -> Option<&DecodedJwtCredential<T>> {
+    if !self.is_valid() {
+      return None;
+    }
+    self.links.last().and_then(|link| link.outcome.as_ref().ok())
+  }
+
+  /// Returns the validated credentials in the order of the chain, or the errors of all invalid links together with
+  /// their position.
+  pub fn into_result(self) -> Result<Vec<DecodedJwtCredential<T>>, Vec<(usize, CredentialChainLinkError)>> {
+    let mut credentials: Vec<DecodedJwtCredential<T>> = Vec::with_capacity(self.links.len());
+    let mut errors: Vec<(usize, CredentialChainLinkError)> = Vec::new();
+    for link in self.links {
+      match link.outcome {
+        Ok(credential) => credentials.push(credential),
+        Err(error) => errors.push((link.index, error)),
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(credentials)
+    } else {
+      Err(errors)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Object;
+  use identity_core::common::Url;
+  use identity_did::DID;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+  use crate::validator::test_utils::sign_credential_jwt;
+
+  fn issue(issuer: &(CoreDocument, SecretKey, String), subject: &str) -> Jwt {
+    let (document, secret_key, fragment) = issuer;
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(document.id().as_str()).unwrap())
+      .subject(Subject::with_id(Url::parse(subject).unwrap()))
+      .build()
+      .unwrap();
+    sign_credential_jwt(&credential, document, fragment, secret_key)
+  }
+
+  fn validate(chain: &[Jwt], root: &CoreDocument, issuers: &[&CoreDocument]) -> CredentialChainValidation<Object> {
+    JwtCredentialChainValidator::with_signature_verifier(EdDSAJwsVerifier::default()).validate(
+      chain,
+      &[root.id().clone()],
+      issuers,
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+  }
+
+  #[test]
+  fn test_valid_chain() {
+    let organization = generate_jwk_document_with_keys();
+    let department = generate_jwk_document_with_keys();
+    let chain = [
+      issue(&organization, department.0.id().as_str()),
+      issue(&department, "did:example:employee"),
+    ];
+
+    let validation = validate(&chain, &organization.0, &[&organization.0, &department.0]);
+    assert!(validation.is_valid());
+    assert_eq!(validation.links[0].subject.as_ref(), Some(department.0.id()));
+    let badge = validation.leaf().unwrap();
+    assert_eq!(
+      badge
+        .credential
+        .credential_subject
+        .get(0)
+        .unwrap()
+        .id
+        .as_ref()
+        .unwrap()
+        .as_str(),
+      "did:example:employee"
+    );
+    assert_eq!(validation.into_result().unwrap().len(), 2);
+  }
+
+  #[test]
+  fn test_broken_link() {
+    let organization = generate_jwk_document_with_keys();
+    let department = generate_jwk_document_with_keys();
+    let impostor = generate_jwk_document_with_keys();
+    let chain = [
+      issue(&organization, department.0.id().as_str()),
+      issue(&impostor, "did:example:employee"),
+    ];
+
+    let validation = validate(&chain, &organization.0, &[&organization.0, &department.0, &impostor.0]);
+    assert!(!validation.is_valid());
+    assert!(validation.leaf().is_none());
+    assert_eq!(validation.first_failure().unwrap().index, 1);
+    let errors = validation.into_result().unwrap_err();
+    assert!(matches!(errors[..], [(1, CredentialChainLinkError::BrokenLink { .. })]));
+  }
+
+  #[test]
+  fn test_invalid_link_invalidates_successors() {
+    let organization = generate_jwk_document_with_keys();
+    let department = generate_jwk_document_with_keys();
+    let chain = [
+      issue(&organization, department.0.id().as_str()),
+      issue(&department, "did:example:employee"),
+    ];
+
+    let validation = validate(&chain, &department.0, &[&organization.0, &department.0]);
+    assert!(matches!(
+      validation.links[0].outcome,
+      Err(CredentialChainLinkError::UntrustedRoot(_))
+    ));
+    assert!(matches!(
+      validation.links[1].outcome,
+      Err(CredentialChainLinkError::InvalidPredecessor)
+    ));
+    assert_eq!(validation.links[1].issuer.as_ref(), Some(department.0.id()));
+  }
+}
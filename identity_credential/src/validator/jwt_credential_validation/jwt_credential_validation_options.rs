@@ -7,6 +7,8 @@ use identity_document::verifiable::JwsVerificationOptions;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::validator::IssuerLocationOptions;
+use crate::validator::JwtHeaderProfile;
 use crate::validator::SubjectHolderRelationship;
 
 /// Options to declare validation criteria for [`Credential`](crate::credential::Credential)s.
@@ -32,6 +34,13 @@ pub struct JwtCredentialValidationOptions {
   #[serde(default)]
   pub status: crate::validator::StatusCheck,
 
+  /// Validation behaviour for suspended credentials, honoured by
+  /// [`JwtCredentialValidator::validate_with_status_list_2021`](crate::validator::JwtCredentialValidator::validate_with_status_list_2021).
+  ///
+  /// Default: [`SuspensionCheck::FailOnSuspended`](crate::validator::SuspensionCheck::FailOnSuspended).
+  #[serde(default)]
+  pub suspension: crate::validator::SuspensionCheck,
+
   /// Declares how credential subjects must relate to the presentation holder during validation.
   ///
   /// <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships>
@@ -40,6 +49,36 @@ pub struct JwtCredentialValidationOptions {
   /// Options which affect the verification of the signature on the credential.
   #[serde(default)]
   pub verification_options: JwsVerificationOptions,
+
+  /// Declares how the issuer and the verification method used to sign the credential are located.
+  ///
+  /// Default: the `kid` header parameter must be an absolute DID URL identifying the issuer.
+  #[serde(default)]
+  pub issuer_location: IssuerLocationOptions,
+
+  /// Declares that the credential's signature must be verified against the issuer's DID Document as it was when the
+  /// credential was issued, rather than against its latest state. This allows credentials signed with key material
+  /// that has since been rotated out of a verification method to remain valid.
+  ///
+  /// # Warning
+  /// The issuance date is chosen by the issuer - or by whoever holds one of its keys - and is not anchored to any
+  /// ledger. A compromised key can therefore be used to sign backdated credentials that verify against a past state
+  /// of the document. `identity_resolver`'s `Resolver::resolve_credential_issuer` mitigates this by requiring the
+  /// signing verification method to still be part of the issuer's latest DID Document - which rules out deactivated
+  /// documents and removed methods - so that removing a compromised method revokes every credential it signed.
+  ///
+  /// Validators are given the issuer's document, so this option is honoured by the code resolving it, e.g.
+  /// `identity_resolver`'s `Resolver::resolve_credential_issuer`.
+  ///
+  /// Default: `false`.
+  #[serde(default)]
+  pub issuer_document_at_issuance: bool,
+
+  /// Declares the header parameters the credential's JWS must have, e.g. `typ: vc+jwt`.
+  ///
+  /// Default: no header parameter is required.
+  #[serde(default)]
+  pub header_profile: JwtHeaderProfile,
 }
 
 impl JwtCredentialValidationOptions {
@@ -68,6 +107,18 @@ impl JwtCredentialValidationOptions {
     self
   }
 
+  /// Declare that suspended credentials are considered valid.
+  pub fn allow_suspended(mut self) -> Self {
+    self.suspension = crate::validator::SuspensionCheck::AllowSuspended;
+    self
+  }
+
+  /// Declare that suspended credentials are **not** considered valid. This is the default.
+  pub fn fail_on_suspended(mut self) -> Self {
+    self.suspension = crate::validator::SuspensionCheck::FailOnSuspended;
+    self
+  }
+
   /// Declares how credential subjects must relate to the presentation holder during validation.
   ///
   /// <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships>
@@ -85,4 +136,23 @@ impl JwtCredentialValidationOptions {
     self.verification_options = options;
     self
   }
+
+  /// Set how the issuer and the verification method used to sign the credential are located.
+  pub fn issuer_location(mut self, issuer_location: IssuerLocationOptions) -> Self {
+    self.issuer_location = issuer_location;
+    self
+  }
+
+  /// Declare whether the credential must be verified against the issuer's DID Document as it was when the credential
+  /// was issued. See the field of the same name.
+  pub fn issuer_document_at_issuance(mut self, value: bool) -> Self {
+    self.issuer_document_at_issuance = value;
+    self
+  }
+
+  /// Declare the header parameters the credential's JWS must have.
+  pub fn header_profile(mut self, header_profile: JwtHeaderProfile) -> Self {
+    self.header_profile = header_profile;
+    self
+  }
 }
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::str::FromStr as _;
 
 use identity_core::convert::FromJson;
@@ -16,6 +17,7 @@ use identity_verification::jws::JwsVerifier;
 
 use super::CompoundCredentialValidationError;
 use super::DecodedJwtCredential;
+use super::IssuerLocationOptions;
 use super::JwtCredentialValidationOptions;
 use super::JwtCredentialValidatorUtils;
 use super::JwtValidationError;
@@ -25,6 +27,8 @@ use crate::credential::CredentialJwtClaims;
 use crate::credential::CredentialT;
 use crate::credential::Jwt;
 use crate::credential::JwtVcV2;
+#[cfg(feature = "status-list-2021")]
+use crate::revocation::status_list_2021::StatusList2021Credential;
 use crate::validator::DecodedJwtCredentialV2;
 use crate::validator::FailFast;
 
@@ -39,10 +43,16 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     Self(signature_verifier)
   }
 
+  /// Returns the verifier cryptographic signatures are delegated to.
+  pub(crate) fn signature_verifier(&self) -> &V {
+    &self.0
+  }
+
   /// Decodes and validates a [`Credential`] issued as a JWT. A [`DecodedJwtCredential`] is returned upon success.
   ///
   /// The following properties are validated according to `options`:
   /// - the issuer's signature on the JWS,
+  /// - the header parameters required by the profile,
   /// - the expiration date,
   /// - the issuance date,
   /// - the semantic structure.
@@ -73,12 +83,20 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let credential_token = self
-      .verify_signature(
-        credential_jwt,
-        std::slice::from_ref(issuer.as_ref()),
-        &options.verification_options,
-      )
+    let credential_token = Self::verify_signature_with_verifier(
+      &self.0,
+      credential_jwt,
+      std::slice::from_ref(issuer.as_ref()),
+      &options.verification_options,
+      &options.issuer_location,
+    )
+    .map_err(|err| CompoundCredentialValidationError {
+      validation_errors: [err].into(),
+    })?;
+
+    options
+      .header_profile
+      .check(&credential_token.header)
       .map_err(|err| CompoundCredentialValidationError {
         validation_errors: [err].into(),
       })?;
@@ -93,11 +111,54 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     Ok(credential_token)
   }
 
+  /// Decodes and validates a [`Credential`] issued as a JWT like [`Self::validate`], checking its `StatusList2021`
+  /// status against `status_list_credential` rather than against the `RevocationBitmap2022` services of `issuer`.
+  ///
+  /// The status is checked according to [`JwtCredentialValidationOptions::status`], and suspended credentials are
+  /// handled according to [`JwtCredentialValidationOptions::suspension`] - see
+  /// [`JwtCredentialValidatorUtils::check_status_with_status_list_2021_options`]. The status is only checked once all
+  /// other validations succeeded.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  #[cfg(feature = "status-list-2021")]
+  pub fn validate_with_status_list_2021<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer: &DOC,
+    status_list_credential: &StatusList2021Credential,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let issuer_options = JwtCredentialValidationOptions {
+      status: crate::validator::StatusCheck::SkipAll,
+      ..options.clone()
+    };
+    let credential_token = self.validate::<DOC, T>(credential_jwt, issuer, &issuer_options, fail_fast)?;
+
+    JwtCredentialValidatorUtils::check_status_with_status_list_2021_options(
+      &credential_token.credential,
+      status_list_credential,
+      options.status,
+      options.suspension,
+    )
+    .map_err(|err| CompoundCredentialValidationError {
+      validation_errors: [err].into(),
+    })?;
+
+    Ok(credential_token)
+  }
+
   /// Decodes and validates a [CredentialV2](crate::credential::CredentialV2) issued as a JWT.
   /// A [`DecodedJwtCredentialV2`] is returned upon success.
   ///
   /// The following properties are validated according to `options`:
   /// - the issuer's signature on the JWS,
+  /// - the header parameters required by the profile,
   /// - the date and time the credential becomes valid,
   /// - the date and time the credential ceases to be valid,
   /// - the semantic structure.
@@ -133,11 +194,19 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
       credential_jwt,
       std::slice::from_ref(issuer.as_ref()),
       &options.verification_options,
+      &options.issuer_location,
     )
     .map_err(|err| CompoundCredentialValidationError {
       validation_errors: [err].into(),
     })?;
 
+    options
+      .header_profile
+      .check(&credential_token.header)
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
     Self::validate_decoded_credential(
       &credential_token.credential,
       std::slice::from_ref(issuer),
@@ -174,7 +243,13 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    Self::verify_signature_with_verifier(&self.0, credential, trusted_issuers, options)
+    Self::verify_signature_with_verifier(
+      &self.0,
+      credential,
+      trusted_issuers,
+      options,
+      &IssuerLocationOptions::default(),
+    )
   }
 
   /// Decode and verify the JWS signature of a [Credential](crate::credential::credential_v2::Credential) issued as a
@@ -203,7 +278,13 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    Self::verify_signature_with_verifier_v2::<DOC, V, T>(&self.0, credential, trusted_issuers, options)
+    Self::verify_signature_with_verifier_v2::<DOC, V, T>(
+      &self.0,
+      credential,
+      trusted_issuers,
+      options,
+      &IssuerLocationOptions::default(),
+    )
   }
 
   // This method takes a slice of issuer's instead of a single issuer in order to better accommodate presentation
@@ -276,7 +357,8 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     jws: &JwsValidationItem<'a>,
     trusted_issuers: &'i [DOC],
     options: &JwsVerificationOptions,
-  ) -> Result<(&'a Jwk, DIDUrl), JwtValidationError>
+    location: &IssuerLocationOptions,
+  ) -> Result<(Cow<'a, Jwk>, DIDUrl), JwtValidationError>
   where
     DOC: AsRef<CoreDocument>,
     'i: 'a,
@@ -289,28 +371,9 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
       ));
     }
 
-    // If no method_url is set, parse the `kid` to a DID Url which should be the identifier
-    // of a verification method in a trusted issuer's DID document.
-    let method_id: DIDUrl =
-      match &options.method_id {
-        Some(method_id) => method_id.clone(),
-        None => {
-          let kid: &str = jws.protected_header().and_then(|header| header.kid()).ok_or(
-            JwtValidationError::MethodDataLookupError {
-              source: None,
-              message: "could not extract kid from protected header",
-              signer_ctx: SignerContext::Issuer,
-            },
-          )?;
-
-          // Convert kid to DIDUrl
-          DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
-            source: Some(err.into()),
-            message: "could not parse kid as a DID Url",
-            signer_ctx: SignerContext::Issuer,
-          })?
-        }
-      };
+    // If no method_url is set, the `kid` - possibly combined with other hints - identifies a verification method
+    // in a trusted issuer's DID document.
+    let method_id: DIDUrl = location.locate_method(jws, options)?;
 
     // locate the corresponding issuer
     let issuer: &CoreDocument = trusted_issuers
@@ -322,11 +385,16 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     // Obtain the public key from the issuer's DID document
     issuer
       .resolve_method(&method_id, options.method_scope)
-      .and_then(|method| method.data().public_key_jwk())
       .ok_or_else(|| JwtValidationError::MethodDataLookupError {
         source: None,
         message: "could not extract JWK from a method identified by kid",
         signer_ctx: SignerContext::Issuer,
+      })?
+      .public_key_as_jwk()
+      .map_err(|err| JwtValidationError::MethodDataLookupError {
+        source: Some(err.into()),
+        message: "could not extract JWK from a method identified by kid",
+        signer_ctx: SignerContext::Issuer,
       })
       .map(move |jwk| (jwk, method_id))
   }
@@ -337,6 +405,7 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     credential: &Jwt,
     trusted_issuers: &[DOC],
     options: &JwsVerificationOptions,
+    location: &IssuerLocationOptions,
   ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
@@ -349,9 +418,9 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
 
     // Start decoding the credential
     let decoded: JwsValidationItem<'_> = Self::decode(credential.as_str())?;
-    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options)?;
+    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options, location)?;
 
-    let credential_token = Self::verify_decoded_signature(decoded, public_key, signature_verifier)?;
+    let credential_token = Self::verify_decoded_signature(decoded, &public_key, signature_verifier)?;
 
     // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
     // returning.
@@ -369,6 +438,7 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     credential: &JwtVcV2,
     trusted_issuers: &[DOC],
     options: &JwsVerificationOptions,
+    location: &IssuerLocationOptions,
   ) -> Result<DecodedJwtCredentialV2<T>, JwtValidationError>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
@@ -381,9 +451,9 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
 
     // Start decoding the credential
     let decoded: JwsValidationItem<'_> = Self::decode(credential.as_str())?;
-    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options)?;
+    let (public_key, method_id) = Self::parse_jwk(&decoded, trusted_issuers, options, location)?;
 
-    let credential_token = Self::verify_decoded_signature_v2(decoded, public_key, signature_verifier)?;
+    let credential_token = Self::verify_decoded_signature_v2(decoded, &public_key, signature_verifier)?;
 
     // Check that the DID component of the parsed `kid` does indeed correspond to the issuer in the credential before
     // returning.
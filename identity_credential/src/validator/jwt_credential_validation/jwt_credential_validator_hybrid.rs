@@ -39,6 +39,7 @@ impl<TRV: JwsVerifier, PQV: JwsVerifier> JwtCredentialValidatorHybrid<TRV, PQV>
   ///
   /// The following properties are validated according to `options`:
   /// - the issuer's PQ/T signature on the JWS,
+  /// - the header parameters required by the profile,
   /// - the expiration date,
   /// - the issuance date,
   /// - the semantic structure.
@@ -79,6 +80,13 @@ impl<TRV: JwsVerifier, PQV: JwsVerifier> JwtCredentialValidatorHybrid<TRV, PQV>
         validation_errors: [err].into(),
       })?;
 
+    options
+      .header_profile
+      .check(&credential_token.header)
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
     JwtCredentialValidator::<TRV>::validate_decoded_credential(
       &credential_token.credential,
       std::slice::from_ref(issuer.as_ref()),
@@ -6,15 +6,19 @@ use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
+use identity_did::DIDUrl;
 use identity_did::DID;
 use identity_verification::jws::Decoder;
 
+use super::JwtCredentialValidationOptions;
 use super::JwtValidationError;
 use super::SignerContext;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::CredentialT;
 use crate::credential::CredentialV2;
+#[cfg(feature = "bitstring-status-list")]
+use crate::revocation::bitstring_status_list::BitstringStatusListCredential;
 #[cfg(feature = "status-list-2021")]
 use crate::revocation::status_list_2021::StatusList2021Credential;
 use crate::validator::SubjectHolderRelationship;
@@ -119,15 +123,38 @@ impl JwtCredentialValidatorUtils {
 
   /// Checks whether the status specified in `credentialStatus` has been set by the issuer.
   ///
-  /// Only supports `StatusList2021`.
+  /// Only supports `StatusList2021`. Suspended credentials are rejected with [`JwtValidationError::Suspended`],
+  /// see [`Self::check_status_with_status_list_2021_options`] to accept them.
   #[cfg(feature = "status-list-2021")]
   pub fn check_status_with_status_list_2021<T>(
     credential: &dyn CredentialT<Properties = T>,
     status_list_credential: &StatusList2021Credential,
     status_check: crate::validator::StatusCheck,
+  ) -> ValidationUnitResult {
+    Self::check_status_with_status_list_2021_options(
+      credential,
+      status_list_credential,
+      status_check,
+      crate::validator::SuspensionCheck::FailOnSuspended,
+    )
+  }
+
+  /// Checks whether the status specified in `credentialStatus` has been set by the issuer, handling suspended
+  /// credentials according to `suspension_check`.
+  ///
+  /// Only supports `StatusList2021`. Fails with [`JwtValidationError::Revoked`] if the credential has been revoked,
+  /// and with [`JwtValidationError::Suspended`] if it has been suspended and `suspension_check` is
+  /// [`SuspensionCheck::FailOnSuspended`](crate::validator::SuspensionCheck::FailOnSuspended).
+  #[cfg(feature = "status-list-2021")]
+  pub fn check_status_with_status_list_2021_options<T>(
+    credential: &dyn CredentialT<Properties = T>,
+    status_list_credential: &StatusList2021Credential,
+    status_check: crate::validator::StatusCheck,
+    suspension_check: crate::validator::SuspensionCheck,
   ) -> ValidationUnitResult {
     use crate::revocation::status_list_2021::CredentialStatus;
     use crate::revocation::status_list_2021::StatusList2021Entry;
+    use crate::validator::SuspensionCheck;
 
     if status_check == crate::validator::StatusCheck::SkipAll {
       return Ok(());
@@ -147,8 +174,10 @@ impl JwtCredentialValidatorUtils {
         .map_err(|e| JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(e.to_string())))?;
       match entry_status {
         CredentialStatus::Revoked => Err(JwtValidationError::Revoked),
-        CredentialStatus::Suspended => Err(JwtValidationError::Suspended),
-        CredentialStatus::Valid => Ok(()),
+        CredentialStatus::Suspended if suspension_check == SuspensionCheck::FailOnSuspended => {
+          Err(JwtValidationError::Suspended)
+        }
+        CredentialStatus::Suspended | CredentialStatus::Valid => Ok(()),
       }
     } else {
       Err(JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(
@@ -157,6 +186,85 @@ impl JwtCredentialValidatorUtils {
     }
   }
 
+  /// Checks whether the status specified in `credentialStatus` has been set by the issuer.
+  ///
+  /// Only supports `BitstringStatusListEntry`. Suspended credentials are rejected with
+  /// [`JwtValidationError::Suspended`], see [`Self::check_bitstring_status_options`] to accept them.
+  #[cfg(feature = "bitstring-status-list")]
+  pub fn check_bitstring_status<T>(
+    credential: &dyn CredentialT<Properties = T>,
+    status_list_credential: &BitstringStatusListCredential,
+    status_check: crate::validator::StatusCheck,
+  ) -> ValidationUnitResult {
+    Self::check_bitstring_status_options(
+      credential,
+      status_list_credential,
+      status_check,
+      crate::validator::SuspensionCheck::FailOnSuspended,
+    )
+  }
+
+  /// Checks whether the status specified in `credentialStatus` has been set by the issuer, handling suspended
+  /// credentials according to `suspension_check`.
+  ///
+  /// Only supports `BitstringStatusListEntry`s with a `statusSize` of 1. Besides the cases described in
+  /// [`Self::check_status_with_status_list_2021_options`], fails with [`JwtValidationError::InvalidStatus`] if
+  /// `status_list_credential` is no longer valid, as required by the
+  /// [validation algorithm](https://www.w3.org/TR/vc-bitstring-status-list/#validate-algorithm).
+  #[cfg(feature = "bitstring-status-list")]
+  pub fn check_bitstring_status_options<T>(
+    credential: &dyn CredentialT<Properties = T>,
+    status_list_credential: &BitstringStatusListCredential,
+    status_check: crate::validator::StatusCheck,
+    suspension_check: crate::validator::SuspensionCheck,
+  ) -> ValidationUnitResult {
+    use crate::revocation::bitstring_status_list::BitstringStatusListEntry;
+    use crate::revocation::status_list_2021::CredentialStatus;
+    use crate::validator::SuspensionCheck;
+
+    let invalid_status = |message: String| JwtValidationError::InvalidStatus(crate::Error::InvalidStatus(message));
+
+    if status_check == crate::validator::StatusCheck::SkipAll {
+      return Ok(());
+    }
+
+    let Some(status) = credential.status() else {
+      return Ok(());
+    };
+
+    let status = BitstringStatusListEntry::try_from(status).map_err(|e| invalid_status(e.to_string()))?;
+    if Some(status.status_list_credential()) != status_list_credential.id.as_ref()
+      || status.purpose() != status_list_credential.purpose()
+    {
+      return Err(invalid_status(
+        "The given statusListCredential doesn't match the credential's status".to_owned(),
+      ));
+    }
+    if status.status_size() != 1 {
+      return Err(invalid_status(format!(
+        "unsupported statusSize {}",
+        status.status_size()
+      )));
+    }
+    if status_list_credential
+      .valid_until
+      .is_some_and(|valid_until| valid_until < Timestamp::now_utc())
+    {
+      return Err(invalid_status("the statusListCredential has expired".to_owned()));
+    }
+
+    let entry_status = status_list_credential
+      .entry(status.index())
+      .map_err(|e| invalid_status(e.to_string()))?;
+    match entry_status {
+      CredentialStatus::Revoked => Err(JwtValidationError::Revoked),
+      CredentialStatus::Suspended if suspension_check == SuspensionCheck::FailOnSuspended => {
+        Err(JwtValidationError::Suspended)
+      }
+      CredentialStatus::Suspended | CredentialStatus::Valid => Ok(()),
+    }
+  }
+
   /// Checks whether the credential status has been revoked.
   ///
   /// Only supports `RevocationBitmap2022`.
@@ -274,4 +382,158 @@ impl JwtCredentialValidatorUtils {
       source: err.into(),
     })
   }
+
+  /// Utility for extracting the DID URL of the verification method that signed a credential from its JWT encoding,
+  /// located as configured by `options`, without validating the credential.
+  ///
+  /// # Errors
+  ///
+  /// If the JWT decoding fails or the verification method cannot be located, see
+  /// [`IssuerLocationOptions`](crate::validator::IssuerLocationOptions).
+  pub fn extract_signing_method_from_jwt(
+    credential: &impl AsRef<str>,
+    options: &JwtCredentialValidationOptions,
+  ) -> std::result::Result<DIDUrl, JwtValidationError> {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(credential.as_ref().as_bytes(), None)
+      .map_err(JwtValidationError::JwsDecodingError)?;
+
+    options
+      .issuer_location
+      .locate_method(&validation_item, &options.verification_options)
+  }
+
+  /// Utility for extracting the issuance date of a credential - its `issuanceDate` or `validFrom` - from its JWT
+  /// encoding, without validating it.
+  ///
+  /// # Errors
+  ///
+  /// If the JWT decoding fails or the claims cannot be deserialized into a Credential V1 or V2.
+  pub fn extract_issuance_date_from_jwt(
+    credential: &impl AsRef<str>,
+  ) -> std::result::Result<Timestamp, JwtValidationError> {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(credential.as_ref().as_bytes(), None)
+      .map_err(JwtValidationError::JwsDecodingError)?;
+
+    let try_v1 = |payload: &[u8]| {
+      CredentialJwtClaims::<'_, Object>::from_json_slice(payload)
+        .map_err(|_| ())
+        .and_then(|claims| claims.try_into_credential().map_err(|_| ()))
+        .map(|credential| credential.valid_from())
+    };
+    let try_v2 = |payload: &[u8]| CredentialV2::<Object>::from_json_slice(payload).map(|cred| cred.valid_from);
+
+    try_v1(validation_item.claims())
+      .or_else(|_| try_v2(validation_item.claims()))
+      .map_err(|_| {
+        JwtValidationError::CredentialStructure(crate::error::Error::JwtClaimsSetDeserializationError(
+          "cannot deserialize a Credential V1 or V2".into(),
+        ))
+      })
+  }
+}
+
+#[cfg(all(test, feature = "status-list-2021"))]
+mod tests {
+  use super::*;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Issuer;
+  use crate::credential::Subject;
+  use crate::revocation::status_list_2021::StatusList2021;
+  use crate::revocation::status_list_2021::StatusList2021CredentialBuilder;
+  use crate::revocation::status_list_2021::StatusList2021Entry;
+  use crate::revocation::status_list_2021::StatusPurpose;
+  use crate::validator::StatusCheck;
+  use crate::validator::SuspensionCheck;
+
+  fn setup(purpose: StatusPurpose) -> (Credential, StatusList2021Credential) {
+    let issuer = Url::parse("did:example:issuer").unwrap();
+    let status_list_url = Url::parse("https://example.com/status/1").unwrap();
+    let mut status_list = StatusList2021CredentialBuilder::new(StatusList2021::default())
+      .issuer(Issuer::Url(issuer.clone()))
+      .purpose(purpose)
+      .subject_id(status_list_url.clone())
+      .build()
+      .unwrap();
+    status_list.set_entry(7, true).unwrap();
+
+    let credential = CredentialBuilder::default()
+      .issuer(issuer)
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .status(StatusList2021Entry::new(status_list_url, purpose, 7, None))
+      .build()
+      .unwrap();
+
+    (credential, status_list)
+  }
+
+  #[test]
+  fn suspended_credentials_can_be_allowed() {
+    let (credential, status_list) = setup(StatusPurpose::Suspension);
+
+    let result =
+      JwtCredentialValidatorUtils::check_status_with_status_list_2021(&credential, &status_list, StatusCheck::Strict);
+    assert!(matches!(result, Err(JwtValidationError::Suspended)));
+
+    JwtCredentialValidatorUtils::check_status_with_status_list_2021_options(
+      &credential,
+      &status_list,
+      StatusCheck::Strict,
+      SuspensionCheck::AllowSuspended,
+    )
+    .unwrap();
+  }
+
+  #[test]
+  fn revoked_credentials_are_never_allowed() {
+    let (credential, status_list) = setup(StatusPurpose::Revocation);
+
+    let result = JwtCredentialValidatorUtils::check_status_with_status_list_2021_options(
+      &credential,
+      &status_list,
+      StatusCheck::Strict,
+      SuspensionCheck::AllowSuspended,
+    );
+    assert!(matches!(result, Err(JwtValidationError::Revoked)));
+  }
+
+  #[cfg(feature = "bitstring-status-list")]
+  #[test]
+  fn bitstring_status_is_checked() {
+    use crate::revocation::bitstring_status_list::BitstringStatusList;
+    use crate::revocation::bitstring_status_list::BitstringStatusListCredentialBuilder;
+
+    let issuer = Url::parse("did:example:issuer").unwrap();
+    let mut status_list = BitstringStatusListCredentialBuilder::new(BitstringStatusList::default())
+      .id(Url::parse("https://example.com/status/1").unwrap())
+      .issuer(Issuer::Url(issuer.clone()))
+      .purpose(StatusPurpose::Suspension)
+      .build()
+      .unwrap();
+    let mut credential = CredentialBuilder::default()
+      .issuer(issuer)
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build_v2()
+      .unwrap();
+    status_list.set_credential_status(&mut credential, 7, false).unwrap();
+
+    JwtCredentialValidatorUtils::check_bitstring_status(&credential, &status_list, StatusCheck::Strict).unwrap();
+
+    status_list.set_entry(7, true).unwrap();
+    let result = JwtCredentialValidatorUtils::check_bitstring_status(&credential, &status_list, StatusCheck::Strict);
+    assert!(matches!(result, Err(JwtValidationError::Suspended)));
+    JwtCredentialValidatorUtils::check_bitstring_status_options(
+      &credential,
+      &status_list,
+      StatusCheck::Strict,
+      SuspensionCheck::AllowSuspended,
+    )
+    .unwrap();
+
+    // A StatusList2021Entry is not a BitstringStatusListEntry.
+    let (credential, _) = setup(StatusPurpose::Suspension);
+    let result = JwtCredentialValidatorUtils::check_bitstring_status(&credential, &status_list, StatusCheck::Strict);
+    assert!(matches!(result, Err(JwtValidationError::InvalidStatus(_))));
+  }
 }
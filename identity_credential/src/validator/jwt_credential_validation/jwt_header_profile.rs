@@ -0,0 +1,125 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jws::media_type_eq;
+use identity_verification::jws::JwsHeader;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::JwtValidationError;
+
+/// Header parameters the JWS of a credential is required to have, as mandated by the profile it is issued under.
+///
+/// Media types are compared as described in [RFC 7515](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.9), i.e.
+/// case-insensitively and with an optional `application/` prefix.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtHeaderProfile {
+  /// The required value of the `typ` header parameter, if any.
+  #[serde(default)]
+  pub typ: Option<String>,
+  /// The required value of the `cty` header parameter, if any.
+  #[serde(default)]
+  pub cty: Option<String>,
+}
+
+impl JwtHeaderProfile {
+  /// Creates a new [`JwtHeaderProfile`] that doesn't require any header parameter.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Requires the `typ` of a [VC Data Model v2.0](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose)
+  /// credential, i.e. `vc+jwt`.
+  pub fn vc_jwt() -> Self {
+    Self::new().typ("vc+jwt")
+  }
+
+  /// Requires the `typ` of an [SD-JWT VC](https://datatracker.ietf.org/doc/draft-ietf-oauth-sd-jwt-vc/), i.e.
+  /// `dc+sd-jwt`.
+  pub fn sd_jwt_vc() -> Self {
+    Self::new().typ("dc+sd-jwt")
+  }
+
+  /// Requires the `typ` header parameter to be `value`.
+  pub fn typ(mut self, value: impl Into<String>) -> Self {
+    self.typ = Some(value.into());
+    self
+  }
+
+  /// Requires the `cty` header parameter to be `value`.
+  pub fn cty(mut self, value: impl Into<String>) -> Self {
+    self.cty = Some(value.into());
+    self
+  }
+
+  /// Checks that `header` has the parameters required by this profile.
+  pub fn check(&self, header: &JwsHeader) -> Result<(), JwtValidationError> {
+    self.check_parameters(header.typ(), header.cty())
+  }
+
+  /// Checks that the given `typ` and `cty` header parameters are the ones required by this profile.
+  pub fn check_parameters(&self, typ: Option<&str>, cty: Option<&str>) -> Result<(), JwtValidationError> {
+    check_parameter("typ", self.typ.as_deref(), typ)?;
+    check_parameter("cty", self.cty.as_deref(), cty)
+  }
+}
+
+fn check_parameter(
+  parameter: &'static str,
+  expected: Option<&str>,
+  found: Option<&str>,
+) -> Result<(), JwtValidationError> {
+  let Some(expected) = expected else {
+    return Ok(());
+  };
+
+  if found.is_some_and(|found| media_type_eq(found, expected)) {
+    Ok(())
+  } else {
+    Err(JwtValidationError::HeaderProfileMismatch {
+      parameter,
+      expected: expected.to_owned(),
+      found: found.map(ToOwned::to_owned),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn media_types_are_compared_case_insensitively_without_prefix() {
+    let profile = JwtHeaderProfile::vc_jwt();
+
+    assert!(profile.check_parameters(Some("vc+jwt"), None).is_ok());
+    assert!(profile.check_parameters(Some("VC+JWT"), None).is_ok());
+    assert!(profile.check_parameters(Some("application/vc+jwt"), None).is_ok());
+  }
+
+  #[test]
+  fn mismatching_headers_are_rejected() {
+    let profile = JwtHeaderProfile::sd_jwt_vc().cty("vc");
+
+    assert!(matches!(
+      profile.check_parameters(Some("JWT"), Some("vc")),
+      Err(JwtValidationError::HeaderProfileMismatch { parameter: "typ", .. })
+    ));
+    assert!(matches!(
+      profile.check_parameters(Some("dc+sd-jwt"), None),
+      Err(JwtValidationError::HeaderProfileMismatch {
+        parameter: "cty",
+        found: None,
+        ..
+      })
+    ));
+    assert!(profile.check_parameters(Some("dc+sd-jwt"), Some("vc")).is_ok());
+  }
+
+  #[test]
+  fn empty_profile_accepts_any_header() {
+    assert!(JwtHeaderProfile::new().check_parameters(None, None).is_ok());
+  }
+}
@@ -4,16 +4,24 @@
 //! Contains functionality for validating credentials issued as JWTs.
 mod decoded_jwt_credential;
 mod error;
+mod issuer_location;
+mod jwt_credential_batch_validation;
+mod jwt_credential_chain_validator;
 mod jwt_credential_validation_options;
 mod jwt_credential_validator;
 #[cfg(feature = "hybrid")]
 mod jwt_credential_validator_hybrid;
 mod jwt_credential_validator_utils;
+mod jwt_header_profile;
 
 pub use decoded_jwt_credential::*;
 pub use error::*;
+pub use issuer_location::*;
+pub use jwt_credential_batch_validation::*;
+pub use jwt_credential_chain_validator::*;
 pub use jwt_credential_validation_options::*;
 pub use jwt_credential_validator::*;
 #[cfg(feature = "hybrid")]
 pub use jwt_credential_validator_hybrid::*;
 pub use jwt_credential_validator_utils::*;
+pub use jwt_header_profile::*;
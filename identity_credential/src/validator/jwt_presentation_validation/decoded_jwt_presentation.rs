@@ -1,6 +1,8 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 use identity_core::common::Object;
 use identity_core::common::StringOrUrl;
 use identity_core::common::Timestamp;
@@ -27,4 +29,17 @@ pub struct DecodedJwtPresentation<CRED, T = Object> {
   pub aud: Option<StringOrUrl>,
   /// The custom claims parsed from the JWT.
   pub custom_claims: Option<Object>,
+  /// The point in time after which the presentation is no longer considered valid, i.e. the earlier of its
+  /// expiration date and the end of the `max_age` window set during validation.
+  pub valid_until: Option<Timestamp>,
+}
+
+impl<CRED, T> DecodedJwtPresentation<CRED, T> {
+  /// Returns how long the presentation remains valid from now on, or `None` if its validity is not bounded.
+  pub fn remaining_validity(&self) -> Option<Duration> {
+    self.valid_until.map(|valid_until| {
+      let remaining: i64 = valid_until.to_unix() - Timestamp::now_utc().to_unix();
+      Duration::from_secs(remaining.max(0) as u64)
+    })
+  }
 }
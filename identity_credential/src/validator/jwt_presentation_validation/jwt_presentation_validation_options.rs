@@ -27,6 +27,16 @@ pub struct JwtPresentationValidationOptions {
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
+
+  /// Declares that the presentation is **not** considered valid if it was issued more than this many seconds
+  /// before `earliest_expiry_date`, or the current datetime if that is not set.
+  /// Presentations without an issuance date are rejected when set.
+  #[serde(default)]
+  pub max_age: Option<u32>,
+
+  /// Declares that the presentation is **not** considered valid unless it contains an `nbf` claim.
+  #[serde(default)]
+  pub require_not_before: bool,
 }
 
 impl JwtPresentationValidationOptions {
@@ -54,4 +64,16 @@ impl JwtPresentationValidationOptions {
     self.latest_issuance_date = Some(timestamp);
     self
   }
+
+  /// Declare that the presentation is **not** considered valid if it was issued more than `seconds` ago.
+  pub fn max_age(mut self, seconds: u32) -> Self {
+    self.max_age = Some(seconds);
+    self
+  }
+
+  /// Declare whether the presentation must contain an `nbf` claim to be considered valid.
+  pub fn require_not_before(mut self, value: bool) -> Self {
+    self.require_not_before = value;
+    self
+  }
 }
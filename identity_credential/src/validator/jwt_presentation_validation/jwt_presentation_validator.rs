@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
@@ -87,19 +88,25 @@ where
       vp,
       aud,
       iat,
+      nbf,
       exp,
       custom: custom_claims,
     }) = serde_json::from_slice(&decoded_jws.claims)
     {
       check_holder(vp.holder.as_str(), holder.as_ref())?;
+      let expiration_date = convert_and_check_exp(exp, options.earliest_expiry_date)?;
+      let issuance_date = convert_and_check_iat(iat, options.latest_issuance_date)?;
+      convert_and_check_iat(nbf, options.latest_issuance_date)?;
+      let valid_until = check_freshness(expiration_date, issuance_date, nbf.is_some(), options)?;
 
       return Ok(DecodedJwtPresentation {
         presentation: vp,
         header: Box::new(decoded_jws.protected),
-        expiration_date: convert_and_check_exp(exp, options.earliest_expiry_date)?,
-        issuance_date: convert_and_check_iat(iat, options.latest_issuance_date)?,
+        expiration_date,
+        issuance_date,
         aud,
         custom_claims,
+        valid_until,
       });
     }
 
@@ -113,6 +120,7 @@ where
 
     check_holder(claims.iss.as_str(), holder.as_ref())?;
     let expiration_date = convert_and_check_exp(claims.exp, options.earliest_expiry_date)?;
+    let has_nbf = claims.issuance_date.as_ref().is_some_and(|id| id.nbf.is_some());
     let issuance_date = {
       let iat = claims
         .issuance_date
@@ -120,6 +128,7 @@ where
         .map(|ts| ts.to_unix());
       convert_and_check_iat(iat, options.latest_issuance_date)?
     };
+    let valid_until = check_freshness(expiration_date, issuance_date, has_nbf, options)?;
 
     let aud = claims.aud.take();
     let custom_claims = claims.custom.take();
@@ -135,6 +144,7 @@ where
       issuance_date,
       aud,
       custom_claims,
+      valid_until,
     };
 
     Ok(decoded_jwt_presentation)
@@ -201,3 +211,38 @@ fn convert_and_check_iat(
     ))
   }
 }
+
+/// Enforces the `max_age` and `require_not_before` options, returning the point in time until which the
+/// presentation is valid.
+pub(super) fn check_freshness(
+  expiration_date: Option<Timestamp>,
+  issuance_date: Option<Timestamp>,
+  has_nbf: bool,
+  options: &JwtPresentationValidationOptions,
+) -> Result<Option<Timestamp>, CompoundJwtPresentationValidationError> {
+  if options.require_not_before && !has_nbf {
+    return Err(CompoundJwtPresentationValidationError::one_presentation_error(
+      JwtValidationError::MissingClaim("nbf"),
+    ));
+  }
+
+  let Some(max_age) = options.max_age else {
+    return Ok(expiration_date);
+  };
+  let issuance_date = issuance_date.ok_or_else(|| {
+    CompoundJwtPresentationValidationError::one_presentation_error(JwtValidationError::MissingClaim("iat"))
+  })?;
+  // A window reaching beyond the largest representable timestamp does not bound the validity.
+  let Some(max_age_expiry) = issuance_date.checked_add(Duration::seconds(max_age)) else {
+    return Ok(expiration_date);
+  };
+
+  if max_age_expiry < options.earliest_expiry_date.unwrap_or_default() {
+    return Err(CompoundJwtPresentationValidationError::one_presentation_error(
+      JwtValidationError::MaxAgeExceeded(max_age),
+    ));
+  }
+
+  let valid_until = expiration_date.map_or(max_age_expiry, |exp| exp.min(max_age_expiry));
+  Ok(Some(valid_until))
+}
@@ -17,6 +17,7 @@ use crate::presentation::PresentationJwtClaims;
 use crate::validator::jwt_credential_validation::JwtValidationError;
 use crate::validator::jwt_credential_validation::SignerContext;
 
+use super::jwt_presentation_validator::check_freshness;
 use super::CompoundJwtPresentationValidationError;
 use super::DecodedJwtPresentation;
 use super::JwtPresentationValidationOptions;
@@ -146,6 +147,8 @@ where
         JwtValidationError::IssuanceDate,
       ));
     }
+    let has_nbf = claims.issuance_date.as_ref().is_some_and(|iss| iss.nbf.is_some());
+    let valid_until = check_freshness(expiration_date, issuance_date, has_nbf, options)?;
 
     let aud: Option<StringOrUrl> = claims.aud.clone();
     let custom_claims: Option<Object> = claims.custom.clone();
@@ -161,6 +164,7 @@ where
       issuance_date,
       aud,
       custom_claims,
+      valid_until,
     };
 
     Ok(decoded_jwt_presentation)
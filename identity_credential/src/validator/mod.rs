@@ -12,6 +12,8 @@ pub use self::jwt_presentation_validation::*;
 pub use self::options::FailFast;
 pub use self::options::StatusCheck;
 pub use self::options::SubjectHolderRelationship;
+pub use self::options::SuspensionCheck;
+pub use self::verification_report::*;
 #[cfg(feature = "sd-jwt")]
 pub use self::sd_jwt::*;
 
@@ -22,6 +24,7 @@ mod jpt_presentation_validation;
 mod jwt_credential_validation;
 mod jwt_presentation_validation;
 mod options;
+mod verification_report;
 #[cfg(feature = "sd-jwt")]
 mod sd_jwt;
 #[cfg(test)]
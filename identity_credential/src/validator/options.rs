@@ -24,6 +24,22 @@ pub enum StatusCheck {
   SkipAll = 2,
 }
 
+/// Controls validation behaviour for credentials that have been suspended, i.e. temporarily revoked, by their
+/// [`credentialStatus`](https://www.w3.org/TR/vc-data-model/#status).
+///
+/// Revoked credentials are always rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Default)]
+#[repr(u8)]
+pub enum SuspensionCheck {
+  /// Reject suspended credentials.
+  ///
+  /// This is the default.
+  #[default]
+  FailOnSuspended = 0,
+  /// Accept suspended credentials, e.g. when the suspension is handled by a separate business rule.
+  AllowSuspended = 1,
+}
+
 /// Declares how credential subjects must relate to the presentation holder during validation.
 ///
 /// See also the [Subject-Holder Relationship](https://www.w3.org/TR/vc-data-model/#subject-holder-relationships) section of the specification.
@@ -0,0 +1,24 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+
+use crate::credential::Credential;
+
+use super::SdJwtDisclosureStats;
+
+/// The outcome of a successful
+/// [`SdJwtCredentialValidator::validate_presentation`](super::SdJwtCredentialValidator::validate_presentation).
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct DecodedSdJwtPresentation<T = Object> {
+  /// The credential built from the disclosed claims.
+  pub credential: Credential<T>,
+  /// Statistics about the SD-JWT's digests and disclosures.
+  pub disclosure_stats: SdJwtDisclosureStats,
+  /// Whether the SD-JWT is bound to its holder through a validated KB-JWT.
+  pub key_bound: bool,
+  /// The point in time until which the KB-JWT is considered valid, if bounded by the validation options.
+  pub valid_until: Option<Timestamp>,
+}
@@ -0,0 +1,239 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use sd_jwt::Disclosure;
+use sd_jwt::Hasher;
+use sd_jwt::SdJwt;
+use sd_jwt::SdJwtClaims;
+use sd_jwt::SHA_ALG_NAME;
+use serde_json::Value;
+
+/// Key of the object properties' digests array.
+const DIGESTS_KEY: &str = "_sd";
+/// Key of an array element's digest.
+const ARRAY_DIGEST_KEY: &str = "...";
+
+/// Statistics about the digests and disclosures of an SD-JWT, as seen by its verifier.
+///
+/// A digest that no disclosure matches is either a decoy or a claim the holder chose not to disclose: the two cannot
+/// be told apart. When all of the token's disclosures are present - e.g. right after issuance - every concealed
+/// digest is a decoy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SdJwtDisclosureStats {
+  /// Total number of digests, including those found in disclosed values.
+  pub digests: usize,
+  /// Number of digests with a matching disclosure.
+  pub disclosed: usize,
+  /// Number of digests without a matching disclosure.
+  pub concealed: usize,
+  /// Number of digests of array elements.
+  pub array_digests: usize,
+}
+
+impl SdJwtDisclosureStats {
+  /// Computes the disclosure statistics of `sd_jwt`, using `hasher` to digest its disclosures.
+  ///
+  /// # Errors
+  /// Fails if `hasher` is not the hasher the SD-JWT was issued with, if a digest appears more than once, or if any
+  /// disclosure does not match a digest. The latter are never silently ignored, as they are a sign of a tampered or
+  /// malformed token.
+  pub fn new(sd_jwt: &SdJwt, hasher: &dyn Hasher) -> Result<Self, SdJwtDisclosureError> {
+    Self::from_parts(sd_jwt.claims(), sd_jwt.disclosures(), hasher)
+  }
+
+  pub(crate) fn from_parts(
+    claims: &SdJwtClaims,
+    disclosures: &[Disclosure],
+    hasher: &dyn Hasher,
+  ) -> Result<Self, SdJwtDisclosureError> {
+    let sd_alg = claims._sd_alg.as_deref().unwrap_or(SHA_ALG_NAME);
+    if sd_alg != hasher.alg_name() {
+      return Err(SdJwtDisclosureError::HasherMismatch {
+        expected: sd_alg.to_owned(),
+        found: hasher.alg_name().to_owned(),
+      });
+    }
+
+    let mut counter = DigestCounter {
+      disclosures: HashMap::with_capacity(disclosures.len()),
+      seen: HashSet::new(),
+      stats: Self::default(),
+    };
+    for disclosure in disclosures {
+      let digest = hasher.encoded_digest(&disclosure.to_string());
+      if counter.disclosures.insert(digest.clone(), disclosure).is_some() {
+        return Err(SdJwtDisclosureError::DuplicateDigest(digest));
+      }
+    }
+
+    counter.visit_digests(&claims._sd, false)?;
+    for value in claims.values() {
+      counter.visit(value)?;
+    }
+
+    // Whatever is left didn't match any digest.
+    if !counter.disclosures.is_empty() {
+      let mut unknown: Vec<String> = counter
+        .disclosures
+        .into_values()
+        .map(|disclosure| disclosure.to_string())
+        .collect();
+      unknown.sort_unstable();
+      return Err(SdJwtDisclosureError::UnknownDisclosures(unknown));
+    }
+
+    Ok(counter.stats)
+  }
+
+  /// Returns whether the SD-JWT contains digests without a matching disclosure, i.e. decoys or undisclosed claims.
+  pub fn has_concealed_digests(&self) -> bool {
+    self.concealed > 0
+  }
+}
+
+struct DigestCounter<'a> {
+  disclosures: HashMap<String, &'a Disclosure>,
+  seen: HashSet<String>,
+  stats: SdJwtDisclosureStats,
+}
+
+impl DigestCounter<'_> {
+  fn visit(&mut self, value: &Value) -> Result<(), SdJwtDisclosureError> {
+    match value {
+      Value::Object(object) => {
+        if let Some(Value::Array(digests)) = object.get(DIGESTS_KEY) {
+          let digests: Vec<String> = digests
+            .iter()
+            .filter_map(Value::as_str)
+            .map(ToOwned::to_owned)
+            .collect();
+          self.visit_digests(&digests, false)?;
+        }
+        for (key, value) in object {
+          if key != DIGESTS_KEY {
+            self.visit(value)?;
+          }
+        }
+      }
+      Value::Array(elements) => {
+        for element in elements {
+          match element.as_object().and_then(array_element_digest) {
+            Some(digest) => self.visit_digests(&[digest.to_owned()], true)?,
+            None => self.visit(element)?,
+          }
+        }
+      }
+      _ => (),
+    }
+
+    Ok(())
+  }
+
+  fn visit_digests(&mut self, digests: &[String], in_array: bool) -> Result<(), SdJwtDisclosureError> {
+    for digest in digests {
+      if !self.seen.insert(digest.clone()) {
+        return Err(SdJwtDisclosureError::DuplicateDigest(digest.clone()));
+      }
+      self.stats.digests += 1;
+      if in_array {
+        self.stats.array_digests += 1;
+      }
+
+      match self.disclosures.remove(digest) {
+        Some(disclosure) => {
+          self.stats.disclosed += 1;
+          self.visit(&disclosure.claim_value)?;
+        }
+        None => self.stats.concealed += 1,
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Returns the digest of a concealed array element, i.e. `{"...": "<digest>"}`.
+fn array_element_digest(object: &serde_json::Map<String, Value>) -> Option<&str> {
+  if object.len() != 1 {
+    return None;
+  }
+  object.get(ARRAY_DIGEST_KEY).and_then(Value::as_str)
+}
+
+/// Errors that can occur when processing an SD-JWT's disclosures.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum SdJwtDisclosureError {
+  /// The given hasher is not the one the SD-JWT was issued with.
+  #[error("the SD-JWT uses hash algorithm \"{expected}\", but \"{found}\" was given")]
+  HasherMismatch {
+    /// The SD-JWT's `_sd_alg`.
+    expected: String,
+    /// The given hasher's algorithm.
+    found: String,
+  },
+  /// The same digest appears more than once in the SD-JWT.
+  #[error("digest \"{0}\" appears more than once")]
+  DuplicateDigest(String),
+  /// Some disclosures don't match any of the SD-JWT's digests.
+  #[error("{} disclosure(s) don't match any digest", .0.len())]
+  UnknownDisclosures(Vec<String>),
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_verification::jwu::encode_b64;
+  use sd_jwt::Sha256Hasher;
+  use serde_json::json;
+
+  use super::*;
+
+  fn disclosure(json: &str) -> Disclosure {
+    Disclosure::parse(&encode_b64(json)).unwrap()
+  }
+
+  #[test]
+  fn counts_disclosed_and_concealed_digests() {
+    let hasher = Sha256Hasher;
+    let name = disclosure(r#"["salt1","name","Alice"]"#);
+    let nationality = disclosure(r#"["salt2","DE"]"#);
+    let digest_of = |disclosure: &Disclosure| hasher.encoded_digest(&disclosure.to_string());
+
+    let claims: SdJwtClaims = serde_json::from_value(json!({
+      "_sd": [digest_of(&name), "decoy"],
+      "_sd_alg": "sha-256",
+      "nationalities": [{ "...": digest_of(&nationality) }, { "...": "concealed" }, "IT"],
+    }))
+    .unwrap();
+
+    let stats = SdJwtDisclosureStats::from_parts(&claims, &[name, nationality.clone()], &hasher).unwrap();
+    assert_eq!(stats.digests, 4);
+    assert_eq!(stats.disclosed, 2);
+    assert_eq!(stats.concealed, 2);
+    assert_eq!(stats.array_digests, 2);
+    assert!(stats.has_concealed_digests());
+
+    let unknown = disclosure(r#"["salt3","age",42]"#);
+    let err = SdJwtDisclosureStats::from_parts(&claims, &[nationality, unknown.clone()], &hasher).unwrap_err();
+    assert!(
+      matches!(err, SdJwtDisclosureError::UnknownDisclosures(unknown_disclosures)
+      if unknown_disclosures == [unknown.to_string()])
+    );
+  }
+
+  #[test]
+  fn duplicate_digests_are_rejected() {
+    let claims: SdJwtClaims = serde_json::from_value(json!({
+      "_sd": ["digest"],
+      "nested": { "_sd": ["digest"] },
+    }))
+    .unwrap();
+
+    let err = SdJwtDisclosureStats::from_parts(&claims, &[], &Sha256Hasher).unwrap_err();
+    assert!(matches!(err, SdJwtDisclosureError::DuplicateDigest(digest) if digest == "digest"));
+  }
+}
@@ -6,6 +6,8 @@ use std::fmt::Display;
 
 use crate::validator::JwtValidationError;
 
+use super::SdJwtCredentialValidatorError;
+
 /// An error indicating that an unexpected value was found.
 #[derive(Debug)]
 pub struct UnexpectedValue {
@@ -69,6 +71,10 @@ pub enum KeyBindingJwtError {
   #[error("invalid KB-JWT 'iat' value, {0}")]
   IssuanceDate(String),
 
+  /// Not-before date validation error.
+  #[error("invalid KB-JWT 'nbf' value, {0}")]
+  NotBefore(String),
+
   /// SD-JWT does not contain a key binding JWT.
   #[error("SD-JWT token requires a KB-JWT, but none was found")]
   MissingKeyBindingJwt,
@@ -76,4 +82,59 @@ pub enum KeyBindingJwtError {
   /// Header value `typ` is invalid.
   #[error("invalid KB-JWT header 'typ' value")]
   InvalidHeaderTypValue(#[source] UnexpectedValue),
+
+  /// The DID in the SD-JWT's holder binding claim does not match the holder's DID Document.
+  #[error("the SD-JWT is bound to a different holder")]
+  HolderBindingMismatch(#[source] UnexpectedValue),
+
+  /// The SD-JWT's holder binding claim is missing or concealed.
+  #[error("the SD-JWT's holder binding claim '{0}' is missing or not disclosed")]
+  HolderBindingClaimNotDisclosed(String),
+
+  /// The value of the SD-JWT's holder binding claim is not a DID.
+  #[error("the SD-JWT's holder binding claim is not a DID")]
+  InvalidHolderBindingClaim(#[source] UnexpectedValue),
+}
+
+/// An error associated with validating an SD-JWT presentation through
+/// [`SdJwtCredentialValidator::validate_presentation`](super::SdJwtCredentialValidator::validate_presentation).
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum SdJwtPresentationValidationError {
+  /// The credential carried by the SD-JWT is invalid.
+  #[error("invalid SD-JWT credential")]
+  Credential(#[source] SdJwtCredentialValidatorError),
+  /// Key binding is required, but the SD-JWT doesn't require it.
+  #[error("the SD-JWT is not bound to a holder key")]
+  KeyBindingNotRequired,
+  /// The KB-JWT is invalid.
+  #[error("invalid KB-JWT")]
+  KeyBinding(#[source] KeyBindingJwtError),
+  /// The SD-JWT is bound to a JWK that is not the public key of any method of the holder's DID Document.
+  #[error("the SD-JWT is bound to a key that doesn't belong to the holder")]
+  UnboundHolderKey,
+  /// The credential's subject doesn't relate to the holder as required.
+  #[error("the credential's subject doesn't match the holder")]
+  SubjectHolderRelationship(#[source] JwtValidationError),
 }
+
+/// Errors caused by a failure to validate an SD-JWT presentation through
+/// [`SdJwtCredentialValidator::validate_presentation`](super::SdJwtCredentialValidator::validate_presentation).
+#[derive(Debug)]
+pub struct CompoundSdJwtPresentationValidationError {
+  /// List of presentation validation errors.
+  pub validation_errors: Vec<SdJwtPresentationValidationError>,
+}
+
+impl Display for CompoundSdJwtPresentationValidationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let detailed_information: String = itertools::intersperse(
+      self.validation_errors.iter().map(|err| err.to_string()),
+      "; ".to_string(),
+    )
+    .collect();
+    write!(f, "[{detailed_information}]")
+  }
+}
+
+impl std::error::Error for CompoundSdJwtPresentationValidationError {}
@@ -1,6 +1,7 @@
 // Copyright 2020-2026 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
 use serde::Deserialize;
 use serde::Serialize;
@@ -25,6 +26,19 @@ pub struct KeyBindingJwtValidationOptions {
   /// Uses the current timestamp during validation if not set.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub latest_issuance_date: Option<Timestamp>,
+  /// Declares that the KB-JWT is considered invalid if its `iat` value is more than this many seconds in the past.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_age: Option<u32>,
+  /// Declares that the KB-JWT is considered invalid if its claims do not contain an `nbf` value.
+  #[serde(default)]
+  pub require_not_before: bool,
+  /// Tolerance for clock skew between the holder and the validator, applied to the `nbf` value of the KB-JWT.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub leeway: Option<Duration>,
+  /// The name of an SD-JWT claim, e.g. `sub`, that binds the token to its holder. The claim must be disclosed and
+  /// contain the DID of the holder document used to validate the KB-JWT.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub holder_binding_claim: Option<String>,
 }
 
 impl KeyBindingJwtValidationOptions {
@@ -59,4 +73,29 @@ impl KeyBindingJwtValidationOptions {
     self.latest_issuance_date = Some(latest_issuance_date);
     self
   }
+
+  /// Declares that the KB-JWT is considered invalid if its `iat` value is more than `seconds` in the past.
+  pub fn max_age(mut self, seconds: u32) -> Self {
+    self.max_age = Some(seconds);
+    self
+  }
+
+  /// Declares whether the KB-JWT must contain an `nbf` value to be considered valid.
+  pub fn require_not_before(mut self, value: bool) -> Self {
+    self.require_not_before = value;
+    self
+  }
+
+  /// Tolerates a clock skew of `leeway` between the holder and the validator when checking the KB-JWT's `nbf` value.
+  pub fn leeway(mut self, leeway: Duration) -> Self {
+    self.leeway = Some(leeway);
+    self
+  }
+
+  /// Requires the DID in the SD-JWT claim `claim`, e.g. `sub`, to match the DID of the holder document used to
+  /// validate the KB-JWT.
+  pub fn holder_binding_claim(mut self, claim: impl Into<String>) -> Self {
+    self.holder_binding_claim = Some(claim.into());
+    self
+  }
 }
@@ -1,10 +1,16 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod decoded_sd_jwt_presentation;
+mod disclosure_stats;
 mod error;
 mod kb_validation_options;
+mod presentation_validation_options;
 mod validator;
 
+pub use decoded_sd_jwt_presentation::*;
+pub use disclosure_stats::*;
 pub use error::*;
 pub use kb_validation_options::*;
+pub use presentation_validation_options::*;
 pub use validator::*;
@@ -0,0 +1,64 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::SubjectHolderRelationship;
+
+use super::KeyBindingJwtValidationOptions;
+
+/// Criteria for validating an SD-JWT presentation, i.e. an SD-JWT together with its Key Binding JWT (KB-JWT), through
+/// [`SdJwtCredentialValidator::validate_presentation`](super::SdJwtCredentialValidator::validate_presentation).
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct SdJwtPresentationValidationOptions {
+  /// Options for validating the credential carried by the SD-JWT.
+  #[serde(default)]
+  pub credential: JwtCredentialValidationOptions,
+  /// Options for validating the KB-JWT.
+  #[serde(default)]
+  pub key_binding: KeyBindingJwtValidationOptions,
+  /// Declares that the presentation is considered invalid if the SD-JWT doesn't require key binding.
+  ///
+  /// Without key binding, anyone in possession of the SD-JWT can present it.
+  #[serde(default)]
+  pub require_key_binding: bool,
+  /// Declares how the credential's subject must relate to the holder, whose DID Document is used to validate the
+  /// KB-JWT. Not checked if not set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub subject_holder_relationship: Option<SubjectHolderRelationship>,
+}
+
+impl SdJwtPresentationValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the options for validating the credential carried by the SD-JWT.
+  pub fn credential_validation_options(mut self, options: JwtCredentialValidationOptions) -> Self {
+    self.credential = options;
+    self
+  }
+
+  /// Sets the options for validating the KB-JWT.
+  pub fn key_binding_validation_options(mut self, options: KeyBindingJwtValidationOptions) -> Self {
+    self.key_binding = options;
+    self
+  }
+
+  /// Declares whether the SD-JWT must require key binding for the presentation to be considered valid.
+  pub fn require_key_binding(mut self, value: bool) -> Self {
+    self.require_key_binding = value;
+    self
+  }
+
+  /// Declares how the credential's subject must relate to the holder.
+  pub fn subject_holder_relationship(mut self, relationship: SubjectHolderRelationship) -> Self {
+    self.subject_holder_relationship = Some(relationship);
+    self
+  }
+}
@@ -5,6 +5,7 @@ use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::CredentialV2;
 use crate::validator::FailFast;
+use crate::validator::IssuerLocationOptions;
 use crate::validator::JwtCredentialValidationOptions;
 use crate::validator::JwtCredentialValidator;
 use crate::validator::JwtCredentialValidatorUtils;
@@ -12,10 +13,13 @@ use crate::validator::JwtValidationError;
 use crate::validator::SignerContext;
 use crate::validator::UnexpectedValue;
 use anyhow::Context as _;
+use identity_core::common::Duration;
 use identity_core::common::Timestamp;
+use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
+use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::verifiable::JwsVerificationOptions;
 use identity_verification::jwk::Jwk;
@@ -27,8 +31,14 @@ use sd_jwt::RequiredKeyBinding;
 use sd_jwt::SdJwt;
 use serde_json::Value;
 
+use super::CompoundSdJwtPresentationValidationError;
+use super::DecodedSdJwtPresentation;
 use super::KeyBindingJwtError;
 use super::KeyBindingJwtValidationOptions;
+use super::SdJwtDisclosureError;
+use super::SdJwtDisclosureStats;
+use super::SdJwtPresentationValidationError;
+use super::SdJwtPresentationValidationOptions;
 
 /// Errors that can occur when validating an SD-JWT credential.
 #[derive(Debug, thiserror::Error)]
@@ -43,6 +53,9 @@ pub enum SdJwtCredentialValidatorError {
   /// SD-JWT specific error like: disclosure processing, or hasher mismatch.
   #[error(transparent)]
   SdJwt(#[from] sd_jwt::Error),
+  /// The SD-JWT's disclosures could not be matched with its digests.
+  #[error(transparent)]
+  Disclosure(#[from] SdJwtDisclosureError),
 }
 
 /// A type validating [`SdJwt`]s.
@@ -56,12 +69,25 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     Self(signature_verifier, Box::new(hasher))
   }
 
+  /// Returns the [`JwsVerifier`] signatures are verified with.
+  #[cfg(feature = "openid4vp")]
+  pub(crate) fn verifier(&self) -> &V {
+    &self.0
+  }
+
+  /// Returns the [`Hasher`] SD-JWTs are decoded with.
+  #[cfg(feature = "openid4vp")]
+  pub(crate) fn hasher(&self) -> &dyn Hasher {
+    self.1.as_ref()
+  }
+
   /// Decodes and validates a [Credential] issued as an SD-JWT.
   /// The credential is constructed by replacing disclosures following the
   /// [Selective Disclosure for JWTs (SD-JWT)](https://www.rfc-editor.org/rfc/rfc9901.html) standard.
   ///
   /// The following properties are validated according to `options`:
   /// - the issuer's signature on the JWS,
+  /// - the disclosures, which must all match one of the SD-JWT's digests,
   /// - the expiration date,
   /// - the issuance date,
   /// - the semantic structure.
@@ -89,14 +115,46 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     trusted_issuers: &[DOC],
     options: &JwtCredentialValidationOptions,
   ) -> Result<Credential<T>, SdJwtCredentialValidatorError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let credential = self.decode_credential::<DOC, T>(sd_jwt, trusted_issuers, options)?;
+    JwtCredentialValidator::<V>::validate_decoded_credential(
+      &credential,
+      trusted_issuers,
+      options,
+      FailFast::FirstError,
+    )
+    .map_err(|mut errs| SdJwtCredentialValidatorError::JwsVerification(errs.validation_errors.swap_remove(0)))?;
+
+    Ok(credential)
+  }
+
+  /// Verifies the issuer's signature and the disclosures of `sd_jwt`, constructing the [Credential] made of its
+  /// disclosed claims without validating its properties.
+  fn decode_credential<DOC, T>(
+    &self,
+    sd_jwt: &SdJwt,
+    trusted_issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+  ) -> Result<Credential<T>, SdJwtCredentialValidatorError>
   where
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
     // Verify the JWS signature.
-    let vm_id = self.verify_signature_impl(&sd_jwt.presentation(), trusted_issuers, &options.verification_options)?;
+    let vm_id = self.verify_signature_impl(
+      &sd_jwt.presentation(),
+      trusted_issuers,
+      &options.verification_options,
+      &options.issuer_location,
+    )?;
     let hasher = self.1.as_ref();
 
+    // Reject disclosures that don't match any digest.
+    SdJwtDisclosureStats::new(sd_jwt, hasher)?;
+
     // Try to construct a credential from the disclosed claims.
     let disclosed_claims = sd_jwt.clone().into_disclosed_object(hasher)?;
     let credential_jwt_claims: CredentialJwtClaims<'_, T> = serde_json::from_value(Value::Object(disclosed_claims))
@@ -104,13 +162,6 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     let credential = credential_jwt_claims
       .try_into_credential()
       .map_err(|e| SdJwtCredentialValidatorError::CredentialStructure(e.into()))?;
-    JwtCredentialValidator::<V>::validate_decoded_credential(
-      &credential,
-      trusted_issuers,
-      options,
-      FailFast::FirstError,
-    )
-    .map_err(|mut errs| SdJwtCredentialValidatorError::JwsVerification(errs.validation_errors.swap_remove(0)))?;
 
     let issuer_id = JwtCredentialValidatorUtils::extract_issuer::<CoreDID, _>(&credential)?;
     if &issuer_id != vm_id.did() {
@@ -131,6 +182,7 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
   ///
   /// The following properties are validated according to `options`:
   /// - the issuer's signature on the JWS,
+  /// - the disclosures, which must all match one of the SD-JWT's digests,
   /// - the expiration date,
   /// - the issuance date,
   /// - the semantic structure.
@@ -164,9 +216,17 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     DOC: AsRef<CoreDocument>,
   {
     // Verify the JWS signature.
-    let vm_id = self.verify_signature_impl(&sd_jwt.presentation(), trusted_issuers, &options.verification_options)?;
+    let vm_id = self.verify_signature_impl(
+      &sd_jwt.presentation(),
+      trusted_issuers,
+      &options.verification_options,
+      &options.issuer_location,
+    )?;
     let hasher = self.1.as_ref();
 
+    // Reject disclosures that don't match any digest.
+    SdJwtDisclosureStats::new(sd_jwt, hasher)?;
+
     // Try to construct a credential from the disclosed claims.
     let disclosed_claims = sd_jwt.clone().into_disclosed_object(hasher)?;
     let credential = CredentialV2::<T>::from_json_value(Value::Object(disclosed_claims))
@@ -192,6 +252,109 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     Ok(credential)
   }
 
+  /// Validates an SD-JWT presentation in one go: the credential it carries - see
+  /// [SdJwtCredentialValidator::validate_credential] - its Key Binding JWT - see
+  /// [SdJwtCredentialValidator::validate_key_binding_jwt] - and their consistency with `holder`'s DID Document.
+  ///
+  /// The following checks are performed:
+  /// 1. whether the SD-JWT requires key binding, if `options.require_key_binding` is set;
+  /// 2. the credential, according to `options.credential`;
+  /// 3. the KB-JWT, according to `options.key_binding`;
+  /// 4. whether the key the SD-JWT is bound to belongs to the holder, i.e. - when the SD-JWT's `cnf` claim carries a
+  ///    JWK - whether it is the public key of one of the methods of `holder`'s DID Document;
+  /// 5. the relationship between the credential's subject and the holder, if `options.subject_holder_relationship` is
+  ///    set.
+  ///
+  /// # Warning
+  /// The caller must ensure that the DID Documents of the trusted issuers and of the holder are up-to-date.
+  ///
+  /// # Errors
+  /// The failing checks are returned, either all of them or only the first one, depending on `fail_fast`.
+  pub fn validate_presentation<DOC, HDOC, T>(
+    &self,
+    sd_jwt: &SdJwt,
+    trusted_issuers: &[DOC],
+    holder: &HDOC,
+    options: &SdJwtPresentationValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedSdJwtPresentation<T>, CompoundSdJwtPresentationValidationError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+    HDOC: AsRef<CoreDocument>,
+  {
+    let mut validation_errors = Vec::new();
+
+    let key_bound = sd_jwt.required_key_bind().is_some();
+    if options.require_key_binding && !key_bound {
+      validation_errors.push(SdJwtPresentationValidationError::KeyBindingNotRequired);
+    }
+
+    let credential = match self.decode_credential::<DOC, T>(sd_jwt, trusted_issuers, &options.credential) {
+      Ok(credential) => {
+        if let Err(errs) = JwtCredentialValidator::<V>::validate_decoded_credential(
+          &credential,
+          trusted_issuers,
+          &options.credential,
+          fail_fast,
+        ) {
+          validation_errors.extend(
+            errs
+              .validation_errors
+              .into_iter()
+              .map(|e| SdJwtPresentationValidationError::Credential(e.into())),
+          );
+        }
+        Some(credential)
+      }
+      Err(e) => {
+        validation_errors.push(SdJwtPresentationValidationError::Credential(e));
+        None
+      }
+    };
+
+    if let Err(e) = self.validate_key_binding_jwt(sd_jwt, holder, &options.key_binding) {
+      validation_errors.push(SdJwtPresentationValidationError::KeyBinding(e));
+    }
+
+    if let Err(e) = check_cnf_jwk(sd_jwt, holder.as_ref()) {
+      validation_errors.push(e);
+    }
+
+    if let (Some(relationship), Some(credential)) = (options.subject_holder_relationship, &credential) {
+      let holder_url = Url::from(holder.as_ref().id().to_url());
+      if let Err(e) =
+        JwtCredentialValidatorUtils::check_subject_holder_relationship(credential, &holder_url, relationship)
+      {
+        validation_errors.push(SdJwtPresentationValidationError::SubjectHolderRelationship(e));
+      }
+    }
+
+    if let FailFast::FirstError = fail_fast {
+      validation_errors.truncate(1);
+    }
+
+    match credential {
+      Some(credential) if validation_errors.is_empty() => Ok(DecodedSdJwtPresentation {
+        credential,
+        disclosure_stats: self
+          .disclosure_stats(sd_jwt)
+          .expect("disclosures have been checked when decoding the credential"),
+        key_bound,
+        valid_until: self.key_binding_jwt_valid_until(sd_jwt, &options.key_binding),
+      }),
+      _ => Err(CompoundSdJwtPresentationValidationError { validation_errors }),
+    }
+  }
+
+  /// Returns statistics about the digests and disclosures of `sd_jwt`, e.g. how many of its claims are concealed.
+  ///
+  /// # Errors
+  /// See [SdJwtDisclosureStats::new].
+  pub fn disclosure_stats(&self, sd_jwt: &SdJwt) -> Result<SdJwtDisclosureStats, SdJwtDisclosureError> {
+    SdJwtDisclosureStats::new(sd_jwt, self.1.as_ref())
+  }
+
   /// Decode and verify the JWS signature of an SD-JWT using the DID Document of a trusted issuer.
   ///
   /// # Warning
@@ -211,7 +374,7 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     DOC: AsRef<CoreDocument>,
   {
     let sd_jwt_str = sd_jwt.presentation();
-    let _ = self.verify_signature_impl(&sd_jwt_str, trusted_issuers, options)?;
+    let _ = self.verify_signature_impl(&sd_jwt_str, trusted_issuers, options, &IssuerLocationOptions::default())?;
 
     Ok(())
   }
@@ -221,6 +384,7 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     sd_jwt: &str,
     trusted_issuers: &[DOC],
     options: &JwsVerificationOptions,
+    location: &IssuerLocationOptions,
   ) -> Result<DIDUrl, JwtValidationError>
   where
     DOC: AsRef<CoreDocument>,
@@ -230,9 +394,10 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
       .expect("valid SD-JWT contains at least one `~`")
       .0;
     let signature = JwtCredentialValidator::<V>::decode(jwt_str).expect("SD-JWT has a valid JWS");
-    let (public_key, method_id) = JwtCredentialValidator::<V>::parse_jwk(&signature, trusted_issuers, options)?;
+    let (public_key, method_id) =
+      JwtCredentialValidator::<V>::parse_jwk(&signature, trusted_issuers, options, location)?;
 
-    JwtCredentialValidator::<V>::verify_signature_raw(signature, public_key, &self.0)?;
+    JwtCredentialValidator::<V>::verify_signature_raw(signature, &public_key, &self.0)?;
     Ok(method_id)
   }
 
@@ -285,13 +450,13 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
         holder_document
           .as_ref()
           .resolve_method(&method_id, None)
-          .and_then(|method| method.data().public_key_jwk())
+          .and_then(|method| method.public_key_as_jwk().ok())
           .ok_or_else(|| JwtValidationError::MethodDataLookupError {
             source: None,
             message: "could not extract JWK from a method identified by kid",
             signer_ctx: SignerContext::Holder,
           })?
-          .clone()
+          .into_owned()
       }
       _ => return Err(KeyBindingJwtError::UnsupportedCnfMethod),
     };
@@ -311,6 +476,10 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
       return Err(sd_jwt::Error::InvalidHasher(hasher.alg_name().to_owned()).into());
     }
 
+    if let Some(claim) = options.holder_binding_claim.as_deref() {
+      check_holder_binding(sd_jwt, hasher, claim, holder_document.as_ref())?;
+    }
+
     let digest = {
       let sd_jwt_str = sd_jwt.to_string();
       let last_tilde_index = sd_jwt_str.rfind('~').expect("valid SD-JWT contains at least one `~`");
@@ -365,6 +534,103 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
       return Err(KeyBindingJwtError::IssuanceDate("value is in the future".to_string()));
     }
 
+    match kb_jwt.claims().properties.get("nbf") {
+      Some(nbf) => {
+        let not_before = nbf
+          .as_i64()
+          .and_then(|nbf| Timestamp::from_unix(nbf).ok())
+          .ok_or_else(|| KeyBindingJwtError::NotBefore("deserialization of `nbf` failed".to_string()))?;
+        let leeway = options.leeway.unwrap_or(Duration::seconds(0));
+        if not_before.checked_sub(leeway).unwrap_or(not_before) > Timestamp::now_utc() {
+          return Err(KeyBindingJwtError::NotBefore("value is in the future".to_string()));
+        }
+      }
+      None if options.require_not_before => {
+        return Err(KeyBindingJwtError::NotBefore("value is missing".to_string()));
+      }
+      None => (),
+    }
+
+    if let Some(max_age) = options.max_age {
+      if issuance_date
+        .checked_add(Duration::seconds(max_age))
+        .is_some_and(|max_age_expiry| max_age_expiry < Timestamp::now_utc())
+      {
+        return Err(KeyBindingJwtError::IssuanceDate(format!(
+          "value is older than {max_age} seconds"
+        )));
+      }
+    }
+
     Ok(())
   }
+
+  /// Returns the point in time until which the KB-JWT of `sd_jwt` is considered valid, i.e. the end of the
+  /// `max_age` window in `options`, or `None` if there is no KB-JWT or its validity is not bounded.
+  ///
+  /// This does **not** validate the KB-JWT; see [SdJwtCredentialValidator::validate_key_binding_jwt].
+  pub fn key_binding_jwt_valid_until(
+    &self,
+    sd_jwt: &SdJwt,
+    options: &KeyBindingJwtValidationOptions,
+  ) -> Option<Timestamp> {
+    let kb_jwt = sd_jwt.key_binding_jwt()?;
+    let max_age = options.max_age?;
+    Timestamp::from_unix(kb_jwt.claims().iat)
+      .ok()?
+      .checked_add(Duration::seconds(max_age))
+  }
+}
+
+/// Checks that the key `sd_jwt` is bound to, if given as a JWK in its `cnf` claim, is the public key of one of the
+/// methods of `holder_document`.
+fn check_cnf_jwk(sd_jwt: &SdJwt, holder_document: &CoreDocument) -> Result<(), SdJwtPresentationValidationError> {
+  let Some(RequiredKeyBinding::Jwk(jwk)) = sd_jwt.required_key_bind() else {
+    return Ok(());
+  };
+  let jwk = Jwk::from_json_value(Value::Object(jwk.clone()))
+    .map_err(|e| SdJwtPresentationValidationError::KeyBinding(KeyBindingJwtError::DeserializationError(e.into())))?;
+  let thumbprint = jwk.thumbprint_sha256_b64();
+
+  let is_holder_key = holder_document
+    .methods(None)
+    .into_iter()
+    .filter_map(|method| method.public_key_as_jwk().ok())
+    .any(|method_jwk| method_jwk.thumbprint_sha256_b64() == thumbprint);
+  if is_holder_key {
+    Ok(())
+  } else {
+    Err(SdJwtPresentationValidationError::UnboundHolderKey)
+  }
+}
+
+/// Checks that `claim` is disclosed and contains the DID of `holder_document`.
+fn check_holder_binding(
+  sd_jwt: &SdJwt,
+  hasher: &dyn Hasher,
+  claim: &str,
+  holder_document: &CoreDocument,
+) -> Result<(), KeyBindingJwtError> {
+  let disclosed_claims = sd_jwt.clone().into_disclosed_object(hasher)?;
+  let value = disclosed_claims
+    .get(claim)
+    .ok_or_else(|| KeyBindingJwtError::HolderBindingClaimNotDisclosed(claim.to_owned()))?;
+  let did = value
+    .as_str()
+    .and_then(|value| CoreDID::parse(value).ok())
+    .ok_or_else(|| {
+      KeyBindingJwtError::InvalidHolderBindingClaim(UnexpectedValue {
+        expected: None,
+        found: value.to_string().into(),
+      })
+    })?;
+
+  if &did != holder_document.id() {
+    return Err(KeyBindingJwtError::HolderBindingMismatch(UnexpectedValue {
+      expected: Some(holder_document.id().to_string().into()),
+      found: did.to_string().into(),
+    }));
+  }
+
+  Ok(())
 }
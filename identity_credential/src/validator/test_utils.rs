@@ -10,10 +10,18 @@ use identity_document::document::CoreDocument;
 use identity_verification::jwk::EdCurve;
 use identity_verification::jwk::Jwk;
 use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jws::CharSet;
+use identity_verification::jws::CompactJwsEncoder;
+use identity_verification::jws::CompactJwsEncodingOptions;
 use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsHeader;
 use identity_verification::jwu;
+use identity_verification::MethodData;
 use identity_verification::VerificationMethod;
 
+use crate::credential::Credential;
+use crate::credential::Jwt;
+
 pub(crate) fn encode_public_ed25519_jwk(public_key: &PublicKey) -> Jwk {
   let x = jwu::encode_b64(public_key.as_ref());
   let mut params = JwkParamsOkp::new();
@@ -39,3 +47,30 @@ pub(crate) fn generate_jwk_document_with_keys() -> (CoreDocument, SecretKey, Str
     .unwrap();
   (document, secret, fragment)
 }
+
+/// Signs `credential` as a JWT with the Ed25519 method identified by `fragment` in `document`.
+pub(crate) fn sign_credential_jwt(
+  credential: &Credential,
+  document: &CoreDocument,
+  fragment: &str,
+  secret_key: &SecretKey,
+) -> Jwt {
+  let payload: String = credential.serialize_jwt(None).unwrap();
+  let method: &VerificationMethod = document.resolve_method(fragment, None).unwrap();
+  let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+    panic!("not a jwk");
+  };
+  let alg: JwsAlgorithm = jwk.alg().unwrap_or("").parse().unwrap();
+
+  let mut header = JwsHeader::new();
+  header.set_alg(alg);
+  header.set_kid(method.id().to_string());
+  let encoding_options: CompactJwsEncodingOptions = CompactJwsEncodingOptions::NonDetached {
+    charset_requirements: CharSet::Default,
+  };
+  let jws_encoder: CompactJwsEncoder<'_> =
+    CompactJwsEncoder::new_with_options(payload.as_bytes(), &header, encoding_options).unwrap();
+  let signature: [u8; 64] = secret_key.sign(jws_encoder.signing_input()).to_bytes();
+
+  Jwt::new(jws_encoder.into_jws(&signature))
+}
@@ -0,0 +1,343 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_core::convert::ToJson;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::CompoundJwtPresentationValidationError;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::DecodedJwtPresentation;
+use crate::validator::JwtValidationError;
+
+/// Media type of credentials secured as JWTs.
+pub const JWT_VC_MEDIA_TYPE: &str = "application/vc+jwt";
+/// Media type of presentations secured as JWTs.
+pub const JWT_VP_MEDIA_TYPE: &str = "application/vp+jwt";
+
+/// A machine-readable record of the outcome of verifying a credential or presentation.
+///
+/// Its JSON representation follows the verification result described in the
+/// [VC Data Model 2.0](https://www.w3.org/TR/vc-data-model-2.0/#verification), with failures reported as
+/// [`ProblemDetails`], so that it can be archived or handed to other systems as is.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationReport {
+  /// Whether the credential or presentation was successfully verified.
+  pub verified: bool,
+  /// The verified credential or presentation. Only set when `verified` is `true`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub verified_document: Option<Value>,
+  /// The media type of the verified credential or presentation, e.g. [`JWT_VC_MEDIA_TYPE`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub media_type: Option<String>,
+  /// The identifier of the party that secured the verified document, i.e. the credential issuer or the
+  /// presentation holder.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub controller: Option<String>,
+  /// Problems that did not prevent verification.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub warnings: Vec<ProblemDetails>,
+  /// Problems that caused verification to fail.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub errors: Vec<ProblemDetails>,
+}
+
+impl VerificationReport {
+  /// Creates a report of a successful verification of `verified_document`.
+  pub fn verified(verified_document: Value) -> Self {
+    Self {
+      verified: true,
+      verified_document: Some(verified_document),
+      media_type: None,
+      controller: None,
+      warnings: Vec::new(),
+      errors: Vec::new(),
+    }
+  }
+
+  /// Creates a report of a failed verification, caused by `errors`.
+  pub fn failed(errors: impl IntoIterator<Item = ProblemDetails>) -> Self {
+    Self {
+      verified: false,
+      verified_document: None,
+      media_type: None,
+      controller: None,
+      warnings: Vec::new(),
+      errors: errors.into_iter().collect(),
+    }
+  }
+
+  /// Creates a report from the result of validating a credential with a
+  /// [`JwtCredentialValidator`](crate::validator::JwtCredentialValidator).
+  pub fn from_credential_validation<T>(
+    result: &Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>,
+  ) -> Self
+  where
+    T: Serialize,
+  {
+    match result {
+      Ok(decoded) => match decoded.credential.to_json_value() {
+        Ok(credential) => Self::verified(credential).with_controller(decoded.credential.issuer.url().to_string()),
+        Err(error) => Self::failed([ProblemDetails::from_error(ProblemType::ParsingError, &error)]),
+      },
+      Err(error) => Self::failed(error.validation_errors.iter().map(ProblemDetails::from)),
+    }
+    .with_media_type(JWT_VC_MEDIA_TYPE)
+  }
+
+  /// Creates a report from the result of validating a presentation with a
+  /// [`JwtPresentationValidator`](crate::validator::JwtPresentationValidator).
+  ///
+  /// Note that this only covers the presentation itself; the credentials it contains should be reported
+  /// separately through [`VerificationReport::from_credential_validation`].
+  pub fn from_presentation_validation<CRED, T>(
+    result: &Result<DecodedJwtPresentation<CRED, T>, CompoundJwtPresentationValidationError>,
+  ) -> Self
+  where
+    CRED: Serialize,
+    T: Serialize,
+  {
+    match result {
+      Ok(decoded) => match decoded.presentation.to_json_value() {
+        Ok(presentation) => Self::verified(presentation).with_controller(decoded.presentation.holder.to_string()),
+        Err(error) => Self::failed([ProblemDetails::from_error(ProblemType::ParsingError, &error)]),
+      },
+      Err(error) => Self::failed(error.presentation_validation_errors.iter().map(ProblemDetails::from)),
+    }
+    .with_media_type(JWT_VP_MEDIA_TYPE)
+  }
+
+  /// Sets the media type of the verified document.
+  pub fn with_media_type(mut self, media_type: impl Into<String>) -> Self {
+    self.media_type = Some(media_type.into());
+    self
+  }
+
+  /// Sets the identifier of the party that secured the verified document.
+  pub fn with_controller(mut self, controller: impl Into<String>) -> Self {
+    self.controller = Some(controller.into());
+    self
+  }
+
+  /// Adds a warning to the report.
+  pub fn with_warning(mut self, warning: ProblemDetails) -> Self {
+    self.warnings.push(warning);
+    self
+  }
+}
+
+/// The category of a problem encountered while verifying a credential or presentation, as defined by the
+/// [VC Data Model 2.0](https://www.w3.org/TR/vc-data-model-2.0/#problem-details) and the
+/// [Bitstring Status List](https://www.w3.org/TR/vc-bitstring-status-list/#processing-errors) specifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ProblemType {
+  /// The credential or presentation could not be parsed.
+  ParsingError,
+  /// A signature or proof could not be verified, or the key material needed to do so could not be found.
+  CryptographicSecurityError,
+  /// A value is not in its expected format or violates the data model.
+  MalformedValueError,
+  /// A value, e.g. a date, is outside of its allowed range.
+  RangeError,
+  /// The status of the credential could not be verified, or indicates that it is no longer valid.
+  StatusVerificationError,
+}
+
+impl ProblemType {
+  /// Returns the URL identifying this problem type.
+  pub const fn as_url(&self) -> &'static str {
+    match self {
+      Self::ParsingError => "https://www.w3.org/TR/vc-data-model#PARSING_ERROR",
+      Self::CryptographicSecurityError => "https://www.w3.org/TR/vc-data-model#CRYPTOGRAPHIC_SECURITY_ERROR",
+      Self::MalformedValueError => "https://www.w3.org/TR/vc-data-model#MALFORMED_VALUE_ERROR",
+      Self::RangeError => "https://www.w3.org/TR/vc-data-model#RANGE_ERROR",
+      Self::StatusVerificationError => "https://www.w3.org/ns/credentials/status-list#STATUS_VERIFICATION_ERROR",
+    }
+  }
+
+  /// Returns the error code the specifications assign to this problem type.
+  pub const fn code(&self) -> i64 {
+    match self {
+      Self::ParsingError => -64,
+      Self::CryptographicSecurityError => -65,
+      Self::MalformedValueError => -66,
+      Self::RangeError => -67,
+      Self::StatusVerificationError => -129,
+    }
+  }
+
+  const fn title(&self) -> &'static str {
+    match self {
+      Self::ParsingError => "Parsing Error",
+      Self::CryptographicSecurityError => "Cryptographic Security Error",
+      Self::MalformedValueError => "Malformed Value Error",
+      Self::RangeError => "Range Error",
+      Self::StatusVerificationError => "Status Verification Error",
+    }
+  }
+}
+
+impl From<&JwtValidationError> for ProblemType {
+  fn from(error: &JwtValidationError) -> Self {
+    match error {
+      JwtValidationError::JwsDecodingError(_) => Self::ParsingError,
+      JwtValidationError::PresentationJwsError(_)
+      | JwtValidationError::MethodDataLookupError { .. }
+      | JwtValidationError::IdentifierMismatch { .. }
+      | JwtValidationError::Signature { .. }
+      | JwtValidationError::DocumentMismatch(_) => Self::CryptographicSecurityError,
+      JwtValidationError::ExpirationDate | JwtValidationError::IssuanceDate | JwtValidationError::MaxAgeExceeded(_) => {
+        Self::RangeError
+      }
+      JwtValidationError::SignerUrl { .. }
+      | JwtValidationError::MissingClaim(_)
+      | JwtValidationError::HeaderProfileMismatch { .. }
+      | JwtValidationError::CredentialStructure(_)
+      | JwtValidationError::PresentationStructure(_)
+      | JwtValidationError::SubjectHolderRelationship
+      | JwtValidationError::MissingPresentationHolder => Self::MalformedValueError,
+      JwtValidationError::InvalidStatus(_)
+      | JwtValidationError::ServiceLookupError
+      | JwtValidationError::Revoked
+      | JwtValidationError::Suspended => Self::StatusVerificationError,
+      #[cfg(feature = "jpt-bbs-plus")]
+      JwtValidationError::OutsideTimeframe => Self::RangeError,
+      #[cfg(feature = "jpt-bbs-plus")]
+      JwtValidationError::JwpDecodingError(_) => Self::ParsingError,
+      #[cfg(feature = "jpt-bbs-plus")]
+      JwtValidationError::JwpProofVerificationError(_) => Self::CryptographicSecurityError,
+    }
+  }
+}
+
+/// A single problem encountered while verifying a credential or presentation, in the
+/// [problem details](https://www.rfc-editor.org/rfc/rfc9457) format used by the VC Data Model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProblemDetails {
+  /// URL identifying the type of the problem.
+  #[serde(rename = "type")]
+  pub type_: String,
+  /// Error code of the problem type.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub code: Option<i64>,
+  /// Short, human-readable summary of the problem type.
+  pub title: String,
+  /// Human-readable explanation of this occurrence of the problem.
+  pub detail: String,
+  /// Additional members, e.g. the name of the underlying validation error.
+  #[serde(flatten)]
+  pub properties: Object,
+}
+
+impl ProblemDetails {
+  /// Creates a new [`ProblemDetails`] of the given type.
+  pub fn new(problem_type: ProblemType, detail: impl Into<String>) -> Self {
+    Self {
+      type_: problem_type.as_url().to_owned(),
+      code: Some(problem_type.code()),
+      title: problem_type.title().to_owned(),
+      detail: detail.into(),
+      properties: Object::new(),
+    }
+  }
+
+  /// Creates a new [`ProblemDetails`] of the given type, describing `error` and all of its sources.
+  pub fn from_error(problem_type: ProblemType, error: &dyn std::error::Error) -> Self {
+    let mut detail: String = error.to_string();
+    let mut source = error.source();
+    while let Some(error) = source {
+      detail.push_str(": ");
+      detail.push_str(&error.to_string());
+      source = error.source();
+    }
+
+    Self::new(problem_type, detail)
+  }
+}
+
+impl From<&JwtValidationError> for ProblemDetails {
+  fn from(error: &JwtValidationError) -> Self {
+    let mut problem = Self::from_error(ProblemType::from(error), error);
+    let variant: &'static str = error.into();
+    problem.properties.insert("validationError".to_owned(), variant.into());
+    problem
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use identity_core::common::Url;
+  use identity_verification::jws::JwsHeader;
+
+  use crate::credential::Credential;
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+
+  use super::*;
+
+  #[test]
+  fn failed_report_lists_every_validation_error() {
+    let result: Result<DecodedJwtCredential, _> = Err(CompoundCredentialValidationError {
+      validation_errors: vec![JwtValidationError::ExpirationDate, JwtValidationError::Revoked],
+    });
+
+    let report = VerificationReport::from_credential_validation(&result);
+    assert_eq!(
+      report.to_json_value().unwrap(),
+      json!({
+        "verified": false,
+        "mediaType": "application/vc+jwt",
+        "errors": [
+          {
+            "type": "https://www.w3.org/TR/vc-data-model#RANGE_ERROR",
+            "code": -67,
+            "title": "Range Error",
+            "detail": "the expiration date is in the past or earlier than required",
+            "validationError": "ExpirationDate",
+          },
+          {
+            "type": "https://www.w3.org/ns/credentials/status-list#STATUS_VERIFICATION_ERROR",
+            "code": -129,
+            "title": "Status Verification Error",
+            "detail": "credential has been revoked",
+            "validationError": "Revoked",
+          },
+        ],
+      })
+    );
+  }
+
+  #[test]
+  fn verified_report_contains_credential_and_issuer() {
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let decoded = DecodedJwtCredential {
+      credential: credential.clone(),
+      header: Box::new(JwsHeader::new()),
+      custom_claims: None,
+    };
+
+    let report = VerificationReport::from_credential_validation(&Ok::<_, CompoundCredentialValidationError>(decoded));
+    assert!(report.verified);
+    assert!(report.errors.is_empty());
+    assert_eq!(report.controller.as_deref(), Some("did:example:issuer"));
+    assert_eq!(
+      Credential::<Object>::from_json_value(report.verified_document.clone().unwrap()).unwrap(),
+      credential
+    );
+
+    let json = report.to_json().unwrap();
+    assert_eq!(VerificationReport::from_json(&json).unwrap(), report);
+  }
+}
@@ -0,0 +1,352 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rust half of the serialization differential tests between this library and its WASM bindings.
+//!
+//! Random credentials and DID documents - both well-formed and mutated into malformed ones - are generated from a
+//! seed and run through the Rust implementation, recording their canonical (RFC 8785) serialization and the outcome
+//! of their validation. When `IDENTITY_DIFFERENTIAL_VECTORS` is set, the resulting test vectors are written to the
+//! given path, for `bindings/wasm/identity_wasm/tests/serialization_differential.ts` to replay them against the
+//! bindings (see the `test:differential` script of `@iota/identity-wasm`).
+//!
+//! `IDENTITY_DIFFERENTIAL_SEED` and `IDENTITY_DIFFERENTIAL_CASES` override the seed and the number of generated
+//! test vectors respectively.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Credential;
+use identity_credential::validator::JwtCredentialValidatorUtils;
+use identity_document::document::CoreDocument;
+use proptest::collection::btree_map;
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::Config;
+use proptest::test_runner::RngAlgorithm;
+use proptest::test_runner::TestRng;
+use proptest::test_runner::TestRunner;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+const DEFAULT_SEED: u64 = 0x1d3e_2a7f_5c4b_9e01;
+const DEFAULT_CASES: usize = 256;
+
+/// Largest integer that survives a round trip through a JavaScript number.
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Kind {
+  Credential,
+  Document,
+}
+
+/// The outcome of running a test vector through the implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Outcome {
+  /// The input could not be deserialized.
+  Unparsable,
+  /// The input was deserialized, but failed validation.
+  Invalid,
+  /// The input was deserialized and passed validation.
+  Valid,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TestVector {
+  kind: Kind,
+  input: Value,
+  outcome: Outcome,
+  /// The canonical serialization of the deserialized input, if it could be deserialized.
+  canonical: Option<String>,
+}
+
+impl TestVector {
+  fn new(kind: Kind, input: Value) -> Self {
+    let (outcome, canonical) = match kind {
+      Kind::Credential => run_credential(&input),
+      Kind::Document => run_document(&input),
+    };
+
+    Self {
+      kind,
+      input,
+      outcome,
+      canonical,
+    }
+  }
+}
+
+fn run_credential(input: &Value) -> (Outcome, Option<String>) {
+  let Ok(credential) = Credential::<Object>::from_json_value(input.clone()) else {
+    return (Outcome::Unparsable, None);
+  };
+  let canonical: String = credential.to_jcs().unwrap();
+  let outcome: Outcome = match JwtCredentialValidatorUtils::check_structure::<Object>(&credential) {
+    Ok(()) => Outcome::Valid,
+    Err(_) => Outcome::Invalid,
+  };
+
+  (outcome, Some(canonical))
+}
+
+fn run_document(input: &Value) -> (Outcome, Option<String>) {
+  match CoreDocument::from_json_value(input.clone()) {
+    Ok(document) => (Outcome::Valid, Some(document.to_jcs().unwrap())),
+    Err(_) => (Outcome::Unparsable, None),
+  }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+  std::env::var(name)
+    .ok()
+    .map(|value| value.parse().unwrap_or_else(|_| panic!("invalid value for {name}")))
+    .unwrap_or(default)
+}
+
+// ===========================================================================
+// Strategies
+// ===========================================================================
+
+/// Arbitrary JSON values, restricted to numbers JavaScript represents exactly.
+fn json_value() -> impl Strategy<Value = Value> {
+  let leaf = prop_oneof![
+    Just(Value::Null),
+    any::<bool>().prop_map(Value::from),
+    (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).prop_map(Value::from),
+    any::<f64>()
+      .prop_filter("finite", |n| n.is_finite())
+      .prop_map(Value::from),
+    any::<String>().prop_map(Value::from),
+  ];
+
+  leaf.prop_recursive(3, 24, 4, |inner| {
+    prop_oneof![
+      vec(inner.clone(), 0..4).prop_map(Value::from),
+      btree_map(any::<String>(), inner, 0..4).prop_map(|map| Value::Object(map.into_iter().collect())),
+    ]
+  })
+}
+
+fn did() -> impl Strategy<Value = String> {
+  prop_oneof![
+    "[0-9a-f]{64}".prop_map(|tag| format!("did:iota:0x{tag}")),
+    "[a-zA-Z0-9]{1,16}".prop_map(|id| format!("did:example:{id}")),
+  ]
+}
+
+fn timestamp() -> impl Strategy<Value = String> {
+  (0..253_402_300_799i64).prop_map(|seconds| Timestamp::from_unix(seconds).unwrap().to_string())
+}
+
+fn properties() -> impl Strategy<Value = Map<String, Value>> {
+  btree_map("[a-zA-Z][a-zA-Z0-9_]{0,12}", json_value(), 0..4).prop_map(|map| map.into_iter().collect())
+}
+
+fn credential() -> impl Strategy<Value = Value> {
+  let issuer = prop_oneof![
+    did().prop_map(Value::from),
+    (did(), any::<String>()).prop_map(|(id, name)| json!({ "id": id, "name": name })),
+  ];
+  let subject = (option::of(did()), properties()).prop_map(|(id, mut claims)| {
+    if let Some(id) = id {
+      claims.insert("id".to_owned(), id.into());
+    }
+    Value::Object(claims)
+  });
+  let subjects = prop_oneof![subject.clone(), vec(subject, 1..3).prop_map(Value::from)];
+
+  (
+    option::of("[a-z]{1,8}".prop_map(|id| format!("https://example.com/credentials/{id}"))),
+    vec("[A-Z][a-zA-Z]{0,16}", 0..3),
+    issuer,
+    timestamp(),
+    option::of(timestamp()),
+    subjects,
+    properties(),
+  )
+    .prop_map(
+      |(id, types, issuer, issuance_date, expiration_date, subjects, properties)| {
+        let mut credential: Map<String, Value> = properties;
+        credential.insert("@context".to_owned(), "https://www.w3.org/2018/credentials/v1".into());
+        if let Some(id) = id {
+          credential.insert("id".to_owned(), id.into());
+        }
+        let types: Vec<String> = std::iter::once("VerifiableCredential".to_owned())
+          .chain(types)
+          .collect();
+        credential.insert("type".to_owned(), types.into());
+        credential.insert("issuer".to_owned(), issuer);
+        credential.insert("issuanceDate".to_owned(), issuance_date.into());
+        if let Some(expiration_date) = expiration_date {
+          credential.insert("expirationDate".to_owned(), expiration_date.into());
+        }
+        credential.insert("credentialSubject".to_owned(), subjects);
+        Value::Object(credential)
+      },
+    )
+}
+
+fn document() -> impl Strategy<Value = Value> {
+  let method = (
+    "[a-z][a-z0-9]{0,8}",
+    prop_oneof![Just("JsonWebKey2020"), Just("Ed25519VerificationKey2018")],
+    "[a-zA-Z0-9_-]{43}",
+  );
+  let service = (
+    "[a-z][a-z0-9]{0,8}",
+    "[A-Z][a-zA-Z]{0,16}",
+    "[a-z]{1,12}".prop_map(|host| format!("https://{host}.example.com/")),
+  );
+
+  (did(), vec(method, 0..4), vec(service, 0..3), properties()).prop_map(|(did, methods, services, properties)| {
+    let mut document: Map<String, Value> = properties;
+    document.insert("id".to_owned(), did.clone().into());
+    let methods: Vec<Value> = methods
+      .into_iter()
+      .map(|(fragment, method_type, x)| {
+        let key: Value = match method_type {
+          "JsonWebKey2020" => json!({ "publicKeyJwk": { "kty": "OKP", "crv": "Ed25519", "x": x } }),
+          _ => json!({ "publicKeyMultibase": format!("z{x}") }),
+        };
+        let mut method = json!({ "id": format!("{did}#{fragment}"), "controller": did, "type": method_type });
+        method.as_object_mut().unwrap().extend(key.as_object().unwrap().clone());
+        method
+      })
+      .collect();
+    if let Some(first) = methods.first() {
+      document.insert("authentication".to_owned(), vec![first["id"].clone()].into());
+    }
+    document.insert("verificationMethod".to_owned(), methods.into());
+    let services: Vec<Value> = services
+      .into_iter()
+      .map(|(fragment, service_type, endpoint)| {
+        json!({ "id": format!("{did}#{fragment}"), "type": service_type, "serviceEndpoint": endpoint })
+      })
+      .collect();
+    document.insert("service".to_owned(), services.into());
+    Value::Object(document)
+  })
+}
+
+/// Replaces, removes or duplicates a random property of `input`, mostly turning it malformed.
+fn mutated(input: impl Strategy<Value = Value>) -> impl Strategy<Value = Value> {
+  (input, any::<prop::sample::Index>(), 0..3u8, json_value()).prop_map(|(mut input, index, mutation, value)| {
+    let object: &mut Map<String, Value> = input.as_object_mut().unwrap();
+    let key: String = object.keys().nth(index.index(object.len())).unwrap().clone();
+    match mutation {
+      0 => object[&key] = value,
+      1 => {
+        object.remove(&key);
+      }
+      _ => {
+        let duplicated: Vec<Value> = match object[&key].clone() {
+          Value::Array(values) => values.iter().chain(values.first()).cloned().collect(),
+          value => vec![value.clone(), value],
+        };
+        object[&key] = duplicated.into();
+      }
+    }
+    input
+  })
+}
+
+fn test_vector() -> impl Strategy<Value = TestVector> {
+  prop_oneof![
+    3 => credential().prop_map(|input| TestVector::new(Kind::Credential, input)),
+    2 => mutated(credential()).prop_map(|input| TestVector::new(Kind::Credential, input)),
+    3 => document().prop_map(|input| TestVector::new(Kind::Document, input)),
+    2 => mutated(document()).prop_map(|input| TestVector::new(Kind::Document, input)),
+  ]
+}
+
+/// The credentials found among this crate's test fixtures.
+fn fixture_vectors() -> Vec<TestVector> {
+  let fixtures: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+  let mut paths: Vec<PathBuf> = std::fs::read_dir(fixtures)
+    .unwrap()
+    .map(|entry| entry.unwrap().path())
+    .filter(|path| {
+      path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("credential-"))
+    })
+    .collect();
+  paths.sort();
+
+  paths
+    .into_iter()
+    .map(|path| {
+      let input: Value = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+      TestVector::new(Kind::Credential, input)
+    })
+    .collect()
+}
+
+fn generate_vectors(seed: u64, cases: usize) -> Vec<TestVector> {
+  let mut seed_bytes = [0; 32];
+  seed_bytes[..8].copy_from_slice(&seed.to_le_bytes());
+  let mut runner = TestRunner::new_with_rng(Config::default(), TestRng::from_seed(RngAlgorithm::ChaCha, &seed_bytes));
+  let strategy = test_vector();
+
+  let mut vectors: Vec<TestVector> = fixture_vectors();
+  vectors.extend((0..cases).map(|_| strategy.new_tree(&mut runner).unwrap().current()));
+  vectors
+}
+
+// ===========================================================================
+// Tests
+// ===========================================================================
+
+#[test]
+fn generation_is_deterministic() {
+  let seed: u64 = env_or("IDENTITY_DIFFERENTIAL_SEED", DEFAULT_SEED);
+  let first: Vec<TestVector> = generate_vectors(seed, 16);
+  let second: Vec<TestVector> = generate_vectors(seed, 16);
+
+  assert_eq!(first.to_json().unwrap(), second.to_json().unwrap());
+}
+
+#[test]
+fn canonical_serialization_round_trips() {
+  let seed: u64 = env_or("IDENTITY_DIFFERENTIAL_SEED", DEFAULT_SEED);
+  let cases: usize = env_or("IDENTITY_DIFFERENTIAL_CASES", DEFAULT_CASES);
+  let vectors: Vec<TestVector> = generate_vectors(seed, cases);
+
+  for (index, vector) in vectors.iter().enumerate() {
+    let Some(canonical) = &vector.canonical else {
+      continue;
+    };
+    let input: Value = serde_json::from_str(canonical).unwrap();
+    let round_trip = TestVector::new(vector.kind, input);
+    assert_eq!(
+      (round_trip.outcome, round_trip.canonical.as_ref()),
+      (vector.outcome, Some(canonical)),
+      "test vector {index} (seed {seed}) is not stable under canonicalization"
+    );
+  }
+
+  // Make sure both well-formed and malformed inputs are exercised.
+  for outcome in [Outcome::Unparsable, Outcome::Valid] {
+    assert!(vectors.iter().any(|vector| vector.outcome == outcome));
+  }
+
+  if let Some(path) = std::env::var_os("IDENTITY_DIFFERENTIAL_VECTORS").map(PathBuf::from) {
+    if let Some(parent) = path.parent() {
+      std::fs::create_dir_all(parent).unwrap();
+    }
+    std::fs::write(path, vectors.to_json_pretty().unwrap()).unwrap();
+  }
+}
@@ -0,0 +1,324 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Derive macros for `identity_credential`.
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms, unreachable_pub, missing_docs, rustdoc::missing_crate_level_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+use syn::Attribute;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Expr;
+use syn::ExprLit;
+use syn::Fields;
+use syn::Lit;
+use syn::LitStr;
+use syn::Meta;
+use syn::Path;
+use syn::Token;
+
+/// Derives `ClaimSchema` and `CredentialSubjectSchema` for a struct with named fields.
+///
+/// The generated JSON Schema and claim paths follow the struct's `serde` representation: the
+/// `rename`, `rename_all`, `skip`, `skip_serializing`, `skip_serializing_if` and `default` attributes
+/// are honored, while `flatten` is not supported. Doc comments on the struct and its fields become
+/// the schema's descriptions.
+///
+/// For every claim, an associated constant `CLAIM_<FIELD>` containing the claim's JSON pointer -
+/// relative to the credential subject - is generated as well.
+///
+/// The path to the `credential` module of `identity_credential` can be overridden with
+/// `#[credential_subject(crate = "identity_iota::credential")]`.
+#[proc_macro_derive(CredentialSubjectSchema, attributes(credential_subject))]
+pub fn derive_credential_subject_schema(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+  let Data::Struct(data) = &input.data else {
+    return Err(syn::Error::new(
+      input.span(),
+      "CredentialSubjectSchema can only be derived for structs",
+    ));
+  };
+  let Fields::Named(fields) = &data.fields else {
+    return Err(syn::Error::new(
+      data.fields.span(),
+      "CredentialSubjectSchema can only be derived for structs with named fields",
+    ));
+  };
+  if !input.generics.params.is_empty() {
+    return Err(syn::Error::new(
+      input.generics.span(),
+      "CredentialSubjectSchema cannot be derived for generic structs",
+    ));
+  }
+
+  let container = ContainerAttributes::parse(&input.attrs)?;
+  let krate = container.krate;
+  let ident = &input.ident;
+  let vis = &input.vis;
+  let title = ident.to_string();
+  let description = doc_comment(&input.attrs).map(|description| quote! { .description(#description) });
+
+  let mut properties = Vec::new();
+  let mut claim_constants = Vec::new();
+  let mut claim_paths = Vec::new();
+  for field in &fields.named {
+    let attributes = FieldAttributes::parse(&field.attrs)?;
+    if attributes.skip {
+      continue;
+    }
+
+    let field_ident = field.ident.as_ref().expect("named field");
+    let field_name = field_ident.to_string();
+    let field_name = field_name.strip_prefix("r#").unwrap_or(&field_name);
+    let claim = match attributes.rename {
+      Some(rename) => rename,
+      None => container.rename_all.apply(field_name),
+    };
+
+    let ty = &field.ty;
+    let description = option_tokens(doc_comment(&field.attrs).as_deref());
+    properties.push(if attributes.optional {
+      quote! { .optional_property::<#ty>(#claim, #description) }
+    } else {
+      quote! { .property::<#ty>(#claim, #description) }
+    });
+
+    let path = json_pointer(&claim);
+    let constant = format_ident!("CLAIM_{}", field_name.to_uppercase());
+    let constant_doc = format!("JSON pointer of the `{claim}` claim, relative to the credential subject.");
+    claim_constants.push(quote! {
+      #[doc = #constant_doc]
+      #vis const #constant: &'static str = #path;
+    });
+    claim_paths.push(path);
+  }
+
+  Ok(quote! {
+    impl #krate::ClaimSchema for #ident {
+      fn json_schema() -> #krate::__private::Value {
+        #krate::ObjectSchemaBuilder::new()
+          .title(#title)
+          #description
+          #(#properties)*
+          .build()
+      }
+    }
+
+    impl #krate::CredentialSubjectSchema for #ident {
+      const CLAIM_PATHS: &'static [&'static str] = &[#(#claim_paths),*];
+    }
+
+    impl #ident {
+      #(#claim_constants)*
+    }
+  })
+}
+
+/// Options of the struct being derived.
+struct ContainerAttributes {
+  /// Path to `identity_credential`'s `credential` module.
+  krate: Path,
+  /// `serde(rename_all)` rule.
+  rename_all: RenameRule,
+}
+
+impl ContainerAttributes {
+  fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+    let mut krate = None;
+    let mut rename_all = RenameRule::None;
+    for attr in attrs {
+      if attr.path().is_ident("credential_subject") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("crate") {
+            krate = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+            Ok(())
+          } else {
+            Err(meta.error("unsupported credential_subject attribute"))
+          }
+        })?;
+      } else if attr.path().is_ident("serde") {
+        attr.parse_nested_meta(|meta| {
+          if meta.path.is_ident("rename_all") {
+            let rule = serialize_name(&meta)?;
+            rename_all = RenameRule::from_lit(&rule)?;
+            Ok(())
+          } else {
+            skip_meta(&meta)
+          }
+        })?;
+      }
+    }
+
+    Ok(Self {
+      krate: krate.unwrap_or_else(|| syn::parse_quote!(::identity_credential::credential)),
+      rename_all,
+    })
+  }
+}
+
+/// Options of a field of the struct being derived.
+#[derive(Default)]
+struct FieldAttributes {
+  /// `serde(rename)`.
+  rename: Option<String>,
+  /// `serde(skip)` or `serde(skip_serializing)`.
+  skip: bool,
+  /// `serde(default)` or `serde(skip_serializing_if)`.
+  optional: bool,
+}
+
+impl FieldAttributes {
+  fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+    let mut attributes = Self::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+      attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("rename") {
+          attributes.rename = Some(serialize_name(&meta)?.value());
+          Ok(())
+        } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+          attributes.skip = true;
+          Ok(())
+        } else if meta.path.is_ident("default") || meta.path.is_ident("skip_serializing_if") {
+          attributes.optional = true;
+          skip_meta(&meta)
+        } else if meta.path.is_ident("flatten") {
+          Err(meta.error("CredentialSubjectSchema doesn't support flattened fields"))
+        } else {
+          skip_meta(&meta)
+        }
+      })?;
+    }
+
+    Ok(attributes)
+  }
+}
+
+/// The `serde(rename_all)` rules.
+enum RenameRule {
+  None,
+  LowerCase,
+  UpperCase,
+  PascalCase,
+  CamelCase,
+  SnakeCase,
+  ScreamingSnakeCase,
+  KebabCase,
+  ScreamingKebabCase,
+}
+
+impl RenameRule {
+  fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+    match lit.value().as_str() {
+      "lowercase" => Ok(Self::LowerCase),
+      "UPPERCASE" => Ok(Self::UpperCase),
+      "PascalCase" => Ok(Self::PascalCase),
+      "camelCase" => Ok(Self::CamelCase),
+      "snake_case" => Ok(Self::SnakeCase),
+      "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+      "kebab-case" => Ok(Self::KebabCase),
+      "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebabCase),
+      _ => Err(syn::Error::new(lit.span(), "unknown rename rule")),
+    }
+  }
+
+  /// Applies this rule to a `snake_case` field name.
+  fn apply(&self, field: &str) -> String {
+    match self {
+      Self::None | Self::SnakeCase => field.to_owned(),
+      Self::LowerCase => field.to_ascii_lowercase(),
+      Self::UpperCase | Self::ScreamingSnakeCase => field.to_ascii_uppercase(),
+      Self::PascalCase => field
+        .split('_')
+        .map(|word| {
+          let mut chars = word.chars();
+          chars
+            .next()
+            .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+            .unwrap_or_default()
+        })
+        .collect(),
+      Self::CamelCase => {
+        let pascal = Self::PascalCase.apply(field);
+        let mut chars = pascal.chars();
+        chars
+          .next()
+          .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+          .unwrap_or_default()
+      }
+      Self::KebabCase => field.replace('_', "-"),
+      Self::ScreamingKebabCase => field.replace('_', "-").to_ascii_uppercase(),
+    }
+  }
+}
+
+/// Parses either `name = "..."` or `name(serialize = "...")`.
+fn serialize_name(meta: &ParseNestedMeta<'_>) -> syn::Result<LitStr> {
+  if meta.input.peek(Token![=]) {
+    return meta.value()?.parse();
+  }
+
+  let mut name = None;
+  meta.parse_nested_meta(|inner| {
+    if inner.path.is_ident("serialize") {
+      name = Some(inner.value()?.parse()?);
+      Ok(())
+    } else {
+      skip_meta(&inner)
+    }
+  })?;
+  name.ok_or_else(|| meta.error("missing `serialize` name"))
+}
+
+/// Consumes a `serde` attribute that is irrelevant to the schema.
+fn skip_meta(meta: &ParseNestedMeta<'_>) -> syn::Result<()> {
+  if meta.input.peek(Token![=]) {
+    meta.value()?.parse::<Expr>()?;
+  } else if meta.input.peek(syn::token::Paren) {
+    meta.parse_nested_meta(|inner| skip_meta(&inner))?;
+  }
+
+  Ok(())
+}
+
+/// Joins the lines of an item's doc comment.
+fn doc_comment(attrs: &[Attribute]) -> Option<String> {
+  let lines: Vec<String> = attrs
+    .iter()
+    .filter_map(|attr| match &attr.meta {
+      Meta::NameValue(name_value) if name_value.path.is_ident("doc") => match &name_value.value {
+        Expr::Lit(ExprLit {
+          lit: Lit::Str(line), ..
+        }) => Some(line.value().trim().to_owned()),
+        _ => None,
+      },
+      _ => None,
+    })
+    .collect();
+  let doc = lines.join("\n").trim().to_owned();
+
+  (!doc.is_empty()).then_some(doc)
+}
+
+fn option_tokens(value: Option<&str>) -> TokenStream2 {
+  match value {
+    Some(value) => quote! { ::core::option::Option::Some(#value) },
+    None => quote! { ::core::option::Option::None },
+  }
+}
+
+/// Encodes `claim` as a single-segment JSON pointer (RFC 6901).
+fn json_pointer(claim: &str) -> String {
+  format!("/{}", claim.replace('~', "~0").replace('/', "~1"))
+}
@@ -0,0 +1,62 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_credential::credential::CredentialSubjectSchema;
+use identity_credential::credential::Subject;
+use identity_credential::credential::JSON_SCHEMA_DIALECT;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+/// A university degree.
+#[derive(Debug, PartialEq, Serialize, Deserialize, CredentialSubjectSchema)]
+#[serde(rename_all = "camelCase")]
+struct Degree {
+  /// The name of the degree.
+  degree_name: String,
+  graduation_year: Option<u16>,
+  #[serde(rename = "type", default)]
+  kind: Vec<String>,
+  #[serde(skip)]
+  internal_note: String,
+}
+
+#[test]
+fn generates_json_schema() {
+  let expected = json!({
+    "$schema": JSON_SCHEMA_DIALECT,
+    "title": "Degree",
+    "description": "A university degree.",
+    "type": "object",
+    "properties": {
+      "degreeName": { "type": "string", "description": "The name of the degree." },
+      "graduationYear": { "anyOf": [{ "type": "integer", "minimum": 0 }, { "type": "null" }] },
+      "type": { "type": "array", "items": { "type": "string" } },
+    },
+    "required": ["degreeName"],
+  });
+
+  assert_eq!(Degree::subject_json_schema(), expected);
+}
+
+#[test]
+fn generates_claim_paths() {
+  assert_eq!(Degree::CLAIM_DEGREE_NAME, "/degreeName");
+  assert_eq!(Degree::CLAIM_GRADUATION_YEAR, "/graduationYear");
+  assert_eq!(Degree::CLAIM_KIND, "/type");
+  assert_eq!(Degree::CLAIM_PATHS, &["/degreeName", "/graduationYear", "/type"]);
+}
+
+#[test]
+fn subject_roundtrip() {
+  let degree = Degree {
+    degree_name: "Bachelor of Science".to_owned(),
+    graduation_year: Some(2024),
+    kind: vec!["BachelorDegree".to_owned()],
+    internal_note: String::new(),
+  };
+
+  let subject: Subject = degree.to_subject().unwrap();
+  assert_eq!(subject.properties["degreeName"], "Bachelor of Science");
+  assert_eq!(Degree::from_subject(&subject).unwrap(), degree);
+}
@@ -0,0 +1,229 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+
+use identity_core::convert::BaseEncoding;
+use identity_jose::jwk::EcxCurve;
+use identity_jose::jwk::EdCurve;
+use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParamsOkp;
+use identity_jose::jwu::decode_b64;
+use identity_jose::jwu::encode_b64;
+
+use crate::CoreDID;
+use crate::Error;
+use crate::DID;
+
+/// Multicodec prefix of Ed25519 public keys, as an unsigned varint.
+const ED25519_PUB: [u8; 2] = [0xed, 0x01];
+/// Multicodec prefix of X25519 public keys, as an unsigned varint.
+const X25519_PUB: [u8; 2] = [0xec, 0x01];
+/// Length of both Ed25519 and X25519 public keys.
+const KEY_LEN: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(into = "CoreDID", try_from = "CoreDID")]
+/// A type representing a `did:key` DID.
+///
+/// Only Ed25519 and X25519 public keys are supported.
+pub struct DIDKey {
+  did: CoreDID,
+  jwk: Jwk,
+}
+
+impl DIDKey {
+  /// [`DIDKey`]'s method.
+  pub const METHOD: &'static str = "key";
+
+  /// Creates a new [DIDKey] from the given public [Jwk].
+  ///
+  /// # Errors
+  /// Fails if `jwk` is neither an Ed25519 nor an X25519 public key.
+  pub fn new(jwk: impl Into<Jwk>) -> Result<Self, Error> {
+    let jwk = jwk.into();
+    let unsupported = || Error::Other("did:key only supports Ed25519 and X25519 public keys");
+    let params = jwk.try_okp_params().map_err(|_| unsupported())?;
+    let prefix = match params.crv.as_str() {
+      crv if crv == EdCurve::Ed25519.name() => ED25519_PUB,
+      crv if crv == EcxCurve::X25519.name() => X25519_PUB,
+      _ => return Err(unsupported()),
+    };
+    let key = decode_b64(&params.x).map_err(|_| Error::Other("invalid public key"))?;
+    if key.len() != KEY_LEN {
+      return Err(Error::Other("invalid public key"));
+    }
+
+    let multicodec: Vec<u8> = prefix.iter().chain(key.iter()).copied().collect();
+    let did = format!("did:key:{}", BaseEncoding::encode_multibase(&multicodec, None))
+      .parse()
+      .expect("valid CoreDID");
+
+    Ok(Self {
+      did,
+      jwk: public_jwk(params.crv.clone(), params.x.clone()),
+    })
+  }
+
+  /// Tries to parse a [`DIDKey`] from a string.
+  pub fn parse(s: &str) -> Result<Self, Error> {
+    s.parse()
+  }
+
+  /// Returns the public key encoded inside this did:key, as a JWK.
+  pub fn jwk(&self) -> Jwk {
+    self.jwk.clone()
+  }
+
+  /// Returns a reference to the [Jwk] encoded inside this did:key.
+  pub fn as_jwk(&self) -> &Jwk {
+    &self.jwk
+  }
+
+  /// Returns whether the key encoded inside this did:key is meant for key agreement rather than signing.
+  pub fn is_key_agreement_key(&self) -> bool {
+    self
+      .jwk
+      .try_okp_params()
+      .is_ok_and(|params| params.crv == EcxCurve::X25519.name())
+  }
+}
+
+fn public_jwk(crv: String, x: String) -> Jwk {
+  Jwk::from_params(JwkParamsOkp { crv, x, d: None })
+}
+
+impl Ord for DIDKey {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.did.cmp(&other.did)
+  }
+}
+
+impl PartialOrd for DIDKey {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Hash for DIDKey {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    self.did.hash(state)
+  }
+}
+
+impl AsRef<CoreDID> for DIDKey {
+  fn as_ref(&self) -> &CoreDID {
+    &self.did
+  }
+}
+
+impl AsRef<Jwk> for DIDKey {
+  fn as_ref(&self) -> &Jwk {
+    &self.jwk
+  }
+}
+
+impl From<DIDKey> for CoreDID {
+  fn from(value: DIDKey) -> Self {
+    value.did
+  }
+}
+
+impl<'a> TryFrom<&'a str> for DIDKey {
+  type Error = Error;
+  fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl Display for DIDKey {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.did)
+  }
+}
+
+impl FromStr for DIDKey {
+  type Err = Error;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    s.parse::<CoreDID>().and_then(TryFrom::try_from)
+  }
+}
+
+impl From<DIDKey> for String {
+  fn from(value: DIDKey) -> Self {
+    value.to_string()
+  }
+}
+
+impl From<DIDKey> for Jwk {
+  fn from(value: DIDKey) -> Self {
+    value.jwk
+  }
+}
+
+impl TryFrom<CoreDID> for DIDKey {
+  type Error = Error;
+  fn try_from(value: CoreDID) -> Result<Self, Self::Error> {
+    let Self::METHOD = value.method() else {
+      return Err(Error::InvalidMethodName);
+    };
+    // Only the base58-btc multibase encoding is allowed.
+    if !value.method_id().starts_with('z') {
+      return Err(Error::InvalidMethodId);
+    }
+    let multicodec = BaseEncoding::decode_multibase(value.method_id()).map_err(|_| Error::InvalidMethodId)?;
+    let (crv, key) = match multicodec.get(..ED25519_PUB.len()) {
+      Some(prefix) if prefix == ED25519_PUB => (EdCurve::Ed25519.name(), &multicodec[ED25519_PUB.len()..]),
+      Some(prefix) if prefix == X25519_PUB => (EcxCurve::X25519.name(), &multicodec[X25519_PUB.len()..]),
+      _ => return Err(Error::InvalidMethodId),
+    };
+    if key.len() != KEY_LEN {
+      return Err(Error::InvalidMethodId);
+    }
+
+    Ok(Self {
+      did: value,
+      jwk: public_jwk(crv.to_owned(), encode_b64(key)),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // Test vectors from the did:key specification.
+  const ED25519_DID: &str = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp";
+  const X25519_DID: &str = "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F";
+
+  #[test]
+  fn test_valid_deserialization() -> Result<(), Error> {
+    let did = DIDKey::parse(ED25519_DID)?;
+    assert_eq!(did.as_jwk().try_okp_params().unwrap().crv, "Ed25519");
+    assert!(!did.is_key_agreement_key());
+
+    let did = DIDKey::parse(X25519_DID)?;
+    assert_eq!(did.as_jwk().try_okp_params().unwrap().crv, "X25519");
+    assert!(did.is_key_agreement_key());
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_roundtrip() {
+    let did = DIDKey::parse(ED25519_DID).unwrap();
+    assert_eq!(DIDKey::new(did.jwk()).unwrap(), did);
+  }
+
+  #[test]
+  fn test_invalid_did_key() {
+    assert!(DIDKey::parse("did:jwk:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp").is_err());
+    // Not base58-btc.
+    assert!(DIDKey::parse("did:key:m7QEJ").is_err());
+    // Truncated key.
+    assert!(DIDKey::parse("did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDoo").is_err());
+  }
+}
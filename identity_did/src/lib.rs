@@ -20,6 +20,7 @@
 mod did;
 mod did_compositejwk;
 mod did_jwk;
+mod did_key;
 mod did_url;
 mod error;
 
@@ -30,4 +31,5 @@ pub use did::CoreDID;
 pub use did::DID;
 pub use did_compositejwk::*;
 pub use did_jwk::*;
+pub use did_key::*;
 pub use error::Error;
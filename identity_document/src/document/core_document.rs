@@ -4,14 +4,21 @@
 use core::convert::TryInto as _;
 use core::fmt::Display;
 use core::fmt::Formatter;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::convert::Infallible;
 
 use identity_did::DIDCompositeJwk;
 use identity_did::DIDJwk;
+use identity_did::DIDKey;
+use identity_verification::jose::jwk::EcCurve;
 use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkOperation;
+use identity_verification::jose::jwk::JwkType;
+use identity_verification::jose::jwk::JwkUse;
 use identity_verification::jose::jws::DecodedJws;
 use identity_verification::jose::jws::Decoder;
+use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jose::jws::JwsVerifier;
 use serde::Serialize;
 
@@ -31,6 +38,7 @@ use crate::utils::Queryable;
 use crate::verifiable::JwsVerificationOptions;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
+use identity_verification::MethodData;
 use identity_verification::MethodRef;
 use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
@@ -265,7 +273,28 @@ impl CoreDocument {
   }
 
   /// Returns a new `CoreDocument` based on the [`DocumentBuilder`] configuration.
+  ///
+  /// # Errors
+  /// Besides the document's invariants, fails with [`Error::InvalidKeyMaterial`] if any of its verification
+  /// methods embeds private key material.
   pub fn from_builder(builder: DocumentBuilder) -> Result<Self> {
+    let embedded_methods = builder
+      .authentication
+      .iter()
+      .chain(&builder.assertion_method)
+      .chain(&builder.key_agreement)
+      .chain(&builder.capability_delegation)
+      .chain(&builder.capability_invocation)
+      .filter_map(|method_ref| match method_ref {
+        MethodRef::Embed(method) => Some(method),
+        MethodRef::Refer(_) => None,
+      });
+    builder
+      .verification_method
+      .iter()
+      .chain(embedded_methods)
+      .try_for_each(check_public_key_material)?;
+
     Self::try_from(CoreDocumentData {
       id: builder.id.ok_or(Error::InvalidDocument("missing id", None))?,
       controller: Some(builder.controller)
@@ -408,8 +437,11 @@ impl CoreDocument {
   ///
   /// # Errors
   ///
-  /// Returns an error if a method or service with the same fragment already exists.
+  /// Returns an error if a method or service with the same fragment already exists, or if the method embeds
+  /// private key material.
   pub fn insert_method(&mut self, method: VerificationMethod, scope: MethodScope) -> Result<()> {
+    check_public_key_material(&method)?;
+
     // Check that the method identifier is not already in use by an existing method or service.
     //
     // NOTE: this check cannot be relied upon if the document contains methods or services whose ids are
@@ -544,27 +576,38 @@ impl CoreDocument {
   where
     Q: Into<DIDUrlQuery<'query>>,
   {
-    let method_query: DIDUrlQuery<'query> = method_query.into();
+    let method_id: DIDUrl = self.general_purpose_method_id(method_query.into())?;
+    let was_attached = self.relationship_mut(relationship).append(MethodRef::Refer(method_id));
 
-    match self.resolve_method(method_query.clone(), Some(MethodScope::VerificationMethod)) {
-      None => match self.resolve_method(method_query, None) {
-        Some(_) => Err(Error::InvalidMethodEmbedded),
-        None => Err(Error::MethodNotFound),
-      },
-      Some(method) => {
-        let method_ref = MethodRef::Refer(method.id().clone());
-
-        let was_attached = match relationship {
-          MethodRelationship::Authentication => self.data.authentication.append(method_ref),
-          MethodRelationship::AssertionMethod => self.data.assertion_method.append(method_ref),
-          MethodRelationship::KeyAgreement => self.data.key_agreement.append(method_ref),
-          MethodRelationship::CapabilityDelegation => self.data.capability_delegation.append(method_ref),
-          MethodRelationship::CapabilityInvocation => self.data.capability_invocation.append(method_ref),
-        };
-
-        Ok(was_attached)
-      }
-    }
+    Ok(was_attached)
+  }
+
+  /// Attaches all of the given `relationships` to the method resolved by `method_query`.
+  /// Returns the relationships that were not already attached.
+  ///
+  /// # Errors
+  ///
+  /// Same as [`Self::attach_method_relationship`]; the document is left unchanged on error.
+  pub fn attach_method_relationships<'query, Q, I>(
+    &mut self,
+    method_query: Q,
+    relationships: I,
+  ) -> Result<Vec<MethodRelationship>>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    let method_id: DIDUrl = self.general_purpose_method_id(method_query.into())?;
+    let attached = relationships
+      .into_iter()
+      .filter(|relationship| {
+        self
+          .relationship_mut(*relationship)
+          .append(MethodRef::Refer(method_id.clone()))
+      })
+      .collect();
+
+    Ok(attached)
   }
 
   /// Detaches the relationship from the method resolved by `method_query`.
@@ -584,28 +627,81 @@ impl CoreDocument {
     method_query: Q,
     relationship: MethodRelationship,
   ) -> Result<bool>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    let method_id: DIDUrl = self.general_purpose_method_id(method_query.into())?;
+    let was_detached = self.relationship_mut(relationship).remove(&method_id);
+
+    Ok(was_detached.is_some())
+  }
+
+  /// Detaches all of the given `relationships` from the method resolved by `method_query`.
+  /// Returns the relationships that were found and removed.
+  ///
+  /// # Errors
+  ///
+  /// Same as [`Self::detach_method_relationship`]; the document is left unchanged on error.
+  pub fn detach_method_relationships<'query, Q, I>(
+    &mut self,
+    method_query: Q,
+    relationships: I,
+  ) -> Result<Vec<MethodRelationship>>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    let method_id: DIDUrl = self.general_purpose_method_id(method_query.into())?;
+    let detached = relationships
+      .into_iter()
+      .filter(|relationship| self.relationship_mut(*relationship).remove(&method_id).is_some())
+      .collect();
+
+    Ok(detached)
+  }
+
+  /// Returns the verification relationships referencing or embedding the method resolved by `method_query`.
+  ///
+  /// Relationships are returned in the order in which they are defined in the DID specification.
+  pub fn method_relationships<'query, Q>(&self, method_query: Q) -> Vec<MethodRelationship>
   where
     Q: Into<DIDUrlQuery<'query>>,
   {
     let method_query: DIDUrlQuery<'query> = method_query.into();
+    METHOD_RELATIONSHIPS
+      .into_iter()
+      .filter(|relationship| self.relationship(*relationship).query(method_query.clone()).is_some())
+      .collect()
+  }
+
+  /// Returns the id of the general purpose verification method resolved by `method_query`.
+  fn general_purpose_method_id(&self, method_query: DIDUrlQuery<'_>) -> Result<DIDUrl> {
     match self.resolve_method(method_query.clone(), Some(MethodScope::VerificationMethod)) {
+      Some(method) => Ok(method.id().clone()),
       None => match self.resolve_method(method_query, None) {
         Some(_) => Err(Error::InvalidMethodEmbedded),
         None => Err(Error::MethodNotFound),
       },
-      Some(method) => {
-        let did_url: DIDUrl = method.id().clone();
-
-        let was_detached = match relationship {
-          MethodRelationship::Authentication => self.data.authentication.remove(&did_url),
-          MethodRelationship::AssertionMethod => self.data.assertion_method.remove(&did_url),
-          MethodRelationship::KeyAgreement => self.data.key_agreement.remove(&did_url),
-          MethodRelationship::CapabilityDelegation => self.data.capability_delegation.remove(&did_url),
-          MethodRelationship::CapabilityInvocation => self.data.capability_invocation.remove(&did_url),
-        };
-
-        Ok(was_detached.is_some())
-      }
+    }
+  }
+
+  fn relationship(&self, relationship: MethodRelationship) -> &OrderedSet<MethodRef> {
+    match relationship {
+      MethodRelationship::Authentication => &self.data.authentication,
+      MethodRelationship::AssertionMethod => &self.data.assertion_method,
+      MethodRelationship::KeyAgreement => &self.data.key_agreement,
+      MethodRelationship::CapabilityDelegation => &self.data.capability_delegation,
+      MethodRelationship::CapabilityInvocation => &self.data.capability_invocation,
+    }
+  }
+
+  fn relationship_mut(&mut self, relationship: MethodRelationship) -> &mut OrderedSet<MethodRef> {
+    match relationship {
+      MethodRelationship::Authentication => &mut self.data.authentication,
+      MethodRelationship::AssertionMethod => &mut self.data.assertion_method,
+      MethodRelationship::KeyAgreement => &mut self.data.key_agreement,
+      MethodRelationship::CapabilityDelegation => &mut self.data.capability_delegation,
+      MethodRelationship::CapabilityInvocation => &mut self.data.capability_invocation,
     }
   }
 
@@ -647,6 +743,28 @@ impl CoreDocument {
     }
   }
 
+  /// Returns the verification methods with the verification relationship `purpose` whose public key can verify
+  /// signatures created with `alg`, in the order they appear in the document.
+  ///
+  /// A method is a candidate when its public key can be expressed as a [`Jwk`] - see
+  /// [`VerificationMethod::public_key_as_jwk`] - which:
+  /// - declares `alg` as its algorithm, or declares no algorithm but has a key type and curve suitable for `alg`;
+  /// - is not restricted to another use than signing, nor to operations not including verification.
+  ///
+  /// Signing code should pick a method from the candidates rather than hard-coding a fragment, so that it keeps
+  /// working when keys are rotated.
+  pub fn signing_candidates(&self, purpose: MethodRelationship, alg: JwsAlgorithm) -> Vec<&VerificationMethod> {
+    self
+      .methods(Some(MethodScope::VerificationRelationship(purpose)))
+      .into_iter()
+      .filter(|method| {
+        method
+          .public_key_as_jwk()
+          .is_ok_and(|jwk| is_jwk_usable_for_signing_with(&jwk, &alg))
+      })
+      .collect()
+  }
+
   /// Returns an iterator over all embedded verification methods in the DID Document.
   ///
   /// This excludes verification methods that are referenced by the DID Document.
@@ -973,15 +1091,14 @@ impl CoreDocument {
         .into(),
     };
 
-    let public_key: &Jwk = self
+    let public_key: Cow<'_, Jwk> = self
       .resolve_method(method_url_query, options.method_scope)
       .ok_or(Error::MethodNotFound)?
-      .data()
-      .try_public_key_jwk()
+      .public_key_as_jwk()
       .map_err(Error::InvalidKeyMaterial)?;
 
     validation_item
-      .verify(signature_verifier, public_key)
+      .verify(signature_verifier, &public_key)
       .map_err(Error::JwsVerificationError)
   }
 
@@ -1060,6 +1177,32 @@ impl CoreDocument {
   }
 }
 
+impl CoreDocument {
+  /// Creates a [`CoreDocument`] from a did:key DID.
+  ///
+  /// The key of an X25519 did:key is only referenced for key agreement, while the key of an Ed25519 did:key is
+  /// referenced by all other verification relationships.
+  pub fn expand_did_key(did_key: DIDKey) -> Result<Self, Error> {
+    let is_key_agreement_key = did_key.is_key_agreement_key();
+    let verification_method = VerificationMethod::try_from(did_key.clone()).map_err(Error::InvalidKeyMaterial)?;
+    let verification_method_id = verification_method.id().clone();
+
+    let builder = DocumentBuilder::default()
+      .id(did_key.into())
+      .verification_method(verification_method);
+    if is_key_agreement_key {
+      builder.key_agreement(verification_method_id).build()
+    } else {
+      builder
+        .assertion_method(verification_method_id.clone())
+        .authentication(verification_method_id.clone())
+        .capability_invocation(verification_method_id.clone())
+        .capability_delegation(verification_method_id.clone())
+        .build()
+    }
+  }
+}
+
 impl CoreDocument {
   /// Creates a [`CoreDocument`] from a did:compositejwk DID.
   pub fn expand_did_compositejwk(did_compositejwk: DIDCompositeJwk) -> Result<Self, Error> {
@@ -1078,6 +1221,66 @@ impl CoreDocument {
   }
 }
 
+/// All verification relationships, in the order in which they are defined in the DID specification.
+const METHOD_RELATIONSHIPS: [MethodRelationship; 5] = [
+  MethodRelationship::Authentication,
+  MethodRelationship::AssertionMethod,
+  MethodRelationship::KeyAgreement,
+  MethodRelationship::CapabilityInvocation,
+  MethodRelationship::CapabilityDelegation,
+];
+
+/// Makes sure `method` doesn't embed private key material, e.g. a `publicKeyJwk` with a `d` parameter.
+fn check_public_key_material(method: &VerificationMethod) -> Result<()> {
+  let is_public = match method.data() {
+    MethodData::PublicKeyJwk(jwk) => jwk.is_public(),
+    MethodData::CompositeJwk(key) => key.traditional_public_key().is_public() && key.pq_public_key().is_public(),
+    _ => true,
+  };
+
+  if is_public {
+    Ok(())
+  } else {
+    Err(Error::InvalidKeyMaterial(
+      identity_verification::Error::PrivateKeyMaterialExposed,
+    ))
+  }
+}
+
+/// Returns whether `jwk` can verify signatures created with `alg`.
+fn is_jwk_usable_for_signing_with(jwk: &Jwk, alg: &JwsAlgorithm) -> bool {
+  if jwk.use_().is_some_and(|use_| use_ != JwkUse::Signature)
+    || jwk
+      .key_ops()
+      .is_some_and(|key_ops| !key_ops.contains(&JwkOperation::Verify))
+  {
+    return false;
+  }
+
+  if let Some(jwk_alg) = jwk.alg() {
+    return jwk_alg == alg.name();
+  }
+
+  match (jwk.kty(), alg) {
+    (JwkType::Okp, JwsAlgorithm::EdDSA) => jwk.try_ed_curve().is_ok(),
+    (JwkType::Ec, JwsAlgorithm::ES256) => jwk.try_ec_curve().is_ok_and(|curve| curve == EcCurve::P256),
+    (JwkType::Ec, JwsAlgorithm::ES384) => jwk.try_ec_curve().is_ok_and(|curve| curve == EcCurve::P384),
+    (JwkType::Ec, JwsAlgorithm::ES512) => jwk.try_ec_curve().is_ok_and(|curve| curve == EcCurve::P521),
+    (JwkType::Ec, JwsAlgorithm::ES256K) => jwk.try_ec_curve().is_ok_and(|curve| curve == EcCurve::Secp256K1),
+    (
+      JwkType::Rsa,
+      JwsAlgorithm::RS256
+      | JwsAlgorithm::RS384
+      | JwsAlgorithm::RS512
+      | JwsAlgorithm::PS256
+      | JwsAlgorithm::PS384
+      | JwsAlgorithm::PS512,
+    ) => true,
+    // Other key types, e.g. post-quantum ones, must declare their algorithm.
+    _ => false,
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use identity_core::convert::FromJson;
@@ -1149,6 +1352,42 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_private_key_material_is_rejected() {
+    // Deserialization bypasses the checks of `MethodBuilder`.
+    let method: VerificationMethod = VerificationMethod::from_json(
+      r#"{
+        "id": "did:example:1234#private-key",
+        "controller": "did:example:1234",
+        "type": "JsonWebKey2020",
+        "publicKeyJwk": {
+          "kty": "OKP",
+          "crv": "Ed25519",
+          "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+          "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        }
+      }"#,
+    )
+    .unwrap();
+
+    let mut document: CoreDocument = document();
+    let err: Error = document
+      .insert_method(method.clone(), MethodScope::VerificationMethod)
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      Error::InvalidKeyMaterial(identity_verification::Error::PrivateKeyMaterialExposed)
+    ));
+    assert!(document.resolve_method("#private-key", None).is_none());
+
+    let err: Error = CoreDocument::builder(Default::default())
+      .id(controller())
+      .authentication(method)
+      .build()
+      .unwrap_err();
+    assert!(matches!(err, Error::InvalidKeyMaterial(_)));
+  }
+
   #[rustfmt::skip]
   #[test]
   fn test_resolve_method() {
@@ -1374,6 +1613,69 @@ mod tests {
       .is_err());
   }
 
+  #[test]
+  fn test_bulk_verification_relationships() {
+    let mut document: CoreDocument = document();
+
+    let fragment = "#bulk-test";
+    let method = method(document.id(), fragment);
+    document
+      .insert_method(method.clone(), MethodScope::VerificationMethod)
+      .unwrap();
+    document
+      .attach_method_relationship(fragment, MethodRelationship::KeyAgreement)
+      .unwrap();
+
+    let attached = document
+      .attach_method_relationships(
+        fragment,
+        [
+          MethodRelationship::AssertionMethod,
+          MethodRelationship::KeyAgreement,
+          MethodRelationship::Authentication,
+        ],
+      )
+      .unwrap();
+    assert_eq!(
+      attached,
+      [MethodRelationship::AssertionMethod, MethodRelationship::Authentication]
+    );
+    assert_eq!(
+      document.method_relationships(fragment),
+      [
+        MethodRelationship::Authentication,
+        MethodRelationship::AssertionMethod,
+        MethodRelationship::KeyAgreement,
+      ]
+    );
+    // Embedded methods are reported too.
+    assert_eq!(
+      document.method_relationships("#auth-key"),
+      [MethodRelationship::Authentication]
+    );
+
+    // Errors leave the document unchanged.
+    assert!(document
+      .attach_method_relationships("#auth-key", [MethodRelationship::KeyAgreement])
+      .is_err());
+    assert_eq!(document.method_relationships("#auth-key").len(), 1);
+
+    let detached = document
+      .detach_method_relationships(
+        fragment,
+        [
+          MethodRelationship::Authentication,
+          MethodRelationship::CapabilityInvocation,
+        ],
+      )
+      .unwrap();
+    assert_eq!(detached, [MethodRelationship::Authentication]);
+
+    // Removing the method removes all references to it.
+    document.remove_method(method.id()).unwrap();
+    assert!(document.method_relationships(fragment).is_empty());
+  }
+
   #[test]
   fn test_method_insert_duplication() {
     let mut document: CoreDocument = document();
@@ -1805,4 +2107,102 @@ mod tests {
 
     assert_eq!(CoreDocument::expand_did_jwk(did_jwk).unwrap(), target_doc);
   }
+
+  #[test]
+  fn test_did_key_expansion() {
+    let did_key = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp"
+      .parse::<DIDKey>()
+      .unwrap();
+    let method_id =
+      "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp#z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp";
+    let doc = CoreDocument::expand_did_key(did_key).unwrap();
+    assert_eq!(doc.verification_method().len(), 1);
+    assert_eq!(doc.verification_method()[0].id().to_string(), method_id);
+    assert_eq!(doc.authentication().len(), 1);
+    assert!(doc.key_agreement().is_empty());
+
+    let did_key = "did:key:z6LSeu9HkTHSfLLeUs2nnzUSNedgDUevfNQgQjQC23ZCit6F"
+      .parse::<DIDKey>()
+      .unwrap();
+    let doc = CoreDocument::expand_did_key(did_key).unwrap();
+    assert_eq!(doc.key_agreement().len(), 1);
+    assert!(doc.authentication().is_empty());
+    assert!(doc.assertion_method().is_empty());
+  }
+
+  #[test]
+  fn test_signing_candidates() {
+    let controller: CoreDID = controller();
+    let jwk_method = |fragment: &str, jwk: serde_json::Value| {
+      VerificationMethod::new_from_jwk(controller.clone(), serde_json::from_value(jwk).unwrap(), Some(fragment))
+        .unwrap()
+    };
+    let ed25519 = serde_json::json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    });
+    let p256 = serde_json::json!({
+      "kty": "EC",
+      "crv": "P-256",
+      "x": "f83OJ3D2xF1Bg8vub9tLe1gHMzV76e8Tus9uPHvRVEU",
+      "y": "x_FEzRu9m36HLN_tue659LNpXW6pCyStikYjKIWI5a0"
+    });
+    let mut ed25519_enc = ed25519.clone();
+    ed25519_enc["use"] = "enc".into();
+    let mut ed25519_sign_only = ed25519.clone();
+    ed25519_sign_only["key_ops"] = serde_json::json!(["sign"]);
+
+    let mut document = CoreDocument::builder(Default::default())
+      .id(controller.clone())
+      .verification_method(method(&controller, "#key-1"))
+      .build()
+      .unwrap();
+    for (fragment, jwk, relationship) in [
+      ("ed25519", ed25519.clone(), MethodRelationship::AssertionMethod),
+      ("p256", p256, MethodRelationship::AssertionMethod),
+      ("ed25519-enc", ed25519_enc, MethodRelationship::AssertionMethod),
+      (
+        "ed25519-sign-only",
+        ed25519_sign_only,
+        MethodRelationship::AssertionMethod,
+      ),
+      ("ed25519-auth", ed25519, MethodRelationship::Authentication),
+    ] {
+      document
+        .insert_method(
+          jwk_method(fragment, jwk),
+          MethodScope::VerificationRelationship(relationship),
+        )
+        .unwrap();
+    }
+    document
+      .attach_method_relationship(
+        &controller.to_url().join("#key-1").unwrap(),
+        MethodRelationship::AssertionMethod,
+      )
+      .unwrap();
+
+    let fragments = |purpose: MethodRelationship, alg: JwsAlgorithm| -> Vec<String> {
+      document
+        .signing_candidates(purpose, alg)
+        .into_iter()
+        .map(|method| method.id().fragment().unwrap().to_owned())
+        .collect()
+    };
+    assert_eq!(
+      fragments(MethodRelationship::AssertionMethod, JwsAlgorithm::EdDSA),
+      ["ed25519"]
+    );
+    assert_eq!(
+      fragments(MethodRelationship::AssertionMethod, JwsAlgorithm::ES256),
+      ["p256"]
+    );
+    assert!(fragments(MethodRelationship::AssertionMethod, JwsAlgorithm::ES384).is_empty());
+    assert_eq!(
+      fragments(MethodRelationship::Authentication, JwsAlgorithm::EdDSA),
+      ["ed25519-auth"]
+    );
+    assert!(fragments(MethodRelationship::KeyAgreement, JwsAlgorithm::EdDSA).is_empty());
+  }
 }
@@ -0,0 +1,388 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed custom top-level properties for DID Documents.
+
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+
+use identity_core::common::Value;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Top-level properties defined by the DID Core specification, which cannot be claimed by a [`DocumentExtension`].
+const RESERVED_PROPERTIES: &[&str] = &[
+  "@context",
+  "id",
+  "controller",
+  "alsoKnownAs",
+  "verificationMethod",
+  "authentication",
+  "assertionMethod",
+  "keyAgreement",
+  "capabilityDelegation",
+  "capabilityInvocation",
+  "service",
+];
+
+/// A typed, custom top-level property of a DID Document.
+///
+/// Implementors are stored in - and read from - a document's custom properties, under the key
+/// [`DocumentExtension::PROPERTY`].
+///
+/// # Example
+/// ```
+/// # use identity_document::document::CoreDocument;
+/// # use identity_document::document::DocumentExtension;
+/// # use identity_core::common::Url;
+/// # use identity_core::convert::FromJson;
+/// #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+/// struct Homepage(Url);
+///
+/// impl DocumentExtension for Homepage {
+///   const PROPERTY: &'static str = "homepage";
+/// }
+///
+/// let mut document = CoreDocument::from_json(r#"{"id": "did:example:123"}"#)?;
+/// let homepage = Homepage(Url::parse("https://example.com")?);
+/// document.set_extension(&homepage)?;
+///
+/// assert_eq!(document.extension::<Homepage>()?, Some(homepage));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait DocumentExtension: Serialize + DeserializeOwned {
+  /// The name of the top-level property this extension is stored under.
+  const PROPERTY: &'static str;
+
+  /// Checks the semantic validity of this extension's value.
+  ///
+  /// The default implementation accepts any value that can be deserialized.
+  fn validate(&self) -> Result<(), Box<dyn StdError + Send + Sync>> {
+    Ok(())
+  }
+}
+
+/// A set of known [`DocumentExtension`]s, used to validate and lint the custom properties of a DID Document.
+#[derive(Debug, Clone, Default)]
+pub struct ExtensionRegistry {
+  extensions: BTreeMap<&'static str, fn(&Value) -> Result<()>>,
+}
+
+impl ExtensionRegistry {
+  /// Creates a new, empty [`ExtensionRegistry`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers the extension `E`.
+  ///
+  /// # Errors
+  /// Fails if `E`'s property clashes with a property defined by the DID Core specification or
+  /// with an already registered extension.
+  pub fn register<E: DocumentExtension>(&mut self) -> Result<()> {
+    if RESERVED_PROPERTIES.contains(&E::PROPERTY) {
+      return Err(Error::InvalidExtension(
+        E::PROPERTY.to_owned(),
+        Some("reserved property name".into()),
+      ));
+    }
+    if self.extensions.contains_key(E::PROPERTY) {
+      return Err(Error::InvalidExtension(
+        E::PROPERTY.to_owned(),
+        Some("an extension with the same property is already registered".into()),
+      ));
+    }
+
+    self.extensions.insert(E::PROPERTY, validate_value::<E>);
+    Ok(())
+  }
+
+  /// Returns whether an extension for `property` has been registered.
+  pub fn is_registered(&self, property: &str) -> bool {
+    self.extensions.contains_key(property)
+  }
+
+  /// Returns an iterator over the properties of all registered extensions.
+  pub fn properties(&self) -> impl Iterator<Item = &'static str> + '_ {
+    self.extensions.keys().copied()
+  }
+
+  /// Validates all custom properties of `document`.
+  ///
+  /// # Errors
+  /// Fails on the first custom property that isn't a registered extension or whose value
+  /// is not valid for its extension.
+  pub fn validate(&self, document: &CoreDocument) -> Result<()> {
+    for (property, value) in custom_properties(document) {
+      let validate_fn = self
+        .extensions
+        .get(property.as_str())
+        .ok_or_else(|| Error::InvalidExtension(property.clone(), Some("unknown extension".into())))?;
+      validate_fn(value)?;
+    }
+
+    Ok(())
+  }
+
+  /// Returns the list of issues found in `document`'s custom properties.
+  ///
+  /// Differently from [`ExtensionRegistry::validate`], this method doesn't stop at the first issue.
+  pub fn lint(&self, document: &CoreDocument) -> Vec<ExtensionLint> {
+    custom_properties(document)
+      .filter_map(|(property, value)| match self.extensions.get(property.as_str()) {
+        Some(validate_fn) => validate_fn(value).err().map(|error| ExtensionLint::Invalid {
+          property: property.clone(),
+          reason: error_chain_to_string(&error),
+        }),
+        None => Some(ExtensionLint::Unknown {
+          property: property.clone(),
+          suggestion: self.closest_property(property),
+        }),
+      })
+      .collect()
+  }
+
+  /// Returns the registered property that is most likely what `property` was meant to be, if any.
+  fn closest_property(&self, property: &str) -> Option<&'static str> {
+    const MAX_DISTANCE: usize = 2;
+
+    self
+      .properties()
+      .map(|known| (known, edit_distance(known, property)))
+      .filter(|(_, distance)| *distance <= MAX_DISTANCE)
+      .min_by_key(|(_, distance)| *distance)
+      .map(|(known, _)| known)
+  }
+}
+
+/// An issue found by [`ExtensionRegistry::lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExtensionLint {
+  /// A custom property that doesn't belong to any registered extension.
+  Unknown {
+    /// The unknown property.
+    property: String,
+    /// A registered property with a similar name, if any - e.g. when `property` contains a typo.
+    suggestion: Option<&'static str>,
+  },
+  /// A custom property whose value is not valid for its registered extension.
+  Invalid {
+    /// The invalid property.
+    property: String,
+    /// Why the property's value is invalid.
+    reason: String,
+  },
+}
+
+impl CoreDocument {
+  /// Returns the value of extension `E`, if this document contains it.
+  ///
+  /// # Errors
+  /// Fails if the document's property for `E` cannot be deserialized into `E`.
+  pub fn extension<E: DocumentExtension>(&self) -> Result<Option<E>> {
+    self
+      .properties()
+      .get(E::PROPERTY)
+      .map(|value| {
+        E::from_json_value(value.clone())
+          .map_err(|err| Error::InvalidExtension(E::PROPERTY.to_owned(), Some(err.into())))
+      })
+      .transpose()
+  }
+
+  /// Sets the value of extension `E`, replacing any previous value.
+  ///
+  /// # Errors
+  /// Fails if `E`'s property is defined by the DID Core specification, or if `extension` is not valid.
+  pub fn set_extension<E: DocumentExtension>(&mut self, extension: &E) -> Result<()> {
+    if RESERVED_PROPERTIES.contains(&E::PROPERTY) {
+      return Err(Error::InvalidExtension(
+        E::PROPERTY.to_owned(),
+        Some("reserved property name".into()),
+      ));
+    }
+    extension
+      .validate()
+      .map_err(|err| Error::InvalidExtension(E::PROPERTY.to_owned(), Some(err)))?;
+    let value = extension
+      .to_json_value()
+      .map_err(|err| Error::InvalidExtension(E::PROPERTY.to_owned(), Some(err.into())))?;
+
+    self.data.properties.insert(E::PROPERTY.to_owned(), value);
+    Ok(())
+  }
+
+  /// Removes extension `E` from this document, returning its value if present.
+  ///
+  /// # Errors
+  /// Fails if the removed value cannot be deserialized into `E`; the property is removed regardless.
+  pub fn remove_extension<E: DocumentExtension>(&mut self) -> Result<Option<E>> {
+    self
+      .data
+      .properties
+      .remove(E::PROPERTY)
+      .map(|value| {
+        E::from_json_value(value).map_err(|err| Error::InvalidExtension(E::PROPERTY.to_owned(), Some(err.into())))
+      })
+      .transpose()
+  }
+}
+
+/// Returns the properties of `document` that aren't defined by the DID Core specification, e.g. skipping `@context`.
+fn custom_properties(document: &CoreDocument) -> impl Iterator<Item = (&String, &Value)> {
+  document
+    .properties()
+    .iter()
+    .filter(|(property, _)| !RESERVED_PROPERTIES.contains(&property.as_str()))
+}
+
+fn validate_value<E: DocumentExtension>(value: &Value) -> Result<()> {
+  let extension = E::from_json_value(value.clone())
+    .map_err(|err| Error::InvalidExtension(E::PROPERTY.to_owned(), Some(err.into())))?;
+  extension
+    .validate()
+    .map_err(|err| Error::InvalidExtension(E::PROPERTY.to_owned(), Some(err)))
+}
+
+fn error_chain_to_string(error: &(dyn StdError + 'static)) -> String {
+  let mut message = error.to_string();
+  let mut source = error.source();
+  while let Some(cause) = source {
+    message.push_str(": ");
+    message.push_str(&cause.to_string());
+    source = cause.source();
+  }
+
+  message
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b: Vec<char> = b.chars().collect();
+  let mut row: Vec<usize> = (0..=b.len()).collect();
+  for (i, char_a) in a.chars().enumerate() {
+    let mut previous_diagonal = row[0];
+    row[0] = i + 1;
+    for (j, char_b) in b.iter().enumerate() {
+      let substitution = previous_diagonal + usize::from(char_a != *char_b);
+      previous_diagonal = row[j + 1];
+      row[j + 1] = substitution.min(row[j] + 1).min(previous_diagonal + 1);
+    }
+  }
+
+  row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use serde::Deserialize;
+
+  use super::*;
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Homepage(Url);
+
+  impl DocumentExtension for Homepage {
+    const PROPERTY: &'static str = "homepage";
+
+    fn validate(&self) -> Result<(), Box<dyn StdError + Send + Sync>> {
+      if self.0.scheme() == "https" {
+        Ok(())
+      } else {
+        Err("homepage must be served over https".into())
+      }
+    }
+  }
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct Reserved(String);
+
+  impl DocumentExtension for Reserved {
+    const PROPERTY: &'static str = "service";
+  }
+
+  fn document() -> CoreDocument {
+    CoreDocument::from_json(r#"{"id": "did:example:123"}"#).unwrap()
+  }
+
+  #[test]
+  fn extension_roundtrip() {
+    let mut document = document();
+    let homepage = Homepage(Url::parse("https://example.com").unwrap());
+    document.set_extension(&homepage).unwrap();
+
+    assert_eq!(document.extension::<Homepage>().unwrap(), Some(homepage));
+    assert!(document.remove_extension::<Homepage>().unwrap().is_some());
+    assert!(document.extension::<Homepage>().unwrap().is_none());
+  }
+
+  #[test]
+  fn invalid_extension_is_rejected() {
+    let mut document = document();
+    let homepage = Homepage(Url::parse("http://example.com").unwrap());
+
+    assert!(matches!(
+      document.set_extension(&homepage),
+      Err(Error::InvalidExtension(property, _)) if property == "homepage"
+    ));
+    assert!(matches!(
+      document.set_extension(&Reserved("foo".to_owned())),
+      Err(Error::InvalidExtension(property, _)) if property == "service"
+    ));
+  }
+
+  #[test]
+  fn registry_validation_and_lints() {
+    let mut registry = ExtensionRegistry::new();
+    registry.register::<Homepage>().unwrap();
+    assert!(registry.register::<Homepage>().is_err());
+    assert!(registry.register::<Reserved>().is_err());
+
+    let mut document = document();
+    document
+      .set_extension(&Homepage(Url::parse("https://example.com").unwrap()))
+      .unwrap();
+    assert!(registry.validate(&document).is_ok());
+
+    let properties = document.properties_mut_unchecked();
+    properties.insert("homepgae".to_owned(), "https://example.com".into());
+    properties.insert("homepage".to_owned(), "http://example.com".into());
+
+    assert!(registry.validate(&document).is_err());
+    let lints = registry.lint(&document);
+    assert_eq!(lints.len(), 2);
+    assert!(lints.contains(&ExtensionLint::Unknown {
+      property: "homepgae".to_owned(),
+      suggestion: Some("homepage"),
+    }));
+    assert!(lints
+      .iter()
+      .any(|lint| matches!(lint, ExtensionLint::Invalid { property, .. } if property == "homepage")));
+  }
+
+  #[test]
+  fn core_properties_are_not_extensions() {
+    let document =
+      CoreDocument::from_json(r#"{"@context": "https://www.w3.org/ns/did/v1", "id": "did:example:123"}"#).unwrap();
+    let registry = ExtensionRegistry::new();
+
+    assert!(registry.validate(&document).is_ok());
+    assert!(registry.lint(&document).is_empty());
+  }
+
+  #[test]
+  fn edit_distance_works() {
+    assert_eq!(edit_distance("homepage", "homepage"), 0);
+    assert_eq!(edit_distance("homepage", "homepgae"), 2);
+    assert_eq!(edit_distance("", "abc"), 3);
+  }
+}
@@ -0,0 +1,391 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Lenient parsing of DID Documents produced by other SSI stacks.
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::common::Value;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_core::convert::FromJson;
+
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+
+/// Verification relationships, which may both embed and reference methods.
+const RELATIONSHIPS: &[&str] = &[
+  "authentication",
+  "assertionMethod",
+  "keyAgreement",
+  "capabilityDelegation",
+  "capabilityInvocation",
+];
+
+/// Legacy or misused method types, along with their current names.
+const LEGACY_METHOD_TYPES: &[(&str, &str)] = &[
+  ("Secp256k1VerificationKey2018", "EcdsaSecp256k1VerificationKey2019"),
+  (
+    "Secp256k1SignatureVerificationKey2018",
+    "EcdsaSecp256k1VerificationKey2019",
+  ),
+  ("Ed25519Signature2018", "Ed25519VerificationKey2018"),
+];
+
+/// A fix applied to a DID Document by [`CoreDocument::from_json_lenient`] in order to make it conformant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Normalization {
+  /// A single `@context` string was wrapped into an array.
+  ContextWrapped,
+  /// The pre-DID Core `publicKey` property was merged into `verificationMethod`.
+  PublicKeyRenamed,
+  /// The pre-DID Core `owner` property of a verification method was renamed `controller`.
+  OwnerRenamed {
+    /// The identifier of the verification method.
+    method: String,
+  },
+  /// A verification method without `controller` was assigned the document's DID as its controller.
+  ControllerAdded {
+    /// The identifier of the verification method.
+    method: String,
+  },
+  /// A relative or non-standard identifier was turned into an absolute DID URL.
+  IdResolved {
+    /// The original identifier.
+    from: String,
+    /// The resolved DID URL.
+    to: String,
+  },
+  /// A legacy type of verification method was replaced by its current name.
+  MethodTypeRenamed {
+    /// The identifier of the verification method.
+    method: String,
+    /// The legacy type.
+    from: String,
+    /// The current type.
+    to: String,
+  },
+  /// Key material encoded as `publicKeyHex` was re-encoded as `publicKeyMultibase`.
+  PublicKeyHexConverted {
+    /// The identifier of the verification method.
+    method: String,
+  },
+  /// A pre-DID Core authentication object was replaced by a reference to the method it names.
+  LegacyAuthenticationReplaced {
+    /// The identifier of the referenced verification method.
+    method: String,
+  },
+}
+
+impl Display for Normalization {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::ContextWrapped => f.write_str("wrapped `@context` into an array"),
+      Self::PublicKeyRenamed => f.write_str("merged `publicKey` into `verificationMethod`"),
+      Self::OwnerRenamed { method } => write!(f, "renamed `owner` of method `{method}` to `controller`"),
+      Self::ControllerAdded { method } => write!(f, "set the document's DID as controller of method `{method}`"),
+      Self::IdResolved { from, to } => write!(f, "resolved identifier `{from}` to `{to}`"),
+      Self::MethodTypeRenamed { method, from, to } => {
+        write!(f, "renamed type `{from}` of method `{method}` to `{to}`")
+      }
+      Self::PublicKeyHexConverted { method } => {
+        write!(
+          f,
+          "converted `publicKeyHex` of method `{method}` to `publicKeyMultibase`"
+        )
+      }
+      Self::LegacyAuthenticationReplaced { method } => {
+        write!(
+          f,
+          "replaced legacy authentication object with a reference to `{method}`"
+        )
+      }
+    }
+  }
+}
+
+impl CoreDocument {
+  /// Deserializes a [`CoreDocument`] from JSON, fixing known quirks of documents produced by other SSI stacks - e.g.
+  /// ACA-Py or Veramo - beforehand.
+  ///
+  /// Returns the document, along with the [`Normalization`]s that were applied to it. The document is validated as
+  /// usual once normalized.
+  ///
+  /// Supported quirks:
+  /// - `@context` expressed as a single string rather than an array;
+  /// - verification methods listed under the pre-DID Core `publicKey` property, or having an `owner` rather than a
+  ///   `controller`;
+  /// - verification methods lacking a `controller`;
+  /// - relative identifiers (e.g. `#key-1`) and Indy-style service identifiers (e.g. `did:sov:123;indy`);
+  /// - legacy method types, e.g. `Secp256k1VerificationKey2018`;
+  /// - key material encoded as `publicKeyHex`;
+  /// - pre-DID Core authentication objects, e.g. `{ "type": "Ed25519SignatureAuthentication2018", "publicKey":
+  ///   "did:sov:123#1" }`.
+  ///
+  /// # Errors
+  /// Fails if `json` is not a JSON object, or if the normalized document is invalid.
+  pub fn from_json_lenient(json: &(impl AsRef<[u8]> + ?Sized)) -> Result<(Self, Vec<Normalization>)> {
+    let mut value: Value =
+      Value::from_json_slice(json).map_err(|err| Error::InvalidDocument("invalid JSON", Some(err)))?;
+    let normalizations: Vec<Normalization> = normalize(&mut value)?;
+    let document: Self =
+      Self::from_json_value(value).map_err(|err| Error::InvalidDocument("invalid normalized document", Some(err)))?;
+
+    Ok((document, normalizations))
+  }
+}
+
+fn normalize(value: &mut Value) -> Result<Vec<Normalization>> {
+  let document = value
+    .as_object_mut()
+    .ok_or(Error::InvalidDocument("expected a JSON object", None))?;
+  let did: String = document
+    .get("id")
+    .and_then(Value::as_str)
+    .ok_or(Error::InvalidDocument("missing document id", None))?
+    .to_owned();
+  let mut normalizations: Vec<Normalization> = Vec::new();
+
+  if let Some(context @ Value::String(_)) = document.get_mut("@context") {
+    *context = Value::Array(vec![context.take()]);
+    normalizations.push(Normalization::ContextWrapped);
+  }
+
+  if let Some(public_keys) = document.remove("publicKey") {
+    let methods = document
+      .entry("verificationMethod")
+      .or_insert_with(|| Value::Array(Vec::new()));
+    match (methods, public_keys) {
+      (Value::Array(methods), Value::Array(public_keys)) => methods.extend(public_keys),
+      (Value::Array(methods), public_key) => methods.push(public_key),
+      _ => {
+        return Err(Error::InvalidDocument(
+          "expected `verificationMethod` to be an array",
+          None,
+        ))
+      }
+    }
+    normalizations.push(Normalization::PublicKeyRenamed);
+  }
+
+  if let Some(Value::Array(methods)) = document.get_mut("verificationMethod") {
+    for method in methods.iter_mut() {
+      normalize_method(method, &did, &mut normalizations);
+    }
+  }
+
+  for relationship in RELATIONSHIPS {
+    let Some(Value::Array(methods)) = document.get_mut(*relationship) else {
+      continue;
+    };
+    for method in methods.iter_mut() {
+      // Pre-DID Core authentication objects only name the method they refer to.
+      let legacy_reference: Option<String> = match &*method {
+        Value::Object(object) if !object.contains_key("id") => {
+          object.get("publicKey").and_then(Value::as_str).map(ToOwned::to_owned)
+        }
+        _ => None,
+      };
+
+      if let Some(mut id) = legacy_reference {
+        resolve_id(&mut id, &did, &mut normalizations);
+        normalizations.push(Normalization::LegacyAuthenticationReplaced { method: id.clone() });
+        *method = Value::String(id);
+      } else if let Value::String(id) = method {
+        resolve_id(id, &did, &mut normalizations);
+      } else {
+        normalize_method(method, &did, &mut normalizations);
+      }
+    }
+  }
+
+  if let Some(Value::Array(services)) = document.get_mut("service") {
+    for service in services.iter_mut() {
+      if let Some(Value::String(id)) = service.get_mut("id") {
+        resolve_id(id, &did, &mut normalizations);
+      }
+    }
+  }
+
+  Ok(normalizations)
+}
+
+fn normalize_method(method: &mut Value, did: &str, normalizations: &mut Vec<Normalization>) {
+  let Some(method) = method.as_object_mut() else {
+    return;
+  };
+  if let Some(Value::String(id)) = method.get_mut("id") {
+    resolve_id(id, did, normalizations);
+  }
+  let id: String = method.get("id").and_then(Value::as_str).unwrap_or_default().to_owned();
+
+  if let Some(owner) = method.remove("owner") {
+    method.entry("controller").or_insert(owner);
+    normalizations.push(Normalization::OwnerRenamed { method: id.clone() });
+  }
+  if !method.contains_key("controller") {
+    method.insert("controller".to_owned(), Value::String(did.to_owned()));
+    normalizations.push(Normalization::ControllerAdded { method: id.clone() });
+  }
+
+  if let Some(Value::String(method_type)) = method.get_mut("type") {
+    if let Some((_, current)) = LEGACY_METHOD_TYPES
+      .iter()
+      .find(|(legacy, _)| *legacy == method_type.as_str())
+    {
+      normalizations.push(Normalization::MethodTypeRenamed {
+        method: id.clone(),
+        from: std::mem::replace(method_type, (*current).to_owned()),
+        to: (*current).to_owned(),
+      });
+    }
+  }
+
+  let public_key: Option<Vec<u8>> = method
+    .get("publicKeyHex")
+    .and_then(Value::as_str)
+    .and_then(|hex| BaseEncoding::decode(&hex.to_ascii_lowercase(), Base::Base16Lower).ok());
+  if let Some(public_key) = public_key {
+    method.remove("publicKeyHex");
+    method.insert(
+      "publicKeyMultibase".to_owned(),
+      Value::String(BaseEncoding::encode_multibase(&public_key, None)),
+    );
+    normalizations.push(Normalization::PublicKeyHexConverted { method: id });
+  }
+}
+
+/// Turns `id` into an absolute DID URL, if it's relative to `did` or an Indy-style `did;fragment` identifier.
+fn resolve_id(id: &mut String, did: &str, normalizations: &mut Vec<Normalization>) {
+  let resolved: String = if id.starts_with('#') {
+    format!("{did}{id}")
+  } else if let Some(fragment) = id.strip_prefix(did).and_then(|rest| rest.strip_prefix(';')) {
+    format!("{did}#{fragment}")
+  } else {
+    return;
+  };
+
+  normalizations.push(Normalization::IdResolved {
+    from: std::mem::replace(id, resolved.clone()),
+    to: resolved,
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_did::DID;
+  use identity_verification::MethodType;
+
+  use super::*;
+  use crate::utils::Queryable;
+
+  const DID: &str = "did:sov:WRfXPg8dantKVubE3HX8pw";
+
+  #[test]
+  fn conformant_documents_are_untouched() {
+    let json = format!(
+      r##"{{
+        "@context": ["https://www.w3.org/ns/did/v1"],
+        "id": "{DID}",
+        "verificationMethod": [{{
+          "id": "{DID}#key-1",
+          "controller": "{DID}",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyBase58": "H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV"
+        }}],
+        "authentication": ["{DID}#key-1"]
+      }}"##
+    );
+    let (_, normalizations) = CoreDocument::from_json_lenient(&json).unwrap();
+
+    assert!(normalizations.is_empty());
+  }
+
+  #[test]
+  fn legacy_acapy_document() {
+    let json = format!(
+      r##"{{
+        "@context": "https://w3id.org/did/v1",
+        "id": "{DID}",
+        "publicKey": [{{
+          "id": "{DID}#1",
+          "type": "Ed25519VerificationKey2018",
+          "publicKeyBase58": "H3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV"
+        }}],
+        "authentication": [{{
+          "type": "Ed25519SignatureAuthentication2018",
+          "publicKey": "{DID}#1"
+        }}],
+        "service": [{{
+          "id": "{DID};indy",
+          "type": "IndyAgent",
+          "serviceEndpoint": "https://agent.example.com/"
+        }}]
+      }}"##
+    );
+    let (document, normalizations) = CoreDocument::from_json_lenient(&json).unwrap();
+
+    assert_eq!(
+      normalizations,
+      vec![
+        Normalization::ContextWrapped,
+        Normalization::PublicKeyRenamed,
+        Normalization::ControllerAdded {
+          method: format!("{DID}#1")
+        },
+        Normalization::LegacyAuthenticationReplaced {
+          method: format!("{DID}#1")
+        },
+        Normalization::IdResolved {
+          from: format!("{DID};indy"),
+          to: format!("{DID}#indy"),
+        },
+      ]
+    );
+    assert_eq!(document.id().as_str(), DID);
+    assert!(document.resolve_method("#1", None).is_some());
+    assert!(document
+      .authentication()
+      .iter()
+      .any(|method| method.id().fragment() == Some("1")));
+    assert!(document.service().query("#indy").is_some());
+  }
+
+  #[test]
+  fn legacy_veramo_method() {
+    let json = format!(
+      r##"{{
+        "id": "{DID}",
+        "verificationMethod": [{{
+          "id": "#owner",
+          "owner": "{DID}",
+          "type": "Secp256k1VerificationKey2018",
+          "publicKeyHex": "02B97C30DE767F084CE3080168EE293053BA33B235D7116A3263D29F1450936B71"
+        }}],
+        "assertionMethod": ["#owner"]
+      }}"##
+    );
+    let (document, normalizations) = CoreDocument::from_json_lenient(&json).unwrap();
+    let method = document.resolve_method("#owner", None).unwrap();
+
+    assert_eq!(normalizations.len(), 5);
+    assert_eq!(method.type_(), &MethodType::custom("EcdsaSecp256k1VerificationKey2019"));
+    assert_eq!(
+      method.data().try_decode().unwrap(),
+      BaseEncoding::decode(
+        "02b97c30de767f084ce3080168ee293053ba33b235d7116a3263d29f1450936b71",
+        Base::Base16Lower
+      )
+      .unwrap()
+    );
+  }
+
+  #[test]
+  fn invalid_documents_are_rejected() {
+    assert!(CoreDocument::from_json_lenient("[]").is_err());
+    assert!(CoreDocument::from_json_lenient(r#"{ "publicKey": [] }"#).is_err());
+  }
+}
@@ -7,6 +7,14 @@
 
 pub use self::builder::DocumentBuilder;
 pub use self::core_document::CoreDocument;
+pub use self::extension::DocumentExtension;
+pub use self::extension::ExtensionLint;
+pub use self::extension::ExtensionRegistry;
+pub use self::lenient::Normalization;
+pub use self::projection::DocumentFrame;
 
 mod builder;
 mod core_document;
+mod extension;
+mod lenient;
+mod projection;
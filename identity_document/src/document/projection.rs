@@ -0,0 +1,314 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Minimal views of DID Documents, for transmission to constrained verifiers.
+
+use identity_core::common::Object;
+use identity_core::common::OrderedSet;
+use identity_verification::MethodRef;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodType;
+use identity_verification::VerificationMethod;
+
+use crate::document::core_document::CoreDocumentData;
+use crate::document::CoreDocument;
+use crate::service::Service;
+
+/// Selects the parts of a [`CoreDocument`] kept by [`CoreDocument::project`].
+///
+/// A verification method is kept if it passes the [`method_types`](Self::method_types) filter and it is either listed
+/// in [`methods`](Self::methods) or used by one of the selected [`relationships`](Self::relationships). A listed method
+/// keeps all of its verification relationships, while a method selected through a relationship only keeps the
+/// selected ones.
+///
+/// Frames can be (de)serialized, so that a verifier can send the frame it needs to a holder or resolver.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentFrame {
+  /// The verification relationships whose methods are kept.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub relationships: Vec<MethodRelationship>,
+  /// The fragments of the verification methods to keep.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub methods: Vec<String>,
+  /// Restricts the kept verification methods to these types. Any type is accepted if empty.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub method_types: Vec<MethodType>,
+  /// The fragments of the services to keep.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub services: Vec<String>,
+  /// The types of the services to keep.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub service_types: Vec<String>,
+  /// Whether the document's controllers are kept.
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub controller: bool,
+  /// Whether the document's `alsoKnownAs` property is kept.
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub also_known_as: bool,
+  /// The custom properties to keep.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub properties: Vec<String>,
+}
+
+impl DocumentFrame {
+  /// Creates a new [`DocumentFrame`] that only keeps the document's `id`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Keeps the methods used by `relationship`.
+  pub fn relationship(mut self, relationship: MethodRelationship) -> Self {
+    self.relationships.push(relationship);
+    self
+  }
+
+  /// Keeps the method whose fragment is `fragment`, along with its verification relationships.
+  pub fn method(mut self, fragment: impl Into<String>) -> Self {
+    self.methods.push(fragment.into());
+    self
+  }
+
+  /// Only keeps methods of type `method_type` - and of any other type passed to this method.
+  pub fn method_type(mut self, method_type: MethodType) -> Self {
+    self.method_types.push(method_type);
+    self
+  }
+
+  /// Keeps the service whose fragment is `fragment`.
+  pub fn service(mut self, fragment: impl Into<String>) -> Self {
+    self.services.push(fragment.into());
+    self
+  }
+
+  /// Keeps the services of type `service_type`.
+  pub fn service_type(mut self, service_type: impl Into<String>) -> Self {
+    self.service_types.push(service_type.into());
+    self
+  }
+
+  /// Keeps the document's controllers.
+  pub fn controller(mut self) -> Self {
+    self.controller = true;
+    self
+  }
+
+  /// Keeps the document's `alsoKnownAs` property.
+  pub fn also_known_as(mut self) -> Self {
+    self.also_known_as = true;
+    self
+  }
+
+  /// Keeps the custom property `property`.
+  pub fn property(mut self, property: impl Into<String>) -> Self {
+    self.properties.push(property.into());
+    self
+  }
+
+  fn lists_method(&self, method: &VerificationMethod) -> bool {
+    method
+      .id()
+      .fragment()
+      .is_some_and(|fragment| self.methods.iter().any(|listed| listed == fragment))
+  }
+
+  fn accepts_type(&self, method: &VerificationMethod) -> bool {
+    self.method_types.is_empty() || self.method_types.contains(method.type_())
+  }
+
+  fn selects_service(&self, service: &Service) -> bool {
+    service
+      .id()
+      .fragment()
+      .is_some_and(|fragment| self.services.iter().any(|listed| listed == fragment))
+      || service.type_().iter().any(|type_| self.service_types.contains(type_))
+  }
+}
+
+impl CoreDocument {
+  /// Returns a minimal view of this document, only made of its `id` and the parts selected by `frame`.
+  ///
+  /// The output is deterministic: kept entries appear in the same order as in this document, and a method reference
+  /// is only kept together with the method it refers to, so that the view is itself a valid document.
+  ///
+  /// # Example
+  /// ```
+  /// # use identity_core::convert::FromJson;
+  /// # use identity_document::document::CoreDocument;
+  /// # use identity_document::document::DocumentFrame;
+  /// # use identity_verification::MethodRelationship;
+  /// let document = CoreDocument::from_json(
+  ///   r##"{
+  ///     "id": "did:example:123",
+  ///     "verificationMethod": [
+  ///       { "id": "did:example:123#sign", "controller": "did:example:123", "type": "JsonWebKey2020", "publicKeyMultibase": "z6Mk" },
+  ///       { "id": "did:example:123#agree", "controller": "did:example:123", "type": "JsonWebKey2020", "publicKeyMultibase": "z6LS" }
+  ///     ],
+  ///     "assertionMethod": ["did:example:123#sign"],
+  ///     "keyAgreement": ["did:example:123#agree"]
+  ///   }"##,
+  /// )?;
+  ///
+  /// let view =
+  ///   document.project(&DocumentFrame::new().relationship(MethodRelationship::AssertionMethod));
+  /// assert_eq!(view.verification_method().len(), 1);
+  /// assert!(view.key_agreement().is_empty());
+  /// # Ok::<(), Box<dyn std::error::Error>>(())
+  /// ```
+  pub fn project(&self, frame: &DocumentFrame) -> CoreDocument {
+    let data = &self.data;
+    let relationships: [(MethodRelationship, &OrderedSet<MethodRef>); 5] = [
+      (MethodRelationship::Authentication, &data.authentication),
+      (MethodRelationship::AssertionMethod, &data.assertion_method),
+      (MethodRelationship::KeyAgreement, &data.key_agreement),
+      (MethodRelationship::CapabilityDelegation, &data.capability_delegation),
+      (MethodRelationship::CapabilityInvocation, &data.capability_invocation),
+    ];
+
+    let keeps_entry = |relationship: MethodRelationship, method_ref: &MethodRef| {
+      self.resolve_method_ref(method_ref).is_some_and(|method| {
+        frame.accepts_type(method) && (frame.relationships.contains(&relationship) || frame.lists_method(method))
+      })
+    };
+    let project_relationship = |relationship: MethodRelationship, method_refs: &OrderedSet<MethodRef>| {
+      method_refs
+        .iter()
+        .filter(|method_ref| keeps_entry(relationship, method_ref))
+        .cloned()
+        .collect::<OrderedSet<MethodRef>>()
+    };
+    let [authentication, assertion_method, key_agreement, capability_delegation, capability_invocation] =
+      relationships.map(|(relationship, method_refs)| project_relationship(relationship, method_refs));
+
+    let verification_method = data
+      .verification_method
+      .iter()
+      .filter(|method| {
+        frame.accepts_type(method)
+          && (frame.lists_method(method)
+            || relationships.iter().any(|(relationship, method_refs)| {
+              frame.relationships.contains(relationship)
+                && method_refs
+                  .iter()
+                  .any(|method_ref| matches!(method_ref, MethodRef::Refer(id) if id == method.id()))
+            }))
+      })
+      .cloned()
+      .collect();
+
+    let service = data
+      .service
+      .iter()
+      .filter(|service| frame.selects_service(service))
+      .cloned()
+      .collect();
+
+    let properties: Object = data
+      .properties
+      .iter()
+      .filter(|(key, _)| frame.properties.contains(key))
+      .map(|(key, value)| (key.clone(), value.clone()))
+      .collect();
+
+    // A subset of a valid document, whose references all point to kept methods, is itself valid.
+    CoreDocument {
+      data: CoreDocumentData {
+        id: data.id.clone(),
+        controller: data.controller.clone().filter(|_| frame.controller),
+        also_known_as: if frame.also_known_as {
+          data.also_known_as.clone()
+        } else {
+          OrderedSet::new()
+        },
+        verification_method,
+        authentication,
+        assertion_method,
+        key_agreement,
+        capability_delegation,
+        capability_invocation,
+        service,
+        properties,
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+
+  fn document() -> CoreDocument {
+    CoreDocument::from_json(
+      r##"{
+        "id": "did:example:1234",
+        "controller": "did:example:abcd",
+        "verificationMethod": [
+          { "id": "did:example:1234#key-1", "controller": "did:example:1234", "type": "JsonWebKey2020", "publicKeyMultibase": "z1" },
+          { "id": "did:example:1234#key-2", "controller": "did:example:1234", "type": "Ed25519VerificationKey2018", "publicKeyMultibase": "z2" },
+          { "id": "did:example:1234#key-3", "controller": "did:example:1234", "type": "JsonWebKey2020", "publicKeyMultibase": "z3" }
+        ],
+        "authentication": [
+          "did:example:1234#key-1",
+          { "id": "did:example:1234#auth-key", "controller": "did:example:1234", "type": "JsonWebKey2020", "publicKeyMultibase": "z4" }
+        ],
+        "assertionMethod": ["did:example:1234#key-1", "did:example:1234#key-2"],
+        "keyAgreement": ["did:example:1234#key-3"],
+        "service": [
+          { "id": "did:example:1234#linked-domain", "type": "LinkedDomains", "serviceEndpoint": "https://example.com" },
+          { "id": "did:example:1234#messaging", "type": "DIDCommMessaging", "serviceEndpoint": "https://example.com/didcomm" }
+        ],
+        "custom": "value",
+        "other": 1
+      }"##,
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn projection_keeps_selected_parts_only() {
+    let frame = DocumentFrame::new()
+      .relationship(MethodRelationship::AssertionMethod)
+      .method_type(MethodType::JSON_WEB_KEY_2020)
+      .service_type("LinkedDomains")
+      .property("custom");
+    let view = document().project(&frame);
+
+    let fragments = |methods: Vec<&VerificationMethod>| -> Vec<String> {
+      methods
+        .into_iter()
+        .map(|method| method.id().fragment().unwrap().to_owned())
+        .collect()
+    };
+    assert_eq!(fragments(view.methods(None)), ["key-1"]);
+    assert_eq!(view.assertion_method().len(), 1);
+    assert!(view.authentication().is_empty());
+    assert!(view.key_agreement().is_empty());
+    assert_eq!(view.service().len(), 1);
+    assert!(view.controller().is_none());
+    assert_eq!(view.properties().len(), 1);
+
+    // A listed method keeps all of its relationships, embedded or not.
+    let view = document().project(&DocumentFrame::new().method("key-1").method("auth-key"));
+    assert_eq!(fragments(view.methods(None)), ["key-1", "auth-key"]);
+    assert_eq!(view.authentication().len(), 2);
+    assert_eq!(view.assertion_method().len(), 1);
+  }
+
+  #[test]
+  fn projection_is_deterministic() {
+    let frame = DocumentFrame::new()
+      .relationship(MethodRelationship::KeyAgreement)
+      .relationship(MethodRelationship::Authentication)
+      .controller();
+    let view = document().project(&frame);
+    assert_eq!(view.to_json().unwrap(), document().project(&frame).to_json().unwrap());
+    assert_eq!(CoreDocument::from_json(&view.to_json().unwrap()).unwrap(), view);
+
+    let frame_json = frame.to_json().unwrap();
+    assert_eq!(DocumentFrame::from_json(&frame_json).unwrap(), frame);
+    assert_eq!(DocumentFrame::new().to_json().unwrap(), "{}");
+  }
+}
@@ -39,4 +39,10 @@ pub enum Error {
   /// Caused by a failure to verify a JSON Web Signature.
   #[error("jws verification failed")]
   JwsVerificationError(#[source] identity_verification::jose::error::Error),
+  /// Caused by an unknown, reserved or invalid typed document extension.
+  #[error("invalid document extension `{0}`")]
+  InvalidExtension(
+    String,
+    #[source] Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  ),
 }
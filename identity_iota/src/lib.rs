@@ -35,11 +35,25 @@ pub mod credential {
   //!
   //! [Specification](https://www.w3.org/TR/vc-data-model/)
 
+  #[cfg(feature = "claims-mapping")]
+  pub use identity_credential::claims_mapping;
   pub use identity_credential::credential::*;
+  #[cfg(feature = "delegation")]
+  pub use identity_credential::delegation;
   #[cfg(feature = "domain-linkage")]
   pub use identity_credential::domain_linkage::*;
   pub use identity_credential::error::*;
+  #[cfg(feature = "interop-tests")]
+  pub use identity_credential::interop;
+  #[cfg(feature = "key-rollover")]
+  pub use identity_credential::key_rollover;
+  #[cfg(feature = "oid4vc")]
+  pub use identity_credential::oid4vc;
+  #[cfg(feature = "openid4vp")]
+  pub use identity_credential::openid4vp;
   pub use identity_credential::presentation::*;
+  #[cfg(feature = "presentation-exchange")]
+  pub use identity_credential::presentation_exchange;
   #[cfg(feature = "revocation-bitmap")]
   pub use identity_credential::revocation::*;
   #[cfg(feature = "sd-jwt-vc")]
@@ -102,6 +116,8 @@ pub mod verification {
 
 pub mod storage {
   //! Storage traits.
+  #[cfg(feature = "chaos")]
+  pub use identity_storage::chaos;
   /// KeyIdStorage types and functionalities.
   pub mod key_id_storage {
     pub use identity_storage::key_id_storage::*;
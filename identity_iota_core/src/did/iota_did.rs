@@ -19,6 +19,8 @@ use ref_cast::RefCastCustom;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::Network;
+
 /// Alias for a `Result` with the error type [`DIDError`].
 type Result<T> = std::result::Result<T, DIDError>;
 
@@ -134,6 +136,20 @@ impl IotaDID {
     CoreDID::parse(input.as_ref().to_lowercase()).and_then(Self::try_from_core)
   }
 
+  /// Parses an [`IotaDID`] from the given `input`, making sure it references a DID Document on `network`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input does not conform to the [`IotaDID`] specification or belongs to another network.
+  pub fn parse_for_network(input: impl AsRef<str>, network: &Network) -> Result<Self> {
+    let did = Self::parse(input)?;
+    if network.matches(did.network_str()) {
+      Ok(did)
+    } else {
+      Err(DIDError::Other("the DID belongs to another network"))
+    }
+  }
+
   /// Converts a [`CoreDID`] to a [`IotaDID`].
   ///
   /// # Errors
@@ -154,6 +170,11 @@ impl IotaDID {
     Self::denormalized_components(self.method_id()).0
   }
 
+  /// Returns the well-known [`Network`] this `DID` belongs to, if any.
+  pub fn network(&self) -> Option<Network> {
+    Network::from_name(self.network_str())
+  }
+
   /// Returns the tag of the `DID`, which is an identity's object id.
   pub fn tag_str(&self) -> &str {
     Self::denormalized_components(self.method_id()).1
@@ -713,6 +734,20 @@ mod tests {
     execute_assertions(VALID_OBJECT_ID_STR);
   }
 
+  #[test]
+  fn test_well_known_network() {
+    let did = IotaDID::parse(format!("did:iota:{VALID_OBJECT_ID_STR}")).unwrap();
+    assert_eq!(did.network(), Some(Network::Mainnet));
+    let did = IotaDID::parse(format!("did:iota:2304aa97:{VALID_OBJECT_ID_STR}")).unwrap();
+    assert_eq!(did.network(), Some(Network::Testnet));
+    let did = IotaDID::parse(format!("did:iota:custom:{VALID_OBJECT_ID_STR}")).unwrap();
+    assert_eq!(did.network(), None);
+
+    let testnet_did = format!("did:iota:testnet:{VALID_OBJECT_ID_STR}");
+    assert!(IotaDID::parse_for_network(&testnet_did, &Network::Testnet).is_ok());
+    assert!(IotaDID::parse_for_network(&testnet_did, &Network::Devnet).is_err());
+  }
+
   #[test]
   fn test_tag() {
     let execute_assertions = |valid_object_id: &str| {
@@ -1,7 +1,10 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Timestamp;
+
 use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::IdentityError;
 use crate::Error;
 use crate::IotaDID;
 use crate::IotaDocument;
@@ -20,17 +23,45 @@ pub trait DidResolutionHandler {
   ///
   /// # Errors
   ///
-  /// - [`DID resolution failed`](Error::DIDResolutionError) if the DID could not be resolved.
+  /// - [`DID not found`](Error::DIDNotFound) if the DID doesn't reference any DID Document on the network.
+  /// - [`DID resolution failed`](Error::DIDResolutionError) if the DID could not be resolved for any other reason.
   async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument>;
+
+  /// Resolve the [`IotaDocument`] of `did` as it was at time `at`.
+  ///
+  /// The default implementation does not support point-in-time resolution and always fails.
+  ///
+  /// # Errors
+  ///
+  /// - [`DID resolution failed`](Error::DIDResolutionError) if the DID could not be resolved at `at`.
+  async fn resolve_did_at(&self, did: &IotaDID, at: Timestamp) -> Result<IotaDocument> {
+    let _ = at;
+    Err(Error::DIDResolutionError(format!(
+      "point-in-time resolution of {did} is not supported"
+    )))
+  }
 }
 
 #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
 #[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
 impl DidResolutionHandler for IdentityClientReadOnly {
   async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument> {
+    self.resolve_did(did).await.map_err(|err| resolution_error(did, err))
+  }
+
+  async fn resolve_did_at(&self, did: &IotaDID, at: Timestamp) -> Result<IotaDocument> {
     self
-      .resolve_did(did)
+      .resolve_did_at(did, at)
       .await
-      .map_err(|err| Error::DIDResolutionError(err.to_string()))
+      .map(|version| version.document)
+      .map_err(|err| resolution_error(did, err))
+  }
+}
+
+/// Converts an error of the IOTA rebased client into a resolution [`Error`], telling apart DIDs that don't exist.
+pub(crate) fn resolution_error(did: &IotaDID, err: crate::rebased::Error) -> Error {
+  match err {
+    crate::rebased::Error::Identity(IdentityError::NotFound(_)) => Error::DIDNotFound(did.to_string()),
+    err => Error::DIDResolutionError(err.to_string()),
   }
 }
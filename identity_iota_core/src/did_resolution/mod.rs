@@ -3,4 +3,6 @@
 
 pub use did_resolution_handler::DidResolutionHandler;
 
+pub(crate) use did_resolution_handler::resolution_error;
+
 mod did_resolution_handler;
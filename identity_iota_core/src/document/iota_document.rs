@@ -30,6 +30,7 @@ use crate::error::Result;
 use crate::Error;
 use crate::IotaDID;
 use crate::IotaDocumentMetadata;
+use crate::IotaDocumentView;
 use crate::StateMetadataDocument;
 use crate::StateMetadataEncoding;
 
@@ -169,6 +170,28 @@ impl IotaDocument {
     &self.document
   }
 
+  /// Returns a borrowed [`IotaDocumentView`] of this document, through which it can be used as a [`CoreDocument`]
+  /// without losing its metadata.
+  pub fn as_view(&self) -> IotaDocumentView<'_> {
+    IotaDocumentView::new_unchecked(&self.document, &self.metadata)
+  }
+
+  /// Splits this document into its [`CoreDocument`] and its metadata.
+  ///
+  /// See also [`IotaDocument::from_parts`] for the inverse operation.
+  pub fn into_parts(self) -> (CoreDocument, IotaDocumentMetadata) {
+    (self.document, self.metadata)
+  }
+
+  /// Creates an [`IotaDocument`] from a [`CoreDocument`] and its metadata.
+  ///
+  /// # Errors
+  /// Fails if the identifier of `document` or any of its controllers does not satisfy the IOTA DID method
+  /// specification.
+  pub fn from_parts(document: CoreDocument, metadata: IotaDocumentMetadata) -> Result<Self> {
+    Self::try_from((document, metadata))
+  }
+
   /// Returns a mutable reference to the underlying [`CoreDocument`].
   ///
   /// WARNING: Mutating the inner document directly bypasses checks and
@@ -312,6 +335,56 @@ impl IotaDocument {
       .map_err(Error::InvalidDoc)
   }
 
+  /// Attaches all of the given `relationships` to the method resolved by `method_query`.
+  /// Returns the relationships that were not already attached.
+  ///
+  /// # Errors
+  ///
+  /// Same as [`Self::attach_method_relationship`]; the document is left unchanged on error.
+  pub fn attach_method_relationships<'query, Q, I>(
+    &mut self,
+    method_query: Q,
+    relationships: I,
+  ) -> Result<Vec<MethodRelationship>>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    self
+      .core_document_mut()
+      .attach_method_relationships(method_query, relationships)
+      .map_err(Error::InvalidDoc)
+  }
+
+  /// Detaches all of the given `relationships` from the method resolved by `method_query`.
+  /// Returns the relationships that were found and removed.
+  ///
+  /// # Errors
+  ///
+  /// Same as [`Self::detach_method_relationship`]; the document is left unchanged on error.
+  pub fn detach_method_relationships<'query, Q, I>(
+    &mut self,
+    method_query: Q,
+    relationships: I,
+  ) -> Result<Vec<MethodRelationship>>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+    I: IntoIterator<Item = MethodRelationship>,
+  {
+    self
+      .core_document_mut()
+      .detach_method_relationships(method_query, relationships)
+      .map_err(Error::InvalidDoc)
+  }
+
+  /// Returns the verification relationships referencing or embedding the method resolved by `method_query`.
+  pub fn method_relationships<'query, Q>(&self, method_query: Q) -> Vec<MethodRelationship>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self.core_document().method_relationships(method_query)
+  }
+
   /// Returns the first [`VerificationMethod`] with an `id` property matching the
   /// provided `method_query` and the verification relationship specified by `scope` if present.
   ///
@@ -540,6 +613,12 @@ impl From<IotaDocument> for CoreDocument {
   }
 }
 
+impl From<IotaDocument> for (CoreDocument, IotaDocumentMetadata) {
+  fn from(document: IotaDocument) -> Self {
+    document.into_parts()
+  }
+}
+
 impl From<CoreDocument> for IotaDocument {
   fn from(value: CoreDocument) -> Self {
     IotaDocument {
@@ -0,0 +1,135 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Deref;
+
+use identity_document::document::CoreDocument;
+
+use crate::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+use crate::IotaDocumentMetadata;
+use crate::Result;
+
+/// A borrowed view over an [`IotaDocument`], i.e. a [`CoreDocument`] satisfying the IOTA DID method
+/// requirements together with its [`IotaDocumentMetadata`].
+///
+/// A view dereferences to its [`CoreDocument`], so that code written against [`CoreDocument`] can operate on
+/// IOTA documents without cloning them or dropping their metadata.
+///
+/// # Example
+/// ```
+/// # use identity_document::document::CoreDocument;
+/// # use identity_iota_core::IotaDocument;
+/// # use identity_iota_core::IotaDocumentView;
+/// # use product_common::network_name::NetworkName;
+/// fn method_count(document: &CoreDocument) -> usize {
+///   document.methods(None).len()
+/// }
+///
+/// let document = IotaDocument::new(&NetworkName::try_from("smr").unwrap());
+/// let view: IotaDocumentView<'_> = document.as_view();
+/// assert_eq!(method_count(&view), 0);
+/// assert_eq!(view.metadata(), &document.metadata);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IotaDocumentView<'a> {
+  document: &'a CoreDocument,
+  metadata: &'a IotaDocumentMetadata,
+}
+
+impl<'a> IotaDocumentView<'a> {
+  /// Creates a new view over `document` and its `metadata`.
+  ///
+  /// # Errors
+  /// Fails if the identifier of `document` or any of its controllers does not satisfy the IOTA DID method
+  /// specification.
+  pub fn new(document: &'a CoreDocument, metadata: &'a IotaDocumentMetadata) -> Result<Self> {
+    IotaDID::check_validity(document.id()).map_err(Error::DIDSyntaxError)?;
+    for controller in document.controller().into_iter().flatten() {
+      IotaDID::check_validity(controller).map_err(Error::DIDSyntaxError)?;
+    }
+
+    Ok(Self::new_unchecked(document, metadata))
+  }
+
+  /// Creates a new view without checking `document`'s identifiers.
+  pub(crate) fn new_unchecked(document: &'a CoreDocument, metadata: &'a IotaDocumentMetadata) -> Self {
+    Self { document, metadata }
+  }
+
+  /// Returns the DID document identifier.
+  pub fn id(&self) -> &'a IotaDID {
+    // CORRECTNESS: the identifier is checked when the view is created.
+    IotaDID::from_inner_ref_unchecked(self.document.id())
+  }
+
+  /// Returns the underlying [`CoreDocument`].
+  pub fn core_document(&self) -> &'a CoreDocument {
+    self.document
+  }
+
+  /// Returns the metadata of the document.
+  pub fn metadata(&self) -> &'a IotaDocumentMetadata {
+    self.metadata
+  }
+
+  /// Clones the viewed document and its metadata into an owned [`IotaDocument`].
+  pub fn to_document(&self) -> IotaDocument {
+    IotaDocument {
+      document: self.document.clone(),
+      metadata: self.metadata.clone(),
+    }
+  }
+}
+
+impl Deref for IotaDocumentView<'_> {
+  type Target = CoreDocument;
+
+  fn deref(&self) -> &Self::Target {
+    self.document
+  }
+}
+
+impl AsRef<CoreDocument> for IotaDocumentView<'_> {
+  fn as_ref(&self) -> &CoreDocument {
+    self.document
+  }
+}
+
+impl From<IotaDocumentView<'_>> for IotaDocument {
+  fn from(view: IotaDocumentView<'_>) -> Self {
+    view.to_document()
+  }
+}
+
+impl<'a> From<&'a IotaDocument> for IotaDocumentView<'a> {
+  fn from(document: &'a IotaDocument) -> Self {
+    document.as_view()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  #[test]
+  fn view_checks_the_document_identifier() {
+    let metadata = IotaDocumentMetadata::new();
+    let not_iota: CoreDocument = CoreDocument::from_json(r#"{ "id": "did:example:123" }"#).unwrap();
+    assert!(IotaDocumentView::new(&not_iota, &metadata).is_err());
+
+    let document: CoreDocument = CoreDocument::from_json(
+      r#"{ "id": "did:iota:0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa" }"#,
+    )
+    .unwrap();
+    let view = IotaDocumentView::new(&document, &metadata).unwrap();
+    assert_eq!(view.id().as_str(), document.id().as_str());
+
+    let owned: IotaDocument = view.to_document();
+    assert_eq!(owned.as_view(), view);
+    assert_eq!(IotaDocument::from(owned.as_view()), owned);
+  }
+}
@@ -3,9 +3,11 @@
 
 pub use iota_document::IotaDocument;
 pub use iota_document_metadata::IotaDocumentMetadata;
+pub use iota_document_view::IotaDocumentView;
 
 mod iota_document;
 mod iota_document_metadata;
+mod iota_document_view;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
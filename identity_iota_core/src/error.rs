@@ -11,6 +11,12 @@ pub enum Error {
   /// Caused by a failure to serialize or deserialize.
   #[error("serialization error: {0}")]
   SerializationError(&'static str, #[source] Option<identity_core::Error>),
+  /// Caused by a failure to encode or decode CBOR state metadata.
+  #[error("CBOR serialization error: {0}")]
+  CborSerializationError(
+    &'static str,
+    #[source] Box<dyn std::error::Error + Send + Sync + 'static>,
+  ),
   /// Caused by an invalid DID.
   #[error("invalid did")]
   DIDSyntaxError(#[source] identity_did::Error),
@@ -20,6 +26,12 @@ pub enum Error {
   /// Caused by a client failure during resolution.
   #[error("DID resolution failed; {0}")]
   DIDResolutionError(String),
+  /// Caused by a DID that doesn't reference any DID Document on the network.
+  #[error("DID Document `{0}` could not be found")]
+  DIDNotFound(String),
+  /// Caused by a failure to resolve a DID Document whose state is cross-checked with other nodes.
+  #[error("cross-checked DID resolution failed")]
+  CrossCheckedResolutionError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
   /// Caused by an invalid network name.
   #[error("\"{0}\" is not a valid network name in the context of the `iota` did method")]
   InvalidNetworkName(String),
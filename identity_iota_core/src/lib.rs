@@ -18,6 +18,7 @@ pub use did::IotaDID;
 #[cfg(feature = "iota-client")]
 pub use did_resolution::DidResolutionHandler;
 pub use document::*;
+pub use network::Network;
 pub use state_metadata::*;
 
 pub use self::error::Error;
@@ -26,6 +27,7 @@ pub use self::error::Result;
 mod did;
 mod document;
 mod error;
+mod network;
 mod state_metadata;
 
 #[cfg(feature = "iota-client")]
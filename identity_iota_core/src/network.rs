@@ -0,0 +1,185 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_core::common::Url;
+use iota_interaction::types::base_types::ObjectID;
+use product_common::network_name::NetworkName;
+
+use crate::IotaDID;
+
+/// Chain identifier, network name, default endpoint and `iota_identity` package history of a well-known network.
+struct Preset {
+  chain_id: &'static str,
+  name: &'static str,
+  endpoint: &'static str,
+  /// The published versions of the `iota_identity` package, oldest first.
+  packages: &'static [&'static str],
+}
+
+const MAINNET: Preset = Preset {
+  chain_id: "6364aad5",
+  name: IotaDID::DEFAULT_NETWORK,
+  endpoint: "https://api.mainnet.iota.cafe",
+  packages: &[
+    "0x84cf5d12de2f9731a89bb519bc0c982a941b319a33abefdd5ed2054ad931de08",
+    "0x36d0d56aea27a59f620ba32b6dd47a5e68d810714468bd270fda5ad37a478767",
+  ],
+};
+
+const TESTNET: Preset = Preset {
+  chain_id: "2304aa97",
+  name: "testnet",
+  endpoint: "https://api.testnet.iota.cafe",
+  packages: &[
+    "0x222741bbdff74b42df48a7b4733185e9b24becb8ccfbafe8eac864ab4e4cc555",
+    "0x3403da7ec4cd2ff9bdf6f34c0b8df5a2bd62c798089feb0d2ebf1c2e953296dc",
+    "0x29359d33a2e84f04407da0d6cff15dd8ad271c75493ef6b78f381993e4c0abb0",
+  ],
+};
+
+const DEVNET: Preset = Preset {
+  chain_id: "daf90477",
+  name: "devnet",
+  endpoint: "https://api.devnet.iota.cafe",
+  packages: &["0x8896ab04fe24c044c54925df3f8a7c383a8d1d6f6bbb95d1c57cfa94c75e520d"],
+};
+
+/// An IOTA network identities can be published on.
+///
+/// Bundles together the network's name - i.e. the network segment of its DIDs -, the endpoint of one of its nodes
+/// and the ID of the `iota_identity` package published on it, so that they can't be mixed up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Network {
+  /// IOTA mainnet.
+  Mainnet,
+  /// IOTA testnet.
+  Testnet,
+  /// IOTA devnet.
+  Devnet,
+  /// Any other network, e.g. a local one.
+  Custom {
+    /// Name of the network, used in its DIDs.
+    hrp: NetworkName,
+    /// Endpoint of a node of the network.
+    url: Url,
+    /// ID of the `iota_identity` package published on the network, if known.
+    package: Option<ObjectID>,
+  },
+}
+
+impl Network {
+  /// The well-known networks.
+  pub const PRESETS: [Self; 3] = [Self::Mainnet, Self::Testnet, Self::Devnet];
+
+  /// Returns the well-known network whose name or chain identifier is `network`, if any.
+  ///
+  /// ```
+  /// # use identity_iota_core::Network;
+  /// assert_eq!(Network::from_name("testnet"), Some(Network::Testnet));
+  /// assert_eq!(Network::from_name("6364aad5"), Some(Network::Mainnet));
+  /// assert_eq!(Network::from_name("smr"), None);
+  /// ```
+  pub fn from_name(network: &str) -> Option<Self> {
+    Self::PRESETS.into_iter().find(|preset| preset.matches(network))
+  }
+
+  /// Returns the name of this network, i.e. the network segment of its DIDs.
+  pub fn name(&self) -> NetworkName {
+    match self {
+      Self::Custom { hrp, .. } => hrp.clone(),
+      _ => NetworkName::try_from(self.preset().name).expect("valid network name"),
+    }
+  }
+
+  /// Returns the chain identifier of this network, if it is a well-known one.
+  pub fn chain_id(&self) -> Option<&'static str> {
+    matches!(self, Self::Mainnet | Self::Testnet | Self::Devnet).then(|| self.preset().chain_id)
+  }
+
+  /// Returns the endpoint of a node of this network.
+  pub fn endpoint(&self) -> &str {
+    match self {
+      Self::Custom { url, .. } => url.as_str(),
+      _ => self.preset().endpoint,
+    }
+  }
+
+  /// Returns the ID of the latest `iota_identity` package published on this network, if known.
+  pub fn package_id(&self) -> Option<ObjectID> {
+    self.package_history().pop()
+  }
+
+  /// Returns the IDs of the `iota_identity` packages published on this network, oldest first.
+  pub fn package_history(&self) -> Vec<ObjectID> {
+    match self {
+      Self::Custom { package, .. } => package.iter().copied().collect(),
+      _ => self
+        .preset()
+        .packages
+        .iter()
+        .map(|id| ObjectID::from_hex_literal(id).expect("valid object ID"))
+        .collect(),
+    }
+  }
+
+  /// Returns whether `network` is either this network's name or its chain identifier.
+  pub fn matches(&self, network: &str) -> bool {
+    self.name().as_ref() == network || self.chain_id() == Some(network)
+  }
+
+  fn preset(&self) -> &'static Preset {
+    match self {
+      Self::Mainnet => &MAINNET,
+      Self::Testnet => &TESTNET,
+      Self::Devnet => &DEVNET,
+      Self::Custom { .. } => unreachable!("custom networks have no preset"),
+    }
+  }
+}
+
+impl Display for Network {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}", self.name())
+  }
+}
+
+impl From<&Network> for NetworkName {
+  fn from(network: &Network) -> Self {
+    network.name()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn presets_are_consistent() {
+    for network in Network::PRESETS {
+      assert_eq!(Network::from_name(network.name().as_ref()), Some(network.clone()));
+      assert_eq!(Network::from_name(network.chain_id().unwrap()), Some(network.clone()));
+      assert!(network.endpoint().parse::<Url>().is_ok());
+      assert_eq!(network.package_history().last().copied(), network.package_id());
+    }
+  }
+
+  #[test]
+  fn custom_networks() {
+    let package = ObjectID::from_hex_literal("0x1").unwrap();
+    let network = Network::Custom {
+      hrp: NetworkName::try_from("local").unwrap(),
+      url: Url::parse("http://127.0.0.1:9000").unwrap(),
+      package: Some(package),
+    };
+
+    assert!(network.matches("local"));
+    assert_eq!(network.chain_id(), None);
+    assert_eq!(network.package_id(), Some(package));
+    assert_eq!(network.endpoint(), "http://127.0.0.1:9000/");
+    assert_eq!(IotaDID::placeholder(&(&network).into()).network_str(), "local");
+  }
+}
@@ -0,0 +1,167 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSync;
+use product_common::core_client::CoreClient;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+use tokio::sync::OnceCell;
+use tokio::sync::RwLock;
+
+use crate::rebased::migration::ControllerToken;
+use crate::rebased::migration::Identity;
+use crate::rebased::migration::OnChainIdentity;
+use crate::rebased::proposals::ConfigChange;
+use crate::rebased::proposals::DetachedCreateProposal;
+use crate::rebased::proposals::Proposal;
+use crate::rebased::proposals::ProposalResult;
+use crate::rebased::Error;
+use crate::rebased::IdentityError;
+use crate::IotaDID;
+
+use super::get_object_id_from_did;
+use super::IdentityClient;
+
+impl<S> IdentityClient<S>
+where
+  S: Signer<IotaKeySignature> + OptionalSync,
+{
+  /// Makes `address` a new controller of the Identity of `did`, with voting power `voting_power`.
+  ///
+  /// The change is executed right away when this client's controller has enough voting power, otherwise a
+  /// [Proposal] is created and left pending for the other controllers' approvals.
+  pub async fn add_controller(
+    &self,
+    did: &IotaDID,
+    address: IotaAddress,
+    voting_power: u64,
+  ) -> Result<TransactionBuilder<ControllerConfigChange>, Error> {
+    let mut config_change = ConfigChange::new();
+    config_change.add_controller(address, voting_power);
+
+    self.controller_config_change(did, config_change).await
+  }
+
+  /// Removes the controller with ID `controller_id` from the Identity of `did`.
+  ///
+  /// See [IdentityClient::add_controller] for when the change is executed.
+  pub async fn remove_controller(
+    &self,
+    did: &IotaDID,
+    controller_id: ObjectID,
+  ) -> Result<TransactionBuilder<ControllerConfigChange>, Error> {
+    let mut config_change = ConfigChange::new();
+    config_change.remove_controller(controller_id);
+
+    self.controller_config_change(did, config_change).await
+  }
+
+  /// Sets the threshold of the Identity of `did` to `threshold`.
+  ///
+  /// See [IdentityClient::add_controller] for when the change is executed.
+  pub async fn update_threshold(
+    &self,
+    did: &IotaDID,
+    threshold: u64,
+  ) -> Result<TransactionBuilder<ControllerConfigChange>, Error> {
+    let mut config_change = ConfigChange::new();
+    config_change.set_threshold(threshold);
+
+    self.controller_config_change(did, config_change).await
+  }
+
+  async fn controller_config_change(
+    &self,
+    did: &IotaDID,
+    config_change: ConfigChange,
+  ) -> Result<TransactionBuilder<ControllerConfigChange>, Error> {
+    let Identity::FullFledged(identity) = self.get_identity(get_object_id_from_did(did)?).await? else {
+      return Err(IdentityError::LegacyIdentity.into());
+    };
+
+    let controller_token = identity.get_controller_token(self).await?.ok_or_else(|| {
+      Error::from(IdentityError::NotAController {
+        address: self.sender_address(),
+        identity: identity.id(),
+      })
+    })?;
+
+    Ok(TransactionBuilder::new(ControllerConfigChange {
+      identity: RwLock::new(identity),
+      controller_token,
+      config_change,
+      cached_proposal: OnceCell::new(),
+    }))
+  }
+}
+
+/// The actual Transaction type returned by [IdentityClient::add_controller], [IdentityClient::remove_controller]
+/// and [IdentityClient::update_threshold].
+#[derive(Debug)]
+pub struct ControllerConfigChange {
+  identity: RwLock<OnChainIdentity>,
+  controller_token: ControllerToken,
+  config_change: ConfigChange,
+  cached_proposal: OnceCell<DetachedCreateProposal<ConfigChange>>,
+}
+
+impl ControllerConfigChange {
+  /// Returns the configuration change this transaction proposes.
+  pub fn config_change(&self) -> &ConfigChange {
+    &self.config_change
+  }
+
+  async fn make_proposal<C>(&self, client: &C) -> Result<DetachedCreateProposal<ConfigChange>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let mut identity = self.identity.write().await;
+    let mut proposal = identity.update_config(&self.controller_token);
+    *proposal = self.config_change.clone();
+
+    Ok(proposal.finish(client).await?.into_inner().detach())
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ControllerConfigChange {
+  type Error = Error;
+  type Output = ProposalResult<Proposal<ConfigChange>>;
+
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    self
+      .cached_proposal
+      .get_or_try_init(|| self.make_proposal(client))
+      .await
+      .map(|proposal| proposal.ptb().clone())
+  }
+
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let Self {
+      identity,
+      cached_proposal,
+      ..
+    } = self;
+    let proposal = cached_proposal
+      .into_inner()
+      .ok_or_else(|| Error::TransactionBuildingFailed("the transaction was never built".to_owned()))?;
+    let mut identity = identity.into_inner();
+
+    proposal.attach(&mut identity).apply(effects, client).await
+  }
+}
@@ -0,0 +1,276 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSync;
+use secret_storage::Signer;
+
+use crate::rebased::migration::get_identity_impl;
+use crate::rebased::migration::Identity;
+use crate::rebased::migration::IdentityResolutionError;
+use crate::rebased::migration::InsufficientControllerVotingPower;
+use crate::rebased::migration::NotAController;
+use crate::rebased::proposals::ProposedTxResult;
+use crate::rebased::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+
+use super::get_object_id_from_did;
+use super::IdentityClient;
+use super::IdentityClientReadOnly;
+
+/// An explicit acknowledgement that a DID Document is to be irreversibly deleted, required by
+/// [IdentityClient::delete_did].
+///
+/// Unlike deactivation, deletion cannot be undone: the DID Document is wiped from its Identity and the DID can
+/// never be updated nor reactivated again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteDidConfirmation {
+  did: IotaDID,
+}
+
+impl DeleteDidConfirmation {
+  /// Confirms that the DID Document of `did` - and of `did` only - is to be irreversibly deleted.
+  pub fn irreversibly_delete(did: IotaDID) -> Self {
+    Self { did }
+  }
+
+  /// Returns the DID whose deletion is confirmed.
+  pub fn did(&self) -> &IotaDID {
+    &self.did
+  }
+}
+
+/// What remains of a deleted DID Document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DidTombstone {
+  /// The deleted DID.
+  pub did: IotaDID,
+  /// The ID of the Identity that contained the deleted DID Document.
+  pub identity_id: ObjectID,
+}
+
+/// The outcome of [IdentityClientReadOnly::resolve_did_or_tombstone].
+#[derive(Debug, Clone)]
+pub enum DidResolution {
+  /// The DID Document, which may be deactivated.
+  Document(IotaDocument),
+  /// The DID Document has been deleted.
+  Deleted(DidTombstone),
+}
+
+impl DidResolution {
+  /// Returns the resolved DID Document, unless it has been deleted.
+  pub fn into_document(self) -> Option<IotaDocument> {
+    match self {
+      Self::Document(doc) => Some(doc),
+      Self::Deleted(_) => None,
+    }
+  }
+
+  /// Returns whether the DID Document has been deleted.
+  pub fn is_deleted(&self) -> bool {
+    matches!(self, Self::Deleted(_))
+  }
+}
+
+impl IdentityClientReadOnly {
+  /// Same as [IdentityClientReadOnly::resolve_did], but returns a [DidTombstone] instead of an error when the DID
+  /// Document has been deleted, so that deleted DIDs can be told apart from DIDs that never existed.
+  pub async fn resolve_did_or_tombstone(&self, did: &IotaDID) -> Result<DidResolution, Error> {
+    self.check_did_network(did)?;
+    let identity = self.get_identity(get_object_id_from_did(did)?).await?;
+
+    match identity {
+      Identity::FullFledged(identity) if identity.has_deleted_did() => Ok(DidResolution::Deleted(DidTombstone {
+        did: did.clone(),
+        identity_id: identity.id(),
+      })),
+      identity => identity.did_document(self.network()).map(DidResolution::Document),
+    }
+  }
+}
+
+impl<S> IdentityClient<S>
+where
+  S: Signer<IotaKeySignature> + OptionalSync,
+{
+  /// Irreversibly deletes the DID Document of `did`, as confirmed by `confirmation`.
+  ///
+  /// Before any transaction is submitted, this method makes sure that:
+  /// - `confirmation` refers to `did`;
+  /// - the DID Document hasn't been deleted already;
+  /// - the corresponding Identity has no outstanding proposals, which deleting its DID Document would leave dangling;
+  /// - this client is a controller with enough voting power to delete the DID Document without other controllers'
+  ///   approval.
+  ///
+  /// Once deleted, `did` resolves to the returned [DidTombstone] through
+  /// [IdentityClientReadOnly::resolve_did_or_tombstone]. Use [IdentityClient::deactivate_did_output] instead to
+  /// disable a DID Document in a reversible way.
+  pub async fn delete_did(
+    &self,
+    did: &IotaDID,
+    confirmation: DeleteDidConfirmation,
+    gas_budget: Option<u64>,
+  ) -> Result<DidTombstone, DeleteDidError> {
+    use DeleteDidErrorKind as ErrorKind;
+
+    let make_err = |kind| DeleteDidError { did: did.clone(), kind };
+
+    if confirmation.did != *did {
+      return Err(make_err(ErrorKind::ConfirmationMismatch {
+        confirmed: confirmation.did,
+      }));
+    }
+
+    self
+      .check_did_network(did)
+      .map_err(|e| make_err(ErrorKind::RpcError(e.into())))?;
+    let identity_id = get_object_id_from_did(did).map_err(|e| make_err(ErrorKind::RpcError(e.into())))?;
+    let mut identity = get_identity_impl(self, identity_id)
+      .await
+      .map_err(|e| make_err(e.into()))?;
+
+    if identity.has_deleted_did() {
+      return Err(make_err(ErrorKind::AlreadyDeleted));
+    }
+
+    let outstanding_proposals = identity.proposals().len();
+    if outstanding_proposals > 0 {
+      return Err(make_err(ErrorKind::OutstandingProposals {
+        count: outstanding_proposals,
+      }));
+    }
+
+    let controller_token = identity
+      .get_controller_token(self)
+      .await
+      .map_err(|e| make_err(ErrorKind::RpcError(e.into())))?
+      .ok_or_else(|| {
+        make_err(
+          NotAController {
+            address: self.address(),
+            identity: did.clone(),
+          }
+          .into(),
+        )
+      })?;
+
+    let vp = identity
+      .controller_voting_power(controller_token.controller_id())
+      .expect("is a controller");
+    let threshold = identity.threshold();
+    if vp < threshold {
+      return Err(make_err(
+        InsufficientControllerVotingPower {
+          controller_token_id: controller_token.controller_id(),
+          controller_voting_power: vp,
+          required: threshold,
+        }
+        .into(),
+      ));
+    }
+
+    let tx = identity
+      .delete_did(&controller_token)
+      .finish(self)
+      .await
+      .map_err(|e| make_err(ErrorKind::TransactionFailed(e.into())))?;
+    let tx = match gas_budget {
+      Some(gas_budget) => tx.with_gas_budget(gas_budget),
+      None => tx,
+    };
+    let output = tx
+      .build_and_execute(self)
+      .await
+      .map_err(|e| make_err(ErrorKind::TransactionFailed(e.into())))?
+      .output;
+
+    match output {
+      ProposedTxResult::Executed(()) => Ok(DidTombstone {
+        did: did.clone(),
+        identity_id,
+      }),
+      ProposedTxResult::Pending(proposal) => Err(make_err(ErrorKind::NotExecuted {
+        proposal_id: proposal.id(),
+      })),
+    }
+  }
+}
+
+/// [IdentityClient::delete_did] error.
+#[derive(Debug, thiserror::Error)]
+#[error("failed to delete DID Document '{did}'")]
+#[non_exhaustive]
+pub struct DeleteDidError {
+  /// The DID whose DID Document was being deleted.
+  pub did: IotaDID,
+  /// Specific type of failure for this error.
+  #[source]
+  pub kind: DeleteDidErrorKind,
+}
+
+/// Types of failure for [DeleteDidError].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum DeleteDidErrorKind {
+  /// The given [DeleteDidConfirmation] refers to another DID.
+  #[error("deletion was confirmed for DID '{confirmed}' instead")]
+  ConfirmationMismatch {
+    /// The DID the confirmation refers to.
+    confirmed: IotaDID,
+  },
+  /// Node RPC failure.
+  #[error(transparent)]
+  RpcError(Box<dyn std::error::Error + Send + Sync>),
+  /// Failed to resolve the corresponding [OnChainIdentity](crate::rebased::migration::OnChainIdentity).
+  #[error(transparent)]
+  IdentityResolution(#[from] IdentityResolutionError),
+  /// The DID Document has already been deleted.
+  #[error("Identity's DID Document is already deleted")]
+  AlreadyDeleted,
+  /// The Identity has proposals that are yet to be executed or deleted.
+  #[error("Identity has {count} outstanding proposal(s)")]
+  OutstandingProposals {
+    /// The number of outstanding proposals.
+    count: usize,
+  },
+  /// The invoking client is not a controller of the given DID document.
+  #[error(transparent)]
+  NotAController(#[from] NotAController),
+  /// The invoking client is a controller but doesn't have enough voting power
+  /// to delete the DID Document on its own.
+  #[error(transparent)]
+  InsufficientVotingPower(#[from] InsufficientControllerVotingPower),
+  /// The deletion transaction failed.
+  #[error("deletion transaction failed")]
+  TransactionFailed(#[source] Box<dyn std::error::Error + Send + Sync>),
+  /// The deletion was proposed, but not executed.
+  #[error("deletion was proposed through proposal '{proposal_id}', but not executed")]
+  NotExecuted {
+    /// The ID of the created proposal.
+    proposal_id: ObjectID,
+  },
+}
+
+#[cfg(test)]
+mod tests {
+  use product_common::network_name::NetworkName;
+
+  use super::*;
+
+  #[test]
+  fn tombstones_are_not_documents() {
+    let did = IotaDID::from_object_id(ObjectID::ZERO, &NetworkName::try_from("iota").unwrap());
+    let resolution = DidResolution::Deleted(DidTombstone {
+      did: did.clone(),
+      identity_id: ObjectID::ZERO,
+    });
+
+    assert!(resolution.is_deleted());
+    assert!(resolution.into_document().is_none());
+    assert_eq!(DeleteDidConfirmation::irreversibly_delete(did.clone()).did(), &did);
+  }
+}
@@ -0,0 +1,173 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::types::base_types::SequenceNumber;
+use iota_interaction::types::digests::ObjectDigest;
+use iota_interaction::types::digests::TransactionDigest;
+use product_common::core_client::CoreClientReadOnly as _;
+
+use crate::rebased::migration::get_identity_object_data;
+use crate::rebased::migration::identity_from_object_data;
+use crate::rebased::migration::IdentityResolutionErrorKind;
+use crate::rebased::rebased_err;
+use crate::rebased::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+
+use super::get_object_id_from_did;
+use super::IdentityClientReadOnly;
+
+/// A state of a DID Document, as stored in a given version of its Identity object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DidDocumentVersion {
+  /// The DID Document. Its metadata hold its creation and last update times.
+  pub document: IotaDocument,
+  /// The version of the Identity object holding this state of the DID Document.
+  pub version: SequenceNumber,
+  /// The digest of the Identity object at [`Self::version`].
+  pub digest: ObjectDigest,
+  /// The digest of the transaction that produced [`Self::version`], if returned by the node.
+  pub previous_transaction: Option<TransactionDigest>,
+}
+
+impl DidDocumentVersion {
+  /// Returns the time the DID Document was created.
+  pub fn created(&self) -> Option<Timestamp> {
+    self.document.metadata.created
+  }
+
+  /// Returns the time this state of the DID Document was produced.
+  pub fn updated(&self) -> Option<Timestamp> {
+    self.document.metadata.updated
+  }
+
+  /// Returns whether the DID Document was deactivated - or deleted - in this state.
+  pub fn is_deactivated(&self) -> bool {
+    self.document.metadata.deactivated.unwrap_or_default()
+  }
+
+  fn from_object_data(client: &IdentityClientReadOnly, data: IotaObjectData) -> Result<Self, Error> {
+    let version = data.version;
+    let digest = data.digest;
+    let previous_transaction = data.previous_transaction;
+    let identity = identity_from_object_data(client, data)
+      .map_err(|err| Error::InvalidIdentityHistory(format!("could not parse Identity version {version}; {err}")))?;
+
+    Ok(Self {
+      document: identity.did_doc,
+      version,
+      digest,
+      previous_transaction,
+    })
+  }
+}
+
+impl IdentityClientReadOnly {
+  /// Queries an [`IotaDocument`] DID Document through its `did`, together with the version of the Identity object it
+  /// is read from.
+  ///
+  /// Only DIDs referencing an Identity object are supported: DIDs of legacy Alias Outputs - migrated or not - cannot be
+  /// resolved this way.
+  ///
+  /// # Errors
+  /// [`Error::DIDResolutionError`] if the DID could not be resolved or if its DID Document was deleted.
+  pub async fn resolve_did_with_metadata(&self, did: &IotaDID) -> Result<DidDocumentVersion, Error> {
+    let data = self.identity_object_data(did).await?;
+    let identity = identity_from_object_data(self, data.clone())
+      .map_err(|err| Error::DIDResolutionError(format!("could not get identity document for {did}; {err}")))?;
+    if identity.has_deleted_did() {
+      return Err(Error::DIDResolutionError(format!("could not find DID Document {did}")));
+    }
+
+    Ok(DidDocumentVersion {
+      document: identity.did_doc,
+      version: data.version,
+      digest: data.digest,
+      previous_transaction: data.previous_transaction,
+    })
+  }
+
+  /// Returns all the states of the DID Document referenced by `did`, from the most recent to the first one.
+  ///
+  /// Each state is read from a version of the DID's Identity object, so the returned history also contains the
+  /// versions produced by updates that left the DID Document untouched, e.g. changes of the Identity's controllers.
+  /// Every version is fetched with a dedicated request: for Identities with long histories, consider paging through
+  /// [`OnChainIdentity::get_history`](crate::rebased::migration::OnChainIdentity::get_history) instead.
+  ///
+  /// # Example
+  /// Finding which DID Document was valid at the time a credential was issued:
+  /// ```ignore
+  /// let history = identity_client.did_document_history(&did).await?;
+  /// let at_issuance = history
+  ///   .iter()
+  ///   .find(|version| version.updated().is_some_and(|updated| updated <= issuance_date));
+  /// ```
+  ///
+  /// # Errors
+  /// - [`Error::DIDResolutionError`] if the DID's Identity object could not be found.
+  /// - [`Error::InvalidIdentityHistory`] if a past version of the Identity could not be fetched or parsed.
+  pub async fn did_document_history(&self, did: &IotaDID) -> Result<Vec<DidDocumentVersion>, Error> {
+    let mut current = self.identity_object_data(did).await?;
+    let mut history = vec![];
+    loop {
+      let previous = self.get_previous_version(current.clone()).await.map_err(rebased_err)?;
+      history.push(DidDocumentVersion::from_object_data(self, current)?);
+      match previous {
+        Some(previous) => current = previous,
+        None => break,
+      }
+    }
+
+    Ok(history)
+  }
+
+  /// Queries the [`IotaDocument`] DID Document of `did` as it was at time `at`, i.e. its most recent state that was
+  /// last updated no later than `at`.
+  ///
+  /// This allows verifying signatures produced with keys that have since been removed from the DID Document, e.g.
+  /// credentials issued before a key rotation. See [`Self::did_document_history`] for the cost of this query.
+  ///
+  /// # Errors
+  /// - [`Error::DIDResolutionError`] if the DID's Identity object could not be found, if its DID Document did not exist
+  ///   yet at `at` or was deactivated at that time.
+  /// - [`Error::InvalidIdentityHistory`] if a past version of the Identity could not be fetched or parsed.
+  pub async fn resolve_did_at(&self, did: &IotaDID, at: Timestamp) -> Result<DidDocumentVersion, Error> {
+    let version = self
+      .did_document_history(did)
+      .await?
+      .into_iter()
+      .find(|version| {
+        version
+          .updated()
+          .or(version.created())
+          .is_some_and(|updated| updated <= at)
+      })
+      .ok_or_else(|| Error::DIDResolutionError(format!("DID Document {did} did not exist at {at}")))?;
+    if version.is_deactivated() {
+      return Err(Error::DIDResolutionError(format!(
+        "DID Document {did} was deactivated at {at}"
+      )));
+    }
+
+    Ok(version)
+  }
+
+  async fn identity_object_data(&self, did: &IotaDID) -> Result<IotaObjectData, Error> {
+    self.check_did_network(did)?;
+    let object_id = get_object_id_from_did(did)?;
+    let options = IotaObjectDataOptions::new().with_content().with_previous_transaction();
+
+    get_identity_object_data(self, object_id, options)
+      .await
+      .map_err(|err| match err.kind {
+        IdentityResolutionErrorKind::NotFound => Error::DIDResolutionError(format!(
+          "could not find Identity object {object_id}; legacy DIDs have no version history"
+        )),
+        _ => Error::DIDResolutionError(format!("could not get Identity object {object_id}; {err}")),
+      })
+  }
+}
@@ -0,0 +1,438 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt as _;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::IotaClientTrait as _;
+
+use crate::did_resolution::resolution_error;
+use crate::rebased::migration::Identity;
+use crate::rebased::Error;
+use crate::rebased::IdentityError;
+use crate::DidResolutionHandler;
+use crate::IotaDID;
+use crate::IotaDocument;
+
+use super::DidDocumentVersion;
+use super::IdentityClientReadOnly;
+use super::RetryPolicy;
+
+/// Configuration of the failover behavior of a [`FailoverIdentityClientReadOnly`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct FailoverConfig {
+  /// The number of consecutive failed requests after which an endpoint is considered unhealthy.
+  ///
+  /// Default: `3`.
+  pub failure_threshold: u32,
+  /// How long an unhealthy endpoint is only tried after all healthy ones, before being given another chance.
+  ///
+  /// Default: 30 seconds.
+  pub cooldown: Duration,
+  /// The number of endpoints queried concurrently for each request, the first successful response being used.
+  ///
+  /// A value greater than `1` hedges requests against slow endpoints at the cost of additional load.
+  ///
+  /// Default: `1`.
+  pub hedging: usize,
+}
+
+impl Default for FailoverConfig {
+  fn default() -> Self {
+    Self {
+      failure_threshold: 3,
+      cooldown: Duration::seconds(30),
+      hedging: 1,
+    }
+  }
+}
+
+impl FailoverConfig {
+  /// Sets the number of consecutive failed requests after which an endpoint is considered unhealthy.
+  pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+    self.failure_threshold = failure_threshold;
+    self
+  }
+
+  /// Sets how long an unhealthy endpoint is deprioritized.
+  pub fn cooldown(mut self, cooldown: Duration) -> Self {
+    self.cooldown = cooldown;
+    self
+  }
+
+  /// Sets the number of endpoints queried concurrently for each request.
+  pub fn hedging(mut self, hedging: usize) -> Self {
+    self.hedging = hedging.max(1);
+    self
+  }
+}
+
+/// The health of an endpoint of a [`FailoverIdentityClientReadOnly`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EndpointHealth {
+  /// The number of consecutive failed requests to the endpoint.
+  pub consecutive_failures: u32,
+  /// When the endpoint was last considered unhealthy, if it currently is.
+  pub unhealthy_since: Option<Timestamp>,
+}
+
+impl EndpointHealth {
+  const HEALTHY: Self = Self {
+    consecutive_failures: 0,
+    unhealthy_since: None,
+  };
+
+  /// Returns whether the endpoint is considered healthy.
+  pub fn is_healthy(&self) -> bool {
+    self.unhealthy_since.is_none()
+  }
+
+  fn record_probe_failure(&mut self, failure_threshold: u32, now: Timestamp) {
+    self.consecutive_failures = self.consecutive_failures.saturating_add(1).max(failure_threshold);
+    self.unhealthy_since = Some(now);
+  }
+
+  fn record_failure(&mut self, failure_threshold: u32, now: Timestamp) {
+    self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+    if self.consecutive_failures >= failure_threshold {
+      // Restart the cooldown, so that an endpoint that keeps failing stays deprioritized.
+      self.unhealthy_since = Some(now);
+    }
+  }
+}
+
+struct Endpoint {
+  client: IdentityClientReadOnly,
+  health: Mutex<EndpointHealth>,
+}
+
+/// A read-only client spreading requests over several [`IdentityClientReadOnly`]s connected to different nodes of
+/// the same network, failing over to the next endpoint when one is down or lagging behind.
+///
+/// Endpoints are tried in the order they were given. An endpoint failing [`FailoverConfig::failure_threshold`]
+/// requests in a row is considered unhealthy and is only tried after all healthy endpoints until
+/// [`FailoverConfig::cooldown`] elapses. Any error causes the request to be retried on the next endpoint, except
+/// for errors that no endpoint would answer differently, such as [`Error::NotAnIdentity`] or an Identity that cannot
+/// be found, which are returned right away without affecting the endpoint's health.
+///
+/// Implements [`DidResolutionHandler`], so it can be attached to a `Resolver` in place of a single client.
+pub struct FailoverIdentityClientReadOnly {
+  endpoints: Vec<Endpoint>,
+  config: FailoverConfig,
+}
+
+impl FailoverIdentityClientReadOnly {
+  /// Creates a new [`FailoverIdentityClientReadOnly`] over `clients`, tried in the given order.
+  ///
+  /// # Errors
+  /// Returns [`Error::InvalidConfig`] if no client is given or if the clients are connected to different networks.
+  pub fn new(clients: impl IntoIterator<Item = IdentityClientReadOnly>) -> Result<Self, Error> {
+    let endpoints: Vec<Endpoint> = clients
+      .into_iter()
+      .map(|client| Endpoint {
+        client,
+        health: Mutex::new(EndpointHealth::HEALTHY),
+      })
+      .collect();
+
+    let Some(first) = endpoints.first() else {
+      return Err(Error::InvalidConfig("at least one client is required".to_owned()));
+    };
+    if let Some(other) = endpoints
+      .iter()
+      .find(|endpoint| endpoint.client.chain_id() != first.client.chain_id())
+    {
+      return Err(Error::InvalidConfig(format!(
+        "all clients must be connected to the same network, found `{}` and `{}`",
+        first.client.chain_id(),
+        other.client.chain_id()
+      )));
+    }
+
+    Ok(Self {
+      endpoints,
+      config: FailoverConfig::default(),
+    })
+  }
+
+  /// Connects to the nodes at `urls` and creates a new [`FailoverIdentityClientReadOnly`] over them.
+  ///
+  /// Nodes that cannot be reached are skipped, as long as at least one can. Requests are not
+  /// [retried](RetryPolicy) on the same node, but failed over to the next one right away.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub async fn from_urls<'u>(urls: impl IntoIterator<Item = &'u str>) -> Result<Self, Error> {
+    let mut clients: Vec<IdentityClientReadOnly> = Vec::new();
+    let mut last_error: Option<Error> = None;
+    for url in urls {
+      let client = match crate::rebased::utils::get_client(url).await {
+        Ok(iota_client) => IdentityClientReadOnly::new(iota_client)
+          .await
+          .map(|client| client.with_retry_policy(RetryPolicy::none())),
+        Err(error) => Err(error),
+      };
+      match client {
+        Ok(client) => clients.push(client),
+        Err(error) => last_error = Some(error),
+      }
+    }
+
+    match (clients.is_empty(), last_error) {
+      (true, Some(error)) => Err(error),
+      _ => Self::new(clients),
+    }
+  }
+
+  /// Replaces the failover configuration.
+  pub fn with_config(mut self, config: FailoverConfig) -> Self {
+    self.config = config;
+    self
+  }
+
+  /// Returns the failover configuration.
+  pub fn config(&self) -> &FailoverConfig {
+    &self.config
+  }
+
+  /// Returns the clients of this [`FailoverIdentityClientReadOnly`], in the order they were given.
+  pub fn clients(&self) -> impl Iterator<Item = &IdentityClientReadOnly> {
+    self.endpoints.iter().map(|endpoint| &endpoint.client)
+  }
+
+  /// Returns the health of each endpoint, in the order they were given.
+  pub fn health(&self) -> Vec<EndpointHealth> {
+    self.endpoints.iter().map(|endpoint| *endpoint.lock_health()).collect()
+  }
+
+  /// Actively probes every endpoint concurrently, returning their updated health in the order they were given.
+  ///
+  /// An endpoint that can't be reached, or that is connected to another network than the others, is immediately
+  /// considered unhealthy, while one that answers is considered healthy again, without waiting for its cooldown to
+  /// elapse. Long-running services, e.g. verifiers, can call this method periodically so that requests are not sent
+  /// to degraded endpoints in the first place.
+  pub async fn check_health(&self) -> Vec<EndpointHealth> {
+    let chain_id = self.endpoints[0].client.chain_id();
+    let probes: FuturesUnordered<_> = self
+      .endpoints
+      .iter()
+      .map(|endpoint| async move {
+        let healthy = endpoint
+          .client
+          .read_api()
+          .get_chain_identifier()
+          .await
+          .is_ok_and(|actual_chain_id| actual_chain_id == chain_id);
+        if healthy {
+          endpoint.record_success();
+        } else {
+          endpoint.record_probe_failure(self.config.failure_threshold);
+        }
+      })
+      .collect();
+    probes.collect::<()>().await;
+
+    self.health()
+  }
+
+  /// Queries an [`IotaDocument`] DID Document through its `did`, failing over across endpoints.
+  pub async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument, Error> {
+    self.endpoints[0].client.check_did_network(did)?;
+    self.with_failover(|client| client.resolve_did(did)).await
+  }
+
+  /// Queries the state of the DID Document of `did` at time `at`, failing over across endpoints.
+  ///
+  /// See [`IdentityClientReadOnly::resolve_did_at`].
+  pub async fn resolve_did_at(&self, did: &IotaDID, at: Timestamp) -> Result<DidDocumentVersion, Error> {
+    self.endpoints[0].client.check_did_network(did)?;
+    self.with_failover(|client| client.resolve_did_at(did, at)).await
+  }
+
+  /// Resolves an [`Identity`] from its ID `object_id`, failing over across endpoints.
+  pub async fn get_identity(&self, object_id: ObjectID) -> Result<Identity, Error> {
+    self.with_failover(|client| client.get_identity(object_id)).await
+  }
+
+  /// Runs `request` against the endpoints until one succeeds, recording their health along the way.
+  async fn with_failover<'a, T, F, Fut>(&'a self, request: F) -> Result<T, Error>
+  where
+    F: Fn(&'a IdentityClientReadOnly) -> Fut,
+    Fut: Future<Output = Result<T, Error>> + 'a,
+  {
+    let mut last_error: Option<Error> = None;
+    for batch in self.endpoint_order().chunks(self.config.hedging.max(1)) {
+      let mut attempts: FuturesUnordered<_> = batch
+        .iter()
+        .map(|&index| {
+          let attempt = request(&self.endpoints[index].client);
+          async move { (index, attempt.await) }
+        })
+        .collect();
+
+      while let Some((index, result)) = attempts.next().await {
+        match result {
+          Ok(value) => {
+            self.endpoints[index].record_success();
+            return Ok(value);
+          }
+          Err(error) if is_definitive(&error) => return Err(error),
+          Err(error) => {
+            self.endpoints[index].record_failure(self.config.failure_threshold);
+            last_error = Some(error);
+          }
+        }
+      }
+    }
+
+    Err(last_error.expect("at least one endpoint exists"))
+  }
+
+  /// Returns the indices of the endpoints in the order they should be tried: healthy endpoints - or unhealthy ones
+  /// whose cooldown elapsed - first, in the order they were given, then the others, least failing first.
+  fn endpoint_order(&self) -> Vec<usize> {
+    endpoint_order(&self.health(), self.config.cooldown, Timestamp::now_utc())
+  }
+}
+
+/// Orders the endpoints whose health is `health` as described in [`FailoverIdentityClientReadOnly::endpoint_order`].
+fn endpoint_order(health: &[EndpointHealth], cooldown: Duration, now: Timestamp) -> Vec<usize> {
+  let (mut preferred, mut deprioritized): (Vec<(usize, &EndpointHealth)>, Vec<(usize, &EndpointHealth)>) =
+    health.iter().enumerate().partition(|(_, health)| {
+      health
+        .unhealthy_since
+        .and_then(|since| since.checked_add(cooldown))
+        .map_or(true, |retry_at| retry_at <= now)
+    });
+  deprioritized.sort_by_key(|(_, health)| health.consecutive_failures);
+  preferred.append(&mut deprioritized);
+
+  preferred.into_iter().map(|(index, _)| index).collect()
+}
+
+impl Endpoint {
+  fn lock_health(&self) -> std::sync::MutexGuard<'_, EndpointHealth> {
+    self.health.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+
+  fn record_success(&self) {
+    *self.lock_health() = EndpointHealth::HEALTHY;
+  }
+
+  fn record_probe_failure(&self, failure_threshold: u32) {
+    self
+      .lock_health()
+      .record_probe_failure(failure_threshold, Timestamp::now_utc());
+  }
+
+  fn record_failure(&self, failure_threshold: u32) {
+    self
+      .lock_health()
+      .record_failure(failure_threshold, Timestamp::now_utc());
+  }
+}
+
+/// Returns whether `error` would be returned by any endpoint, making a failover pointless.
+fn is_definitive(error: &Error) -> bool {
+  matches!(
+    error,
+    Error::NotAnIdentity { .. } | Error::Identity(IdentityError::NotFound(_))
+  )
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl DidResolutionHandler for FailoverIdentityClientReadOnly {
+  async fn resolve_did(&self, did: &IotaDID) -> crate::Result<IotaDocument> {
+    self.resolve_did(did).await.map_err(|err| resolution_error(did, err))
+  }
+
+  async fn resolve_did_at(&self, did: &IotaDID, at: Timestamp) -> crate::Result<IotaDocument> {
+    self
+      .resolve_did_at(did, at)
+      .await
+      .map(|version| version.document)
+      .map_err(|err| resolution_error(did, err))
+  }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn missing_identities_are_not_failed_over() {
+    assert!(is_definitive(&IdentityError::NotFound(ObjectID::ZERO).into()));
+    assert!(is_definitive(&Error::NotAnIdentity {
+      object_id: ObjectID::ZERO,
+      actual_type: "0x2::coin::Coin<0x2::iota::IOTA>".to_owned(),
+    }));
+    assert!(!is_definitive(&Error::RpcError("connection reset".to_owned())));
+  }
+
+  #[test]
+  fn failures_make_endpoints_unhealthy_once_the_threshold_is_reached() {
+    let now = Timestamp::now_utc();
+    let mut health = EndpointHealth::HEALTHY;
+
+    health.record_failure(2, now);
+    assert_eq!(health.consecutive_failures, 1);
+    assert!(health.is_healthy());
+
+    health.record_failure(2, now);
+    assert_eq!(health.consecutive_failures, 2);
+    assert_eq!(health.unhealthy_since, Some(now));
+
+    // A failed probe makes an endpoint unhealthy right away.
+    let mut health = EndpointHealth::HEALTHY;
+    health.record_probe_failure(3, now);
+    assert_eq!(health.consecutive_failures, 3);
+    assert!(!health.is_healthy());
+  }
+
+  #[test]
+  fn unhealthy_endpoints_are_tried_last_until_their_cooldown_elapses() {
+    let cooldown = Duration::seconds(30);
+    let now = Timestamp::now_utc();
+    let unhealthy = |consecutive_failures, since| EndpointHealth {
+      consecutive_failures,
+      unhealthy_since: Some(since),
+    };
+    let health = [
+      unhealthy(5, now),
+      EndpointHealth::HEALTHY,
+      unhealthy(3, now),
+      EndpointHealth::HEALTHY,
+    ];
+
+    // Healthy endpoints keep their order, unhealthy ones follow, least failing first.
+    assert_eq!(endpoint_order(&health, cooldown, now), [1, 3, 2, 0]);
+
+    // Once the cooldown elapsed, unhealthy endpoints are tried in their original order again.
+    let later = now.checked_add(cooldown).unwrap();
+    assert_eq!(endpoint_order(&health, cooldown, later), [0, 1, 2, 3]);
+  }
+
+  #[tokio::test]
+  async fn health_checks_restore_reachable_endpoints() -> anyhow::Result<()> {
+    let iota_client = iota_sdk::IotaClientBuilder::default().build_testnet().await?;
+    let client = IdentityClientReadOnly::new(iota_client).await?;
+    let failover = FailoverIdentityClientReadOnly::new([client.clone(), client])?
+      .with_config(FailoverConfig::default().failure_threshold(1));
+
+    failover.endpoints[1].record_failure(failover.config.failure_threshold);
+    assert!(!failover.health()[1].is_healthy());
+    assert_eq!(failover.endpoint_order(), [0, 1]);
+
+    let health = failover.check_health().await;
+    assert!(health.iter().all(EndpointHealth::is_healthy));
+
+    Ok(())
+  }
+}
@@ -48,6 +48,7 @@ use crate::rebased::assets::AuthenticatedAssetBuilder;
 use crate::rebased::migration::Identity;
 use crate::rebased::migration::IdentityBuilder;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::IotaClientTrait;
 use iota_interaction::IotaKeySignature;
@@ -55,6 +56,9 @@ use iota_interaction::MoveType;
 use iota_interaction::OptionalSync;
 
 use super::IdentityClientReadOnly;
+use super::TransactionBuilderGasEstimationExt as _;
+use super::TransactionBuilderRetryExt as _;
+use super::DEFAULT_GAS_SAFETY_MARGIN_PERCENT;
 
 /// Mirrored types from identity_storage::KeyId
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -257,62 +261,71 @@ where
 
   // TODO: define what happens for (legacy|migrated|new) documents
   /// Updates a DID Document.
+  ///
+  /// When no `gas_budget` is given, it is [estimated](TransactionBuilderGasEstimationExt::estimate_gas) through a dry
+  /// run, with a [safety margin](DEFAULT_GAS_SAFETY_MARGIN_PERCENT).
   pub async fn publish_did_document_update(
     &self,
     document: IotaDocument,
-    gas_budget: u64,
+    gas_budget: impl Into<Option<u64>>,
   ) -> Result<IotaDocument, Error> {
     let mut oci =
       if let Identity::FullFledged(value) = self.get_identity(get_object_id_from_did(document.id())?).await? {
         value
       } else {
-        return Err(Error::Identity("only new identities can be updated".to_string()));
+        return Err(IdentityError::LegacyIdentity.into());
       };
 
     let controller_token = oci.get_controller_token(self).await?.ok_or_else(|| {
-      Error::Identity(format!(
-        "address {} has no control over Identity {}",
-        self.sender_address(),
-        oci.id()
-      ))
+      Error::from(IdentityError::NotAController {
+        address: self.sender_address(),
+        identity: oci.id(),
+      })
     })?;
 
-    oci
+    let tx = oci
       .update_did_document(document.clone(), &controller_token)
       .finish(self)
-      .await?
-      .with_gas_budget(gas_budget)
-      .build_and_execute(self)
-      .await
-      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))?;
+      .await?;
+    let tx = match gas_budget.into() {
+      Some(gas_budget) => tx.with_gas_budget(gas_budget),
+      None => {
+        tx.with_estimated_gas_budget(self, DEFAULT_GAS_SAFETY_MARGIN_PERCENT)
+          .await?
+      }
+    };
+    tx.build_and_execute_with_retry(self).await?;
 
     Ok(document)
   }
 
   /// Deactivates a DID document.
-  pub async fn deactivate_did_output(&self, did: &IotaDID, gas_budget: u64) -> Result<(), Error> {
+  ///
+  /// When no `gas_budget` is given, it is [estimated](TransactionBuilderGasEstimationExt::estimate_gas) through a dry
+  /// run, with a [safety margin](DEFAULT_GAS_SAFETY_MARGIN_PERCENT).
+  pub async fn deactivate_did_output(&self, did: &IotaDID, gas_budget: impl Into<Option<u64>>) -> Result<(), Error> {
     let mut oci = if let Identity::FullFledged(value) = self.get_identity(get_object_id_from_did(did)?).await? {
       value
     } else {
-      return Err(Error::Identity("only new identities can be deactivated".to_string()));
+      return Err(IdentityError::LegacyIdentity.into());
     };
 
     let controller_token = oci.get_controller_token(self).await?.ok_or_else(|| {
-      Error::Identity(format!(
-        "address {} has no control over Identity {}",
-        self.sender_address(),
-        oci.id()
-      ))
+      Error::from(IdentityError::NotAController {
+        address: self.sender_address(),
+        identity: oci.id(),
+      })
     })?;
 
-    oci
-      .deactivate_did(&controller_token)
-      .finish(self)
-      .await?
-      .with_gas_budget(gas_budget)
-      .build_and_execute(self)
-      .await
-      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))?;
+    let tx = oci.deactivate_did(&controller_token).finish(self).await?;
+    let tx = match gas_budget.into() {
+      Some(gas_budget) => tx.with_gas_budget(gas_budget),
+      None => {
+        tx.with_estimated_gas_budget(self, DEFAULT_GAS_SAFETY_MARGIN_PERCENT)
+          .await?
+      }
+    };
+    tx.build_and_execute_with_retry(self).await?;
 
     Ok(())
   }
@@ -472,6 +485,7 @@ pub fn get_sender_public_key(sender_public_jwk: &Jwk) -> Result<Vec<u8>, Error>
 pub struct PublishDidDocument {
   did_document: IotaDocument,
   controller: IotaAddress,
+  encoding: StateMetadataEncoding,
   cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -481,14 +495,25 @@ impl PublishDidDocument {
     Self {
       did_document,
       controller,
+      encoding: StateMetadataEncoding::default(),
       cached_ptb: OnceCell::new(),
     }
   }
 
+  /// Sets the [StateMetadataEncoding] used to store the DID Document on-chain.
+  /// Defaults to [StateMetadataEncoding::Json].
+  ///
+  /// Later updates of the DID Document keep this encoding.
+  pub fn with_state_metadata_encoding(mut self, encoding: StateMetadataEncoding) -> Self {
+    self.encoding = encoding;
+    self.cached_ptb = OnceCell::new();
+    self
+  }
+
   async fn make_ptb(&self, client: &impl CoreClientReadOnly) -> Result<ProgrammableTransaction, Error> {
     let package = identity_package_id(client).await?;
     let did_doc = StateMetadataDocument::from(self.did_document.clone())
-      .pack(StateMetadataEncoding::Json)
+      .pack(self.encoding)
       .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
 
     let programmable_tx_bcs =
@@ -517,7 +542,8 @@ impl Transaction for PublishDidDocument {
     let tx = {
       let builder = IdentityBuilder::new(self.did_document)
         .threshold(1)
-        .controller(self.controller, 1);
+        .controller(self.controller, 1)
+        .state_metadata_encoding(self.encoding);
       CreateIdentity::new(builder)
     };
 
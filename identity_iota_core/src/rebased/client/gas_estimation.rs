@@ -0,0 +1,130 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaExecutionStatus;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_interaction::types::gas::GasCostSummary;
+use iota_interaction::IotaClientTrait as _;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+
+use crate::rebased::Error;
+
+use super::IdentityClient;
+use super::TransactionBuilderOfflineExt as _;
+
+/// The safety margin, in percent, added on top of the estimated gas cost when no other is given.
+pub const DEFAULT_GAS_SAFETY_MARGIN_PERCENT: u64 = 20;
+
+/// The gas a transaction is expected to cost, as estimated by
+/// [TransactionBuilderGasEstimationExt::estimate_gas] through a dry run.
+///
+/// All amounts are in NANOS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GasEstimate {
+  /// The cost of the computation.
+  pub computation_cost: u64,
+  /// The cost of the storage used by the objects the transaction creates or mutates.
+  pub storage_cost: u64,
+  /// The refund for the storage freed by the objects the transaction deletes or mutates.
+  pub storage_rebate: u64,
+}
+
+impl GasEstimate {
+  fn new(summary: &GasCostSummary) -> Self {
+    Self {
+      computation_cost: summary.computation_cost,
+      storage_cost: summary.storage_cost,
+      storage_rebate: summary.storage_rebate,
+    }
+  }
+
+  /// Returns a gas budget covering this estimate plus `safety_margin_percent` percent of it.
+  ///
+  /// The storage rebate is not deducted, as the budget must cover the costs before any refund.
+  pub fn budget(&self, safety_margin_percent: u64) -> u64 {
+    let cost = self.computation_cost.saturating_add(self.storage_cost);
+    let margin = cost.saturating_mul(safety_margin_percent).saturating_add(99) / 100;
+
+    cost.saturating_add(margin)
+  }
+}
+
+/// Extension trait to estimate the gas of a [TransactionBuilder]'s transaction through a dry run.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderGasEstimationExt<Tx: Transaction>: Sized {
+  /// Dry runs the transaction with `client`'s address as its sender, returning the estimated gas cost together with
+  /// a new builder for the same transaction.
+  ///
+  /// The transaction is built without being signed. Gas settings of this builder - e.g. sponsor or gas payment -
+  /// are not carried over to the returned one, so estimation must happen before they are set.
+  async fn estimate_gas<S>(self, client: &IdentityClient<S>) -> Result<(GasEstimate, Self), Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync;
+
+  /// Sets the gas budget of the transaction to its [estimate](Self::estimate_gas) plus `safety_margin_percent`
+  /// percent of it - see [DEFAULT_GAS_SAFETY_MARGIN_PERCENT].
+  async fn with_estimated_gas_budget<S>(
+    self,
+    client: &IdentityClient<S>,
+    safety_margin_percent: u64,
+  ) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    let (estimate, builder) = self.estimate_gas(client).await?;
+    Ok(builder.with_gas_budget(estimate.budget(safety_margin_percent)))
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> TransactionBuilderGasEstimationExt<Tx> for TransactionBuilder<Tx>
+where
+  Tx: Transaction + OptionalSend + OptionalSync,
+{
+  async fn estimate_gas<S>(self, client: &IdentityClient<S>) -> Result<(GasEstimate, Self), Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    let offline_tx = self.build_offline(client, client.public_key().clone()).await?;
+    let response = client
+      .read_api()
+      .dry_run_transaction_block(offline_tx.tx_data().clone())
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))?;
+
+    if let IotaExecutionStatus::Failure { error } = response.effects.status() {
+      return Err(Error::GasIssue(format!(
+        "the dry run of the transaction failed: {error}"
+      )));
+    }
+
+    let estimate = GasEstimate::new(response.effects.gas_cost_summary());
+    Ok((estimate, TransactionBuilder::new(offline_tx.into_transaction())))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn budget_includes_safety_margin() {
+    let estimate = GasEstimate {
+      computation_cost: 1_000_000,
+      storage_cost: 2_000_001,
+      storage_rebate: 1_500_000,
+    };
+
+    assert_eq!(estimate.budget(0), 3_000_001);
+    assert_eq!(estimate.budget(DEFAULT_GAS_SAFETY_MARGIN_PERCENT), 3_600_002);
+  }
+}
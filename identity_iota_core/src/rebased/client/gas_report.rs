@@ -0,0 +1,203 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use iota_interaction::types::digests::TransactionDigest;
+use iota_interaction::types::gas::GasCostSummary;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(not(target_arch = "wasm32"))]
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+#[cfg(not(target_arch = "wasm32"))]
+use product_common::transaction::TransactionOutput;
+
+/// The identity operation a transaction carried out, used to tag [GasReport]s.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum IdentityOperation {
+  /// Publication of a new DID document.
+  Publish,
+  /// Update of an existing DID document.
+  Update,
+  /// Deactivation of a DID document.
+  Deactivate,
+  /// Change of an identity's controllers or threshold.
+  ControllerChange,
+  /// Any other, application-defined, operation.
+  Other(String),
+}
+
+impl Display for IdentityOperation {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Publish => f.write_str("publish"),
+      Self::Update => f.write_str("update"),
+      Self::Deactivate => f.write_str("deactivate"),
+      Self::ControllerChange => f.write_str("controller_change"),
+      Self::Other(operation) => f.write_str(operation),
+    }
+  }
+}
+
+/// The gas an executed transaction cost, tagged with the [IdentityOperation] it carried out and the network it was
+/// executed on.
+///
+/// All amounts are in NANOS.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GasReport {
+  /// The operation the transaction carried out.
+  pub operation: IdentityOperation,
+  /// The network the transaction was executed on, e.g. as returned by
+  /// [IdentityClientReadOnly::network](super::IdentityClientReadOnly::network).
+  pub network: String,
+  /// The digest of the transaction.
+  pub digest: TransactionDigest,
+  /// The cost of the computation.
+  pub computation_cost: u64,
+  /// The cost of the storage used by the objects the transaction created or mutated.
+  pub storage_cost: u64,
+  /// The refund for the storage freed by the objects the transaction deleted or mutated.
+  pub storage_rebate: u64,
+  /// The part of the storage fee that is not refunded.
+  pub non_refundable_storage_fee: u64,
+}
+
+impl GasReport {
+  /// Creates a new [GasReport] out of the gas cost summary of transaction `digest`.
+  pub fn new(
+    operation: IdentityOperation,
+    network: impl Into<String>,
+    digest: TransactionDigest,
+    summary: &GasCostSummary,
+  ) -> Self {
+    Self {
+      operation,
+      network: network.into(),
+      digest,
+      computation_cost: summary.computation_cost,
+      storage_cost: summary.storage_cost,
+      storage_rebate: summary.storage_rebate,
+      non_refundable_storage_fee: summary.non_refundable_storage_fee,
+    }
+  }
+
+  /// Creates a new [GasReport] out of the output of an executed transaction.
+  ///
+  /// Returns `None` if the transaction's response doesn't contain its effects.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn from_output<T>(
+    operation: IdentityOperation,
+    network: impl Into<String>,
+    output: &TransactionOutput<T>,
+  ) -> Option<Self> {
+    let effects = output.response.effects.as_ref()?;
+    Some(Self::new(
+      operation,
+      network,
+      output.response.digest,
+      effects.gas_cost_summary(),
+    ))
+  }
+
+  /// Returns the gas the transaction's sender was charged, i.e. the computation and storage costs minus the storage
+  /// rebate. A negative amount means the sender was refunded more than it paid.
+  pub fn net_gas_cost(&self) -> i128 {
+    i128::from(self.computation_cost) + i128::from(self.storage_cost) - i128::from(self.storage_rebate)
+  }
+
+  /// Aggregates `reports` per network and operation.
+  pub fn aggregate<'a, I>(reports: I) -> BTreeMap<(String, IdentityOperation), GasAggregate>
+  where
+    I: IntoIterator<Item = &'a GasReport>,
+  {
+    let mut aggregates: BTreeMap<(String, IdentityOperation), GasAggregate> = BTreeMap::new();
+    for report in reports {
+      aggregates
+        .entry((report.network.clone(), report.operation.clone()))
+        .or_default()
+        .add(report);
+    }
+
+    aggregates
+  }
+}
+
+/// The total gas cost of a set of [GasReport]s, as computed by [GasReport::aggregate].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GasAggregate {
+  /// The number of aggregated reports.
+  pub count: u64,
+  /// The total computation cost.
+  pub computation_cost: u128,
+  /// The total storage cost.
+  pub storage_cost: u128,
+  /// The total storage rebate.
+  pub storage_rebate: u128,
+  /// The total non-refundable storage fee.
+  pub non_refundable_storage_fee: u128,
+}
+
+impl GasAggregate {
+  /// Adds `report` to this aggregate.
+  pub fn add(&mut self, report: &GasReport) {
+    self.count += 1;
+    self.computation_cost += u128::from(report.computation_cost);
+    self.storage_cost += u128::from(report.storage_cost);
+    self.storage_rebate += u128::from(report.storage_rebate);
+    self.non_refundable_storage_fee += u128::from(report.non_refundable_storage_fee);
+  }
+
+  /// Returns the total gas charged, see [GasReport::net_gas_cost].
+  pub fn net_gas_cost(&self) -> i128 {
+    // Sums of `u64`s can't overflow an `i128` in any realistic scenario.
+    self.computation_cost as i128 + self.storage_cost as i128 - self.storage_rebate as i128
+  }
+
+  /// Returns the average gas charged per operation, or `None` if no report was aggregated.
+  pub fn average_net_gas_cost(&self) -> Option<i128> {
+    (self.count > 0).then(|| self.net_gas_cost() / i128::from(self.count))
+  }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+  use super::*;
+
+  fn report(operation: IdentityOperation, network: &str, computation_cost: u64, storage_rebate: u64) -> GasReport {
+    GasReport {
+      operation,
+      network: network.to_owned(),
+      digest: TransactionDigest::random(),
+      computation_cost,
+      storage_cost: 2_000,
+      storage_rebate,
+      non_refundable_storage_fee: 10,
+    }
+  }
+
+  #[test]
+  fn reports_are_aggregated_per_network_and_operation() {
+    let reports = [
+      report(IdentityOperation::Publish, "testnet", 1_000, 0),
+      report(IdentityOperation::Update, "testnet", 1_000, 1_500),
+      report(IdentityOperation::Update, "testnet", 3_000, 1_500),
+      report(IdentityOperation::Update, "mainnet", 1_000, 1_500),
+    ];
+    let aggregates = GasReport::aggregate(&reports);
+    assert_eq!(aggregates.len(), 3);
+
+    let updates = aggregates[&("testnet".to_owned(), IdentityOperation::Update)];
+    assert_eq!(updates.count, 2);
+    assert_eq!(updates.computation_cost, 4_000);
+    assert_eq!(updates.net_gas_cost(), 5_000);
+    assert_eq!(updates.average_net_gas_cost(), Some(2_500));
+    assert_eq!(reports[0].net_gas_cost(), 3_000);
+    assert_eq!(GasAggregate::default().average_net_gas_cost(), None);
+  }
+}
@@ -0,0 +1,248 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::Coin;
+use iota_interaction::rpc_types::Page;
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::transaction::GasData;
+use iota_interaction::types::transaction::TransactionDataAPI as _;
+use iota_interaction::IotaClientTrait as _;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use iota_interaction::IOTA_COIN_TYPE;
+use product_common::core_client::CoreClient as _;
+use product_common::transaction::transaction_builder::MutGasDataRef;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+
+use crate::rebased::Error;
+
+use super::IdentityClient;
+use super::IdentityClientReadOnly;
+
+/// An account paying for the gas of transactions sent by others - e.g. a service account of a wallet provider,
+/// sponsoring the transactions of users holding no tokens.
+///
+/// See [TransactionBuilderGasSponsorExt::with_gas_sponsor]. Sponsors that cannot sign locally - e.g. a remote gas
+/// station - can instead be plugged in through [TransactionBuilder::with_sponsor]'s callback.
+#[derive(Debug, Clone)]
+pub struct GasSponsor<S> {
+  address: IotaAddress,
+  signer: S,
+  gas_budget: Option<u64>,
+}
+
+impl<S> GasSponsor<S>
+where
+  S: Signer<IotaKeySignature> + OptionalSync,
+{
+  /// Creates a new [GasSponsor] paying with the coins owned by `address`, and signing with `signer`.
+  pub fn new(address: IotaAddress, signer: S) -> Self {
+    Self {
+      address,
+      signer,
+      gas_budget: None,
+    }
+  }
+
+  /// Caps the gas the sponsor pays for a single transaction to `gas_budget`, overriding the transaction's own
+  /// budget.
+  pub fn with_gas_budget(mut self, gas_budget: u64) -> Self {
+    self.gas_budget = Some(gas_budget);
+    self
+  }
+
+  /// Returns the address of the sponsor.
+  pub fn address(&self) -> IotaAddress {
+    self.address
+  }
+
+  /// Returns the gas budget of the sponsor, if any.
+  pub fn gas_budget(&self) -> Option<u64> {
+    self.gas_budget
+  }
+
+  /// Pays for the gas of `tx_data` with a coin of the sponsor large enough to cover its budget, and signs it.
+  ///
+  /// All the coins of the sponsor are considered, the first one covering the budget is used.
+  pub async fn sponsor(
+    &self,
+    client: &IdentityClientReadOnly,
+    mut tx_data: MutGasDataRef<'_>,
+  ) -> Result<Signature, Error> {
+    let budget = self.gas_budget.unwrap_or_else(|| tx_data.gas_budget());
+    let price = tx_data.gas_price();
+    let coin = find_gas_coin(budget, |cursor| async move {
+      client
+        .coin_read_api()
+        .get_coins(self.address, Some(IOTA_COIN_TYPE.to_owned()), cursor, None)
+        .await
+    })
+    .await
+    .map_err(|e| Error::RpcError(e.to_string()))?
+    .ok_or_else(|| {
+      Error::GasIssue(format!(
+        "gas sponsor {} owns no coin covering a gas budget of {budget}",
+        self.address
+      ))
+    })?;
+
+    *tx_data.gas_data_mut() = GasData {
+      price,
+      payment: vec![coin.object_ref()],
+      owner: self.address,
+      budget,
+    };
+
+    self
+      .signer
+      .sign(&tx_data)
+      .await
+      .map_err(|e| Error::TransactionSigningFailed(e.to_string()))
+  }
+}
+
+/// Extension trait to have the gas of a [TransactionBuilder]'s transaction paid by a [GasSponsor].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderGasSponsorExt<Tx: Transaction>: Sized {
+  /// Has `sponsor` pay for the gas of the transaction, which `client`'s signer - e.g. an identity controller - only
+  /// signs as its sender.
+  async fn with_gas_sponsor<S, S2>(self, client: &IdentityClient<S>, sponsor: &GasSponsor<S2>) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    S2: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> TransactionBuilderGasSponsorExt<Tx> for TransactionBuilder<Tx>
+where
+  Tx: Transaction + OptionalSend + OptionalSync,
+{
+  async fn with_gas_sponsor<S, S2>(self, client: &IdentityClient<S>, sponsor: &GasSponsor<S2>) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    S2: Signer<IotaKeySignature> + OptionalSync,
+  {
+    self
+      .with_sender(client.sender_address())
+      .with_sponsor(client, |tx_data| async move {
+        sponsor.sponsor(client, tx_data).await.map_err(anyhow::Error::from)
+      })
+      .await
+      .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))
+  }
+}
+
+/// Returns the first coin with a balance of at least `budget`, going through the pages returned by `next_page` -
+/// called with the cursor of the previous page - until one is found or there are no more pages.
+async fn find_gas_coin<C, E, F, Fut>(budget: u64, mut next_page: F) -> Result<Option<Coin>, E>
+where
+  F: FnMut(Option<C>) -> Fut,
+  Fut: Future<Output = Result<Page<Coin, C>, E>>,
+{
+  let mut cursor = None;
+  loop {
+    let page = next_page(cursor).await?;
+    if let Some(coin) = page.data.into_iter().find(|coin| coin.balance >= budget) {
+      return Ok(Some(coin));
+    }
+    if !page.has_next_page || page.next_cursor.is_none() {
+      return Ok(None);
+    }
+    cursor = page.next_cursor;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::convert::Infallible;
+
+  use iota_interaction::types::base_types::ObjectID;
+  use iota_interaction::types::base_types::SequenceNumber;
+  use iota_interaction::types::digests::ObjectDigest;
+  use iota_interaction::types::digests::TransactionDigest;
+
+  use super::*;
+
+  fn coin(balance: u64) -> Coin {
+    Coin {
+      coin_type: IOTA_COIN_TYPE.to_owned(),
+      coin_object_id: ObjectID::random(),
+      version: SequenceNumber::new(),
+      digest: ObjectDigest::random(),
+      balance,
+      previous_transaction: TransactionDigest::random(),
+    }
+  }
+
+  /// Splits `balances` into pages of `page_size` coins, the cursor being the index of the next page.
+  fn pages(balances: &[u64], page_size: usize) -> Vec<Page<Coin, usize>> {
+    let chunks: Vec<_> = balances.chunks(page_size).collect();
+    chunks
+      .iter()
+      .enumerate()
+      .map(|(index, chunk)| {
+        let has_next_page = index + 1 < chunks.len();
+        Page {
+          data: chunk.iter().copied().map(coin).collect(),
+          next_cursor: has_next_page.then_some(index + 1),
+          has_next_page,
+        }
+      })
+      .collect()
+  }
+
+  async fn find(budget: u64, pages: Vec<Page<Coin, usize>>) -> (Option<u64>, Vec<Option<usize>>) {
+    let mut requested = Vec::new();
+    let coin = find_gas_coin(budget, |cursor: Option<usize>| {
+      requested.push(cursor);
+      let page = pages[cursor.unwrap_or(0)].clone();
+      async move { Ok::<_, Infallible>(page) }
+    })
+    .await
+    .unwrap();
+
+    (coin.map(|coin| coin.balance), requested)
+  }
+
+  #[tokio::test]
+  async fn first_coin_covering_the_budget_is_selected() {
+    let (balance, requested) = find(100, pages(&[10, 150, 100, 1_000], 10)).await;
+
+    assert_eq!(balance, Some(150));
+    assert_eq!(requested, vec![None]);
+  }
+
+  #[tokio::test]
+  async fn coins_beyond_the_first_page_are_considered() {
+    let (balance, requested) = find(100, pages(&[10, 20, 30, 40, 500], 2)).await;
+
+    assert_eq!(balance, Some(500));
+    assert_eq!(requested, vec![None, Some(1), Some(2)]);
+  }
+
+  #[tokio::test]
+  async fn insufficient_balance_is_reported() {
+    // The coins' total balance covers the budget, but the gas must be paid with a single coin.
+    let (balance, requested) = find(100, pages(&[60, 60, 99], 2)).await;
+
+    assert_eq!(balance, None);
+    assert_eq!(requested, vec![None, Some(1)]);
+
+    let no_coins = Page {
+      data: vec![],
+      next_cursor: None,
+      has_next_page: false,
+    };
+    let (balance, _) = find(1, vec![no_coins]).await;
+    assert_eq!(balance, None);
+  }
+}
@@ -0,0 +1,314 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use futures::pin_mut;
+use futures::Stream;
+use futures::StreamExt as _;
+use iota_interaction::move_types::identifier::Identifier;
+use iota_interaction::rpc_types::EventFilter;
+use iota_interaction::rpc_types::IotaEvent;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::types::base_types::ObjectID;
+use serde::Deserialize;
+
+use crate::rebased::migration::get_identity_object_data;
+use crate::rebased::migration::identity_from_object_data;
+use crate::rebased::proposals::ProposalEvent;
+use crate::rebased::Error;
+use crate::IotaDocument;
+
+use super::IdentityClientReadOnly;
+
+/// A change to an Identity, see [`IdentityClientReadOnly::subscribe_identity_events`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IdentityEvent {
+  /// A controller created a proposal that still needs approvals to be executed.
+  ProposalCreated {
+    /// The ID of the proposal.
+    proposal: ObjectID,
+    /// The ID of the controller token used to create the proposal.
+    controller: ObjectID,
+  },
+  /// A proposal reached the Identity's threshold and can now be executed.
+  ProposalApproved {
+    /// The ID of the proposal.
+    proposal: ObjectID,
+  },
+  /// A proposal was executed.
+  ProposalExecuted {
+    /// The ID of the proposal.
+    proposal: ObjectID,
+    /// The ID of the controller token used to execute the proposal.
+    controller: ObjectID,
+  },
+  /// The Identity's DID Document was updated.
+  DocumentUpdated {
+    /// The updated DID Document.
+    document: IotaDocument,
+  },
+  /// A controller was added to the Identity.
+  ControllerAdded {
+    /// The ID of the new controller's token.
+    controller: ObjectID,
+    /// The voting power of the new controller.
+    voting_power: u64,
+  },
+  /// A controller was removed from the Identity.
+  ControllerRemoved {
+    /// The ID of the removed controller's token.
+    controller: ObjectID,
+  },
+  /// The Identity's DID Document was deactivated.
+  Deactivated,
+  /// The Identity's DID Document was deleted.
+  Deleted,
+}
+
+impl IdentityClientReadOnly {
+  /// Returns a stream of the changes made to the Identity with ID `object_id` from now on.
+  ///
+  /// Proposal events are received through a subscription to the events the Identity package emits for `object_id`,
+  /// which requires the client this [`IdentityClientReadOnly`] was created from to be connected through WebSocket,
+  /// e.g. with `IotaClientBuilder::ws_url`. Whenever a proposal is executed, the Identity is fetched again and the
+  /// resulting changes - to its DID Document and controllers - are yielded right after the
+  /// [`IdentityEvent::ProposalExecuted`] event. Changes are computed between the observed states of the Identity, so
+  /// that the changes of proposals executed in quick succession may be merged.
+  ///
+  /// Only the events emitted once the subscription is established are yielded. The stream never ends on its own:
+  /// drop it to unsubscribe.
+  ///
+  /// # Example
+  /// ```ignore
+  /// let mut events = pin!(identity_client.subscribe_identity_events(identity_id));
+  /// while let Some(event) = events.try_next().await? {
+  ///   if let IdentityEvent::DocumentUpdated { document } = event {
+  ///     cache.insert(document.id().clone(), document);
+  ///   }
+  /// }
+  /// ```
+  ///
+  /// # Errors
+  /// The stream yields an error and ends if the subscription cannot be established or is closed by the node, or if
+  /// the Identity cannot be fetched.
+  pub fn subscribe_identity_events(
+    &self,
+    object_id: ObjectID,
+  ) -> impl Stream<Item = Result<IdentityEvent, Error>> + '_ {
+    async_stream::try_stream! {
+      // Subscribe before fetching the Identity, so that no change goes unnoticed in between.
+      let events = self
+        .sdk_client
+        .event_api()
+        .subscribe_event(identity_event_filter(&self.package_history, object_id))
+        .await
+        .map_err(|e| Error::RpcError(e.to_string()))?;
+      pin_mut!(events);
+      let mut state = IdentityState::fetch(self, object_id).await?;
+
+      while let Some(event) = events.next().await {
+        let event = event.map_err(|e| Error::RpcError(e.to_string()))?;
+        let Some(event) = parse_event(&event, object_id) else {
+          continue;
+        };
+        let executed = matches!(event, IdentityEvent::ProposalExecuted { .. });
+        yield event;
+
+        if executed {
+          let new_state = IdentityState::fetch(self, object_id).await?;
+          for event in state.changes_to(&new_state) {
+            yield event;
+          }
+          state = new_state;
+        }
+      }
+
+      Err(Error::RpcError("the node closed the event subscription".to_owned()))?;
+    }
+  }
+}
+
+/// Matches the events emitted by any version of the Identity package - whose IDs are `packages` - for the Identity
+/// `object_id`.
+fn identity_event_filter(packages: &[ObjectID], object_id: ObjectID) -> EventFilter {
+  let module = Identifier::new("identity").expect("valid identifier");
+  let emitted_by_identity_package = packages
+    .iter()
+    .map(|&package| EventFilter::MoveEventModule {
+      package,
+      module: module.clone(),
+    })
+    .collect();
+
+  EventFilter::All(vec![
+    EventFilter::Any(emitted_by_identity_package),
+    EventFilter::MoveEventField {
+      path: "/identity".to_owned(),
+      value: serde_json::json!(object_id),
+    },
+  ])
+}
+
+/// Converts `event` into an [`IdentityEvent`], if it concerns the Identity `object_id`.
+fn parse_event(event: &IotaEvent, object_id: ObjectID) -> Option<IdentityEvent> {
+  #[derive(Deserialize)]
+  struct ProposalApproved {
+    identity: ObjectID,
+    proposal: ObjectID,
+  }
+
+  if event.type_.module.as_str() != "identity" {
+    return None;
+  }
+  match event.type_.name.as_str() {
+    "ProposalEvent" => serde_json::from_value::<ProposalEvent>(event.parsed_json.clone())
+      .ok()
+      .filter(|event| event.identity == object_id)
+      .map(|event| {
+        if event.executed {
+          IdentityEvent::ProposalExecuted {
+            proposal: event.proposal,
+            controller: event.controller,
+          }
+        } else {
+          IdentityEvent::ProposalCreated {
+            proposal: event.proposal,
+            controller: event.controller,
+          }
+        }
+      }),
+    "ProposalApproved" => serde_json::from_value::<ProposalApproved>(event.parsed_json.clone())
+      .ok()
+      .filter(|event| event.identity == object_id)
+      .map(|event| IdentityEvent::ProposalApproved {
+        proposal: event.proposal,
+      }),
+    _ => None,
+  }
+}
+
+/// The parts of an Identity whose changes are reported.
+struct IdentityState {
+  document: IotaDocument,
+  controllers: HashMap<ObjectID, u64>,
+  deleted: bool,
+}
+
+impl IdentityState {
+  async fn fetch(client: &IdentityClientReadOnly, object_id: ObjectID) -> Result<Self, Error> {
+    let data = get_identity_object_data(client, object_id, IotaObjectDataOptions::new().with_content())
+      .await
+      .map_err(|err| Error::DIDResolutionError(format!("could not get Identity object {object_id}; {err}")))?;
+    let identity = identity_from_object_data(client, data)
+      .map_err(|err| Error::DIDResolutionError(format!("could not parse Identity object {object_id}; {err}")))?;
+
+    Ok(Self {
+      controllers: identity.controllers().clone(),
+      deleted: identity.has_deleted_did(),
+      document: identity.did_doc,
+    })
+  }
+
+  fn is_deactivated(&self) -> bool {
+    self.document.metadata.deactivated.unwrap_or_default()
+  }
+
+  /// Returns the events leading from this state to `new`.
+  fn changes_to(&self, new: &Self) -> Vec<IdentityEvent> {
+    let mut events = vec![];
+    if new.deleted && !self.deleted {
+      events.push(IdentityEvent::Deleted);
+    } else if new.is_deactivated() && !self.is_deactivated() {
+      events.push(IdentityEvent::Deactivated);
+    } else if new.document != self.document {
+      events.push(IdentityEvent::DocumentUpdated {
+        document: new.document.clone(),
+      });
+    }
+
+    let mut added: Vec<_> = new
+      .controllers
+      .iter()
+      .filter(|(controller, _)| !self.controllers.contains_key(controller))
+      .map(|(&controller, &voting_power)| IdentityEvent::ControllerAdded {
+        controller,
+        voting_power,
+      })
+      .collect();
+    let mut removed: Vec<_> = self
+      .controllers
+      .keys()
+      .filter(|controller| !new.controllers.contains_key(controller))
+      .map(|&controller| IdentityEvent::ControllerRemoved { controller })
+      .collect();
+    // Keep the order deterministic.
+    let controller_of = |event: &IdentityEvent| match event {
+      IdentityEvent::ControllerAdded { controller, .. } | IdentityEvent::ControllerRemoved { controller } => {
+        *controller
+      }
+      _ => unreachable!(),
+    };
+    added.sort_by_key(controller_of);
+    removed.sort_by_key(controller_of);
+    events.extend(removed);
+    events.extend(added);
+
+    events
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use product_common::network_name::NetworkName;
+
+  use super::*;
+
+  fn state(controllers: &[(ObjectID, u64)]) -> IdentityState {
+    IdentityState {
+      document: IotaDocument::new(&NetworkName::try_from("iota").unwrap()),
+      controllers: controllers.iter().copied().collect(),
+      deleted: false,
+    }
+  }
+
+  #[test]
+  fn state_changes_are_reported() {
+    let (a, b) = (ObjectID::from_single_byte(1), ObjectID::from_single_byte(2));
+    let old = state(&[(a, 1)]);
+    assert!(old.changes_to(&state(&[(a, 1)])).is_empty());
+
+    let mut new = state(&[(b, 2)]);
+    new.document.metadata.deactivated = Some(true);
+    assert_eq!(
+      old.changes_to(&new),
+      vec![
+        IdentityEvent::Deactivated,
+        IdentityEvent::ControllerRemoved { controller: a },
+        IdentityEvent::ControllerAdded {
+          controller: b,
+          voting_power: 2
+        },
+      ]
+    );
+
+    new.deleted = true;
+    assert_eq!(old.changes_to(&new)[0], IdentityEvent::Deleted);
+  }
+
+  #[test]
+  fn subscription_is_restricted_to_the_identity() {
+    let packages = [ObjectID::from_single_byte(1), ObjectID::from_single_byte(2)];
+    let identity = ObjectID::from_single_byte(3);
+
+    let EventFilter::All(filters) = identity_event_filter(&packages, identity) else {
+      panic!("expected a conjunction of filters");
+    };
+    assert!(matches!(&filters[0], EventFilter::Any(modules) if modules.len() == packages.len()));
+    assert!(matches!(
+      &filters[1],
+      EventFilter::MoveEventField { path, value } if path == "/identity" && *value == serde_json::json!(identity)
+    ));
+  }
+}
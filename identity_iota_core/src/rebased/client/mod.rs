@@ -1,11 +1,38 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod controller_management;
+mod did_deletion;
+mod did_history;
+mod failover;
 mod full_client;
+mod gas_estimation;
+mod gas_report;
+mod gas_sponsor;
+#[cfg(not(target_arch = "wasm32"))]
+mod identity_events;
+mod move_objects;
+mod offline;
 mod read_only;
+mod retry;
+mod state_cross_check;
+mod tx_journal;
 
+pub use controller_management::*;
+pub use did_deletion::*;
+pub use did_history::*;
+pub use failover::*;
 pub use full_client::*;
-
+pub use gas_estimation::*;
+pub use gas_report::*;
+pub use gas_sponsor::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use identity_events::*;
+pub use move_objects::*;
+pub use offline::*;
 pub use read_only::*;
+pub use retry::*;
+pub use state_cross_check::*;
+pub use tx_journal::*;
 
 pub use iota_interaction::IotaKeySignature;
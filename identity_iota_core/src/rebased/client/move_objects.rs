@@ -0,0 +1,184 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use futures::stream::FuturesOrdered;
+use futures::StreamExt as _;
+use iota_interaction::move_types::language_storage::StructTag;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::rpc_types::IotaObjectResponse;
+use iota_interaction::rpc_types::IotaParsedData;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::TypeTag;
+use iota_interaction::IotaClientTrait;
+use iota_interaction::MoveType;
+use iota_interaction::OptionalSync;
+use product_common::core_client::CoreClientReadOnly;
+use serde::de::DeserializeOwned;
+
+use crate::rebased::Error;
+
+/// Extension trait to read Move objects of a known type through any [CoreClientReadOnly], e.g.
+/// [IdentityClientReadOnly](super::IdentityClientReadOnly).
+///
+/// Objects are deserialized from their BCS representation when the node provides it, falling back to their JSON
+/// representation otherwise. Before being deserialized, an object's type is checked against `T` as published in any
+/// version of the client's package - see [CoreClientReadOnly::package_history] - so that objects created before a
+/// package upgrade can be read too, while same-named types of unrelated packages are rejected.
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+pub trait MoveObjectReadExt {
+  /// Fetches the object `object_id` and deserializes it as a `T`.
+  ///
+  /// # Errors
+  /// - [Error::RpcError] if the node couldn't be queried.
+  /// - [Error::ObjectLookup] if the object doesn't exist, is not a `T`, or cannot be deserialized as one.
+  async fn get_move_object<T>(&self, object_id: ObjectID) -> Result<T, Error>
+  where
+    T: MoveType + DeserializeOwned + Send;
+
+  /// Fetches the objects `object_ids` concurrently and deserializes them as `T`s, returning the outcome of each
+  /// in the order the IDs were given.
+  async fn get_move_objects<T>(&self, object_ids: &[ObjectID]) -> Vec<Result<T, Error>>
+  where
+    T: MoveType + DeserializeOwned + Send;
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl<C> MoveObjectReadExt for C
+where
+  C: CoreClientReadOnly + OptionalSync,
+{
+  async fn get_move_object<T>(&self, object_id: ObjectID) -> Result<T, Error>
+  where
+    T: MoveType + DeserializeOwned + Send,
+  {
+    let options = IotaObjectDataOptions::new().with_content().with_bcs();
+    let response = self
+      .client_adapter()
+      .read_api()
+      .get_object_with_options(object_id, options)
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))?;
+
+    parse_move_object(object_id, response, &self.package_history())
+  }
+
+  async fn get_move_objects<T>(&self, object_ids: &[ObjectID]) -> Vec<Result<T, Error>>
+  where
+    T: MoveType + DeserializeOwned + Send,
+  {
+    object_ids
+      .iter()
+      .map(|&object_id| self.get_move_object::<T>(object_id))
+      .collect::<FuturesOrdered<_>>()
+      .collect()
+      .await
+  }
+}
+
+/// Deserializes the object contained in `response` as a `T` published in one of the packages of `package_history`,
+/// preferring its BCS representation.
+fn parse_move_object<T>(
+  object_id: ObjectID,
+  response: IotaObjectResponse,
+  package_history: &[ObjectID],
+) -> Result<T, Error>
+where
+  T: MoveType + DeserializeOwned,
+{
+  let bcs_result = response.move_object_bcs().map(|bytes| bcs::from_bytes::<T>(bytes));
+  let Some(data) = response.data else {
+    return Err(Error::ObjectLookup(
+      response
+        .error
+        .map_or_else(|| format!("object `{object_id}` not found"), |e| e.to_string()),
+    ));
+  };
+  let Some(IotaParsedData::MoveObject(object)) = data.content else {
+    return Err(Error::ObjectLookup(format!(
+      "object `{object_id}` is not a Move object"
+    )));
+  };
+
+  check_move_type::<T>(object_id, &object.type_, package_history)?;
+
+  match bcs_result {
+    Some(Ok(value)) => Ok(value),
+    bcs_result => serde_json::from_value(object.fields.to_json_value()).map_err(|json_err| {
+      let bcs_err = match bcs_result {
+        Some(Err(e)) => e.to_string(),
+        _ => "no BCS representation".to_owned(),
+      };
+      Error::ObjectLookup(format!(
+        "failed to deserialize object `{object_id}` from BCS ({bcs_err}) or JSON ({json_err})"
+      ))
+    }),
+  }
+}
+
+/// Checks that `object_type` is `T` as published in one of the packages of `package_history`.
+fn check_move_type<T: MoveType>(
+  object_id: ObjectID,
+  object_type: &StructTag,
+  package_history: &[ObjectID],
+) -> Result<(), Error> {
+  let is_expected_type = package_history
+    .iter()
+    .any(|&package| matches!(T::move_type(package), TypeTag::Struct(expected) if *expected == *object_type));
+  if is_expected_type {
+    return Ok(());
+  }
+
+  let expected = match T::move_type(ObjectID::ZERO) {
+    TypeTag::Struct(tag) => format!("{}::{}", tag.module, tag.name),
+    type_tag => type_tag.to_canonical_string(true),
+  };
+  Err(Error::ObjectLookup(format!(
+    "object `{object_id}` is a `{}`, not a `{expected}` of any version of the client's package",
+    object_type.to_canonical_string(true),
+  )))
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::rebased::migration::ControllerCap;
+  use crate::rebased::migration::DelegationToken;
+
+  use super::*;
+
+  fn struct_tag<T: MoveType>(package: ObjectID) -> StructTag {
+    let TypeTag::Struct(tag) = T::move_type(package) else {
+      panic!("T must be a Move struct")
+    };
+    *tag
+  }
+
+  #[test]
+  fn types_of_any_package_version_are_accepted() {
+    let (original, upgraded) = (ObjectID::from_single_byte(1), ObjectID::from_single_byte(2));
+    let history = [original, upgraded];
+    let object_id = ObjectID::from_single_byte(3);
+
+    assert!(check_move_type::<ControllerCap>(object_id, &struct_tag::<ControllerCap>(original), &history).is_ok());
+    assert!(check_move_type::<ControllerCap>(object_id, &struct_tag::<ControllerCap>(upgraded), &history).is_ok());
+  }
+
+  #[test]
+  fn types_of_other_packages_are_rejected() {
+    let history = [ObjectID::from_single_byte(1)];
+    let object_id = ObjectID::from_single_byte(3);
+
+    let same_name_other_package = struct_tag::<ControllerCap>(ObjectID::from_single_byte(2));
+    assert!(matches!(
+      check_move_type::<ControllerCap>(object_id, &same_name_other_package, &history),
+      Err(Error::ObjectLookup(_))
+    ));
+    let other_type = struct_tag::<DelegationToken>(history[0]);
+    assert!(matches!(
+      check_move_type::<ControllerCap>(object_id, &other_type, &history),
+      Err(Error::ObjectLookup(_))
+    ));
+  }
+}
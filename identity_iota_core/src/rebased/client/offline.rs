@@ -0,0 +1,245 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::IotaTransactionBlockResponseOptions;
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::crypto::PublicKey;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::crypto::ToFromBytes;
+use iota_interaction::types::quorum_driver_types::ExecuteTransactionRequestType;
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::IotaClientTrait as _;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Error as SecretStorageError;
+use secret_storage::Signer;
+
+use crate::rebased::Error;
+
+use super::IdentityClient;
+use super::IdentityClientReadOnly;
+
+/// A transaction built for a sender whose key is not available to the client - e.g. a controller key kept in cold
+/// storage - see [TransactionBuilderOfflineExt::build_offline].
+///
+/// The transaction is carried to the signing machine through [OfflineTransaction::tx_bytes], signed there with
+/// [sign_transaction_bytes], and executed back online through [OfflineTransaction::execute_signed].
+#[derive(Debug)]
+pub struct OfflineTransaction<Tx> {
+  tx_data: TransactionData,
+  tx: Tx,
+}
+
+impl<Tx> OfflineTransaction<Tx> {
+  /// Returns the data of the transaction to sign.
+  pub fn tx_data(&self) -> &TransactionData {
+    &self.tx_data
+  }
+
+  /// Consumes this [OfflineTransaction], returning the transaction it was built from.
+  pub fn into_transaction(self) -> Tx {
+    self.tx
+  }
+
+  /// Returns the base64-encoded BCS serialization of the transaction to sign.
+  pub fn tx_bytes(&self) -> Result<String, Error> {
+    Ok(BaseEncoding::encode(&bcs::to_bytes(&self.tx_data)?, Base::Base64Pad))
+  }
+
+  /// Executes the transaction with the base64-encoded `signature` produced offline, and applies its effects.
+  pub async fn execute_signed(self, client: &IdentityClientReadOnly, signature: &str) -> Result<Tx::Output, Error>
+  where
+    Tx: Transaction,
+    Tx::Error: Display,
+  {
+    let mut effects = client
+      .execute_signed_transaction_data(self.tx_data, vec![decode_signature(signature)?])
+      .await?;
+
+    self
+      .tx
+      .apply(&mut effects, client)
+      .await
+      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))
+  }
+}
+
+/// Extension trait to build the transaction of a [TransactionBuilder] without signing it.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderOfflineExt<Tx: Transaction> {
+  /// Builds the transaction - selecting gas and gas budget - with `sender` as its sender, leaving it unsigned.
+  ///
+  /// `sender` is the public key of the offline signer, which pays for gas unless a sponsor is set.
+  async fn build_offline(
+    self,
+    client: &IdentityClientReadOnly,
+    sender: PublicKey,
+  ) -> Result<OfflineTransaction<Tx>, Error>;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> TransactionBuilderOfflineExt<Tx> for TransactionBuilder<Tx>
+where
+  Tx: Transaction + OptionalSend + OptionalSync,
+{
+  async fn build_offline(
+    self,
+    client: &IdentityClientReadOnly,
+    sender: PublicKey,
+  ) -> Result<OfflineTransaction<Tx>, Error> {
+    let offline_client = IdentityClient {
+      read_client: client.clone(),
+      public_key: Some(sender.clone()),
+      signer: OfflineSigner { public_key: sender },
+    };
+    let (tx_data, _, tx) = self
+      .build(&offline_client)
+      .await
+      .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+
+    Ok(OfflineTransaction { tx_data, tx })
+  }
+}
+
+/// Signs the base64-encoded transaction `tx_bytes` - see [OfflineTransaction::tx_bytes] - with `signer`, e.g. a
+/// `StorageSigner` on an air-gapped machine, returning the base64-encoded signature.
+pub async fn sign_transaction_bytes<S>(tx_bytes: &str, signer: &S) -> Result<String, Error>
+where
+  S: Signer<IotaKeySignature> + ?Sized,
+{
+  let bytes =
+    BaseEncoding::decode(tx_bytes, Base::Base64Pad).map_err(|e| Error::InvalidArgument(format!("tx_bytes: {e}")))?;
+  let tx_data: TransactionData = bcs::from_bytes(&bytes)?;
+  let signature = signer
+    .sign(&tx_data)
+    .await
+    .map_err(|e| Error::TransactionSigningFailed(e.to_string()))?;
+
+  Ok(BaseEncoding::encode(signature.as_ref(), Base::Base64Pad))
+}
+
+impl IdentityClientReadOnly {
+  /// Executes the base64-encoded transaction `tx_bytes` with the base64-encoded `signature`, both produced
+  /// offline - see [OfflineTransaction] - returning the transaction's effects.
+  ///
+  /// Prefer [OfflineTransaction::execute_signed] when the [OfflineTransaction] is still available, as it also
+  /// applies the transaction's off-chain effects.
+  pub async fn execute_signed_transaction(
+    &self,
+    tx_bytes: &str,
+    signature: &str,
+  ) -> Result<IotaTransactionBlockEffects, Error> {
+    let bytes =
+      BaseEncoding::decode(tx_bytes, Base::Base64Pad).map_err(|e| Error::InvalidArgument(format!("tx_bytes: {e}")))?;
+    let tx_data: TransactionData = bcs::from_bytes(&bytes)?;
+
+    self
+      .execute_signed_transaction_data(tx_data, vec![decode_signature(signature)?])
+      .await
+  }
+
+  pub(crate) async fn execute_signed_transaction_data(
+    &self,
+    tx_data: TransactionData,
+    signatures: Vec<Signature>,
+  ) -> Result<IotaTransactionBlockEffects, Error> {
+    let response = self
+      .quorum_driver_api()
+      .execute_transaction_block(
+        tx_data,
+        signatures,
+        Some(IotaTransactionBlockResponseOptions::full_content()),
+        Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+      )
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))?;
+
+    response
+      .effects()
+      .cloned()
+      .ok_or_else(|| Error::TransactionUnexpectedResponse("the response contains no effects".to_owned()))
+  }
+}
+
+fn decode_signature(signature: &str) -> Result<Signature, Error> {
+  let bytes =
+    BaseEncoding::decode(signature, Base::Base64Pad).map_err(|e| Error::InvalidArgument(format!("signature: {e}")))?;
+  Signature::from_bytes(&bytes).map_err(|e| Error::InvalidArgument(format!("signature: {e}")))
+}
+
+/// A [Signer] standing in for a key that is not available.
+///
+/// It returns a placeholder signature - the all-zero signature of the signer's scheme - which is never submitted.
+struct OfflineSigner {
+  public_key: PublicKey,
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl Signer<IotaKeySignature> for OfflineSigner {
+  type KeyId = IotaAddress;
+
+  fn key_id(&self) -> Self::KeyId {
+    IotaAddress::from(&self.public_key)
+  }
+
+  async fn public_key(&self) -> Result<PublicKey, SecretStorageError> {
+    Ok(self.public_key.clone())
+  }
+
+  async fn sign(&self, _data: &TransactionData) -> Result<Signature, SecretStorageError> {
+    let mut placeholder = vec![self.public_key.flag()];
+    placeholder.extend([0; 64]);
+    placeholder.extend_from_slice(self.public_key.as_ref());
+    Signature::from_bytes(&placeholder).map_err(|e| SecretStorageError::Other(e.into()))
+  }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+  use iota_interaction::types::base_types::ObjectID;
+  use iota_interaction::types::base_types::SequenceNumber;
+  use iota_interaction::types::crypto::IotaKeyPair;
+  use iota_interaction::types::crypto::SignatureScheme;
+  use iota_interaction::types::digests::ObjectDigest;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn placeholder_signatures_round_trip_through_base64() {
+    for scheme in [
+      SignatureScheme::ED25519,
+      SignatureScheme::Secp256k1,
+      SignatureScheme::Secp256r1,
+    ] {
+      let public_key = IotaKeyPair::generate(scheme).public();
+      let signer = OfflineSigner {
+        public_key: public_key.clone(),
+      };
+      let tx_data = TransactionData::new_transfer_iota(
+        IotaAddress::ZERO,
+        signer.key_id(),
+        (ObjectID::ZERO, SequenceNumber::new(), ObjectDigest::random()),
+        1_000,
+        1_000,
+        None,
+      );
+
+      let signature = signer.sign(&tx_data).await.unwrap();
+
+      let encoded = BaseEncoding::encode(signature.as_ref(), Base::Base64Pad);
+      assert_eq!(decode_signature(&encoded).unwrap(), signature);
+    }
+  }
+}
@@ -19,8 +19,10 @@ use iota_interaction::move_types::language_storage::StructTag;
 use iota_interaction::rpc_types::IotaObjectDataFilter;
 use iota_interaction::rpc_types::IotaObjectDataOptions;
 use iota_interaction::rpc_types::IotaObjectResponseQuery;
+use iota_interaction::rpc_types::ObjectsPage;
 use iota_interaction::types::base_types::IotaAddress;
 use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::base_types::ObjectType;
 use iota_interaction::types::TypeTag;
 use iota_interaction::IotaClientTrait;
 use iota_interaction::MoveType;
@@ -36,9 +38,15 @@ use crate::rebased::migration::ControllerCap;
 use crate::rebased::migration::ControllerToken;
 use crate::rebased::migration::DelegationToken;
 use crate::rebased::migration::Identity;
+use crate::rebased::migration::OnChainIdentity;
+use crate::rebased::migration::UnmigratedAlias;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use crate::IotaDID;
 use crate::IotaDocument;
+use crate::Network;
+
+use super::RetryPolicy;
 
 #[cfg(not(target_arch = "wasm32"))]
 use iota_interaction::IotaClient;
@@ -51,9 +59,13 @@ use iota_interaction_ts::bindings::WasmIotaClient;
 #[derive(Clone)]
 pub struct IdentityClientReadOnly {
   iota_client: IotaClientAdapter,
+  /// The wrapped client, for the APIs the adapter doesn't abstract over, e.g. event subscriptions.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub(super) sdk_client: IotaClient,
   package_history: Vec<ObjectID>,
   network: NetworkName,
   chain_id: String,
+  pub(super) retry_policy: RetryPolicy,
 }
 
 impl Deref for IdentityClientReadOnly {
@@ -100,12 +112,24 @@ impl IdentityClientReadOnly {
     #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
     #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
   ) -> Result<Self, Error> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let sdk_client = iota_client.clone();
     let client = IotaClientAdapter::new(iota_client);
     let network = network_id(&client).await?;
-    Self::new_internal(client, network).await
+    Self::new_internal(
+      client,
+      #[cfg(not(target_arch = "wasm32"))]
+      sdk_client,
+      network,
+    )
+    .await
   }
 
-  async fn new_internal(iota_client: IotaClientAdapter, network: NetworkName) -> Result<Self, Error> {
+  async fn new_internal(
+    iota_client: IotaClientAdapter,
+    #[cfg(not(target_arch = "wasm32"))] sdk_client: IotaClient,
+    network: NetworkName,
+  ) -> Result<Self, Error> {
     let chain_id = network.as_ref().to_string();
     let (network, package_history) = {
       let package_registry = iota::package::identity_package_registry().await;
@@ -126,9 +150,12 @@ impl IdentityClientReadOnly {
     };
     Ok(IdentityClientReadOnly {
       iota_client,
+      #[cfg(not(target_arch = "wasm32"))]
+      sdk_client,
       package_history,
       network,
       chain_id,
+      retry_policy: RetryPolicy::default(),
     })
   }
 
@@ -140,6 +167,8 @@ impl IdentityClientReadOnly {
     #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
     package_id: ObjectID,
   ) -> Result<Self, Error> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let sdk_client = iota_client.clone();
     let client = IotaClientAdapter::new(iota_client);
     let network = network_id(&client).await?;
 
@@ -149,7 +178,60 @@ impl IdentityClientReadOnly {
       registry.insert_new_package_version(&network, package_id);
     }
 
-    Self::new_internal(client, network).await
+    Self::new_internal(
+      client,
+      #[cfg(not(target_arch = "wasm32"))]
+      sdk_client,
+      network,
+    )
+    .await
+  }
+
+  /// Attempts to create a new [`IdentityClientReadOnly`] from the given IOTA client, which must be connected
+  /// to `network`.
+  ///
+  /// # Failures
+  /// This function fails if `iota_client` is connected to a network other than `network`, or if the ID of
+  /// the `iota_identity` package published on a custom `network` is neither given nor already known.
+  pub async fn new_with_network(
+    #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
+    #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
+    network: &Network,
+  ) -> Result<Self, Error> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let sdk_client = iota_client.clone();
+    let client = IotaClientAdapter::new(iota_client);
+    let chain_id = network_id(&client).await?;
+    if let Some(expected_chain_id) = network.chain_id() {
+      if chain_id.as_ref() != expected_chain_id {
+        return Err(Error::InvalidConfig(format!(
+          "network `{network}` has chain ID `{expected_chain_id}`, but the client is connected to chain `{chain_id}`"
+        )));
+      }
+    }
+
+    if let Network::Custom { hrp, package, .. } = network {
+      let mut registry = iota::package::identity_package_registry_mut().await;
+      if let Some(package) = package {
+        registry.insert_new_package_version(&chain_id, *package);
+      }
+      registry.insert_alias(hrp.as_ref(), chain_id.as_ref());
+    }
+
+    Self::new_internal(
+      client,
+      #[cfg(not(target_arch = "wasm32"))]
+      sdk_client,
+      chain_id,
+    )
+    .await
+  }
+
+  /// Connects to `network` through its [endpoint](Network::endpoint).
+  #[cfg(not(target_arch = "wasm32"))]
+  pub async fn connect(network: &Network) -> Result<Self, Error> {
+    let iota_client = crate::rebased::utils::get_client(network.endpoint()).await?;
+    Self::new_with_network(iota_client, network).await
   }
 
   /// Sets the migration registry ID for the current network.
@@ -161,8 +243,20 @@ impl IdentityClientReadOnly {
 
   /// Queries an [`IotaDocument`] DID Document through its `did`.
   pub async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument, Error> {
-    // Make sure `did` references a DID Document on the network
-    // this client is connected to.
+    self.check_did_network(did)?;
+    let identity = self.get_identity(get_object_id_from_did(did)?).await?;
+    let did_doc = identity.did_document(self.network())?;
+
+    match identity {
+      Identity::FullFledged(identity) if identity.has_deleted_did() => {
+        Err(Error::DIDResolutionError(format!("could not find DID Document {did}")))
+      }
+      _ => Ok(did_doc),
+    }
+  }
+
+  /// Makes sure `did` references a DID Document on the network this client is connected to.
+  pub(crate) fn check_did_network(&self, did: &IotaDID) -> Result<(), Error> {
     let did_network = did.network_str();
     let client_network = self.network.as_ref();
     if did_network != client_network && did_network != self.chain_id() {
@@ -172,19 +266,23 @@ impl IdentityClientReadOnly {
         but this client is connected to network `{client_network}`"
       )));
     }
-    let identity = self.get_identity(get_object_id_from_did(did)?).await?;
-    let did_doc = identity.did_document(self.network())?;
 
-    match identity {
-      Identity::FullFledged(identity) if identity.has_deleted_did() => {
-        Err(Error::DIDResolutionError(format!("could not find DID Document {did}")))
-      }
-      _ => Ok(did_doc),
-    }
+    Ok(())
   }
 
   /// Resolves an [`Identity`] from its ID `object_id`.
+  ///
+  /// # Errors
+  /// Returns [`Error::NotAnIdentity`] when `object_id` refers to an object that is neither an Identity created
+  /// through one of this client's packages nor a legacy Identity, and [`IdentityError::NotFound`] when it refers to
+  /// no object at all.
+  ///
+  /// The lookup is retried according to this client's [`RetryPolicy`] when the node can't be reached.
   pub async fn get_identity(&self, object_id: ObjectID) -> Result<Identity, Error> {
+    self.retry_policy.retry(|| self.get_identity_once(object_id)).await
+  }
+
+  async fn get_identity_once(&self, object_id: ObjectID) -> Result<Identity, Error> {
     // spawn all checks
     cfg_if::cfg_if! {
       // Unfortunately the compiler runs into lifetime problems if we try to use a 'type ='
@@ -199,21 +297,70 @@ impl IdentityClientReadOnly {
     all_futures.push(Box::pin(resolve_migrated(self, object_id)));
     all_futures.push(Box::pin(resolve_unmigrated(self, object_id)));
 
-    all_futures
+    let identity = all_futures
       .filter_map(|res| Box::pin(async move { res.ok().flatten() }))
       .next()
+      .await;
+
+    match identity {
+      Some(identity) => Ok(identity),
+      None => Err(self.identity_resolution_failure(object_id).await),
+    }
+  }
+
+  /// Explains why no [`Identity`] could be resolved from `object_id`, telling apart objects that are not Identities
+  /// from objects that don't exist or couldn't be parsed, and from a node that can't be reached.
+  async fn identity_resolution_failure(&self, object_id: ObjectID) -> Error {
+    let response = match self
+      .iota_client
+      .read_api()
+      .get_object_with_options(object_id, IotaObjectDataOptions::new().with_type())
       .await
-      .ok_or_else(|| Error::DIDResolutionError(format!("could not find DID document for {object_id}")))
+    {
+      Ok(response) => response,
+      Err(e) => return Error::RpcError(e.to_string()),
+    };
+    let object_type = match response.data.map(|data| data.object_type()) {
+      Some(Ok(object_type)) => object_type,
+      _ => return IdentityError::NotFound(object_id).into(),
+    };
+
+    let actual_type: TypeTag = match object_type {
+      ObjectType::Struct(move_type) => move_type.into(),
+      ObjectType::Package => {
+        return Error::NotAnIdentity {
+          object_id,
+          actual_type: "Move package".to_owned(),
+        }
+      }
+    };
+    let is_identity = self
+      .package_history
+      .iter()
+      .map(|package| OnChainIdentity::move_type(*package))
+      .chain(std::iter::once(UnmigratedAlias::move_type(self.package_id())))
+      .any(|identity_type| identity_type == actual_type);
+
+    if is_identity {
+      // The object is an Identity that failed to be parsed.
+      Error::DIDResolutionError(format!("could not parse DID document for {object_id}"))
+    } else {
+      Error::NotAnIdentity {
+        object_id,
+        actual_type: actual_type.to_canonical_string(true),
+      }
+    }
   }
 
   /// Returns a stream yielding the unique DIDs the given address can access as a controller.
   /// # Notes
   /// This is a streaming version of [dids_controlled_by](Self::dids_controlled_by).
   /// # Errors
-  /// This stream might return a [QueryControlledDidsError] when the underlying RPC call fails.
+  /// This stream might return a [QueryControlledDidsError] when the underlying RPC call fails, after being retried
+  /// according to this client's [`RetryPolicy`].
   /// When an error occurs, the stream might successfully yield a value if polled again, depending
   /// on the actual RPC error.
-  /// [QueryControlledDidsError]'s source can be downcasted to [SDK's Error](iota_interaction::error::Error).
+  /// [QueryControlledDidsError]'s source can be downcasted to [Error], see [`RetryPolicy::is_retryable`].
   /// # Example
   /// ```ignore
   /// # use std::pin::pin;
@@ -255,9 +402,8 @@ impl IdentityClientReadOnly {
     // Create a stream that returns unique DIDs.
     async_stream::try_stream! {
       let mut page = self
-      .client_adapter()
-      .read_api()
-      .get_owned_objects(address, Some(query.clone()), None, None)
+      .retry_policy
+      .retry(|| self.owned_controller_tokens(address, &query, None))
       .await
       .map_err(|e| QueryControlledDidsError { address, source: e.into() })?;
       let mut identities = HashSet::new();
@@ -277,10 +423,10 @@ impl IdentityClientReadOnly {
           yield IotaDID::new(&token.controller_of().into_bytes(), &self.network);
         } else if page.has_next_page && page.next_cursor.is_some() {
           // The page's content was exhausted, but a new page can be fetched.
+          let cursor = page.next_cursor;
           page = self
-            .client_adapter()
-            .read_api()
-            .get_owned_objects(address, Some(query.clone()), page.next_cursor, None)
+            .retry_policy
+            .retry(|| self.owned_controller_tokens(address, &query, cursor))
             .await
             .map_err(|e| QueryControlledDidsError { address, source: e.into() })?;
         } else {
@@ -291,13 +437,28 @@ impl IdentityClientReadOnly {
     }
   }
 
+  async fn owned_controller_tokens(
+    &self,
+    address: IotaAddress,
+    query: &IotaObjectResponseQuery,
+    cursor: Option<ObjectID>,
+  ) -> Result<ObjectsPage, Error> {
+    self
+      .client_adapter()
+      .read_api()
+      .get_owned_objects(address, Some(query.clone()), cursor, None)
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))
+  }
+
   /// Returns the list of **all** unique DIDs the given address has access to as a controller.
   /// # Notes
   /// For a streaming version of this API see [dids_controlled_by_streamed](Self::dids_controlled_by_streamed).
   /// # Errors
-  /// This method might return a [QueryControlledDidsError] when the underlying RPC call fails.
-  /// [QueryControlledDidsError]'s source can be downcasted to [SDK's Error](iota_interaction::error::Error)
-  /// in order to check whether calling this method again might return a successful result.
+  /// This method might return a [QueryControlledDidsError] when the underlying RPC call fails, after being retried
+  /// according to this client's [`RetryPolicy`].
+  /// [QueryControlledDidsError]'s source can be downcasted to [Error] in order to check - e.g. with
+  /// [`RetryPolicy::is_retryable`] - whether calling this method again might return a successful result.
   /// # Example
   /// ```
   /// # use identity_iota_core::rebased::client::IdentityClientReadOnly;
@@ -348,11 +509,15 @@ fn history_type_tags<T: MoveType>(history: &[ObjectID]) -> impl Iterator<Item =
 }
 
 async fn network_id(iota_client: &IotaClientAdapter) -> Result<NetworkName, Error> {
-  let network_id = iota_client
-    .read_api()
-    .get_chain_identifier()
-    .await
-    .map_err(|e| Error::RpcError(e.to_string()))?;
+  let network_id = RetryPolicy::default()
+    .retry(|| async {
+      iota_client
+        .read_api()
+        .get_chain_identifier()
+        .await
+        .map_err(|e| Error::RpcError(e.to_string()))
+    })
+    .await?;
   Ok(network_id.try_into().expect("chain ID is a valid network name"))
 }
 
@@ -0,0 +1,350 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::hash_map::RandomState;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::future::Future;
+use std::hash::BuildHasher as _;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+
+use crate::rebased::execution::sleep;
+use crate::rebased::Error;
+
+use super::IdentityClient;
+use super::IdentityClientReadOnly;
+
+type RetryClassifier = Arc<dyn Fn(&Error) -> bool + Send + Sync>;
+
+/// How the RPC calls of an [IdentityClientReadOnly] or [IdentityClient] are retried when they fail with a
+/// transient error - e.g. a node being unreachable or overloaded.
+///
+/// Failed calls are retried up to [RetryPolicy::max_attempts] times in total, waiting between attempts for a backoff
+/// that grows exponentially from [RetryPolicy::initial_backoff] up to [RetryPolicy::max_backoff]. Unless disabled,
+/// each backoff is randomly shortened by up to half, so that clients failing together don't retry together.
+///
+/// By default, only [Error::Network] and [Error::RpcError] are considered transient - see
+/// [RetryPolicy::with_classifier].
+///
+/// The policy applies to the following calls only:
+/// - the lookup of the network's chain identifier when creating a client, with the default policy;
+/// - [IdentityClientReadOnly::get_identity], and therefore DID resolution;
+/// - [IdentityClientReadOnly::dids_controlled_by] and its streaming version;
+/// - the submission of transactions through [TransactionBuilderRetryExt::build_and_execute_with_retry], used by
+///   [IdentityClient::publish_did_document_update] and [IdentityClient::deactivate_did_output].
+///
+/// Other calls - e.g. building and executing transactions with [TransactionBuilder::build_and_execute] - are not
+/// retried, but can be wrapped in [RetryPolicy::retry].
+#[derive(Clone)]
+pub struct RetryPolicy {
+  max_attempts: u32,
+  initial_backoff: Duration,
+  max_backoff: Duration,
+  multiplier: u32,
+  jitter: bool,
+  classifier: Option<RetryClassifier>,
+}
+
+impl Debug for RetryPolicy {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RetryPolicy")
+      .field("max_attempts", &self.max_attempts)
+      .field("initial_backoff", &self.initial_backoff)
+      .field("max_backoff", &self.max_backoff)
+      .field("multiplier", &self.multiplier)
+      .field("jitter", &self.jitter)
+      .field("custom_classifier", &self.classifier.is_some())
+      .finish()
+  }
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    Self {
+      max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+      initial_backoff: Self::DEFAULT_INITIAL_BACKOFF,
+      max_backoff: Self::DEFAULT_MAX_BACKOFF,
+      multiplier: 2,
+      jitter: true,
+      classifier: None,
+    }
+  }
+}
+
+impl RetryPolicy {
+  /// The number of attempts made for a call, unless set with [RetryPolicy::with_max_attempts].
+  pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+  /// The backoff before the first retry, unless set with [RetryPolicy::with_initial_backoff].
+  pub const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+  /// The maximum backoff between two attempts, unless set with [RetryPolicy::with_max_backoff].
+  pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+  /// Creates a new [RetryPolicy] with the default settings.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns a [RetryPolicy] that never retries.
+  pub fn none() -> Self {
+    Self::default().with_max_attempts(1)
+  }
+
+  /// Sets the number of attempts made for a call, including the first one. `0` is treated as `1`.
+  pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+    self.max_attempts = max_attempts.max(1);
+    self
+  }
+
+  /// Sets the backoff before the first retry.
+  pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+    self.initial_backoff = initial_backoff;
+    self
+  }
+
+  /// Sets the maximum backoff between two attempts.
+  pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+    self.max_backoff = max_backoff;
+    self
+  }
+
+  /// Sets the factor the backoff is multiplied by after each retry. `0` is treated as `1`, i.e. a constant backoff.
+  pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+    self.multiplier = multiplier.max(1);
+    self
+  }
+
+  /// Enables or disables the random shortening of backoffs.
+  pub fn with_jitter(mut self, jitter: bool) -> Self {
+    self.jitter = jitter;
+    self
+  }
+
+  /// Sets the function deciding whether a failed call is retried, replacing the default classification.
+  pub fn with_classifier<F>(mut self, classifier: F) -> Self
+  where
+    F: Fn(&Error) -> bool + Send + Sync + 'static,
+  {
+    self.classifier = Some(Arc::new(classifier));
+    self
+  }
+
+  /// Returns the number of attempts made for a call, including the first one.
+  pub fn max_attempts(&self) -> u32 {
+    self.max_attempts
+  }
+
+  /// Returns the backoff before the first retry.
+  pub fn initial_backoff(&self) -> Duration {
+    self.initial_backoff
+  }
+
+  /// Returns the maximum backoff between two attempts.
+  pub fn max_backoff(&self) -> Duration {
+    self.max_backoff
+  }
+
+  /// Returns whether a call failing with `error` is retried.
+  pub fn is_retryable(&self, error: &Error) -> bool {
+    match &self.classifier {
+      Some(classifier) => classifier(error),
+      None => matches!(error, Error::Network(..) | Error::RpcError(_)),
+    }
+  }
+
+  /// Returns the backoff after the failed attempt number `attempt`, starting at 1, before applying jitter.
+  pub fn backoff(&self, attempt: u32) -> Duration {
+    let factor = self.multiplier.saturating_pow(attempt.saturating_sub(1));
+    self
+      .initial_backoff
+      .checked_mul(factor)
+      .unwrap_or(self.max_backoff)
+      .min(self.max_backoff)
+  }
+
+  /// Runs `operation`, retrying it according to this policy, and returns the result of its last attempt.
+  pub async fn retry<T, F, Fut>(&self, operation: F) -> Result<T, Error>
+  where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+  {
+    self.retry_if(|error| self.is_retryable(error), operation).await
+  }
+
+  /// Like [RetryPolicy::retry], for calls failing with errors other than [Error], retried when `is_retryable`.
+  pub(crate) async fn retry_if<T, E, F, Fut>(&self, is_retryable: impl Fn(&E) -> bool, mut operation: F) -> Result<T, E>
+  where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+  {
+    let mut attempt = 1;
+    loop {
+      match operation().await {
+        Err(error) if attempt < self.max_attempts && is_retryable(&error) => {
+          sleep(self.jittered(self.backoff(attempt))).await;
+          attempt += 1;
+        }
+        result => return result,
+      }
+    }
+  }
+
+  /// Randomly shortens `backoff` by up to half, if jitter is enabled.
+  fn jittered(&self, backoff: Duration) -> Duration {
+    if !self.jitter {
+      return backoff;
+    }
+
+    // `RandomState` is randomly seeded, which is enough randomness to spread retries.
+    let random = RandomState::new().hash_one(backoff);
+    let half = backoff / 2;
+    let half_nanos = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+    half + Duration::from_nanos(random.checked_rem(half_nanos.saturating_add(1)).unwrap_or_default())
+  }
+}
+
+impl IdentityClientReadOnly {
+  /// Sets how this client's RPC calls are retried when failing with a transient error.
+  pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+    self.retry_policy = retry_policy;
+    self
+  }
+
+  /// Returns how this client's RPC calls are retried when failing with a transient error.
+  pub fn retry_policy(&self) -> &RetryPolicy {
+    &self.retry_policy
+  }
+}
+
+impl<S> IdentityClient<S> {
+  /// Sets how this client's RPC calls are retried when failing with a transient error.
+  pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+    self.read_client.retry_policy = retry_policy;
+    self
+  }
+}
+
+/// Extension trait to execute the transaction of a [TransactionBuilder], retrying its submission according to the
+/// client's [RetryPolicy].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderRetryExt<Tx: Transaction> {
+  /// Builds and signs the transaction once, then submits it until it's executed or its submission fails with an error
+  /// that is not transient, and applies its effects.
+  ///
+  /// Submitting the same signed transaction again is safe: a transaction is executed at most once.
+  async fn build_and_execute_with_retry<S>(self, client: &IdentityClient<S>) -> Result<Tx::Output, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> TransactionBuilderRetryExt<Tx> for TransactionBuilder<Tx>
+where
+  Tx: Transaction + OptionalSend + OptionalSync,
+  Tx::Error: Display,
+{
+  async fn build_and_execute_with_retry<S>(self, client: &IdentityClient<S>) -> Result<Tx::Output, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    let (tx_data, signatures, tx) = self
+      .build(client)
+      .await
+      .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+
+    let read_client = &client.read_client;
+    let mut effects = read_client
+      .retry_policy()
+      .retry(|| read_client.execute_signed_transaction_data(tx_data.clone(), signatures.clone()))
+      .await?;
+
+    tx.apply(&mut effects, read_client)
+      .await
+      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))
+  }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+  use std::sync::atomic::AtomicU32;
+  use std::sync::atomic::Ordering;
+
+  use super::*;
+
+  #[test]
+  fn backoff_grows_exponentially_up_to_its_maximum() {
+    let policy = RetryPolicy::new()
+      .with_initial_backoff(Duration::from_millis(100))
+      .with_max_backoff(Duration::from_millis(350));
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(350));
+
+    for attempt in 1..10 {
+      let backoff = policy.backoff(attempt);
+      let jittered = policy.jittered(backoff);
+      assert!(jittered >= backoff / 2 && jittered <= backoff);
+    }
+  }
+
+  #[test]
+  fn only_transient_errors_are_retried_by_default() {
+    let policy = RetryPolicy::new();
+    assert!(policy.is_retryable(&Error::RpcError("connection reset".to_owned())));
+    assert!(!policy.is_retryable(&Error::InvalidArgument("bad input".to_owned())));
+
+    let policy = policy.with_classifier(|error| matches!(error, Error::InvalidArgument(_)));
+    assert!(!policy.is_retryable(&Error::RpcError("connection reset".to_owned())));
+    assert!(policy.is_retryable(&Error::InvalidArgument("bad input".to_owned())));
+  }
+
+  #[tokio::test]
+  async fn retries_stop_at_max_attempts_or_on_permanent_errors() {
+    let policy = RetryPolicy::new()
+      .with_max_attempts(3)
+      .with_initial_backoff(Duration::from_millis(1));
+
+    let attempts = AtomicU32::new(0);
+    let result: Result<(), Error> = policy
+      .retry(|| async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(Error::RpcError("node unavailable".to_owned()))
+      })
+      .await;
+    assert!(matches!(result, Err(Error::RpcError(_))));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    let attempts = AtomicU32::new(0);
+    let result: Result<(), Error> = policy
+      .retry(|| async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(Error::InvalidArgument("bad input".to_owned()))
+      })
+      .await;
+    assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+    let attempts = AtomicU32::new(0);
+    let result = policy
+      .retry(|| async {
+        match attempts.fetch_add(1, Ordering::SeqCst) {
+          0 => Err(Error::RpcError("node unavailable".to_owned())),
+          _ => Ok(42),
+        }
+      })
+      .await;
+    assert_eq!(result.unwrap(), 42);
+  }
+}
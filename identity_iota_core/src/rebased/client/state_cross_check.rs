@@ -0,0 +1,236 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::error::Error as StdError;
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::types::base_types::ObjectID;
+
+use crate::rebased::migration::get_identity_object_data;
+use crate::rebased::migration::identity_from_object_data;
+use crate::rebased::migration::IdentityResolutionError;
+use crate::rebased::Error;
+use crate::DidResolutionHandler;
+use crate::IotaDID;
+use crate::IotaDocument;
+
+use super::get_object_id_from_did;
+use super::IdentityClientReadOnly;
+
+/// Cross-checks the state of an Identity object returned by an RPC node with another source, so that a single node
+/// doesn't need to be trusted.
+///
+/// This is not a proof of the object's state: checkpoint inclusion and committee signatures are not verified. The
+/// bundled [`QuorumStateChecker`] moves the trust from a single node to a quorum of independent ones.
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+pub trait IdentityStateChecker {
+  /// Checks that `object` is the current state of the Identity object.
+  ///
+  /// `object` contains the object's reference - i.e. its ID, version and digest - its BCS-encoded content
+  /// and the digest of the transaction that last modified it.
+  async fn check_identity_state(&self, object: &IotaObjectData) -> Result<(), Box<dyn StdError + Send + Sync>>;
+}
+
+/// An [`IdentityStateChecker`] accepting an Identity's state when at least `quorum` independent nodes - the
+/// witnesses - report the very same object reference for it.
+///
+/// The witnesses are trusted as much as the node the state is fetched from: agreement makes a forged state less
+/// likely, it doesn't prove it to be correct.
+///
+/// The witnesses should be operated by parties unrelated to each other and to the node the state is fetched from.
+#[derive(Clone)]
+pub struct QuorumStateChecker {
+  witnesses: Vec<IdentityClientReadOnly>,
+  quorum: usize,
+}
+
+impl QuorumStateChecker {
+  /// Creates a new [`QuorumStateChecker`] requiring all of `witnesses` to agree on the state.
+  pub fn new(witnesses: impl IntoIterator<Item = IdentityClientReadOnly>) -> Self {
+    let witnesses: Vec<_> = witnesses.into_iter().collect();
+    let quorum = witnesses.len();
+    Self { witnesses, quorum }
+  }
+
+  /// Only requires `quorum` of the witnesses to agree on the state. A `quorum` of zero is treated as one.
+  pub fn with_quorum(mut self, quorum: usize) -> Self {
+    self.quorum = quorum.max(1);
+    self
+  }
+
+  /// Returns the witnesses.
+  pub fn witnesses(&self) -> &[IdentityClientReadOnly] {
+    &self.witnesses
+  }
+
+  /// Returns the number of witnesses that must agree on the state.
+  pub fn quorum(&self) -> usize {
+    self.quorum
+  }
+}
+
+/// Failure of a [`QuorumStateChecker`] to confirm the state of an Identity.
+#[derive(Debug, thiserror::Error)]
+#[error("only {confirmations} witness(es) confirmed version {version} of Identity `{identity}`, {required} required")]
+#[non_exhaustive]
+pub struct QuorumNotReached {
+  /// The ID of the Identity.
+  pub identity: ObjectID,
+  /// The version of the Identity that was to be confirmed.
+  pub version: u64,
+  /// The number of witnesses that confirmed the state.
+  pub confirmations: usize,
+  /// The number of confirmations required.
+  pub required: usize,
+  /// The errors of the witnesses that could not be queried.
+  pub witness_errors: Vec<IdentityResolutionError>,
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl IdentityStateChecker for QuorumStateChecker {
+  async fn check_identity_state(&self, object: &IotaObjectData) -> Result<(), Box<dyn StdError + Send + Sync>> {
+    let mut confirmations = 0;
+    let mut witness_errors = Vec::new();
+    for witness in &self.witnesses {
+      match get_identity_object_data(witness, object.object_id, IotaObjectDataOptions::new()).await {
+        Ok(data) if data.version == object.version && data.digest == object.digest => confirmations += 1,
+        Ok(_) => (),
+        Err(err) => witness_errors.push(err),
+      }
+
+      if confirmations >= self.quorum {
+        return Ok(());
+      }
+    }
+
+    Err(Box::new(QuorumNotReached {
+      identity: object.object_id,
+      version: object.version.value(),
+      confirmations,
+      required: self.quorum,
+      witness_errors,
+    }))
+  }
+}
+
+/// [`IdentityClientReadOnly::resolve_did_cross_checked`] error.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum CrossCheckedResolutionError {
+  /// The DID cannot be resolved by this client, e.g. it belongs to another network.
+  #[error("cannot resolve DID `{did}` with this client")]
+  InvalidDid {
+    /// The DID.
+    did: IotaDID,
+    /// The cause.
+    #[source]
+    source: Box<Error>,
+  },
+  /// The Identity object could not be fetched or parsed, e.g. because the DID is a legacy one.
+  #[error(transparent)]
+  IdentityResolution(#[from] IdentityResolutionError),
+  /// The checker rejected the state of the Identity.
+  #[error("the state of Identity `{identity}` could not be cross-checked")]
+  StateCheck {
+    /// The ID of the Identity.
+    identity: ObjectID,
+    /// The checker's error.
+    #[source]
+    source: Box<dyn StdError + Send + Sync>,
+  },
+  /// The DID Document has been deleted.
+  #[error("DID Document `{0}` has been deleted")]
+  DeletedDidDocument(IotaDID),
+}
+
+impl IdentityClientReadOnly {
+  /// Queries an [`IotaDocument`] DID Document through its `did`, cross-checking the state of the Identity object
+  /// it is read from with `checker` before returning it.
+  ///
+  /// The returned document is guaranteed to be parsed from the very object data accepted by `checker`, which is
+  /// therefore what the assurance of this method entirely depends on - see [`IdentityStateChecker`].
+  ///
+  /// Unlike [`IdentityClientReadOnly::resolve_did`], only DIDs referencing an Identity object are supported:
+  /// DIDs of legacy Alias Outputs - migrated or not - cannot be resolved this way.
+  pub async fn resolve_did_cross_checked<C>(
+    &self,
+    did: &IotaDID,
+    checker: &C,
+  ) -> Result<IotaDocument, CrossCheckedResolutionError>
+  where
+    C: IdentityStateChecker + ?Sized,
+  {
+    let invalid_did = |err| CrossCheckedResolutionError::InvalidDid {
+      did: did.clone(),
+      source: Box::new(err),
+    };
+    self.check_did_network(did).map_err(invalid_did)?;
+    let object_id = get_object_id_from_did(did).map_err(invalid_did)?;
+    let options = IotaObjectDataOptions::new()
+      .with_content()
+      .with_bcs()
+      .with_previous_transaction();
+    let data = get_identity_object_data(self, object_id, options).await?;
+
+    checker
+      .check_identity_state(&data)
+      .await
+      .map_err(|source| CrossCheckedResolutionError::StateCheck {
+        identity: object_id,
+        source,
+      })?;
+
+    // The document is parsed out of the very same object data that has been checked.
+    let identity = identity_from_object_data(self, data)?;
+    if identity.has_deleted_did() {
+      return Err(CrossCheckedResolutionError::DeletedDidDocument(did.clone()));
+    }
+
+    Ok(identity.did_document().clone())
+  }
+}
+
+/// A [`DidResolutionHandler`] that resolves DID Documents through
+/// [`IdentityClientReadOnly::resolve_did_cross_checked`], e.g. to attach cross-checked resolution to a `Resolver`.
+#[derive(Clone)]
+pub struct CrossCheckedDidResolver<C> {
+  client: IdentityClientReadOnly,
+  checker: C,
+}
+
+impl<C> CrossCheckedDidResolver<C> {
+  /// Creates a new [`CrossCheckedDidResolver`] resolving DIDs with `client` and cross-checking their state with
+  /// `checker`.
+  pub fn new(client: IdentityClientReadOnly, checker: C) -> Self {
+    Self { client, checker }
+  }
+
+  /// Returns the client used for resolution.
+  pub fn client(&self) -> &IdentityClientReadOnly {
+    &self.client
+  }
+
+  /// Returns the checker used for resolution.
+  pub fn checker(&self) -> &C {
+    &self.checker
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl<C> DidResolutionHandler for CrossCheckedDidResolver<C>
+where
+  C: IdentityStateChecker + Send + Sync,
+{
+  async fn resolve_did(&self, did: &IotaDID) -> crate::Result<IotaDocument> {
+    self
+      .client
+      .resolve_did_cross_checked(did, &self.checker)
+      .await
+      .map_err(|err| crate::Error::CrossCheckedResolutionError(Box::new(err)))
+  }
+}
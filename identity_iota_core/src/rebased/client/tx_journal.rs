@@ -0,0 +1,356 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+use identity_core::common::Timestamp;
+use iota_interaction::rpc_types::IotaExecutionStatus;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_interaction::rpc_types::IotaTransactionBlockResponseOptions;
+use iota_interaction::types::digests::TransactionDigest;
+use iota_interaction::IotaClientTrait as _;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::rebased::Error;
+
+use super::IdentityClient;
+use super::IdentityClientReadOnly;
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The last known status of a journaled transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum TxStatus {
+  /// The transaction was signed and handed over for submission, but its outcome is unknown.
+  Submitted,
+  /// The transaction was executed successfully.
+  Succeeded,
+  /// The transaction was executed, but failed with the given error.
+  Failed(String),
+}
+
+impl TxStatus {
+  /// Returns whether the outcome of the transaction is known.
+  pub fn is_final(&self) -> bool {
+    !matches!(self, Self::Submitted)
+  }
+}
+
+/// A transaction recorded in a [TxJournal].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct JournalEntry {
+  /// The application-defined key of the operation the transaction carries out.
+  pub key: String,
+  /// The digest of the transaction.
+  pub digest: TransactionDigest,
+  /// The last known status of the transaction.
+  pub status: TxStatus,
+  /// When the entry was last updated.
+  pub updated: Timestamp,
+}
+
+/// Persistent storage of the transactions submitted by an application, keyed by an application-defined operation
+/// key - e.g. `"update:<did>:<version>"`.
+///
+/// A transaction is recorded, together with its digest, right before it is submitted. After a crash or a lost
+/// response, the journal tells which operations might already have been carried out, so that retry logic can
+/// check their outcome through [IdentityClientReadOnly::resume] instead of submitting them again.
+///
+/// Entries are kept until they are explicitly removed - see [TxJournal::remove] and [TxJournal::prune].
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+pub trait TxJournal: OptionalSync {
+  /// Records that the transaction `digest`, carrying out operation `key`, has status `status`, replacing any
+  /// previous entry for `key`.
+  async fn record(&self, key: &str, digest: TransactionDigest, status: TxStatus) -> Result<(), BoxedError>;
+
+  /// Returns the entry recorded for operation `key`, if any.
+  async fn lookup(&self, key: &str) -> Result<Option<JournalEntry>, BoxedError>;
+
+  /// Returns all entries whose outcome is still unknown.
+  async fn pending(&self) -> Result<Vec<JournalEntry>, BoxedError>;
+
+  /// Removes the entry recorded for operation `key`, returning it. Once removed, the operation can be carried out
+  /// again.
+  async fn remove(&self, key: &str) -> Result<Option<JournalEntry>, BoxedError>;
+
+  /// Removes the entries with a known outcome that were last updated before `before`, returning how many were
+  /// removed. Entries whose outcome is still unknown are kept.
+  async fn prune(&self, before: Timestamp) -> Result<usize, BoxedError>;
+}
+
+/// A volatile [TxJournal], mostly useful for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryTxJournal {
+  entries: RwLock<HashMap<String, JournalEntry>>,
+}
+
+impl InMemoryTxJournal {
+  /// Creates a new, empty, [InMemoryTxJournal].
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl TxJournal for InMemoryTxJournal {
+  async fn record(&self, key: &str, digest: TransactionDigest, status: TxStatus) -> Result<(), BoxedError> {
+    let entry = JournalEntry {
+      key: key.to_owned(),
+      digest,
+      status,
+      updated: Timestamp::now_utc(),
+    };
+    self
+      .entries
+      .write()
+      .expect("journal lock poisoned")
+      .insert(key.to_owned(), entry);
+    Ok(())
+  }
+
+  async fn lookup(&self, key: &str) -> Result<Option<JournalEntry>, BoxedError> {
+    Ok(self.entries.read().expect("journal lock poisoned").get(key).cloned())
+  }
+
+  async fn pending(&self) -> Result<Vec<JournalEntry>, BoxedError> {
+    Ok(
+      self
+        .entries
+        .read()
+        .expect("journal lock poisoned")
+        .values()
+        .filter(|entry| !entry.status.is_final())
+        .cloned()
+        .collect(),
+    )
+  }
+
+  async fn remove(&self, key: &str) -> Result<Option<JournalEntry>, BoxedError> {
+    Ok(self.entries.write().expect("journal lock poisoned").remove(key))
+  }
+
+  async fn prune(&self, before: Timestamp) -> Result<usize, BoxedError> {
+    let mut entries = self.entries.write().expect("journal lock poisoned");
+    let len = entries.len();
+    entries.retain(|_, entry| !entry.status.is_final() || entry.updated >= before);
+
+    Ok(len - entries.len())
+  }
+}
+
+/// Failed to read from or write to a [TxJournal].
+#[derive(Debug, thiserror::Error)]
+#[error("transaction journal failure")]
+#[non_exhaustive]
+pub struct TxJournalError(#[source] pub BoxedError);
+
+/// The outcome of [TransactionBuilderJournalExt::build_and_execute_journaled].
+pub enum JournaledExecution<T> {
+  /// The transaction was executed.
+  Executed(T),
+  /// A transaction for the same operation was already submitted, hence none was submitted this time. Its outcome
+  /// can be checked through [IdentityClientReadOnly::resume].
+  AlreadySubmitted(JournalEntry),
+}
+
+/// Extension trait to execute the transaction of a [TransactionBuilder] while recording it in a [TxJournal].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderJournalExt<Tx: Transaction> {
+  /// Same as [TransactionBuilder::build_and_execute], but the signed transaction is recorded in `journal` under
+  /// operation `key` right before being submitted, and is **not** submitted if `journal` already holds an entry for
+  /// `key` - unless that entry's transaction [failed](TxStatus::Failed), in which case the operation is retried.
+  ///
+  /// Once the transaction is executed, its entry is updated with its outcome. If its submission fails and the node
+  /// doesn't know about the transaction, its entry is removed; otherwise it is left as [TxStatus::Submitted], to be
+  /// checked through [IdentityClientReadOnly::resume_journal].
+  async fn build_and_execute_journaled<S, J>(
+    self,
+    client: &IdentityClient<S>,
+    journal: &J,
+    key: &str,
+  ) -> Result<JournaledExecution<Tx::Output>, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    J: TxJournal + ?Sized;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> TransactionBuilderJournalExt<Tx> for TransactionBuilder<Tx>
+where
+  Tx: Transaction + OptionalSend + OptionalSync,
+  Tx::Error: Display,
+{
+  async fn build_and_execute_journaled<S, J>(
+    self,
+    client: &IdentityClient<S>,
+    journal: &J,
+    key: &str,
+  ) -> Result<JournaledExecution<Tx::Output>, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    J: TxJournal + ?Sized,
+  {
+    match journal.lookup(key).await.map_err(TxJournalError)? {
+      Some(entry) if !matches!(entry.status, TxStatus::Failed(_)) => {
+        return Ok(JournaledExecution::AlreadySubmitted(entry))
+      }
+      _ => (),
+    }
+
+    let (tx_data, signatures, tx) = self
+      .build(client)
+      .await
+      .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+    let digest = tx_data.digest();
+
+    // Recording happens before submission, so that no transaction can reach the network unrecorded.
+    journal
+      .record(key, digest, TxStatus::Submitted)
+      .await
+      .map_err(TxJournalError)?;
+
+    let read_client = &client.read_client;
+    let mut effects = match read_client
+      .retry_policy()
+      .retry(|| read_client.execute_signed_transaction_data(tx_data.clone(), signatures.clone()))
+      .await
+    {
+      Ok(effects) => effects,
+      Err(e) => {
+        match read_client.resume(digest).await? {
+          TxStatus::Submitted => {
+            journal.remove(key).await.map_err(TxJournalError)?;
+          }
+          status => journal.record(key, digest, status).await.map_err(TxJournalError)?,
+        }
+        return Err(e);
+      }
+    };
+
+    let status = match effects.status() {
+      IotaExecutionStatus::Success => TxStatus::Succeeded,
+      IotaExecutionStatus::Failure { error } => TxStatus::Failed(error.clone()),
+    };
+    journal.record(key, digest, status).await.map_err(TxJournalError)?;
+
+    tx.apply(&mut effects, read_client)
+      .await
+      .map(JournaledExecution::Executed)
+      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))
+  }
+}
+
+impl IdentityClientReadOnly {
+  /// Checks the status of the transaction `digest`, e.g. one recorded in a [TxJournal] before a crash.
+  ///
+  /// Returns [TxStatus::Submitted] if the node doesn't know about the transaction, which either hasn't been
+  /// executed yet or never reached the network.
+  pub async fn resume(&self, digest: TransactionDigest) -> Result<TxStatus, Error> {
+    let Ok(response) = self
+      .read_api()
+      .get_transaction_with_options(digest, IotaTransactionBlockResponseOptions::new().with_effects())
+      .await
+    else {
+      return Ok(TxStatus::Submitted);
+    };
+
+    Ok(match response.effects_execution_status() {
+      Some(IotaExecutionStatus::Success) => TxStatus::Succeeded,
+      Some(IotaExecutionStatus::Failure { error }) => TxStatus::Failed(error),
+      None => TxStatus::Submitted,
+    })
+  }
+
+  /// Checks the status of all pending transactions of `journal` through [IdentityClientReadOnly::resume], recording
+  /// the outcome of those that have one. Returns the updated entries.
+  pub async fn resume_journal<J>(&self, journal: &J) -> Result<Vec<JournalEntry>, Error>
+  where
+    J: TxJournal + ?Sized,
+  {
+    let mut resumed = Vec::new();
+    for entry in journal.pending().await.map_err(TxJournalError)? {
+      let status = self.resume(entry.digest).await?;
+      if status.is_final() {
+        journal
+          .record(&entry.key, entry.digest, status.clone())
+          .await
+          .map_err(TxJournalError)?;
+      }
+      resumed.push(JournalEntry {
+        status,
+        updated: Timestamp::now_utc(),
+        ..entry
+      });
+    }
+
+    Ok(resumed)
+  }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn in_memory_journal_tracks_pending_entries() {
+    let journal = InMemoryTxJournal::new();
+    let digest = TransactionDigest::random();
+
+    journal.record("update", digest, TxStatus::Submitted).await.unwrap();
+    assert_eq!(journal.lookup("update").await.unwrap().unwrap().digest, digest);
+    assert_eq!(journal.pending().await.unwrap().len(), 1);
+
+    journal.record("update", digest, TxStatus::Succeeded).await.unwrap();
+    assert!(journal.pending().await.unwrap().is_empty());
+    assert!(journal.lookup("deactivate").await.unwrap().is_none());
+  }
+
+  #[tokio::test]
+  async fn in_memory_journal_entries_can_be_removed_and_pruned() {
+    let journal = InMemoryTxJournal::new();
+    journal
+      .record("update", TransactionDigest::random(), TxStatus::Succeeded)
+      .await
+      .unwrap();
+    journal
+      .record("deactivate", TransactionDigest::random(), TxStatus::Submitted)
+      .await
+      .unwrap();
+    journal
+      .record(
+        "delete",
+        TransactionDigest::random(),
+        TxStatus::Failed("aborted".to_owned()),
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(journal.prune(Timestamp::from_unix(0).unwrap()).await.unwrap(), 0);
+    let later = Timestamp::now_utc()
+      .checked_add(identity_core::common::Duration::seconds(1))
+      .unwrap();
+    assert_eq!(journal.prune(later).await.unwrap(), 2);
+    assert!(journal.lookup("update").await.unwrap().is_none());
+    assert!(journal.lookup("delete").await.unwrap().is_none());
+
+    // Entries whose outcome is unknown are only removed explicitly.
+    assert!(journal.remove("deactivate").await.unwrap().is_some());
+    assert!(journal.pending().await.unwrap().is_empty());
+  }
+}
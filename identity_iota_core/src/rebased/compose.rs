@@ -0,0 +1,284 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+
+use crate::rebased::iota::ptb_merge_tx;
+
+type BoxedStdError = Box<dyn std::error::Error + Send + Sync>;
+
+const COMPOSED_TRANSACTION_TYPE: &str = concat!(module_path!(), "::ComposedTransaction<");
+
+/// A [Transaction] that executes two transactions atomically, as a single programmable transaction.
+///
+/// Both transactions can come from different products - e.g. an identity update and a notarization - and
+/// are charged a single gas budget, set on the
+/// [TransactionBuilder](product_common::transaction::transaction_builder::TransactionBuilder) of the composed
+/// transaction. The commands of `second` are executed after those of `first` and the output of the composed transaction
+/// is the pair of both outputs.
+///
+/// More than two transactions can be composed by nesting [ComposedTransaction]s, see [TransactionComposeExt::compose].
+/// Transactions of the same kind - e.g. two identity creations - cannot be composed: the effects of a transaction
+/// are not attributed to the commands that caused them, hence the objects created or modified by either transaction
+/// could not be told apart.
+#[derive(Debug)]
+pub struct ComposedTransaction<A, B> {
+  first: A,
+  second: B,
+}
+
+impl<A, B> ComposedTransaction<A, B>
+where
+  A: Transaction,
+  B: Transaction,
+{
+  /// Composes `first` and `second` into a single transaction.
+  ///
+  /// # Errors
+  /// Returns [ComposedTransactionError::SameKind] if the same kind of transaction is composed more than once.
+  pub fn new(first: A, second: B) -> Result<Self, ComposedTransactionError> {
+    let mut kinds = Vec::new();
+    composed_kinds(std::any::type_name::<A>(), &mut kinds);
+    composed_kinds(std::any::type_name::<B>(), &mut kinds);
+    kinds.sort_unstable();
+    if let Some(kind) = kinds.windows(2).find(|pair| pair[0] == pair[1]).map(|pair| pair[0]) {
+      return Err(ComposedTransactionError::SameKind(kind.to_owned()));
+    }
+
+    Ok(Self { first, second })
+  }
+
+  /// Returns the composed transactions.
+  pub fn into_parts(self) -> (A, B) {
+    (self.first, self.second)
+  }
+}
+
+/// Collects the kinds - i.e. the type names without generic arguments - of the transactions composed in the
+/// transaction type named `type_name`.
+fn composed_kinds<'a>(type_name: &'a str, kinds: &mut Vec<&'a str>) {
+  let Some(args) = type_name
+    .strip_prefix(COMPOSED_TRANSACTION_TYPE)
+    .and_then(|args| args.strip_suffix('>'))
+  else {
+    let kind = type_name.split('<').next().unwrap_or(type_name);
+    kinds.push(kind);
+    return;
+  };
+
+  // Splits the type arguments at the only comma that is not nested in another type.
+  let mut depth = 0_usize;
+  for (i, c) in args.char_indices() {
+    match c {
+      '<' | '(' | '[' => depth += 1,
+      '>' | ')' | ']' => depth = depth.saturating_sub(1),
+      ',' if depth == 0 => {
+        composed_kinds(args[..i].trim(), kinds);
+        composed_kinds(args[i + 1..].trim(), kinds);
+        return;
+      }
+      _ => (),
+    }
+  }
+  kinds.push(type_name);
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<A, B> Transaction for ComposedTransaction<A, B>
+where
+  A: Transaction + OptionalSend + OptionalSync,
+  B: Transaction + OptionalSend + OptionalSync,
+{
+  type Output = (A::Output, B::Output);
+  type Error = ComposedTransactionError;
+
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let first = self
+      .first
+      .build_programmable_transaction(client)
+      .await
+      .map_err(|e| ComposedTransactionError::First(e.into()))?;
+    let second = self
+      .second
+      .build_programmable_transaction(client)
+      .await
+      .map_err(|e| ComposedTransactionError::Second(e.into()))?;
+
+    let mut ptb = Ptb::new();
+    ptb_merge_tx(&mut ptb, first);
+    ptb_merge_tx(&mut ptb, second);
+
+    Ok(ptb.finish())
+  }
+
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    // Each transaction removes the effects it is concerned with. As both transactions are of different kinds, they
+    // are concerned with different effects.
+    let first = self
+      .first
+      .apply(effects, client)
+      .await
+      .map_err(|e| ComposedTransactionError::First(e.into()))?;
+    let second = self
+      .second
+      .apply(effects, client)
+      .await
+      .map_err(|e| ComposedTransactionError::Second(e.into()))?;
+
+    Ok((first, second))
+  }
+}
+
+/// Error returned by a [ComposedTransaction], identifying which of the composed transactions failed.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ComposedTransactionError {
+  /// Building or applying the first transaction failed.
+  #[error("first composed transaction failed")]
+  First(#[source] BoxedStdError),
+  /// Building or applying the second transaction failed.
+  #[error("second composed transaction failed")]
+  Second(#[source] BoxedStdError),
+  /// The same kind of transaction was composed more than once.
+  #[error("transactions of kind `{0}` cannot be composed with each other, as their effects cannot be told apart")]
+  SameKind(String),
+}
+
+/// Extension trait to compose a [Transaction] with other transactions.
+pub trait TransactionComposeExt: Transaction + Sized {
+  /// Composes this transaction with `other` into a [ComposedTransaction] that executes both atomically.
+  ///
+  /// Gas settings - e.g. the gas budget or a sponsor - are set on the builder of the composed transaction. The
+  /// transactions of [TransactionBuilder](product_common::transaction::transaction_builder::TransactionBuilder)s
+  /// are composed through
+  /// [TransactionBuilder::into_inner](product_common::transaction::transaction_builder::TransactionBuilder::into_inner).
+  ///
+  ///
+  /// # Example
+  /// ```ignore
+  /// let composed = first_asset_builder
+  ///   .finish(&client)
+  ///   .into_inner()
+  ///   .compose(second_asset_builder.finish(&client).into_inner())?
+  ///   .compose(notarization)?;
+  /// let ((first_asset, second_asset), notarization) = TransactionBuilder::new(composed)
+  ///   .with_gas_budget(gas_budget)
+  ///   .build_and_execute(&client)
+  ///   .await?
+  ///   .output;
+  /// ```
+  ///
+  /// # Errors
+  /// Returns [ComposedTransactionError::SameKind] if the same kind of transaction is composed more than once.
+  fn compose<B>(self, other: B) -> Result<ComposedTransaction<Self, B>, ComposedTransactionError>
+  where
+    B: Transaction;
+}
+
+impl<A> TransactionComposeExt for A
+where
+  A: Transaction,
+{
+  fn compose<B>(self, other: B) -> Result<ComposedTransaction<Self, B>, ComposedTransactionError>
+  where
+    B: Transaction,
+  {
+    ComposedTransaction::new(self, other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::marker::PhantomData;
+
+  use super::*;
+  use crate::rebased::Error;
+
+  macro_rules! mock_transaction {
+    ($name:ident) => {
+      #[derive(Debug, Default)]
+      struct $name<T = ()>(PhantomData<T>);
+
+      #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+      #[cfg_attr(feature = "send-sync", async_trait)]
+      impl<T: OptionalSend + OptionalSync> Transaction for $name<T> {
+        type Output = ();
+        type Error = Error;
+
+        async fn build_programmable_transaction<C>(&self, _client: &C) -> Result<ProgrammableTransaction, Error>
+        where
+          C: CoreClientReadOnly + OptionalSync,
+        {
+          Ok(Ptb::new().finish())
+        }
+
+        async fn apply<C>(self, _effects: &mut IotaTransactionBlockEffects, _client: &C) -> Result<(), Error>
+        where
+          C: CoreClientReadOnly + OptionalSync,
+        {
+          Ok(())
+        }
+      }
+    };
+  }
+
+  fn type_name_of<T>(_: &T) -> &'static str {
+    std::any::type_name::<T>()
+  }
+
+  mock_transaction!(CreateThing);
+  mock_transaction!(UpdateThing);
+  mock_transaction!(DeleteThing);
+
+  #[test]
+  fn transactions_of_different_kinds_can_be_composed() {
+    let composed = CreateThing::<()>::default()
+      .compose(UpdateThing::<()>::default())
+      .unwrap()
+      .compose(DeleteThing::<()>::default())
+      .unwrap();
+
+    let mut kinds = Vec::new();
+    composed_kinds(type_name_of(&composed), &mut kinds);
+    assert_eq!(kinds.len(), 3);
+    assert!(kinds[0].ends_with("::CreateThing"));
+    assert!(kinds[1].ends_with("::UpdateThing"));
+    assert!(kinds[2].ends_with("::DeleteThing"));
+  }
+
+  #[test]
+  fn transactions_of_the_same_kind_cannot_be_composed() {
+    let result = CreateThing::<()>::default().compose(CreateThing::<()>::default());
+    assert!(matches!(result, Err(ComposedTransactionError::SameKind(kind)) if kind.ends_with("::CreateThing")));
+
+    // Generic arguments don't make a different kind.
+    let result = CreateThing::<u8>::default().compose(CreateThing::<u16>::default());
+    assert!(matches!(result, Err(ComposedTransactionError::SameKind(_))));
+
+    // Nested compositions are checked as a whole.
+    let nested = UpdateThing::<()>::default()
+      .compose(CreateThing::<(u8, u16)>::default())
+      .unwrap();
+    let result = DeleteThing::<()>::default().compose(nested);
+    assert!(result.is_ok());
+    let nested = UpdateThing::<()>::default()
+      .compose(CreateThing::<(u8, u16)>::default())
+      .unwrap();
+    let result = nested.compose(CreateThing::<()>::default());
+    assert!(matches!(result, Err(ComposedTransactionError::SameKind(kind)) if kind.ends_with("::CreateThing")));
+  }
+}
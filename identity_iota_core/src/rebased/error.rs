@@ -3,6 +3,9 @@
 
 //! Errors that may occur for the rebased logic.
 
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::base_types::ObjectID;
+
 use crate::iota_interaction_adapter::AdapterError;
 
 /// This type represents all possible errors that can occur in the library.
@@ -21,6 +24,14 @@ pub enum Error {
   /// Caused by a look failures during resolution.
   #[error("DID resolution failed: {0}")]
   DIDResolutionError(String),
+  /// The object a DID or an Identity ID refers to exists, but it is not an Identity.
+  #[error("object `{object_id}` is not an Identity but a `{actual_type}`; {}", not_an_identity_hint(.actual_type))]
+  NotAnIdentity {
+    /// The ID of the resolved object.
+    object_id: ObjectID,
+    /// The Move type of the resolved object.
+    actual_type: String,
+  },
   /// Caused by invalid or missing arguments.
   #[error("invalid or missing argument: {0}")]
   InvalidArgument(String),
@@ -60,6 +71,18 @@ pub enum Error {
     #[cfg(target_arch = "wasm32")]
     response: String,
   },
+  /// An operation stopped being awaited before completing, see [ExecutionContext](crate::rebased::ExecutionContext).
+  #[error("stopped awaiting operation: {interruption}")]
+  Interrupted {
+    /// Why the operation stopped being awaited.
+    interruption: crate::rebased::Interruption,
+    /// The digest of the interrupted transaction, if it was signed - and possibly submitted - before the
+    /// interruption.
+    digest: Option<iota_interaction::types::digests::TransactionDigest>,
+  },
+  /// A transaction journal could not be read from or written to.
+  #[error(transparent)]
+  TxJournal(#[from] crate::rebased::client::TxJournalError),
   /// Config is invalid.
   #[error("invalid config: {0}")]
   InvalidConfig(String),
@@ -70,8 +93,8 @@ pub enum Error {
   #[error("failed to serialize DID document; {0}")]
   DidDocSerialization(String),
   /// Identity related error.
-  #[error("identity error; {0}")]
-  Identity(String),
+  #[error("identity error")]
+  Identity(#[from] IdentityError),
   #[error("unexpected state when looking up identity history; {0}")]
   /// Unexpected state when looking up identity history.
   InvalidIdentityHistory(String),
@@ -98,6 +121,52 @@ pub enum Error {
   IotaInteractionAdapterError(#[from] AdapterError),
 }
 
+/// Failures concerning an on-chain Identity, see [Error::Identity].
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum IdentityError {
+  /// The Identity doesn't exist, e.g. it has been deleted in the meantime.
+  #[error("identity `{0}` cannot be found")]
+  NotFound(ObjectID),
+  /// The operation is only supported by Identities, not by legacy Alias Output based DID Documents.
+  #[error("only new identities support this operation")]
+  LegacyIdentity,
+  /// The given token doesn't grant access to the Identity.
+  #[error("token `{token}` doesn't grant access to identity `{identity}`")]
+  TokenMismatch {
+    /// The ID of the token.
+    token: ObjectID,
+    /// The ID of the Identity.
+    identity: ObjectID,
+  },
+  /// The given address doesn't control the Identity.
+  #[error("address `{address}` has no control over identity `{identity}`")]
+  NotAController {
+    /// The address.
+    address: IotaAddress,
+    /// The ID of the Identity.
+    identity: ObjectID,
+  },
+  /// The Identity has no controllers.
+  #[error("identity has no controller")]
+  MissingController,
+  /// The Identity's DID Document has been deleted and cannot be changed anymore.
+  #[error("the DID Document of identity `{0}` has been deleted")]
+  DeletedDidDocument(ObjectID),
+  /// The given controller capability doesn't allow delegating its access.
+  #[error("controller capability `{0}` cannot be delegated")]
+  NonDelegableController(ObjectID),
+}
+
+fn not_an_identity_hint(actual_type: &str) -> &'static str {
+  if actual_type.ends_with("::identity::Identity") {
+    "the Identity was created through a package unknown to this client, make sure the client is connected to the \
+     network the Identity lives on or create it with `IdentityClientReadOnly::new_with_pkg_id`"
+  } else {
+    "make sure the DID or object ID is correct and refers to an Identity on the network this client is connected to"
+  }
+}
+
 /// Can be used for example like `map_err(rebased_err)` to convert other error
 ///  types to identity_iota_core::rebased::Error.
 pub fn rebased_err<T>(error: T) -> Error
@@ -106,3 +175,18 @@ where
 {
   error.into()
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn identity_errors_are_preserved_as_source() {
+    let err = Error::from(IdentityError::NotFound(ObjectID::ZERO));
+    let source = std::error::Error::source(&err).expect("has a source");
+    assert!(matches!(
+      source.downcast_ref::<IdentityError>(),
+      Some(IdentityError::NotFound(id)) if *id == ObjectID::ZERO
+    ));
+  }
+}
@@ -0,0 +1,324 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::future::Either;
+use iota_interaction::rpc_types::IotaTransactionBlockResponseOptions;
+use iota_interaction::types::digests::TransactionDigest;
+use iota_interaction::IotaClientTrait as _;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+use tokio::sync::watch;
+
+use crate::rebased::client::IdentityClient;
+use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::Error;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A token to signal, possibly from another task, that the operations awaited under an [ExecutionContext] should
+/// no longer be awaited.
+///
+/// Clones of a token share its state: cancelling one cancels all of them.
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+  cancelled: Arc<watch::Sender<bool>>,
+}
+
+impl Default for CancellationToken {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl CancellationToken {
+  /// Creates a new, non-cancelled, [CancellationToken].
+  pub fn new() -> Self {
+    Self {
+      cancelled: Arc::new(watch::Sender::new(false)),
+    }
+  }
+
+  /// Cancels this token and all of its clones.
+  pub fn cancel(&self) {
+    self.cancelled.send_replace(true);
+  }
+
+  /// Returns whether this token has been cancelled.
+  pub fn is_cancelled(&self) -> bool {
+    *self.cancelled.borrow()
+  }
+
+  /// Completes once this token is cancelled.
+  pub async fn cancelled(&self) {
+    let mut receiver = self.cancelled.subscribe();
+    // The sender lives as long as `self`, hence waiting cannot fail.
+    let _ = receiver.wait_for(|cancelled| *cancelled).await;
+  }
+}
+
+/// Why an operation awaited under an [ExecutionContext] stopped being awaited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Interruption {
+  /// The context's [CancellationToken] was cancelled.
+  Cancelled,
+  /// The context's timeout elapsed.
+  TimedOut,
+}
+
+impl Display for Interruption {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Cancelled => f.write_str("operation was cancelled"),
+      Self::TimedOut => f.write_str("operation timed out"),
+    }
+  }
+}
+
+/// Bounds how long the client awaits an operation - e.g. the execution of a transaction - through an overall
+/// timeout and an optional [CancellationToken].
+///
+/// Interrupting an operation only stops the client from awaiting it: a transaction that was already submitted
+/// might still be executed by the network. Its status can be checked later on through
+/// [IdentityClientReadOnly::wait_for_finality].
+#[derive(Debug, Clone)]
+pub struct ExecutionContext {
+  cancellation: Option<CancellationToken>,
+  timeout: Option<Duration>,
+  poll_interval: Duration,
+}
+
+impl Default for ExecutionContext {
+  fn default() -> Self {
+    Self {
+      cancellation: None,
+      timeout: None,
+      poll_interval: DEFAULT_POLL_INTERVAL,
+    }
+  }
+}
+
+impl ExecutionContext {
+  /// Creates a new [ExecutionContext] that never interrupts an operation.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Interrupts operations when `token` is cancelled.
+  pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+    self.cancellation = Some(token);
+    self
+  }
+
+  /// Interrupts operations that take longer than `timeout`.
+  pub fn with_timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /// Sets the time waited between two status queries when polling the network.
+  ///
+  /// Default: 500 milliseconds.
+  pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+    self.poll_interval = poll_interval;
+    self
+  }
+
+  /// Returns the [CancellationToken] of this context, if any.
+  pub fn cancellation(&self) -> Option<&CancellationToken> {
+    self.cancellation.as_ref()
+  }
+
+  /// Returns the timeout of this context, if any.
+  pub fn timeout(&self) -> Option<Duration> {
+    self.timeout
+  }
+
+  /// Awaits `future` until it completes or this context interrupts it, whichever happens first.
+  pub async fn run<F>(&self, future: F) -> Result<F::Output, Interruption>
+  where
+    F: Future,
+  {
+    if self.cancellation.as_ref().is_some_and(CancellationToken::is_cancelled) {
+      return Err(Interruption::Cancelled);
+    }
+
+    let cancelled = async {
+      match &self.cancellation {
+        Some(token) => token.cancelled().await,
+        None => std::future::pending().await,
+      }
+    };
+    let timed_out = async {
+      match self.timeout {
+        Some(timeout) => sleep(timeout).await,
+        None => std::future::pending().await,
+      }
+    };
+    let interrupted = futures::future::select(Box::pin(cancelled), Box::pin(timed_out));
+
+    match futures::future::select(Box::pin(future), interrupted).await {
+      Either::Left((output, _)) => Ok(output),
+      Either::Right((Either::Left(_), _), _) => Err(Interruption::Cancelled),
+      Either::Right((Either::Right(_), _), _) => Err(Interruption::TimedOut),
+    }
+  }
+}
+
+impl IdentityClientReadOnly {
+  /// Waits until the transaction `digest` has been executed by the network, polling the node this client is
+  /// connected to until it knows about the transaction or `ctx` interrupts the wait.
+  ///
+  /// Use this to find out the fate of a transaction whose execution stopped being awaited, e.g. through
+  /// [TransactionBuilderExecuteWithExt::build_and_execute_with].
+  ///
+  /// # Errors
+  /// Returns [Error::Interrupted] if `ctx` interrupts the wait before the transaction is found.
+  pub async fn wait_for_finality(&self, digest: TransactionDigest, ctx: &ExecutionContext) -> Result<(), Error> {
+    let poll = async {
+      loop {
+        let found = self
+          .read_api()
+          .get_transaction_with_options(digest, IotaTransactionBlockResponseOptions::default())
+          .await
+          .is_ok();
+        if found {
+          return;
+        }
+        sleep(ctx.poll_interval).await;
+      }
+    };
+
+    ctx.run(poll).await.map_err(|interruption| Error::Interrupted {
+      interruption,
+      digest: Some(digest),
+    })
+  }
+}
+
+/// Extension trait to execute the transaction of a [TransactionBuilder] under an [ExecutionContext].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderExecuteWithExt<Tx: Transaction> {
+  /// Same as [TransactionBuilder::build_and_execute], but stops awaiting the transaction when `ctx` interrupts its
+  /// building or execution.
+  ///
+  /// The transaction is signed before being submitted, so that its digest is known should its execution be
+  /// interrupted.
+  ///
+  /// # Errors
+  /// Returns [Error::Interrupted] if `ctx` interrupts the execution. If the transaction was already signed, the
+  /// error carries its digest: the transaction might have been submitted and executed regardless, which can be
+  /// checked through [IdentityClientReadOnly::wait_for_finality].
+  async fn build_and_execute_with<S>(
+    self,
+    client: &IdentityClient<S>,
+    ctx: &ExecutionContext,
+  ) -> Result<Tx::Output, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<Tx> TransactionBuilderExecuteWithExt<Tx> for TransactionBuilder<Tx>
+where
+  Tx: Transaction + OptionalSend + OptionalSync,
+  Tx::Error: Into<Error>,
+{
+  async fn build_and_execute_with<S>(
+    self,
+    client: &IdentityClient<S>,
+    ctx: &ExecutionContext,
+  ) -> Result<Tx::Output, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    let (tx_data, signatures, tx) = ctx
+      .run(self.build(client))
+      .await
+      .map_err(|interruption| Error::Interrupted {
+        interruption,
+        digest: None,
+      })?
+      .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+    let digest = tx_data.digest();
+
+    let read_client: &IdentityClientReadOnly = client;
+    let mut effects = ctx
+      .run(read_client.execute_signed_transaction_data(tx_data, signatures))
+      .await
+      .map_err(|interruption| Error::Interrupted {
+        interruption,
+        digest: Some(digest),
+      })??;
+
+    tx.apply(&mut effects, read_client).await.map_err(Into::into)
+  }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+  tokio::time::sleep(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+  use wasm_bindgen::JsCast as _;
+  use wasm_bindgen::JsValue;
+
+  let millis: i32 = duration.as_millis().try_into().unwrap_or(i32::MAX);
+  let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+    let set_timeout = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("setTimeout"))
+      .ok()
+      .and_then(|set_timeout| set_timeout.dyn_into::<js_sys::Function>().ok());
+    if let Some(set_timeout) = set_timeout {
+      let _ = set_timeout.call2(&JsValue::NULL, &resolve, &JsValue::from(millis));
+    }
+  });
+  let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn run_completes_uninterrupted_futures() {
+    let ctx = ExecutionContext::new().with_timeout(Duration::from_secs(60));
+    assert_eq!(ctx.run(async { 42 }).await, Ok(42));
+  }
+
+  #[tokio::test]
+  async fn run_is_interrupted_by_timeout() {
+    let ctx = ExecutionContext::new().with_timeout(Duration::from_millis(10));
+    let output = ctx.run(std::future::pending::<()>()).await;
+    assert_eq!(output, Err(Interruption::TimedOut));
+  }
+
+  #[tokio::test]
+  async fn run_is_interrupted_by_cancellation() {
+    let token = CancellationToken::new();
+    let ctx = ExecutionContext::new().with_cancellation(token.clone());
+
+    let canceller = async {
+      sleep(Duration::from_millis(10)).await;
+      token.cancel();
+    };
+    let (output, _) = futures::future::join(ctx.run(std::future::pending::<()>()), canceller).await;
+    assert_eq!(output, Err(Interruption::Cancelled));
+
+    // Already cancelled contexts don't even poll the future.
+    assert_eq!(ctx.run(async { 42 }).await, Err(Interruption::Cancelled));
+  }
+}
@@ -54,7 +54,6 @@ pub(crate) fn ptb_merge_tx_with_inputs_replacement(
   }
 }
 
-#[cfg(test)]
 #[inline]
 pub(crate) fn ptb_merge_tx(ptb: &mut Ptb, other: ProgrammableTransaction) {
   ptb_merge_tx_with_inputs_replacement(ptb, other, vec![]);
@@ -181,4 +180,35 @@ mod tests {
     assert_eq!(pt, expected_pt);
     assert_eq!(pt.inputs.len(), 2);
   }
+
+  #[test]
+  fn merging_consecutive_pts_offsets_results() {
+    let recipient = IotaAddress::random_for_testing_only();
+    let pt = || {
+      let (mut ptb, coin) = empty_iota_coin_ptb();
+      ptb.transfer_arg(recipient, coin);
+      ptb.finish()
+    };
+
+    let mut ptb = Ptb::new();
+    ptb_merge_tx(&mut ptb, pt());
+    ptb_merge_tx(&mut ptb, pt());
+    let pt = ptb.finish();
+
+    let expected_pt = {
+      let (mut ptb, coin) = empty_iota_coin_ptb();
+      ptb.transfer_arg(recipient, coin);
+      let coin = ptb.programmable_move_call(
+        IOTA_FRAMEWORK_PACKAGE_ID,
+        ident_str!("coin").into(),
+        ident_str!("zero").into(),
+        vec![IOTA_COIN_TYPE.parse().unwrap()],
+        vec![],
+      );
+      ptb.transfer_arg(recipient, coin);
+      ptb.finish()
+    };
+
+    assert_eq!(pt, expected_pt);
+  }
 }
@@ -107,3 +107,30 @@ pub(crate) fn approve_proposal<T: MoveType>(
 
   Ok(bcs::to_bytes(&ptb.finish())?)
 }
+
+pub(crate) fn delete_proposal<T: MoveType>(
+  identity: OwnedObjectRef,
+  controller_cap: ControllerTokenRef,
+  proposal_id: ObjectID,
+  package: ObjectID,
+) -> Result<ProgrammableTransactionBcs, Error> {
+  let mut ptb = Ptb::new();
+  let identity = utils::owned_ref_to_shared_object_arg(identity, &mut ptb, true)
+    .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+  let capability = ControllerTokenArg::from_ref(controller_cap, &mut ptb, package)?;
+  let proposal_id = ptb
+    .pure(proposal_id)
+    .map_err(|e| Error::InvalidArgument(e.to_string()))?;
+
+  ptb.programmable_move_call(
+    package,
+    ident_str!("identity").into(),
+    ident_str!("delete_proposal").into(),
+    vec![T::move_type(package)],
+    vec![identity, capability.arg(), proposal_id],
+  );
+
+  capability.put_back(&mut ptb, package);
+
+  Ok(bcs::to_bytes(&ptb.finish())?)
+}
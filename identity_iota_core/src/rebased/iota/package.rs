@@ -15,38 +15,19 @@ use tokio::sync::RwLockReadGuard;
 use tokio::sync::RwLockWriteGuard;
 
 use crate::rebased::Error;
-
-macro_rules! object_id {
-  ($id:literal) => {
-    ObjectID::from_hex_literal($id).unwrap()
-  };
-}
+use crate::Network;
 
 static IOTA_IDENTITY_PACKAGE_REGISTRY: LazyLock<RwLock<PackageRegistry>> = LazyLock::new(|| {
   RwLock::new({
     let mut registry = PackageRegistry::default();
     // Add well-known networks.
-    registry.insert_env(
-      Env::new_with_alias("6364aad5", "iota"),
-      vec![
-        object_id!("0x84cf5d12de2f9731a89bb519bc0c982a941b319a33abefdd5ed2054ad931de08"),
-        object_id!("0x36d0d56aea27a59f620ba32b6dd47a5e68d810714468bd270fda5ad37a478767"),
-      ],
-    );
-    registry.insert_env(
-      Env::new_with_alias("2304aa97", "testnet"),
-      vec![
-        object_id!("0x222741bbdff74b42df48a7b4733185e9b24becb8ccfbafe8eac864ab4e4cc555"),
-        object_id!("0x3403da7ec4cd2ff9bdf6f34c0b8df5a2bd62c798089feb0d2ebf1c2e953296dc"),
-        object_id!("0x29359d33a2e84f04407da0d6cff15dd8ad271c75493ef6b78f381993e4c0abb0"),
-      ],
-    );
-    registry.insert_env(
-      Env::new_with_alias("daf90477", "devnet"),
-      vec![object_id!(
-        "0x8896ab04fe24c044c54925df3f8a7c383a8d1d6f6bbb95d1c57cfa94c75e520d"
-      )],
-    );
+    for network in Network::PRESETS {
+      let chain_id = network.chain_id().expect("well-known networks have a chain ID");
+      registry.insert_env(
+        Env::new_with_alias(chain_id, network.name().as_ref()),
+        network.package_history(),
+      );
+    }
 
     registry
   })
@@ -117,6 +98,11 @@ impl PackageRegistry {
     self.envs.insert(chain_id, history);
   }
 
+  /// Sets `alias` as an alias of `chain_id`.
+  pub(crate) fn insert_alias(&mut self, alias: impl Into<String>, chain_id: impl Into<String>) {
+    self.aliases.insert(alias.into(), chain_id.into());
+  }
+
   pub(crate) fn insert_new_package_version(&mut self, chain_id: &str, package: ObjectID) {
     let history = self.envs.entry(chain_id.to_string()).or_default();
     if history.last() != Some(&package) {
@@ -0,0 +1,303 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSync;
+use product_common::core_client::CoreClient;
+use secret_storage::Signer;
+
+use crate::rebased::client::IdentityClient;
+use crate::rebased::proposals::ConfigChange;
+use crate::rebased::proposals::ProposalBuilder;
+use crate::rebased::proposals::ProposedTxResult;
+use crate::rebased::Error;
+
+use super::ControllerToken;
+use super::InsufficientControllerVotingPower;
+use super::InvalidControllerTokenForIdentity;
+use super::NotAController;
+use super::OnChainIdentity;
+
+impl OnChainIdentity {
+  /// Starts transferring the control `controller_token` grants over this identity to address `new_controller`.
+  ///
+  /// The returned [ProposalBuilder] adds `new_controller` to this identity's controllers, with the same
+  /// voting power as `controller_token`'s controller. Once the proposal has been executed, `new_controller`
+  /// must finalize the transfer through [OnChainIdentity::complete_control_transfer].
+  ///
+  /// # Errors
+  /// This method fails if `controller_token` is a delegation token or if it doesn't grant
+  /// access to this identity.
+  pub fn begin_control_transfer<'i, 'c>(
+    &'i mut self,
+    controller_token: &'c ControllerToken,
+    new_controller: IotaAddress,
+  ) -> Result<ProposalBuilder<'i, 'c, ConfigChange>, ControlTransferError> {
+    let voting_power = self.transferable_voting_power(controller_token)?;
+
+    Ok(
+      self
+        .update_config(controller_token)
+        .add_controller(new_controller, voting_power),
+    )
+  }
+
+  /// Finalizes a control transfer started with [OnChainIdentity::begin_control_transfer].
+  ///
+  /// The returned [ProposalBuilder] - which must be executed by the new controller - removes
+  /// `previous_controller` from this identity's controllers.
+  ///
+  /// # Errors
+  /// This method fails if:
+  /// - `new_controller_token` is a delegation token or it doesn't grant access to this identity;
+  /// - `previous_controller` is not among this identity's controllers;
+  /// - `new_controller_token` belongs to `previous_controller`.
+  pub fn complete_control_transfer<'i, 'c>(
+    &'i mut self,
+    new_controller_token: &'c ControllerToken,
+    previous_controller: ObjectID,
+  ) -> Result<ProposalBuilder<'i, 'c, ConfigChange>, ControlTransferError> {
+    self.transferable_voting_power(new_controller_token)?;
+    if new_controller_token.controller_id() == previous_controller {
+      return Err(self.control_transfer_error(ControlTransferErrorKind::SameController(previous_controller)));
+    }
+    if !self.multicontroller().has_member(previous_controller) {
+      return Err(self.control_transfer_error(ControlTransferErrorKind::UnknownController(previous_controller)));
+    }
+
+    Ok(
+      self
+        .update_config(new_controller_token)
+        .remove_controller(previous_controller),
+    )
+  }
+
+  /// Transfers the control `current_controller` has over this identity to `new_controller`'s address.
+  ///
+  /// This is a convenience method for custody migrations where both signers are available at once.
+  /// It performs the following steps:
+  /// 1. `current_controller` adds `new_controller`'s address to this identity's controllers, with its same voting power
+  ///    - see [OnChainIdentity::begin_control_transfer];
+  /// 2. `new_controller` removes `current_controller` from this identity's controllers - see
+  ///    [OnChainIdentity::complete_control_transfer].
+  ///
+  /// Before submitting any transaction, this method makes sure that:
+  /// - the two clients have different addresses;
+  /// - `new_controller`'s address isn't already a controller of this identity;
+  /// - `current_controller` owns a [ControllerCap](super::ControllerCap) for this identity;
+  /// - `current_controller` has enough voting power to execute both steps without other controllers' approval.
+  ///
+  /// # Notes
+  /// Should the second step fail, `new_controller` is left as an additional controller of this identity.
+  /// The transfer can then be finalized by calling [OnChainIdentity::complete_control_transfer].
+  pub async fn transfer_control<S1, S2>(
+    &mut self,
+    current_controller: &IdentityClient<S1>,
+    new_controller: &IdentityClient<S2>,
+  ) -> Result<ControlTransferOutcome, ControlTransferError>
+  where
+    S1: Signer<IotaKeySignature> + OptionalSync,
+    S2: Signer<IotaKeySignature> + OptionalSync,
+  {
+    use ControlTransferErrorKind as ErrorKind;
+
+    let identity_id = self.id();
+    let did = self.did_document().id().clone();
+    let make_err = |kind| ControlTransferError {
+      identity: identity_id,
+      kind,
+    };
+
+    let current_address = current_controller.sender_address();
+    let new_address = new_controller.sender_address();
+    if current_address == new_address {
+      return Err(make_err(ErrorKind::SameAddress(new_address)));
+    }
+
+    let current_token = self
+      .get_controller_token(current_controller)
+      .await
+      .map_err(|e| make_err(ErrorKind::RpcError(e)))?
+      .ok_or_else(|| {
+        make_err(
+          NotAController {
+            address: current_address,
+            identity: did.clone(),
+          }
+          .into(),
+        )
+      })?;
+    let voting_power = self.transferable_voting_power(&current_token)?;
+    let threshold = self.threshold();
+    if voting_power < threshold {
+      return Err(make_err(
+        InsufficientControllerVotingPower {
+          controller_token_id: current_token.controller_id(),
+          controller_voting_power: voting_power,
+          required: threshold,
+        }
+        .into(),
+      ));
+    }
+
+    let maybe_new_controller_token = self
+      .get_controller_token(new_controller)
+      .await
+      .map_err(|e| make_err(ErrorKind::RpcError(e)))?;
+    if maybe_new_controller_token.is_some() {
+      return Err(make_err(ErrorKind::AlreadyAController(new_address)));
+    }
+
+    // Step 1: the current controller adds the new one.
+    let ProposedTxResult::Executed(_) = self
+      .begin_control_transfer(&current_token, new_address)?
+      .finish(current_controller)
+      .await
+      .map_err(|e| make_err(ErrorKind::Transaction(e)))?
+      .build_and_execute(current_controller)
+      .await
+      .map_err(|e| {
+        make_err(ErrorKind::Transaction(Error::TransactionUnexpectedResponse(
+          e.to_string(),
+        )))
+      })?
+      .output
+    else {
+      return Err(make_err(ErrorKind::NotExecuted));
+    };
+
+    // Step 2: the new controller removes the previous one.
+    let new_controller_token = self
+      .get_controller_token(new_controller)
+      .await
+      .map_err(|e| make_err(ErrorKind::RpcError(e)))?
+      .ok_or_else(|| {
+        make_err(
+          NotAController {
+            address: new_address,
+            identity: did,
+          }
+          .into(),
+        )
+      })?;
+    let previous_controller = current_token.controller_id();
+    let ProposedTxResult::Executed(_) = self
+      .complete_control_transfer(&new_controller_token, previous_controller)?
+      .finish(new_controller)
+      .await
+      .map_err(|e| make_err(ErrorKind::Transaction(e)))?
+      .build_and_execute(new_controller)
+      .await
+      .map_err(|e| {
+        make_err(ErrorKind::Transaction(Error::TransactionUnexpectedResponse(
+          e.to_string(),
+        )))
+      })?
+      .output
+    else {
+      return Err(make_err(ErrorKind::NotExecuted));
+    };
+
+    Ok(ControlTransferOutcome {
+      previous_controller,
+      new_controller_token,
+    })
+  }
+
+  /// Returns the voting power of `controller_token`'s controller, making sure the token can be used in a control
+  /// transfer.
+  fn transferable_voting_power(&self, controller_token: &ControllerToken) -> Result<u64, ControlTransferError> {
+    if controller_token.controller_of() != self.id() {
+      return Err(
+        self.control_transfer_error(
+          InvalidControllerTokenForIdentity {
+            identity: self.id(),
+            controller_token: controller_token.clone(),
+          }
+          .into(),
+        ),
+      );
+    }
+    if let Some(delegation_token) = controller_token.as_delegate() {
+      return Err(self.control_transfer_error(ControlTransferErrorKind::DelegationToken(delegation_token.id())));
+    }
+
+    self
+      .controller_voting_power(controller_token.controller_id())
+      .ok_or_else(|| {
+        self.control_transfer_error(ControlTransferErrorKind::UnknownController(
+          controller_token.controller_id(),
+        ))
+      })
+  }
+
+  fn control_transfer_error(&self, kind: ControlTransferErrorKind) -> ControlTransferError {
+    ControlTransferError {
+      identity: self.id(),
+      kind,
+    }
+  }
+}
+
+/// The result of a successful [OnChainIdentity::transfer_control].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ControlTransferOutcome {
+  /// The ID of the controller that has been removed from the identity.
+  pub previous_controller: ObjectID,
+  /// The token granting the new controller access to the identity.
+  pub new_controller_token: ControllerToken,
+}
+
+/// Failed to transfer the control over an [OnChainIdentity].
+#[derive(Debug, thiserror::Error)]
+#[error("failed to transfer the control over Identity `{identity}`")]
+#[non_exhaustive]
+pub struct ControlTransferError {
+  /// The ID of the Identity whose control was being transferred.
+  pub identity: ObjectID,
+  /// Specific type of failure for this error.
+  #[source]
+  pub kind: ControlTransferErrorKind,
+}
+
+/// Types of failure for [ControlTransferError].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ControlTransferErrorKind {
+  /// The presented token doesn't grant access to the identity.
+  #[error(transparent)]
+  InvalidControllerToken(#[from] InvalidControllerTokenForIdentity),
+  /// Control can only be transferred through a `ControllerCap`, not a delegation token.
+  #[error("delegation token `{0}` cannot be used to transfer the control over an identity")]
+  DelegationToken(ObjectID),
+  /// The given controller is not among the identity's controllers.
+  #[error("controller `{0}` is not among the identity's controllers")]
+  UnknownController(ObjectID),
+  /// The previous and the new controller are the same.
+  #[error("controller `{0}` cannot transfer its control to itself")]
+  SameController(ObjectID),
+  /// The current and the new controller share the same address.
+  #[error("address `{0}` cannot transfer its control to itself")]
+  SameAddress(IotaAddress),
+  /// The new controller's address already controls the identity.
+  #[error("address `{0}` is already a controller of the identity")]
+  AlreadyAController(IotaAddress),
+  /// The invoking client is not a controller of the identity.
+  #[error(transparent)]
+  NotAController(#[from] NotAController),
+  /// The current controller doesn't have enough voting power to carry out the transfer on its own.
+  #[error(transparent)]
+  InsufficientVotingPower(#[from] InsufficientControllerVotingPower),
+  /// Node RPC failure.
+  #[error("RPC request failed")]
+  RpcError(#[source] Error),
+  /// Failed to build or execute one of the transfer's transactions.
+  #[error("control transfer transaction failed")]
+  Transaction(#[source] Error),
+  /// One of the transfer's proposals was created but it wasn't executed.
+  #[error("a control transfer proposal requires the approval of other controllers")]
+  NotExecuted,
+}
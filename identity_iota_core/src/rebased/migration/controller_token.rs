@@ -9,6 +9,7 @@ use crate::IotaDID;
 use crate::rebased::iota::move_calls::ControllerTokenRef;
 use crate::rebased::iota::package::identity_package_id;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use async_trait::async_trait;
 use iota_interaction::move_types::language_storage::TypeTag;
 use iota_interaction::rpc_types::IotaExecutionStatus;
@@ -499,11 +500,13 @@ impl DelegationTokenRevocation {
     is_revocation: bool,
   ) -> Result<Self, Error> {
     if delegation_token.controller_of != identity.id() {
-      return Err(Error::Identity(format!(
-        "DelegationToken {} has no control over Identity {}",
-        delegation_token.id,
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: delegation_token.id,
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     Ok(Self {
@@ -601,11 +604,13 @@ impl DeleteDelegationToken {
   /// Returns a new [DeleteDelegationToken] [Transaction], that will delete the given [DelegationToken].
   pub fn new(identity: &OnChainIdentity, delegation_token: DelegationToken) -> Result<Self, Error> {
     if identity.id() != delegation_token.controller_of {
-      return Err(Error::Identity(format!(
-        "DelegationToken {} has no control over Identity {}",
-        delegation_token.id,
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: delegation_token.id,
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     Ok(Self {
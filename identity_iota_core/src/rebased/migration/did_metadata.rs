@@ -0,0 +1,158 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Subject;
+use iota_interaction::types::base_types::ObjectID;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::rebased::Error;
+
+use super::OnChainIdentity;
+
+/// The type of the credentials created through [`OnChainIdentity::did_metadata_credential`].
+pub const DID_METADATA_CREDENTIAL_TYPE: &str = "DIDMetadataCredential";
+
+/// A controller of an [`OnChainIdentity`] and its voting power.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControllerMetadata {
+  /// The ID of the controller's [`ControllerCap`](super::ControllerCap).
+  pub id: ObjectID,
+  /// The voting power of the controller.
+  pub voting_power: u64,
+}
+
+/// The on-chain attributes of an [`OnChainIdentity`], as attested by a DID metadata credential.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidMetadata {
+  /// The ID of the [`OnChainIdentity`].
+  pub object_id: ObjectID,
+  /// The controllers of the identity, ordered by ID.
+  pub controllers: Vec<ControllerMetadata>,
+  /// The voting power required to execute a proposal.
+  pub threshold: u64,
+  /// The creation time of the DID Document.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub created: Option<Timestamp>,
+  /// The time of the last update of the DID Document.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub updated: Option<Timestamp>,
+  /// Whether the DID Document is deactivated.
+  #[serde(default)]
+  pub deactivated: bool,
+}
+
+impl DidMetadata {
+  /// Extracts the [`DidMetadata`] from a credential created through
+  /// [`OnChainIdentity::did_metadata_credential`].
+  ///
+  /// Only the structure of `credential` is checked; its signature must be verified, e.g. with a
+  /// [`JwtCredentialValidator`](identity_credential::validator::JwtCredentialValidator), before trusting the
+  /// returned metadata.
+  pub fn from_credential(credential: &Credential) -> Result<Self, Error> {
+    if !credential.types.iter().any(|t| t == DID_METADATA_CREDENTIAL_TYPE) {
+      return Err(Error::InvalidArgument(format!(
+        "credential is not of type `{DID_METADATA_CREDENTIAL_TYPE}`"
+      )));
+    }
+    let OneOrMany::One(subject) = &credential.credential_subject else {
+      return Err(Error::InvalidArgument(
+        "a DID metadata credential must have exactly one subject".to_owned(),
+      ));
+    };
+    if subject.id.as_ref() != Some(credential.issuer.url()) {
+      return Err(Error::InvalidArgument(
+        "a DID metadata credential must be issued by its subject".to_owned(),
+      ));
+    }
+
+    subject
+      .properties
+      .to_json_value()
+      .and_then(Self::from_json_value)
+      .map_err(|e| Error::ParsingFailed(e.to_string()))
+  }
+}
+
+/// A DID metadata credential, created through [`OnChainIdentity::did_metadata_credential`], that is yet to be
+/// signed.
+///
+/// The credential is only meaningful once signed with a verification method of its issuer's DID Document, e.g. by
+/// passing [`UnsignedDidMetadataCredential::credential`] to `JwkDocumentExt::create_credential_jwt`.
+#[derive(Debug, Clone, PartialEq)]
+#[must_use = "a DID metadata credential must be signed to be of any use"]
+pub struct UnsignedDidMetadataCredential(Credential);
+
+impl UnsignedDidMetadataCredential {
+  /// Returns the credential to sign.
+  pub fn credential(&self) -> &Credential {
+    &self.0
+  }
+
+  /// Consumes this wrapper, returning the credential to sign.
+  pub fn into_credential(self) -> Credential {
+    self.0
+  }
+}
+
+impl OnChainIdentity {
+  /// Returns the [`DidMetadata`] of this [`OnChainIdentity`].
+  pub fn did_metadata(&self) -> DidMetadata {
+    let mut controllers: Vec<ControllerMetadata> = self
+      .controllers()
+      .iter()
+      .map(|(id, voting_power)| ControllerMetadata {
+        id: *id,
+        voting_power: *voting_power,
+      })
+      .collect();
+    controllers.sort_by_key(|controller| controller.id);
+
+    let metadata = &self.did_document().metadata;
+    DidMetadata {
+      object_id: self.id(),
+      controllers,
+      threshold: self.threshold(),
+      created: metadata.created,
+      updated: metadata.updated,
+      deactivated: metadata.deactivated.unwrap_or_default(),
+    }
+  }
+
+  /// Creates an [`UnsignedDidMetadataCredential`], issued by this identity's DID to itself, attesting its
+  /// [`DidMetadata`].
+  ///
+  /// The credential allows off-chain consumers to learn about the identity's controllers without querying the
+  /// ledger. It is **not signed**: this crate has no access to the identity's keys, so the caller must sign it
+  /// with a verification method of this identity's DID Document, e.g. through
+  /// `JwkDocumentExt::create_credential_jwt`, before handing it out.
+  pub fn did_metadata_credential(&self) -> Result<UnsignedDidMetadataCredential, Error> {
+    let did_url =
+      Url::parse(self.did_document().id().to_string()).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+    let properties: Object = self
+      .did_metadata()
+      .to_json_value()
+      .ok()
+      .and_then(|value| Object::from_json_value(value).ok())
+      .ok_or_else(|| Error::DidDocSerialization("failed to serialize DID metadata".to_owned()))?;
+
+    CredentialBuilder::default()
+      .issuer(did_url.clone())
+      .type_(DID_METADATA_CREDENTIAL_TYPE)
+      .subject(Subject::with_id_and_properties(did_url, properties))
+      .issuance_date(Timestamp::now_utc())
+      .build()
+      .map(UnsignedDidMetadataCredential)
+      .map_err(|e| Error::InvalidArgument(e.to_string()))
+  }
+}
@@ -51,23 +51,30 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::proposals::get_pending_proposal;
 use crate::rebased::proposals::BorrowAction;
 use crate::rebased::proposals::ConfigChange;
 use crate::rebased::proposals::ControllerExecution;
+use crate::rebased::proposals::PendingProposal;
 use crate::rebased::proposals::ProposalBuilder;
 use crate::rebased::proposals::SendAction;
 use crate::rebased::proposals::UpdateDidDocument;
 use crate::rebased::rebased_err;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use iota_interaction::IotaClientTrait;
 use iota_interaction::MoveType;
 
 use super::ControllerCap;
 use super::ControllerToken;
+use super::DelegatePermissions;
+use super::DelegateToken;
 use super::DelegationToken;
 use super::DelegationTokenRevocation;
 use super::DeleteDelegationToken;
 use super::Multicontroller;
+use super::OwnedTokenLocator;
+use super::TokenLocator;
 use super::UnmigratedAlias;
 
 const MODULE: &str = "identity";
@@ -139,6 +146,18 @@ impl OnChainIdentity {
     &mut self.did_doc
   }
 
+  /// Returns the [`StateMetadataEncoding`] the DID Document of this [`OnChainIdentity`] is stored with on-chain.
+  ///
+  /// Defaults to [`StateMetadataEncoding::Json`] when there is no DID Document, e.g. because it has been deactivated.
+  pub fn state_metadata_encoding(&self) -> StateMetadataEncoding {
+    self
+      .multi_controller
+      .controlled_value()
+      .as_deref()
+      .and_then(|packed| StateMetadataDocument::packed_encoding(packed).ok())
+      .unwrap_or_default()
+  }
+
   /// Returns whether the [IotaDocument] contained in this [OnChainIdentity] has been deleted.
   /// Once a DID Document is deleted, it cannot be reactivated.
   ///
@@ -158,6 +177,17 @@ impl OnChainIdentity {
     self.multi_controller.proposals()
   }
 
+  /// Fetches this [`OnChainIdentity`]'s active proposals, together with their votes, expiration and the threshold
+  /// they need to reach.
+  pub async fn list_proposals(&self, client: &impl CoreClientReadOnly) -> Result<Vec<PendingProposal>, Error> {
+    let mut proposals = Vec::with_capacity(self.proposals().len());
+    for &proposal_id in self.proposals() {
+      proposals.push(get_pending_proposal(client, proposal_id, self.threshold()).await?);
+    }
+
+    Ok(proposals)
+  }
+
   /// Returns this [`OnChainIdentity`]'s controllers as the map: `controller_id -> controller_voting_power`.
   pub fn controllers(&self) -> &HashMap<ObjectID, u64> {
     self.multi_controller.controllers()
@@ -176,24 +206,13 @@ impl OnChainIdentity {
   /// Returns a [ControllerToken] owned by `address` that grants access to this Identity.
   /// ## Notes
   /// [None] is returned if `address` doesn't own a valid [ControllerToken].
+  /// See [OnChainIdentity::find_controller_token] for tokens held in other ways.
   pub async fn get_controller_token_for_address(
     &self,
     address: IotaAddress,
     client: &(impl CoreClientReadOnly + OptionalSync),
   ) -> Result<Option<ControllerToken>, Error> {
-    let maybe_controller_cap = client
-      .find_object_for_address::<ControllerCap, _>(address, |token| token.controller_of() == self.id())
-      .await;
-
-    if let Ok(Some(controller_cap)) = maybe_controller_cap {
-      return Ok(Some(controller_cap.into()));
-    }
-
-    client
-      .find_object_for_address::<DelegationToken, _>(address, |token| token.controller_of() == self.id())
-      .await
-      .map(|maybe_delegate| maybe_delegate.map(ControllerToken::from))
-      .map_err(|e| Error::RpcError(format!("{e:#}")))
+    self.find_controller_token(address, &OwnedTokenLocator, client).await
   }
 
   /// Returns a [ControllerToken], owned by `client`'s sender address, that grants access to this Identity.
@@ -208,17 +227,36 @@ impl OnChainIdentity {
       .await
   }
 
+  /// Returns a [ControllerToken] that grants `address` access to this Identity, as located by `locator`.
+  ///
+  /// Use a [CustodyTokenLocator](super::CustodyTokenLocator) for tokens held by multisig addresses or wrapped in
+  /// objects such as kiosks or vaults, or a custom [TokenLocator] for other custody setups.
+  pub async fn find_controller_token<L>(
+    &self,
+    address: IotaAddress,
+    locator: &L,
+    client: &(impl CoreClientReadOnly + OptionalSync),
+  ) -> Result<Option<ControllerToken>, Error>
+  where
+    L: TokenLocator + ?Sized,
+  {
+    locator.locate_token(self, address, client).await
+  }
+
   pub(crate) fn multicontroller(&self) -> &Multicontroller<Option<Vec<u8>>> {
     &self.multi_controller
   }
 
   /// Updates this [`OnChainIdentity`]'s DID Document.
+  ///
+  /// The updated document is stored with the identity's current [`StateMetadataEncoding`].
   pub fn update_did_document<'i, 'c>(
     &'i mut self,
     updated_doc: IotaDocument,
     controller_token: &'c ControllerToken,
   ) -> ProposalBuilder<'i, 'c, UpdateDidDocument> {
-    ProposalBuilder::new(self, controller_token, UpdateDidDocument::new(updated_doc))
+    let action = UpdateDidDocument::new_with_encoding(updated_doc, self.state_metadata_encoding());
+    ProposalBuilder::new(self, controller_token, action)
   }
 
   /// Updates this [`OnChainIdentity`]'s configuration.
@@ -338,6 +376,41 @@ impl OnChainIdentity {
     Ok(history)
   }
 
+  /// Returns a [Transaction] that mints a new [DelegationToken] for `controller_capability` with `permissions`
+  /// and sends it to `to_address`, which can then act on this Identity in the controller's stead.
+  ///
+  /// This allows e.g. keeping the governance key that holds the [ControllerCap] offline, while day-to-day
+  /// operations are carried out by a separate operational key. The delegation can be withdrawn at any time
+  /// through [OnChainIdentity::revoke_delegation_token].
+  pub fn delegate_control(
+    &self,
+    controller_capability: &ControllerCap,
+    to_address: IotaAddress,
+    permissions: DelegatePermissions,
+  ) -> Result<TransactionBuilder<DelegateToken>, Error> {
+    if controller_capability.controller_of() != self.id() {
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_capability.id(),
+          identity: self.id(),
+        }
+        .into(),
+      );
+    }
+
+    controller_capability
+      .delegate(to_address, Some(permissions))
+      .ok_or_else(|| IdentityError::NonDelegableController(controller_capability.id()).into())
+  }
+
+  /// Returns `true` if `delegation_token` currently grants access to this Identity, i.e. it hasn't been
+  /// revoked and the controller that issued it is still a member of this Identity.
+  pub fn is_delegation_token_valid(&self, delegation_token: &DelegationToken) -> bool {
+    delegation_token.controller_of() == self.id()
+      && self.multi_controller.has_member(delegation_token.controller())
+      && !self.multi_controller.is_token_revoked(delegation_token.id())
+  }
+
   /// Returns a [Transaction] to revoke a [DelegationToken].
   pub fn revoke_delegation_token(
     &self,
@@ -384,15 +457,23 @@ async fn get_previous_version(
 }
 
 /// Returns the [`OnChainIdentity`] having ID `object_id`, if it exists.
+///
+/// # Errors
+/// Returns [`Error::NotAnIdentity`] when `object_id` refers to an object that is not an Identity.
 pub async fn get_identity(
   client: &impl CoreClientReadOnly,
   object_id: ObjectID,
 ) -> Result<Option<OnChainIdentity>, Error> {
+  use IdentityResolutionErrorKind::InvalidType;
   use IdentityResolutionErrorKind::NotFound;
 
   match get_identity_impl(client, object_id).await {
     Ok(identity) => Ok(Some(identity)),
     Err(IdentityResolutionError { kind: NotFound, .. }) => Ok(None),
+    Err(IdentityResolutionError {
+      kind: InvalidType(actual_type),
+      ..
+    }) => Err(Error::NotAnIdentity { object_id, actual_type }),
     Err(e) => {
       // Use anyhow to format the error in such a way that all its causes are displayed too.
       let formatted_err_msg = format!("{:#}", anyhow::Error::new(e));
@@ -405,10 +486,20 @@ pub(crate) async fn get_identity_impl(
   client: &impl CoreClientReadOnly,
   object_id: ObjectID,
 ) -> Result<OnChainIdentity, IdentityResolutionError> {
+  let data = get_identity_object_data(client, object_id, IotaObjectDataOptions::new().with_content()).await?;
+  identity_from_object_data(client, data)
+}
+
+/// Fetches the object data of the Identity with ID `object_id`. `options` must request the object's content.
+pub(crate) async fn get_identity_object_data(
+  client: &impl CoreClientReadOnly,
+  object_id: ObjectID,
+  options: IotaObjectDataOptions,
+) -> Result<IotaObjectData, IdentityResolutionError> {
   let response = client
     .client_adapter()
     .read_api()
-    .get_object_with_options(object_id, IotaObjectDataOptions::new().with_content())
+    .get_object_with_options(object_id, options)
     .await
     .map_err(|e| IdentityResolutionError {
       kind: IdentityResolutionErrorKind::RpcError(e.into()),
@@ -427,7 +518,15 @@ pub(crate) async fn get_identity_impl(
     }
   }
 
-  let data = response.data.expect("already handled errors in response");
+  Ok(response.data.expect("already handled errors in response"))
+}
+
+/// Parses an [`OnChainIdentity`] out of its object data.
+pub(crate) fn identity_from_object_data(
+  client: &impl CoreClientReadOnly,
+  data: IotaObjectData,
+) -> Result<OnChainIdentity, IdentityResolutionError> {
+  let object_id = data.object_id;
   let network = client.network_name();
   let did = IotaDID::from_object_id(object_id, network);
   let IdentityData {
@@ -597,6 +696,7 @@ pub struct IdentityBuilder {
   did_doc: IotaDocument,
   threshold: Option<u64>,
   controllers: HashMap<IotaAddress, (u64, bool)>,
+  encoding: StateMetadataEncoding,
 }
 
 impl IdentityBuilder {
@@ -609,6 +709,7 @@ impl IdentityBuilder {
       did_doc,
       threshold: None,
       controllers: HashMap::new(),
+      encoding: StateMetadataEncoding::default(),
     }
   }
 
@@ -631,6 +732,13 @@ impl IdentityBuilder {
     self
   }
 
+  /// Sets the [`StateMetadataEncoding`] used to store the identity's DID Document on-chain.
+  /// Defaults to [`StateMetadataEncoding::Json`].
+  pub fn state_metadata_encoding(mut self, encoding: StateMetadataEncoding) -> Self {
+    self.encoding = encoding;
+    self
+  }
+
   /// Sets multiple controllers in a single step. See [`IdentityBuilder::controller`].
   pub fn controllers<I>(self, controllers: I) -> Self
   where
@@ -696,10 +804,11 @@ impl CreateIdentity {
       did_doc,
       threshold,
       controllers,
+      encoding,
     } = &self.builder;
     let package = identity_package_id(client).await?;
     let did_doc = StateMetadataDocument::from(did_doc.clone())
-      .pack(StateMetadataEncoding::default())
+      .pack(*encoding)
       .map_err(|e| Error::DidDocSerialization(e.to_string()))?;
     let pt_bcs = if controllers.is_empty() {
       move_calls::identity::new_identity(Some(&did_doc), package).await?
@@ -710,7 +819,7 @@ impl CreateIdentity {
           &controllers
             .values()
             .next()
-            .ok_or_else(|| Error::Identity("could not get controller".to_string()))?
+            .ok_or_else(|| Error::from(IdentityError::MissingController))?
             .0
         }
         None => {
@@ -762,7 +871,7 @@ impl Transaction for CreateIdentity {
       .map(|(i, obj)| (i, obj.object_id()));
 
     let target_did_bytes = StateMetadataDocument::from(self.builder.did_doc)
-      .pack(StateMetadataEncoding::Json)
+      .pack(self.builder.encoding)
       .map_err(|e| Error::DidDocSerialization(e.to_string()))?;
 
     let is_target_identity = |identity: &OnChainIdentity| -> bool {
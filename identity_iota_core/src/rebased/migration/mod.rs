@@ -2,13 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod alias;
+mod control_transfer;
 mod controller_token;
+mod did_metadata;
 mod identity;
 mod multicontroller;
 mod registry;
+mod token_locator;
 
 pub use alias::*;
+pub use control_transfer::*;
 pub use controller_token::*;
+pub use did_metadata::*;
 pub use identity::*;
 pub use multicontroller::*;
 pub use registry::*;
+pub use token_locator::*;
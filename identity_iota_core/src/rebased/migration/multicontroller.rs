@@ -63,6 +63,13 @@ impl<T> Proposal<T> {
   pub fn expiration_epoch(&self) -> Option<u64> {
     self.expiration_epoch
   }
+
+  /// Returns `true` if this [`Proposal`] is expired at epoch `current_epoch`.
+  pub fn is_expired(&self, current_epoch: u64) -> bool {
+    self
+      .expiration_epoch
+      .is_some_and(|expiration| expiration < current_epoch)
+  }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -127,6 +134,7 @@ pub struct Multicontroller<T> {
   threshold: u64,
   active_proposals: HashSet<ObjectID>,
   proposals: Bag,
+  revoked_tokens: HashSet<ObjectID>,
 }
 
 impl<T> Multicontroller<T> {
@@ -167,6 +175,16 @@ impl<T> Multicontroller<T> {
   pub fn has_member(&self, cap_id: ObjectID) -> bool {
     self.controllers.contains_key(&cap_id)
   }
+
+  /// Returns the IDs of the delegation tokens that have been revoked.
+  pub fn revoked_tokens(&self) -> &HashSet<ObjectID> {
+    &self.revoked_tokens
+  }
+
+  /// Returns `true` if the delegation token with ID `token_id` has been revoked.
+  pub fn is_token_revoked(&self, token_id: ObjectID) -> bool {
+    self.revoked_tokens.contains(&token_id)
+  }
 }
 
 impl<T> TryFrom<IotaMulticontroller<T>> for Multicontroller<T> {
@@ -178,6 +196,7 @@ impl<T> TryFrom<IotaMulticontroller<T>> for Multicontroller<T> {
       threshold,
       active_proposals,
       proposals,
+      revoked_tokens,
     } = value;
     let controllers = controllers
       .contents
@@ -191,6 +210,9 @@ impl<T> TryFrom<IotaMulticontroller<T>> for Multicontroller<T> {
       threshold: threshold.try_into()?,
       active_proposals,
       proposals,
+      revoked_tokens: revoked_tokens
+        .map(|revoked_tokens| revoked_tokens.contents.into_iter().collect())
+        .unwrap_or_default(),
     })
   }
 }
@@ -202,4 +224,7 @@ struct IotaMulticontroller<T> {
   threshold: Number<u64>,
   active_proposals: HashSet<ObjectID>,
   proposals: Bag,
+  // Missing in objects created by package versions predating token revocation.
+  #[serde(default)]
+  revoked_tokens: Option<VecSet<ObjectID>>,
 }
@@ -0,0 +1,205 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::object::Owner;
+use iota_interaction::IotaClientTrait;
+use iota_interaction::OptionalSync;
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::rebased::Error;
+
+use super::ControllerCap;
+use super::ControllerToken;
+use super::DelegationToken;
+use super::OnChainIdentity;
+
+/// The maximum number of objects [CustodyTokenLocator] walks through when following the owners of a token.
+const DEFAULT_MAX_CUSTODY_DEPTH: usize = 8;
+
+/// A strategy for locating the [ControllerToken] that grants an address access to an [OnChainIdentity].
+///
+/// Implement this trait to support custody setups not covered by [OwnedTokenLocator] and [CustodyTokenLocator].
+/// Locators can be combined as a tuple `(first, second)`, which tries `first` and falls back to `second`.
+///
+/// ## Notes
+/// A located token can only be used in transactions authorized by whoever holds it: a token held by a multisig
+/// address requires a transaction signed by that multisig, and a token held by an object, e.g. a kiosk, must be
+/// borrowed from it through the object's own Move API.
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+pub trait TokenLocator: OptionalSync {
+  /// Returns a [ControllerToken] through which `address` can access `identity`, if any.
+  async fn locate_token<C>(
+    &self,
+    identity: &OnChainIdentity,
+    address: IotaAddress,
+    client: &C,
+  ) -> Result<Option<ControllerToken>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync;
+}
+
+/// Locates tokens owned directly by the address, preferring a [ControllerCap] over a [DelegationToken].
+/// Delegation tokens that are [no longer valid](OnChainIdentity::is_delegation_token_valid) are ignored.
+///
+/// This is the strategy used by [OnChainIdentity::get_controller_token].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OwnedTokenLocator;
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl TokenLocator for OwnedTokenLocator {
+  async fn locate_token<C>(
+    &self,
+    identity: &OnChainIdentity,
+    address: IotaAddress,
+    client: &C,
+  ) -> Result<Option<ControllerToken>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let maybe_controller_cap = client
+      .find_object_for_address::<ControllerCap, _>(address, |token| token.controller_of() == identity.id())
+      .await;
+
+    if let Ok(Some(controller_cap)) = maybe_controller_cap {
+      return Ok(Some(controller_cap.into()));
+    }
+
+    client
+      .find_object_for_address::<DelegationToken, _>(address, |token| identity.is_delegation_token_valid(token))
+      .await
+      .map(|maybe_delegate| maybe_delegate.map(ControllerToken::from))
+      .map_err(|e| Error::RpcError(format!("{e:#}")))
+  }
+}
+
+/// Locates tokens held on behalf of the address by custodians: addresses - e.g. multisig addresses the address
+/// is a member of - or objects - e.g. kiosks or vaults - the address has access to.
+///
+/// The controller capabilities of the identity are found by following their owners, up to a configurable depth,
+/// which covers capabilities wrapped in dynamic object fields or transferred to objects. Delegation tokens are
+/// only found when owned directly by a custodian.
+#[derive(Debug, Clone)]
+pub struct CustodyTokenLocator {
+  custodians: Vec<IotaAddress>,
+  max_depth: usize,
+}
+
+impl CustodyTokenLocator {
+  /// Creates a new [CustodyTokenLocator] accepting tokens held by the address itself or by any of `custodians`.
+  pub fn new(custodians: impl IntoIterator<Item = IotaAddress>) -> Self {
+    Self {
+      custodians: custodians.into_iter().collect(),
+      max_depth: DEFAULT_MAX_CUSTODY_DEPTH,
+    }
+  }
+
+  /// Adds an object, e.g. a kiosk or a vault, whose tokens are accepted.
+  #[must_use]
+  pub fn with_object(mut self, object: ObjectID) -> Self {
+    self.custodians.push(object.into());
+    self
+  }
+
+  /// Sets the maximum number of objects walked through when following the owners of a token.
+  #[must_use]
+  pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+    self.max_depth = max_depth;
+    self
+  }
+
+  /// Returns whether the chain of owners of `object` reaches `address` or one of the custodians.
+  async fn is_in_custody<C>(&self, object: ObjectID, address: IotaAddress, client: &C) -> Result<bool, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let mut current: ObjectID = object;
+    for _ in 0..=self.max_depth {
+      let owner: Option<Owner> = client
+        .client_adapter()
+        .read_api()
+        .get_object_with_options(current, IotaObjectDataOptions::new().with_owner())
+        .await
+        .map_err(|e| Error::RpcError(e.to_string()))?
+        .owner();
+
+      let holder: IotaAddress = match owner {
+        Some(Owner::AddressOwner(holder)) => {
+          return Ok(holder == address || self.custodians.contains(&holder));
+        }
+        Some(Owner::ObjectOwner(holder)) => holder,
+        _ => return Ok(false),
+      };
+      if self.custodians.contains(&holder) {
+        return Ok(true);
+      }
+      current = holder.into();
+    }
+
+    Ok(false)
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl TokenLocator for CustodyTokenLocator {
+  async fn locate_token<C>(
+    &self,
+    identity: &OnChainIdentity,
+    address: IotaAddress,
+    client: &C,
+  ) -> Result<Option<ControllerToken>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    for &controller_id in identity.controllers().keys() {
+      if self.is_in_custody(controller_id, address, client).await? {
+        let token = client
+          .get_object_by_id::<ControllerToken>(controller_id)
+          .await
+          .map_err(|e| Error::RpcError(format!("{e:#}")))?;
+        return Ok(Some(token));
+      }
+    }
+
+    for &custodian in &self.custodians {
+      let maybe_delegate = client
+        .find_object_for_address::<DelegationToken, _>(custodian, |token| identity.is_delegation_token_valid(token))
+        .await
+        .map_err(|e| Error::RpcError(format!("{e:#}")))?;
+      if let Some(delegate) = maybe_delegate {
+        return Ok(Some(delegate.into()));
+      }
+    }
+
+    Ok(None)
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait(?Send))]
+impl<A, B> TokenLocator for (A, B)
+where
+  A: TokenLocator,
+  B: TokenLocator,
+{
+  async fn locate_token<C>(
+    &self,
+    identity: &OnChainIdentity,
+    address: IotaAddress,
+    client: &C,
+  ) -> Result<Option<ControllerToken>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    if let Some(token) = self.0.locate_token(identity, address, client).await? {
+      return Ok(Some(token));
+    }
+    self.1.locate_token(identity, address, client).await
+  }
+}
@@ -5,7 +5,10 @@
 pub mod assets;
 /// Module for handling client operations.
 pub mod client;
+/// Composition of multiple transactions into a single programmable transaction.
+pub mod compose;
 mod error;
+mod execution;
 mod iota;
 /// Module for handling migration operations.
 pub mod migration;
@@ -17,6 +20,7 @@ pub mod utils;
 
 pub use assets::*;
 pub use error::*;
+pub use execution::*;
 
 /// Integration with IOTA's Keytool.
 #[cfg(feature = "keytool")]
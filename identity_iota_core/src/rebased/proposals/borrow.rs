@@ -19,6 +19,7 @@ use tokio::sync::Mutex;
 
 use crate::rebased::migration::Proposal;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use async_trait::async_trait;
 use iota_interaction::rpc_types::IotaExecutionStatus;
 use iota_interaction::rpc_types::IotaObjectData;
@@ -205,11 +206,13 @@ where
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let package = identity_package_id(client).await?;
@@ -264,11 +267,13 @@ where
     _client: &C,
   ) -> Result<UserDrivenTx<'i, Self::Action>, Error> {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let proposal_id = self.id();
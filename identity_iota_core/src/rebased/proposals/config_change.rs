@@ -28,6 +28,7 @@ use serde::Serialize;
 use crate::rebased::iota::types::Number;
 use crate::rebased::migration::OnChainIdentity;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use iota_interaction::MoveType;
 use iota_interaction::OptionalSync;
 
@@ -237,11 +238,13 @@ impl ProposalT for Proposal<ConfigChange> {
     action.validate(identity)?;
 
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let package = identity_package_id(client).await?;
@@ -284,11 +287,13 @@ impl ProposalT for Proposal<ConfigChange> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let proposal_id = self.id();
@@ -9,6 +9,7 @@ use crate::rebased::migration::ControllerToken;
 use crate::rebased::migration::Proposal;
 
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use async_trait::async_trait;
 use iota_interaction::rpc_types::IotaExecutionStatus;
 use iota_interaction::rpc_types::IotaObjectRef;
@@ -185,11 +186,13 @@ where
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
@@ -252,11 +255,13 @@ where
     _client: &C,
   ) -> Result<UserDrivenTx<'i, Self::Action>, Error> {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let proposal_id = self.id();
@@ -5,6 +5,7 @@ mod access_sub_identity;
 mod borrow;
 mod config_change;
 mod controller;
+mod pending;
 mod send;
 mod update_did_doc;
 mod upgrade;
@@ -20,6 +21,7 @@ use async_trait::async_trait;
 pub use borrow::*;
 pub use config_change::*;
 pub use controller::*;
+pub use pending::*;
 use iota_interaction::rpc_types::IotaExecutionStatus;
 use iota_interaction::rpc_types::IotaObjectData;
 use iota_interaction::rpc_types::IotaObjectDataOptions;
@@ -49,6 +51,7 @@ use super::iota::package::identity_package_id;
 use crate::rebased::migration::OnChainIdentity;
 use crate::rebased::migration::Proposal;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use iota_interaction::MoveType;
 
 use super::migration::ControllerToken;
@@ -99,6 +102,17 @@ where
   ) -> Result<TransactionBuilder<ApproveProposal<'_, 'i, A>>, Error> {
     ApproveProposal::new(self, identity, controller_token).map(TransactionBuilder::new)
   }
+
+  /// Creates a new [DeleteProposal] for the provided [`Proposal`], rejecting it.
+  ///
+  /// Proposals can only be deleted once they are expired, if they have no votes, or if `identity` has been deleted.
+  pub fn delete<'i>(
+    self,
+    identity: &'i mut OnChainIdentity,
+    controller_token: &ControllerToken,
+  ) -> Result<TransactionBuilder<DeleteProposal<'i, A>>, Error> {
+    DeleteProposal::new(self, identity, controller_token).map(TransactionBuilder::new)
+  }
 }
 
 /// A builder for creating a [`Proposal`].
@@ -193,6 +207,40 @@ impl<A> CreateProposal<'_, A> {
   pub fn ptb(&self) -> &ProgrammableTransaction {
     &self.ptb
   }
+
+  /// Releases the [`OnChainIdentity`] this transaction borrows, so that the transaction can be kept alongside it.
+  pub(crate) fn detach(self) -> DetachedCreateProposal<A> {
+    DetachedCreateProposal {
+      chained_execution: self.chained_execution,
+      ptb: self.ptb,
+      _action: PhantomData,
+    }
+  }
+}
+
+/// A [`CreateProposal`] that doesn't borrow its [`OnChainIdentity`], see [`CreateProposal::detach`].
+#[derive(Debug)]
+pub(crate) struct DetachedCreateProposal<A> {
+  chained_execution: bool,
+  ptb: ProgrammableTransaction,
+  _action: PhantomData<A>,
+}
+
+impl<A> DetachedCreateProposal<A> {
+  /// Returns the transaction's [ProgrammableTransaction].
+  pub(crate) fn ptb(&self) -> &ProgrammableTransaction {
+    &self.ptb
+  }
+
+  /// Turns this back into a [`CreateProposal`] for `identity`.
+  pub(crate) fn attach(self, identity: &mut OnChainIdentity) -> CreateProposal<'_, A> {
+    CreateProposal {
+      identity,
+      chained_execution: self.chained_execution,
+      ptb: self.ptb,
+      _action: PhantomData,
+    }
+  }
 }
 
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
@@ -230,7 +278,7 @@ where
     // or simply created. Refetch it, to sync it with its on-chain state.
     *identity = get_identity(client, identity.id())
       .await?
-      .ok_or_else(|| Error::Identity(format!("identity {} cannot be found", identity.id())))?;
+      .ok_or_else(|| Error::from(IdentityError::NotFound(identity.id())))?;
 
     if chained_execution {
       // The proposal has been created and executed right-away. Parse its effects.
@@ -299,7 +347,7 @@ where
 
     *identity = get_identity(client, identity.id())
       .await?
-      .ok_or_else(|| Error::Identity(format!("identity {} cannot be found", identity.id())))?;
+      .ok_or_else(|| Error::from(IdentityError::NotFound(identity.id())))?;
 
     Proposal::<A>::parse_tx_effects(effects)
   }
@@ -322,11 +370,13 @@ impl<'p, 'i, A> ApproveProposal<'p, 'i, A> {
     controller_token: &ControllerToken,
   ) -> Result<Self, Error> {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     Ok(Self {
@@ -351,7 +401,7 @@ impl<A: MoveType> ApproveProposal<'_, '_, A> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .ok_or_else(|| Error::Identity(format!("identity {} doesn't exist", identity.id())))?;
+      .ok_or_else(|| Error::from(IdentityError::NotFound(identity.id())))?;
     let controller_cap = controller_token.controller_ref(client).await?;
     let package = identity_package_id(client).await?;
 
@@ -404,6 +454,99 @@ where
   }
 }
 
+/// A transaction to delete a [`Proposal`].
+#[derive(Debug)]
+pub struct DeleteProposal<'i, A> {
+  proposal: Proposal<A>,
+  identity: &'i mut OnChainIdentity,
+  controller_token: ControllerToken,
+  cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<'i, A> DeleteProposal<'i, A> {
+  /// Creates a new [Transaction] to delete `identity`'s `proposal`.
+  pub fn new(
+    proposal: Proposal<A>,
+    identity: &'i mut OnChainIdentity,
+    controller_token: &ControllerToken,
+  ) -> Result<Self, Error> {
+    if identity.id() != controller_token.controller_of() {
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
+    }
+
+    Ok(Self {
+      proposal,
+      identity,
+      controller_token: controller_token.clone(),
+      cached_ptb: OnceCell::new(),
+    })
+  }
+}
+
+impl<A: MoveType> DeleteProposal<'_, A> {
+  async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let Self {
+      proposal,
+      identity,
+      controller_token,
+      ..
+    } = self;
+    let identity_ref = client
+      .get_object_ref_by_id(identity.id())
+      .await?
+      .ok_or_else(|| Error::from(IdentityError::NotFound(identity.id())))?;
+    let controller_cap = controller_token.controller_ref(client).await?;
+    let package = identity_package_id(client).await?;
+
+    let tx = move_calls::identity::delete_proposal::<A>(identity_ref, controller_cap, proposal.id(), package)?;
+
+    Ok(bcs::from_bytes(&tx)?)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<A> Transaction for DeleteProposal<'_, A>
+where
+  A: MoveType + OptionalSend + OptionalSync,
+{
+  type Output = ();
+  type Error = Error;
+
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let Self { identity, .. } = self;
+
+    if let IotaExecutionStatus::Failure { error } = effects.status() {
+      return Err(Error::TransactionUnexpectedResponse(error.clone()));
+    }
+
+    *identity = get_identity(client, identity.id())
+      .await?
+      .ok_or_else(|| Error::from(IdentityError::NotFound(identity.id())))?;
+
+    Ok(())
+  }
+}
+
 async fn obj_data_for_id(client: &impl CoreClientReadOnly, obj_id: ObjectID) -> anyhow::Result<IotaObjectData> {
   use anyhow::Context;
 
@@ -451,11 +594,11 @@ impl<'i, A> UserDrivenTx<'i, A> {
   }
 }
 
+/// The `ProposalEvent` emitted when a proposal is created or executed.
 #[derive(Debug, Deserialize)]
-struct ProposalEvent {
-  identity: ObjectID,
-  controller: ObjectID,
-  proposal: ObjectID,
-  #[allow(dead_code)]
-  executed: bool,
+pub(crate) struct ProposalEvent {
+  pub(crate) identity: ObjectID,
+  pub(crate) controller: ObjectID,
+  pub(crate) proposal: ObjectID,
+  pub(crate) executed: bool,
 }
@@ -0,0 +1,166 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::rpc_types::IotaParsedData;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::TypeTag;
+use iota_interaction::IotaClientTrait as _;
+use product_common::core_client::CoreClientReadOnly;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::rebased::migration::Proposal;
+use crate::rebased::Error;
+
+use super::ConfigChange;
+use super::SendAction;
+use super::UpdateDidDocument;
+use super::Upgrade;
+
+/// A [`Proposal`] of any kind, as returned by
+/// [`OnChainIdentity::list_proposals`](crate::rebased::migration::OnChainIdentity::list_proposals).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum AnyProposal {
+  /// A proposal to update, deactivate or delete the DID Document.
+  UpdateDidDocument(Proposal<UpdateDidDocument>),
+  /// A proposal to change the Identity's controllers or threshold.
+  ConfigChange(Proposal<ConfigChange>),
+  /// A proposal to send assets owned by the Identity.
+  Send(Proposal<SendAction>),
+  /// A proposal to upgrade the Identity to the latest package version.
+  Upgrade(Proposal<Upgrade>),
+  /// A proposal with an action this library doesn't expose a typed interface for - e.g. borrowing assets, whose
+  /// intent only lives in the transaction executing it.
+  Other {
+    /// The Move type of the proposal's action.
+    action_type: TypeTag,
+    /// The proposal, with its action in JSON form.
+    proposal: Proposal<Value>,
+  },
+}
+
+macro_rules! with_proposal {
+  ($any:expr, $proposal:ident => $e:expr) => {
+    match $any {
+      AnyProposal::UpdateDidDocument($proposal) => $e,
+      AnyProposal::ConfigChange($proposal) => $e,
+      AnyProposal::Send($proposal) => $e,
+      AnyProposal::Upgrade($proposal) => $e,
+      AnyProposal::Other {
+        proposal: $proposal, ..
+      } => $e,
+    }
+  };
+}
+
+/// An active [`Proposal`] of an [`OnChainIdentity`](crate::rebased::migration::OnChainIdentity), together with the
+/// voting threshold it has to reach to be executed.
+///
+/// Typed proposals can be approved through [`Proposal::approve`], deleted through [`Proposal::delete`] and executed
+/// through [`ProposalT::into_tx`](super::ProposalT::into_tx).
+#[derive(Debug, Clone)]
+pub struct PendingProposal {
+  proposal: AnyProposal,
+  threshold: u64,
+}
+
+impl PendingProposal {
+  /// Returns this proposal's ID.
+  pub fn id(&self) -> ObjectID {
+    with_proposal!(&self.proposal, proposal => proposal.id())
+  }
+
+  /// Returns the votes this proposal received.
+  pub fn votes(&self) -> u64 {
+    with_proposal!(&self.proposal, proposal => proposal.votes())
+  }
+
+  /// Returns the IDs of the controllers that approved this proposal.
+  pub fn voters(&self) -> &HashSet<ObjectID> {
+    with_proposal!(&self.proposal, proposal => proposal.voters())
+  }
+
+  /// Returns the epoch this proposal expires after, if any.
+  pub fn expiration_epoch(&self) -> Option<u64> {
+    with_proposal!(&self.proposal, proposal => proposal.expiration_epoch())
+  }
+
+  /// Returns `true` if this proposal is expired at epoch `current_epoch`.
+  pub fn is_expired(&self, current_epoch: u64) -> bool {
+    with_proposal!(&self.proposal, proposal => proposal.is_expired(current_epoch))
+  }
+
+  /// Returns the votes required to execute this proposal.
+  pub fn threshold(&self) -> u64 {
+    self.threshold
+  }
+
+  /// Returns `true` if this proposal received enough votes to be executed.
+  pub fn is_approved(&self) -> bool {
+    self.votes() >= self.threshold
+  }
+
+  /// Returns a reference to the typed proposal.
+  pub fn proposal(&self) -> &AnyProposal {
+    &self.proposal
+  }
+
+  /// Consumes this [`PendingProposal`], returning the typed proposal.
+  pub fn into_proposal(self) -> AnyProposal {
+    self.proposal
+  }
+}
+
+/// Fetches the proposal with ID `proposal_id`, parsing it according to the type of its action.
+pub(crate) async fn get_pending_proposal(
+  client: &impl CoreClientReadOnly,
+  proposal_id: ObjectID,
+  threshold: u64,
+) -> Result<PendingProposal, Error> {
+  let data = client
+    .client_adapter()
+    .read_api()
+    .get_object_with_options(proposal_id, IotaObjectDataOptions::new().with_type().with_content())
+    .await
+    .map_err(|e| Error::RpcError(e.to_string()))?
+    .into_object()
+    .map_err(|e| Error::ObjectLookup(format!("could not get proposal {proposal_id}; {e}")))?;
+  let Some(IotaParsedData::MoveObject(value)) = data.content else {
+    return Err(Error::ObjectLookup(format!(
+      "proposal {proposal_id} has no Move content"
+    )));
+  };
+  let action_type = value
+    .type_
+    .type_params
+    .first()
+    .cloned()
+    .ok_or_else(|| Error::ParsingFailed(format!("object {proposal_id} is not a proposal")))?;
+  let fields = value.fields.to_json_value();
+
+  fn parse<A: DeserializeOwned>(proposal_id: ObjectID, fields: Value) -> Result<Proposal<A>, Error> {
+    serde_json::from_value(fields)
+      .map_err(|e| Error::ParsingFailed(format!("could not parse proposal {proposal_id}; {e}")))
+  }
+
+  let action = match &action_type {
+    TypeTag::Struct(tag) => (tag.module.as_str(), tag.name.as_str()),
+    _ => ("", ""),
+  };
+  let proposal = match action {
+    ("update_value_proposal", "UpdateValue") => AnyProposal::UpdateDidDocument(parse(proposal_id, fields)?),
+    ("config_proposal", "Modify") => AnyProposal::ConfigChange(parse(proposal_id, fields)?),
+    ("transfer_proposal", "Send") => AnyProposal::Send(parse(proposal_id, fields)?),
+    ("upgrade_proposal", "Upgrade") => AnyProposal::Upgrade(parse(proposal_id, fields)?),
+    _ => AnyProposal::Other {
+      proposal: parse(proposal_id, fields)?,
+      action_type,
+    },
+  };
+
+  Ok(PendingProposal { proposal, threshold })
+}
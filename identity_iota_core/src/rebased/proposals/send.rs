@@ -20,6 +20,7 @@ use crate::rebased::iota::move_calls;
 use crate::rebased::migration::ControllerToken;
 use crate::rebased::migration::OnChainIdentity;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 
 use super::CreateProposal;
 use super::ExecuteProposal;
@@ -97,11 +98,13 @@ impl ProposalT for Proposal<SendAction> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
     let package = identity_package_id(client).await?;
     let identity_ref = client
@@ -156,11 +159,13 @@ impl ProposalT for Proposal<SendAction> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
     let proposal_id = self.id();
     let identity_ref = client
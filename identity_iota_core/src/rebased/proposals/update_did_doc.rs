@@ -8,6 +8,7 @@ use std::marker::PhantomData;
 use crate::rebased::iota::move_calls;
 use crate::rebased::migration::ControllerToken;
 use crate::IotaDocument;
+use crate::StateMetadataEncoding;
 use async_trait::async_trait;
 use iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use iota_interaction::types::base_types::ObjectID;
@@ -20,6 +21,7 @@ use serde::Serialize;
 use crate::rebased::migration::OnChainIdentity;
 use crate::rebased::migration::Proposal;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use iota_interaction::MoveType;
 
 use super::CreateProposal;
@@ -43,9 +45,21 @@ impl MoveType for UpdateDidDocument {
 }
 
 impl UpdateDidDocument {
-  /// Creates a new [`UpdateDidDocument`] action.
+  /// Creates a new [`UpdateDidDocument`] action, packing `document` with the default [`StateMetadataEncoding`].
+  ///
+  /// Use [`UpdateDidDocument::new_with_encoding`] to keep the encoding of an existing identity - see
+  /// [`OnChainIdentity::state_metadata_encoding`].
   pub fn new(document: IotaDocument) -> Self {
-    Self(Some(document.pack().expect("a valid IotaDocument is packable")))
+    Self::new_with_encoding(document, StateMetadataEncoding::default())
+  }
+
+  /// Creates a new [`UpdateDidDocument`] action, packing `document` with the given `encoding`.
+  pub fn new_with_encoding(document: IotaDocument, encoding: StateMetadataEncoding) -> Self {
+    Self(Some(
+      document
+        .pack_with_encoding(encoding)
+        .expect("a valid IotaDocument is packable"),
+    ))
   }
 
   /// Creates a new [`UpdateDidDocument`] action to deactivate the DID Document.
@@ -81,14 +95,16 @@ impl ProposalT for Proposal<UpdateDidDocument> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
     if identity.has_deleted_did() {
-      return Err(Error::Identity("cannot update a deleted DID Document".into()));
+      return Err(IdentityError::DeletedDidDocument(identity.id()).into());
     }
 
     let package = identity_package_id(client).await?;
@@ -131,14 +147,16 @@ impl ProposalT for Proposal<UpdateDidDocument> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
     if identity.has_deleted_did() {
-      return Err(Error::Identity("cannot update a deleted DID Document".into()));
+      return Err(IdentityError::DeletedDidDocument(identity.id()).into());
     }
 
     let proposal_id = self.id();
@@ -19,6 +19,7 @@ use serde::Serialize;
 use crate::rebased::migration::OnChainIdentity;
 use crate::rebased::migration::Proposal;
 use crate::rebased::Error;
+use crate::rebased::IdentityError;
 use iota_interaction::MoveType;
 use iota_interaction::OptionalSync;
 
@@ -62,11 +63,13 @@ impl ProposalT for Proposal<Upgrade> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let identity_ref = client
@@ -101,11 +104,13 @@ impl ProposalT for Proposal<Upgrade> {
     C: CoreClientReadOnly + OptionalSync,
   {
     if identity.id() != controller_token.controller_of() {
-      return Err(Error::Identity(format!(
-        "token {} doesn't grant access to identity {}",
-        controller_token.id(),
-        identity.id()
-      )));
+      return Err(
+        IdentityError::TokenMismatch {
+          token: controller_token.id(),
+          identity: identity.id(),
+        }
+        .into(),
+      );
     }
 
     let proposal_id = self.id();
@@ -23,6 +23,9 @@ pub(crate) static PLACEHOLDER_DID: Lazy<CoreDID> = Lazy::new(|| CoreDID::parse("
 /// Magic bytes used to mark DID documents.
 const DID_MARKER: &[u8] = b"DID";
 
+/// Length of the `[marker, version, encoding, data length]` header preceding the encoded document.
+const HEADER_LEN: usize = 7;
+
 /// Intermediate representation of the DID document as it is contained in the identity.
 ///
 /// DID instances in the document are replaced by the `PLACEHOLDER_DID`.
@@ -83,11 +86,7 @@ impl StateMetadataDocument {
   /// Pack a [`StateMetadataDocument`] into bytes, suitable for storing in an identity,
   /// according to the given `encoding`.
   pub fn pack(self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
-    let encoded_message_data: Vec<u8> = match encoding {
-      StateMetadataEncoding::Json => self
-        .to_json_vec()
-        .map_err(|err| Error::SerializationError("failed to serialize document to JSON", Some(err)))?,
-    };
+    let encoded_message_data: Vec<u8> = self.encode(encoding)?;
 
     // Prepend flags and length.
     let encoded_message_data_with_flags =
@@ -95,6 +94,61 @@ impl StateMetadataDocument {
     Ok(encoded_message_data_with_flags)
   }
 
+  /// Returns the number of bytes [`StateMetadataDocument::pack`] produces for the given `encoding`.
+  pub fn packed_len(&self, encoding: StateMetadataEncoding) -> Result<usize> {
+    let data_len: usize = self.encode(encoding)?.len();
+    u16::try_from(data_len).map_err(|_| Error::SerializationError("failed to convert usize to u16", None))?;
+    Ok(HEADER_LEN + data_len)
+  }
+
+  /// Returns the [`StateMetadataEncoding`] yielding the smallest packed document, together with its packed size.
+  ///
+  /// On ties, the encoding listed first in [`StateMetadataEncoding::ALL`] is preferred.
+  pub fn smallest_encoding(&self) -> Result<(StateMetadataEncoding, usize)> {
+    let sizes: Vec<(StateMetadataEncoding, usize)> = StateMetadataEncoding::ALL
+      .into_iter()
+      .map(|encoding| self.packed_len(encoding).map(|len| (encoding, len)))
+      .collect::<Result<_>>()?;
+
+    Ok(
+      sizes
+        .into_iter()
+        .min_by_key(|(_, len)| *len)
+        .expect("at least one encoding is supported"),
+    )
+  }
+
+  fn encode(&self, encoding: StateMetadataEncoding) -> Result<Vec<u8>> {
+    match encoding {
+      StateMetadataEncoding::Json => self
+        .to_json_vec()
+        .map_err(|err| Error::SerializationError("failed to serialize document to JSON", Some(err))),
+      StateMetadataEncoding::Cbor => {
+        let mut buffer: Vec<u8> = Vec::new();
+        ciborium::into_writer(self, &mut buffer)
+          .map_err(|err| Error::CborSerializationError("failed to serialize document to CBOR", err.into()))?;
+        Ok(buffer)
+      }
+    }
+  }
+
+  /// Returns the [`StateMetadataEncoding`] of bytes produced by [`StateMetadataDocument::pack`], without decoding
+  /// the document they contain.
+  pub fn packed_encoding(data: &[u8]) -> Result<StateMetadataEncoding> {
+    if data.get(0..=2) != Some(DID_MARKER) {
+      return Err(Error::InvalidStateMetadata("missing `DID` marker"));
+    }
+    let encoding: u8 = *data
+      .get(4)
+      .ok_or(identity_document::Error::InvalidDocument(
+        "state metadata decoding: expected encoding at offset 4",
+        None,
+      ))
+      .map_err(Error::InvalidDoc)?;
+
+    StateMetadataEncoding::try_from(encoding)
+  }
+
   /// Unpack bytes into a [`StateMetadataDocument`].
   pub fn unpack(data: &[u8]) -> Result<Self> {
     // Check marker.
@@ -163,6 +217,12 @@ impl StateMetadataDocument {
           Some(err),
         )
       }),
+      StateMetadataEncoding::Cbor => ciborium::from_reader(data).map_err(|err| {
+        Error::CborSerializationError(
+          "state metadata decoding: failed to deserialize CBOR document",
+          err.into(),
+        )
+      }),
     }
   }
 }
@@ -428,6 +488,54 @@ mod tests {
     assert_eq!(&packed[7..], expected_payload.as_bytes());
   }
 
+  #[test]
+  fn test_cbor_packing_roundtrip() {
+    let TestSetup { document, did_self, .. } = test_document();
+
+    let state_metadata_doc: StateMetadataDocument = StateMetadataDocument::from(document.clone());
+    let packed: Vec<u8> = state_metadata_doc.clone().pack(StateMetadataEncoding::Cbor).unwrap();
+    assert_eq!(&packed[0..3], DID_MARKER);
+    assert_eq!(packed[4], StateMetadataEncoding::Cbor as u8);
+
+    let unpacked_doc = StateMetadataDocument::unpack(&packed).unwrap();
+    assert_eq!(unpacked_doc, state_metadata_doc);
+    assert_eq!(unpacked_doc.into_iota_document(&did_self).unwrap(), document);
+  }
+
+  #[test]
+  fn test_packed_encoding() {
+    let TestSetup { document, .. } = test_document();
+    let state_metadata_doc: StateMetadataDocument = StateMetadataDocument::from(document);
+
+    for encoding in StateMetadataEncoding::ALL {
+      let packed: Vec<u8> = state_metadata_doc.clone().pack(encoding).unwrap();
+      assert_eq!(StateMetadataDocument::packed_encoding(&packed).unwrap(), encoding);
+    }
+    assert!(StateMetadataDocument::packed_encoding(&[]).is_err());
+    assert!(StateMetadataDocument::packed_encoding(b"DID\x01").is_err());
+  }
+
+  #[test]
+  fn test_packed_len() {
+    let TestSetup { document, .. } = test_document();
+    let state_metadata_doc: StateMetadataDocument = StateMetadataDocument::from(document);
+
+    let json_len: usize = state_metadata_doc.packed_len(StateMetadataEncoding::Json).unwrap();
+    let cbor_len: usize = state_metadata_doc.packed_len(StateMetadataEncoding::Cbor).unwrap();
+    for (encoding, len) in [
+      (StateMetadataEncoding::Json, json_len),
+      (StateMetadataEncoding::Cbor, cbor_len),
+    ] {
+      assert_eq!(state_metadata_doc.clone().pack(encoding).unwrap().len(), len);
+    }
+
+    assert!(cbor_len < json_len);
+    assert_eq!(
+      state_metadata_doc.smallest_encoding().unwrap(),
+      (StateMetadataEncoding::Cbor, cbor_len)
+    );
+  }
+
   #[test]
   fn test_no_controller() {
     let TestSetup {
@@ -12,6 +12,14 @@ pub enum StateMetadataEncoding {
   /// State Metadata encoded as JSON.
   #[default]
   Json = 0,
+  /// State Metadata encoded as CBOR ([RFC 8949](https://www.rfc-editor.org/rfc/rfc8949)), which is usually more
+  /// compact than JSON.
+  Cbor = 1,
+}
+
+impl StateMetadataEncoding {
+  /// All supported encodings.
+  pub const ALL: [Self; 2] = [Self::Json, Self::Cbor];
 }
 
 impl TryFrom<u8> for StateMetadataEncoding {
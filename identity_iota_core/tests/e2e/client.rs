@@ -3,6 +3,11 @@
 
 use crate::common::get_funded_test_client;
 use crate::common::TestClient;
+use crate::common::TEST_GAS_BUDGET;
+use identity_iota_core::rebased::client::sign_transaction_bytes;
+use identity_iota_core::rebased::client::TransactionBuilderOfflineExt as _;
+use identity_iota_core::rebased::compose::ComposedTransactionError;
+use identity_iota_core::rebased::compose::TransactionComposeExt as _;
 use identity_iota_core::rebased::migration;
 use identity_iota_core::IotaDocument;
 
@@ -11,7 +16,10 @@ use identity_storage::JwkDocumentExt;
 use identity_storage::JwkMemStore;
 use identity_verification::MethodScope;
 use iota_sdk::types::crypto::SignatureScheme;
+use iota_sdk::types::transaction::TransactionDataAPI as _;
 use product_common::core_client::CoreClient;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer as _;
 
 #[tokio::test]
 async fn can_create_an_identity() -> anyhow::Result<()> {
@@ -31,6 +39,66 @@ async fn can_create_an_identity() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn can_create_an_identity_offline() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+  let public_key = identity_client.signer().public_key().await?;
+
+  let offline_tx = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_offline(&identity_client, public_key)
+    .await?;
+  let tx_data = offline_tx.tx_data();
+  assert_eq!(tx_data.sender(), identity_client.sender_address());
+  assert_eq!(tx_data.gas_owner(), identity_client.sender_address());
+  assert!(!tx_data.gas().is_empty());
+
+  // The transaction travels to the signer as bytes, and its signature back.
+  let signature = sign_transaction_bytes(&offline_tx.tx_bytes()?, identity_client.signer()).await?;
+  let identity = offline_tx.execute_signed(&identity_client, &signature).await?;
+
+  let did = identity.did_document().id();
+  assert_eq!(did.network_str(), identity_client.network().as_ref());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn composed_transactions_are_executed_together() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let create_identity = || {
+    identity_client
+      .create_identity(IotaDocument::new(identity_client.network()))
+      .finish()
+      .into_inner()
+  };
+  let create_asset = identity_client
+    .create_authenticated_asset::<u64>(42)
+    .finish(&identity_client)
+    .into_inner();
+
+  let (identity, asset) = TransactionBuilder::new(create_identity().compose(create_asset)?)
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  assert_eq!(
+    identity.did_document().id().network_str(),
+    identity_client.network().as_ref()
+  );
+  assert_eq!(asset.content(), &42);
+
+  // Both identities would be parsed out of the same effects.
+  let result = create_identity().compose(create_identity());
+  assert!(matches!(result, Err(ComposedTransactionError::SameKind(_))));
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn can_update_a_did_document() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
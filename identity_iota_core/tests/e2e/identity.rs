@@ -6,12 +6,16 @@ use crate::common::get_key_data;
 use crate::common::TestClient;
 use crate::common::TEST_COIN_TYPE;
 use crate::common::TEST_GAS_BUDGET;
+use identity_core::common::Timestamp;
 use identity_iota_core::rebased::client::get_object_id_from_did;
 use identity_iota_core::rebased::migration::get_identity;
 use identity_iota_core::rebased::migration::has_previous_version;
 use identity_iota_core::rebased::migration::ControllerToken;
+use identity_iota_core::rebased::migration::DelegatePermissions;
 use identity_iota_core::rebased::migration::DelegationToken;
+use identity_iota_core::rebased::migration::DidMetadata;
 use identity_iota_core::rebased::migration::Identity;
+use identity_iota_core::rebased::proposals::AnyProposal;
 use identity_iota_core::rebased::proposals::ProposalResult;
 use identity_iota_core::IotaDID;
 use identity_iota_core::IotaDocument;
@@ -31,6 +35,34 @@ use product_common::core_client::CoreClient;
 use product_common::core_client::CoreClientReadOnly;
 use secret_storage::Signer as _;
 
+#[tokio::test]
+async fn did_metadata_credential_reflects_identity() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+
+  let credential = identity.did_metadata_credential()?.into_credential();
+  assert_eq!(
+    credential.issuer.url().to_string(),
+    identity.did_document().id().to_string()
+  );
+
+  let metadata = DidMetadata::from_credential(&credential)?;
+  assert_eq!(metadata, identity.did_metadata());
+  assert_eq!(metadata.object_id, identity.id());
+  assert_eq!(metadata.controllers.len(), 1);
+  assert_eq!(metadata.threshold, 1);
+  assert!(!metadata.deactivated);
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn identity_deactivation_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -159,6 +191,59 @@ async fn approving_proposal_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn listing_proposals_works() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+  let bob_client = test_client.new_user_client().await?;
+
+  let mut identity = alice_client
+    .create_identity(IotaDocument::new(alice_client.network()))
+    .controller(alice_client.sender_address(), 1)
+    .controller(bob_client.sender_address(), 1)
+    .threshold(2)
+    .finish()
+    .build_and_execute(&alice_client)
+    .await?
+    .output;
+  let alice_token = identity
+    .get_controller_token(&alice_client)
+    .await?
+    .expect("alice is a controller");
+  identity
+    .update_config(&alice_token)
+    .threshold(1)
+    .finish(&alice_client)
+    .await?
+    .build_and_execute(&alice_client)
+    .await?;
+
+  let proposals = identity.list_proposals(&alice_client).await?;
+  assert_eq!(proposals.len(), 1);
+  let pending = &proposals[0];
+  assert_eq!(pending.votes(), 1);
+  assert_eq!(pending.threshold(), 2);
+  assert!(!pending.is_approved());
+  assert!(pending.voters().contains(&alice_token.controller_id()));
+  let AnyProposal::ConfigChange(mut proposal) = pending.clone().into_proposal() else {
+    anyhow::bail!("expected a config change proposal");
+  };
+  assert_eq!(proposal.action().threshold(), Some(1));
+
+  let bob_token = identity
+    .get_controller_token(&bob_client)
+    .await?
+    .expect("bob is a controller");
+  proposal
+    .approve(&identity, &bob_token)?
+    .build_and_execute(&bob_client)
+    .await?;
+  let proposals = identity.list_proposals(&bob_client).await?;
+  assert!(proposals[0].is_approved());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn adding_controller_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -194,6 +279,128 @@ async fn adding_controller_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn client_controller_management_executes_changes_directly() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+  let bob_client = test_client.new_user_client().await?;
+
+  let identity = alice_client
+    .create_identity(IotaDocument::new(alice_client.network()))
+    .finish()
+    .build_and_execute(&alice_client)
+    .await?
+    .output;
+  let did = identity.did_document().id().clone();
+
+  // Alice alone has enough voting power: every change is executed right away.
+  let ProposalResult::Executed(_) = alice_client
+    .add_controller(&did, bob_client.sender_address(), 1)
+    .await?
+    .build_and_execute(&alice_client)
+    .await?
+    .output
+  else {
+    anyhow::bail!("adding a controller didn't execute right away");
+  };
+  let identity = get_identity(&alice_client, identity.id())
+    .await?
+    .expect("identity exists");
+  let bob_token = identity
+    .get_controller_token(&bob_client)
+    .await?
+    .expect("bob is a controller");
+  assert_eq!(identity.controllers().len(), 2);
+
+  let ProposalResult::Executed(_) = alice_client
+    .remove_controller(&did, bob_token.controller_id())
+    .await?
+    .build_and_execute(&alice_client)
+    .await?
+    .output
+  else {
+    anyhow::bail!("removing a controller didn't execute right away");
+  };
+  let identity = get_identity(&alice_client, identity.id())
+    .await?
+    .expect("identity exists");
+  assert_eq!(identity.controllers().len(), 1);
+  assert!(identity.controller_voting_power(bob_token.controller_id()).is_none());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn client_controller_management_creates_pending_proposals() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+  let bob_client = test_client.new_user_client().await?;
+
+  let identity = alice_client
+    .create_identity(IotaDocument::new(alice_client.network()))
+    .controller(alice_client.sender_address(), 1)
+    .controller(bob_client.sender_address(), 1)
+    .threshold(2)
+    .finish()
+    .build_and_execute(&alice_client)
+    .await?
+    .output;
+  let did = identity.did_document().id().clone();
+
+  // Alice lacks the voting power to change the threshold on her own: the change is left pending.
+  let ProposalResult::Pending(proposal) = alice_client
+    .update_threshold(&did, 1)
+    .await?
+    .build_and_execute(&alice_client)
+    .await?
+    .output
+  else {
+    anyhow::bail!("the threshold update was executed right away");
+  };
+  assert_eq!(proposal.votes(), 1);
+  assert_eq!(proposal.action().threshold(), Some(1));
+
+  let identity = get_identity(&alice_client, identity.id())
+    .await?
+    .expect("identity exists");
+  assert_eq!(identity.threshold(), 2);
+  let proposals = identity.list_proposals(&alice_client).await?;
+  assert_eq!(proposals.len(), 1);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn transferring_control_works() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+  let bob_client = test_client.new_user_client().await?;
+
+  let mut identity = alice_client
+    .create_identity(IotaDocument::new(alice_client.network()))
+    .finish()
+    .build_and_execute(&alice_client)
+    .await?
+    .output;
+  let alice_token = identity
+    .get_controller_token(&alice_client)
+    .await?
+    .expect("alice is a controller");
+
+  let outcome = identity.transfer_control(&alice_client, &bob_client).await?;
+
+  assert_eq!(outcome.previous_controller, alice_token.controller_id());
+  assert_eq!(identity.controllers().len(), 1);
+  assert!(identity.controller_voting_power(alice_token.controller_id()).is_none());
+  let bob_token = identity
+    .get_controller_token(&bob_client)
+    .await?
+    .expect("bob is a controller");
+  assert_eq!(bob_token.id(), outcome.new_controller_token.id());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn can_get_historical_identity_data() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -293,6 +500,58 @@ async fn can_get_historical_identity_data() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn did_document_history_lists_document_versions() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let did = IotaDID::parse(format!("did:iota:{}", identity.id()))?;
+  let original = identity_client.resolve_did_with_metadata(&did).await?;
+
+  let mut updated_did_doc = IotaDocument::new_with_id(did.clone());
+  let (_, key_id, public_key_jwk, _) = get_key_data().await?;
+  updated_did_doc.insert_method(
+    VerificationMethod::new_from_jwk(did.clone(), public_key_jwk, Some(key_id.as_str()))?,
+    MethodScope::VerificationMethod,
+  )?;
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+  identity
+    .update_did_document(updated_did_doc, &token)
+    .finish(&identity_client)
+    .await?
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?;
+
+  let current = identity_client.resolve_did_with_metadata(&did).await?;
+  assert!(current.version > original.version);
+  assert_eq!(current.document.methods(None).len(), 1);
+
+  let history = identity_client.did_document_history(&did).await?;
+  assert_eq!(history.len(), 2);
+  assert_eq!(history[0], current);
+  assert_eq!(history[1].version, original.version);
+  assert!(history[1].document.methods(None).is_empty());
+  assert_eq!(history[1].created(), history[0].created());
+
+  let updated = current.updated().expect("updated is set");
+  assert_eq!(identity_client.resolve_did_at(&did, updated).await?, current);
+  let before_creation = Timestamp::from_unix(original.created().expect("created is set").to_unix() - 1)?;
+  assert!(identity_client.resolve_did_at(&did, before_creation).await.is_err());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn send_proposal_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -620,6 +879,62 @@ async fn controller_delegation_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn delegate_control_works() -> anyhow::Result<()> {
+  let test_client = TestClient::new().await?;
+  let governance_client = test_client.new_user_client().await?;
+  let operational_client = test_client.new_user_client().await?;
+
+  let identity = governance_client
+    .create_identity(IotaDocument::new(test_client.network()))
+    .controller_with_delegation(governance_client.sender_address(), 1)
+    .finish()
+    .build_and_execute(&governance_client)
+    .await?
+    .output;
+  let governance_token = identity
+    .get_controller_token(&governance_client)
+    .await?
+    .expect("is a controller");
+  let governance_cap = governance_token.as_controller().expect("is a controller cap");
+
+  // The operational key has no access to the Identity until the governance key delegates it.
+  assert!(identity.get_controller_token(&operational_client).await?.is_none());
+
+  let delegation_token = identity
+    .delegate_control(
+      governance_cap,
+      operational_client.sender_address(),
+      DelegatePermissions::CREATE_PROPOSAL | DelegatePermissions::EXECUTE_PROPOSAL,
+    )?
+    .build_and_execute(&governance_client)
+    .await?
+    .output;
+  assert!(identity.is_delegation_token_valid(&delegation_token));
+
+  // The delegation token is picked up transparently.
+  let operational_token = identity
+    .get_controller_token(&operational_client)
+    .await?
+    .expect("has a delegation token");
+  assert_eq!(operational_token.id(), delegation_token.id());
+  assert_eq!(operational_token.controller_id(), governance_cap.id());
+
+  identity
+    .revoke_delegation_token(governance_cap, &delegation_token)?
+    .build_and_execute(&governance_client)
+    .await?;
+
+  // Once revoked, the delegation token is ignored.
+  let identity = get_identity(&governance_client, identity.id())
+    .await?
+    .expect("identity exists");
+  assert!(!identity.is_delegation_token_valid(&delegation_token));
+  assert!(identity.get_controller_token(&operational_client).await?.is_none());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn access_sub_identity_works() -> anyhow::Result<()> {
   let client = TestClient::new().await?;
@@ -164,6 +164,21 @@ impl Default for JwsHeader {
   }
 }
 
+/// Returns whether the media types `lhs` and `rhs`, as found in the `typ` and `cty` header parameters, are equal.
+///
+/// As recommended by [RFC 7515](https://www.rfc-editor.org/rfc/rfc7515#section-4.1.9), media types are compared
+/// case-insensitively and with the `application/` prefix being optional.
+pub fn media_type_eq(lhs: &str, rhs: &str) -> bool {
+  fn strip_application_prefix(media_type: &str) -> &str {
+    match media_type.get(..12) {
+      Some(prefix) if prefix.eq_ignore_ascii_case("application/") => &media_type[12..],
+      _ => media_type,
+    }
+  }
+
+  strip_application_prefix(lhs).eq_ignore_ascii_case(strip_application_prefix(rhs))
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -218,4 +233,14 @@ mod tests {
     assert!(!header4.is_disjoint(&header3));
     assert!(header4.is_disjoint(&header2));
   }
+
+  #[test]
+  fn test_media_type_eq() {
+    assert!(media_type_eq("vc+jwt", "vc+jwt"));
+    assert!(media_type_eq("VC+JWT", "vc+jwt"));
+    assert!(media_type_eq("Application/vc+jwt", "vc+jwt"));
+    assert!(media_type_eq("vc+jwt", "application/VC+JWT"));
+    assert!(!media_type_eq("vc+jwt", "dc+sd-jwt"));
+    assert!(!media_type_eq("text/vc+jwt", "vc+jwt"));
+  }
 }
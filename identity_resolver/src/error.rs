@@ -71,4 +71,29 @@ pub enum ErrorCause {
   /// No client attached to the specific network.
   #[error("none of the attached clients support the network {0}")]
   UnsupportedNetwork(String),
+  /// Caused by attempting to resolve a DID at a given point in time, when no handler able to do so is attached to the
+  /// [`Resolver`](crate::resolution::Resolver) for its method.
+  #[error(
+    "did resolution failed: point-in-time resolution of the DID method \"{method}\" is not supported by the resolver"
+  )]
+  UnsupportedHistoricalResolution {
+    /// The method that is unsupported.
+    method: String,
+  },
+  /// Caused by resolving the DID Document of a credential's issuer at issuance, when the verification method that
+  /// signed the credential is no longer part of the issuer's latest DID Document.
+  #[error(
+    "did resolution failed: the method \"{method}\" that signed the credential was removed from the issuer's DID document"
+  )]
+  RemovedIssuerMethod {
+    /// The DID URL of the removed method.
+    method: String,
+  },
+  /// Caused by a failure to extract the issuer, the issuance date or the signing method of a credential.
+  #[error("did resolution failed: could not extract the credential's issuer")]
+  #[non_exhaustive]
+  InvalidCredential {
+    /// The source of the error.
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
 }
@@ -0,0 +1,317 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::FuturesUnordered;
+use futures::TryStreamExt;
+use identity_core::common::Timestamp;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+
+use super::commands::Command;
+use super::commands::SendSyncCommand;
+use super::CachedResolution;
+use super::MemResolutionCache;
+use super::ResolutionCache;
+use super::ResolutionCacheKey;
+use super::Resolver;
+use crate::Result;
+
+/// A [`Resolver`] that memoizes the DID documents it resolves.
+///
+/// The latest state of documents is served from the cache for [`CachingResolver::ttl`] after having been resolved.
+/// Past states of documents, resolved through [`CachingResolver::resolve_at`], never change and are served from the
+/// cache for as long as it keeps them. Failed resolutions are not cached.
+///
+/// Results are stored in a [`ResolutionCache`], by default a [`MemResolutionCache`] holding at most
+/// [`CachingResolver::max_entries`] documents. A cache shared with other resolvers, e.g. an
+/// [`IssuerDocumentCache`](super::IssuerDocumentCache), can be set with [`CachingResolver::cache`].
+///
+/// # Example
+/// ```ignore
+/// let resolver = CachingResolver::new(resolver)
+///   .ttl(Duration::from_secs(60))
+///   .max_entries(1_000);
+/// let issuer_document: CoreDocument = resolver.resolve(&issuer_did).await?;
+/// // The issuer rotated its keys.
+/// resolver.invalidate(&issuer_did);
+/// ```
+pub struct CachingResolver<DOC = CoreDocument, CMD = SendSyncCommand<DOC>>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+{
+  resolver: Resolver<DOC, CMD>,
+  ttl: Duration,
+  cache: Arc<dyn ResolutionCache<DOC>>,
+}
+
+impl<DOC, CMD> CachingResolver<DOC, CMD>
+where
+  DOC: Clone + Send + Sync + 'static,
+  CMD: for<'r> Command<'r, Result<DOC>>,
+{
+  /// The default time documents are cached for: five minutes.
+  pub const DEFAULT_TTL: Duration = Duration::from_secs(5 * 60);
+  /// The default maximum number of cached documents.
+  pub const DEFAULT_MAX_ENTRIES: usize = MemResolutionCache::<DOC>::DEFAULT_MAX_ENTRIES;
+
+  /// Creates a new [`CachingResolver`], with an empty cache, resolving documents through `resolver`.
+  pub fn new(resolver: Resolver<DOC, CMD>) -> Self {
+    Self {
+      resolver,
+      ttl: Self::DEFAULT_TTL,
+      cache: Arc::new(MemResolutionCache::new()),
+    }
+  }
+
+  /// Sets for how long the latest state of resolved documents is cached.
+  #[must_use]
+  pub fn ttl(mut self, ttl: Duration) -> Self {
+    self.ttl = ttl;
+    self
+  }
+
+  /// Replaces the cache with an empty [`MemResolutionCache`] holding at most `max_entries` documents. A value of `0`
+  /// disables caching.
+  #[must_use]
+  pub fn max_entries(mut self, max_entries: usize) -> Self {
+    self.cache = Arc::new(MemResolutionCache::new().max_entries(max_entries));
+    self
+  }
+
+  /// Replaces the cache with `cache`, e.g. to share it with other resolvers.
+  #[must_use]
+  pub fn cache(mut self, cache: Arc<dyn ResolutionCache<DOC>>) -> Self {
+    self.cache = cache;
+    self
+  }
+
+  /// Returns the wrapped resolver.
+  pub fn resolver(&self) -> &Resolver<DOC, CMD> {
+    &self.resolver
+  }
+
+  /// Returns the cache resolution results are stored in.
+  pub fn resolution_cache(&self) -> &Arc<dyn ResolutionCache<DOC>> {
+    &self.cache
+  }
+
+  /// Returns the DID document of `did`, from the cache if possible.
+  ///
+  /// # Errors
+  /// See [`Resolver::resolve`].
+  pub async fn resolve<D: DID>(&self, did: &D) -> Result<DOC> {
+    let key = ResolutionCacheKey::latest(did.as_str());
+    if let Some(document) = self.cached(&key) {
+      return Ok(document);
+    }
+
+    let document = self.resolver.resolve(did).await?;
+    self.insert(key, document.clone());
+
+    Ok(document)
+  }
+
+  /// Returns the DID document of `did` as it was at time `at`, from the cache if possible.
+  ///
+  /// # Errors
+  /// See [`Resolver::resolve_at`].
+  pub async fn resolve_at<D: DID>(&self, did: &D, at: Timestamp) -> Result<DOC> {
+    let key = ResolutionCacheKey::at(did.as_str(), at);
+    if let Some(document) = self.cached(&key) {
+      return Ok(document);
+    }
+
+    let document = self.resolver.resolve_at(did, at).await?;
+    self.insert(key, document.clone());
+
+    Ok(document)
+  }
+
+  /// Concurrently fetches the DID documents of `dids`, from the cache if possible.
+  ///
+  /// # Errors
+  /// See [`Resolver::resolve_multiple`].
+  pub async fn resolve_multiple<D: DID>(&self, dids: &[D]) -> Result<HashMap<D, DOC>> {
+    let dids: HashSet<D> = dids.iter().cloned().collect();
+    dids
+      .into_iter()
+      .map(|did| async move {
+        let document = self.resolve(&did).await?;
+        Ok((did, document))
+      })
+      .collect::<FuturesUnordered<_>>()
+      .try_collect()
+      .await
+  }
+
+  /// Removes the cached documents of `did`, in all their states, so that they are resolved again on next use.
+  pub fn invalidate<D: DID>(&self, did: &D) {
+    self.cache.remove_did(did.as_str());
+  }
+
+  /// Removes all cached documents.
+  pub fn clear(&self) {
+    self.cache.clear();
+  }
+
+  /// Returns the number of cached documents, including expired ones that haven't been evicted yet.
+  pub fn len(&self) -> usize {
+    self.cache.len()
+  }
+
+  /// Returns whether no document is cached.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  fn cached(&self, key: &ResolutionCacheKey) -> Option<DOC> {
+    self
+      .cache
+      .get(key)
+      .filter(|entry| entry.is_fresh(Timestamp::now_utc(), self.ttl))
+      .map(|entry| entry.document)
+  }
+
+  fn insert(&self, key: ResolutionCacheKey, document: DOC) {
+    let resolution = CachedResolution::new(document, &key.version);
+    self.cache.insert(key, resolution);
+  }
+}
+
+impl<DOC, CMD> std::fmt::Debug for CachingResolver<DOC, CMD>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: AsRef<CoreDocument>,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CachingResolver")
+      .field("resolver", &self.resolver)
+      .field("ttl", &self.ttl)
+      .field("cached_entries", &self.cache.len())
+      .finish_non_exhaustive()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+
+  use identity_did::CoreDID;
+  use identity_document::document::DocumentBuilder;
+
+  use super::*;
+
+  fn setup() -> (CachingResolver, Arc<AtomicUsize>) {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut resolver = Resolver::<CoreDocument>::new();
+    let counter = calls.clone();
+    resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+      let counter = counter.clone();
+      async move {
+        counter.fetch_add(1, Ordering::SeqCst);
+        DocumentBuilder::default().id(did).build()
+      }
+    });
+
+    let counter = calls.clone();
+    resolver.attach_history_handler("foo".to_owned(), move |did: CoreDID, _at: Timestamp| {
+      let counter = counter.clone();
+      async move {
+        counter.fetch_add(1, Ordering::SeqCst);
+        DocumentBuilder::default().id(did).build()
+      }
+    });
+
+    (CachingResolver::new(resolver), calls)
+  }
+
+  /// Sets when the latest state of the document of `did` was resolved.
+  fn set_fetched_at(resolver: &CachingResolver, did: &CoreDID, fetched_at: Timestamp) {
+    let key = ResolutionCacheKey::latest(did.as_str());
+    let mut entry = resolver.cache.get(&key).unwrap();
+    entry.fetched_at = fetched_at;
+    resolver.cache.insert(key, entry);
+  }
+
+  #[tokio::test]
+  async fn documents_are_cached_until_invalidated() {
+    let (resolver, calls) = setup();
+    let did = CoreDID::parse("did:foo:123").unwrap();
+
+    resolver.resolve(&did).await.unwrap();
+    resolver.resolve_multiple(&[did.clone(), did.clone()]).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    resolver.invalidate(&did);
+    assert!(resolver.is_empty());
+    resolver.resolve(&did).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+  }
+
+  #[tokio::test]
+  async fn oldest_documents_are_evicted_when_full() {
+    let (resolver, calls) = setup();
+    let resolver = resolver.ttl(Duration::from_secs(3_600)).max_entries(2);
+    let dids: Vec<CoreDID> = (0..3)
+      .map(|i| CoreDID::parse(format!("did:foo:{i}")).unwrap())
+      .collect();
+
+    resolver.resolve(&dids[0]).await.unwrap();
+    resolver.resolve(&dids[1]).await.unwrap();
+    // Make `did:foo:0` the oldest entry.
+    set_fetched_at(
+      &resolver,
+      &dids[0],
+      Timestamp::from_unix(Timestamp::now_utc().to_unix() - 10).unwrap(),
+    );
+
+    resolver.resolve(&dids[2]).await.unwrap();
+    assert_eq!(resolver.len(), 2);
+    resolver.resolve(&dids[1]).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    resolver.resolve(&dids[0]).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+  }
+
+  #[tokio::test]
+  async fn expired_documents_are_resolved_again() {
+    let (resolver, calls) = setup();
+    let resolver = resolver.ttl(Duration::from_secs(60));
+    let did = CoreDID::parse("did:foo:123").unwrap();
+
+    resolver.resolve(&did).await.unwrap();
+    set_fetched_at(&resolver, &did, Timestamp::from_unix(0).unwrap());
+    resolver.resolve(&did).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+  }
+
+  #[tokio::test]
+  async fn past_states_are_cached_forever() {
+    let (resolver, calls) = setup();
+    let resolver = resolver.ttl(Duration::from_secs(60));
+    let did = CoreDID::parse("did:foo:123").unwrap();
+    let at = Timestamp::from_unix(Timestamp::now_utc().to_unix() - 3_600).unwrap();
+
+    resolver.resolve_at(&did, at).await.unwrap();
+    let key = ResolutionCacheKey::at(did.as_str(), at);
+    let mut entry = resolver.cache.get(&key).unwrap();
+    entry.fetched_at = Timestamp::from_unix(0).unwrap();
+    resolver.cache.insert(key, entry);
+
+    resolver.resolve_at(&did, at).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    // The latest state is cached independently.
+    resolver.resolve(&did).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    resolver.invalidate(&did);
+    assert!(resolver.is_empty());
+  }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::future::Future;
+use identity_core::common::Timestamp;
 use identity_did::DID;
 
 use crate::Error;
@@ -16,7 +17,8 @@ use std::pin::Pin;
 pub trait Command<'a, T>: std::fmt::Debug + private::Sealed {
   type Output: Future<Output = T> + 'a;
 
-  fn apply(&self, input: &'a str) -> Self::Output;
+  /// Resolves the DID `input`, in the state it had at time `at` if given.
+  fn apply(&self, input: &'a str, at: Option<Timestamp>) -> Self::Output;
 }
 
 mod private {
@@ -40,8 +42,9 @@ impl<DOC: 'static> std::fmt::Debug for SingleThreadedCommand<DOC> {
 }
 
 /// Internal representation of a thread safe handler.
-type SendSyncCallback<DOC> =
-  Box<dyn for<'r> Fn(&'r str) -> Pin<Box<dyn Future<Output = Result<DOC>> + 'r + Send>> + Send + Sync>;
+type SendSyncCallback<DOC> = Box<
+  dyn for<'r> Fn(&'r str, Option<Timestamp>) -> Pin<Box<dyn Future<Output = Result<DOC>> + 'r + Send>> + Send + Sync,
+>;
 
 /// Wrapper around a thread safe callback.
 pub struct SendSyncCommand<DOC: 'static> {
@@ -50,8 +53,8 @@ pub struct SendSyncCommand<DOC: 'static> {
 
 impl<'a, DOC: 'static> Command<'a, Result<DOC>> for SendSyncCommand<DOC> {
   type Output = Pin<Box<dyn Future<Output = Result<DOC>> + 'a + Send>>;
-  fn apply(&self, input: &'a str) -> Self::Output {
-    (self.fun)(input)
+  fn apply(&self, input: &'a str, at: Option<Timestamp>) -> Self::Output {
+    (self.fun)(input, at)
   }
 }
 
@@ -71,7 +74,33 @@ impl<DOC: 'static> SendSyncCommand<DOC> {
     E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
     DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
   {
-    let fun: SendSyncCallback<DOC> = Box::new(move |input: &str| {
+    Self::from_handler(move |did: D, _at: Option<Timestamp>| handler(did))
+  }
+
+  /// Equivalent to [`SendSyncCommand::new`](SendSyncCommand::new()), for a handler resolving DIDs at a given point
+  /// in time. The current time is used when the command is applied without one.
+  pub(super) fn new_at<D, F, Fut, DOCUMENT, E, DIDERR>(handler: F) -> Self
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Timestamp) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    Self::from_handler(move |did: D, at: Option<Timestamp>| handler(did, at.unwrap_or_else(Timestamp::now_utc)))
+  }
+
+  fn from_handler<D, F, Fut, DOCUMENT, E, DIDERR>(handler: F) -> Self
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Option<Timestamp>) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let fun: SendSyncCallback<DOC> = Box::new(move |input: &str, at: Option<Timestamp>| {
       let handler_clone: F = handler.clone();
       let did_parse_attempt = D::try_from(input)
         .map_err(|error| ErrorCause::DIDParsingError { source: error.into() })
@@ -79,7 +108,7 @@ impl<DOC: 'static> SendSyncCommand<DOC> {
 
       Box::pin(async move {
         let did: D = did_parse_attempt?;
-        handler_clone(did)
+        handler_clone(did, at)
           .await
           .map(Into::into)
           .map_err(|error| ErrorCause::HandlerError { source: error.into() })
@@ -97,7 +126,7 @@ impl<DOC: 'static> SendSyncCommand<DOC> {
 
 /// Internal representation of a single threaded handler.
 pub(super) type SingleThreadedCallback<DOC> =
-  Box<dyn for<'r> Fn(&'r str) -> Pin<Box<dyn Future<Output = Result<DOC>> + 'r>>>;
+  Box<dyn for<'r> Fn(&'r str, Option<Timestamp>) -> Pin<Box<dyn Future<Output = Result<DOC>> + 'r>>>;
 
 /// Wrapper around a single threaded callback.
 pub struct SingleThreadedCommand<DOC> {
@@ -105,8 +134,8 @@ pub struct SingleThreadedCommand<DOC> {
 }
 impl<'a, DOC: 'static> Command<'a, Result<DOC>> for SingleThreadedCommand<DOC> {
   type Output = Pin<Box<dyn Future<Output = Result<DOC>> + 'a>>;
-  fn apply(&self, input: &'a str) -> Self::Output {
-    (self.fun)(input)
+  fn apply(&self, input: &'a str, at: Option<Timestamp>) -> Self::Output {
+    (self.fun)(input, at)
   }
 }
 
@@ -121,7 +150,32 @@ impl<DOC: 'static> SingleThreadedCommand<DOC> {
     E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
     DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
   {
-    let fun: SingleThreadedCallback<DOC> = Box::new(move |input: &str| {
+    Self::from_handler(move |did: D, _at: Option<Timestamp>| handler(did))
+  }
+
+  /// Equivalent to [`SendSyncCommand::new_at`](SendSyncCommand::new_at()), but with less `Send` + `Sync` bounds.
+  pub(super) fn new_at<D, F, Fut, DOCUMENT, E, DIDERR>(handler: F) -> Self
+  where
+    D: DID + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Timestamp) -> Fut + 'static + Clone,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    Self::from_handler(move |did: D, at: Option<Timestamp>| handler(did, at.unwrap_or_else(Timestamp::now_utc)))
+  }
+
+  fn from_handler<D, F, Fut, DOCUMENT, E, DIDERR>(handler: F) -> Self
+  where
+    D: DID + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Option<Timestamp>) -> Fut + 'static + Clone,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let fun: SingleThreadedCallback<DOC> = Box::new(move |input: &str, at: Option<Timestamp>| {
       let handler_clone: F = handler.clone();
       let did_parse_attempt = D::try_from(input)
         .map_err(|error| ErrorCause::DIDParsingError { source: error.into() })
@@ -129,7 +183,7 @@ impl<DOC: 'static> SingleThreadedCommand<DOC> {
 
       Box::pin(async move {
         let did: D = did_parse_attempt?;
-        handler_clone(did)
+        handler_clone(did, at)
           .await
           .map(Into::into)
           .map_err(|error| ErrorCause::HandlerError { source: error.into() })
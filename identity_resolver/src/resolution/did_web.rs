@@ -0,0 +1,358 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use percent_encoding::percent_decode_str;
+
+use super::commands::SendSyncCommand;
+use super::commands::SingleThreadedCommand;
+use super::CachedResolution;
+use super::DocumentVersion;
+use super::MemResolutionCache;
+use super::ResolutionCache;
+use super::ResolutionCacheKey;
+use super::Resolver;
+
+/// The DID method name of `did:web`.
+pub const DID_WEB_METHOD: &str = "web";
+/// The maximum size of a fetched `did:web` document.
+const MAX_DOCUMENT_SIZE: usize = 1_048_576;
+
+/// Resolves [`did:web`](https://w3c-ccg.github.io/did-method-web/) DIDs by fetching their documents over HTTPS,
+/// e.g. `did:web:example.com:user:alice` from `https://example.com/user/alice/did.json`.
+///
+/// Resolved documents can be cached for a configurable amount of time - see [`WebDidHandler::cache_ttl`]. They are
+/// stored in a [`ResolutionCache`], by default a [`MemResolutionCache`] holding at most
+/// [`MemResolutionCache::DEFAULT_MAX_ENTRIES`] documents, which clones of a handler share.
+///
+/// # Example
+/// ```ignore
+/// let mut resolver = Resolver::<CoreDocument>::new();
+/// resolver.attach_web_handler(WebDidHandler::new().cache_ttl(Duration::from_secs(5 * 60)));
+/// ```
+#[derive(Clone)]
+pub struct WebDidHandler {
+  client: reqwest::Client,
+  cache_ttl: Option<Duration>,
+  cache: Arc<dyn ResolutionCache<CoreDocument>>,
+}
+
+impl WebDidHandler {
+  /// Creates a new [`WebDidHandler`], with caching disabled.
+  pub fn new() -> Self {
+    Self {
+      client: reqwest::Client::default(),
+      cache_ttl: None,
+      cache: Arc::new(MemResolutionCache::new()),
+    }
+  }
+
+  /// Sets the HTTP client used to fetch documents, e.g. to configure timeouts or proxies.
+  #[must_use]
+  pub fn client(mut self, client: reqwest::Client) -> Self {
+    self.client = client;
+    self
+  }
+
+  /// Caches resolved documents for `ttl`.
+  #[must_use]
+  pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+    self.cache_ttl = Some(ttl);
+    self
+  }
+
+  /// Replaces the cache with an empty [`MemResolutionCache`] holding at most `max_entries` documents. A value of `0`
+  /// disables caching.
+  #[must_use]
+  pub fn max_entries(mut self, max_entries: usize) -> Self {
+    self.cache = Arc::new(MemResolutionCache::new().max_entries(max_entries));
+    self
+  }
+
+  /// Replaces the cache with `cache`, e.g. to share it with other resolvers.
+  #[must_use]
+  pub fn cache(mut self, cache: Arc<dyn ResolutionCache<CoreDocument>>) -> Self {
+    self.cache = cache;
+    self
+  }
+
+  /// Removes all cached `did:web` documents.
+  pub fn clear_cache(&self) {
+    let prefix = format!("did:{DID_WEB_METHOD}:");
+    for key in self.cache.keys() {
+      if key.did.starts_with(&prefix) {
+        self.cache.remove(&key);
+      }
+    }
+  }
+
+  /// Resolves `did`, returning a cached document if a fresh one is available.
+  ///
+  /// # Errors
+  /// Fails if `did` is not a valid `did:web` DID, if its document cannot be fetched, or if the fetched document is
+  /// not the document of `did`.
+  pub async fn resolve(&self, did: &CoreDID) -> Result<CoreDocument, WebDidError> {
+    if let Some(document) = self.cached(did) {
+      return Ok(document);
+    }
+
+    let url = did_web_url(did)?;
+    let response = self
+      .client
+      .get(url.to_string())
+      .header(reqwest::header::ACCEPT, "application/did+json, application/json")
+      .send()
+      .await
+      .map_err(WebDidError::Request)?;
+    let status = response.status();
+    if !status.is_success() {
+      return Err(WebDidError::UnexpectedStatus(status.as_u16()));
+    }
+
+    // We use a stream so we can limit the size of the response.
+    let mut stream = response.bytes_stream();
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(item) = stream.next().await {
+      body.extend(item.map_err(WebDidError::Request)?);
+      if body.len() > MAX_DOCUMENT_SIZE {
+        return Err(WebDidError::ResponseTooLarge);
+      }
+    }
+
+    let document: CoreDocument =
+      serde_json::from_slice(&body).map_err(|err| WebDidError::InvalidDocument(Box::new(err)))?;
+    if document.id() != did {
+      return Err(WebDidError::DidMismatch {
+        expected: did.to_string(),
+        actual: document.id().to_string(),
+      });
+    }
+
+    if self.cache_ttl.is_some() {
+      self.cache.insert(
+        ResolutionCacheKey::latest(did.as_str()),
+        CachedResolution::new(document.clone(), &DocumentVersion::Latest),
+      );
+    }
+
+    Ok(document)
+  }
+
+  fn cached(&self, did: &CoreDID) -> Option<CoreDocument> {
+    let ttl = self.cache_ttl?;
+    let key = ResolutionCacheKey::latest(did.as_str());
+    let cached = self.cache.get(&key)?;
+    if cached.is_fresh(Timestamp::now_utc(), ttl) {
+      Some(cached.document)
+    } else {
+      self.cache.remove(&key);
+      None
+    }
+  }
+}
+
+impl Default for WebDidHandler {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl std::fmt::Debug for WebDidHandler {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("WebDidHandler")
+      .field("cache_ttl", &self.cache_ttl)
+      .finish()
+  }
+}
+
+/// Returns the HTTPS URL the document of the `did:web` DID `did` is published at.
+///
+/// # Errors
+/// Fails if `did` is not a valid `did:web` DID.
+pub fn did_web_url(did: &CoreDID) -> Result<Url, WebDidError> {
+  let invalid = || WebDidError::InvalidDid(did.to_string());
+  if did.method() != DID_WEB_METHOD {
+    return Err(invalid());
+  }
+
+  // Colons separate the path segments, hence a port - like any other character - is percent-encoded.
+  let decode = |segment: &str| -> Result<String, WebDidError> {
+    percent_decode_str(segment)
+      .decode_utf8()
+      .map(|segment| segment.into_owned())
+      .map_err(|_| invalid())
+  };
+  let mut segments = did.method_id().split(':');
+  let domain = decode(
+    segments
+      .next()
+      .filter(|domain| !domain.is_empty())
+      .ok_or_else(invalid)?,
+  )?;
+  let path: Vec<String> = segments.map(decode).collect::<Result<_, _>>()?;
+  if domain.contains(['/', '\\', '?', '#', '@'])
+    || path
+      .iter()
+      .any(|segment| segment.is_empty() || segment == "." || segment == "..")
+  {
+    return Err(invalid());
+  }
+
+  let mut url = Url::parse(format!("https://{domain}/")).map_err(|_| invalid())?;
+  {
+    // Pushed segments are percent-encoded again, as needed.
+    let mut url_path = url.path_segments_mut().map_err(|_| invalid())?;
+    url_path.clear();
+    if path.is_empty() {
+      url_path.push(".well-known");
+    } else {
+      url_path.extend(&path);
+    }
+    url_path.push("did.json");
+  }
+
+  Ok(url)
+}
+
+impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SendSyncCommand<DOC>> {
+  /// Attaches a handler resolving `did:web` DIDs through `handler`.
+  ///
+  /// See also [`attach_handler`](Self::attach_handler).
+  pub fn attach_web_handler(&mut self, handler: WebDidHandler) {
+    let handler = move |did: CoreDID| {
+      let handler = handler.clone();
+      async move { handler.resolve(&did).await }
+    };
+    self.attach_handler(DID_WEB_METHOD.to_owned(), handler)
+  }
+}
+
+impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
+  /// Attaches a handler resolving `did:web` DIDs through `handler`.
+  ///
+  /// See also [`attach_handler`](Self::attach_handler).
+  pub fn attach_web_handler(&mut self, handler: WebDidHandler) {
+    let handler = move |did: CoreDID| {
+      let handler = handler.clone();
+      async move { handler.resolve(&did).await }
+    };
+    self.attach_handler(DID_WEB_METHOD.to_owned(), handler)
+  }
+}
+
+/// Errors that can occur when resolving a `did:web` DID through a [`WebDidHandler`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum WebDidError {
+  /// The DID is not a valid `did:web` DID.
+  #[error("\"{0}\" is not a valid did:web DID")]
+  InvalidDid(String),
+  /// The request for the DID document failed.
+  #[error("request for the did:web document failed")]
+  Request(#[source] reqwest::Error),
+  /// The web server answered with an unexpected HTTP status.
+  #[error("the web server answered with HTTP status {0}")]
+  UnexpectedStatus(u16),
+  /// The fetched document exceeds the maximum allowed size.
+  #[error("the did:web document exceeds 1 MiB")]
+  ResponseTooLarge,
+  /// The fetched document is not a valid DID document.
+  #[error("invalid did:web document")]
+  InvalidDocument(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The fetched document is the document of a different DID.
+  #[error("expected the DID document of \"{expected}\", but got the one of \"{actual}\"")]
+  DidMismatch {
+    /// The requested DID.
+    expected: String,
+    /// The DID of the fetched document.
+    actual: String,
+  },
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn url_of(did: &str) -> Result<String, WebDidError> {
+    did_web_url(&CoreDID::parse(did).unwrap()).map(|url| url.to_string())
+  }
+
+  #[test]
+  fn did_web_urls() {
+    assert_eq!(
+      url_of("did:web:w3c-ccg.github.io").unwrap(),
+      "https://w3c-ccg.github.io/.well-known/did.json"
+    );
+    assert_eq!(
+      url_of("did:web:w3c-ccg.github.io:user:alice").unwrap(),
+      "https://w3c-ccg.github.io/user/alice/did.json"
+    );
+    assert_eq!(
+      url_of("did:web:example.com%3A3000:user:alice").unwrap(),
+      "https://example.com:3000/user/alice/did.json"
+    );
+    assert_eq!(
+      url_of("did:web:example.com%3a3000").unwrap(),
+      "https://example.com:3000/.well-known/did.json"
+    );
+    assert_eq!(
+      url_of("did:web:example.com:user%20name:a%3Ab").unwrap(),
+      "https://example.com/user%20name/a:b/did.json"
+    );
+  }
+
+  #[test]
+  fn invalid_did_web_dids() {
+    assert!(matches!(url_of("did:example:123"), Err(WebDidError::InvalidDid(_))));
+    assert!(matches!(
+      url_of("did:web:example.com::alice"),
+      Err(WebDidError::InvalidDid(_))
+    ));
+    assert!(matches!(
+      url_of("did:web:example.com:%2E%2E:alice"),
+      Err(WebDidError::InvalidDid(_))
+    ));
+    assert!(matches!(
+      url_of("did:web:example.com%2Fuser"),
+      Err(WebDidError::InvalidDid(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn fresh_documents_are_served_from_cache() {
+    let did = CoreDID::parse("did:web:unreachable.invalid").unwrap();
+    let document = CoreDocument::builder(Default::default())
+      .id(did.clone())
+      .build()
+      .unwrap();
+    let cache: Arc<MemResolutionCache<CoreDocument>> = Arc::new(MemResolutionCache::new().max_entries(1));
+    let handler = WebDidHandler::new()
+      .cache_ttl(Duration::from_secs(5 * 60))
+      .cache(cache.clone());
+    cache.insert(
+      ResolutionCacheKey::latest(did.as_str()),
+      CachedResolution::new(document.clone(), &DocumentVersion::Latest),
+    );
+
+    assert_eq!(handler.clone().resolve(&did).await.unwrap(), document);
+
+    // The cache is bounded.
+    cache.insert(
+      ResolutionCacheKey::latest("did:web:other.invalid"),
+      CachedResolution::new(document.clone(), &DocumentVersion::Latest),
+    );
+    assert_eq!(cache.len(), 1);
+    assert!(handler.cached(&did).is_none());
+
+    handler.clear_cache();
+    assert!(cache.is_empty());
+  }
+}
@@ -0,0 +1,322 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use serde_json::Value;
+
+use super::commands::SendSyncCommand;
+use super::commands::SingleThreadedCommand;
+use super::Resolver;
+
+/// The maximum size of a resolution result fetched from a gateway.
+const MAX_RESPONSE_SIZE: usize = 1_048_576;
+/// The media type of a DID resolution result.
+const RESOLUTION_RESULT_MEDIA_TYPE: &str = r#"application/ld+json;profile="https://w3id.org/did-resolution""#;
+
+/// Verifies a DID document returned by a DID resolution gateway before it is handed out by the [`Resolver`].
+#[async_trait]
+pub trait GatewayResultVerifier: Send + Sync {
+  /// Checks that `document` is the genuine DID document of `did`.
+  async fn verify(
+    &self,
+    did: &CoreDID,
+    document: &CoreDocument,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Resolves DIDs through a remote DID resolution gateway exposing the
+/// [Universal Resolver](https://github.com/decentralized-identity/universal-resolver) HTTP interface,
+/// i.e. `GET <endpoint>/1.0/identifiers/<did>`.
+///
+/// This is useful in environments that cannot connect to the DID method's nodes directly. Since the gateway
+/// is trusted to return the right document, results can be checked independently by configuring a
+/// [`GatewayResultVerifier`] - see [`HttpGatewayHandler::verifier`].
+///
+/// # Example
+/// ```ignore
+/// let gateway = HttpGatewayHandler::new(Url::parse("https://dev.uniresolver.io")?)
+///   .verifier(IotaCrossCheckVerifier::new(light_client));
+///
+/// let mut resolver = Resolver::<CoreDocument>::new();
+/// resolver.attach_http_gateway_handler("iota".to_owned(), gateway);
+/// ```
+#[derive(Clone)]
+pub struct HttpGatewayHandler {
+  client: reqwest::Client,
+  endpoint: Url,
+  verifier: Option<Arc<dyn GatewayResultVerifier>>,
+}
+
+impl HttpGatewayHandler {
+  /// Creates a new [`HttpGatewayHandler`] for the gateway reachable at `endpoint`, e.g. `https://dev.uniresolver.io`.
+  pub fn new(endpoint: Url) -> Self {
+    Self {
+      client: reqwest::Client::new(),
+      endpoint,
+      verifier: None,
+    }
+  }
+
+  /// Sets the HTTP client used to reach the gateway, e.g. to configure timeouts or proxies.
+  #[must_use]
+  pub fn client(mut self, client: reqwest::Client) -> Self {
+    self.client = client;
+    self
+  }
+
+  /// Sets the [`GatewayResultVerifier`] every resolved document is checked against.
+  #[must_use]
+  pub fn verifier(mut self, verifier: impl GatewayResultVerifier + 'static) -> Self {
+    self.verifier = Some(Arc::new(verifier));
+    self
+  }
+
+  /// Returns the endpoint of the gateway.
+  pub fn endpoint(&self) -> &Url {
+    &self.endpoint
+  }
+
+  /// Resolves `did` through the gateway.
+  ///
+  /// # Errors
+  /// Fails if the gateway cannot be reached or reports an error, if the returned document is not the document
+  /// of `did`, or if the configured [`GatewayResultVerifier`] rejects it.
+  pub async fn resolve(&self, did: &CoreDID) -> Result<CoreDocument, HttpGatewayError> {
+    let url = self
+      .endpoint
+      .join(format!("1.0/identifiers/{did}"))
+      .map_err(|err| HttpGatewayError::InvalidEndpoint(Box::new(err)))?;
+
+    let response = self
+      .client
+      .get(url.to_string())
+      .header(reqwest::header::ACCEPT, RESOLUTION_RESULT_MEDIA_TYPE)
+      .send()
+      .await
+      .map_err(HttpGatewayError::Request)?;
+    let status = response.status();
+
+    // We use a stream so we can limit the size of the response.
+    let mut stream = response.bytes_stream();
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(item) = stream.next().await {
+      body.extend(item.map_err(HttpGatewayError::Request)?);
+      if body.len() > MAX_RESPONSE_SIZE {
+        return Err(HttpGatewayError::ResponseTooLarge);
+      }
+    }
+
+    let document = parse_resolution_result(&body, status)?;
+    if document.id() != did {
+      return Err(HttpGatewayError::DidMismatch {
+        expected: did.to_string(),
+        actual: document.id().to_string(),
+      });
+    }
+    if let Some(verifier) = &self.verifier {
+      verifier
+        .verify(did, &document)
+        .await
+        .map_err(HttpGatewayError::VerificationFailed)?;
+    }
+
+    Ok(document)
+  }
+}
+
+impl std::fmt::Debug for HttpGatewayHandler {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("HttpGatewayHandler")
+      .field("endpoint", &self.endpoint)
+      .field("verifier", &self.verifier.is_some())
+      .finish()
+  }
+}
+
+/// Extracts the DID document from a gateway's response, which is either a DID resolution result or a bare document.
+fn parse_resolution_result(body: &[u8], status: reqwest::StatusCode) -> Result<CoreDocument, HttpGatewayError> {
+  let mut json: Value = match serde_json::from_slice(body) {
+    Ok(json) => json,
+    Err(_) if !status.is_success() => return Err(HttpGatewayError::UnexpectedStatus(status.as_u16())),
+    Err(err) => return Err(HttpGatewayError::InvalidResponse(Box::new(err))),
+  };
+
+  if let Some(metadata) = json.get("didResolutionMetadata") {
+    if let Some(error) = metadata.get("error").and_then(Value::as_str) {
+      return Err(HttpGatewayError::ResolutionFailed {
+        error: error.to_owned(),
+        message: metadata
+          .get("errorMessage")
+          .and_then(Value::as_str)
+          .map(ToOwned::to_owned),
+      });
+    }
+  }
+  if !status.is_success() {
+    return Err(HttpGatewayError::UnexpectedStatus(status.as_u16()));
+  }
+
+  let document = match json.get_mut("didDocument") {
+    Some(document) => document.take(),
+    None => json,
+  };
+  serde_json::from_value(document).map_err(|err| HttpGatewayError::InvalidResponse(Box::new(err)))
+}
+
+impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SendSyncCommand<DOC>> {
+  /// Attaches a handler resolving DIDs of `method` through the DID resolution gateway `gateway`.
+  ///
+  /// See also [`attach_handler`](Self::attach_handler).
+  pub fn attach_http_gateway_handler(&mut self, method: String, gateway: HttpGatewayHandler) {
+    let handler = move |did: CoreDID| {
+      let gateway = gateway.clone();
+      async move { gateway.resolve(&did).await }
+    };
+    self.attach_handler(method, handler)
+  }
+}
+
+impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
+  /// Attaches a handler resolving DIDs of `method` through the DID resolution gateway `gateway`.
+  ///
+  /// See also [`attach_handler`](Self::attach_handler).
+  pub fn attach_http_gateway_handler(&mut self, method: String, gateway: HttpGatewayHandler) {
+    let handler = move |did: CoreDID| {
+      let gateway = gateway.clone();
+      async move { gateway.resolve(&did).await }
+    };
+    self.attach_handler(method, handler)
+  }
+}
+
+/// Errors that can occur when resolving a DID through an [`HttpGatewayHandler`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum HttpGatewayError {
+  /// The resolution URL cannot be derived from the gateway's endpoint.
+  #[error("invalid gateway endpoint")]
+  InvalidEndpoint(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The request to the gateway failed.
+  #[error("request to the DID resolution gateway failed")]
+  Request(#[source] reqwest::Error),
+  /// The gateway answered with an unexpected HTTP status.
+  #[error("the DID resolution gateway answered with HTTP status {0}")]
+  UnexpectedStatus(u16),
+  /// The gateway's response exceeds the maximum allowed size.
+  #[error("the DID resolution gateway's response exceeds 1 MiB")]
+  ResponseTooLarge,
+  /// The gateway's response is not a valid DID resolution result.
+  #[error("invalid DID resolution result")]
+  InvalidResponse(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The gateway reported a resolution error.
+  #[error("the DID resolution gateway reported error \"{error}\"")]
+  ResolutionFailed {
+    /// The error code, e.g. `notFound`.
+    error: String,
+    /// The error message, if any.
+    message: Option<String>,
+  },
+  /// The gateway returned the document of a different DID.
+  #[error("expected the DID document of \"{expected}\", but got the one of \"{actual}\"")]
+  DidMismatch {
+    /// The requested DID.
+    expected: String,
+    /// The DID of the returned document.
+    actual: String,
+  },
+  /// The configured [`GatewayResultVerifier`] rejected the returned document.
+  #[error("the resolved DID document failed verification")]
+  VerificationFailed(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
+#[cfg(all(feature = "iota", not(target_arch = "wasm32")))]
+mod iota_verifier {
+  use identity_did::DID;
+  use identity_iota_core::DidResolutionHandler;
+  use identity_iota_core::IotaDID;
+
+  use super::*;
+
+  /// A [`GatewayResultVerifier`] that checks `did:iota` documents returned by a gateway against the document
+  /// resolved through a [`DidResolutionHandler`] - e.g. one backed by a light client or a trusted node.
+  #[derive(Debug)]
+  pub struct IotaCrossCheckVerifier<CLI> {
+    client: CLI,
+  }
+
+  impl<CLI> IotaCrossCheckVerifier<CLI> {
+    /// Creates a new [`IotaCrossCheckVerifier`] resolving documents through `client`.
+    pub fn new(client: CLI) -> Self {
+      Self { client }
+    }
+  }
+
+  #[async_trait]
+  impl<CLI> GatewayResultVerifier for IotaCrossCheckVerifier<CLI>
+  where
+    CLI: DidResolutionHandler + Send + Sync,
+  {
+    async fn verify(
+      &self,
+      did: &CoreDID,
+      document: &CoreDocument,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+      let did = IotaDID::try_from(did.as_str())?;
+      let expected = self.client.resolve_did(&did).await?;
+      if expected.as_ref() != document {
+        return Err("the DID document returned by the gateway differs from the one on the ledger".into());
+      }
+
+      Ok(())
+    }
+  }
+}
+
+#[cfg(all(feature = "iota", not(target_arch = "wasm32")))]
+pub use iota_verifier::IotaCrossCheckVerifier;
+
+#[cfg(test)]
+mod tests {
+  use reqwest::StatusCode;
+  use serde_json::json;
+
+  use super::*;
+
+  fn document() -> Value {
+    json!({ "id": "did:example:123", "verificationMethod": [] })
+  }
+
+  #[test]
+  fn parses_resolution_results_and_bare_documents() {
+    let result = json!({
+      "didDocument": document(),
+      "didResolutionMetadata": { "contentType": "application/did+ld+json" },
+      "didDocumentMetadata": {}
+    });
+    let parsed = parse_resolution_result(&serde_json::to_vec(&result).unwrap(), StatusCode::OK).unwrap();
+    assert_eq!(parsed.id().as_str(), "did:example:123");
+
+    let parsed = parse_resolution_result(&serde_json::to_vec(&document()).unwrap(), StatusCode::OK).unwrap();
+    assert_eq!(parsed.id().as_str(), "did:example:123");
+  }
+
+  #[test]
+  fn reports_gateway_errors() {
+    let result = json!({
+      "didDocument": null,
+      "didResolutionMetadata": { "error": "notFound", "errorMessage": "DID not found" },
+      "didDocumentMetadata": {}
+    });
+    let error = parse_resolution_result(&serde_json::to_vec(&result).unwrap(), StatusCode::NOT_FOUND).unwrap_err();
+    assert!(matches!(error, HttpGatewayError::ResolutionFailed { error, .. } if error == "notFound"));
+
+    let error = parse_resolution_result(b"Bad Gateway", StatusCode::BAD_GATEWAY).unwrap_err();
+    assert!(matches!(error, HttpGatewayError::UnexpectedStatus(502)));
+  }
+}
@@ -0,0 +1,382 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use identity_core::common::Timestamp;
+use identity_credential::validator::JwtCredentialValidatorUtils;
+use identity_credential::validator::JwtValidationError;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+
+use super::commands::Command;
+use super::commands::SendSyncCommand;
+use super::CachedResolution;
+use super::DocumentVersion;
+use super::MemResolutionCache;
+use super::ResolutionCache;
+use super::ResolutionCacheKey;
+use super::Resolver;
+
+/// Determines for how long documents cached by an [`IssuerDocumentCache`] may be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IssuerDocumentCachePolicy {
+  /// For how long a document is fresh, i.e. is used without being resolved again.
+  pub max_age: Duration,
+  /// For how long after becoming stale a document is still used while waiting to be revalidated,
+  /// see [`IssuerDocumentCache::revalidate`].
+  pub stale_while_revalidate: Duration,
+}
+
+impl IssuerDocumentCachePolicy {
+  /// Creates a new policy keeping documents fresh for `max_age`, without serving stale documents.
+  pub fn new(max_age: Duration) -> Self {
+    Self {
+      max_age,
+      stale_while_revalidate: Duration::ZERO,
+    }
+  }
+
+  /// Sets for how long after becoming stale a document may still be used.
+  #[must_use]
+  pub fn stale_while_revalidate(mut self, duration: Duration) -> Self {
+    self.stale_while_revalidate = duration;
+    self
+  }
+
+  fn freshness<DOC>(&self, entry: &CachedResolution<DOC>, now: Timestamp) -> Freshness {
+    let age = entry.age(now);
+    if entry.immutable || age <= self.max_age {
+      Freshness::Fresh
+    } else if age <= self.max_age.saturating_add(self.stale_while_revalidate) {
+      Freshness::Stale
+    } else {
+      Freshness::Expired
+    }
+  }
+}
+
+impl Default for IssuerDocumentCachePolicy {
+  /// Documents are fresh for five minutes.
+  fn default() -> Self {
+    Self::new(Duration::from_secs(5 * 60))
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Freshness {
+  Fresh,
+  Stale,
+  Expired,
+}
+
+/// Caches the DID documents of credential issuers for validators, resolving them through a [`Resolver`].
+///
+/// Unlike a generic resolution cache, the cache never silently hands out outdated documents: documents are fresh for
+/// [`IssuerDocumentCachePolicy::max_age`], can be used for [`IssuerDocumentCachePolicy::stale_while_revalidate`]
+/// afterwards, and must be resolved again beyond that. If that fails, [`IssuerDocumentCacheError::Stale`] is returned
+/// rather than the outdated document, so that validation fails.
+///
+/// Stale documents are revalidated by [`IssuerDocumentCache::revalidate`], usually called periodically by a
+/// background task, or one by one through [`IssuerDocumentCache::refresh`]. Past states of documents, resolved through
+/// [`IssuerDocumentCache::resolve_at`], never change and never become stale.
+///
+/// Documents are stored in a [`ResolutionCache`], by default a [`MemResolutionCache`]. A cache shared with other
+/// resolvers, e.g. a [`CachingResolver`](super::CachingResolver), can be set with [`IssuerDocumentCache::cache`].
+///
+/// # Example
+/// ```ignore
+/// let cache = IssuerDocumentCache::new(resolver, IssuerDocumentCachePolicy::default());
+/// let issuer_document: CoreDocument = cache.resolve_credential_issuer(&credential_jwt).await?;
+/// let decoded = validator.validate(&credential_jwt, &issuer_document, &options, FailFast::FirstError)?;
+/// ```
+pub struct IssuerDocumentCache<DOC = CoreDocument, CMD = SendSyncCommand<DOC>>
+where
+  CMD: for<'r> Command<'r, crate::Result<DOC>>,
+{
+  resolver: Resolver<DOC, CMD>,
+  policy: IssuerDocumentCachePolicy,
+  cache: Arc<dyn ResolutionCache<DOC>>,
+}
+
+impl<DOC, CMD> IssuerDocumentCache<DOC, CMD>
+where
+  DOC: Clone + Send + Sync + 'static,
+  CMD: for<'r> Command<'r, crate::Result<DOC>>,
+{
+  /// Creates a new, empty [`IssuerDocumentCache`] resolving documents through `resolver`.
+  pub fn new(resolver: Resolver<DOC, CMD>, policy: IssuerDocumentCachePolicy) -> Self {
+    Self {
+      resolver,
+      policy,
+      cache: Arc::new(MemResolutionCache::new()),
+    }
+  }
+
+  /// Replaces the cache with `cache`, e.g. to share it with other resolvers.
+  #[must_use]
+  pub fn cache(mut self, cache: Arc<dyn ResolutionCache<DOC>>) -> Self {
+    self.cache = cache;
+    self
+  }
+
+  /// Returns the policy of this cache.
+  pub fn policy(&self) -> &IssuerDocumentCachePolicy {
+    &self.policy
+  }
+
+  /// Returns the resolver documents are resolved through.
+  pub fn resolver(&self) -> &Resolver<DOC, CMD> {
+    &self.resolver
+  }
+
+  /// Returns the document of `did`, resolving it if it isn't cached, or if the cached document has outlived the
+  /// policy.
+  ///
+  /// # Errors
+  /// - [`IssuerDocumentCacheError::Resolution`] if an uncached document couldn't be resolved.
+  /// - [`IssuerDocumentCacheError::Stale`] if a cached document has outlived the policy and couldn't be resolved again.
+  pub async fn resolve<D: DID>(&self, did: &D) -> Result<DOC, IssuerDocumentCacheError> {
+    self.resolve_version(did, DocumentVersion::Latest).await
+  }
+
+  /// Returns the document of `did` as it was at time `at`, see [`Self::resolve`] and
+  /// [`Resolver::resolve_at`](super::Resolver::resolve_at).
+  ///
+  /// # Errors
+  /// See [`Self::resolve`].
+  pub async fn resolve_at<D: DID>(&self, did: &D, at: Timestamp) -> Result<DOC, IssuerDocumentCacheError> {
+    self.resolve_version(did, DocumentVersion::VersionTime(at)).await
+  }
+
+  async fn resolve_version<D: DID>(&self, did: &D, version: DocumentVersion) -> Result<DOC, IssuerDocumentCacheError> {
+    let now = Timestamp::now_utc();
+    let key = ResolutionCacheKey::new(did.as_str(), version);
+    let cached = self.cache.get(&key).map(|entry| {
+      let freshness = self.policy.freshness(&entry, now);
+      (entry.document, entry.fetched_at, freshness)
+    });
+
+    match cached {
+      Some((document, _, Freshness::Fresh | Freshness::Stale)) => Ok(document),
+      Some((_, fetched_at, Freshness::Expired)) => {
+        self
+          .resolve_and_cache(did, key)
+          .await
+          .map_err(|source| IssuerDocumentCacheError::Stale {
+            did: did.to_string(),
+            fetched_at,
+            source: Box::new(source),
+          })
+      }
+      None => self
+        .resolve_and_cache(did, key)
+        .await
+        .map_err(|source| IssuerDocumentCacheError::Resolution {
+          did: did.to_string(),
+          source: Box::new(source),
+        }),
+    }
+  }
+
+  /// Resolves the document of the issuer of the JWT encoded credential `credential`, see [`Self::resolve`].
+  ///
+  /// The credential is **not** validated.
+  pub async fn resolve_credential_issuer(&self, credential: &impl AsRef<str>) -> Result<DOC, IssuerDocumentCacheError> {
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential)
+      .map_err(IssuerDocumentCacheError::InvalidIssuer)?;
+    self.resolve(&issuer).await
+  }
+
+  /// Resolves the document of `did`, replacing the cached one.
+  ///
+  /// The cached document, if any, is kept if resolution fails.
+  pub async fn refresh<D: DID>(&self, did: &D) -> Result<DOC, IssuerDocumentCacheError> {
+    self
+      .resolve_and_cache(did, ResolutionCacheKey::latest(did.as_str()))
+      .await
+      .map_err(|source| IssuerDocumentCacheError::Resolution {
+        did: did.to_string(),
+        source: Box::new(source),
+      })
+  }
+
+  async fn resolve_and_cache<D: DID>(&self, did: &D, key: ResolutionCacheKey) -> crate::Result<DOC> {
+    let document = match &key.version {
+      DocumentVersion::VersionTime(at) => self.resolver.resolve_at(did, *at).await?,
+      _ => self.resolver.resolve(did).await?,
+    };
+    let resolution = CachedResolution::new(document.clone(), &key.version);
+    self.cache.insert(key, resolution);
+
+    Ok(document)
+  }
+
+  /// Concurrently resolves again the latest state of every cached document that is no longer fresh.
+  ///
+  /// Returns the errors of the documents that couldn't be resolved; these are kept in the cache until they expire.
+  pub async fn revalidate(&self) -> Vec<IssuerDocumentCacheError> {
+    let now = Timestamp::now_utc();
+    let outdated: Vec<CoreDID> = self
+      .cache
+      .keys()
+      .into_iter()
+      .filter(|key| key.version == DocumentVersion::Latest)
+      .filter(|key| {
+        self
+          .cache
+          .get(key)
+          .is_some_and(|entry| self.policy.freshness(&entry, now) != Freshness::Fresh)
+      })
+      .filter_map(|key| CoreDID::parse(key.did).ok())
+      .collect();
+
+    outdated
+      .iter()
+      .map(|did| self.refresh(did))
+      .collect::<FuturesUnordered<_>>()
+      .filter_map(|result| async move { result.err() })
+      .collect()
+      .await
+  }
+
+  /// Removes the cached documents of `did`, in all their states.
+  pub fn invalidate<D: DID>(&self, did: &D) {
+    self.cache.remove_did(did.as_str());
+  }
+
+  /// Removes all cached documents.
+  pub fn clear(&self) {
+    self.cache.clear();
+  }
+}
+
+impl<DOC, CMD> std::fmt::Debug for IssuerDocumentCache<DOC, CMD>
+where
+  CMD: for<'r> Command<'r, crate::Result<DOC>>,
+  DOC: AsRef<CoreDocument>,
+{
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("IssuerDocumentCache")
+      .field("resolver", &self.resolver)
+      .field("policy", &self.policy)
+      .finish_non_exhaustive()
+  }
+}
+
+/// Errors that can occur when resolving a document through an [`IssuerDocumentCache`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum IssuerDocumentCacheError {
+  /// The issuer of a credential couldn't be determined.
+  #[error("could not determine the issuer of the credential")]
+  InvalidIssuer(#[source] JwtValidationError),
+  /// The document of `did` couldn't be resolved.
+  #[error("could not resolve the DID document of \"{did}\"")]
+  Resolution {
+    /// The DID whose document couldn't be resolved.
+    did: String,
+    /// The cause of the failure.
+    #[source]
+    source: Box<crate::Error>,
+  },
+  /// The cached document of `did` is stale beyond the cache's policy and couldn't be resolved again.
+  #[error("the cached DID document of \"{did}\" is stale beyond policy and could not be refreshed")]
+  Stale {
+    /// The DID whose document is stale.
+    did: String,
+    /// When the stale document was resolved.
+    fetched_at: Timestamp,
+    /// The cause of the refresh failure.
+    #[source]
+    source: Box<crate::Error>,
+  },
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::AtomicBool;
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+  use std::sync::Arc;
+
+  use identity_document::document::DocumentBuilder;
+
+  use super::*;
+
+  #[derive(Default)]
+  struct Handler {
+    calls: AtomicUsize,
+    offline: AtomicBool,
+  }
+
+  fn setup(policy: IssuerDocumentCachePolicy) -> (IssuerDocumentCache, Arc<Handler>) {
+    let handler = Arc::new(Handler::default());
+    let mut resolver = Resolver::<CoreDocument>::new();
+    let state = handler.clone();
+    resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+      let state = state.clone();
+      async move {
+        state.calls.fetch_add(1, Ordering::SeqCst);
+        if state.offline.load(Ordering::SeqCst) {
+          return Err(std::io::Error::other("offline"));
+        }
+        Ok(DocumentBuilder::default().id(did).build().unwrap())
+      }
+    });
+
+    (IssuerDocumentCache::new(resolver, policy), handler)
+  }
+
+  /// Makes the cached document of `did` `age` old.
+  fn age_entry(cache: &IssuerDocumentCache, did: &CoreDID, age: Duration) {
+    let key = ResolutionCacheKey::latest(did.as_str());
+    let mut entry = cache.cache.get(&key).unwrap();
+    entry.fetched_at = Timestamp::from_unix(Timestamp::now_utc().to_unix() - age.as_secs() as i64).unwrap();
+    cache.cache.insert(key, entry);
+  }
+
+  #[tokio::test]
+  async fn fresh_and_stale_documents_are_served_from_cache() {
+    let policy =
+      IssuerDocumentCachePolicy::new(Duration::from_secs(60)).stale_while_revalidate(Duration::from_secs(60));
+    let (cache, handler) = setup(policy);
+    let did = CoreDID::parse("did:foo:123").unwrap();
+
+    cache.resolve(&did).await.unwrap();
+    cache.resolve(&did).await.unwrap();
+    assert_eq!(handler.calls.load(Ordering::SeqCst), 1);
+
+    age_entry(&cache, &did, Duration::from_secs(90));
+    cache.resolve(&did).await.unwrap();
+    assert_eq!(handler.calls.load(Ordering::SeqCst), 1);
+
+    assert!(cache.revalidate().await.is_empty());
+    assert_eq!(handler.calls.load(Ordering::SeqCst), 2);
+    cache.resolve(&did).await.unwrap();
+    assert_eq!(handler.calls.load(Ordering::SeqCst), 2);
+  }
+
+  #[tokio::test]
+  async fn documents_stale_beyond_policy_are_rejected() {
+    let (cache, handler) = setup(IssuerDocumentCachePolicy::new(Duration::from_secs(60)));
+    let did = CoreDID::parse("did:foo:123").unwrap();
+    cache.resolve(&did).await.unwrap();
+
+    age_entry(&cache, &did, Duration::from_secs(90));
+    handler.offline.store(true, Ordering::SeqCst);
+    assert!(matches!(
+      cache.resolve(&did).await.unwrap_err(),
+      IssuerDocumentCacheError::Stale { .. }
+    ));
+    assert_eq!(cache.revalidate().await.len(), 1);
+
+    handler.offline.store(false, Ordering::SeqCst);
+    cache.resolve(&did).await.unwrap();
+    assert_eq!(handler.calls.load(Ordering::SeqCst), 4);
+  }
+}
@@ -0,0 +1,296 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_iota_core::DidResolutionHandler;
+use identity_iota_core::Error;
+use identity_iota_core::IotaDID;
+use identity_iota_core::IotaDocument;
+
+use super::Resolver;
+
+/// Which network a [`LegacyFallbackHandler`] queries first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ResolutionOrder {
+  /// Query the IOTA rebased network first, falling back to the legacy Stardust network.
+  #[default]
+  RebasedFirst,
+  /// Query the legacy Stardust network first, falling back to the IOTA rebased network.
+  LegacyFirst,
+}
+
+/// Where a document resolved through a [`LegacyFallbackHandler`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentOrigin {
+  /// The document was created on the IOTA rebased network.
+  Rebased,
+  /// The document was migrated from the legacy Stardust network to the IOTA rebased network; its legacy DID is
+  /// listed in its `alsoKnownAs` property.
+  Migrated,
+  /// The document was resolved from the legacy Stardust network.
+  Legacy,
+}
+
+/// An [`IotaDocument`] resolved through a [`LegacyFallbackHandler`], annotated with its [`DocumentOrigin`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResolvedIotaDocument {
+  /// The resolved document.
+  pub document: IotaDocument,
+  /// Where the document comes from.
+  pub origin: DocumentOrigin,
+}
+
+impl AsRef<CoreDocument> for ResolvedIotaDocument {
+  fn as_ref(&self) -> &CoreDocument {
+    self.document.as_ref()
+  }
+}
+
+impl From<ResolvedIotaDocument> for IotaDocument {
+  fn from(value: ResolvedIotaDocument) -> Self {
+    value.document
+  }
+}
+
+impl From<ResolvedIotaDocument> for CoreDocument {
+  fn from(value: ResolvedIotaDocument) -> Self {
+    value.document.into()
+  }
+}
+
+/// Resolves `did:iota` DIDs through both the IOTA rebased network and the legacy Stardust network, so that
+/// applications keep working while identities are being migrated.
+///
+/// The network queried first is chosen through [`LegacyFallbackHandler::order`]; the other one is only queried if
+/// the DID cannot be found on the first one - i.e. if it fails with [`identity_iota_core::Error::DIDNotFound`].
+/// Any other failure, e.g. an unreachable node, is returned right away.
+///
+/// # Example
+/// ```ignore
+/// // `legacy_client` is any `DidResolutionHandler` able to resolve DIDs on the Stardust network.
+/// let handler = LegacyFallbackHandler::new(identity_client, legacy_client);
+/// let mut resolver = Resolver::<ResolvedIotaDocument>::new();
+/// resolver.attach_iota_handler_with_legacy_fallback(handler);
+///
+/// let resolved = resolver.resolve(&did).await?;
+/// if resolved.origin == DocumentOrigin::Legacy {
+///   // Ask the controller to migrate their identity.
+/// }
+/// ```
+#[derive(Debug)]
+pub struct LegacyFallbackHandler<R, L> {
+  rebased: R,
+  legacy: L,
+  order: ResolutionOrder,
+}
+
+impl<R, L> LegacyFallbackHandler<R, L>
+where
+  R: DidResolutionHandler,
+  L: DidResolutionHandler,
+{
+  /// Creates a new [`LegacyFallbackHandler`] resolving DIDs through `rebased` first, then through `legacy`.
+  pub fn new(rebased: R, legacy: L) -> Self {
+    Self {
+      rebased,
+      legacy,
+      order: ResolutionOrder::default(),
+    }
+  }
+
+  /// Sets which network is queried first.
+  #[must_use]
+  pub fn order(mut self, order: ResolutionOrder) -> Self {
+    self.order = order;
+    self
+  }
+
+  /// Resolves `did`, annotating the resolved document with its origin.
+  ///
+  /// # Errors
+  /// - [`LegacyFallbackError::NotFound`] if the DID could be resolved on neither network.
+  /// - [`LegacyFallbackError::Resolution`] if the network queried first failed for any reason other than the DID not
+  ///   being found.
+  pub async fn resolve(&self, did: &IotaDID) -> Result<ResolvedIotaDocument, LegacyFallbackError> {
+    match self.order {
+      ResolutionOrder::RebasedFirst => match self.resolve_rebased(did).await {
+        Err(rebased @ Error::DIDNotFound(_)) => self
+          .resolve_legacy(did)
+          .await
+          .map_err(|legacy| LegacyFallbackError::NotFound { rebased, legacy }),
+        result => result.map_err(LegacyFallbackError::Resolution),
+      },
+      ResolutionOrder::LegacyFirst => match self.resolve_legacy(did).await {
+        Err(legacy @ Error::DIDNotFound(_)) => self
+          .resolve_rebased(did)
+          .await
+          .map_err(|rebased| LegacyFallbackError::NotFound { rebased, legacy }),
+        result => result.map_err(LegacyFallbackError::Resolution),
+      },
+    }
+  }
+
+  async fn resolve_rebased(&self, did: &IotaDID) -> Result<ResolvedIotaDocument, Error> {
+    let document = self.rebased.resolve_did(did).await?;
+    let origin = if has_legacy_alias(&document) {
+      DocumentOrigin::Migrated
+    } else {
+      DocumentOrigin::Rebased
+    };
+
+    Ok(ResolvedIotaDocument { document, origin })
+  }
+
+  async fn resolve_legacy(&self, did: &IotaDID) -> Result<ResolvedIotaDocument, Error> {
+    let document = self.legacy.resolve_did(did).await?;
+    Ok(ResolvedIotaDocument {
+      document,
+      origin: DocumentOrigin::Legacy,
+    })
+  }
+}
+
+/// Returns whether `document` lists, as an alias, another `did:iota` DID - i.e. the DID it had before being migrated.
+fn has_legacy_alias(document: &IotaDocument) -> bool {
+  document
+    .also_known_as()
+    .iter()
+    .any(|alias| IotaDID::parse(alias.as_str()).is_ok_and(|alias| alias.as_str() != document.id().as_str()))
+}
+
+/// [`LegacyFallbackHandler::resolve`] error.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum LegacyFallbackError {
+  /// The DID could not be found on the network queried first, and the fallback network failed to resolve it too.
+  #[error("DID resolution failed on both the IOTA rebased and the legacy Stardust networks")]
+  NotFound {
+    /// The error returned by the IOTA rebased network.
+    #[source]
+    rebased: Error,
+    /// The error returned by the legacy Stardust network.
+    legacy: Error,
+  },
+  /// The network queried first failed for a reason other than the DID not being found, so the fallback network was
+  /// not queried.
+  #[error("DID resolution failed")]
+  Resolution(#[source] Error),
+}
+
+impl<DOC> Resolver<DOC>
+where
+  DOC: From<ResolvedIotaDocument> + AsRef<CoreDocument> + 'static,
+{
+  /// Attaches `handler` to resolve IOTA DIDs on both the IOTA rebased and the legacy Stardust networks.
+  ///
+  /// Use a `Resolver<ResolvedIotaDocument>` to retain the origin of resolved documents.
+  ///
+  /// See also [`attach_iota_handler`](Self::attach_iota_handler).
+  pub fn attach_iota_handler_with_legacy_fallback<R, L>(&mut self, handler: LegacyFallbackHandler<R, L>)
+  where
+    R: DidResolutionHandler + Send + Sync + 'static,
+    L: DidResolutionHandler + Send + Sync + 'static,
+  {
+    let handler = Arc::new(handler);
+    let handler = move |did: IotaDID| {
+      let handler = handler.clone();
+      async move { handler.resolve(&did).await }
+    };
+
+    self.attach_handler(IotaDID::METHOD.to_owned(), handler);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const DID: &str = "did:iota:0x0101010101010101010101010101010101010101010101010101010101010101";
+  const LEGACY_DID: &str = "did:iota:0x0202020202020202020202020202020202020202020202020202020202020202";
+
+  struct DummyClient(Option<IotaDocument>);
+
+  #[async_trait::async_trait]
+  impl DidResolutionHandler for DummyClient {
+    async fn resolve_did(&self, did: &IotaDID) -> identity_iota_core::Result<IotaDocument> {
+      self
+        .0
+        .clone()
+        .filter(|document| document.id() == did)
+        .ok_or_else(|| Error::DIDNotFound(did.to_string()))
+    }
+  }
+
+  struct UnreachableClient;
+
+  #[async_trait::async_trait]
+  impl DidResolutionHandler for UnreachableClient {
+    async fn resolve_did(&self, _did: &IotaDID) -> identity_iota_core::Result<IotaDocument> {
+      Err(Error::DIDResolutionError("node unreachable".to_owned()))
+    }
+  }
+
+  fn document() -> IotaDocument {
+    IotaDocument::new_with_id(IotaDID::parse(DID).unwrap())
+  }
+
+  #[tokio::test]
+  async fn falls_back_to_the_legacy_network() {
+    let handler = LegacyFallbackHandler::new(DummyClient(None), DummyClient(Some(document())));
+    let mut resolver = Resolver::<ResolvedIotaDocument>::new();
+    resolver.attach_iota_handler_with_legacy_fallback(handler);
+
+    let did = IotaDID::parse(DID).unwrap();
+    let resolved = resolver.resolve(&did).await.unwrap();
+    assert_eq!(resolved.origin, DocumentOrigin::Legacy);
+    assert_eq!(resolved.document.id(), &did);
+  }
+
+  #[tokio::test]
+  async fn migrated_documents_are_annotated() {
+    let mut migrated = document();
+    migrated
+      .also_known_as_mut()
+      .prepend(IotaDID::parse(LEGACY_DID).unwrap().into_url().into());
+    let handler = LegacyFallbackHandler::new(DummyClient(Some(migrated)), DummyClient(Some(document())));
+
+    let did = IotaDID::parse(DID).unwrap();
+    assert_eq!(handler.resolve(&did).await.unwrap().origin, DocumentOrigin::Migrated);
+
+    let handler = handler.order(ResolutionOrder::LegacyFirst);
+    assert_eq!(handler.resolve(&did).await.unwrap().origin, DocumentOrigin::Legacy);
+
+    let unknown = IotaDID::parse(LEGACY_DID).unwrap();
+    assert!(matches!(
+      handler.resolve(&unknown).await,
+      Err(LegacyFallbackError::NotFound {
+        rebased: Error::DIDNotFound(_),
+        legacy: Error::DIDNotFound(_),
+      })
+    ));
+  }
+
+  #[tokio::test]
+  async fn other_errors_do_not_fall_back() {
+    let did = IotaDID::parse(DID).unwrap();
+
+    let handler = LegacyFallbackHandler::new(UnreachableClient, DummyClient(Some(document())));
+    assert!(matches!(
+      handler.resolve(&did).await,
+      Err(LegacyFallbackError::Resolution(Error::DIDResolutionError(_)))
+    ));
+
+    let handler =
+      LegacyFallbackHandler::new(DummyClient(Some(document())), UnreachableClient).order(ResolutionOrder::LegacyFirst);
+    assert!(matches!(
+      handler.resolve(&did).await,
+      Err(LegacyFallbackError::Resolution(Error::DIDResolutionError(_)))
+    ));
+  }
+}
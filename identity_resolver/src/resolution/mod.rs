@@ -1,7 +1,16 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod caching_resolver;
 mod commands;
+#[cfg(feature = "did-web")]
+mod did_web;
+#[cfg(feature = "http-gateway")]
+mod http_gateway;
+mod issuer_document_cache;
+#[cfg(all(feature = "iota", not(target_arch = "wasm32")))]
+mod legacy_fallback;
+mod resolution_cache;
 mod resolver;
 #[cfg(test)]
 mod tests;
@@ -9,6 +18,15 @@ mod tests;
 use self::commands::SingleThreadedCommand;
 use identity_document::document::CoreDocument;
 
+pub use caching_resolver::*;
+#[cfg(feature = "did-web")]
+pub use did_web::*;
+#[cfg(feature = "http-gateway")]
+pub use http_gateway::*;
+pub use issuer_document_cache::*;
+#[cfg(all(feature = "iota", not(target_arch = "wasm32")))]
+pub use legacy_fallback::*;
+pub use resolution_cache::*;
 pub use resolver::Resolver;
 /// Alias for a [`Resolver`] that is not [`Send`] + [`Sync`].
 pub type SingleThreadedResolver<DOC = CoreDocument> = Resolver<DOC, SingleThreadedCommand<DOC>>;
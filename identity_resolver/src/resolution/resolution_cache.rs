@@ -0,0 +1,271 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use identity_core::common::Timestamp;
+
+/// The state of a DID document a resolution result is for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DocumentVersion {
+  /// The latest state of the document, which may change at any time.
+  Latest,
+  /// The state the document had at the given time, see [`Resolver::resolve_at`](super::Resolver::resolve_at).
+  VersionTime(Timestamp),
+  /// The state of the document with the given version identifier.
+  VersionId(String),
+}
+
+/// The key of a resolution result in a [`ResolutionCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ResolutionCacheKey {
+  /// The resolved DID.
+  pub did: String,
+  /// The resolved state of the DID's document.
+  pub version: DocumentVersion,
+}
+
+impl ResolutionCacheKey {
+  /// Creates a new key for the `version` of the document of `did`.
+  pub fn new(did: impl Into<String>, version: DocumentVersion) -> Self {
+    Self {
+      did: did.into(),
+      version,
+    }
+  }
+
+  /// Creates a new key for the latest state of the document of `did`.
+  pub fn latest(did: impl Into<String>) -> Self {
+    Self::new(did, DocumentVersion::Latest)
+  }
+
+  /// Creates a new key for the state the document of `did` had at time `at`.
+  pub fn at(did: impl Into<String>, at: Timestamp) -> Self {
+    Self::new(did, DocumentVersion::VersionTime(at))
+  }
+}
+
+/// A resolution result stored in a [`ResolutionCache`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CachedResolution<DOC> {
+  /// The resolved document.
+  pub document: DOC,
+  /// When the document was resolved.
+  pub fetched_at: Timestamp,
+  /// Whether the resolved state of the document can no longer change, in which case it may be cached forever.
+  pub immutable: bool,
+}
+
+impl<DOC> CachedResolution<DOC> {
+  /// Creates a new [`CachedResolution`] of `document`, just resolved in its `version`.
+  ///
+  /// Past states of a document, identified by a version identifier or by a time preceding the resolution, are
+  /// immutable. The latest state, or the state at a time yet to come, is not.
+  pub fn new(document: DOC, version: &DocumentVersion) -> Self {
+    let fetched_at = Timestamp::now_utc();
+    let immutable = match version {
+      DocumentVersion::Latest => false,
+      DocumentVersion::VersionTime(at) => *at < fetched_at,
+      DocumentVersion::VersionId(_) => true,
+    };
+
+    Self {
+      document,
+      fetched_at,
+      immutable,
+    }
+  }
+
+  /// Returns for how long the document has been cached at `now`.
+  pub fn age(&self, now: Timestamp) -> Duration {
+    let seconds = now.to_unix().saturating_sub(self.fetched_at.to_unix());
+    Duration::from_secs(seconds.try_into().unwrap_or_default())
+  }
+
+  /// Returns whether the document may still be used at `now`, if mutable documents are cached for `ttl`.
+  pub fn is_fresh(&self, now: Timestamp, ttl: Duration) -> bool {
+    self.immutable || self.age(now) <= ttl
+  }
+}
+
+/// A store of resolution results, shared by caching resolvers such as [`CachingResolver`](super::CachingResolver)
+/// and [`IssuerDocumentCache`](super::IssuerDocumentCache).
+///
+/// The store only holds results: for how long mutable ones may be used is decided by the resolvers using it.
+pub trait ResolutionCache<DOC>: Send + Sync {
+  /// Returns the result stored under `key`, if any.
+  fn get(&self, key: &ResolutionCacheKey) -> Option<CachedResolution<DOC>>;
+
+  /// Stores `resolution` under `key`, replacing any previous result.
+  fn insert(&self, key: ResolutionCacheKey, resolution: CachedResolution<DOC>);
+
+  /// Removes the result stored under `key`, if any.
+  fn remove(&self, key: &ResolutionCacheKey);
+
+  /// Removes the results of all states of the document of `did`.
+  fn remove_did(&self, did: &str);
+
+  /// Returns the keys of all stored results.
+  fn keys(&self) -> Vec<ResolutionCacheKey>;
+
+  /// Removes all stored results.
+  fn clear(&self);
+
+  /// Returns the number of stored results.
+  fn len(&self) -> usize;
+
+  /// Returns whether no result is stored.
+  fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+/// An in-memory [`ResolutionCache`].
+///
+/// At most [`MemResolutionCache::max_entries`] results are stored: when the store is full, the mutable results that
+/// were resolved the longest time ago are evicted first, then the immutable ones.
+#[derive(Debug)]
+pub struct MemResolutionCache<DOC> {
+  max_entries: usize,
+  entries: RwLock<HashMap<ResolutionCacheKey, CachedResolution<DOC>>>,
+}
+
+impl<DOC> MemResolutionCache<DOC> {
+  /// The default maximum number of stored results.
+  pub const DEFAULT_MAX_ENTRIES: usize = 1_024;
+
+  /// Creates a new, empty [`MemResolutionCache`].
+  pub fn new() -> Self {
+    Self {
+      max_entries: Self::DEFAULT_MAX_ENTRIES,
+      entries: RwLock::new(HashMap::new()),
+    }
+  }
+
+  /// Sets the maximum number of stored results. A value of `0` disables caching.
+  #[must_use]
+  pub fn max_entries(mut self, max_entries: usize) -> Self {
+    self.max_entries = max_entries;
+    self
+  }
+
+  fn read_entries(&self) -> std::sync::RwLockReadGuard<'_, HashMap<ResolutionCacheKey, CachedResolution<DOC>>> {
+    // The lock is never held while running user code, so it can't be poisoned.
+    self.entries.read().expect("the cache lock is not poisoned")
+  }
+
+  fn write_entries(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<ResolutionCacheKey, CachedResolution<DOC>>> {
+    self.entries.write().expect("the cache lock is not poisoned")
+  }
+}
+
+impl<DOC> Default for MemResolutionCache<DOC> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<DOC> ResolutionCache<DOC> for MemResolutionCache<DOC>
+where
+  DOC: Clone + Send + Sync,
+{
+  fn get(&self, key: &ResolutionCacheKey) -> Option<CachedResolution<DOC>> {
+    self.read_entries().get(key).cloned()
+  }
+
+  fn insert(&self, key: ResolutionCacheKey, resolution: CachedResolution<DOC>) {
+    if self.max_entries == 0 {
+      return;
+    }
+
+    let mut entries = self.write_entries();
+    while !entries.contains_key(&key) && entries.len() >= self.max_entries {
+      let evicted = entries
+        .iter()
+        .min_by_key(|(_, entry)| (entry.immutable, entry.fetched_at))
+        .map(|(key, _)| key.clone())
+        .expect("the cache is not empty");
+      entries.remove(&evicted);
+    }
+
+    entries.insert(key, resolution);
+  }
+
+  fn remove(&self, key: &ResolutionCacheKey) {
+    self.write_entries().remove(key);
+  }
+
+  fn remove_did(&self, did: &str) {
+    self.write_entries().retain(|key, _| key.did != did);
+  }
+
+  fn keys(&self) -> Vec<ResolutionCacheKey> {
+    self.read_entries().keys().cloned().collect()
+  }
+
+  fn clear(&self) {
+    self.write_entries().clear();
+  }
+
+  fn len(&self) -> usize {
+    self.read_entries().len()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn resolution(version: &DocumentVersion, fetched_secs_ago: i64) -> CachedResolution<u32> {
+    let mut resolution = CachedResolution::new(0, version);
+    resolution.fetched_at = Timestamp::from_unix(Timestamp::now_utc().to_unix() - fetched_secs_ago).unwrap();
+    resolution
+  }
+
+  #[test]
+  fn past_versions_are_immutable() {
+    let past = Timestamp::from_unix(0).unwrap();
+    let future = Timestamp::from_unix(Timestamp::now_utc().to_unix() + 3_600).unwrap();
+
+    assert!(!CachedResolution::new(0, &DocumentVersion::Latest).immutable);
+    assert!(CachedResolution::new(0, &DocumentVersion::VersionTime(past)).immutable);
+    assert!(!CachedResolution::new(0, &DocumentVersion::VersionTime(future)).immutable);
+    assert!(CachedResolution::new(0, &DocumentVersion::VersionId("1".to_owned())).immutable);
+
+    let stale = resolution(&DocumentVersion::VersionTime(past), 3_600);
+    assert!(stale.is_fresh(Timestamp::now_utc(), Duration::from_secs(60)));
+    let stale = resolution(&DocumentVersion::Latest, 3_600);
+    assert!(!stale.is_fresh(Timestamp::now_utc(), Duration::from_secs(60)));
+  }
+
+  #[test]
+  fn mutable_results_are_evicted_first() {
+    let cache = MemResolutionCache::new().max_entries(2);
+    let past = Timestamp::from_unix(0).unwrap();
+
+    cache.insert(
+      ResolutionCacheKey::at("did:foo:0", past),
+      resolution(&DocumentVersion::VersionTime(past), 20),
+    );
+    cache.insert(
+      ResolutionCacheKey::latest("did:foo:0"),
+      resolution(&DocumentVersion::Latest, 10),
+    );
+    cache.insert(
+      ResolutionCacheKey::latest("did:foo:1"),
+      resolution(&DocumentVersion::Latest, 0),
+    );
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&ResolutionCacheKey::latest("did:foo:0")).is_none());
+    assert!(cache.get(&ResolutionCacheKey::at("did:foo:0", past)).is_some());
+
+    cache.remove_did("did:foo:0");
+    assert_eq!(cache.keys(), vec![ResolutionCacheKey::latest("did:foo:1")]);
+  }
+}
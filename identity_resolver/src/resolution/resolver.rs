@@ -4,8 +4,14 @@
 use core::future::Future;
 use futures::stream::FuturesUnordered;
 use futures::TryStreamExt;
+use identity_core::common::Timestamp;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidatorUtils;
+use identity_did::CoreDID;
 use identity_did::DIDCompositeJwk;
 use identity_did::DIDJwk;
+use identity_did::DIDKey;
+use identity_did::DIDUrl;
 use identity_did::DID;
 use std::collections::HashSet;
 
@@ -27,11 +33,14 @@ use super::commands::SingleThreadedCommand;
 ///
 /// The resolver will only be able to resolve DID documents for methods it has been configured for. This is done by
 /// attaching method specific handlers with [`Self::attach_handler`](Self::attach_handler()).
+/// Resolving DID documents as they were at a given point in time, through [`Self::resolve_at`](Self::resolve_at()),
+/// additionally requires handlers attached with [`Self::attach_history_handler`](Self::attach_history_handler()).
 pub struct Resolver<DOC = CoreDocument, CMD = SendSyncCommand<DOC>>
 where
   CMD: for<'r> Command<'r, Result<DOC>>,
 {
   command_map: HashMap<String, CMD>,
+  history_map: HashMap<String, CMD>,
   _required: PhantomData<DOC>,
 }
 
@@ -55,6 +64,7 @@ where
   pub fn new() -> Self {
     Self {
       command_map: HashMap::new(),
+      history_map: HashMap::new(),
       _required: PhantomData::<DOC>,
     }
   }
@@ -101,7 +111,77 @@ where
       })
       .map_err(Error::new)?;
 
-    delegate.apply(did.as_str()).await
+    delegate.apply(did.as_str(), None).await
+  }
+
+  /// Fetches the DID Document of the given DID as it was at time `at`.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the resolver has no history handler for the method corresponding to the given DID, see
+  /// [`Self::attach_history_handler`](Self::attach_history_handler()), or if the resolution process itself fails, e.g.
+  /// because the DID did not exist at `at`.
+  pub async fn resolve_at<D: DID>(&self, did: &D, at: Timestamp) -> Result<DOC> {
+    let method: &str = did.method();
+    let delegate: &M = self
+      .history_map
+      .get(method)
+      .ok_or_else(|| ErrorCause::UnsupportedHistoricalResolution {
+        method: method.to_owned(),
+      })
+      .map_err(Error::new)?;
+
+    delegate.apply(did.as_str(), Some(at)).await
+  }
+
+  /// Fetches the DID Document of the issuer of the JWT encoded credential `credential`, to validate it with `options`.
+  ///
+  /// If [`issuer_document_at_issuance`](JwtCredentialValidationOptions::issuer_document_at_issuance) is set, the
+  /// document is resolved as it was at the credential's issuance date, see [`Self::resolve_at`](Self::resolve_at()).
+  /// Otherwise its latest state is resolved.
+  ///
+  /// # Warning
+  /// The issuance date of a credential is set by its issuer and can be backdated by anyone holding one of its keys.
+  /// To prevent a compromised key from signing credentials that verify against a past state of the document, the
+  /// verification method that signed the credential must still be part of the issuer's **latest** DID Document:
+  /// credentials signed by methods that have since been removed - or whose document has been deactivated - are
+  /// rejected. Only the key material of a method still in use may differ from the one it had at issuance.
+  ///
+  /// The credential is **not** validated.
+  ///
+  /// # Errors
+  /// * If the issuer, the issuance date or the signing verification method cannot be extracted from the credential.
+  /// * If the resolution process of the issuer's DID fails.
+  /// * If the document is resolved at issuance and the signing method is no longer in the issuer's latest document.
+  pub async fn resolve_credential_issuer(
+    &self,
+    credential: &impl AsRef<str>,
+    options: &JwtCredentialValidationOptions,
+  ) -> Result<DOC>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    let invalid_credential = |err: identity_credential::validator::JwtValidationError| {
+      Error::new(ErrorCause::InvalidCredential { source: err.into() })
+    };
+    let issuer: CoreDID =
+      JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential).map_err(invalid_credential)?;
+    if !options.issuer_document_at_issuance {
+      return self.resolve(&issuer).await;
+    }
+
+    let issuance_date: Timestamp =
+      JwtCredentialValidatorUtils::extract_issuance_date_from_jwt(credential).map_err(invalid_credential)?;
+    let method_id: DIDUrl =
+      JwtCredentialValidatorUtils::extract_signing_method_from_jwt(credential, options).map_err(invalid_credential)?;
+    let latest_document: DOC = self.resolve(&issuer).await?;
+    if latest_document.as_ref().resolve_method(&method_id, None).is_none() {
+      return Err(Error::new(ErrorCause::RemovedIssuerMethod {
+        method: method_id.to_string(),
+      }));
+    }
+
+    self.resolve_at(&issuer, issuance_date).await
   }
 
   /// Concurrently fetches the DID Documents of the multiple given DIDs.
@@ -188,6 +268,26 @@ impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
     let command = SendSyncCommand::new(handler);
     self.command_map.insert(method, command);
   }
+
+  /// Attach a new handler responsible for resolving DIDs of the given DID method as they were at a given point in
+  /// time, see [`Self::resolve_at`](Self::resolve_at()).
+  ///
+  /// The `handler` is expected to be a closure taking an owned DID and a [`Timestamp`], with the same requirements as
+  /// the ones of [`Self::attach_handler`](Self::attach_handler()).
+  ///
+  /// NOTE: If there already exists a history handler for this method then it will be replaced with the new handler.
+  pub fn attach_history_handler<D, F, Fut, DOCUMENT, E, DIDERR>(&mut self, method: String, handler: F)
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Timestamp) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let command = SendSyncCommand::new_at(handler);
+    self.history_map.insert(method, command);
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
@@ -247,39 +347,101 @@ impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
     let command = SingleThreadedCommand::new(handler);
     self.command_map.insert(method, command);
   }
+
+  /// Attach a new handler responsible for resolving DIDs of the given DID method as they were at a given point in
+  /// time, see [`Self::resolve_at`](Self::resolve_at()).
+  ///
+  /// The `handler` is expected to be a closure taking an owned DID and a [`Timestamp`], with the same requirements as
+  /// the ones of [`Self::attach_handler`](Self::attach_handler()).
+  ///
+  /// NOTE: If there already exists a history handler for this method then it will be replaced with the new handler.
+  pub fn attach_history_handler<D, F, Fut, DOCUMENT, E, DIDERR>(&mut self, method: String, handler: F)
+  where
+    D: DID + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Timestamp) -> Fut + 'static + Clone,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let command = SingleThreadedCommand::new_at(handler);
+    self.history_map.insert(method, command);
+  }
 }
 
 impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
-  /// Attaches a handler capable of resolving `did:jwk` DIDs.
+  /// Attaches a handler capable of resolving `did:jwk` DIDs, at any point in time.
   pub fn attach_did_jwk_handler(&mut self) {
     let handler = |did_jwk: DIDJwk| async move { CoreDocument::expand_did_jwk(did_jwk) };
-    self.attach_handler(DIDJwk::METHOD.to_string(), handler)
+    self.attach_handler(DIDJwk::METHOD.to_string(), handler);
+    // Documents of `did:jwk` DIDs never change.
+    self.attach_history_handler(DIDJwk::METHOD.to_string(), move |did: DIDJwk, _at: Timestamp| {
+      handler(did)
+    })
   }
 }
 
 impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SendSyncCommand<DOC>> {
-  /// Attaches a handler capable of resolving `did:jwk` DIDs.
+  /// Attaches a handler capable of resolving `did:jwk` DIDs, at any point in time.
   pub fn attach_did_jwk_handler(&mut self) {
     let handler = |did_jwk: DIDJwk| async move { CoreDocument::expand_did_jwk(did_jwk) };
-    self.attach_handler(DIDJwk::METHOD.to_string(), handler)
+    self.attach_handler(DIDJwk::METHOD.to_string(), handler);
+    // Documents of `did:jwk` DIDs never change.
+    self.attach_history_handler(DIDJwk::METHOD.to_string(), move |did: DIDJwk, _at: Timestamp| {
+      handler(did)
+    })
+  }
+}
+
+impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
+  /// Attaches a handler capable of resolving `did:key` DIDs, at any point in time.
+  pub fn attach_did_key_handler(&mut self) {
+    let handler = |did_key: DIDKey| async move { CoreDocument::expand_did_key(did_key) };
+    self.attach_handler(DIDKey::METHOD.to_string(), handler);
+    // Documents of `did:key` DIDs never change.
+    self.attach_history_handler(DIDKey::METHOD.to_string(), move |did: DIDKey, _at: Timestamp| {
+      handler(did)
+    })
+  }
+}
+
+impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SendSyncCommand<DOC>> {
+  /// Attaches a handler capable of resolving `did:key` DIDs, at any point in time.
+  pub fn attach_did_key_handler(&mut self) {
+    let handler = |did_key: DIDKey| async move { CoreDocument::expand_did_key(did_key) };
+    self.attach_handler(DIDKey::METHOD.to_string(), handler);
+    // Documents of `did:key` DIDs never change.
+    self.attach_history_handler(DIDKey::METHOD.to_string(), move |did: DIDKey, _at: Timestamp| {
+      handler(did)
+    })
   }
 }
 
 impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
-  /// Attaches a handler capable of resolving `did:compositejwk` DIDs.
+  /// Attaches a handler capable of resolving `did:compositejwk` DIDs, at any point in time.
   pub fn attach_did_compositejwk_handler(&mut self) {
     let handler =
       |did_compositejwk: DIDCompositeJwk| async move { CoreDocument::expand_did_compositejwk(did_compositejwk) };
-    self.attach_handler(DIDCompositeJwk::METHOD.to_string(), handler)
+    self.attach_handler(DIDCompositeJwk::METHOD.to_string(), handler);
+    // Documents of `did:compositejwk` DIDs never change.
+    self.attach_history_handler(
+      DIDCompositeJwk::METHOD.to_string(),
+      move |did: DIDCompositeJwk, _at: Timestamp| handler(did),
+    )
   }
 }
 
 impl<DOC: From<CoreDocument> + 'static> Resolver<DOC, SendSyncCommand<DOC>> {
-  /// Attaches a handler capable of resolving `did:compositejwk` DIDs.
+  /// Attaches a handler capable of resolving `did:compositejwk` DIDs, at any point in time.
   pub fn attach_did_compositejwk_handler(&mut self) {
     let handler =
       |did_compositejwk: DIDCompositeJwk| async move { CoreDocument::expand_did_compositejwk(did_compositejwk) };
-    self.attach_handler(DIDCompositeJwk::METHOD.to_string(), handler)
+    self.attach_handler(DIDCompositeJwk::METHOD.to_string(), handler);
+    // Documents of `did:compositejwk` DIDs never change.
+    self.attach_history_handler(
+      DIDCompositeJwk::METHOD.to_string(),
+      move |did: DIDCompositeJwk, _at: Timestamp| handler(did),
+    )
   }
 }
 
@@ -288,6 +450,7 @@ mod iota_handler {
   use crate::ErrorCause;
 
   use super::Resolver;
+  use identity_core::common::Timestamp;
   use identity_document::document::CoreDocument;
   use identity_iota_core::IotaDID;
   use identity_iota_core::IotaDocument;
@@ -303,21 +466,29 @@ mod iota_handler {
     where
       DOC: From<IotaDocument> + AsRef<CoreDocument> + 'static,
     {
-      /// Convenience method for attaching a new handler responsible for resolving IOTA DIDs.
+      /// Convenience method for attaching a new handler responsible for resolving IOTA DIDs, either in their latest
+      /// state or at a given point in time.
       ///
-      /// See also [`attach_handler`](Self::attach_handler).
+      /// See also [`attach_handler`](Self::attach_handler) and
+      /// [`attach_history_handler`](Self::attach_history_handler).
       pub fn attach_iota_handler<CLI>(&mut self, client: CLI)
       where
         CLI: DidResolutionHandler + Send + Sync + 'static,
       {
         let arc_client: Arc<CLI> = Arc::new(client);
+        let history_client: Arc<CLI> = arc_client.clone();
 
         let handler = move |did: IotaDID| {
           let future_client = arc_client.clone();
           async move { future_client.resolve_did(&did).await }
         };
+        let history_handler = move |did: IotaDID, at: Timestamp| {
+          let future_client = history_client.clone();
+          async move { future_client.resolve_did_at(&did, at).await }
+        };
 
         self.attach_handler(IotaDID::METHOD.to_owned(), handler);
+        self.attach_history_handler(IotaDID::METHOD.to_owned(), history_handler);
       }
 
       /// Convenience method for attaching multiple handlers responsible for resolving IOTA DIDs
@@ -351,6 +522,7 @@ mod iota_handler {
         I: IntoIterator<Item = (&'static str, CLI)>,
       {
         let arc_clients = Arc::new(clients.into_iter().collect::<HashMap<&'static str, CLI>>());
+        let history_clients = arc_clients.clone();
 
         let handler = move |did: IotaDID| {
           let future_client = arc_clients.clone();
@@ -368,8 +540,22 @@ mod iota_handler {
               .map_err(|err| crate::Error::new(ErrorCause::HandlerError { source: Box::new(err) }))
           }
         };
+        let history_handler = move |did: IotaDID, at: Timestamp| {
+          let future_client = history_clients.clone();
+          async move {
+            let did_network = did.network_str();
+            let client: &CLI = future_client
+              .get(did_network)
+              .ok_or_else(|| crate::Error::new(ErrorCause::UnsupportedNetwork(did_network.to_string())))?;
+            client
+              .resolve_did_at(&did, at)
+              .await
+              .map_err(|err| crate::Error::new(ErrorCause::HandlerError { source: Box::new(err) }))
+          }
+        };
 
         self.attach_handler(IotaDID::METHOD.to_owned(), handler);
+        self.attach_history_handler(IotaDID::METHOD.to_owned(), history_handler);
       }
     }
   }
@@ -393,12 +579,14 @@ where
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("Resolver")
       .field("command_map", &self.command_map)
+      .field("history_map", &self.history_map)
       .finish()
   }
 }
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
   use identity_did::CoreDID;
   use identity_iota_core::DidResolutionHandler;
   use identity_iota_core::IotaDID;
@@ -454,4 +642,134 @@ mod tests {
     let doc = resolver.resolve(&did_jwk).await.unwrap();
     assert_eq!(doc.id(), expected_did);
   }
+
+  #[tokio::test]
+  async fn test_did_key_resolution() {
+    let mut resolver = Resolver::<CoreDocument>::new();
+    resolver.attach_did_key_handler();
+
+    let did_key = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp"
+      .parse::<DIDKey>()
+      .unwrap();
+    let expected_did: &CoreDID = did_key.as_ref();
+
+    let doc = resolver.resolve(&did_key).await.unwrap();
+    assert_eq!(doc.id(), expected_did);
+  }
+
+  #[tokio::test]
+  async fn test_resolve_at() {
+    let rotation = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+    let mut resolver = Resolver::<CoreDocument>::new();
+    resolver.attach_did_key_handler();
+    resolver.attach_history_handler("foo".to_owned(), move |did: CoreDID, at: Timestamp| async move {
+      let document = CoreDocument::builder(Object::new()).id(did).build()?;
+      if at < rotation {
+        Ok(document)
+      } else {
+        Err(identity_document::Error::InvalidDocument("rotated", None))
+      }
+    });
+
+    let did = CoreDID::parse("did:foo:123").unwrap();
+    let before_rotation = Timestamp::parse("2023-06-01T00:00:00Z").unwrap();
+    assert_eq!(resolver.resolve_at(&did, before_rotation).await.unwrap().id(), &did);
+    assert!(resolver.resolve_at(&did, rotation).await.is_err());
+    // History handlers are not used for the latest state.
+    assert!(matches!(
+      resolver.resolve(&did).await.unwrap_err().into_error_cause(),
+      ErrorCause::UnsupportedMethodError { .. }
+    ));
+
+    let did_key = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp"
+      .parse::<DIDKey>()
+      .unwrap();
+    assert!(resolver.resolve_at(&did_key, before_rotation).await.is_ok());
+
+    let did_jwk = CoreDID::parse("did:jwk:eyJrdHkiOiJPS1AifQ").unwrap();
+    assert!(matches!(
+      resolver
+        .resolve_at(&did_jwk, rotation)
+        .await
+        .unwrap_err()
+        .into_error_cause(),
+      ErrorCause::UnsupportedHistoricalResolution { .. }
+    ));
+  }
+
+  fn document_with_methods(did: &str, fragments: &[&str]) -> CoreDocument {
+    use identity_core::convert::FromJson;
+
+    let methods: Vec<String> = fragments
+      .iter()
+      .map(|fragment| {
+        format!(
+          r#"{{"id": "{did}#{fragment}", "controller": "{did}", "type": "Ed25519VerificationKey2018", "publicKeyMultibase": "zH3C2AVvLMv6gmMNam3uVAjZpfkcJCwDwnZn6z3wXmqPV"}}"#
+        )
+      })
+      .collect();
+    CoreDocument::from_json(&format!(
+      r#"{{"id": "{did}", "verificationMethod": [{}]}}"#,
+      methods.join(",")
+    ))
+    .unwrap()
+  }
+
+  /// Returns an unsigned JWT credential, sufficient for locating its issuer.
+  fn credential_jwt(issuer: &str, kid: &str, issuance_date: Timestamp) -> String {
+    use identity_core::common::Url;
+    use identity_core::convert::Base;
+    use identity_core::convert::BaseEncoding;
+    use identity_credential::credential::Credential;
+    use identity_credential::credential::CredentialBuilder;
+    use identity_credential::credential::Subject;
+
+    let credential: Credential = CredentialBuilder::default()
+      .issuer(Url::parse(issuer).unwrap())
+      .issuance_date(issuance_date)
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .build()
+      .unwrap();
+    let header = format!(r#"{{"alg": "EdDSA", "kid": "{kid}"}}"#);
+    let claims = credential.serialize_jwt(None).unwrap();
+
+    format!(
+      "{}.{}.{}",
+      BaseEncoding::encode(&header, Base::Base64Url),
+      BaseEncoding::encode(&claims, Base::Base64Url),
+      BaseEncoding::encode("signature", Base::Base64Url),
+    )
+  }
+
+  #[tokio::test]
+  async fn test_resolve_credential_issuer_at_issuance() {
+    const ISSUER: &str = "did:foo:issuer";
+    let rotation = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+    let mut resolver = Resolver::<CoreDocument>::new();
+    // `key-1` is removed at the rotation, `key-2` is kept.
+    resolver.attach_handler("foo".to_owned(), |did: CoreDID| async move {
+      Ok::<_, std::convert::Infallible>(document_with_methods(did.as_str(), &["key-2"]))
+    });
+    resolver.attach_history_handler("foo".to_owned(), move |did: CoreDID, at: Timestamp| async move {
+      let fragments: &[&str] = if at < rotation { &["key-1", "key-2"] } else { &["key-2"] };
+      Ok::<_, std::convert::Infallible>(document_with_methods(did.as_str(), fragments))
+    });
+    let options = JwtCredentialValidationOptions::default().issuer_document_at_issuance(true);
+    let issuance_date = Timestamp::parse("2023-06-01T00:00:00Z").unwrap();
+
+    let credential = credential_jwt(ISSUER, "did:foo:issuer#key-2", issuance_date);
+    let document = resolver.resolve_credential_issuer(&credential, &options).await.unwrap();
+    assert!(document.resolve_method("#key-1", None).is_some());
+
+    // A backdated credential signed with a removed method is rejected.
+    let credential = credential_jwt(ISSUER, "did:foo:issuer#key-1", issuance_date);
+    assert!(matches!(
+      resolver
+        .resolve_credential_issuer(&credential, &options)
+        .await
+        .unwrap_err()
+        .into_error_cause(),
+      ErrorCause::RemovedIssuerMethod { .. }
+    ));
+  }
 }
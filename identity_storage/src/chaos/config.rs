@@ -0,0 +1,125 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use rand::rngs::StdRng;
+use rand::Rng as _;
+use rand::SeedableRng as _;
+
+/// Describes how a chaos storage wrapper degrades the storage it wraps.
+///
+/// By default no degradation is applied.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChaosConfig {
+  /// The range the delay applied to each operation is uniformly sampled from.
+  pub latency: Option<(Duration, Duration)>,
+  /// The probability, between `0.0` and `1.0`, that an operation fails with a retryable I/O failure without reaching
+  /// the wrapped storage.
+  pub failure_rate: f64,
+  /// The probability, between `0.0` and `1.0`, that a write operation fails after being applied by the wrapped
+  /// storage.
+  pub partial_write_rate: f64,
+  /// The seed of the random number generator, for reproducible runs.
+  pub seed: Option<u64>,
+}
+
+impl ChaosConfig {
+  /// Creates a new [`ChaosConfig`] that applies no degradation.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Delays each operation by a random duration between `min` and `max`.
+  pub fn latency(mut self, min: Duration, max: Duration) -> Self {
+    self.latency = Some((min, max.max(min)));
+    self
+  }
+
+  /// Makes operations fail with a retryable I/O failure with probability `rate`.
+  pub fn failure_rate(mut self, rate: f64) -> Self {
+    self.failure_rate = rate;
+    self
+  }
+
+  /// Makes write operations fail after being applied with probability `rate`.
+  pub fn partial_write_rate(mut self, rate: f64) -> Self {
+    self.partial_write_rate = rate;
+    self
+  }
+
+  /// Seeds the random number generator deciding delays and failures.
+  pub fn seed(mut self, seed: u64) -> Self {
+    self.seed = Some(seed);
+    self
+  }
+}
+
+/// The fault decided for an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Fault {
+  /// The operation must not reach the wrapped storage.
+  Failure,
+  /// The operation must reach the wrapped storage, but its outcome must be replaced with an error.
+  PartialWrite,
+}
+
+/// The state shared by the chaos storage wrappers.
+#[derive(Debug)]
+pub(crate) struct Chaos {
+  config: ChaosConfig,
+  rng: Mutex<StdRng>,
+  injected: AtomicUsize,
+}
+
+impl Chaos {
+  pub(crate) fn new(config: ChaosConfig) -> Self {
+    let rng = config
+      .seed
+      .map(StdRng::seed_from_u64)
+      .unwrap_or_else(StdRng::from_entropy);
+    Self {
+      config,
+      rng: Mutex::new(rng),
+      injected: AtomicUsize::new(0),
+    }
+  }
+
+  pub(crate) fn config(&self) -> &ChaosConfig {
+    &self.config
+  }
+
+  pub(crate) fn injected_faults(&self) -> usize {
+    self.injected.load(Ordering::Relaxed)
+  }
+
+  /// Waits for the configured latency, then decides whether the operation - a write if `write` is set - fails.
+  pub(crate) async fn before_operation(&self, write: bool) -> Option<Fault> {
+    // The lock must be released before awaiting, so that the returned future stays `Send`.
+    let (delay, fault) = {
+      let mut rng = self.rng.lock().unwrap_or_else(PoisonError::into_inner);
+      let delay = self.config.latency.map(|(min, max)| rng.gen_range(min..=max));
+      let fault = if rng.gen::<f64>() < self.config.failure_rate {
+        Some(Fault::Failure)
+      } else if write && rng.gen::<f64>() < self.config.partial_write_rate {
+        Some(Fault::PartialWrite)
+      } else {
+        None
+      };
+      (delay, fault)
+    };
+
+    if let Some(delay) = delay.filter(|delay| !delay.is_zero()) {
+      tokio::time::sleep(delay).await;
+    }
+    if fault.is_some() {
+      self.injected.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fault
+  }
+}
@@ -0,0 +1,139 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::config::Chaos;
+use super::config::Fault;
+use super::ChaosConfig;
+use crate::key_storage::JwkGenOutput;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageError;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::KeyStorageResult;
+use crate::key_storage::KeyType;
+
+/// A [`JwkStorage`] wrapper that delays and randomly fails the operations of the wrapped storage, as configured by a
+/// [`ChaosConfig`].
+///
+/// Failed operations return a [`KeyStorageErrorKind::RetryableIOFailure`] without reaching the wrapped storage.
+/// Partially failed write operations - [`JwkStorage::generate`], [`JwkStorage::insert`] and [`JwkStorage::delete`] -
+/// are applied by the wrapped storage, but return a [`KeyStorageErrorKind::Unspecified`] error.
+#[derive(Debug)]
+pub struct ChaosJwkStorage<K> {
+  inner: K,
+  chaos: Chaos,
+}
+
+impl<K> ChaosJwkStorage<K> {
+  /// Wraps `inner`, degrading its behaviour according to `config`.
+  pub fn new(inner: K, config: ChaosConfig) -> Self {
+    Self {
+      inner,
+      chaos: Chaos::new(config),
+    }
+  }
+
+  /// Returns a reference to the wrapped storage.
+  pub fn inner(&self) -> &K {
+    &self.inner
+  }
+
+  /// Returns the wrapped storage.
+  pub fn into_inner(self) -> K {
+    self.inner
+  }
+
+  /// Returns the configuration of this wrapper.
+  pub fn config(&self) -> &ChaosConfig {
+    self.chaos.config()
+  }
+
+  /// Returns the number of operations that were made to fail, fully or partially.
+  pub fn injected_faults(&self) -> usize {
+    self.chaos.injected_faults()
+  }
+
+  async fn before_read(&self) -> KeyStorageResult<()> {
+    match self.chaos.before_operation(false).await {
+      Some(_) => Err(failure()),
+      None => Ok(()),
+    }
+  }
+
+  async fn write<T>(&self, operation: impl std::future::Future<Output = KeyStorageResult<T>>) -> KeyStorageResult<T> {
+    match self.chaos.before_operation(true).await {
+      Some(Fault::Failure) => Err(failure()),
+      Some(Fault::PartialWrite) => operation.await.and_then(|_| {
+        Err(
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message("injected partial write failure: the operation was applied"),
+        )
+      }),
+      None => operation.await,
+    }
+  }
+}
+
+fn failure() -> KeyStorageError {
+  KeyStorageError::new(KeyStorageErrorKind::RetryableIOFailure).with_custom_message("injected failure")
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorage> JwkStorage for ChaosJwkStorage<K> {
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    self.write(self.inner.generate(key_type, alg)).await
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    self.write(self.inner.insert(jwk)).await
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    self.before_read().await?;
+    self.inner.sign(key_id, data, public_key).await
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    self.write(self.inner.delete(key_id)).await
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    self.before_read().await?;
+    self.inner.exists(key_id).await
+  }
+}
+
+#[cfg(all(test, feature = "memstore"))]
+mod tests {
+  use crate::key_storage::JwkMemStore;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn partial_writes_are_applied() {
+    let storage = ChaosJwkStorage::new(JwkMemStore::new(), ChaosConfig::new().partial_write_rate(1.0));
+    let err = storage
+      .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::Unspecified));
+    assert_eq!(storage.inner().count().await, 1);
+    assert_eq!(storage.injected_faults(), 1);
+
+    // Reads are not affected by partial write failures.
+    assert!(!storage.exists(&KeyId::new("missing")).await.unwrap());
+
+    let storage = ChaosJwkStorage::new(storage.into_inner(), ChaosConfig::new().failure_rate(1.0));
+    let err = storage
+      .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::RetryableIOFailure));
+    assert_eq!(storage.inner().count().await, 1);
+  }
+}
@@ -0,0 +1,95 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::config::Chaos;
+use super::config::Fault;
+use super::ChaosConfig;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageError;
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_id_storage::KeyIdStorageResult;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::KeyId;
+
+/// A [`KeyIdStorage`] wrapper that delays and randomly fails the operations of the wrapped storage, as configured by
+/// a [`ChaosConfig`].
+///
+/// Failed operations return a [`KeyIdStorageErrorKind::RetryableIOFailure`] without reaching the wrapped storage.
+/// Partially failed write operations - [`KeyIdStorage::insert_key_id`] and [`KeyIdStorage::delete_key_id`] - are
+/// applied by the wrapped storage, but return a [`KeyIdStorageErrorKind::Unspecified`] error.
+#[derive(Debug)]
+pub struct ChaosKeyIdStorage<I> {
+  inner: I,
+  chaos: Chaos,
+}
+
+impl<I> ChaosKeyIdStorage<I> {
+  /// Wraps `inner`, degrading its behaviour according to `config`.
+  pub fn new(inner: I, config: ChaosConfig) -> Self {
+    Self {
+      inner,
+      chaos: Chaos::new(config),
+    }
+  }
+
+  /// Returns a reference to the wrapped storage.
+  pub fn inner(&self) -> &I {
+    &self.inner
+  }
+
+  /// Returns the wrapped storage.
+  pub fn into_inner(self) -> I {
+    self.inner
+  }
+
+  /// Returns the configuration of this wrapper.
+  pub fn config(&self) -> &ChaosConfig {
+    self.chaos.config()
+  }
+
+  /// Returns the number of operations that were made to fail, fully or partially.
+  pub fn injected_faults(&self) -> usize {
+    self.chaos.injected_faults()
+  }
+
+  async fn write(
+    &self,
+    operation: impl std::future::Future<Output = KeyIdStorageResult<()>>,
+  ) -> KeyIdStorageResult<()> {
+    match self.chaos.before_operation(true).await {
+      Some(Fault::Failure) => Err(failure()),
+      Some(Fault::PartialWrite) => operation.await.and_then(|_| {
+        Err(
+          KeyIdStorageError::new(KeyIdStorageErrorKind::Unspecified)
+            .with_custom_message("injected partial write failure: the operation was applied"),
+        )
+      }),
+      None => operation.await,
+    }
+  }
+}
+
+fn failure() -> KeyIdStorageError {
+  KeyIdStorageError::new(KeyIdStorageErrorKind::RetryableIOFailure).with_custom_message("injected failure")
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<I: KeyIdStorage> KeyIdStorage for ChaosKeyIdStorage<I> {
+  async fn insert_key_id(&self, method_digest: MethodDigest, key_id: KeyId) -> KeyIdStorageResult<()> {
+    self.write(self.inner.insert_key_id(method_digest, key_id)).await
+  }
+
+  async fn get_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    if self.chaos.before_operation(false).await.is_some() {
+      return Err(failure());
+    }
+    self.inner.get_key_id(method_digest).await
+  }
+
+  async fn delete_key_id(&self, method_digest: &MethodDigest) -> KeyIdStorageResult<()> {
+    self.write(self.inner.delete_key_id(method_digest)).await
+  }
+}
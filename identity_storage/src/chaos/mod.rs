@@ -0,0 +1,17 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Storage wrappers that degrade the behaviour of the storages they wrap, for testing purposes.
+//!
+//! [`ChaosJwkStorage`] and [`ChaosKeyIdStorage`] forward every operation to the wrapped storage, after an optional
+//! random delay, and make a random share of those operations fail as configured through a [`ChaosConfig`]. Combined
+//! into a [`Storage`](crate::Storage), they can be used to check that the code working with it - e.g. the rollback
+//! logic of [`JwkDocumentExt`](crate::JwkDocumentExt) - behaves correctly when storages are unreliable.
+
+mod config;
+mod jwk_storage;
+mod key_id_storage;
+
+pub use config::*;
+pub use jwk_storage::*;
+pub use key_id_storage::*;
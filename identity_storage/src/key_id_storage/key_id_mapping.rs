@@ -0,0 +1,81 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::VersionedSchema;
+use identity_verification::jwu::decode_b64;
+use identity_verification::jwu::encode_b64;
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::key_storage::KeyId;
+
+use super::MethodDigest;
+
+/// An entry of a [`KeyIdStorage`](super::KeyIdStorage), mapping a method to the [`KeyId`] of its key.
+///
+/// Implements [`VersionedSchema`], allowing the mappings of a [`KeyIdStorage`](super::KeyIdStorage) to be exported
+/// with [`ToVersionedJson`](identity_core::convert::ToVersionedJson) and imported by later releases through
+/// [`FromVersionedJson`](identity_core::convert::FromVersionedJson).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyIdMapping {
+  /// The digest of the method, base64url-encoded in its [packed](MethodDigest::pack) form.
+  #[serde(serialize_with = "serialize_digest", deserialize_with = "deserialize_digest")]
+  pub method_digest: MethodDigest,
+  /// The identifier of the method's key.
+  pub key_id: KeyId,
+}
+
+impl KeyIdMapping {
+  /// Creates a new [`KeyIdMapping`].
+  pub fn new(method_digest: MethodDigest, key_id: KeyId) -> Self {
+    Self { method_digest, key_id }
+  }
+}
+
+impl VersionedSchema for KeyIdMapping {
+  const KIND: &'static str = "KeyIdMapping";
+  const VERSION: u16 = 1;
+}
+
+fn serialize_digest<S: Serializer>(digest: &MethodDigest, serializer: S) -> Result<S::Ok, S::Error> {
+  serializer.serialize_str(&encode_b64(digest.pack()))
+}
+
+fn deserialize_digest<'de, D: Deserializer<'de>>(deserializer: D) -> Result<MethodDigest, D::Error> {
+  let encoded = String::deserialize(deserializer)?;
+  let packed: Vec<u8> = decode_b64(&encoded).map_err(D::Error::custom)?;
+  MethodDigest::unpack(packed).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromVersionedJson;
+  use identity_core::convert::ToVersionedJson;
+  use identity_core::convert::VERSIONED_JSON_MEDIA_TYPE;
+  use identity_core::json;
+  use serde_json::Value;
+
+  use super::*;
+
+  #[test]
+  fn versioned_round_trip() {
+    let packed: Vec<u8> = vec![0, 255, 212, 82, 63, 57, 19, 134, 193];
+    let mapping = KeyIdMapping::new(MethodDigest::unpack(packed.clone()).unwrap(), KeyId::new("key-1"));
+
+    let json: Vec<u8> = mapping.to_versioned_json().unwrap();
+    assert_eq!(
+      serde_json::from_slice::<Value>(&json).unwrap(),
+      json!({
+        "typ": VERSIONED_JSON_MEDIA_TYPE,
+        "kind": "KeyIdMapping",
+        "version": 1,
+        "data": { "methodDigest": encode_b64(packed), "keyId": "key-1" }
+      })
+    );
+    assert_eq!(KeyIdMapping::from_versioned_json(&json).unwrap(), mapping);
+  }
+}
@@ -0,0 +1,18 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::KeyIdMapping;
+use super::KeyIdStorage;
+use super::KeyIdStorageResult;
+
+/// Extension to the [`KeyIdStorage`] for storages able to enumerate the mappings they hold.
+///
+/// Required for maintenance tasks such as [`KeyGarbageCollector`](crate::storage::KeyGarbageCollector).
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait KeyIdStorageListing: KeyIdStorage {
+  /// Returns all [`KeyIdMapping`]s held by the storage, in no particular order.
+  async fn list_key_id_mappings(&self) -> KeyIdStorageResult<Vec<KeyIdMapping>>;
+}
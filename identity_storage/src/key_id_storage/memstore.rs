@@ -4,6 +4,9 @@
 use crate::key_id_storage::key_id_storage::KeyIdStorage;
 use crate::key_id_storage::key_id_storage_error::KeyIdStorageError;
 use crate::key_id_storage::key_id_storage_error::KeyIdStorageErrorKind;
+use crate::key_id_storage::KeyIdMapping;
+use crate::key_id_storage::KeyIdStorageListing;
+use crate::key_storage::shared::FailNext;
 use crate::key_storage::shared::Shared;
 use crate::key_storage::KeyId;
 use async_trait::async_trait;
@@ -17,16 +20,25 @@ use super::method_digest::MethodDigest;
 type KeyIdStore = HashMap<MethodDigest, KeyId>;
 
 /// An insecure, in-memory [`KeyIdStorage`] implementation that serves as an example and may be used in tests.
+///
+/// Like [`JwkMemStore`](crate::key_storage::JwkMemStore), a `KeyIdMemstore` can be reset to a previous state and can
+/// be made to fail, see [`KeyIdMemstore::snapshot`] and [`KeyIdMemstore::fail_next`].
 #[derive(Debug)]
 pub struct KeyIdMemstore {
   key_id_store: Shared<KeyIdStore>,
+  faults: FailNext<KeyIdStorageErrorKind>,
 }
 
+/// The key ids held by a [`KeyIdMemstore`] at a given point in time, see [`KeyIdMemstore::snapshot`].
+#[derive(Debug, Clone)]
+pub struct KeyIdMemstoreSnapshot(KeyIdStore);
+
 impl KeyIdMemstore {
   /// Creates a new, empty `KeyIdMemstore` instance.
   pub fn new() -> Self {
     Self {
       key_id_store: Shared::new(HashMap::new()),
+      faults: FailNext::default(),
     }
   }
 
@@ -34,6 +46,34 @@ impl KeyIdMemstore {
   pub async fn count(&self) -> usize {
     self.key_id_store.read().await.keys().count()
   }
+
+  /// Returns a copy of the key ids currently held by this [`KeyIdMemstore`].
+  pub async fn snapshot(&self) -> KeyIdMemstoreSnapshot {
+    KeyIdMemstoreSnapshot(self.key_id_store.read().await.clone())
+  }
+
+  /// Replaces the key ids held by this [`KeyIdMemstore`] with the ones of `snapshot`.
+  pub async fn restore(&self, snapshot: &KeyIdMemstoreSnapshot) {
+    *self.key_id_store.write().await = snapshot.0.clone();
+  }
+
+  /// Makes the next `count` [`KeyIdStorage`] operations fail with an error of the given `kind`, without touching the
+  /// stored key ids. Replaces any fault set previously.
+  pub fn fail_next(&self, count: usize, kind: KeyIdStorageErrorKind) {
+    self.faults.set(count, kind);
+  }
+
+  /// Cancels the faults set through [`KeyIdMemstore::fail_next`] that have not been triggered yet.
+  pub fn clear_faults(&self) {
+    self.faults.clear();
+  }
+
+  fn check_faults(&self) -> KeyIdStorageResult<()> {
+    match self.faults.take() {
+      Some(kind) => Err(KeyIdStorageError::new(kind).with_custom_message("injected fault")),
+      None => Ok(()),
+    }
+  }
 }
 
 impl Default for KeyIdMemstore {
@@ -46,6 +86,7 @@ impl Default for KeyIdMemstore {
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
 impl KeyIdStorage for KeyIdMemstore {
   async fn insert_key_id(&self, key: MethodDigest, value: KeyId) -> KeyIdStorageResult<()> {
+    self.check_faults()?;
     let mut key_id_store: RwLockWriteGuard<'_, KeyIdStore> = self.key_id_store.write().await;
     if key_id_store.contains_key(&key) {
       return Err(KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdAlreadyExists));
@@ -55,6 +96,7 @@ impl KeyIdStorage for KeyIdMemstore {
   }
 
   async fn get_key_id(&self, key: &MethodDigest) -> KeyIdStorageResult<KeyId> {
+    self.check_faults()?;
     let key_id_store: RwLockReadGuard<'_, KeyIdStore> = self.key_id_store.read().await;
     Ok(
       key_id_store
@@ -65,6 +107,7 @@ impl KeyIdStorage for KeyIdMemstore {
   }
 
   async fn delete_key_id(&self, key: &MethodDigest) -> KeyIdStorageResult<()> {
+    self.check_faults()?;
     let mut key_id_store: RwLockWriteGuard<'_, KeyIdStore> = self.key_id_store.write().await;
     key_id_store
       .remove(key)
@@ -73,6 +116,21 @@ impl KeyIdStorage for KeyIdMemstore {
   }
 }
 
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(? Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl KeyIdStorageListing for KeyIdMemstore {
+  async fn list_key_id_mappings(&self) -> KeyIdStorageResult<Vec<KeyIdMapping>> {
+    self.check_faults()?;
+    let key_id_store: RwLockReadGuard<'_, KeyIdStore> = self.key_id_store.read().await;
+    Ok(
+      key_id_store
+        .iter()
+        .map(|(method_digest, key_id)| KeyIdMapping::new(method_digest.clone(), key_id.clone()))
+        .collect(),
+    )
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::key_id_storage::key_id_storage::KeyIdStorage;
@@ -112,4 +170,27 @@ mod tests {
     let _expected_error: KeyIdStorageError = KeyIdStorageError::new(KeyIdStorageErrorKind::KeyIdNotFound);
     assert!(matches!(repeat_deletion_result.unwrap_err(), _expected_error));
   }
+
+  #[tokio::test]
+  async fn memstore_snapshot_and_faults() {
+    let verification_method: VerificationMethod = crate::storage::tests::test_utils::create_verification_method();
+    let method_digest: MethodDigest = MethodDigest::new(&verification_method).unwrap();
+    let memstore: KeyIdMemstore = KeyIdMemstore::new();
+    let snapshot = memstore.snapshot().await;
+
+    memstore.fail_next(1, KeyIdStorageErrorKind::Unavailable);
+    let err: KeyIdStorageError = memstore
+      .insert_key_id(method_digest.clone(), KeyId::new("keyid"))
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyIdStorageErrorKind::Unavailable));
+    assert_eq!(memstore.count().await, 0);
+
+    memstore
+      .insert_key_id(method_digest.clone(), KeyId::new("keyid"))
+      .await
+      .unwrap();
+    memstore.restore(&snapshot).await;
+    assert!(memstore.get_key_id(&method_digest).await.is_err());
+  }
 }
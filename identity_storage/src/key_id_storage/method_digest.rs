@@ -45,6 +45,12 @@ pub struct MethodDigest {
 }
 
 impl MethodDigest {
+  /// The version of the digest formula used by [`MethodDigest::new`].
+  ///
+  /// It is the first byte of a [packed](MethodDigest::pack) digest. Digests persisted by older releases keep their
+  /// version, so that they can still be [unpacked](MethodDigest::unpack).
+  pub const CURRENT_VERSION: u8 = 0;
+
   /// Creates a new [`MethodDigest`].
   pub fn new(verification_method: &VerificationMethod) -> Result<Self, MethodDigestConstructionError> {
     // Method digest version 0 formula: SeaHash(<fragment><JWK thumbprint if JWK else decoded public key>)
@@ -76,7 +82,7 @@ impl MethodDigest {
     let key_hash: u64 = hasher.finish();
 
     Ok(Self {
-      version: 0,
+      version: Self::CURRENT_VERSION,
       value: key_hash,
     })
   }
@@ -94,7 +100,7 @@ impl MethodDigest {
       return Err(KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError));
     }
     let version: u8 = bytes[0];
-    if version != 0 {
+    if version > Self::CURRENT_VERSION {
       return Err(KeyIdStorageError::new(super::KeyIdStorageErrorKind::SerializationError));
     }
     let value_le_bytes: [u8; 8] = bytes[1..9]
@@ -103,6 +109,11 @@ impl MethodDigest {
     let value: u64 = u64::from_le_bytes(value_le_bytes);
     Ok(Self { version, value })
   }
+
+  /// Returns the version of the digest formula used to compute this [`MethodDigest`].
+  pub fn version(&self) -> u8 {
+    self.version
+  }
 }
 
 #[cfg(test)]
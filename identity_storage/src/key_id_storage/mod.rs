@@ -9,8 +9,10 @@
 //! to its [`KeyId`](crate::key_storage::KeyId).
 
 #[allow(clippy::module_inception)]
+mod key_id_mapping;
 mod key_id_storage;
 mod key_id_storage_error;
+mod key_id_storage_listing;
 mod method_digest;
 
 #[cfg(feature = "keytool")]
@@ -21,8 +23,10 @@ mod memstore;
 #[cfg(test)]
 mod tests;
 
+pub use key_id_mapping::*;
 pub use key_id_storage::*;
 pub use key_id_storage_error::*;
+pub use key_id_storage_listing::*;
 #[cfg(feature = "memstore")]
 pub use memstore::*;
 pub use method_digest::*;
@@ -0,0 +1,21 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_verification::jwk::Jwk;
+
+use super::JwkStorage;
+use super::KeyId;
+use super::KeyStorageResult;
+
+/// Extension to the [`JwkStorage`] for storages allowing their private keys to be exported, e.g. for backups.
+///
+/// Storages whose keys must never leave them, such as hardware security modules, shouldn't implement this trait.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageKeyExport: JwkStorage {
+  /// Returns the key with the given `key_id`, including its private components.
+  ///
+  /// The returned key can be put back into a storage through [`JwkStorage::insert`].
+  async fn export_key(&self, key_id: &KeyId) -> KeyStorageResult<Jwk>;
+}
@@ -0,0 +1,18 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::JwkStorage;
+use super::KeyId;
+use super::KeyStorageResult;
+
+/// Extension to the [`JwkStorage`] for storages able to enumerate the keys they hold.
+///
+/// Required for maintenance tasks such as [`KeyGarbageCollector`](crate::storage::KeyGarbageCollector).
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageListing: JwkStorage {
+  /// Returns the [`KeyId`]s of all keys held by the storage, in no particular order.
+  async fn list_key_ids(&self) -> KeyStorageResult<Vec<KeyId>>;
+}
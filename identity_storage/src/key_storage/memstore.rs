@@ -19,6 +19,7 @@ use identity_verification::jwk::BlsCurve;
 use identity_verification::jwk::FromJwk as _;
 use identity_verification::jwk::ToJwk as _;
 use rand::distributions::DistString;
+use shared::FailNext;
 use shared::Shared;
 use tokio::sync::RwLockReadGuard;
 use tokio::sync::RwLockWriteGuard;
@@ -30,21 +31,61 @@ use super::KeyStorageErrorKind;
 use super::KeyStorageResult;
 use super::KeyType;
 use crate::key_storage::JwkStorage;
+use crate::key_storage::JwkStorageKeyExport;
+use crate::key_storage::JwkStorageListing;
 
 /// The map from key ids to JWKs.
 type JwkKeyStore = HashMap<KeyId, Jwk>;
 
 /// An insecure, in-memory [`JwkStorage`] implementation that serves as an example and may be used in tests.
+///
+/// Besides storing keys, a `JwkMemStore` can be reset to a previous state through [`JwkMemStore::snapshot`] and
+/// [`JwkMemStore::restore`], and can be made to fail through [`JwkMemStore::fail_next`], so that error paths of
+/// the code using it can be tested.
 #[derive(Debug)]
 pub struct JwkMemStore {
   jwk_store: Shared<JwkKeyStore>,
+  faults: FailNext<KeyStorageErrorKind>,
 }
 
+/// The keys held by a [`JwkMemStore`] at a given point in time, see [`JwkMemStore::snapshot`].
+#[derive(Debug, Clone)]
+pub struct JwkMemStoreSnapshot(JwkKeyStore);
+
 impl JwkMemStore {
   /// Creates a new, empty `JwkMemStore` instance.
   pub fn new() -> Self {
     Self {
       jwk_store: Shared::new(HashMap::new()),
+      faults: FailNext::default(),
+    }
+  }
+
+  /// Returns a copy of the keys currently held by this [`JwkMemStore`].
+  pub async fn snapshot(&self) -> JwkMemStoreSnapshot {
+    JwkMemStoreSnapshot(self.jwk_store.read().await.clone())
+  }
+
+  /// Replaces the keys held by this [`JwkMemStore`] with the ones of `snapshot`.
+  pub async fn restore(&self, snapshot: &JwkMemStoreSnapshot) {
+    *self.jwk_store.write().await = snapshot.0.clone();
+  }
+
+  /// Makes the next `count` storage operations - the methods of [`JwkStorage`] and its extension traits - fail with
+  /// an error of the given `kind`, without touching the stored keys. Replaces any fault set previously.
+  pub fn fail_next(&self, count: usize, kind: KeyStorageErrorKind) {
+    self.faults.set(count, kind);
+  }
+
+  /// Cancels the faults set through [`JwkMemStore::fail_next`] that have not been triggered yet.
+  pub fn clear_faults(&self) {
+    self.faults.clear();
+  }
+
+  fn check_faults(&self) -> KeyStorageResult<()> {
+    match self.faults.take() {
+      Some(kind) => Err(KeyStorageError::new(kind).with_custom_message("injected fault")),
+      None => Ok(()),
     }
   }
 
@@ -71,6 +112,7 @@ impl JwkMemStore {
 #[cfg_attr(feature = "send-sync-storage", async_trait)]
 impl JwkStorage for JwkMemStore {
   async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    self.check_faults()?;
     let key_type: MemStoreKeyType = MemStoreKeyType::try_from(&key_type)?;
 
     check_key_alg_compatibility(key_type, &alg)?;
@@ -103,6 +145,7 @@ impl JwkStorage for JwkMemStore {
   }
 
   async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    self.check_faults()?;
     let key_type = MemStoreKeyType::try_from(&jwk)?;
 
     if !jwk.is_private() {
@@ -143,6 +186,7 @@ impl JwkStorage for JwkMemStore {
   }
 
   async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    self.check_faults()?;
     let jwk_store: RwLockReadGuard<'_, JwkKeyStore> = self.jwk_store.read().await;
 
     // Extract the required alg from the given public key
@@ -191,6 +235,7 @@ impl JwkStorage for JwkMemStore {
   }
 
   async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    self.check_faults()?;
     let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
 
     jwk_store
@@ -200,11 +245,36 @@ impl JwkStorage for JwkMemStore {
   }
 
   async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    self.check_faults()?;
     let jwk_store: RwLockReadGuard<'_, JwkKeyStore> = self.jwk_store.read().await;
     Ok(jwk_store.contains_key(key_id))
   }
 }
 
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorageListing for JwkMemStore {
+  async fn list_key_ids(&self) -> KeyStorageResult<Vec<KeyId>> {
+    self.check_faults()?;
+    Ok(self.jwk_store.read().await.keys().cloned().collect())
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl JwkStorageKeyExport for JwkMemStore {
+  async fn export_key(&self, key_id: &KeyId) -> KeyStorageResult<Jwk> {
+    self.check_faults()?;
+    self
+      .jwk_store
+      .read()
+      .await
+      .get(key_id)
+      .cloned()
+      .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))
+  }
+}
+
 #[derive(Debug, Copy, Clone)]
 enum MemStoreKeyType {
   Ed25519,
@@ -382,6 +452,7 @@ mod pqc_liboqs {
   #[cfg_attr(feature = "send-sync-storage", async_trait)]
   impl JwkStoragePQ for JwkMemStore {
     async fn generate_pq_key(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+      self.check_faults()?;
       if key_type != JwkMemStore::PQ_KEY_TYPE {
         return Err(
           KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
@@ -465,6 +536,7 @@ mod pqc_liboqs {
       public_key: &PostQuantumJwk,
       ctx: Option<&[u8]>,
     ) -> KeyStorageResult<Vec<u8>> {
+      self.check_faults()?;
       let jwk_store: RwLockReadGuard<'_, JwkKeyStore> = self.jwk_store.read().await;
 
       // Extract the required alg from the given public key
@@ -597,6 +669,7 @@ mod bbs_plus_impl {
   #[cfg_attr(feature = "send-sync-storage", async_trait)]
   impl JwkStorageBbsPlusExt for JwkMemStore {
     async fn generate_bbs(&self, key_type: KeyType, alg: ProofAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+      self.check_faults()?;
       if key_type != JwkMemStore::BLS12381G2_KEY_TYPE {
         return Err(
           KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
@@ -621,6 +694,7 @@ mod bbs_plus_impl {
       header: &[u8],
       public_key: &Jwk,
     ) -> KeyStorageResult<Vec<u8>> {
+      self.check_faults()?;
       let jwk_store = self.jwk_store.read().await;
 
       // Extract the required alg from the given public key
@@ -655,6 +729,7 @@ mod bbs_plus_impl {
       signature: &[u8],
       ctx: ProofUpdateCtx,
     ) -> KeyStorageResult<Vec<u8>> {
+      self.check_faults()?;
       let jwk_store = self.jwk_store.read().await;
 
       // Extract the required alg from the given public key
@@ -689,6 +764,9 @@ mod bbs_plus_impl {
 pub(crate) mod shared {
   use core::fmt::Debug;
   use core::fmt::Formatter;
+  use std::sync::Mutex;
+  use std::sync::MutexGuard;
+  use std::sync::PoisonError;
   use tokio::sync::RwLock;
   use tokio::sync::RwLockReadGuard;
   use tokio::sync::RwLockWriteGuard;
@@ -696,6 +774,10 @@ pub(crate) mod shared {
   #[derive(Default)]
   pub(crate) struct Shared<T>(RwLock<T>);
 
+  /// A fault making the next `count` operations of a storage fail with an error of kind `K`.
+  #[derive(Debug)]
+  pub(crate) struct FailNext<K>(Mutex<Option<(usize, K)>>);
+
   impl<T> Shared<T> {
     pub(crate) fn new(data: T) -> Self {
       Self(RwLock::new(data))
@@ -710,6 +792,40 @@ pub(crate) mod shared {
     }
   }
 
+  impl<K> Default for FailNext<K> {
+    fn default() -> Self {
+      Self(Mutex::new(None))
+    }
+  }
+
+  impl<K: Clone> FailNext<K> {
+    pub(crate) fn set(&self, count: usize, kind: K) {
+      *self.lock() = (count > 0).then_some((count, kind));
+    }
+
+    pub(crate) fn clear(&self) {
+      *self.lock() = None;
+    }
+
+    /// Consumes one failure, returning the kind of error to fail with.
+    pub(crate) fn take(&self) -> Option<K> {
+      let mut fault = self.lock();
+      let (count, kind) = fault.as_mut()?;
+      let kind = kind.clone();
+      *count -= 1;
+      if *count == 0 {
+        *fault = None;
+      }
+
+      Some(kind)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<(usize, K)>> {
+      // The lock is never held across an await point or a panic, poisoning can be ignored.
+      self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+  }
+
   impl<T: Debug> Debug for Shared<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
       Debug::fmt(&self.0, f)
@@ -769,6 +885,29 @@ mod tests {
     assert!(matches!(err.kind(), KeyStorageErrorKind::Unspecified))
   }
 
+  #[tokio::test]
+  async fn snapshot_and_faults() {
+    let store: JwkMemStore = JwkMemStore::new();
+    let snapshot = store.snapshot().await;
+    let JwkGenOutput { key_id, .. } = store
+      .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+      .await
+      .unwrap();
+
+    store.fail_next(2, KeyStorageErrorKind::RetryableIOFailure);
+    for _ in 0..2 {
+      let err = store.exists(&key_id).await.unwrap_err();
+      assert!(matches!(err.kind(), KeyStorageErrorKind::RetryableIOFailure));
+    }
+    assert!(store.exists(&key_id).await.unwrap());
+
+    store.fail_next(1, KeyStorageErrorKind::Unavailable);
+    store.clear_faults();
+    store.restore(&snapshot).await;
+    assert!(!store.exists(&key_id).await.unwrap());
+    assert_eq!(store.count().await, 0);
+  }
+
   #[tokio::test]
   async fn exists() {
     let store: JwkMemStore = JwkMemStore::new();
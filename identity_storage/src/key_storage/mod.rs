@@ -13,6 +13,8 @@ mod jwk_gen_output;
 mod jwk_storage;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwk_storage_bbs_plus_ext;
+mod jwk_storage_key_export;
+mod jwk_storage_listing;
 #[cfg(feature = "pqc")]
 mod jwk_storage_pqc;
 mod key_id;
@@ -32,6 +34,8 @@ pub mod public_modules {
   pub use super::jwk_storage::*;
   #[cfg(feature = "jpt-bbs-plus")]
   pub use super::jwk_storage_bbs_plus_ext::*;
+  pub use super::jwk_storage_key_export::*;
+  pub use super::jwk_storage_listing::*;
   #[cfg(feature = "pqc")]
   pub use super::jwk_storage_pqc::*;
   pub use super::key_id::*;
@@ -14,10 +14,16 @@
   clippy::missing_safety_doc
 )]
 
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod key_id_storage;
 pub mod key_storage;
+#[cfg(feature = "status-list-2021")]
+pub mod status_list_index;
 pub mod storage;
 
 pub use key_id_storage::*;
 pub use key_storage::public_modules::*;
+#[cfg(feature = "status-list-2021")]
+pub use status_list_index::*;
 pub use storage::*;
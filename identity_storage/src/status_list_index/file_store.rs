@@ -0,0 +1,257 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs;
+use std::io::ErrorKind;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::IndexAllocation;
+use super::IndexAllocator;
+use super::IndexAllocatorConfig;
+use super::IndexAllocatorError;
+use super::IndexAllocatorErrorKind;
+use super::IndexAllocatorResult;
+use super::IndexAllocatorState;
+use super::StatusListIndex;
+use super::StatusListUsage;
+
+/// An [`IndexAllocator`] persisting its state to a JSON file.
+///
+/// Every allocation is appended to a log next to the state file - `<path>.log` - before it is returned,
+/// so that no entry is handed out twice even if the process crashes. The log is compacted into the state file,
+/// atomically replacing it, when the allocator is opened and every [`FileIndexAllocator::COMPACTION_THRESHOLD`]
+/// allocations. The files must not be shared by more than one allocator at a time.
+///
+/// File operations are blocking and happen on the calling task.
+#[derive(Debug)]
+pub struct FileIndexAllocator {
+  path: PathBuf,
+  inner: Mutex<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+  state: IndexAllocatorState,
+  log: fs::File,
+  /// The number of allocations appended to the log since it was last compacted.
+  logged: usize,
+}
+
+/// An allocation, as appended to the log.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoggedAllocation {
+  credential_id: String,
+  index: StatusListIndex,
+}
+
+impl FileIndexAllocator {
+  /// The number of allocations after which the log is compacted into the state file.
+  pub const COMPACTION_THRESHOLD: usize = 1024;
+
+  /// Opens the allocator persisted at `path`, creating it with `config` if the file doesn't exist.
+  ///
+  /// # Errors
+  /// Fails if the persisted state was created with a different configuration or is inconsistent.
+  pub fn open(path: impl AsRef<Path>, config: IndexAllocatorConfig) -> IndexAllocatorResult<Self> {
+    let path = path.as_ref().to_owned();
+    let mut state = match fs::read(&path) {
+      Ok(bytes) => {
+        let state: IndexAllocatorState = serde_json::from_slice(&bytes).map_err(|err| {
+          IndexAllocatorError::new(IndexAllocatorErrorKind::SerializationError)
+            .with_custom_message("cannot deserialize the persisted allocator state")
+            .with_source(err)
+        })?;
+        if state.config() != &config {
+          return Err(
+            IndexAllocatorError::new(IndexAllocatorErrorKind::InconsistentState)
+              .with_custom_message("the persisted allocator state was created with a different configuration"),
+          );
+        }
+        state
+      }
+      Err(err) if err.kind() == ErrorKind::NotFound => IndexAllocatorState::new(config)?,
+      Err(err) => return Err(io_error(err)),
+    };
+
+    let log_path = log_path(&path);
+    let log = match fs::read(&log_path) {
+      Ok(bytes) => bytes,
+      Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+      Err(err) => return Err(io_error(err)),
+    };
+    // A trailing incomplete line is an allocation that was interrupted before being returned.
+    let complete = log
+      .iter()
+      .rposition(|byte| *byte == b'\n')
+      .map_or(&[][..], |end| &log[..end]);
+    for line in complete.split(|byte| *byte == b'\n').filter(|line| !line.is_empty()) {
+      let LoggedAllocation { credential_id, index } = serde_json::from_slice(line).map_err(|err| {
+        IndexAllocatorError::new(IndexAllocatorErrorKind::SerializationError)
+          .with_custom_message("cannot deserialize a logged allocation")
+          .with_source(err)
+      })?;
+      state.record(&credential_id, index)?;
+    }
+
+    persist(&path, &state)?;
+    let log = create_log(&log_path)?;
+
+    Ok(Self {
+      path,
+      inner: Mutex::new(Inner { state, log, logged: 0 }),
+    })
+  }
+
+  /// Returns the path of the file the allocator's state is persisted to.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  fn lock(&self) -> IndexAllocatorResult<std::sync::MutexGuard<'_, Inner>> {
+    self.inner.lock().map_err(|_| {
+      IndexAllocatorError::new(IndexAllocatorErrorKind::Unavailable)
+        .with_custom_message("the allocator state lock is poisoned")
+    })
+  }
+
+  /// Writes the state to the state file and empties the log.
+  fn compact(&self, inner: &mut Inner) -> IndexAllocatorResult<()> {
+    persist(&self.path, &inner.state)?;
+    inner.log = create_log(&log_path(&self.path))?;
+    inner.logged = 0;
+
+    Ok(())
+  }
+}
+
+#[async_trait]
+impl IndexAllocator for FileIndexAllocator {
+  async fn allocate(&self, credential_id: &str) -> IndexAllocatorResult<IndexAllocation> {
+    let mut inner = self.lock()?;
+    if let Some(index) = inner.state.get_index(credential_id) {
+      return Ok(IndexAllocation {
+        index: index.clone(),
+        new_status_list: false,
+      });
+    }
+
+    // Only record the allocation in memory once it has been persisted.
+    let allocation = inner.state.draw()?;
+    let mut line = serde_json::to_vec(&LoggedAllocation {
+      credential_id: credential_id.to_owned(),
+      index: allocation.index.clone(),
+    })
+    .map_err(|err| {
+      IndexAllocatorError::new(IndexAllocatorErrorKind::SerializationError)
+        .with_custom_message("cannot serialize the allocation")
+        .with_source(err)
+    })?;
+    line.push(b'\n');
+    inner.log.write_all(&line).map_err(io_error)?;
+    inner.log.sync_data().map_err(io_error)?;
+    inner.state.record(credential_id, allocation.index.clone())?;
+
+    inner.logged += 1;
+    if inner.logged >= Self::COMPACTION_THRESHOLD {
+      // The allocation is already persisted in the log: a failed compaction is retried on the next allocation.
+      let _ = self.compact(&mut inner);
+    }
+
+    Ok(allocation)
+  }
+
+  async fn get_index(&self, credential_id: &str) -> IndexAllocatorResult<Option<StatusListIndex>> {
+    Ok(self.lock()?.state.get_index(credential_id).cloned())
+  }
+
+  async fn status_lists(&self) -> IndexAllocatorResult<Vec<StatusListUsage>> {
+    Ok(self.lock()?.state.status_lists().to_vec())
+  }
+}
+
+fn log_path(path: &Path) -> PathBuf {
+  let mut log_path = path.as_os_str().to_owned();
+  log_path.push(".log");
+  PathBuf::from(log_path)
+}
+
+/// Creates an empty log at `path`, replacing the existing one.
+fn create_log(path: &Path) -> IndexAllocatorResult<fs::File> {
+  fs::File::create(path).map_err(io_error)
+}
+
+/// Writes `state` to a temporary file and renames it to `path`, so that `path` always contains a complete state.
+fn persist(path: &Path, state: &IndexAllocatorState) -> IndexAllocatorResult<()> {
+  let bytes = serde_json::to_vec(state).map_err(|err| {
+    IndexAllocatorError::new(IndexAllocatorErrorKind::SerializationError)
+      .with_custom_message("cannot serialize the allocator state")
+      .with_source(err)
+  })?;
+
+  let mut tmp_path = path.as_os_str().to_owned();
+  tmp_path.push(".tmp");
+  let tmp_path = PathBuf::from(tmp_path);
+
+  let mut file = fs::File::create(&tmp_path).map_err(io_error)?;
+  file.write_all(&bytes).map_err(io_error)?;
+  file.sync_all().map_err(io_error)?;
+  fs::rename(&tmp_path, path).map_err(io_error)
+}
+
+fn io_error(err: std::io::Error) -> IndexAllocatorError {
+  IndexAllocatorError::new(IndexAllocatorErrorKind::RetryableIOFailure).with_source(err)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+
+  use super::*;
+
+  fn config() -> IndexAllocatorConfig {
+    IndexAllocatorConfig::new(Url::parse("https://example.com/status/").unwrap()).capacity(2)
+  }
+
+  #[tokio::test]
+  async fn allocations_survive_restarts() {
+    let path = std::env::temp_dir().join(format!("index_allocator_{}.json", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let _ = fs::remove_file(log_path(&path));
+
+    let allocator = FileIndexAllocator::open(&path, config()).unwrap();
+    let first = allocator.allocate("credential-1").await.unwrap();
+    allocator.allocate("credential-2").await.unwrap();
+    // Allocations are only appended to the log until it is compacted.
+    assert_eq!(fs::read_to_string(log_path(&path)).unwrap().lines().count(), 2);
+    drop(allocator);
+
+    // An interrupted allocation is ignored.
+    fs::OpenOptions::new()
+      .append(true)
+      .open(log_path(&path))
+      .unwrap()
+      .write_all(b"{\"credentialId\":")
+      .unwrap();
+
+    let allocator = FileIndexAllocator::open(&path, config()).unwrap();
+    assert!(fs::read(log_path(&path)).unwrap().is_empty());
+    let allocation = allocator.allocate("credential-3").await.unwrap();
+    assert!(allocation.new_status_list);
+    assert_eq!(allocation.index.status_list.as_str(), "https://example.com/status/1");
+    assert_eq!(allocator.get_index("credential-1").await.unwrap(), Some(first.index));
+    assert!(allocator.status_lists().await.unwrap()[0].is_exhausted());
+
+    assert!(FileIndexAllocator::open(&path, config().capacity(3)).is_err());
+
+    fs::remove_file(&path).unwrap();
+    fs::remove_file(log_path(&path)).unwrap();
+  }
+}
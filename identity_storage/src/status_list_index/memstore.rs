@@ -0,0 +1,58 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use crate::key_storage::shared::Shared;
+
+use super::IndexAllocation;
+use super::IndexAllocator;
+use super::IndexAllocatorConfig;
+use super::IndexAllocatorResult;
+use super::IndexAllocatorState;
+use super::StatusListIndex;
+use super::StatusListUsage;
+
+/// An in-memory [`IndexAllocator`] implementation that may be used in tests.
+///
+/// Allocations are lost when the allocator is dropped: use [`FileIndexAllocator`](super::FileIndexAllocator)
+/// or a custom implementation backed by a database in production.
+#[derive(Debug)]
+pub struct IndexAllocatorMemstore {
+  state: Shared<IndexAllocatorState>,
+}
+
+impl IndexAllocatorMemstore {
+  /// Creates a new [`IndexAllocatorMemstore`] for status lists configured by `config`.
+  pub fn new(config: IndexAllocatorConfig) -> IndexAllocatorResult<Self> {
+    IndexAllocatorState::new(config).map(Self::from_state)
+  }
+
+  /// Creates a new [`IndexAllocatorMemstore`] resuming from `state`.
+  pub fn from_state(state: IndexAllocatorState) -> Self {
+    Self {
+      state: Shared::new(state),
+    }
+  }
+
+  /// Returns a copy of the allocator's current state.
+  pub async fn state(&self) -> IndexAllocatorState {
+    self.state.read().await.clone()
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl IndexAllocator for IndexAllocatorMemstore {
+  async fn allocate(&self, credential_id: &str) -> IndexAllocatorResult<IndexAllocation> {
+    self.state.write().await.allocate(credential_id)
+  }
+
+  async fn get_index(&self, credential_id: &str) -> IndexAllocatorResult<Option<StatusListIndex>> {
+    Ok(self.state.read().await.get_index(credential_id).cloned())
+  }
+
+  async fn status_lists(&self) -> IndexAllocatorResult<Vec<StatusListUsage>> {
+    Ok(self.state.read().await.status_lists().to_vec())
+  }
+}
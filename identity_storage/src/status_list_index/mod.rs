@@ -0,0 +1,29 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An Index Allocator hands out the status list entries referenced by issued credentials.
+//!
+//! This module provides implementations of the [`IndexAllocator`] trait, which guarantees that no two credentials
+//! are ever assigned the same entry of a status list - reusing an entry would let the revocation of
+//! one credential silently revoke another. Once a status list is exhausted, allocation rolls over
+//! to a new list.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod file_store;
+#[cfg(feature = "memstore")]
+mod memstore;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use file_store::*;
+#[cfg(feature = "memstore")]
+pub use memstore::*;
+
+pub use identity_credential::revocation::status_list_2021::IndexAllocation;
+pub use identity_credential::revocation::status_list_2021::IndexAllocator;
+pub use identity_credential::revocation::status_list_2021::IndexAllocatorConfig;
+pub use identity_credential::revocation::status_list_2021::IndexAllocatorError;
+pub use identity_credential::revocation::status_list_2021::IndexAllocatorErrorKind;
+pub use identity_credential::revocation::status_list_2021::IndexAllocatorResult;
+pub use identity_credential::revocation::status_list_2021::IndexAllocatorState;
+pub use identity_credential::revocation::status_list_2021::StatusListIndex;
+pub use identity_credential::revocation::status_list_2021::StatusListUsage;
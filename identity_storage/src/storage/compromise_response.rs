@@ -0,0 +1,429 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::Subject;
+use identity_did::DIDUrl;
+use identity_iota_core::IotaDID;
+use identity_iota_core::IotaDocument;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError;
+use super::JwsSignatureOptions;
+use super::Storage;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyType;
+
+/// The type of the credentials issued by [`KeyCompromiseResponse`] to notify verifiers of a key compromise.
+pub const KEY_COMPROMISE_NOTICE_TYPE: &str = "KeyCompromiseNotice";
+
+const RELATIONSHIPS: [MethodRelationship; 5] = [
+  MethodRelationship::Authentication,
+  MethodRelationship::AssertionMethod,
+  MethodRelationship::KeyAgreement,
+  MethodRelationship::CapabilityDelegation,
+  MethodRelationship::CapabilityInvocation,
+];
+
+/// Responds to the compromise of a verification method of an [`IotaDocument`].
+///
+/// Executing the response:
+/// 1. removes the compromised method, together with every reference to it, from the document;
+/// 2. optionally generates a replacement method, bound to the same verification relationships;
+/// 3. bumps the document's `updated` metadata and publishes the updated document;
+/// 4. deletes the compromised key from storage;
+/// 5. issues a signed [`KEY_COMPROMISE_NOTICE_TYPE`] credential, notifying verifiers of the incident.
+///
+/// The outcome of every step is collected in a [`KeyCompromiseReport`], meant to be kept as an audit record.
+///
+/// # Example
+/// ```ignore
+/// let report = KeyCompromiseResponse::new("key-1")
+///   .rotate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, Some("key-2"))
+///   .compromised_since(leak_detected_at)
+///   .reason("private key leaked through a misconfigured backup")
+///   .execute(&mut document, &storage, &my_publisher)
+///   .await?;
+/// audit_log.append(&report);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyCompromiseResponse {
+  fragment: String,
+  rotation: Option<KeyRotation>,
+  notice_fragment: Option<String>,
+  notice_options: JwsSignatureOptions,
+  compromised_since: Option<Timestamp>,
+  reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct KeyRotation {
+  key_type: KeyType,
+  alg: JwsAlgorithm,
+  fragment: Option<String>,
+}
+
+impl KeyCompromiseResponse {
+  /// Creates a new [`KeyCompromiseResponse`] to the compromise of the method identified by `fragment`.
+  pub fn new(fragment: impl Into<String>) -> Self {
+    Self {
+      fragment: fragment.into(),
+      rotation: None,
+      notice_fragment: None,
+      notice_options: JwsSignatureOptions::default(),
+      compromised_since: None,
+      reason: None,
+    }
+  }
+
+  /// Replaces the compromised method with a newly generated one, identified by `fragment` if given.
+  ///
+  /// The new method is embedded in the same verification relationship as the compromised one, or attached to the
+  /// same verification relationships otherwise.
+  #[must_use]
+  pub fn rotate(mut self, key_type: KeyType, alg: JwsAlgorithm, fragment: Option<&str>) -> Self {
+    self.rotation = Some(KeyRotation {
+      key_type,
+      alg,
+      fragment: fragment.map(ToOwned::to_owned),
+    });
+    self
+  }
+
+  /// Sets the method used to sign the incident notice. Defaults to the replacement method, if any.
+  #[must_use]
+  pub fn notice_signed_with(mut self, fragment: impl Into<String>) -> Self {
+    self.notice_fragment = Some(fragment.into());
+    self
+  }
+
+  /// Sets the options used to sign the incident notice.
+  #[must_use]
+  pub fn notice_options(mut self, options: JwsSignatureOptions) -> Self {
+    self.notice_options = options;
+    self
+  }
+
+  /// Sets the earliest point in time the key might have been compromised at.
+  #[must_use]
+  pub fn compromised_since(mut self, timestamp: Timestamp) -> Self {
+    self.compromised_since = Some(timestamp);
+    self
+  }
+
+  /// Sets a human-readable description of the incident, included in the incident notice.
+  #[must_use]
+  pub fn reason(mut self, reason: impl Into<String>) -> Self {
+    self.reason = Some(reason.into());
+    self
+  }
+
+  /// Executes this response on `document`, using `storage` for key management and `publisher` to publish the
+  /// updated document.
+  ///
+  /// `document` is left untouched if the response fails before the updated document has been published.
+  /// Afterwards, `document` is the published document, which is also the case when
+  /// [`KeyCompromiseResponseError::KeyDeletion`] or [`KeyCompromiseResponseError::Notice`] are returned.
+  pub async fn execute<K, I, P>(
+    self,
+    document: &mut IotaDocument,
+    storage: &Storage<K, I>,
+    publisher: &P,
+  ) -> Result<KeyCompromiseReport, KeyCompromiseResponseError>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    P: DidDocumentPublisher + ?Sized,
+  {
+    let compromised_method: DIDUrl = document
+      .resolve_method(self.fragment.as_str(), None)
+      .ok_or_else(|| KeyCompromiseResponseError::MethodNotFound(self.fragment.clone()))?
+      .id()
+      .clone();
+    if self.notice_fragment.is_none() && self.rotation.is_none() {
+      return Err(KeyCompromiseResponseError::MissingNoticeSigner);
+    }
+    if self.notice_fragment.is_some() && compromised_method.fragment() == self.notice_fragment.as_deref() {
+      return Err(KeyCompromiseResponseError::CompromisedNoticeSigner);
+    }
+
+    let relationships: Vec<MethodRelationship> = RELATIONSHIPS
+      .into_iter()
+      .filter(|relationship| references_method(document, *relationship, &compromised_method))
+      .collect();
+
+    let mut updated: IotaDocument = document.clone();
+    let (_, scope) = updated
+      .remove_method_and_scope(&compromised_method)
+      .ok_or_else(|| KeyCompromiseResponseError::MethodNotFound(self.fragment.clone()))?;
+
+    let replacement_method: Option<DIDUrl> = match &self.rotation {
+      Some(rotation) => Some(generate_replacement(&mut updated, storage, rotation, scope, &relationships).await?),
+      None => None,
+    };
+
+    let notice_fragment: String = self
+      .notice_fragment
+      .or_else(|| {
+        replacement_method
+          .as_ref()
+          .and_then(DIDUrl::fragment)
+          .map(ToOwned::to_owned)
+      })
+      .ok_or(KeyCompromiseResponseError::MissingNoticeSigner)?;
+    if updated.resolve_method(notice_fragment.as_str(), None).is_none() {
+      discard_replacement(&mut updated, storage, replacement_method.as_ref()).await;
+      return Err(KeyCompromiseResponseError::MethodNotFound(notice_fragment));
+    }
+
+    let executed_at: Timestamp = Timestamp::now_utc();
+    updated.metadata.updated = Some(executed_at);
+    let published: IotaDocument = match publisher.publish_document(updated.clone()).await {
+      Ok(published) => published,
+      Err(source) => {
+        discard_replacement(&mut updated, storage, replacement_method.as_ref()).await;
+        return Err(KeyCompromiseResponseError::Publication(source));
+      }
+    };
+    let mut previous: IotaDocument = std::mem::replace(document, published);
+
+    // The compromised key is only deleted once the document no longer references it, so that the response
+    // can be retried if publishing fails. Keys that aren't held in `storage` have nothing to delete.
+    let key_deleted: bool = match previous.purge_method(storage, &compromised_method).await {
+      Ok(()) => true,
+      Err(JwkStorageDocumentError::KeyIdStorageError(err))
+        if matches!(err.kind(), KeyIdStorageErrorKind::KeyIdNotFound) =>
+      {
+        false
+      }
+      Err(err) => return Err(KeyCompromiseResponseError::KeyDeletion(err)),
+    };
+
+    let mut report = KeyCompromiseReport {
+      did: document.id().clone(),
+      compromised_method,
+      relationships,
+      replacement_method,
+      key_deleted,
+      compromised_since: self.compromised_since,
+      reason: self.reason,
+      executed_at,
+      incident_notice: None,
+    };
+    let notice: Credential = report.notice_credential()?;
+    let jwt: Jwt = document
+      .create_credential_jwt(&notice, storage, &notice_fragment, &self.notice_options, None)
+      .await
+      .map_err(KeyCompromiseResponseError::Notice)?;
+    report.incident_notice = Some(jwt);
+
+    Ok(report)
+  }
+}
+
+fn references_method(document: &IotaDocument, relationship: MethodRelationship, method: &DIDUrl) -> bool {
+  let core_document = document.core_document();
+  let method_refs = match relationship {
+    MethodRelationship::Authentication => core_document.authentication(),
+    MethodRelationship::AssertionMethod => core_document.assertion_method(),
+    MethodRelationship::KeyAgreement => core_document.key_agreement(),
+    MethodRelationship::CapabilityDelegation => core_document.capability_delegation(),
+    MethodRelationship::CapabilityInvocation => core_document.capability_invocation(),
+  };
+
+  method_refs.iter().any(|method_ref| method_ref.id() == method)
+}
+
+async fn generate_replacement<K, I>(
+  document: &mut IotaDocument,
+  storage: &Storage<K, I>,
+  rotation: &KeyRotation,
+  scope: MethodScope,
+  relationships: &[MethodRelationship],
+) -> Result<DIDUrl, KeyCompromiseResponseError>
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  let fragment: String = document
+    .generate_method(
+      storage,
+      rotation.key_type.clone(),
+      rotation.alg,
+      rotation.fragment.as_deref(),
+      scope,
+    )
+    .await
+    .map_err(KeyCompromiseResponseError::Rotation)?;
+  let replacement: DIDUrl = document
+    .resolve_method(fragment.as_str(), None)
+    .expect("the method was just generated")
+    .id()
+    .clone();
+
+  // An embedded method already carries its relationship.
+  if scope == MethodScope::VerificationMethod {
+    for relationship in relationships {
+      if let Err(err) = document.attach_method_relationship(&replacement, *relationship) {
+        discard_replacement(document, storage, Some(&replacement)).await;
+        return Err(KeyCompromiseResponseError::Document(err));
+      }
+    }
+  }
+
+  Ok(replacement)
+}
+
+/// Removes a replacement method that won't be published, deleting its key.
+async fn discard_replacement<K, I>(document: &mut IotaDocument, storage: &Storage<K, I>, replacement: Option<&DIDUrl>)
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  if let Some(replacement) = replacement {
+    let _ = document.purge_method(storage, replacement).await;
+  }
+}
+
+/// Publishes updated DID documents, e.g. by executing a DID update through an `IdentityClient`.
+///
+/// # Example
+/// ```ignore
+/// struct ClientPublisher<S>(IdentityClient<S>);
+///
+/// #[async_trait]
+/// impl<S> DidDocumentPublisher for ClientPublisher<S>
+/// where
+///   S: Signer<IotaKeySignature> + OptionalSync,
+/// {
+///   async fn publish_document(&self, document: IotaDocument) -> Result<IotaDocument, Box<dyn Error + Send + Sync>> {
+///     let published = self.0.publish_did_update(document).await?.build_and_execute(&self.0).await?;
+///     Ok(published.output)
+///   }
+/// }
+/// ```
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait DidDocumentPublisher {
+  /// Publishes `document`, returning the document as published.
+  async fn publish_document(
+    &self,
+    document: IotaDocument,
+  ) -> Result<IotaDocument, Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// The audit record of an executed [`KeyCompromiseResponse`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct KeyCompromiseReport {
+  /// The DID whose document was updated.
+  pub did: IotaDID,
+  /// The ID of the compromised method.
+  pub compromised_method: DIDUrl,
+  /// The verification relationships the compromised method was bound to.
+  pub relationships: Vec<MethodRelationship>,
+  /// The ID of the method replacing the compromised one, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub replacement_method: Option<DIDUrl>,
+  /// Whether the compromised key was deleted from storage; `false` if the key wasn't held in storage.
+  pub key_deleted: bool,
+  /// The earliest point in time the key might have been compromised at, if known.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub compromised_since: Option<Timestamp>,
+  /// A description of the incident, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub reason: Option<String>,
+  /// When the response was executed, which is also the `updated` metadata of the published document.
+  pub executed_at: Timestamp,
+  /// The signed incident notice.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub incident_notice: Option<Jwt>,
+}
+
+impl KeyCompromiseReport {
+  fn notice_credential(&self) -> Result<Credential, KeyCompromiseResponseError> {
+    let did_url =
+      Url::parse(self.did.as_str()).map_err(|err| KeyCompromiseResponseError::NoticeCreation(err.into()))?;
+
+    let mut properties = Object::new();
+    properties.insert(
+      "compromisedMethod".to_owned(),
+      Value::String(self.compromised_method.to_string()),
+    );
+    if let Some(replacement_method) = &self.replacement_method {
+      properties.insert(
+        "replacementMethod".to_owned(),
+        Value::String(replacement_method.to_string()),
+      );
+    }
+    if let Some(compromised_since) = self.compromised_since {
+      properties.insert(
+        "compromisedSince".to_owned(),
+        Value::String(compromised_since.to_rfc3339()),
+      );
+    }
+    if let Some(reason) = &self.reason {
+      properties.insert("reason".to_owned(), Value::String(reason.clone()));
+    }
+    properties.insert(
+      "documentUpdated".to_owned(),
+      Value::String(self.executed_at.to_rfc3339()),
+    );
+
+    CredentialBuilder::default()
+      .type_(KEY_COMPROMISE_NOTICE_TYPE)
+      .issuer(did_url.clone())
+      .subject(Subject::with_id_and_properties(did_url, properties))
+      .issuance_date(self.executed_at)
+      .build()
+      .map_err(|err| KeyCompromiseResponseError::NoticeCreation(err.into()))
+  }
+}
+
+/// Errors that can occur when executing a [`KeyCompromiseResponse`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum KeyCompromiseResponseError {
+  /// The document doesn't contain the method identified by the given fragment.
+  #[error("method \"{0}\" not found in the DID document")]
+  MethodNotFound(String),
+  /// Neither a replacement method nor a method to sign the incident notice with have been configured.
+  #[error("no method to sign the incident notice with: rotate the compromised key or set a notice signer")]
+  MissingNoticeSigner,
+  /// The incident notice would be signed with the compromised method.
+  #[error("the incident notice cannot be signed with the compromised method")]
+  CompromisedNoticeSigner,
+  /// The replacement method couldn't be generated.
+  #[error("failed to generate the replacement method")]
+  Rotation(#[source] JwkStorageDocumentError),
+  /// The document couldn't be updated.
+  #[error("failed to update the DID document")]
+  Document(#[source] identity_iota_core::Error),
+  /// The updated document couldn't be published.
+  #[error("failed to publish the updated DID document")]
+  Publication(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The compromised key couldn't be deleted from storage. The updated document has been published.
+  #[error("the updated DID document was published, but the compromised key couldn't be deleted")]
+  KeyDeletion(#[source] JwkStorageDocumentError),
+  /// The incident notice couldn't be created.
+  #[error("failed to create the incident notice")]
+  NoticeCreation(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The incident notice couldn't be signed. The updated document has been published.
+  #[error("the updated DID document was published, but the incident notice couldn't be signed")]
+  Notice(#[source] JwkStorageDocumentError),
+}
@@ -17,6 +17,8 @@ use async_trait::async_trait;
 #[cfg(feature = "hybrid")]
 use identity_did::DIDCompositeJwk;
 use identity_did::DIDJwk;
+use identity_did::DIDKey;
+use identity_did::DID;
 use identity_document::document::CoreDocument;
 #[cfg(feature = "hybrid")]
 use identity_verification::jwk::CompositeAlgId;
@@ -40,6 +42,15 @@ pub trait DidJwkDocumentExt {
     key_type: KeyType,
     alg: JwsAlgorithm,
   ) -> StorageResult<(CoreDocument, String)>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+  /// Create a did:key DID document with an Ed25519 or X25519 key. Returns the DID document and the fragment
+  async fn new_did_key<K, I>(
+    storage: &Storage<K, I>,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+  ) -> StorageResult<(CoreDocument, String)>
   where
     K: JwkStorage,
     I: KeyIdStorage;
@@ -114,6 +125,45 @@ impl DidJwkDocumentExt for CoreDocument {
     Ok((document, fragment.to_string()))
   }
 
+  async fn new_did_key<K, I>(
+    storage: &Storage<K, I>,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+  ) -> StorageResult<(CoreDocument, String)>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let JwkGenOutput { key_id, jwk } = K::generate(storage.key_storage(), key_type, alg)
+      .await
+      .map_err(Error::KeyStorageError)?;
+
+    let did = match DIDKey::new(jwk) {
+      Ok(did) => did,
+      Err(err) => {
+        // The key is of no use if it cannot be encoded in a did:key.
+        let _ = K::delete(storage.key_storage(), &key_id).await;
+        return Err(Error::EncodingError(Box::new(err)));
+      }
+    };
+    let fragment = did.method_id().to_owned();
+
+    let document = CoreDocument::expand_did_key(did).map_err(|err| Error::EncodingError(Box::new(err)))?;
+
+    let verification_method = document
+      .resolve_method(fragment.as_str(), None)
+      .ok_or(identity_verification::Error::MissingIdFragment)
+      .map_err(Error::VerificationMethodConstructionError)?;
+
+    let method_digest = MethodDigest::new(verification_method).map_err(Error::MethodDigestConstructionError)?;
+
+    I::insert_key_id(storage.key_id_storage(), method_digest, key_id.clone())
+      .await
+      .map_err(Error::KeyIdStorageError)?;
+
+    Ok((document, fragment))
+  }
+
   #[cfg(feature = "pqc")]
   async fn new_did_jwk_pqc<K, I>(
     storage: &Storage<K, I>,
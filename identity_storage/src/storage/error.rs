@@ -51,6 +51,17 @@ pub enum JwkStorageDocumentError {
   /// Caused by a failure during (de)serialization of JWS claims.
   #[error("could not produce JWS payload from the given claims: serialization failed")]
   ClaimsSerializationError(#[source] identity_credential::Error),
+  /// Caused by a JWS header differing from the one required by a
+  /// [`SigningProfile`](crate::storage::SigningProfile) enforcing its headers.
+  #[error("the `{parameter}` header is {found:?}, but the signing profile requires `{expected}`")]
+  HeaderProfileViolation {
+    /// The name of the header parameter.
+    parameter: &'static str,
+    /// The value required by the profile.
+    expected: String,
+    /// The actual value, if any.
+    found: Option<String>,
+  },
   /// Caused by a failure to undo a failed storage operation.
   #[error("storage operation failed after altering state. Unable to undo operation(s): {message}")]
   UndoOperationFailed {
@@ -5,9 +5,12 @@ use std::borrow::Cow;
 
 use super::JwkStorageDocumentError as Error;
 use super::JwsSignatureOptions;
+use super::JwtProfile;
+use super::SigningProfile;
 use super::Storage;
 
 use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageErrorKind;
 use crate::key_id_storage::KeyIdStorageResult;
 use crate::key_id_storage::MethodDigest;
 use crate::key_storage::JwkGenOutput;
@@ -20,6 +23,7 @@ use async_trait::async_trait;
 use identity_core::common::Object;
 use identity_credential::credential::Credential;
 use identity_credential::credential::CredentialV2;
+use identity_credential::credential::EnvelopedVc;
 use identity_credential::credential::Jws;
 use identity_credential::credential::Jwt;
 use identity_credential::credential::JwtVcV2;
@@ -33,6 +37,7 @@ use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jose::jws::JwsHeader;
 use identity_verification::jws::CharSet;
 use identity_verification::MethodData;
+use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
 use identity_verification::VerificationMethod;
 use serde::de::DeserializeOwned;
@@ -99,6 +104,21 @@ pub trait JwkDocumentExt: private::Sealed {
     K: JwkStorage,
     I: KeyIdStorage;
 
+  /// Returns the verification methods with the verification relationship `purpose` that can sign with `alg` through
+  /// [`JwkDocumentExt::create_jws`], in the order they appear in the document.
+  ///
+  /// These are the [signing candidates](CoreDocument::signing_candidates) whose `publicKeyJwk` declares `alg` as its
+  /// algorithm, and whose private key is available in `storage`.
+  async fn available_signing_candidates<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    purpose: MethodRelationship,
+    alg: JwsAlgorithm,
+  ) -> StorageResult<Vec<VerificationMethod>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+
   /// Produces a JWT where the payload is produced from the given `credential`
   /// in accordance with either [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token)
   /// or [VC Data Model v2.0](https://www.w3.org/TR/vc-data-model-2.0/).
@@ -121,6 +141,47 @@ pub trait JwkDocumentExt: private::Sealed {
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
 
+  /// Returns the verification method to sign with and the signature options to sign with it, following `profile`.
+  ///
+  /// The method is the one identified by `fragment` if given. Otherwise it's the first method of the document -
+  /// among the [available signing candidates](JwkDocumentExt::available_signing_candidates) for the profile's
+  /// purpose and algorithm - whose key is in `storage`.
+  ///
+  /// The `kid`, `typ` and `cty` of `options` are set to the method's `id` and the profile's `typ` and `cty` when
+  /// unset, after which the profile's [header override](SigningProfile::with_header_override) is applied. Profiles
+  /// [enforcing their headers](SigningProfile::with_enforced_headers) fail with
+  /// [`JwkStorageDocumentError::HeaderProfileViolation`] when the resulting headers differ from the required ones.
+  ///
+  /// This is useful for SD-JWT VCs, whose header is built from the returned options.
+  async fn signing_options_with_profile<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: Option<&str>,
+    profile: &SigningProfile,
+    options: &JwsSignatureOptions,
+  ) -> StorageResult<(VerificationMethod, JwsSignatureOptions)>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+
+  /// Like [`JwkDocumentExt::create_credential_jwt`], selecting the signing method and populating the JWS header
+  /// following `profile` - see [`JwkDocumentExt::signing_options_with_profile`] - when no `fragment` is given.
+  ///
+  /// Fails for the [`JwtProfile::SdJwtVc`] profile, as SD-JWT VCs are not created from a [`Credential`].
+  async fn create_credential_jwt_with_profile<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: Option<&str>,
+    profile: &SigningProfile,
+    options: &JwsSignatureOptions,
+    custom_claims: Option<Object>,
+  ) -> StorageResult<Jwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
+
   /// Returns a JWT containing the given VC Data Model 2.0 `credential` in accordance with the mediatype
   /// `application/vc+jwt` defined in [Securing Verifiable Credentials using JOSE and COSE](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose).
   ///
@@ -160,6 +221,29 @@ pub trait JwkDocumentExt: private::Sealed {
     I: KeyIdStorage,
     T: Clone + Serialize + DeserializeOwned + Sync,
     CRED: ToOwned<Owned = CRED> + Serialize + DeserializeOwned + Clone + Sync;
+
+  /// Returns a JWT containing the given VC Data Model 2.0 `presentation` of enveloped credentials in accordance
+  /// with the mediatype `application/vp+jwt` defined in
+  /// [Securing Verifiable Credentials using JOSE and COSE](https://www.w3.org/TR/vc-jose-cose/#securing-vps-with-jose).
+  ///
+  /// Unless the `kid` is explicitly set in the options, the `kid` in the protected header is the `id`
+  /// of the method identified by `fragment` and the JWS signature will be produced by the corresponding
+  /// private key backed by the `storage` in accordance with the passed `signature_options`.
+  ///
+  /// Fails if `presentation` does not use the VC Data Model 2.0 base context, see
+  /// [`PresentationV2Builder`](identity_credential::presentation::PresentationV2Builder).
+  async fn create_presentation_jwt_v2<K, I, T>(
+    &self,
+    presentation: &Presentation<EnvelopedVc, T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    signature_options: &JwsSignatureOptions,
+    presentation_options: &JwtPresentationOptions,
+  ) -> StorageResult<Jwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: Clone + Serialize + DeserializeOwned + Sync;
 }
 
 mod private {
@@ -462,6 +546,41 @@ impl JwkDocumentExt for CoreDocument {
     Ok(Jws::new(jws_encoder.into_jws(&signature)))
   }
 
+  async fn available_signing_candidates<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    purpose: MethodRelationship,
+    alg: JwsAlgorithm,
+  ) -> StorageResult<Vec<VerificationMethod>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let mut candidates: Vec<VerificationMethod> = Vec::new();
+    for method in self.signing_candidates(purpose, alg) {
+      // `create_jws` only signs with methods whose `publicKeyJwk` declares the algorithm.
+      match method.data() {
+        MethodData::PublicKeyJwk(jwk) if jwk.alg().is_some() => (),
+        _ => continue,
+      }
+
+      let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+      let key_id: KeyId = match <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest).await {
+        Ok(key_id) => key_id,
+        Err(error) if matches!(error.kind(), KeyIdStorageErrorKind::KeyIdNotFound) => continue,
+        Err(error) => return Err(Error::KeyIdStorageError(error)),
+      };
+      if <K as JwkStorage>::exists(storage.key_storage(), &key_id)
+        .await
+        .map_err(Error::KeyStorageError)?
+      {
+        candidates.push(method.clone());
+      }
+    }
+
+    Ok(candidates)
+  }
+
   async fn create_credential_jwt<K, I, T>(
     &self,
     credential: &Credential<T>,
@@ -497,6 +616,87 @@ impl JwkDocumentExt for CoreDocument {
       .map(|jws| Jwt::new(jws.into()))
   }
 
+  async fn signing_options_with_profile<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: Option<&str>,
+    profile: &SigningProfile,
+    options: &JwsSignatureOptions,
+  ) -> StorageResult<(VerificationMethod, JwsSignatureOptions)>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let method: VerificationMethod = match fragment {
+      Some(fragment) => self
+        .resolve_method(fragment, None)
+        .ok_or(Error::MethodNotFound)?
+        .clone(),
+      None => {
+        let purpose: MethodRelationship = profile.purpose();
+        let mut algs: Vec<JwsAlgorithm> = match profile.alg() {
+          Some(alg) => vec![alg.clone()],
+          None => self
+            .methods(Some(purpose.into()))
+            .into_iter()
+            .filter_map(|method| match method.data() {
+              MethodData::PublicKeyJwk(jwk) => jwk.alg().and_then(|alg| alg.parse().ok()),
+              _ => None,
+            })
+            .collect(),
+        };
+        algs.sort();
+        algs.dedup();
+
+        let mut candidates: Vec<VerificationMethod> = Vec::new();
+        for alg in algs {
+          candidates.extend(self.available_signing_candidates(storage, purpose, alg).await?);
+        }
+        self
+          .methods(Some(purpose.into()))
+          .into_iter()
+          .find(|method| candidates.iter().any(|candidate| candidate.id() == method.id()))
+          .cloned()
+          .ok_or(Error::MethodNotFound)?
+      }
+    };
+
+    let mut options: JwsSignatureOptions = options.clone();
+    profile.apply(&method, &mut options)?;
+
+    Ok((method, options))
+  }
+
+  async fn create_credential_jwt_with_profile<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: Option<&str>,
+    profile: &SigningProfile,
+    options: &JwsSignatureOptions,
+    custom_claims: Option<Object>,
+  ) -> StorageResult<Jwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+  {
+    if profile.profile() == JwtProfile::SdJwtVc {
+      return Err(Error::EncodingError(Box::<dyn std::error::Error + Send + Sync>::from(
+        "SD-JWT VCs cannot be created from a credential",
+      )));
+    }
+
+    let (method, options) = self
+      .signing_options_with_profile(storage, fragment, profile, options)
+      .await?;
+    let fragment: &str = method.id().fragment().ok_or(Error::MethodNotFound)?;
+
+    self
+      .create_credential_jwt(credential, storage, fragment, &options, custom_claims)
+      .await
+  }
+
   async fn create_credential_v2_jwt<K, I, T>(
     &self,
     credential: &CredentialV2<T>,
@@ -576,6 +776,31 @@ impl JwkDocumentExt for CoreDocument {
       .await
       .map(|jws| Jwt::new(jws.into()))
   }
+
+  async fn create_presentation_jwt_v2<K, I, T>(
+    &self,
+    presentation: &Presentation<EnvelopedVc, T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    jws_options: &JwsSignatureOptions,
+    jwt_options: &JwtPresentationOptions,
+  ) -> StorageResult<Jwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: Clone + Serialize + DeserializeOwned + Sync,
+  {
+    if !presentation.is_v2() {
+      return Err(Error::ClaimsSerializationError(
+        identity_credential::Error::MissingBaseContext,
+      ));
+    }
+
+    // `create_presentation_jwt` takes care of setting `typ` to `vp+jwt` for VC Data Model 2.0 presentations.
+    self
+      .create_presentation_jwt(presentation, storage, fragment, jws_options, jwt_options)
+      .await
+  }
 }
 
 /// Attempt to revert key generation. If this succeeds the original `source_error` is returned,
@@ -660,6 +885,22 @@ mod iota_document {
         .await
     }
 
+    async fn available_signing_candidates<K, I>(
+      &self,
+      storage: &Storage<K, I>,
+      purpose: MethodRelationship,
+      alg: JwsAlgorithm,
+    ) -> StorageResult<Vec<VerificationMethod>>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+    {
+      self
+        .core_document()
+        .available_signing_candidates(storage, purpose, alg)
+        .await
+    }
+
     async fn create_credential_jwt<K, I, T>(
       &self,
       credential: &Credential<T>,
@@ -678,6 +919,43 @@ mod iota_document {
         .create_credential_jwt(credential, storage, fragment, options, custom_claims)
         .await
     }
+    async fn signing_options_with_profile<K, I>(
+      &self,
+      storage: &Storage<K, I>,
+      fragment: Option<&str>,
+      profile: &SigningProfile,
+      options: &JwsSignatureOptions,
+    ) -> StorageResult<(VerificationMethod, JwsSignatureOptions)>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+    {
+      self
+        .core_document()
+        .signing_options_with_profile(storage, fragment, profile, options)
+        .await
+    }
+
+    async fn create_credential_jwt_with_profile<K, I, T>(
+      &self,
+      credential: &Credential<T>,
+      storage: &Storage<K, I>,
+      fragment: Option<&str>,
+      profile: &SigningProfile,
+      options: &JwsSignatureOptions,
+      custom_claims: Option<Object>,
+    ) -> StorageResult<Jwt>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+    {
+      self
+        .core_document()
+        .create_credential_jwt_with_profile(credential, storage, fragment, profile, options, custom_claims)
+        .await
+    }
+
     async fn create_presentation_jwt<K, I, CRED, T>(
       &self,
       presentation: &Presentation<CRED, T>,
@@ -715,5 +993,24 @@ mod iota_document {
         .create_credential_v2_jwt(credential, storage, fragment, options)
         .await
     }
+
+    async fn create_presentation_jwt_v2<K, I, T>(
+      &self,
+      presentation: &Presentation<EnvelopedVc, T>,
+      storage: &Storage<K, I>,
+      fragment: &str,
+      options: &JwsSignatureOptions,
+      jwt_options: &JwtPresentationOptions,
+    ) -> StorageResult<Jwt>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      T: Clone + Serialize + DeserializeOwned + Sync,
+    {
+      self
+        .core_document()
+        .create_presentation_jwt_v2(presentation, storage, fragment, options, jwt_options)
+        .await
+    }
   }
 }
@@ -0,0 +1,200 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use identity_document::document::CoreDocument;
+use serde::Serialize;
+
+use super::Storage;
+use crate::key_id_storage::KeyIdMapping;
+use crate::key_id_storage::KeyIdStorageError;
+use crate::key_id_storage::KeyIdStorageListing;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorageListing;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageError;
+
+/// Finds, and optionally deletes, the keys of a [`Storage`] that are no longer referenced by any verification method
+/// of a set of DID documents.
+///
+/// The collection cross-references the storage contents with the given documents:
+/// - a [`KeyIdMapping`] is orphaned if no method of the documents has its [`MethodDigest`];
+/// - a key is orphaned if no mapping that isn't orphaned points to it.
+///
+/// Keys are only referenced by methods through their mappings, so the documents must include **every** document
+/// whose methods are backed by the storage: a key used by a document left out is considered orphaned.
+///
+/// The composite key id of a hybrid method, i.e. `"<traditional>~<post-quantum>"`, references both of its component
+/// keys.
+///
+/// Collection runs in dry-run mode by default, only reporting what would be deleted.
+///
+/// # Example
+/// ```ignore
+/// let report = KeyGarbageCollector::new().collect(&storage, [&issuer_doc, &holder_doc]).await?;
+/// review(&report);
+/// let report = KeyGarbageCollector::new()
+///   .dry_run(false)
+///   .collect(&storage, [&issuer_doc, &holder_doc])
+///   .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyGarbageCollector {
+  dry_run: bool,
+}
+
+impl Default for KeyGarbageCollector {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl KeyGarbageCollector {
+  /// Creates a new [`KeyGarbageCollector`], in dry-run mode.
+  pub fn new() -> Self {
+    Self { dry_run: true }
+  }
+
+  /// Sets whether orphaned keys and mappings are only reported (`true`, the default) or also deleted (`false`).
+  #[must_use]
+  pub fn dry_run(mut self, dry_run: bool) -> Self {
+    self.dry_run = dry_run;
+    self
+  }
+
+  /// Finds the keys and mappings of `storage` not referenced by any method of `documents`, deleting them unless in
+  /// dry-run mode.
+  ///
+  /// Orphaned mappings are deleted before orphaned keys. If a deletion fails, the entries deleted up to that point
+  /// stay deleted; running the collection again resumes the cleanup.
+  pub async fn collect<'d, K, I, D>(
+    &self,
+    storage: &Storage<K, I>,
+    documents: impl IntoIterator<Item = &'d D>,
+  ) -> Result<KeyGarbageCollectionReport, KeyGarbageCollectionError>
+  where
+    K: JwkStorageListing,
+    I: KeyIdStorageListing,
+    D: AsRef<CoreDocument> + ?Sized + 'd,
+  {
+    // Methods whose digest cannot be computed cannot have a key in storage.
+    let referenced_digests: HashSet<MethodDigest> = documents
+      .into_iter()
+      .flat_map(|document| document.as_ref().methods(None))
+      .filter_map(|method| MethodDigest::new(method).ok())
+      .collect();
+
+    let (referenced_mappings, orphaned_mappings): (Vec<KeyIdMapping>, Vec<KeyIdMapping>) = storage
+      .key_id_storage()
+      .list_key_id_mappings()
+      .await
+      .map_err(KeyGarbageCollectionError::KeyIdListing)?
+      .into_iter()
+      .partition(|mapping| referenced_digests.contains(&mapping.method_digest));
+    let referenced_keys: HashSet<KeyId> = referenced_mappings
+      .iter()
+      .flat_map(|mapping| key_components(&mapping.key_id))
+      .collect();
+
+    let stored_keys: HashSet<KeyId> = storage
+      .key_storage()
+      .list_key_ids()
+      .await
+      .map_err(KeyGarbageCollectionError::KeyListing)?
+      .into_iter()
+      .collect();
+    let orphaned_keys: Vec<KeyId> = stored_keys.difference(&referenced_keys).cloned().collect();
+    let missing_keys: Vec<KeyIdMapping> = referenced_mappings
+      .into_iter()
+      .filter(|mapping| !key_components(&mapping.key_id).all(|key_id| stored_keys.contains(&key_id)))
+      .collect();
+
+    if !self.dry_run {
+      for mapping in &orphaned_mappings {
+        storage
+          .key_id_storage()
+          .delete_key_id(&mapping.method_digest)
+          .await
+          .map_err(|source| KeyGarbageCollectionError::KeyIdDeletion {
+            mapping: mapping.clone(),
+            source,
+          })?;
+      }
+      for key_id in &orphaned_keys {
+        storage
+          .key_storage()
+          .delete(key_id)
+          .await
+          .map_err(|source| KeyGarbageCollectionError::KeyDeletion {
+            key_id: key_id.clone(),
+            source,
+          })?;
+      }
+    }
+
+    Ok(KeyGarbageCollectionReport {
+      dry_run: self.dry_run,
+      orphaned_keys,
+      orphaned_mappings,
+      missing_keys,
+    })
+  }
+}
+
+/// Returns the ids of the keys referenced by the key id of a mapping: the two component keys of a composite key id, or
+/// the key id itself.
+fn key_components(key_id: &KeyId) -> impl Iterator<Item = KeyId> + '_ {
+  key_id.as_str().split('~').map(|component| KeyId::new(component))
+}
+
+/// The outcome of a [`KeyGarbageCollector`] run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct KeyGarbageCollectionReport {
+  /// Whether the orphaned entries were only reported; `false` if they were deleted.
+  pub dry_run: bool,
+  /// The keys not referenced by any method.
+  pub orphaned_keys: Vec<KeyId>,
+  /// The mappings whose method is not part of any document.
+  pub orphaned_mappings: Vec<KeyIdMapping>,
+  /// The mappings of existing methods pointing to keys - or, for hybrid methods, to any key - that are not in the key
+  /// storage. They are never deleted.
+  pub missing_keys: Vec<KeyIdMapping>,
+}
+
+impl KeyGarbageCollectionReport {
+  /// Returns `true` if no orphaned keys or mappings were found.
+  pub fn is_clean(&self) -> bool {
+    self.orphaned_keys.is_empty() && self.orphaned_mappings.is_empty()
+  }
+}
+
+/// Errors that can occur when running a [`KeyGarbageCollector`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum KeyGarbageCollectionError {
+  /// The keys of the key storage couldn't be listed.
+  #[error("failed to list the keys of the key storage")]
+  KeyListing(#[source] KeyStorageError),
+  /// The mappings of the key id storage couldn't be listed.
+  #[error("failed to list the mappings of the key id storage")]
+  KeyIdListing(#[source] KeyIdStorageError),
+  /// An orphaned mapping couldn't be deleted.
+  #[error("failed to delete the orphaned mapping to key \"{}\"", .mapping.key_id)]
+  KeyIdDeletion {
+    /// The mapping that couldn't be deleted.
+    mapping: KeyIdMapping,
+    /// The source error.
+    source: KeyIdStorageError,
+  },
+  /// An orphaned key couldn't be deleted.
+  #[error("failed to delete the orphaned key \"{key_id}\"")]
+  KeyDeletion {
+    /// The key that couldn't be deleted.
+    key_id: KeyId,
+    /// The source error.
+    source: KeyStorageError,
+  },
+}
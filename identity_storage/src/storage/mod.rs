@@ -3,6 +3,8 @@
 
 //! This module provides a type wrapping a key and key id storage.
 
+#[cfg(feature = "iota-document")]
+mod compromise_response;
 mod error;
 #[macro_use]
 mod jwk_document_ext;
@@ -10,19 +12,31 @@ mod jwk_document_ext;
 mod hybrid_jws_document_ext;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwp_document_ext;
+mod key_garbage_collection;
 #[cfg(feature = "pqc")]
 mod pqc_jws_document_ext;
+#[cfg(feature = "backup")]
+mod recovery_bundle;
 mod signature_options;
+mod signing_profile;
+#[cfg(feature = "status-list-2021")]
+mod status_list_update;
 #[cfg(feature = "jpt-bbs-plus")]
 mod timeframe_revocation_ext;
 
 mod did_jwk_document_ext;
 
+#[cfg(feature = "storage-signer")]
+mod multisig_signer;
 #[cfg(feature = "storage-signer")]
 mod storage_signer;
+#[cfg(feature = "storage-signer")]
+mod user_confirmed_signer;
 #[cfg(all(test, feature = "memstore"))]
 pub(crate) mod tests;
 
+#[cfg(feature = "iota-document")]
+pub use compromise_response::*;
 pub use error::*;
 
 #[cfg(feature = "hybrid")]
@@ -30,11 +44,21 @@ pub use hybrid_jws_document_ext::*;
 pub use jwk_document_ext::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use jwp_document_ext::*;
+pub use key_garbage_collection::*;
 #[cfg(feature = "pqc")]
 pub use pqc_jws_document_ext::*;
+#[cfg(feature = "backup")]
+pub use recovery_bundle::*;
 pub use signature_options::*;
+pub use signing_profile::*;
+#[cfg(feature = "status-list-2021")]
+pub use status_list_update::*;
+#[cfg(feature = "storage-signer")]
+pub use multisig_signer::*;
 #[cfg(feature = "storage-signer")]
 pub use storage_signer::*;
+#[cfg(feature = "storage-signer")]
+pub use user_confirmed_signer::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use timeframe_revocation_ext::*;
 
@@ -0,0 +1,180 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::crypto::PublicKey;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::multisig::MultiSig;
+use iota_interaction::types::multisig::MultiSigPublicKey;
+use iota_interaction::types::signature::GenericSignature;
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSync;
+use secret_storage::Error as SecretStorageError;
+use secret_storage::Signer;
+
+/// Signer for transactions sent by a multisig address.
+///
+/// A [`MultiSigSigner`] collects the signatures of several [`Signer`]s - e.g. [`StorageSigner`](crate::StorageSigner)s
+/// or signers backed by remote key stores - over the same transaction and aggregates them into the multisig signature
+/// accepted by the network. Signers are asked for their signature in order, until the combined weight of the collected
+/// signatures reaches the multisig threshold. The order of the signers doesn't need to match the one of the multisig's
+/// public keys.
+///
+/// ## Notes
+/// A multisig signature is not a [`Signature`], therefore a [`MultiSigSigner`] cannot be used where a
+/// `Signer<IotaKeySignature>` is expected. The aggregated signature returned by [`MultiSigSigner::sign`] must be
+/// attached to the transaction data when submitting the transaction.
+#[derive(Debug)]
+pub struct MultiSigSigner<S> {
+  multisig_pk: MultiSigPublicKey,
+  signers: Vec<S>,
+}
+
+impl<S> MultiSigSigner<S> {
+  /// Creates a new [`MultiSigSigner`] for the multisig address defined by `multisig_pk`, collecting signatures from
+  /// `signers`.
+  pub fn new(multisig_pk: MultiSigPublicKey, signers: Vec<S>) -> Self {
+    Self { multisig_pk, signers }
+  }
+
+  /// Creates a new [`MultiSigSigner`] for the multisig address made of `public_keys` with their respective weights
+  /// and the given `threshold`.
+  pub fn from_public_keys(
+    public_keys: Vec<(PublicKey, u8)>,
+    threshold: u16,
+    signers: Vec<S>,
+  ) -> Result<Self, MultiSigSignerError> {
+    let (public_keys, weights) = public_keys.into_iter().unzip();
+    let multisig_pk = MultiSigPublicKey::new(public_keys, weights, threshold)
+      .map_err(|e| MultiSigSignerError::InvalidMultiSigPublicKey(e.into()))?;
+
+    Ok(Self::new(multisig_pk, signers))
+  }
+
+  /// Returns the multisig public key.
+  pub fn multisig_public_key(&self) -> &MultiSigPublicKey {
+    &self.multisig_pk
+  }
+
+  /// Returns the multisig address this [`MultiSigSigner`] signs for.
+  pub fn address(&self) -> IotaAddress {
+    IotaAddress::from(&self.multisig_pk)
+  }
+
+  /// Returns the signers signatures are collected from.
+  pub fn signers(&self) -> &[S] {
+    &self.signers
+  }
+
+  /// Returns the weight of `public_key` within the multisig, if it's a member of it.
+  fn weight_of(&self, public_key: &PublicKey) -> Option<u16> {
+    self
+      .multisig_pk
+      .pubkeys()
+      .iter()
+      .find_map(|(member, weight)| (member == public_key).then_some(*weight as u16))
+  }
+
+  /// Returns the position of `public_key` among the members of the multisig, if it's a member of it.
+  fn index_of(&self, public_key: &PublicKey) -> Option<usize> {
+    self
+      .multisig_pk
+      .pubkeys()
+      .iter()
+      .position(|(member, _)| member == public_key)
+  }
+}
+
+impl<S> MultiSigSigner<S>
+where
+  S: Signer<IotaKeySignature> + OptionalSync,
+{
+  /// Signs `data` with as many signers as required to reach the multisig threshold, returning the aggregated
+  /// multisig signature.
+  ///
+  /// Signers that fail to sign - e.g. an unreachable remote signer - are skipped, as long as the remaining ones
+  /// reach the threshold.
+  pub async fn sign(&self, data: &TransactionData) -> Result<GenericSignature, MultiSigSignerError> {
+    let threshold: u16 = *self.multisig_pk.threshold();
+    let mut weight: u16 = 0;
+    let mut signatures: Vec<Signature> = Vec::new();
+    let mut failures: Vec<SecretStorageError> = Vec::new();
+
+    for signer in &self.signers {
+      if weight >= threshold {
+        break;
+      }
+
+      let public_key = match signer.public_key().await {
+        Ok(public_key) => public_key,
+        Err(e) => {
+          failures.push(e);
+          continue;
+        }
+      };
+      let Some(signer_weight) = self.weight_of(&public_key) else {
+        return Err(MultiSigSignerError::NotAMember(public_key));
+      };
+      match signer.sign(data).await {
+        Ok(signature) => {
+          signatures.push(signature);
+          weight = weight.saturating_add(signer_weight);
+        }
+        Err(e) => failures.push(e),
+      }
+    }
+
+    if weight < threshold {
+      return Err(MultiSigSignerError::ThresholdNotReached {
+        weight,
+        threshold,
+        failures,
+      });
+    }
+
+    self.combine(signatures)
+  }
+
+  /// Aggregates `signatures` - collected by other means, e.g. from offline signers - into a multisig signature.
+  ///
+  /// `signatures` can be given in any order.
+  pub fn combine(&self, mut signatures: Vec<Signature>) -> Result<GenericSignature, MultiSigSignerError> {
+    // A multisig signature lists its signatures in the order of the public keys they were made with.
+    signatures.sort_by_cached_key(|signature| {
+      signature
+        .to_public_key()
+        .ok()
+        .and_then(|public_key| self.index_of(&public_key))
+    });
+
+    MultiSig::combine(signatures, self.multisig_pk.clone())
+      .map(GenericSignature::MultiSig)
+      .map_err(|e| MultiSigSignerError::Aggregation(e.into()))
+  }
+}
+
+/// Error type returned by [`MultiSigSigner`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum MultiSigSignerError {
+  /// The given public keys, weights and threshold don't define a valid multisig.
+  #[error("invalid multisig public key")]
+  InvalidMultiSigPublicKey(#[source] Box<dyn std::error::Error + Send + Sync>),
+  /// A signer's public key is not part of the multisig.
+  #[error("signer with public key {0:?} is not a member of the multisig")]
+  NotAMember(PublicKey),
+  /// The collected signatures don't reach the multisig threshold.
+  #[error("collected signatures have a combined weight of {weight}, below the threshold of {threshold}")]
+  ThresholdNotReached {
+    /// Combined weight of the collected signatures.
+    weight: u16,
+    /// The multisig threshold.
+    threshold: u16,
+    /// The errors of the signers that failed to sign.
+    failures: Vec<SecretStorageError>,
+  },
+  /// The collected signatures could not be aggregated into a multisig signature.
+  #[error("failed to aggregate signatures into a multisig signature")]
+  Aggregation(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
@@ -0,0 +1,327 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+
+use crypto::ciphers::chacha::XChaCha20Poly1305;
+use crypto::ciphers::traits::Aead;
+use crypto::keys::pbkdf::PBKDF2_HMAC_SHA512;
+use identity_core::common::Timestamp;
+use identity_core::convert::FromVersionedJson;
+use identity_core::convert::ToVersionedJson;
+use identity_core::convert::VersionedSchema;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwu::decode_b64;
+use identity_verification::jwu::encode_b64;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+use super::Storage;
+use crate::key_id_storage::KeyIdMapping;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_id_storage::KeyIdStorageError;
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_id_storage::MethodDigest;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::JwkStorageKeyExport;
+use crate::key_storage::KeyId;
+use crate::key_storage::KeyStorageError;
+
+const KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 16;
+const PBKDF2_ITERATIONS: u32 = 600_000;
+/// Upper bound on the iterations accepted from a bundle, so that opening a crafted one doesn't hang.
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+
+/// The contents of an [`EncryptedRecoveryBundle`]: DID documents, the [`KeyId`]s of their methods' keys and,
+/// if exported, the keys themselves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", bound(deserialize = "D: DeserializeOwned"))]
+#[non_exhaustive]
+pub struct RecoveryBundle<D> {
+  /// When the bundle was created.
+  pub created: Timestamp,
+  /// The exported DID documents.
+  pub documents: Vec<D>,
+  /// The key ids of the documents' methods backed by the storage.
+  pub mappings: Vec<RecoveryMapping>,
+  /// The exported private keys; empty if keys weren't exported.
+  pub keys: Vec<RecoveryKey>,
+}
+
+/// The [`KeyId`] of the key of the verification method `method`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecoveryMapping {
+  /// The ID of the verification method.
+  pub method: DIDUrl,
+  /// The mapping stored for `method`.
+  #[serde(flatten)]
+  pub mapping: KeyIdMapping,
+}
+
+/// A private key exported from a [`JwkStorage`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecoveryKey {
+  /// The identifier of the key in the storage it was exported from.
+  pub key_id: KeyId,
+  /// The key, including its private components.
+  pub jwk: Jwk,
+}
+
+/// A [`RecoveryBundle`] encrypted with a password, see [`Storage::export_bundle`].
+///
+/// The bundle is encrypted with XChaCha20-Poly1305, using a key derived from the password through
+/// PBKDF2-HMAC-SHA512. Serialize it with [`ToVersionedJson`] to store it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedRecoveryBundle {
+  iterations: u32,
+  salt: String,
+  nonce: String,
+  ciphertext: String,
+  tag: String,
+}
+
+impl VersionedSchema for EncryptedRecoveryBundle {
+  const KIND: &'static str = "EncryptedRecoveryBundle";
+  const VERSION: u16 = 1;
+}
+
+impl EncryptedRecoveryBundle {
+  fn seal<D: Serialize>(bundle: &RecoveryBundle<D>, password: &str) -> Result<Self, RecoveryBundleError> {
+    let plaintext =
+      Zeroizing::new(serde_json::to_vec(bundle).map_err(|err| RecoveryBundleError::Serialization(err.into()))?);
+
+    let mut salt = [0; SALT_LENGTH];
+    let mut nonce = [0; XChaCha20Poly1305::NONCE_LENGTH];
+    crypto::utils::rand::fill(&mut salt).map_err(|err| RecoveryBundleError::Encryption(err.to_string()))?;
+    crypto::utils::rand::fill(&mut nonce).map_err(|err| RecoveryBundleError::Encryption(err.to_string()))?;
+    let key = derive_key(password, &salt, PBKDF2_ITERATIONS);
+
+    let mut ciphertext = vec![0; plaintext.len()];
+    let mut tag = [0; XChaCha20Poly1305::TAG_LENGTH];
+    XChaCha20Poly1305::try_encrypt(
+      key.as_slice().into(),
+      nonce.as_slice().into(),
+      Self::KIND.as_bytes(),
+      &plaintext,
+      &mut ciphertext,
+      tag.as_mut_slice().into(),
+    )
+    .map_err(|err| RecoveryBundleError::Encryption(err.to_string()))?;
+
+    Ok(Self {
+      iterations: PBKDF2_ITERATIONS,
+      salt: encode_b64(salt),
+      nonce: encode_b64(nonce),
+      ciphertext: encode_b64(ciphertext),
+      tag: encode_b64(tag),
+    })
+  }
+
+  fn open<D: DeserializeOwned>(&self, password: &str) -> Result<RecoveryBundle<D>, RecoveryBundleError> {
+    // The iterations are read from the bundle: too few weaken the key derivation, too many hang the importer.
+    if !(PBKDF2_ITERATIONS..=MAX_PBKDF2_ITERATIONS).contains(&self.iterations) {
+      return Err(RecoveryBundleError::UnsupportedIterations(self.iterations));
+    }
+    let decode = |value: &str| decode_b64(value).map_err(|err| RecoveryBundleError::Serialization(err.into()));
+    let salt = decode(&self.salt)?;
+    let nonce = decode(&self.nonce)?;
+    let ciphertext = decode(&self.ciphertext)?;
+    let tag = decode(&self.tag)?;
+    if nonce.len() != XChaCha20Poly1305::NONCE_LENGTH || tag.len() != XChaCha20Poly1305::TAG_LENGTH {
+      return Err(RecoveryBundleError::Decryption);
+    }
+    let key = derive_key(password, &salt, self.iterations);
+
+    let mut plaintext = Zeroizing::new(vec![0; ciphertext.len()]);
+    XChaCha20Poly1305::try_decrypt(
+      key.as_slice().into(),
+      nonce.as_slice().into(),
+      Self::KIND.as_bytes(),
+      &mut plaintext,
+      &ciphertext,
+      tag.as_slice().into(),
+    )
+    .map_err(|_| RecoveryBundleError::Decryption)?;
+
+    serde_json::from_slice(&plaintext).map_err(|err| RecoveryBundleError::Serialization(err.into()))
+  }
+
+  /// Serializes this bundle as versioned JSON.
+  pub fn to_json_vec(&self) -> Result<Vec<u8>, RecoveryBundleError> {
+    self
+      .to_versioned_json()
+      .map_err(|err| RecoveryBundleError::Serialization(err.into()))
+  }
+
+  /// Deserializes a bundle serialized through [`EncryptedRecoveryBundle::to_json_vec`].
+  pub fn from_json_slice(json: &[u8]) -> Result<Self, RecoveryBundleError> {
+    Self::from_versioned_json(json).map_err(|err| RecoveryBundleError::Serialization(err.into()))
+  }
+}
+
+fn derive_key(password: &str, salt: &[u8], iterations: u32) -> Zeroizing<[u8; KEY_LENGTH]> {
+  let mut key = Zeroizing::new([0; KEY_LENGTH]);
+  let iterations = NonZeroU32::new(iterations).unwrap_or(NonZeroU32::MIN);
+  PBKDF2_HMAC_SHA512(password.as_bytes(), salt, iterations, key.as_mut_slice());
+  key
+}
+
+impl<K: JwkStorage, I: KeyIdStorage> Storage<K, I> {
+  /// Exports `documents` and the key ids of their methods into a password-encrypted bundle, without the keys.
+  ///
+  /// Restoring from such a bundle requires the keys to still be available in the key storage, e.g. because it is
+  /// backed up separately. Use [`Storage::export_bundle_with_keys`] to also export the keys, if the key storage allows
+  /// it.
+  pub async fn export_bundle<D>(
+    &self,
+    documents: &[D],
+    password: &str,
+  ) -> Result<EncryptedRecoveryBundle, RecoveryBundleError>
+  where
+    D: AsRef<CoreDocument> + Serialize,
+  {
+    let bundle = self.recovery_bundle(documents).await?;
+    EncryptedRecoveryBundle::seal(&bundle, password)
+  }
+
+  /// Restores the contents of `bundle` into this storage, returning the bundled documents.
+  ///
+  /// Keys contained in the bundle are inserted into the key storage - which assigns them new key ids - and the
+  /// mappings are stored with the key ids of the inserted keys. Mappings of keys that weren't exported keep their
+  /// key id.
+  ///
+  /// The storage is expected not to hold the bundled mappings yet: the import stops at the first mapping that
+  /// already exists, leaving the entries imported until then in place.
+  pub async fn import_bundle<D>(
+    &self,
+    bundle: &EncryptedRecoveryBundle,
+    password: &str,
+  ) -> Result<Vec<D>, RecoveryBundleError>
+  where
+    D: DeserializeOwned,
+  {
+    let RecoveryBundle {
+      documents,
+      mappings,
+      keys,
+      ..
+    } = bundle.open::<D>(password)?;
+
+    let mut imported_keys: HashMap<KeyId, KeyId> = HashMap::with_capacity(keys.len());
+    for RecoveryKey { key_id, jwk } in keys {
+      let new_key_id = self
+        .key_storage()
+        .insert(jwk)
+        .await
+        .map_err(RecoveryBundleError::KeyStorage)?;
+      imported_keys.insert(key_id, new_key_id);
+    }
+
+    for RecoveryMapping { mapping, .. } in mappings {
+      let key_id = imported_keys.get(&mapping.key_id).cloned().unwrap_or(mapping.key_id);
+      self
+        .key_id_storage()
+        .insert_key_id(mapping.method_digest, key_id)
+        .await
+        .map_err(RecoveryBundleError::KeyIdStorage)?;
+    }
+
+    Ok(documents)
+  }
+
+  async fn recovery_bundle<'d, D>(&self, documents: &'d [D]) -> Result<RecoveryBundle<&'d D>, RecoveryBundleError>
+  where
+    D: AsRef<CoreDocument> + Serialize,
+  {
+    let mut mappings = vec![];
+    for method in documents.iter().flat_map(|document| document.as_ref().methods(None)) {
+      // Methods whose digest cannot be computed cannot have a key in storage.
+      let Ok(method_digest) = MethodDigest::new(method) else {
+        continue;
+      };
+      match self.key_id_storage().get_key_id(&method_digest).await {
+        Ok(key_id) => mappings.push(RecoveryMapping {
+          method: method.id().clone(),
+          mapping: KeyIdMapping::new(method_digest, key_id),
+        }),
+        Err(err) if matches!(err.kind(), KeyIdStorageErrorKind::KeyIdNotFound) => continue,
+        Err(err) => return Err(RecoveryBundleError::KeyIdStorage(err)),
+      }
+    }
+
+    Ok(RecoveryBundle {
+      created: Timestamp::now_utc(),
+      documents: documents.iter().collect(),
+      mappings,
+      keys: vec![],
+    })
+  }
+}
+
+impl<K: JwkStorageKeyExport, I: KeyIdStorage> Storage<K, I> {
+  /// Exports `documents`, the key ids of their methods and the keys themselves into a password-encrypted bundle.
+  ///
+  /// See [`Storage::export_bundle`] for exporting a bundle without keys.
+  pub async fn export_bundle_with_keys<D>(
+    &self,
+    documents: &[D],
+    password: &str,
+  ) -> Result<EncryptedRecoveryBundle, RecoveryBundleError>
+  where
+    D: AsRef<CoreDocument> + Serialize,
+  {
+    let mut bundle = self.recovery_bundle(documents).await?;
+    for mapping in &bundle.mappings {
+      let key_id = &mapping.mapping.key_id;
+      if bundle.keys.iter().any(|key| &key.key_id == key_id) {
+        continue;
+      }
+      let jwk = self
+        .key_storage()
+        .export_key(key_id)
+        .await
+        .map_err(RecoveryBundleError::KeyStorage)?;
+      bundle.keys.push(RecoveryKey {
+        key_id: key_id.clone(),
+        jwk,
+      });
+    }
+
+    EncryptedRecoveryBundle::seal(&bundle, password)
+  }
+}
+
+/// Errors that can occur when exporting or importing a recovery bundle.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum RecoveryBundleError {
+  /// Caused by a failure in the key storage.
+  #[error("key storage operation failed")]
+  KeyStorage(#[source] KeyStorageError),
+  /// Caused by a failure in the key id storage.
+  #[error("key id storage operation failed")]
+  KeyIdStorage(#[source] KeyIdStorageError),
+  /// The bundle couldn't be (de)serialized.
+  #[error("failed to (de)serialize the recovery bundle")]
+  Serialization(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The bundle couldn't be encrypted.
+  #[error("failed to encrypt the recovery bundle: {0}")]
+  Encryption(String),
+  /// The bundle couldn't be decrypted, because the password is wrong or the bundle was tampered with.
+  #[error("failed to decrypt the recovery bundle: wrong password or corrupted bundle")]
+  Decryption,
+  /// The bundle's key derivation uses a number of PBKDF2 iterations outside of the accepted range.
+  #[error("unsupported number of PBKDF2 iterations: {0}")]
+  UnsupportedIterations(u32),
+}
@@ -0,0 +1,183 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use identity_verification::jose::jws::media_type_eq;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodRelationship;
+use identity_verification::VerificationMethod;
+
+use super::JwkStorageDocumentError;
+use super::JwsSignatureOptions;
+
+type HeaderOverride = Arc<dyn Fn(&VerificationMethod, &mut JwsSignatureOptions) + Send + Sync>;
+
+/// The kind of JWT a credential is secured as, determining the `typ` header of the JWS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum JwtProfile {
+  /// A [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token) JWT, typed `JWT`.
+  #[default]
+  Jwt,
+  /// A [VC Data Model v2.0](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose) credential, typed `vc+jwt`.
+  VcJwt,
+  /// An [SD-JWT VC](https://datatracker.ietf.org/doc/draft-ietf-oauth-sd-jwt-vc/), typed `dc+sd-jwt`.
+  SdJwtVc,
+}
+
+impl JwtProfile {
+  /// Returns the `typ` header value of this profile.
+  pub const fn typ(&self) -> &'static str {
+    match self {
+      Self::Jwt => "JWT",
+      Self::VcJwt => "vc+jwt",
+      Self::SdJwtVc => "dc+sd-jwt",
+    }
+  }
+}
+
+/// How the signing method and the JWS header of a credential are chosen when no fragment is given - see
+/// [`JwkDocumentExt::signing_options_with_profile`](super::JwkDocumentExt::signing_options_with_profile).
+#[derive(Clone, Default)]
+pub struct SigningProfile {
+  profile: JwtProfile,
+  purpose: Option<MethodRelationship>,
+  alg: Option<JwsAlgorithm>,
+  cty: Option<String>,
+  enforce_headers: bool,
+  header_override: Option<HeaderOverride>,
+}
+
+impl Debug for SigningProfile {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SigningProfile")
+      .field("profile", &self.profile)
+      .field("purpose", &self.purpose)
+      .field("alg", &self.alg)
+      .field("cty", &self.cty)
+      .field("enforce_headers", &self.enforce_headers)
+      .field("header_override", &self.header_override.is_some())
+      .finish()
+  }
+}
+
+impl SigningProfile {
+  /// Creates a new [`SigningProfile`] for `profile`, selecting any `assertionMethod` whose key is in storage.
+  pub fn new(profile: JwtProfile) -> Self {
+    Self {
+      profile,
+      ..Default::default()
+    }
+  }
+
+  /// Only selects methods with the verification relationship `purpose`, instead of `assertionMethod`.
+  pub fn with_purpose(mut self, purpose: MethodRelationship) -> Self {
+    self.purpose = Some(purpose);
+    self
+  }
+
+  /// Only selects methods signing with `alg`.
+  pub fn with_alg(mut self, alg: JwsAlgorithm) -> Self {
+    self.alg = Some(alg);
+    self
+  }
+
+  /// Requires the `cty` header to be `cty`, which is filled in when unset.
+  pub fn with_cty(mut self, cty: impl Into<String>) -> Self {
+    self.cty = Some(cty.into());
+    self
+  }
+
+  /// Fails signing when the final `typ` or `cty` header - e.g. one set in the given options or by the
+  /// [header override](SigningProfile::with_header_override) - differs from the one required by this profile,
+  /// instead of signing with it.
+  pub fn with_enforced_headers(mut self) -> Self {
+    self.enforce_headers = true;
+    self
+  }
+
+  /// Sets a hook called with the selected method and the populated options right before signing, e.g. to replace
+  /// the `kid` with a key identifier of another format.
+  pub fn with_header_override<F>(mut self, header_override: F) -> Self
+  where
+    F: Fn(&VerificationMethod, &mut JwsSignatureOptions) + Send + Sync + 'static,
+  {
+    self.header_override = Some(Arc::new(header_override));
+    self
+  }
+
+  /// Returns the JWT profile.
+  pub fn profile(&self) -> JwtProfile {
+    self.profile
+  }
+
+  /// Returns the verification relationship of the selected methods.
+  pub fn purpose(&self) -> MethodRelationship {
+    self.purpose.unwrap_or(MethodRelationship::AssertionMethod)
+  }
+
+  /// Returns the algorithm of the selected methods, if restricted.
+  pub fn alg(&self) -> Option<&JwsAlgorithm> {
+    self.alg.as_ref()
+  }
+
+  /// Returns the required `cty` header, if any.
+  pub fn cty(&self) -> Option<&str> {
+    self.cty.as_deref()
+  }
+
+  /// Returns whether the headers required by this profile are enforced at signing time.
+  pub fn enforces_headers(&self) -> bool {
+    self.enforce_headers
+  }
+
+  /// Fills in the `kid`, `typ` and `cty` of `options` for `method` when unset, then applies the header override, if
+  /// any, and finally enforces the required headers, if requested.
+  pub(crate) fn apply(
+    &self,
+    method: &VerificationMethod,
+    options: &mut JwsSignatureOptions,
+  ) -> Result<(), JwkStorageDocumentError> {
+    if options.kid.is_none() {
+      options.kid = Some(method.id().to_string());
+    }
+    if options.typ.is_none() {
+      options.typ = Some(self.profile.typ().to_owned());
+    }
+    if options.cty.is_none() {
+      options.cty = self.cty.clone();
+    }
+    if let Some(header_override) = &self.header_override {
+      header_override(method, options);
+    }
+
+    if self.enforce_headers {
+      check_header("typ", Some(self.profile.typ()), options.typ.as_deref())?;
+      check_header("cty", self.cty.as_deref(), options.cty.as_deref())?;
+    }
+
+    Ok(())
+  }
+}
+
+fn check_header(
+  parameter: &'static str,
+  expected: Option<&str>,
+  found: Option<&str>,
+) -> Result<(), JwkStorageDocumentError> {
+  let Some(expected) = expected else {
+    return Ok(());
+  };
+
+  if found.is_some_and(|found| media_type_eq(found, expected)) {
+    Ok(())
+  } else {
+    Err(JwkStorageDocumentError::HeaderProfileViolation {
+      parameter,
+      expected: expected.to_owned(),
+      found: found.map(ToOwned::to_owned),
+    })
+  }
+}
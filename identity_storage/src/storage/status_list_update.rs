@@ -0,0 +1,245 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+use identity_credential::credential::Jwt;
+use identity_credential::revocation::status_list_2021::IndexAllocator;
+use identity_credential::revocation::status_list_2021::StatusList2021Credential;
+use identity_credential::revocation::status_list_2021::StatusList2021CredentialError;
+use identity_credential::revocation::status_list_2021::StatusListChange;
+use identity_credential::revocation::status_list_2021::StatusListManager;
+use identity_credential::revocation::status_list_2021::StatusListManagerError;
+use identity_credential::revocation::status_list_2021::StatusPurpose;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError;
+use super::JwsSignatureOptions;
+use super::Storage;
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// Changes the status of many credentials referenced by a [`StatusList2021Credential`] at once, and re-signs it.
+///
+/// # Example
+/// ```ignore
+/// let update = StatusListUpdate::new()
+///   .revoke(42)
+///   .revoke(1337)
+///   .apply(&mut status_list_credential, &issuer_document, &storage, "key-1", &JwsSignatureOptions::default())
+///   .await?;
+/// update.publish(&my_publisher).await?;
+/// audit_log.append(&update.record);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StatusListUpdate {
+  updates: Vec<(usize, bool)>,
+}
+
+impl StatusListUpdate {
+  /// Creates a new, empty [`StatusListUpdate`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the `index`-th entry to `value`.
+  #[must_use]
+  pub fn set(mut self, index: usize, value: bool) -> Self {
+    self.updates.push((index, value));
+    self
+  }
+
+  /// Sets the `index`-th entry, revoking - or suspending, depending on the list's purpose - the referencing
+  /// credential.
+  #[must_use]
+  pub fn revoke(self, index: usize) -> Self {
+    self.set(index, true)
+  }
+
+  /// Unsets the `index`-th entry. Only suspension lists allow unsetting an entry.
+  #[must_use]
+  pub fn reinstate(self, index: usize) -> Self {
+    self.set(index, false)
+  }
+
+  /// Returns the number of queued updates.
+  pub fn len(&self) -> usize {
+    self.updates.len()
+  }
+
+  /// Returns whether no update has been queued.
+  pub fn is_empty(&self) -> bool {
+    self.updates.is_empty()
+  }
+
+  /// Applies all updates to `status_list`, refreshes its issuance date and signs it as a JWT with the verification
+  /// method identified by `fragment` in `issuer_document`.
+  ///
+  /// `status_list` is only modified when all updates are valid and signing succeeded.
+  pub async fn apply<D, K, I>(
+    self,
+    status_list: &mut StatusList2021Credential,
+    issuer_document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+  ) -> Result<SignedStatusListUpdate, StatusListUpdateError>
+  where
+    D: JwkDocumentExt + Sync,
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let mut updated_status_list = status_list.clone();
+    let changes = updated_status_list
+      .update_entries(self.updates)
+      .map_err(StatusListUpdateError::InvalidUpdate)?;
+
+    let updated_at = Timestamp::now_utc();
+    let mut credential: Credential = updated_status_list.into_inner();
+    credential.issuance_date = updated_at;
+    let jwt = issuer_document
+      .create_credential_jwt(&credential, storage, fragment, options, None)
+      .await
+      .map_err(StatusListUpdateError::Signing)?;
+    let updated_status_list =
+      StatusList2021Credential::try_from(credential).map_err(StatusListUpdateError::InvalidUpdate)?;
+
+    let record = StatusListUpdateRecord {
+      status_list: updated_status_list.id().cloned(),
+      purpose: updated_status_list.purpose(),
+      changes,
+      updated_at,
+    };
+    *status_list = updated_status_list.clone();
+
+    Ok(SignedStatusListUpdate {
+      status_list: updated_status_list,
+      jwt,
+      record,
+    })
+  }
+}
+
+/// The outcome of [`StatusListUpdate::apply`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct SignedStatusListUpdate {
+  /// The updated status list credential.
+  pub status_list: StatusList2021Credential,
+  /// The updated status list credential, signed as a JWT.
+  pub jwt: Jwt,
+  /// The audit record of this update.
+  pub record: StatusListUpdateRecord,
+}
+
+impl SignedStatusListUpdate {
+  /// Makes the updated status list available to verifiers through `publisher`.
+  pub async fn publish<P>(&self, publisher: &P) -> Result<(), StatusListUpdateError>
+  where
+    P: StatusListPublisher + ?Sized,
+  {
+    publisher
+      .publish(&self.status_list, &self.jwt)
+      .await
+      .map_err(StatusListUpdateError::Publication)
+  }
+}
+
+/// An audit record of the entries changed by a [`StatusListUpdate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct StatusListUpdateRecord {
+  /// The ID of the updated status list, if any.
+  pub status_list: Option<Url>,
+  /// The purpose of the updated status list.
+  pub purpose: StatusPurpose,
+  /// The entries whose value changed. Updates that didn't change an entry's value are omitted.
+  pub changes: Vec<StatusListChange>,
+  /// When the update took place, which is also the new issuance date of the status list credential.
+  pub updated_at: Timestamp,
+}
+
+/// Extension trait to re-sign the status list credential managed by a [`StatusListManager`].
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait StatusListManagerExt {
+  /// Refreshes the issuance date of the managed status list credential and signs it as a JWT with the verification
+  /// method identified by `fragment` in `issuer_document`.
+  ///
+  /// The manager's pending changes are only cleared when signing succeeded.
+  async fn sign_status_list<D, K, I>(
+    &mut self,
+    issuer_document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+  ) -> Result<Jwt, StatusListUpdateError>
+  where
+    D: JwkDocumentExt + Sync,
+    K: JwkStorage,
+    I: KeyIdStorage;
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<A: IndexAllocator> StatusListManagerExt for StatusListManager<A> {
+  async fn sign_status_list<D, K, I>(
+    &mut self,
+    issuer_document: &D,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+  ) -> Result<Jwt, StatusListUpdateError>
+  where
+    D: JwkDocumentExt + Sync,
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let signed_at = Timestamp::now_utc();
+    let mut credential: Credential = self.status_list_credential().clone().into_inner();
+    credential.issuance_date = signed_at;
+    let jwt = issuer_document
+      .create_credential_jwt(&credential, storage, fragment, options, None)
+      .await
+      .map_err(StatusListUpdateError::Signing)?;
+    self.mark_signed(signed_at).map_err(StatusListUpdateError::Manager)?;
+
+    Ok(jwt)
+  }
+}
+
+/// Makes an updated [`StatusList2021Credential`] available to verifiers,
+/// e.g. through an HTTP `PUT` to the list's URL or by updating the issuer's on-chain resources.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait StatusListPublisher {
+  /// Publishes `status_list`, together with its JWT encoding `jwt`.
+  async fn publish(
+    &self,
+    status_list: &StatusList2021Credential,
+    jwt: &Jwt,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
+}
+
+/// Errors that can occur when updating a status list through [`StatusListUpdate`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum StatusListUpdateError {
+  /// The status list cannot be updated as requested.
+  #[error("invalid status list update")]
+  InvalidUpdate(#[source] StatusList2021CredentialError),
+  /// The updated status list couldn't be signed.
+  #[error("failed to sign the updated status list")]
+  Signing(#[source] JwkStorageDocumentError),
+  /// The status list manager couldn't record the signed status list.
+  #[error("failed to record the signed status list")]
+  Manager(#[source] StatusListManagerError),
+  /// The updated status list couldn't be published.
+  #[error("failed to publish the updated status list")]
+  Publication(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
@@ -7,6 +7,8 @@ use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
 use identity_credential::credential::Jws;
 use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtHeaderProfile;
+use identity_credential::validator::JwtValidationError;
 use identity_did::DIDUrl;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
@@ -20,8 +22,13 @@ use identity_verification::MethodRelationship;
 use identity_verification::MethodScope;
 
 use crate::key_id_storage::KeyIdMemstore;
+use crate::key_id_storage::KeyIdStorageErrorKind;
 use crate::key_storage::JwkMemStore;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::storage::JwkStorageDocumentError;
 use crate::storage::JwsSignatureOptions;
+use crate::storage::JwtProfile;
+use crate::storage::SigningProfile;
 
 use crate::storage::JwkDocumentExt;
 use crate::Storage;
@@ -356,6 +363,206 @@ async fn signing_credential() {
     .is_ok());
 }
 
+#[tokio::test]
+async fn signing_credential_with_profile() {
+  let (mut document, storage) = setup();
+  let credential: Credential = Credential::from_json(
+    r#"
+    {
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "type": "VerifiableCredential",
+      "issuer": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": {
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21"
+      }
+    }"#,
+  )
+  .unwrap();
+  let profile = SigningProfile::new(JwtProfile::VcJwt).with_header_override(|_, options| {
+    options.cty = Some("test-cty".to_owned());
+  });
+
+  // Only methods with the `assertionMethod` relationship are selected.
+  let err = document
+    .create_credential_jwt_with_profile(&credential, &storage, None, &profile, &JwsSignatureOptions::new(), None)
+    .await
+    .unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::MethodNotFound));
+
+  let mut fragments: Vec<String> = Vec::new();
+  for scope in [
+    MethodScope::VerificationMethod,
+    MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod),
+  ] {
+    let fragment = document
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        None,
+        scope,
+      )
+      .await
+      .unwrap();
+    fragments.push(fragment);
+  }
+
+  let jwt = document
+    .create_credential_jwt_with_profile(&credential, &storage, None, &profile, &JwsSignatureOptions::new(), None)
+    .await
+    .unwrap();
+  let decoded = document
+    .verify_jws(
+      jwt.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new(),
+    )
+    .unwrap();
+  let method_id: DIDUrl = document.id().to_url().join(format!("#{}", fragments[1])).unwrap();
+  assert_eq!(decoded.protected.kid().unwrap(), method_id.to_string());
+  assert_eq!(decoded.protected.typ().unwrap(), "vc+jwt");
+  assert_eq!(decoded.protected.cty().unwrap(), "test-cty");
+
+  // Explicit options take precedence over the profile.
+  let (method, options) = document
+    .signing_options_with_profile(
+      &storage,
+      Some(&fragments[0]),
+      &profile,
+      &JwsSignatureOptions::new().typ("JWT"),
+    )
+    .await
+    .unwrap();
+  assert_eq!(method.id().fragment(), Some(fragments[0].as_str()));
+  assert_eq!(options.typ.as_deref(), Some("JWT"));
+
+  let err = document
+    .create_credential_jwt_with_profile(
+      &credential,
+      &storage,
+      None,
+      &SigningProfile::new(JwtProfile::SdJwtVc),
+      &JwsSignatureOptions::new(),
+      None,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::EncodingError(_)));
+}
+
+#[tokio::test]
+async fn enforcing_signing_profile_headers() {
+  let (mut document, storage) = setup();
+  let credential: Credential = Credential::from_json(
+    r#"
+    {
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "type": "VerifiableCredential",
+      "issuer": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": {
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21"
+      }
+    }"#,
+  )
+  .unwrap();
+  document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod),
+    )
+    .await
+    .unwrap();
+  let profile = SigningProfile::new(JwtProfile::VcJwt)
+    .with_cty("vc")
+    .with_enforced_headers();
+
+  // Headers deviating from the profile are rejected at signing time.
+  let err = document
+    .create_credential_jwt_with_profile(
+      &credential,
+      &storage,
+      None,
+      &profile,
+      &JwsSignatureOptions::new().typ("JWT"),
+      None,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    err,
+    JwkStorageDocumentError::HeaderProfileViolation { parameter: "typ", .. }
+  ));
+
+  let err = document
+    .create_credential_jwt_with_profile(
+      &credential,
+      &storage,
+      None,
+      &profile
+        .clone()
+        .with_header_override(|_, options| options.cty = Some("json".to_owned())),
+      &JwsSignatureOptions::new(),
+      None,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    err,
+    JwkStorageDocumentError::HeaderProfileViolation { parameter: "cty", .. }
+  ));
+
+  // Media types are compared like at validation time.
+  document
+    .create_credential_jwt_with_profile(
+      &credential,
+      &storage,
+      None,
+      &profile,
+      &JwsSignatureOptions::new().typ("application/VC+JWT").cty("VC"),
+      None,
+    )
+    .await
+    .unwrap();
+
+  // Profile-conforming tokens pass validation requiring the profile's headers.
+  let jwt = document
+    .create_credential_jwt_with_profile(&credential, &storage, None, &profile, &JwsSignatureOptions::new(), None)
+    .await
+    .unwrap();
+  let validator =
+    identity_credential::validator::JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  let decoded = validator
+    .validate::<_, Object>(
+      &jwt,
+      &document,
+      &JwtCredentialValidationOptions::default().header_profile(JwtHeaderProfile::vc_jwt().cty("vc")),
+      identity_credential::validator::FailFast::FirstError,
+    )
+    .unwrap();
+  assert_eq!(decoded.header.typ(), Some("vc+jwt"));
+  assert_eq!(decoded.header.cty(), Some("vc"));
+
+  // Tokens of another profile are rejected at validation time.
+  let err = validator
+    .validate::<_, Object>(
+      &jwt,
+      &document,
+      &JwtCredentialValidationOptions::default().header_profile(JwtHeaderProfile::sd_jwt_vc()),
+      identity_credential::validator::FailFast::FirstError,
+    )
+    .unwrap_err();
+  assert!(matches!(
+    err.validation_errors.as_slice(),
+    [JwtValidationError::HeaderProfileMismatch { parameter: "typ", .. }]
+  ));
+}
+
 #[tokio::test]
 async fn purging() {
   let (mut document, storage) = setup();
@@ -380,6 +587,164 @@ async fn purging() {
   assert_eq!(storage.key_storage().count().await, 0);
 }
 
+#[tokio::test]
+async fn generation_rollback() {
+  let (mut document, storage) = setup();
+
+  // A failure to store the key id undoes the key generation.
+  storage
+    .key_id_storage()
+    .fail_next(1, KeyIdStorageErrorKind::Unavailable);
+  let err = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#key-1"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::KeyIdStorageError(_)));
+  assert!(document.resolve_method("#key-1", None).is_none());
+  assert_eq!(storage.key_storage().count().await, 0);
+
+  // Faults only affect the given number of operations.
+  document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#key-1"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  assert_eq!(storage.key_id_storage().count().await, 1);
+}
+
+#[tokio::test]
+async fn purging_rollback() {
+  let (mut document, storage, fragment) = setup_with_method().await;
+  let method_id: DIDUrl = document.resolve_method(&fragment, None).unwrap().id().to_owned();
+  let (keys, key_ids) = (
+    storage.key_storage().snapshot().await,
+    storage.key_id_storage().snapshot().await,
+  );
+
+  // A failure to find the key id leaves everything untouched.
+  storage
+    .key_id_storage()
+    .fail_next(1, KeyIdStorageErrorKind::Unavailable);
+  let err = document.purge_method(&storage, &method_id).await.unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::KeyIdStorageError(_)));
+  assert!(document.resolve_method(&fragment, None).is_some());
+
+  // A failure to delete the key restores the deleted key id and the method.
+  storage.key_storage().fail_next(1, KeyStorageErrorKind::Unavailable);
+  let err = document.purge_method(&storage, &method_id).await.unwrap_err();
+  assert!(matches!(err, JwkStorageDocumentError::KeyStorageError(_)));
+  assert!(document.resolve_method(&fragment, None).is_some());
+  assert_eq!(storage.key_id_storage().count().await, 1);
+  assert_eq!(storage.key_storage().count().await, 1);
+
+  // Restoring the storages makes a purged method usable again.
+  let original_document = document.clone();
+  document.purge_method(&storage, &method_id).await.unwrap();
+  assert_eq!(storage.key_storage().count().await, 0);
+  storage.key_storage().restore(&keys).await;
+  storage.key_id_storage().restore(&key_ids).await;
+  assert!(original_document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+}
+
+#[cfg(feature = "chaos")]
+#[tokio::test]
+async fn generation_rollback_under_chaos() {
+  use crate::chaos::ChaosConfig;
+  use crate::chaos::ChaosJwkStorage;
+  use crate::chaos::ChaosKeyIdStorage;
+
+  let mut document = CoreDocument::from_json(MOCK_DOCUMENT_JSON).unwrap();
+  let config = ChaosConfig::new().failure_rate(0.4).seed(7);
+  let storage = Storage::new(
+    ChaosJwkStorage::new(JwkMemStore::new(), config.clone()),
+    ChaosKeyIdStorage::new(KeyIdMemstore::new(), config.seed(11)),
+  );
+
+  let (mut generated, mut stray_keys) = (0, 0);
+  for _ in 0..50 {
+    match document
+      .generate_method(
+        &storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        None,
+        MethodScope::VerificationMethod,
+      )
+      .await
+    {
+      Ok(_) => generated += 1,
+      Err(JwkStorageDocumentError::UndoOperationFailed { .. }) => stray_keys += 1,
+      Err(_) => (),
+    }
+  }
+
+  // Every method of the document is backed by storage, and only failed undos leave keys behind.
+  assert!(storage.key_storage().injected_faults() > 0 && storage.key_id_storage().injected_faults() > 0);
+  assert_eq!(document.methods(None).len(), generated + 1);
+  assert_eq!(storage.key_id_storage().inner().count().await, generated);
+  assert_eq!(storage.key_storage().inner().count().await, generated + stray_keys);
+}
+
+#[tokio::test]
+async fn available_signing_candidates() {
+  let (mut document, storage) = setup();
+  let other_storage: MemStorage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let mut fragments: Vec<String> = Vec::new();
+  for storage in [&storage, &other_storage] {
+    let fragment: String = document
+      .generate_method(
+        storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        None,
+        MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod),
+      )
+      .await
+      .unwrap();
+    fragments.push(fragment);
+  }
+
+  for (storage, fragment) in [(&storage, &fragments[0]), (&other_storage, &fragments[1])] {
+    let candidates = document
+      .available_signing_candidates(storage, MethodRelationship::AssertionMethod, JwsAlgorithm::EdDSA)
+      .await
+      .unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].id().fragment(), Some(fragment.as_str()));
+  }
+
+  assert_eq!(
+    document
+      .signing_candidates(MethodRelationship::AssertionMethod, JwsAlgorithm::EdDSA)
+      .len(),
+    2
+  );
+  assert!(document
+    .available_signing_candidates(&storage, MethodRelationship::Authentication, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap()
+    .is_empty());
+  assert!(document
+    .available_signing_candidates(&storage, MethodRelationship::AssertionMethod, JwsAlgorithm::ES256)
+    .await
+    .unwrap()
+    .is_empty());
+}
+
 #[cfg(feature = "iota-document")]
 mod iota_document_tests {
   // Write a single test for the IotaDocument case just to check that it works
@@ -0,0 +1,199 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota_core::IotaDocument;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+
+use crate::key_storage::JwkMemStore;
+use crate::storage::tests::test_utils;
+use crate::storage::tests::test_utils::Setup;
+use crate::storage::DidDocumentPublisher;
+use crate::storage::JwkDocumentExt;
+use crate::storage::KeyCompromiseResponse;
+use crate::storage::KeyCompromiseResponseError;
+use crate::storage::KEY_COMPROMISE_NOTICE_TYPE;
+
+struct AcceptingPublisher;
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl DidDocumentPublisher for AcceptingPublisher {
+  async fn publish_document(
+    &self,
+    document: IotaDocument,
+  ) -> Result<IotaDocument, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    Ok(document)
+  }
+}
+
+struct FailingPublisher;
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl DidDocumentPublisher for FailingPublisher {
+  async fn publish_document(
+    &self,
+    _document: IotaDocument,
+  ) -> Result<IotaDocument, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    Err("node unavailable".into())
+  }
+}
+
+/// Returns a document with an embedded assertion method and a generic method referenced by two relationships.
+async fn setup() -> Setup<IotaDocument, IotaDocument> {
+  let mut setup = test_utils::setup_iotadocument(Some("key-0"), None).await;
+  setup
+    .issuer_doc
+    .generate_method(
+      &setup.issuer_storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("key-1"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  for relationship in [MethodRelationship::Authentication, MethodRelationship::AssertionMethod] {
+    setup
+      .issuer_doc
+      .attach_method_relationship("key-1", relationship)
+      .unwrap();
+  }
+
+  setup
+}
+
+#[tokio::test]
+async fn rotates_compromised_method() {
+  let Setup {
+    issuer_doc: mut document,
+    issuer_storage: storage,
+    ..
+  } = setup().await;
+
+  let report = KeyCompromiseResponse::new("key-1")
+    .rotate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, Some("key-2"))
+    .reason("leaked")
+    .execute(&mut document, &storage, &AcceptingPublisher)
+    .await
+    .unwrap();
+
+  assert!(document.resolve_method("key-1", None).is_none());
+  for relationship in [MethodRelationship::Authentication, MethodRelationship::AssertionMethod] {
+    assert!(document
+      .resolve_method("key-2", Some(MethodScope::VerificationRelationship(relationship)))
+      .is_some());
+  }
+  assert_eq!(
+    report.relationships,
+    [MethodRelationship::Authentication, MethodRelationship::AssertionMethod]
+  );
+  assert_eq!(
+    report.replacement_method.as_ref().and_then(|id| id.fragment()),
+    Some("key-2")
+  );
+  assert!(report.key_deleted);
+  assert_eq!(storage.key_storage().count().await, 2);
+  assert_eq!(document.metadata.updated, Some(report.executed_at));
+
+  let notice = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+    .validate::<_, Object>(
+      report.incident_notice.as_ref().unwrap(),
+      &document,
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap()
+    .credential;
+  assert!(notice.types.iter().any(|type_| type_ == KEY_COMPROMISE_NOTICE_TYPE));
+  let subject = notice.credential_subject.iter().next().unwrap();
+  assert_eq!(
+    subject.properties["compromisedMethod"],
+    report.compromised_method.to_string()
+  );
+  assert_eq!(subject.properties["reason"], "leaked");
+}
+
+#[tokio::test]
+async fn embedded_methods_are_replaced_in_place() {
+  let Setup {
+    issuer_doc: mut document,
+    issuer_storage: storage,
+    ..
+  } = setup().await;
+
+  let report = KeyCompromiseResponse::new("key-0")
+    .rotate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, None)
+    .execute(&mut document, &storage, &AcceptingPublisher)
+    .await
+    .unwrap();
+
+  let replacement = report.replacement_method.unwrap();
+  assert_eq!(report.relationships, [MethodRelationship::AssertionMethod]);
+  assert!(document
+    .resolve_method(&replacement, Some(MethodScope::assertion_method()))
+    .is_some());
+  assert!(document
+    .resolve_method(&replacement, Some(MethodScope::VerificationMethod))
+    .is_none());
+}
+
+#[tokio::test]
+async fn failed_publication_leaves_document_and_storage_untouched() {
+  let Setup {
+    issuer_doc: mut document,
+    issuer_storage: storage,
+    ..
+  } = setup().await;
+  let original = document.clone();
+
+  let error = KeyCompromiseResponse::new("key-1")
+    .rotate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, Some("key-2"))
+    .execute(&mut document, &storage, &FailingPublisher)
+    .await
+    .unwrap_err();
+
+  assert!(matches!(error, KeyCompromiseResponseError::Publication(_)));
+  assert_eq!(document, original);
+  assert_eq!(storage.key_storage().count().await, 2);
+  assert_eq!(storage.key_id_storage().count().await, 2);
+}
+
+#[tokio::test]
+async fn incident_notice_requires_an_uncompromised_signer() {
+  let Setup {
+    issuer_doc: mut document,
+    issuer_storage: storage,
+    ..
+  } = setup().await;
+
+  let error = KeyCompromiseResponse::new("key-1")
+    .execute(&mut document, &storage, &AcceptingPublisher)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, KeyCompromiseResponseError::MissingNoticeSigner));
+
+  let error = KeyCompromiseResponse::new("key-1")
+    .notice_signed_with("key-1")
+    .execute(&mut document, &storage, &AcceptingPublisher)
+    .await
+    .unwrap_err();
+  assert!(matches!(error, KeyCompromiseResponseError::CompromisedNoticeSigner));
+
+  let report = KeyCompromiseResponse::new("key-1")
+    .notice_signed_with("key-0")
+    .execute(&mut document, &storage, &AcceptingPublisher)
+    .await
+    .unwrap();
+  assert!(report.replacement_method.is_none());
+  assert!(report.incident_notice.is_some());
+  assert_eq!(storage.key_storage().count().await, 1);
+}
@@ -14,12 +14,22 @@ use identity_credential::credential::CredentialBuilder;
 use identity_credential::credential::Subject;
 use identity_credential::sd_jwt_payload::SdJwt;
 use identity_credential::sd_jwt_payload::Sha256Hasher;
+use identity_credential::validator::FailFast;
 use identity_credential::validator::JwtCredentialValidationOptions;
 use identity_credential::validator::KeyBindingJwtError;
 use identity_credential::validator::KeyBindingJwtValidationOptions;
 use identity_credential::validator::SdJwtCredentialValidator;
+use identity_credential::validator::SdJwtPresentationValidationError;
+use identity_credential::validator::SdJwtPresentationValidationOptions;
+use identity_credential::validator::SubjectHolderRelationship;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_iota_core::IotaDocument;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jwu::decode_b64;
+use identity_verification::MethodBuilder;
+use identity_verification::MethodData;
+use identity_verification::MethodScope;
+use identity_verification::MethodType;
 use sd_jwt::KeyBindingJwtBuilder;
 use sd_jwt::RequiredKeyBinding;
 use sd_jwt::SdJwtBuilder;
@@ -29,6 +39,13 @@ const NONCE: &str = "nonce-test";
 const VERIFIER_ID: &str = "did:test:verifier";
 
 async fn setup_test() -> anyhow::Result<(Setup<IotaDocument, IotaDocument>, Credential, SdJwt)> {
+  setup_test_with_binding(false).await
+}
+
+/// Issues an SD-JWT bound to the holder's key, referenced through its `kid` or - if `jwk_binding` - embedded as a JWK.
+async fn setup_test_with_binding(
+  jwk_binding: bool,
+) -> anyhow::Result<(Setup<IotaDocument, IotaDocument>, Credential, SdJwt)> {
   let setup: Setup<IotaDocument, IotaDocument> = setup_iotadocument(Some("issuer-key"), Some("holder-key")).await;
 
   let subject: Subject = Subject::from_json_value(json!({
@@ -53,14 +70,25 @@ async fn setup_test() -> anyhow::Result<(Setup<IotaDocument, IotaDocument>, Cred
     StorageSigner::new_from_vm_fragment(&setup.issuer_storage, &setup.issuer_doc, &setup.issuer_method_fragment)
       .await?;
   let holder_kid = format!("{}#{}", setup.subject_doc.id(), &setup.subject_method_fragment);
+  let required_key_binding = if jwk_binding {
+    let holder_jwk = setup
+      .subject_doc
+      .resolve_method(holder_kid.as_str(), None)
+      .and_then(|method| method.data().public_key_jwk())
+      .unwrap();
+    RequiredKeyBinding::Jwk(serde_json::to_value(holder_jwk)?.as_object().unwrap().clone())
+  } else {
+    RequiredKeyBinding::Kid(holder_kid.clone())
+  };
   let mut sd_jwt = SdJwtBuilder::new(credential.to_jwt_claims(None)?)?
     .make_concealable("/vc/credentialSubject/degree/type")?
     .make_concealable("/vc/credentialSubject/degree/name")?
+    .make_concealable("/sub")?
     .header(
       "kid",
       format!("{}#{}", setup.issuer_doc.id(), &setup.issuer_method_fragment),
     )
-    .require_key_binding(RequiredKeyBinding::Kid(holder_kid.clone()))
+    .require_key_binding(required_key_binding)
     .finish(&issuer_signer, "EdDSA")
     .await?;
 
@@ -144,3 +172,251 @@ async fn kb_too_late() -> anyhow::Result<()> {
 
   Ok(())
 }
+
+#[tokio::test]
+async fn kb_holder_binding() -> anyhow::Result<()> {
+  let (setup, _credential, sd_jwt) = setup_test().await?;
+  let validator = SdJwtCredentialValidator::new(EdDSAJwsVerifier::default(), Sha256Hasher);
+  let options = KeyBindingJwtValidationOptions::new()
+    .nonce(NONCE)
+    .aud(VERIFIER_ID)
+    .holder_binding_claim("sub");
+  validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options)?;
+
+  // The issuer's DID in `iss` is not the holder's.
+  let options = options.holder_binding_claim("iss");
+  let kb_validation = validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options);
+  assert!(matches!(
+    kb_validation.err().unwrap(),
+    KeyBindingJwtError::HolderBindingMismatch(_)
+  ));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn sd_jwt_presentation_validation() -> anyhow::Result<()> {
+  let (setup, credential, sd_jwt) = setup_test().await?;
+  let validator = SdJwtCredentialValidator::new(EdDSAJwsVerifier::default(), Sha256Hasher);
+  let options = SdJwtPresentationValidationOptions::new()
+    .key_binding_validation_options(KeyBindingJwtValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID))
+    .require_key_binding(true)
+    .subject_holder_relationship(SubjectHolderRelationship::AlwaysSubject);
+
+  let presentation = validator.validate_presentation::<_, _, Object>(
+    &sd_jwt,
+    std::slice::from_ref(&setup.issuer_doc),
+    &setup.subject_doc,
+    &options,
+    FailFast::FirstError,
+  )?;
+  assert_eq!(presentation.credential, credential);
+  assert!(presentation.key_bound);
+  assert_eq!(presentation.disclosure_stats.disclosed, 2);
+
+  // The issuer is not the holder: both the KB-JWT and the subject-holder relationship are reported.
+  let validation_errors = validator
+    .validate_presentation::<_, _, Object>(
+      &sd_jwt,
+      std::slice::from_ref(&setup.issuer_doc),
+      &setup.issuer_doc,
+      &options,
+      FailFast::AllErrors,
+    )
+    .unwrap_err()
+    .validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [
+      SdJwtPresentationValidationError::KeyBinding(_),
+      SdJwtPresentationValidationError::SubjectHolderRelationship(_)
+    ]
+  ));
+
+  let validation_errors = validator
+    .validate_presentation::<_, _, Object>(
+      &sd_jwt,
+      std::slice::from_ref(&setup.issuer_doc),
+      &setup.issuer_doc,
+      &options,
+      FailFast::FirstError,
+    )
+    .unwrap_err()
+    .validation_errors;
+  assert_eq!(validation_errors.len(), 1);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn sd_jwt_presentation_validation_binds_cnf_jwk_to_holder() -> anyhow::Result<()> {
+  let (setup, _credential, sd_jwt) = setup_test_with_binding(true).await?;
+  let validator = SdJwtCredentialValidator::new(EdDSAJwsVerifier::default(), Sha256Hasher);
+  let options = SdJwtPresentationValidationOptions::new()
+    .key_binding_validation_options(KeyBindingJwtValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID))
+    .require_key_binding(true);
+
+  validator.validate_presentation::<_, _, Object>(
+    &sd_jwt,
+    std::slice::from_ref(&setup.issuer_doc),
+    &setup.subject_doc,
+    &options,
+    FailFast::AllErrors,
+  )?;
+
+  // The KB-JWT is signed with the `cnf` key, but the key doesn't belong to the presented holder.
+  let validation_errors = validator
+    .validate_presentation::<_, _, Object>(
+      &sd_jwt,
+      std::slice::from_ref(&setup.issuer_doc),
+      &setup.issuer_doc,
+      &options,
+      FailFast::AllErrors,
+    )
+    .unwrap_err()
+    .validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [SdJwtPresentationValidationError::UnboundHolderKey]
+  ));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn sd_jwt_presentation_validation_binds_cnf_jwk_to_legacy_holder_key() -> anyhow::Result<()> {
+  let (setup, _credential, sd_jwt) = setup_test_with_binding(true).await?;
+  let validator = SdJwtCredentialValidator::new(EdDSAJwsVerifier::default(), Sha256Hasher);
+  let options = SdJwtPresentationValidationOptions::new()
+    .key_binding_validation_options(KeyBindingJwtValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID))
+    .require_key_binding(true);
+
+  // The holder's key is expressed through an `Ed25519VerificationKey2018` method instead of a JWK.
+  let mut holder_doc = setup.subject_doc.clone();
+  let holder_kid = format!("{}#{}", setup.subject_doc.id(), &setup.subject_method_fragment);
+  let method_id = holder_doc
+    .resolve_method(holder_kid.as_str(), None)
+    .unwrap()
+    .id()
+    .clone();
+  let method = holder_doc.remove_method(&method_id).unwrap();
+  let params: &JwkParamsOkp = method.data().public_key_jwk().unwrap().try_okp_params()?;
+  let legacy_method = MethodBuilder::default()
+    .id(method_id)
+    .controller(method.controller().clone())
+    .type_(MethodType::ED25519_VERIFICATION_KEY_2018)
+    .data(MethodData::new_base58(decode_b64(&params.x)?))
+    .build()?;
+  holder_doc.insert_method(legacy_method, MethodScope::VerificationMethod)?;
+
+  validator.validate_presentation::<_, _, Object>(
+    &sd_jwt,
+    std::slice::from_ref(&setup.issuer_doc),
+    &holder_doc,
+    &options,
+    FailFast::AllErrors,
+  )?;
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn kb_holder_binding_claim_must_be_a_disclosed_did() -> anyhow::Result<()> {
+  let (setup, _credential, sd_jwt) = setup_test().await?;
+  let validator = SdJwtCredentialValidator::new(EdDSAJwsVerifier::default(), Sha256Hasher);
+  let options = KeyBindingJwtValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID);
+
+  // The claim is missing.
+  let kb_validation = validator.validate_key_binding_jwt(
+    &sd_jwt,
+    &setup.subject_doc,
+    &options.clone().holder_binding_claim("holder"),
+  );
+  assert!(matches!(
+    kb_validation.err().unwrap(),
+    KeyBindingJwtError::HolderBindingClaimNotDisclosed(claim) if claim == "holder"
+  ));
+
+  // The claim's value is not a DID.
+  let kb_validation = validator.validate_key_binding_jwt(
+    &sd_jwt,
+    &setup.subject_doc,
+    &options.clone().holder_binding_claim("jti"),
+  );
+  assert!(matches!(
+    kb_validation.err().unwrap(),
+    KeyBindingJwtError::InvalidHolderBindingClaim(_)
+  ));
+
+  // The claim is concealed by the holder.
+  let (mut sd_jwt, _) = sd_jwt.into_presentation(&Sha256Hasher)?.conceal("/sub")?.finish();
+  let holder_signer = StorageSigner::new_from_vm_fragment(
+    &setup.subject_storage,
+    &setup.subject_doc,
+    &setup.subject_method_fragment,
+  )
+  .await?;
+  let kb_jwt = KeyBindingJwtBuilder::new()
+    .aud(VERIFIER_ID)
+    .nonce(NONCE)
+    .iat(Timestamp::now_utc().to_unix())
+    .header(
+      "kid",
+      format!("{}#{}", setup.subject_doc.id(), &setup.subject_method_fragment),
+    )
+    .finish(&sd_jwt, &Sha256Hasher, "EdDSA", &holder_signer)
+    .await?;
+  sd_jwt.attach_key_binding_jwt(kb_jwt);
+  validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options)?;
+  let kb_validation =
+    validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options.holder_binding_claim("sub"));
+  assert!(matches!(
+    kb_validation.err().unwrap(),
+    KeyBindingJwtError::HolderBindingClaimNotDisclosed(_)
+  ));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn kb_not_before() -> anyhow::Result<()> {
+  let (setup, _credential, sd_jwt) = setup_test().await?;
+  let validator = SdJwtCredentialValidator::new(EdDSAJwsVerifier::default(), Sha256Hasher);
+  let options = KeyBindingJwtValidationOptions::new().nonce(NONCE).aud(VERIFIER_ID);
+
+  // The `nbf` claim is required but missing.
+  let kb_validation =
+    validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options.clone().require_not_before(true));
+  assert!(matches!(kb_validation.err().unwrap(), KeyBindingJwtError::NotBefore(_)));
+
+  // The KB-JWT only becomes valid in a minute.
+  let (mut sd_jwt, _) = sd_jwt.into_presentation(&Sha256Hasher)?.finish();
+  let holder_signer = StorageSigner::new_from_vm_fragment(
+    &setup.subject_storage,
+    &setup.subject_doc,
+    &setup.subject_method_fragment,
+  )
+  .await?;
+  let not_before = Timestamp::now_utc().checked_add(Duration::seconds(60)).unwrap();
+  let kb_jwt = KeyBindingJwtBuilder::new()
+    .aud(VERIFIER_ID)
+    .nonce(NONCE)
+    .iat(Timestamp::now_utc().to_unix())
+    .insert_property("nbf", json!(not_before.to_unix()))
+    .header(
+      "kid",
+      format!("{}#{}", setup.subject_doc.id(), &setup.subject_method_fragment),
+    )
+    .finish(&sd_jwt, &Sha256Hasher, "EdDSA", &holder_signer)
+    .await?;
+  sd_jwt.attach_key_binding_jwt(kb_jwt);
+
+  let kb_validation = validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options);
+  assert!(matches!(kb_validation.err().unwrap(), KeyBindingJwtError::NotBefore(_)));
+
+  // A leeway covering the clock skew makes it valid.
+  let options = options.require_not_before(true).leeway(Duration::minutes(2));
+  validator.validate_key_binding_jwt(&sd_jwt, &setup.subject_doc, &options)?;
+
+  Ok(())
+}
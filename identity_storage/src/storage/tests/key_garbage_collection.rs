@@ -0,0 +1,132 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+
+use crate::key_id_storage::KeyIdStorageErrorKind;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::storage::JwkDocumentExt;
+use crate::storage::KeyGarbageCollectionError;
+use crate::storage::KeyGarbageCollector;
+
+use super::test_utils::setup_coredocument;
+use super::test_utils::Setup;
+
+#[tokio::test]
+async fn orphaned_keys_are_collected() {
+  let Setup {
+    issuer_doc: mut document,
+    issuer_storage: storage,
+    ..
+  } = setup_coredocument(Some("key-1"), None).await;
+
+  // A key whose method was removed from the document.
+  document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("key-2"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let removed_method = document.resolve_method("key-2", None).unwrap().id().clone();
+  document.remove_method(&removed_method).unwrap();
+  // A key without any mapping.
+  let stray_key = storage
+    .key_storage()
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap()
+    .key_id;
+
+  let report = KeyGarbageCollector::new().collect(&storage, [&document]).await.unwrap();
+  assert!(report.dry_run);
+  assert_eq!(report.orphaned_keys.len(), 2);
+  assert!(report.orphaned_keys.contains(&stray_key));
+  assert_eq!(report.orphaned_mappings.len(), 1);
+  assert!(report.missing_keys.is_empty());
+  assert_eq!(storage.key_storage().count().await, 3);
+  assert_eq!(storage.key_id_storage().count().await, 2);
+
+  let report = KeyGarbageCollector::new()
+    .dry_run(false)
+    .collect(&storage, [&document])
+    .await
+    .unwrap();
+  assert!(!report.dry_run);
+  assert_eq!(report.orphaned_keys.len(), 2);
+  assert_eq!(storage.key_storage().count().await, 1);
+  assert_eq!(storage.key_id_storage().count().await, 1);
+
+  let report = KeyGarbageCollector::new().collect(&storage, [&document]).await.unwrap();
+  assert!(report.is_clean());
+  assert!(document.resolve_method("key-1", None).is_some());
+}
+
+#[tokio::test]
+async fn failed_deletions_are_reported() {
+  let Setup {
+    issuer_doc: document,
+    issuer_storage: storage,
+    ..
+  } = setup_coredocument(None, None).await;
+
+  storage
+    .key_id_storage()
+    .fail_next(1, KeyIdStorageErrorKind::RetryableIOFailure);
+  let error = KeyGarbageCollector::new()
+    .collect(&storage, [&document])
+    .await
+    .unwrap_err();
+  assert!(matches!(error, KeyGarbageCollectionError::KeyIdListing(_)));
+
+  // Without the document, its key is orphaned.
+  let documents: [&identity_document::document::CoreDocument; 0] = [];
+  let report = KeyGarbageCollector::new()
+    .dry_run(false)
+    .collect(&storage, documents)
+    .await
+    .unwrap();
+  assert_eq!(report.orphaned_keys.len(), 1);
+  assert_eq!(storage.key_storage().count().await, 0);
+  assert_eq!(storage.key_id_storage().count().await, 0);
+}
+
+#[cfg(all(feature = "hybrid", feature = "pqc-liboqs"))]
+#[tokio::test]
+async fn hybrid_method_keys_are_not_collected() {
+  use identity_verification::jwk::CompositeAlgId;
+
+  use crate::storage::JwkDocumentExtHybrid;
+
+  let Setup {
+    issuer_doc: mut document,
+    issuer_storage: storage,
+    ..
+  } = setup_coredocument(None, None).await;
+  document
+    .generate_method_hybrid(
+      &storage,
+      CompositeAlgId::IdMldsa44Ed25519,
+      Some("hybrid-key"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  // The traditional and the post-quantum key of the hybrid method, besides the key of the setup.
+  assert_eq!(storage.key_storage().count().await, 3);
+
+  let report = KeyGarbageCollector::new()
+    .dry_run(false)
+    .collect(&storage, [&document])
+    .await
+    .unwrap();
+  assert!(report.is_clean());
+  assert!(report.missing_keys.is_empty());
+  assert_eq!(storage.key_storage().count().await, 3);
+  assert_eq!(storage.key_id_storage().count().await, 2);
+}
@@ -2,8 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod api;
+#[cfg(feature = "iota-document")]
+mod compromise_response;
 mod credential_jws;
 mod credential_validation;
 mod kb_jwt;
+mod key_garbage_collection;
+#[cfg(feature = "storage-signer")]
+mod multisig_signer;
 mod presentation_validation;
+#[cfg(feature = "backup")]
+mod recovery_bundle;
+#[cfg(feature = "status-list-2021")]
+mod status_list_update;
 pub(crate) mod test_utils;
@@ -0,0 +1,249 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use async_trait::async_trait;
+use identity_verification::jws::JwsAlgorithm;
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::base_types::SequenceNumber;
+use iota_interaction::types::crypto::PublicKey;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::digests::ObjectDigest;
+use iota_interaction::types::multisig::MultiSig;
+use iota_interaction::types::signature::GenericSignature;
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::IotaKeySignature;
+use secret_storage::Error as SecretStorageError;
+use secret_storage::Signer;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkGenOutput;
+use crate::key_storage::JwkMemStore;
+use crate::JwkStorage;
+use crate::KeyId;
+use crate::MultiSigSigner;
+use crate::MultiSigSignerError;
+use crate::Storage;
+use crate::StorageSigner;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+/// A [`StorageSigner`] counting how many times it has been asked to sign, that can be made unreachable.
+struct TestSigner<'s> {
+  inner: StorageSigner<'s, JwkMemStore, KeyIdMemstore>,
+  reachable: bool,
+  sign_calls: AtomicUsize,
+}
+
+impl TestSigner<'_> {
+  fn unreachable(mut self) -> Self {
+    self.reachable = false;
+    self
+  }
+
+  fn sign_calls(&self) -> usize {
+    self.sign_calls.load(Ordering::SeqCst)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl Signer<IotaKeySignature> for TestSigner<'_> {
+  type KeyId = KeyId;
+
+  fn key_id(&self) -> KeyId {
+    self.inner.key_id().clone()
+  }
+
+  async fn public_key(&self) -> Result<PublicKey, SecretStorageError> {
+    self.inner.public_key().await
+  }
+
+  async fn sign(&self, data: &TransactionData) -> Result<Signature, SecretStorageError> {
+    self.sign_calls.fetch_add(1, Ordering::SeqCst);
+    if !self.reachable {
+      return Err(SecretStorageError::StoreDisconnected("signer is offline".to_owned()));
+    }
+
+    self.inner.sign(data).await
+  }
+}
+
+async fn generate_signer(storage: &MemStorage) -> TestSigner<'_> {
+  let JwkGenOutput { key_id, jwk } = storage
+    .key_storage()
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+
+  TestSigner {
+    inner: StorageSigner::new(storage, key_id, jwk),
+    reachable: true,
+    sign_calls: AtomicUsize::new(0),
+  }
+}
+
+/// Generates `n` signers, returning them together with their public keys.
+async fn generate_signers(storage: &MemStorage, n: usize) -> (Vec<TestSigner<'_>>, Vec<PublicKey>) {
+  let mut signers = Vec::with_capacity(n);
+  let mut public_keys = Vec::with_capacity(n);
+  for _ in 0..n {
+    let signer = generate_signer(storage).await;
+    public_keys.push(signer.public_key().await.unwrap());
+    signers.push(signer);
+  }
+
+  (signers, public_keys)
+}
+
+fn tx_data(sender: IotaAddress) -> TransactionData {
+  TransactionData::new_transfer_iota(
+    IotaAddress::ZERO,
+    sender,
+    (ObjectID::ZERO, SequenceNumber::new(), ObjectDigest::random()),
+    1_000,
+    1_000,
+    None,
+  )
+}
+
+fn expect_multisig(signature: GenericSignature) -> MultiSig {
+  match signature {
+    GenericSignature::MultiSig(multisig) => multisig,
+    _ => panic!("expected a multisig signature"),
+  }
+}
+
+#[tokio::test]
+async fn signers_are_only_asked_until_the_threshold_is_reached() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (signers, public_keys) = generate_signers(&storage, 3).await;
+  let weighted_keys = public_keys.into_iter().map(|public_key| (public_key, 1)).collect();
+  let multisig_signer = MultiSigSigner::from_public_keys(weighted_keys, 2, signers).unwrap();
+  let tx_data = tx_data(multisig_signer.address());
+
+  let multisig = expect_multisig(multisig_signer.sign(&tx_data).await.unwrap());
+
+  assert_eq!(multisig.get_sigs().len(), 2);
+  assert_eq!(multisig.get_indices().unwrap(), vec![0, 1]);
+  let sign_calls: Vec<usize> = multisig_signer.signers().iter().map(TestSigner::sign_calls).collect();
+  assert_eq!(sign_calls, vec![1, 1, 0]);
+}
+
+#[tokio::test]
+async fn weights_count_towards_the_threshold() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (signers, public_keys) = generate_signers(&storage, 3).await;
+  let weighted_keys = public_keys.into_iter().zip([1, 3, 1]).collect();
+  // The heaviest key alone reaches the threshold.
+  let mut signers = signers.into_iter();
+  let heaviest = signers.nth(1).unwrap();
+  let multisig_signer = MultiSigSigner::from_public_keys(weighted_keys, 3, vec![heaviest]).unwrap();
+  let tx_data = tx_data(multisig_signer.address());
+
+  let multisig = expect_multisig(multisig_signer.sign(&tx_data).await.unwrap());
+
+  assert_eq!(multisig.get_indices().unwrap(), vec![1]);
+}
+
+#[tokio::test]
+async fn signatures_are_ordered_as_the_public_keys() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (signers, public_keys) = generate_signers(&storage, 3).await;
+  let weighted_keys = public_keys.into_iter().map(|public_key| (public_key, 1)).collect();
+  // Signers are given in the reverse order of their public keys.
+  let signers: Vec<_> = signers.into_iter().rev().collect();
+  let multisig_signer = MultiSigSigner::from_public_keys(weighted_keys, 3, signers).unwrap();
+  let tx_data = tx_data(multisig_signer.address());
+
+  let mut signatures = Vec::new();
+  for signer in multisig_signer.signers() {
+    signatures.push(signer.inner.sign(&tx_data).await.unwrap());
+  }
+  let expected_sigs: Vec<_> = signatures
+    .iter()
+    .rev()
+    .map(|signature| signature.to_compressed().unwrap())
+    .collect();
+
+  let multisig = expect_multisig(multisig_signer.sign(&tx_data).await.unwrap());
+  assert_eq!(multisig.get_indices().unwrap(), vec![0, 1, 2]);
+  assert_eq!(multisig.get_sigs(), expected_sigs.as_slice());
+
+  // Signatures collected by other means are reordered as well.
+  let combined = expect_multisig(multisig_signer.combine(signatures).unwrap());
+  assert_eq!(combined.get_indices().unwrap(), vec![0, 1, 2]);
+  assert_eq!(combined.get_sigs(), expected_sigs.as_slice());
+}
+
+#[tokio::test]
+async fn unreachable_signers_are_skipped() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (mut signers, public_keys) = generate_signers(&storage, 3).await;
+  let weighted_keys = public_keys.into_iter().map(|public_key| (public_key, 1)).collect();
+  let unreachable = signers.remove(0).unreachable();
+  signers.insert(0, unreachable);
+  let multisig_signer = MultiSigSigner::from_public_keys(weighted_keys, 2, signers).unwrap();
+  let tx_data = tx_data(multisig_signer.address());
+
+  let multisig = expect_multisig(multisig_signer.sign(&tx_data).await.unwrap());
+
+  assert_eq!(multisig.get_indices().unwrap(), vec![1, 2]);
+}
+
+#[tokio::test]
+async fn threshold_not_reached() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (mut signers, public_keys) = generate_signers(&storage, 3).await;
+  let weighted_keys = public_keys.into_iter().map(|public_key| (public_key, 1)).collect();
+  // Only two of the three members are available, and one of them is unreachable.
+  signers.truncate(2);
+  let unreachable = signers.pop().unwrap().unreachable();
+  signers.push(unreachable);
+  let multisig_signer = MultiSigSigner::from_public_keys(weighted_keys, 2, signers).unwrap();
+  let tx_data = tx_data(multisig_signer.address());
+
+  let error = multisig_signer.sign(&tx_data).await.unwrap_err();
+
+  assert!(matches!(
+    error,
+    MultiSigSignerError::ThresholdNotReached {
+      weight: 1,
+      threshold: 2,
+      ref failures,
+    } if failures.len() == 1
+  ));
+}
+
+#[tokio::test]
+async fn non_members_are_rejected() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (_, public_keys) = generate_signers(&storage, 2).await;
+  let outsider = generate_signer(&storage).await;
+  let outsider_public_key = outsider.public_key().await.unwrap();
+  let weighted_keys = public_keys.into_iter().map(|public_key| (public_key, 1)).collect();
+  let multisig_signer = MultiSigSigner::from_public_keys(weighted_keys, 1, vec![outsider]).unwrap();
+  let tx_data = tx_data(multisig_signer.address());
+
+  let error = multisig_signer.sign(&tx_data).await.unwrap_err();
+
+  assert!(matches!(error, MultiSigSignerError::NotAMember(public_key) if public_key == outsider_public_key));
+  assert_eq!(multisig_signer.signers()[0].sign_calls(), 0);
+}
+
+#[tokio::test]
+async fn invalid_multisigs_are_rejected() {
+  let storage = MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let (_, public_keys) = generate_signers(&storage, 2).await;
+  let weighted_keys: Vec<_> = public_keys.into_iter().map(|public_key| (public_key, 1)).collect();
+
+  // The threshold cannot be reached by the members' combined weight.
+  let result = MultiSigSigner::<TestSigner<'_>>::from_public_keys(weighted_keys.clone(), 3, vec![]);
+  assert!(matches!(result, Err(MultiSigSignerError::InvalidMultiSigPublicKey(_))));
+
+  let result = MultiSigSigner::<TestSigner<'_>>::from_public_keys(weighted_keys, 0, vec![]);
+  assert!(matches!(result, Err(MultiSigSignerError::InvalidMultiSigPublicKey(_))));
+}
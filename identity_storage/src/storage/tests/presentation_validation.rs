@@ -5,12 +5,18 @@ use identity_core::common::Duration;
 use identity_core::common::Object;
 use identity_core::common::StringOrUrl;
 use identity_core::common::Timestamp;
+use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::CredentialV2;
+use identity_credential::credential::EnvelopedVc;
 use identity_credential::credential::Jwt;
+use identity_credential::credential::Subject;
 use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_credential::presentation::PresentationBuilder;
+use identity_credential::presentation::PresentationV2Builder;
 use identity_credential::validator::DecodedJwtPresentation;
 use identity_credential::validator::JwtPresentationValidationOptions;
 use identity_credential::validator::JwtPresentationValidator;
@@ -19,6 +25,7 @@ use identity_credential::validator::JwtValidationError;
 use identity_did::CoreDID;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_verification::jws::JwsAlgorithm;
 use identity_verification::MethodScope;
@@ -370,6 +377,80 @@ where
   assert!(validation_ok);
 }
 
+#[tokio::test]
+async fn max_age() {
+  max_age_impl(setup_coredocument(None, None).await).await;
+  max_age_impl(setup_iotadocument(None, None).await).await;
+}
+
+async fn max_age_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument> + Clone,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let jws = sign_credential(&setup, &credential.credential).await;
+
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .credential(jws)
+      .build()
+      .unwrap();
+
+  // Presentation issued 10 minutes ago.
+  let issuance_date = Timestamp::now_utc().checked_sub(Duration::minutes(10)).unwrap();
+  let presentation_options = JwtPresentationOptions {
+    issuance_date: Some(issuance_date),
+    expiration_date: None,
+    audience: None,
+    custom_claims: None,
+  };
+
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &presentation_options,
+    )
+    .await
+    .unwrap();
+
+  let validation_error: JwtValidationError = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default().max_age(60),
+    )
+    .err()
+    .unwrap()
+    .presentation_validation_errors
+    .into_iter()
+    .next()
+    .unwrap();
+  assert!(matches!(validation_error, JwtValidationError::MaxAgeExceeded(60)));
+
+  let decoded: DecodedJwtPresentation<Jwt> = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default().max_age(3600),
+    )
+    .unwrap();
+  assert_eq!(decoded.valid_until, issuance_date.checked_add(Duration::hours(1)));
+  assert!(decoded.remaining_validity().unwrap() <= std::time::Duration::from_secs(50 * 60));
+
+  // The presentation is issued with an `nbf` claim.
+  JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default().require_not_before(true),
+    )
+    .unwrap();
+}
+
 #[tokio::test]
 async fn presentation_jws_error() {
   presentation_jws_error_impl(setup_coredocument(None, None).await).await;
@@ -423,6 +504,88 @@ where
   ));
 }
 
+#[tokio::test]
+async fn test_presentation_jwt_v2() {
+  test_presentation_jwt_v2_impl(setup_coredocument(None, None).await).await;
+  test_presentation_jwt_v2_impl(setup_iotadocument(None, None).await).await;
+}
+async fn test_presentation_jwt_v2_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialV2 = CredentialBuilder::default()
+    .issuer(Url::parse(setup.issuer_doc.as_ref().id().as_str()).unwrap())
+    .type_("UniversityDegreeCredential")
+    .subject(Subject::with_id(setup.subject_doc.as_ref().id().to_url().into()))
+    .build_v2()
+    .unwrap();
+  let credential_jwt = setup
+    .issuer_doc
+    .create_credential_v2_jwt(
+      &credential,
+      &setup.issuer_storage,
+      &setup.issuer_method_fragment,
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let presentation: Presentation<EnvelopedVc> =
+    PresentationV2Builder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+      .jwt_credential(credential_jwt)
+      .build()
+      .unwrap();
+
+  let presentation_jwt = setup
+    .subject_doc
+    .create_presentation_jwt_v2(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let decoded_jws = setup
+    .subject_doc
+    .as_ref()
+    .verify_jws(
+      presentation_jwt.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::default(),
+    )
+    .unwrap();
+  assert_eq!(decoded_jws.protected.typ(), Some("vp+jwt"));
+
+  let decoded_presentation: DecodedJwtPresentation<EnvelopedVc> = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, EnvelopedVc, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default(),
+    )
+    .unwrap();
+  assert!(decoded_presentation.presentation.is_v2());
+  assert_eq!(decoded_presentation.presentation.verifiable_credential.len(), 1);
+
+  // VC Data Model 1.1 presentations are rejected.
+  let mut presentation_v1 = presentation;
+  presentation_v1.context = Credential::<()>::base_context().clone().into();
+  assert!(setup
+    .subject_doc
+    .create_presentation_jwt_v2(
+      &presentation_v1,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .is_err());
+}
+
 async fn sign_credential<T>(setup: &Setup<T, T>, credential: &Credential) -> Jwt
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
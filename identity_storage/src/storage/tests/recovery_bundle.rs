@@ -0,0 +1,115 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::EncryptedRecoveryBundle;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwsSignatureOptions;
+use crate::storage::RecoveryBundleError;
+use crate::Storage;
+
+use super::test_utils::setup_coredocument;
+use super::test_utils::Setup;
+
+#[tokio::test]
+async fn bundle_with_keys_restores_signing() {
+  let Setup {
+    issuer_doc: document,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = setup_coredocument(None, None).await;
+
+  let bundle = storage
+    .export_bundle_with_keys(std::slice::from_ref(&document), "correct horse")
+    .await
+    .unwrap();
+  let bundle = EncryptedRecoveryBundle::from_json_slice(&bundle.to_json_vec().unwrap()).unwrap();
+
+  let restored_storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  let error = restored_storage
+    .import_bundle::<CoreDocument>(&bundle, "battery staple")
+    .await
+    .unwrap_err();
+  assert!(matches!(error, RecoveryBundleError::Decryption));
+  assert_eq!(restored_storage.key_storage().count().await, 0);
+
+  let documents: Vec<CoreDocument> = restored_storage.import_bundle(&bundle, "correct horse").await.unwrap();
+  assert_eq!(documents, [document.clone()]);
+  assert_eq!(restored_storage.key_storage().count().await, 1);
+  assert_eq!(restored_storage.key_id_storage().count().await, 1);
+
+  let jws = documents[0]
+    .create_jws(&restored_storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new()
+    )
+    .is_ok());
+}
+
+#[tokio::test]
+async fn bundle_without_keys_restores_mappings() {
+  let Setup {
+    issuer_doc: document,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = setup_coredocument(None, None).await;
+
+  let bundle = storage
+    .export_bundle(std::slice::from_ref(&document), "correct horse")
+    .await
+    .unwrap();
+
+  // The keys survived, the key ids were lost.
+  storage
+    .key_id_storage()
+    .restore(&KeyIdMemstore::new().snapshot().await)
+    .await;
+  let _: Vec<CoreDocument> = storage.import_bundle(&bundle, "correct horse").await.unwrap();
+
+  assert_eq!(storage.key_storage().count().await, 1);
+  assert_eq!(storage.key_id_storage().count().await, 1);
+  assert!(document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .is_ok());
+}
+
+#[tokio::test]
+async fn bundles_with_out_of_range_iterations_are_rejected() {
+  let Setup {
+    issuer_doc: document,
+    issuer_storage: storage,
+    ..
+  } = setup_coredocument(None, None).await;
+
+  let bundle = storage
+    .export_bundle(std::slice::from_ref(&document), "correct horse")
+    .await
+    .unwrap();
+  let json: serde_json::Value = serde_json::from_slice(&bundle.to_json_vec().unwrap()).unwrap();
+
+  for iterations in [1, u32::MAX] {
+    let mut json = json.clone();
+    json["data"]["iterations"] = iterations.into();
+    let bundle = EncryptedRecoveryBundle::from_json_slice(&serde_json::to_vec(&json).unwrap()).unwrap();
+
+    let error = storage
+      .import_bundle::<CoreDocument>(&bundle, "correct horse")
+      .await
+      .unwrap_err();
+    assert!(matches!(error, RecoveryBundleError::UnsupportedIterations(value) if value == iterations));
+  }
+}
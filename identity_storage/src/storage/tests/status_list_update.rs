@@ -0,0 +1,372 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Issuer;
+use identity_credential::credential::Jwt;
+use identity_credential::revocation::status_list_2021::CredentialStatus;
+use identity_credential::revocation::status_list_2021::StatusList2021;
+use identity_credential::revocation::status_list_2021::StatusList2021Credential;
+use identity_credential::revocation::status_list_2021::StatusList2021CredentialBuilder;
+use identity_credential::revocation::status_list_2021::StatusList2021CredentialError;
+use identity_credential::revocation::status_list_2021::StatusList2021Entry;
+use identity_credential::revocation::status_list_2021::StatusListChange;
+use identity_credential::revocation::status_list_2021::StatusListManager;
+use identity_credential::revocation::status_list_2021::StatusPurpose;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_credential::validator::JwtValidationError;
+use identity_credential::validator::StatusCheck;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+use crate::status_list_index::IndexAllocatorConfig;
+use crate::status_list_index::IndexAllocatorMemstore;
+use crate::storage::tests::test_utils;
+use crate::storage::tests::test_utils::Setup;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwsSignatureOptions;
+use crate::storage::StatusListManagerExt;
+use crate::storage::StatusListPublisher;
+use crate::storage::StatusListUpdate;
+use crate::storage::StatusListUpdateError;
+
+const STATUS_LIST_URL: &str = "https://example.com/status/1";
+
+fn status_list(issuer_doc: &CoreDocument, purpose: StatusPurpose) -> StatusList2021Credential {
+  StatusList2021CredentialBuilder::new(StatusList2021::default())
+    .issuer(Issuer::Url(Url::parse(issuer_doc.id().as_str()).unwrap()))
+    .purpose(purpose)
+    .subject_id(Url::parse(STATUS_LIST_URL).unwrap())
+    .build()
+    .unwrap()
+}
+
+/// Checks that `jwt` is a valid signature of `issuer_doc` over a status list credential, which is returned.
+fn decode_status_list(jwt: &Jwt, issuer_doc: &CoreDocument) -> StatusList2021Credential {
+  let decoded = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+    .validate::<_, Object>(
+      jwt,
+      issuer_doc,
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap();
+
+  StatusList2021Credential::try_from(decoded.credential).unwrap()
+}
+
+#[derive(Default)]
+struct RecordingPublisher {
+  published: Mutex<Vec<(StatusList2021Credential, Jwt)>>,
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl StatusListPublisher for RecordingPublisher {
+  async fn publish(
+    &self,
+    status_list: &StatusList2021Credential,
+    jwt: &Jwt,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    self.published.lock().unwrap().push((status_list.clone(), jwt.clone()));
+    Ok(())
+  }
+}
+
+struct FailingPublisher;
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl StatusListPublisher for FailingPublisher {
+  async fn publish(
+    &self,
+    _status_list: &StatusList2021Credential,
+    _jwt: &Jwt,
+  ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    Err("unreachable endpoint".into())
+  }
+}
+
+#[tokio::test]
+async fn apply_changes_entries_and_resigns_the_status_list() {
+  let Setup {
+    issuer_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let mut status_list = status_list(&issuer_doc, StatusPurpose::Revocation);
+  status_list.set_entry(5, true).unwrap();
+  let issued_before = Timestamp::now_utc();
+
+  let update = StatusListUpdate::new()
+    .revoke(1)
+    .revoke(5)
+    .revoke(7)
+    .apply(
+      &mut status_list,
+      &issuer_doc,
+      &storage,
+      &fragment,
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  // Entry 5 was already revoked, hence it is not part of the changes.
+  assert_eq!(
+    update.record.changes,
+    vec![
+      StatusListChange {
+        index: 1,
+        previous: false,
+        current: true,
+      },
+      StatusListChange {
+        index: 7,
+        previous: false,
+        current: true,
+      },
+    ]
+  );
+  assert_eq!(update.record.purpose, StatusPurpose::Revocation);
+  assert_eq!(
+    update.record.status_list.as_ref().map(Url::as_str),
+    Some(STATUS_LIST_URL)
+  );
+  assert!(update.record.updated_at >= issued_before);
+
+  assert_eq!(status_list, update.status_list);
+  assert_eq!(status_list.clone().into_inner().issuance_date, update.record.updated_at);
+
+  // The JWT signs the very same, updated, status list.
+  let signed_status_list = decode_status_list(&update.jwt, &issuer_doc);
+  for (index, status) in [
+    (0, CredentialStatus::Valid),
+    (1, CredentialStatus::Revoked),
+    (5, CredentialStatus::Revoked),
+    (7, CredentialStatus::Revoked),
+  ] {
+    assert_eq!(status_list.entry(index).unwrap(), status);
+    assert_eq!(signed_status_list.entry(index).unwrap(), status);
+  }
+  assert_eq!(signed_status_list.into_inner().issuance_date, update.record.updated_at);
+}
+
+#[tokio::test]
+async fn invalid_updates_leave_the_status_list_untouched() {
+  let Setup {
+    issuer_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let mut status_list = status_list(&issuer_doc, StatusPurpose::Revocation);
+  status_list.set_entry(3, true).unwrap();
+  let original = status_list.clone();
+
+  // Revocations cannot be undone: the whole update is rejected, including the valid revocation.
+  let result = StatusListUpdate::new()
+    .revoke(1)
+    .reinstate(3)
+    .apply(
+      &mut status_list,
+      &issuer_doc,
+      &storage,
+      &fragment,
+      &JwsSignatureOptions::default(),
+    )
+    .await;
+  assert!(matches!(
+    result,
+    Err(StatusListUpdateError::InvalidUpdate(
+      StatusList2021CredentialError::UnreversibleRevocation
+    ))
+  ));
+  assert_eq!(status_list, original);
+}
+
+#[tokio::test]
+async fn signing_failures_leave_the_status_list_untouched() {
+  let Setup {
+    issuer_doc,
+    issuer_storage: storage,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let mut status_list = status_list(&issuer_doc, StatusPurpose::Suspension);
+  let original = status_list.clone();
+
+  let result = StatusListUpdate::new()
+    .revoke(1)
+    .apply(
+      &mut status_list,
+      &issuer_doc,
+      &storage,
+      "unknown-method",
+      &JwsSignatureOptions::default(),
+    )
+    .await;
+  assert!(matches!(result, Err(StatusListUpdateError::Signing(_))));
+  assert_eq!(status_list, original);
+}
+
+#[tokio::test]
+async fn suspensions_can_be_lifted() {
+  let Setup {
+    issuer_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let mut status_list = status_list(&issuer_doc, StatusPurpose::Suspension);
+  status_list.set_entry(2, true).unwrap();
+
+  let update = StatusListUpdate::new()
+    .reinstate(2)
+    .revoke(4)
+    .apply(
+      &mut status_list,
+      &issuer_doc,
+      &storage,
+      &fragment,
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  assert_eq!(update.record.changes.len(), 2);
+  assert_eq!(status_list.entry(2).unwrap(), CredentialStatus::Valid);
+  assert_eq!(status_list.entry(4).unwrap(), CredentialStatus::Suspended);
+  let signed_status_list = decode_status_list(&update.jwt, &issuer_doc);
+  assert_eq!(signed_status_list.entry(2).unwrap(), CredentialStatus::Valid);
+  assert_eq!(signed_status_list.entry(4).unwrap(), CredentialStatus::Suspended);
+}
+
+#[tokio::test]
+async fn publish_forwards_the_signed_status_list() {
+  let Setup {
+    issuer_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let mut status_list = status_list(&issuer_doc, StatusPurpose::Revocation);
+  let update = StatusListUpdate::new()
+    .revoke(1)
+    .apply(
+      &mut status_list,
+      &issuer_doc,
+      &storage,
+      &fragment,
+      &JwsSignatureOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let publisher = RecordingPublisher::default();
+  update.publish(&publisher).await.unwrap();
+  let published = publisher.published.into_inner().unwrap();
+  assert_eq!(published, vec![(update.status_list.clone(), update.jwt.clone())]);
+
+  assert!(matches!(
+    update.publish(&FailingPublisher).await,
+    Err(StatusListUpdateError::Publication(_))
+  ));
+}
+
+#[tokio::test]
+async fn signing_a_managed_status_list_clears_its_pending_changes() {
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let allocator = IndexAllocatorMemstore::new(IndexAllocatorConfig::new(
+    Url::parse("https://example.com/status/").unwrap(),
+  ))
+  .unwrap();
+  // The first status list handed out by the allocator.
+  let mut manager = StatusListManager::create(
+    Url::parse("https://example.com/status/0").unwrap(),
+    Issuer::Url(Url::parse(issuer_doc.id().as_str()).unwrap()),
+    StatusPurpose::Revocation,
+    allocator,
+  )
+  .unwrap();
+  let mut credential = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None).credential;
+  let index = manager.allocate(&mut credential).await.unwrap().index();
+  let credential_id = credential.id.as_ref().unwrap().as_str();
+  manager.revoke(credential_id).await.unwrap();
+  assert!(manager.has_pending_changes());
+
+  // A failed signature keeps the changes pending.
+  assert!(manager
+    .sign_status_list(&issuer_doc, &storage, "unknown-method", &JwsSignatureOptions::default())
+    .await
+    .is_err());
+  assert!(manager.has_pending_changes());
+
+  let jwt = manager
+    .sign_status_list(&issuer_doc, &storage, &fragment, &JwsSignatureOptions::default())
+    .await
+    .unwrap();
+  assert!(!manager.has_pending_changes());
+
+  let signed_status_list = decode_status_list(&jwt, &issuer_doc);
+  assert_eq!(signed_status_list.entry(index).unwrap(), CredentialStatus::Revoked);
+  assert_eq!(
+    signed_status_list.into_inner().issuance_date,
+    manager.status_list_credential().clone().into_inner().issuance_date
+  );
+}
+
+#[tokio::test]
+async fn suspended_credentials_are_validated_according_to_the_options() {
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: fragment,
+    ..
+  } = test_utils::setup_coredocument(None, None).await;
+  let mut status_list = status_list(&issuer_doc, StatusPurpose::Suspension);
+  status_list.set_entry(3, true).unwrap();
+
+  let expiration_date = Timestamp::parse("2100-01-01T00:00:00Z").unwrap();
+  let mut credential =
+    test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, Some(expiration_date)).credential;
+  credential.credential_status =
+    Some(StatusList2021Entry::new(Url::parse(STATUS_LIST_URL).unwrap(), StatusPurpose::Suspension, 3, None).into());
+  let jwt = issuer_doc
+    .create_credential_jwt(&credential, &storage, &fragment, &JwsSignatureOptions::default(), None)
+    .await
+    .unwrap();
+
+  let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  let validate = |options: JwtCredentialValidationOptions| {
+    validator.validate_with_status_list_2021::<_, Object>(
+      &jwt,
+      &issuer_doc,
+      &status_list,
+      &options,
+      FailFast::AllErrors,
+    )
+  };
+
+  let error = validate(JwtCredentialValidationOptions::default()).unwrap_err();
+  assert!(matches!(
+    error.validation_errors.as_slice(),
+    [JwtValidationError::Suspended]
+  ));
+  assert!(validate(JwtCredentialValidationOptions::default().allow_suspended()).is_ok());
+  assert!(validate(JwtCredentialValidationOptions::default().status_check(StatusCheck::SkipAll)).is_ok());
+}
@@ -0,0 +1,429 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::future::Future;
+
+use async_trait::async_trait;
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::crypto::PublicKey;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::transaction::Command;
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::types::transaction::TransactionDataAPI as _;
+use iota_interaction::types::transaction::TransactionKind;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use secret_storage::Error as SecretStorageError;
+use secret_storage::Signer;
+
+/// Asks the user to approve a transaction before it gets signed.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait SigningConfirmation {
+  /// Presents `summary` - and possibly the raw transaction `data` - to the user, returning whether the user
+  /// approved the transaction.
+  async fn confirm(&self, summary: &TransactionSummary, data: &TransactionData) -> bool;
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<F, Fut> SigningConfirmation for F
+where
+  F: Fn(TransactionSummary) -> Fut + OptionalSync,
+  Fut: Future<Output = bool> + OptionalSend,
+{
+  async fn confirm(&self, summary: &TransactionSummary, _data: &TransactionData) -> bool {
+    self(summary.clone()).await
+  }
+}
+
+/// A [`Signer`] wrapper requiring the user's approval - obtained through a [`SigningConfirmation`] - before any
+/// transaction is signed, similarly to hardware wallets and browser wallet extensions.
+///
+/// Transactions the user rejects fail with [`SecretStorageError::Other`] wrapping a [`SigningRejected`] error.
+///
+/// Move calls are only summarized as [`DidOperation`]s when they target one of the known identity packages - see
+/// [`UserConfirmedSigner::with_identity_packages`] - so that a same-named module of another package cannot pass
+/// itself off as an identity operation.
+#[derive(Debug, Clone)]
+pub struct UserConfirmedSigner<S, C> {
+  signer: S,
+  confirmation: C,
+  identity_packages: Vec<ObjectID>,
+}
+
+impl<S, C> UserConfirmedSigner<S, C> {
+  /// Creates a new [`UserConfirmedSigner`] asking for `confirmation` before signing with `signer`.
+  ///
+  /// With the `iota-document` feature, the identity packages of all well-known networks are recognized; otherwise
+  /// none is until [`UserConfirmedSigner::with_identity_packages`] is called.
+  pub fn new(signer: S, confirmation: C) -> Self {
+    Self {
+      signer,
+      confirmation,
+      identity_packages: well_known_identity_packages(),
+    }
+  }
+
+  /// Sets the IDs of the identity packages - every version of them - whose Move calls are summarized as
+  /// [`DidOperation`]s, e.g. the package history of an `IdentityClientReadOnly` connected to a private network.
+  pub fn with_identity_packages(mut self, packages: impl IntoIterator<Item = ObjectID>) -> Self {
+    self.identity_packages = packages.into_iter().collect();
+    self
+  }
+
+  /// Returns the IDs of the identity packages whose Move calls are summarized as [`DidOperation`]s.
+  pub fn identity_packages(&self) -> &[ObjectID] {
+    &self.identity_packages
+  }
+
+  /// Returns a reference to the wrapped signer.
+  pub fn signer(&self) -> &S {
+    &self.signer
+  }
+
+  /// Consumes this [`UserConfirmedSigner`], returning the wrapped signer.
+  pub fn into_inner(self) -> S {
+    self.signer
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<S, C> Signer<IotaKeySignature> for UserConfirmedSigner<S, C>
+where
+  S: Signer<IotaKeySignature> + OptionalSync,
+  C: SigningConfirmation + OptionalSync,
+{
+  type KeyId = S::KeyId;
+
+  fn key_id(&self) -> Self::KeyId {
+    self.signer.key_id()
+  }
+
+  async fn public_key(&self) -> Result<PublicKey, SecretStorageError> {
+    self.signer.public_key().await
+  }
+
+  async fn sign(&self, data: &TransactionData) -> Result<Signature, SecretStorageError> {
+    let summary = TransactionSummary::new(data, &self.identity_packages);
+    if !self.confirmation.confirm(&summary, data).await {
+      return Err(SecretStorageError::Other(SigningRejected.into()));
+    }
+
+    self.signer.sign(data).await
+  }
+}
+
+/// The user rejected signing a transaction.
+#[derive(Debug, Clone, Copy, thiserror::Error)]
+#[error("the user rejected signing the transaction")]
+#[non_exhaustive]
+pub struct SigningRejected;
+
+/// A structured, human-readable summary of a transaction, presented to the user before signing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionSummary {
+  /// The transaction's sender.
+  pub sender: IotaAddress,
+  /// The address paying for gas, which differs from `sender` in sponsored transactions.
+  pub gas_owner: IotaAddress,
+  /// The maximum amount of gas, in NANOS, the transaction may consume.
+  pub gas_budget: u64,
+  /// The gas price, in NANOS per gas unit.
+  pub gas_price: u64,
+  /// The operations carried out by the transaction, in order.
+  pub operations: Vec<TransactionOperation>,
+}
+
+impl TransactionSummary {
+  /// Summarizes the transaction `data`, recognizing the calls into `identity_packages` as [`DidOperation`]s.
+  pub fn new(data: &TransactionData, identity_packages: &[ObjectID]) -> Self {
+    let operations = match data.kind() {
+      TransactionKind::ProgrammableTransaction(pt) => pt
+        .commands
+        .iter()
+        .map(|command| TransactionOperation::new(command, identity_packages))
+        .collect(),
+      _ => vec![TransactionOperation::System],
+    };
+
+    Self {
+      sender: data.sender(),
+      gas_owner: data.gas_owner(),
+      gas_budget: data.gas_budget(),
+      gas_price: data.gas_price(),
+      operations,
+    }
+  }
+
+  /// Returns whether the transaction's gas is paid by an address other than its sender.
+  pub fn is_sponsored(&self) -> bool {
+    self.sender != self.gas_owner
+  }
+
+  /// Returns the DID operations carried out by the transaction.
+  pub fn did_operations(&self) -> impl Iterator<Item = &DidOperation> {
+    self.operations.iter().filter_map(|operation| match operation {
+      TransactionOperation::Did { operation, .. } => Some(operation),
+      _ => None,
+    })
+  }
+}
+
+impl Display for TransactionSummary {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "sender: {}", self.sender)?;
+    if self.is_sponsored() {
+      writeln!(f, "gas sponsor: {}", self.gas_owner)?;
+    }
+    writeln!(
+      f,
+      "gas budget: {} NANOS at {} NANOS/unit",
+      self.gas_budget, self.gas_price
+    )?;
+    for (i, operation) in self.operations.iter().enumerate() {
+      writeln!(f, "{}. {operation}", i + 1)?;
+    }
+
+    Ok(())
+  }
+}
+
+/// A single operation carried out by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TransactionOperation {
+  /// An operation on an Identity, i.e. a call into the `identity`, `controller` or `migration` modules of an identity
+  /// package.
+  Did {
+    /// The package the called module belongs to.
+    package: ObjectID,
+    /// The operation.
+    operation: DidOperation,
+  },
+  /// Any other Move call.
+  MoveCall {
+    /// The package the called module belongs to.
+    package: ObjectID,
+    /// The called module.
+    module: String,
+    /// The called function.
+    function: String,
+  },
+  /// A transfer of objects.
+  TransferObjects {
+    /// The number of transferred objects.
+    objects: usize,
+  },
+  /// A split or merge of coins.
+  CoinManagement,
+  /// A command preparing arguments for other commands, e.g. building a vector.
+  ArgumentPreparation,
+  /// The publication or upgrade of a Move package.
+  PackageManagement,
+  /// A non-programmable, system transaction.
+  System,
+}
+
+impl TransactionOperation {
+  fn new(command: &Command, identity_packages: &[ObjectID]) -> Self {
+    match command {
+      Command::MoveCall(call) => match identity_packages
+        .contains(&call.package)
+        .then(|| DidOperation::from_move_call(&call.module, &call.function))
+        .flatten()
+      {
+        Some(operation) => Self::Did {
+          package: call.package,
+          operation,
+        },
+        None => Self::MoveCall {
+          package: call.package,
+          module: call.module.to_string(),
+          function: call.function.to_string(),
+        },
+      },
+      Command::TransferObjects(objects, _) => Self::TransferObjects { objects: objects.len() },
+      Command::SplitCoins(..) | Command::MergeCoins(..) => Self::CoinManagement,
+      Command::MakeMoveVec(..) => Self::ArgumentPreparation,
+      Command::Publish(..) | Command::Upgrade(..) => Self::PackageManagement,
+    }
+  }
+}
+
+impl Display for TransactionOperation {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Did { package, operation } => write!(f, "{operation} (package {package})"),
+      Self::MoveCall {
+        package,
+        module,
+        function,
+      } => write!(f, "call {package}::{module}::{function}"),
+      Self::TransferObjects { objects } => write!(f, "transfer {objects} object(s)"),
+      Self::CoinManagement => f.write_str("split or merge coins"),
+      Self::ArgumentPreparation => f.write_str("prepare arguments"),
+      Self::PackageManagement => f.write_str("publish or upgrade a package"),
+      Self::System => f.write_str("system transaction"),
+    }
+  }
+}
+
+/// An operation on an Identity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DidOperation {
+  /// Creation of a new Identity.
+  CreateIdentity,
+  /// Migration of a legacy Alias Output to an Identity.
+  MigrateAliasOutput,
+  /// Proposal to update the DID Document.
+  ProposeDidUpdate,
+  /// Execution of a DID Document update.
+  ExecuteDidUpdate,
+  /// Proposal to change the Identity's controllers or threshold.
+  ProposeConfigChange,
+  /// Execution of a configuration change.
+  ExecuteConfigChange,
+  /// Proposal to send assets owned by the Identity.
+  ProposeSend,
+  /// Execution of a send.
+  ExecuteSend,
+  /// Proposal to borrow assets owned by the Identity.
+  ProposeBorrow,
+  /// Execution of a borrow.
+  ExecuteBorrow,
+  /// Proposal to delete the Identity.
+  ProposeDeletion,
+  /// Execution of an Identity deletion.
+  ExecuteDeletion,
+  /// Approval of a pending proposal.
+  ApproveProposal,
+  /// Deletion of a pending proposal.
+  DeleteProposal,
+  /// Delegation of a controller's capability.
+  Delegate,
+  /// Revocation of a delegation token.
+  RevokeDelegation,
+  /// Any other operation, identified by the called Move function.
+  Other(String),
+}
+
+impl DidOperation {
+  fn from_move_call(module: &str, function: &str) -> Option<Self> {
+    let operation = match (module, function) {
+      ("migration", "migrate_alias_output") => Self::MigrateAliasOutput,
+      ("identity", "new" | "new_with_controller" | "new_with_controllers") => Self::CreateIdentity,
+      ("identity", "propose_update") => Self::ProposeDidUpdate,
+      ("identity", "execute_update") => Self::ExecuteDidUpdate,
+      ("identity", "propose_config_change" | "propose_new_controller") => Self::ProposeConfigChange,
+      ("identity", "execute_config_change") => Self::ExecuteConfigChange,
+      ("identity", "propose_send") => Self::ProposeSend,
+      ("identity", "execute_send") => Self::ExecuteSend,
+      ("identity", "propose_borrow") => Self::ProposeBorrow,
+      ("identity", "execute_borrow") => Self::ExecuteBorrow,
+      ("identity", "propose_deletion") => Self::ProposeDeletion,
+      ("identity", "execute_deletion" | "delete") => Self::ExecuteDeletion,
+      ("identity", "approve_proposal") => Self::ApproveProposal,
+      ("identity", "delete_proposal") => Self::DeleteProposal,
+      ("identity", "revoke_token") => Self::RevokeDelegation,
+      ("controller", "delegate" | "delegate_with_permissions") => Self::Delegate,
+      ("identity", function) => Self::Other(function.to_owned()),
+      _ => return None,
+    };
+
+    Some(operation)
+  }
+}
+
+impl Display for DidOperation {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let description = match self {
+      Self::CreateIdentity => "create a new identity",
+      Self::MigrateAliasOutput => "migrate a legacy Alias Output to an identity",
+      Self::ProposeDidUpdate => "propose a DID Document update",
+      Self::ExecuteDidUpdate => "update the DID Document",
+      Self::ProposeConfigChange => "propose a change of controllers or threshold",
+      Self::ExecuteConfigChange => "change controllers or threshold",
+      Self::ProposeSend => "propose to send assets owned by the identity",
+      Self::ExecuteSend => "send assets owned by the identity",
+      Self::ProposeBorrow => "propose to borrow assets owned by the identity",
+      Self::ExecuteBorrow => "borrow assets owned by the identity",
+      Self::ProposeDeletion => "propose to delete the identity",
+      Self::ExecuteDeletion => "delete the identity",
+      Self::ApproveProposal => "approve a proposal",
+      Self::DeleteProposal => "delete a proposal",
+      Self::Delegate => "delegate a controller capability",
+      Self::RevokeDelegation => "revoke a delegation token",
+      Self::Other(function) => return write!(f, "identity operation `{function}`"),
+    };
+
+    f.write_str(description)
+  }
+}
+
+#[cfg(feature = "iota-document")]
+fn well_known_identity_packages() -> Vec<ObjectID> {
+  identity_iota_core::Network::PRESETS
+    .iter()
+    .flat_map(identity_iota_core::Network::package_history)
+    .collect()
+}
+
+#[cfg(not(feature = "iota-document"))]
+fn well_known_identity_packages() -> Vec<ObjectID> {
+  Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+  use iota_interaction::ident_str;
+
+  use super::*;
+
+  #[test]
+  fn identity_move_calls_are_recognized() {
+    assert_eq!(
+      DidOperation::from_move_call("identity", "propose_update"),
+      Some(DidOperation::ProposeDidUpdate)
+    );
+    assert_eq!(
+      DidOperation::from_move_call("identity", "destroy_controller_cap"),
+      Some(DidOperation::Other("destroy_controller_cap".to_owned()))
+    );
+    assert_eq!(DidOperation::from_move_call("coin", "zero"), None);
+  }
+
+  #[test]
+  fn only_calls_into_identity_packages_are_did_operations() {
+    let identity_package = ObjectID::from_single_byte(1);
+    let other_package = ObjectID::from_single_byte(2);
+    let call = |package| {
+      Command::move_call(
+        package,
+        ident_str!("identity").into(),
+        ident_str!("propose_update").into(),
+        vec![],
+        vec![],
+      )
+    };
+
+    assert_eq!(
+      TransactionOperation::new(&call(identity_package), &[identity_package]),
+      TransactionOperation::Did {
+        package: identity_package,
+        operation: DidOperation::ProposeDidUpdate,
+      }
+    );
+    assert!(matches!(
+      TransactionOperation::new(&call(other_package), &[identity_package]),
+      TransactionOperation::MoveCall { package, .. } if package == other_package
+    ));
+  }
+}
@@ -10,6 +10,19 @@ use crate::verification_method::VerificationMethod;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 
+/// How to handle private key material found in the `publicKeyJwk` of a [`VerificationMethod`] being built.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrivateKeyPolicy {
+  /// Fail with [`Error::PrivateKeyMaterialExposed`](crate::Error::PrivateKeyMaterialExposed).
+  #[default]
+  Reject,
+  /// Remove the private key parameters, e.g. `d`, keeping only the public key.
+  ///
+  /// Symmetric (`oct`) keys have no public part and are always rejected.
+  Strip,
+}
+
 /// A `MethodBuilder` is used to generate a customized `Method`.
 #[derive(Clone, Debug, Default)]
 pub struct MethodBuilder {
@@ -18,6 +31,7 @@ pub struct MethodBuilder {
   pub(crate) type_: Option<MethodType>,
   pub(crate) data: Option<MethodData>,
   pub(crate) properties: Object,
+  pub(crate) private_key_policy: PrivateKeyPolicy,
 }
 
 impl MethodBuilder {
@@ -29,6 +43,7 @@ impl MethodBuilder {
       type_: None,
       data: None,
       properties,
+      private_key_policy: PrivateKeyPolicy::default(),
     }
   }
 
@@ -60,6 +75,13 @@ impl MethodBuilder {
     self
   }
 
+  /// Sets how private key material in a `publicKeyJwk` is handled. Defaults to [`PrivateKeyPolicy::Reject`].
+  #[must_use]
+  pub fn private_key_policy(mut self, value: PrivateKeyPolicy) -> Self {
+    self.private_key_policy = value;
+    self
+  }
+
   /// Returns a new `VerificationMethod` based on the `MethodBuilder` configuration.
   pub fn build(self) -> Result<VerificationMethod> {
     VerificationMethod::from_builder(self)
@@ -166,4 +188,39 @@ mod tests {
     let err: Error = VerificationMethod::new_from_jwk(did, jwk, Some("#frag")).unwrap_err();
     assert!(matches!(err, Error::PrivateKeyMaterialExposed));
   }
+
+  #[test]
+  fn test_jwk_private_key_material_can_be_stripped() {
+    let jwk: Jwk = Jwk::from_json(
+      r#"
+      {
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "kid": "key-0",
+        "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A",
+        "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+      }
+    "#,
+    )
+    .unwrap();
+
+    let did: CoreDID = "did:example:123".parse().unwrap();
+    let method: VerificationMethod =
+      VerificationMethod::new_from_jwk_with_policy(did.clone(), jwk.clone(), None, PrivateKeyPolicy::Strip).unwrap();
+    let public_jwk: &Jwk = method.data().public_key_jwk().unwrap();
+    assert!(public_jwk.is_public());
+    assert_eq!(public_jwk, &jwk.to_public().unwrap());
+    assert_eq!(method.id().fragment(), Some("key-0"));
+
+    let secret: Jwk = Jwk::from_json(r#"{ "kty": "oct", "k": "c2VjcmV0" }"#).unwrap();
+    let err: Error = MethodBuilder::default()
+      .id(did.clone().join("#key-1").unwrap())
+      .controller(did)
+      .type_(MethodType::JSON_WEB_KEY_2020)
+      .data(MethodData::PublicKeyJwk(secret))
+      .private_key_policy(PrivateKeyPolicy::Strip)
+      .build()
+      .unwrap_err();
+    assert!(matches!(err, Error::PrivateKeyMaterialExposed));
+  }
 }
@@ -7,8 +7,14 @@ use std::borrow::Cow;
 
 use identity_did::DIDCompositeJwk;
 use identity_did::DIDJwk;
+use identity_did::DIDKey;
 use identity_jose::jwk::CompositeJwk;
+use identity_jose::jwk::EcxCurve;
+use identity_jose::jwk::EdCurve;
 use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParamsOkp;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jwu;
 use serde::de;
 use serde::Deserialize;
 use serde::Serialize;
@@ -23,11 +29,17 @@ use crate::verification_method::MethodBuilder;
 use crate::verification_method::MethodData;
 use crate::verification_method::MethodRef;
 use crate::verification_method::MethodType;
+use crate::verification_method::PrivateKeyPolicy;
 use crate::CustomMethodData;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_did::DID;
 
+/// Multicodec prefix of Ed25519 public keys.
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+/// Multicodec prefix of X25519 public keys.
+const X25519_MULTICODEC: [u8; 2] = [0xec, 0x01];
+
 /// A DID Document Verification Method.
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#verification-method-properties)
@@ -75,17 +87,22 @@ impl VerificationMethod {
       return Err(Error::InvalidMethod("empty id fragment"));
     }
 
-    if let Some(MethodData::PublicKeyJwk(ref jwk)) = builder.data {
-      if !jwk.is_public() {
-        return Err(crate::error::Error::PrivateKeyMaterialExposed);
-      }
+    let data: MethodData = match builder.data {
+      Some(MethodData::PublicKeyJwk(jwk)) if !jwk.is_public() => match builder.private_key_policy {
+        PrivateKeyPolicy::Strip => jwk
+          .to_public()
+          .map(MethodData::PublicKeyJwk)
+          .ok_or(Error::PrivateKeyMaterialExposed)?,
+        PrivateKeyPolicy::Reject => return Err(Error::PrivateKeyMaterialExposed),
+      },
+      data => data.ok_or(Error::InvalidMethod("missing data"))?,
     };
 
     Ok(VerificationMethod {
       id,
       controller: builder.controller.ok_or(Error::InvalidMethod("missing controller"))?,
       type_: builder.type_.ok_or(Error::InvalidMethod("missing type"))?,
-      data: builder.data.ok_or(Error::InvalidMethod("missing data"))?,
+      data,
       properties: builder.properties,
     })
   }
@@ -202,7 +219,24 @@ impl VerificationMethod {
   ///   of `kid` as their fragment identifier. This is done automatically if `None` is passed in as the fragment.
   /// - It is recommended that [`Jwk`] kid values are set to the public key fingerprint. See
   ///   [`Jwk::thumbprint_sha256_b64`](Jwk::thumbprint_sha256_b64).
+  ///
+  /// # Errors
+  /// Fails with [`Error::PrivateKeyMaterialExposed`] if `key` contains private key material; see
+  /// [`VerificationMethod::new_from_jwk_with_policy`] to strip it instead.
   pub fn new_from_jwk<D: DID>(did: D, key: Jwk, fragment: Option<&str>) -> Result<Self> {
+    Self::new_from_jwk_with_policy(did, key, fragment, PrivateKeyPolicy::Reject)
+  }
+
+  /// Same as [`VerificationMethod::new_from_jwk`], handling private key material in `key` according to `policy`.
+  ///
+  /// Use [`PrivateKeyPolicy::Strip`] when `key` comes from an external source that may hand out private keys,
+  /// making sure only the public key ends up in the method.
+  pub fn new_from_jwk_with_policy<D: DID>(
+    did: D,
+    key: Jwk,
+    fragment: Option<&str>,
+    policy: PrivateKeyPolicy,
+  ) -> Result<Self> {
     // If a fragment is given use that, otherwise use the JWK's `kid` if it is set.
     let fragment: Cow<'_, str> = {
       let given_fragment: &str = fragment
@@ -225,6 +259,7 @@ impl VerificationMethod {
       .controller(did.into())
       .type_(MethodType::JSON_WEB_KEY_2020)
       .data(MethodData::PublicKeyJwk(key))
+      .private_key_policy(policy)
       .build()
   }
 }
@@ -273,6 +308,55 @@ impl VerificationMethod {
   }
 }
 
+impl VerificationMethod {
+  // ===========================================================================
+  // Key material
+  // ===========================================================================
+
+  /// Returns the public key of this method as a [`Jwk`], converting the key material of legacy method types.
+  ///
+  /// Besides `publicKeyJwk` key material, the following is supported:
+  /// - `Ed25519VerificationKey2018` and `X25519KeyAgreementKey2019` keys, encoded as `publicKeyBase58` or
+  ///   `publicKeyMultibase`;
+  /// - `Ed25519VerificationKey2020` and `X25519KeyAgreementKey2020` keys, encoded as multicodec-prefixed
+  ///   `publicKeyMultibase`.
+  ///
+  /// # Errors
+  /// Fails with [`Error::NotPublicKeyJwk`] for other method types, or if the key material is invalid.
+  pub fn public_key_as_jwk(&self) -> Result<Cow<'_, Jwk>> {
+    if let MethodData::PublicKeyJwk(jwk) = self.data() {
+      return Ok(Cow::Borrowed(jwk));
+    }
+
+    let (curve, multicodec): (&str, [u8; 2]) = match self.type_().as_str() {
+      "Ed25519VerificationKey2018" | "Ed25519VerificationKey2020" => (EdCurve::Ed25519.name(), ED25519_MULTICODEC),
+      "X25519KeyAgreementKey2019" | "X25519KeyAgreementKey2020" => (EcxCurve::X25519.name(), X25519_MULTICODEC),
+      _ => return Err(Error::NotPublicKeyJwk),
+    };
+
+    let decoded: Vec<u8> = self.data().try_decode()?;
+    let key: &[u8] = match decoded.strip_prefix(multicodec.as_slice()) {
+      Some(unprefixed) if unprefixed.len() == 32 => unprefixed,
+      _ if decoded.len() == 32 => decoded.as_slice(),
+      _ => {
+        return Err(Error::InvalidMethodDataTransformation(
+          "unexpected public key length for the method type",
+        ))
+      }
+    };
+
+    let mut params = JwkParamsOkp::new();
+    params.crv = curve.to_owned();
+    params.x = jwu::encode_b64(key);
+    let mut jwk = Jwk::from_params(params);
+    if curve == EdCurve::Ed25519.name() {
+      jwk.set_alg(JwsAlgorithm::EdDSA.name());
+    }
+
+    Ok(Cow::Owned(jwk))
+  }
+}
+
 impl Display for VerificationMethod {
   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
     self.fmt_json(f)
@@ -302,6 +386,16 @@ impl TryFrom<DIDJwk> for VerificationMethod {
   }
 }
 
+impl TryFrom<DIDKey> for VerificationMethod {
+  type Error = Error;
+  fn try_from(did: DIDKey) -> Result<Self, Self::Error> {
+    let jwk = did.jwk();
+    // A did:key's only verification method is identified by the DID's method-specific ID.
+    let fragment = did.method_id().to_owned();
+    Self::new_from_jwk(did, jwk, Some(&fragment))
+  }
+}
+
 impl TryFrom<DIDCompositeJwk> for VerificationMethod {
   type Error = Error;
   fn try_from(did: DIDCompositeJwk) -> Result<Self, Self::Error> {
@@ -353,3 +447,70 @@ impl From<_VerificationMethod> for VerificationMethod {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::BaseEncoding;
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  const PUBLIC_KEY: [u8; 32] = [7; 32];
+
+  fn method(method_type: &str, key_material: (&str, String)) -> VerificationMethod {
+    let mut json = serde_json::json!({
+      "id": "did:example:123#key-1",
+      "controller": "did:example:123",
+      "type": method_type,
+    });
+    json[key_material.0] = key_material.1.into();
+    VerificationMethod::from_json_value(json).unwrap()
+  }
+
+  #[test]
+  fn legacy_key_material_is_converted_to_jwk() {
+    let mut prefixed: Vec<u8> = ED25519_MULTICODEC.to_vec();
+    prefixed.extend(PUBLIC_KEY);
+
+    for (method_type, key_material, crv) in [
+      (
+        "Ed25519VerificationKey2018",
+        ("publicKeyBase58", BaseEncoding::encode_base58(&PUBLIC_KEY)),
+        "Ed25519",
+      ),
+      (
+        "Ed25519VerificationKey2020",
+        ("publicKeyMultibase", BaseEncoding::encode_multibase(&prefixed, None)),
+        "Ed25519",
+      ),
+      (
+        "X25519KeyAgreementKey2019",
+        ("publicKeyBase58", BaseEncoding::encode_base58(&PUBLIC_KEY)),
+        "X25519",
+      ),
+    ] {
+      let method = method(method_type, key_material);
+      let jwk = method.public_key_as_jwk().unwrap();
+      let params = jwk.try_okp_params().unwrap();
+
+      assert_eq!(params.crv, crv);
+      assert_eq!(params.x, jwu::encode_b64(PUBLIC_KEY));
+      assert!(params.d.is_none());
+    }
+  }
+
+  #[test]
+  fn unsupported_key_material_is_rejected() {
+    let wrong_length = method(
+      "Ed25519VerificationKey2018",
+      ("publicKeyBase58", BaseEncoding::encode_base58(&[7; 31])),
+    );
+    assert!(wrong_length.public_key_as_jwk().is_err());
+
+    let unknown_type = method(
+      "EcdsaSecp256k1VerificationKey2019",
+      ("publicKeyBase58", BaseEncoding::encode_base58(&PUBLIC_KEY)),
+    );
+    assert!(matches!(unknown_type.public_key_as_jwk(), Err(Error::NotPublicKeyJwk)));
+  }
+}
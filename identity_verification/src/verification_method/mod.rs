@@ -15,6 +15,7 @@ mod method_scope;
 mod method_type;
 
 pub use self::builder::MethodBuilder;
+pub use self::builder::PrivateKeyPolicy;
 pub use self::material::CustomMethodData;
 pub use self::material::MethodData;
 pub use self::method::VerificationMethod;